@@ -30,7 +30,8 @@ impl DutyDuckApiClient {
     ///
     /// # Examples
     /// ```
-    /// let client = DutyDuckApiClient::new();
+    /// # use api_client_rs::DutyDuckApiClient;
+    /// let client = DutyDuckApiClient::new("https://api.example.com");
     /// ```
     pub fn new(base_url: impl IntoUrl) -> Self {
         Self {
@@ -51,8 +52,12 @@ impl DutyDuckApiClient {
     ///
     /// # Examples
     /// ```
-    /// let client = DutyDuckApiClient::new();
+    /// # use api_client_rs::DutyDuckApiClient;
+    /// # fn main() -> anyhow::Result<()> {
+    /// let client = DutyDuckApiClient::new("https://api.example.com");
     /// client.set_api_token_id("my-token-id".to_string())?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn set_api_token_id(&self, token_id: String) -> anyhow::Result<()> {
         let mut auth_token = self
@@ -74,8 +79,12 @@ impl DutyDuckApiClient {
     ///
     /// # Examples
     /// ```
-    /// let client = DutyDuckApiClient::new();
+    /// # use api_client_rs::DutyDuckApiClient;
+    /// # fn main() -> anyhow::Result<()> {
+    /// let client = DutyDuckApiClient::new("https://api.example.com");
     /// client.set_api_token_secret_key("my-secret-key".to_string())?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn set_api_token_secret_key(&self, secret_key: String) -> anyhow::Result<()> {
         let mut auth_token = self
@@ -93,7 +102,8 @@ impl DutyDuckApiClient {
     ///
     /// # Examples
     /// ```
-    /// let client = DutyDuckApiClient::new();
+    /// # use api_client_rs::DutyDuckApiClient;
+    /// let client = DutyDuckApiClient::new("https://api.example.com");
     /// let auth_client = client.auth();
     /// ```
     pub fn auth(&self) -> AuthSubclient {
@@ -109,7 +119,8 @@ impl DutyDuckApiClient {
     ///
     /// # Examples
     /// ```
-    /// let client = DutyDuckApiClient::new();
+    /// # use api_client_rs::DutyDuckApiClient;
+    /// let client = DutyDuckApiClient::new("https://api.example.com");
     /// let tasks_client = client.tasks();
     /// ```
     pub fn tasks(&self) -> TasksSubclient {
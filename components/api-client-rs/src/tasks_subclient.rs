@@ -1,5 +1,8 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, SecondsFormat, Utc};
 use reqwest::Method;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{ClientResult, DutyDuckApiClient, ResponseExtention};
 
@@ -20,21 +23,38 @@ impl TasksSubclient {
             .await
     }
 
+    /// Previews the next runs of a cron schedule, and checks that it is valid
+    pub async fn preview_schedule(&self, command: PreviewTaskScheduleCommand) -> ClientResult<TaskSchedulePreview> {
+        let url = self.client.base_url.join("/tasks/schedule-preview").unwrap();
+        self.client
+            .request(Method::POST, url)?
+            .json(&command)
+            .send()
+            .await?
+            .json_or_err()
+            .await
+    }
+
     pub fn start_task(&self, task_id: impl Into<String>) -> StartTaskBuilder {
         StartTaskBuilder {
             client: self.client.clone(),
             task_id: task_id.into(),
             new_task: None,
             abort_previous_running_task: false,
+            run_id: None,
         }
     }
 
-    pub async fn send_heartbeat(&self, task_id: &str) -> ClientResult<()> {
-        let url = self
+    /// Sends a heartbeat for a running run of a task, identified by the run id returned when it started
+    pub async fn send_heartbeat(&self, task_id: &str, run_id: Option<&str>) -> ClientResult<()> {
+        let mut url = self
             .client
             .base_url
             .join(&format!("/tasks/{task_id}/heartbeat"))
             .unwrap();
+        if let Some(run_id) = run_id {
+            url.query_pairs_mut().append_pair("runId", run_id);
+        }
         self.client
             .request(Method::POST, url)?
             .send()
@@ -43,6 +63,31 @@ impl TasksSubclient {
             .await
     }
 
+    /// Appends lines of output to a task run, identified by its start date and its run id
+    pub async fn append_task_run_logs(
+        &self,
+        task_id: &str,
+        started_at: DateTime<Utc>,
+        run_id: &str,
+        lines: Vec<TaskRunLogLine>,
+    ) -> ClientResult<()> {
+        let started_at = started_at.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+        let mut url = self
+            .client
+            .base_url
+            .join(&format!("/tasks/{task_id}/runs/{started_at}/logs"))
+            .unwrap();
+        // run ids are chosen by the clients, so they are percent-encoded
+        url.query_pairs_mut().append_pair("runId", run_id);
+        self.client
+            .request(Method::POST, url)?
+            .json(&AppendTaskRunLogsCommand { lines })
+            .send()
+            .await?
+            .ok_or_err()
+            .await
+    }
+
     pub fn finish_task(&self, task_id: impl Into<String>) -> FinishTaskBuilder {
         FinishTaskBuilder {
             task_id: task_id.into(),
@@ -50,6 +95,7 @@ impl TasksSubclient {
             status: FinishedTaskStatus::Success,
             exit_code: None,
             error_message: None,
+            run_id: None,
         }
     }
 }
@@ -59,6 +105,7 @@ pub struct StartTaskBuilder {
     task_id: String,
     client: DutyDuckApiClient,
     abort_previous_running_task: bool,
+    run_id: Option<String>,
 }
 
 impl StartTaskBuilder {
@@ -72,7 +119,13 @@ impl StartTaskBuilder {
         self
     }
 
-    pub async fn send(self) -> ClientResult<()> {
+    /// Identifies the new run among the running runs of the task, a random id is generated by the server otherwise
+    pub fn with_run_id(mut self, run_id: impl Into<String>) -> Self {
+        self.run_id = Some(run_id.into());
+        self
+    }
+
+    pub async fn send(self) -> ClientResult<StartTaskResponse> {
         let url = self
             .client
             .base_url
@@ -81,13 +134,14 @@ impl StartTaskBuilder {
         let command = StartTaskCommand {
             new_task: self.new_task,
             abort_previous_running_task: self.abort_previous_running_task,
+            run_id: self.run_id,
         };
         self.client
             .request(Method::POST, url)?
             .json(&command)
             .send()
             .await?
-            .ok_or_err()
+            .json_or_err()
             .await
     }
 }
@@ -98,6 +152,7 @@ pub struct FinishTaskBuilder {
     status: FinishedTaskStatus,
     exit_code: Option<i32>,
     error_message: Option<String>,
+    run_id: Option<String>,
 }
 
 impl FinishTaskBuilder {
@@ -126,6 +181,12 @@ impl FinishTaskBuilder {
         self
     }
 
+    /// Finishes the run with this id, instead of the oldest running run of the task
+    pub fn with_run_id(mut self, run_id: impl Into<String>) -> Self {
+        self.run_id = Some(run_id.into());
+        self
+    }
+
     pub async fn send(self) -> ClientResult<()> {
         let url = self
             .client
@@ -136,6 +197,7 @@ impl FinishTaskBuilder {
             status: self.status,
             exit_code: self.exit_code,
             error_message: self.error_message,
+            run_id: self.run_id,
         };
         self.client
             .request(Method::POST, url)?
@@ -149,15 +211,54 @@ impl FinishTaskBuilder {
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-
 pub struct CreateTaskCommand {
     pub id: String,
     pub name: Option<String>,
     pub description: Option<String>,
     pub cron_schedule: Option<String>,
+    pub time_zone: Option<String>,
     pub start_window_seconds: Option<u32>,
     pub lateness_window_seconds: Option<u32>,
     pub heartbeat_timeout_seconds: Option<u32>,
+    pub max_duration_seconds: Option<u32>,
+    pub max_concurrent_runs: Option<u32>,
+    pub upstream_task_ids: Option<Vec<String>>,
+    pub metadata: Option<EntityMetadata>,
+}
+
+/// Key-value records used to tag a task (e.g. by team or environment) and to filter tasks
+#[derive(Debug, Serialize, Default)]
+pub struct EntityMetadata {
+    pub records: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewTaskScheduleCommand {
+    pub cron_schedule: String,
+    pub time_zone: Option<String>,
+    pub start_window_seconds: Option<u32>,
+    pub lateness_window_seconds: Option<u32>,
+    pub runs: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskSchedulePreview {
+    /// A human-readable description of the cron schedule
+    pub description: String,
+    pub time_zone: String,
+    pub start_window_seconds: u32,
+    pub lateness_window_seconds: u32,
+    pub next_runs: Vec<ScheduledTaskRun>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledTaskRun {
+    pub due_at: DateTime<Utc>,
+    pub late_at: DateTime<Utc>,
+    pub absent_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Serialize)]
@@ -165,6 +266,16 @@ pub struct CreateTaskCommand {
 struct StartTaskCommand {
     new_task: Option<NewTask>,
     abort_previous_running_task: bool,
+    run_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartTaskResponse {
+    /// The start date of the new task run, which identifies it
+    pub started_at: DateTime<Utc>,
+    /// The id of the new task run, to send its heartbeats and finish it
+    pub run_id: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -173,9 +284,14 @@ pub struct NewTask {
     pub name: Option<String>,
     pub description: Option<String>,
     pub cron_schedule: Option<String>,
+    pub time_zone: Option<String>,
     pub start_window_seconds: Option<u32>,
     pub lateness_window_seconds: Option<u32>,
     pub heartbeat_timeout_seconds: Option<u32>,
+    pub max_duration_seconds: Option<u32>,
+    pub max_concurrent_runs: Option<u32>,
+    pub upstream_task_ids: Option<Vec<String>>,
+    pub metadata: Option<EntityMetadata>,
 }
 
 #[derive(Debug, Serialize)]
@@ -192,4 +308,25 @@ struct FinishTaskCommand {
     status: FinishedTaskStatus,
     exit_code: Option<i32>,
     error_message: Option<String>,
+    run_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppendTaskRunLogsCommand {
+    lines: Vec<TaskRunLogLine>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRunLogLine {
+    pub stream: TaskRunLogStream,
+    pub line: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskRunLogStream {
+    Stdout,
+    Stderr,
 }
@@ -1,7 +1,7 @@
 import { Sema } from "async-sema";
-import puppeteer, { Page, PuppeteerError, TimeoutError } from "puppeteer-core";
+import puppeteer, { HTTPResponse, Page, PuppeteerError, TimeoutError } from "puppeteer-core";
 import { createLogger } from "./logger.js";
-import { HttpErrorKind, HttpRequest, HttpResponse } from "../compiled_proto/browser.js";
+import { HttpErrorKind, HttpMethod, HttpRequest, HttpResponse } from "../compiled_proto/browser.js";
 import { Resolver } from "node:dns/promises";
 
 type BrowserOptions = {
@@ -24,8 +24,80 @@ const ChromeErrorStatusMapping: Record<string, [HttpErrorKind, string]> = {
     ERR_ABORTED: [HttpErrorKind.CONNECT, "The operation was aborted"],
     ERR_CONTENT_DECODING_FAILED: [HttpErrorKind.DECODE, "The content decoding failed"],
     ERR_TOO_MANY_REDIRECTS: [HttpErrorKind.REDIRECT, "Too many redirects"],
+    ERR_CERT_DATE_INVALID: [HttpErrorKind.TLS_CERTIFICATE, "The certificate has expired or is not yet valid"],
+    ERR_CERT_AUTHORITY_INVALID: [HttpErrorKind.TLS_CERTIFICATE, "The certificate authority is not trusted"],
+    ERR_CERT_COMMON_NAME_INVALID: [HttpErrorKind.TLS_CERTIFICATE, "The certificate does not match the host"],
+    ERR_CERT_REVOKED: [HttpErrorKind.TLS_CERTIFICATE, "The certificate has been revoked"],
+    ERR_CERT_INVALID: [HttpErrorKind.TLS_CERTIFICATE, "The certificate is invalid"],
 };
 
+const HttpMethodNames: Partial<Record<HttpMethod, string>> = {
+    [HttpMethod.GET]: "GET",
+    [HttpMethod.HEAD]: "HEAD",
+    [HttpMethod.POST]: "POST",
+    [HttpMethod.PUT]: "PUT",
+    [HttpMethod.PATCH]: "PATCH",
+    [HttpMethod.DELETE]: "DELETE",
+    [HttpMethod.OPTIONS]: "OPTIONS",
+};
+
+/**
+ * Build the headers sent along with the request, including the authentication
+ */
+const requestHeaders = (request: HttpRequest): Record<string, string> => {
+    const headers = { ...request.httpHeaders };
+    if (request.authentication?.basic) {
+        const { username, password } = request.authentication.basic;
+        headers["Authorization"] = `Basic ${Buffer.from(`${username}:${password}`).toString("base64")}`;
+    } else if (request.authentication?.bearer) {
+        headers["Authorization"] = `Bearer ${request.authentication.bearer.token}`;
+    }
+    return headers;
+}
+
+/**
+ * Get the maximum number of redirects to follow, undefined if the browser's own limit applies
+ */
+const maxRedirectHops = (request: HttpRequest): number | undefined => {
+    if (!request.redirectPolicy) {
+        return undefined;
+    }
+    if (!request.redirectPolicy.follow) {
+        return 0;
+    }
+    return request.redirectPolicy.maxHops;
+}
+
+/**
+ * Intercept the navigation requests of the page to set the method and the body of the request,
+ * and to stop following redirects beyond the maximum number of hops.
+ * `onRedirectBlocked` is called with the last redirect response when a redirect is not followed.
+ */
+const interceptNavigationRequests = async (page: Page, request: HttpRequest, onRedirectBlocked: (redirectResponse: HTTPResponse | null) => void) => {
+    const maxHops = maxRedirectHops(request);
+    await page.setRequestInterception(true);
+    page.on("request", (interceptedRequest) => {
+        if (!interceptedRequest.isNavigationRequest() || interceptedRequest.frame() !== page.mainFrame()) {
+            interceptedRequest.continue();
+            return;
+        }
+
+        const redirectChain = interceptedRequest.redirectChain();
+        if (redirectChain.length === 0) {
+            const headers = { ...interceptedRequest.headers() };
+            if (request.bodyContentType !== undefined) {
+                headers["content-type"] = request.bodyContentType;
+            }
+            interceptedRequest.continue({ method: HttpMethodNames[request.method] ?? "GET", postData: request.body, headers });
+        } else if (maxHops !== undefined && redirectChain.length > maxHops) {
+            onRedirectBlocked(redirectChain[redirectChain.length - 1].response());
+            interceptedRequest.abort();
+        } else {
+            interceptedRequest.continue();
+        }
+    });
+}
+
 const createBrowser = async (options: BrowserOptions): Promise<Browser> => {
     const semaphore = new Sema(options.maxOpenPages);
     const resolver = new Resolver();
@@ -67,6 +139,9 @@ const createBrowser = async (options: BrowserOptions): Promise<Browser> => {
         await acquirePermit();
 
         let page: Page | undefined;
+        let fetchStart = performance.now();
+        // set when a redirect is not followed because of the redirect policy
+        const blockedRedirect: { blocked: boolean, response: HTTPResponse | null } = { blocked: false, response: null };
         // create an empty response
         const response: HttpResponse = {
             httpCode: undefined,
@@ -79,6 +154,7 @@ const createBrowser = async (options: BrowserOptions): Promise<Browser> => {
             responseBodyContent: undefined,
             error: undefined,
             errorMessage: undefined,
+            tlsCertificate: undefined,
         }
 
         try {
@@ -90,11 +166,16 @@ const createBrowser = async (options: BrowserOptions): Promise<Browser> => {
             await page.setUserAgent('Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36');
             await page.setCacheEnabled(false);
             await page.setViewport({ width: 1280, height: 800 });
-            await page.setExtraHTTPHeaders(request.httpHeaders);
+            await page.setExtraHTTPHeaders(requestHeaders(request));
+            await interceptNavigationRequests(page, request, (redirectResponse) => {
+                blockedRedirect.blocked = true;
+                blockedRedirect.response = redirectResponse;
+                response.responseTimeMs = Math.round(performance.now() - fetchStart);
+            });
 
             // navigate to the endpoint and wait for the page to load
             // measure the time it takes to load the page
-            const fetchStart = performance.now();
+            fetchStart = performance.now();
             const pageResponse = await page.goto(request.endpoint, { waitUntil: "load", timeout: request.requestTimeoutMs, signal: abortSignal });
             response.responseTimeMs = Math.round(performance.now() - fetchStart);
 
@@ -104,6 +185,18 @@ const createBrowser = async (options: BrowserOptions): Promise<Browser> => {
             // get the response IP address
             response.responseIpAddress = pageResponse.remoteAddress().ip;
 
+            // get the certificate, only set for pages loaded over HTTPS
+            const securityDetails = pageResponse.securityDetails();
+            if (securityDetails) {
+                response.tlsCertificate = {
+                    subject: securityDetails.subjectName(),
+                    issuer: securityDetails.issuer(),
+                    subjectAlternativeNames: securityDetails.subjectAlternativeNames(),
+                    validFrom: securityDetails.validFrom(),
+                    validTo: securityDetails.validTo(),
+                };
+            }
+
             // get the http code
             response.httpCode = pageResponse?.status();
             if (response.httpCode >= 400) {
@@ -133,7 +226,18 @@ const createBrowser = async (options: BrowserOptions): Promise<Browser> => {
         }
         // Catch all errors and map them to an error kind and a message
         catch (error) {
-            if (error instanceof TimeoutError) {
+            if (blockedRedirect.blocked) {
+                const redirectResponse = blockedRedirect.response;
+                if (maxRedirectHops(request) === 0 && redirectResponse) {
+                    // redirects are not followed, so the redirect response is the response of the monitor
+                    response.httpCode = redirectResponse.status();
+                    response.httpHeaders = redirectResponse.headers();
+                    response.responseIpAddress = redirectResponse.remoteAddress().ip;
+                } else {
+                    response.error = HttpErrorKind.REDIRECT;
+                    response.errorMessage = "Too many redirects";
+                }
+            } else if (error instanceof TimeoutError) {
                 response.error = HttpErrorKind.TIMEOUT;
                 response.errorMessage = "The page took too long to load";
                 logger.debug({ error }, "A timeout error occurred while fetching a page");
@@ -213,6 +317,9 @@ export const createBrowserPool = async (numBrowsers: number, options: BrowserOpt
                     requestTimeoutMs: 10000,
                     httpHeaders: {
                     },
+                    method: HttpMethod.GET,
+                    authentication: undefined,
+                    redirectPolicy: undefined,
                 });
                 logger.info({ httpCode: response.httpCode, error: response.error, errorMessage: response.errorMessage }, "Tested browser successfully");
             }
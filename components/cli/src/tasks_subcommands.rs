@@ -1,16 +1,42 @@
-use std::time::Duration;
+use std::{
+    collections::VecDeque,
+    process::Stdio,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
 
 use crate::config::Config;
 use anyhow::Context;
-use api_client_rs::{ClientError, DutyDuckApiClient, NewTask};
+use api_client_rs::{
+    ClientError, DutyDuckApiClient, EntityMetadata, NewTask, PreviewTaskScheduleCommand, TaskRunLogLine, TaskRunLogStream,
+    TasksSubclient,
+};
+use chrono::{DateTime, Local, Utc};
 use clap::*;
 use reqwest::StatusCode;
-use tokio::process::Child;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader},
+    process::Child,
+    task::JoinHandle,
+};
+
+/// The interval at which the output of the process is sent to the platform
+const LOGS_INTERVAL: Duration = Duration::from_secs(5);
+/// The maximum number of lines sent at once. When the process outputs more lines between two sends, only the last lines are sent
+const MAXIMUM_LOG_LINES_PER_REQUEST: usize = 1000;
+/// How long the remaining output is read once the process exited.
+/// Processes started by the task can keep the output open after it exited, the output is then sent as is
+const OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_secs(2);
 
 #[derive(Subcommand)]
 pub enum TasksCommands {
     /// Run a process locally, wrapped in a task run. The status of the process will be reported back to the platform.
-    Run(RunCommand),
+    Run(Box<RunCommand>),
+    /// Check a cron schedule and print the next times a task with this schedule is due, late and absent
+    PreviewSchedule(PreviewScheduleCommand),
 }
 
 #[derive(Args)]
@@ -24,6 +50,9 @@ pub struct RunCommand {
     /// Whether to abort the previous running task if there is a running task with the same id
     #[arg(long)]
     pub abort_previous_running_task: bool,
+    /// Identifies the run among the concurrent runs of the task (e.g. the name of a shard). Generated by the platform if not set
+    #[arg(long)]
+    pub run_id: Option<String>,
     /// The name of the newly-created task
     #[arg(long)]
     pub name: Option<String>,
@@ -33,6 +62,9 @@ pub struct RunCommand {
     /// The cron schedule of the newly-created task
     #[arg(long)]
     pub cron_schedule: Option<String>,
+    /// The IANA time zone in which the cron schedule of the newly-created task is evaluated (e.g. Europe/Paris). Defaults to UTC
+    #[arg(long, requires = "cron_schedule")]
+    pub time_zone: Option<String>,
     /// The start window of the newly-created task
     #[arg(long)]
     pub start_window_seconds: Option<u32>,
@@ -42,69 +74,201 @@ pub struct RunCommand {
     /// The heartbeat timeout of the newly-created task
     #[arg(long)]
     pub heartbeat_timeout_seconds: Option<u32>,
+    /// The maximum duration of a run of the newly-created task, after which the run is considered timed out
+    #[arg(long)]
+    pub max_duration_seconds: Option<u32>,
+    /// The maximum number of runs of the newly-created task that can be running at the same time
+    #[arg(long)]
+    pub max_concurrent_runs: Option<u32>,
+    /// The id of a task that must finish before the newly-created task is due. Can be repeated
+    #[arg(long = "upstream-task-id")]
+    pub upstream_task_ids: Vec<String>,
+    /// A metadata record of the newly-created task, e.g. team=billing. Can be repeated
+    #[arg(long = "metadata", value_parser = parse_metadata_record)]
+    pub metadata: Vec<(String, String)>,
+    /// Kill the process when the platform reports that the run exceeded the maximum duration of the task
+    #[arg(long)]
+    pub kill_on_timeout: bool,
+    /// Do not send the output of the process to the platform
+    #[arg(long)]
+    pub no_logs: bool,
     /// The command to run
     pub command: String,
     /// The arguments to pass to the command
     pub args: Vec<String>,
 }
 
+#[derive(Args)]
+pub struct PreviewScheduleCommand {
+    /// The cron schedule to preview, e.g. "0 2 * * *"
+    pub cron_schedule: String,
+    /// The IANA time zone in which the cron schedule is evaluated (e.g. Europe/Paris). Defaults to UTC
+    #[arg(long)]
+    pub time_zone: Option<String>,
+    /// The start window of the task, after which it is late
+    #[arg(long)]
+    pub start_window_seconds: Option<u32>,
+    /// The lateness window of the task, after which it is absent
+    #[arg(long)]
+    pub lateness_window_seconds: Option<u32>,
+    /// The number of upcoming runs to print
+    #[arg(long)]
+    pub runs: Option<u32>,
+}
+
 pub async fn handle_tasks_command(command: TasksCommands) -> anyhow::Result<()> {
     let config = Config::load().await?;
     let client = config.get_api_client()?;
 
     match command {
-        TasksCommands::Run(command) => run_task(&client, command).await,
+        TasksCommands::Run(command) => run_task(&client, *command).await,
+        TasksCommands::PreviewSchedule(command) => preview_schedule(&client, command).await,
+    }
+}
+
+async fn preview_schedule(client: &DutyDuckApiClient, command: PreviewScheduleCommand) -> anyhow::Result<()> {
+    let preview = client
+        .tasks()
+        .preview_schedule(PreviewTaskScheduleCommand {
+            cron_schedule: command.cron_schedule,
+            time_zone: command.time_zone,
+            start_window_seconds: command.start_window_seconds,
+            lateness_window_seconds: command.lateness_window_seconds,
+            runs: command.runs,
+        })
+        .await
+        .context("Failed to preview the schedule")?;
+
+    println!("{} ({})", preview.description, preview.time_zone);
+    println!(
+        "Late {}s after it is due, absent {}s after it is late",
+        preview.start_window_seconds, preview.lateness_window_seconds
+    );
+    // dates are shown in the local time of this machine
+    let format = |date: DateTime<Utc>| date.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S %:z").to_string();
+    for run in preview.next_runs {
+        println!(
+            "due {}  late {}  absent {}",
+            format(run.due_at),
+            format(run.late_at),
+            format(run.absent_at)
+        );
+    }
+    Ok(())
+}
+
+/// Parses a metadata record given as `key=value`
+fn parse_metadata_record(record: &str) -> Result<(String, String), String> {
+    match record.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("invalid metadata record `{record}`, expected key=value")),
     }
 }
 
 async fn run_task(client: &DutyDuckApiClient, command: RunCommand) -> anyhow::Result<()> {
     let client = client.tasks();
+    let (stdout, stderr) = match command.no_logs {
+        true => (Stdio::inherit(), Stdio::inherit()),
+        false => (Stdio::piped(), Stdio::piped()),
+    };
     let mut process: Child = tokio::process::Command::new(&command.command)
         .args(command.args)
+        .stdout(stdout)
+        .stderr(stderr)
         // kill the process if the child handle is dropped, which allows the task to stop
         // if the platform reports that the task has been aborted
         .kill_on_drop(true)
         .spawn()
         .context("Failed to start child process")?;
 
+    // the output of the process is still shown locally, and is collected to be sent to the platform
+    let logs = LogBuffer::default();
+    let mut output_readers = Vec::new();
+    if let Some(stdout) = process.stdout.take() {
+        output_readers.push(capture_output(stdout, tokio::io::stdout(), TaskRunLogStream::Stdout, logs.clone()));
+    }
+    if let Some(stderr) = process.stderr.take() {
+        output_readers.push(capture_output(stderr, tokio::io::stderr(), TaskRunLogStream::Stderr, logs.clone()));
+    }
+
     let mut request = client.start_task(&command.task_id);
     if command.abort_previous_running_task {
         request = request.abort_previous_running_task();
     }
+    if let Some(run_id) = command.run_id {
+        request = request.with_run_id(run_id);
+    }
     if command.create {
         request = request.with_new_task(NewTask {
             name: command.name.or(Some(command.command)),
             description: command.description,
             cron_schedule: command.cron_schedule,
+            time_zone: command.time_zone,
             start_window_seconds: command.start_window_seconds,
             lateness_window_seconds: command.lateness_window_seconds,
             heartbeat_timeout_seconds: command.heartbeat_timeout_seconds,
+            max_duration_seconds: command.max_duration_seconds,
+            max_concurrent_runs: command.max_concurrent_runs,
+            upstream_task_ids: Some(command.upstream_task_ids).filter(|ids| !ids.is_empty()),
+            metadata: Some(EntityMetadata {
+                records: command.metadata.into_iter().collect(),
+            })
+            .filter(|metadata| !metadata.records.is_empty()),
         });
     }
 
-    request
+    let start = request
         .send()
         .await
         .context("Failed to send start task request")?;
+    let (started_at, run_id) = (start.started_at, start.run_id);
+
+    let send_logs_task = tokio::spawn({
+        let client = client.clone();
+        let task_id = command.task_id.clone();
+        let run_id = run_id.clone();
+        let logs = logs.clone();
+        async move {
+            let mut interval = tokio::time::interval(LOGS_INTERVAL);
+            loop {
+                interval.tick().await;
+                send_logs(&client, &task_id, started_at, &run_id, &logs).await;
+            }
+        }
+    });
 
     let heartbeat_interval = Duration::from_secs(10);
+    // set when the platform reports that the run exceeded the maximum duration of the task
+    let timed_out = Arc::new(AtomicBool::new(false));
     let send_heartbeat_task = tokio::spawn({
         let client = client.clone();
         let task_id = command.task_id.clone();
+        let run_id = run_id.clone();
+        let timed_out = timed_out.clone();
+        let kill_on_timeout = command.kill_on_timeout;
         async move {
             let mut interval = tokio::time::interval(heartbeat_interval);
             loop {
                 interval.tick().await;
-                match client.send_heartbeat(&task_id).await {
+                match client.send_heartbeat(&task_id, Some(&run_id)).await {
                     Ok(_) => (),
-                    // If the platform reports that the task is no longer running (i.e. it has been aborted),
+                    // If the platform reports that the task or this run is no longer running (i.e. it has been aborted),
                     // we can stop sending heartbeats and we can kill the local process
-                    Err(ClientError::InvalidStatusCode(StatusCode::BAD_REQUEST, _)) => {
+                    Err(ClientError::InvalidStatusCode(StatusCode::BAD_REQUEST | StatusCode::NOT_FOUND, _)) => {
                         eprintln!(
                             "Tried to send a heartbeat but the task is no longer running. Maybe it was aborted?"
                         );
                         break;
                     }
+                    // The run is no longer running on the platform, but the process may be left running until it exits
+                    Err(ClientError::InvalidStatusCode(StatusCode::CONFLICT, _)) => {
+                        eprintln!("The task run exceeded the maximum duration of the task");
+                        timed_out.store(true, Ordering::SeqCst);
+                        if kill_on_timeout {
+                            break;
+                        }
+                        std::future::pending::<()>().await;
+                    }
                     Err(e) => eprintln!("Failed to send heartbeat: {}", e),
                 }
             }
@@ -116,9 +280,24 @@ async fn run_task(client: &DutyDuckApiClient, command: RunCommand) -> anyhow::Re
 
     tokio::select! {
         child_exit = process.wait() => {
+            // the last lines of output are sent before the run is finished, so that they are part of the failure notifications
+            send_logs_task.abort();
+            let drain_deadline = tokio::time::Instant::now() + OUTPUT_DRAIN_TIMEOUT;
+            for mut reader in output_readers {
+                if tokio::time::timeout_at(drain_deadline, &mut reader).await.is_err() {
+                    reader.abort();
+                }
+            }
+            send_logs(&client, &command.task_id, started_at, &run_id, &logs).await;
+
+            // the platform has already ended the run, it can no longer be finished
+            if timed_out.load(Ordering::SeqCst) {
+                return Ok(());
+            }
+
             let finish_request = match child_exit {
                 Ok(status) => {
-                    let mut request = client.finish_task(&command.task_id);
+                    let mut request = client.finish_task(&command.task_id).with_run_id(&run_id);
                     if let Some(exit_code) = status.code() {
                         request = request.with_exit_code(exit_code);
                     }
@@ -129,7 +308,7 @@ async fn run_task(client: &DutyDuckApiClient, command: RunCommand) -> anyhow::Re
                 }
                 Err(e) => {
                     eprintln!("Failed to wait for child process: {}", e);
-                    client.finish_task(&command.task_id).failure()
+                    client.finish_task(&command.task_id).with_run_id(&run_id).failure()
                 }
             };
         
@@ -140,8 +319,8 @@ async fn run_task(client: &DutyDuckApiClient, command: RunCommand) -> anyhow::Re
         }
         _ = send_heartbeat_task => {
             // if the heartbeat task completes before the child process, it can only mean that
-            // the task was aborted, so we can kill the local process
-            eprintln!("Task was aborted, killing subprocess");
+            // the task was aborted or timed out, so we can kill the local process
+            eprintln!("Task is no longer running, killing subprocess");
             process.start_kill().context("Failed to kill subprocess")?;
         }
         _ = ctrl_c => {
@@ -152,9 +331,11 @@ async fn run_task(client: &DutyDuckApiClient, command: RunCommand) -> anyhow::Re
             
             // Wait for the process to actually terminate
             process.wait().await.context("Failed to wait for subprocess to terminate")?;
+            send_logs_task.abort();
+            send_logs(&client, &command.task_id, started_at, &run_id, &logs).await;
             
             // Send failure status to the API
-            client.finish_task(&command.task_id)
+            client.finish_task(&command.task_id).with_run_id(&run_id)
                 .aborted()
                 .send()
                 .await
@@ -166,3 +347,67 @@ async fn run_task(client: &DutyDuckApiClient, command: RunCommand) -> anyhow::Re
 
     Ok(())
 }
+
+/// The lines of output that have not been sent to the platform yet
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<VecDeque<TaskRunLogLine>>>);
+
+impl LogBuffer {
+    fn push(&self, line: TaskRunLogLine) {
+        let mut lines = self.0.lock().unwrap();
+        if lines.len() == MAXIMUM_LOG_LINES_PER_REQUEST {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    fn take(&self) -> Vec<TaskRunLogLine> {
+        self.0.lock().unwrap().drain(..).collect()
+    }
+}
+
+/// Copies an output of the process to the output of the CLI, line by line, and collects the lines
+fn capture_output(
+    output: impl AsyncRead + Unpin + Send + 'static,
+    mut local_output: impl AsyncWrite + Unpin + Send + 'static,
+    stream: TaskRunLogStream,
+    logs: LogBuffer,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut reader = BufReader::new(output);
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            match reader.read_until(b'\n', &mut line).await {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let _ = local_output.write_all(&line).await;
+                    let _ = local_output.flush().await;
+                    logs.push(TaskRunLogLine {
+                        stream,
+                        line: String::from_utf8_lossy(&line)
+                            .trim_end_matches(['\n', '\r'])
+                            .to_string(),
+                    });
+                }
+            }
+        }
+    })
+}
+
+/// Sends the collected lines of output to the platform. Failures are reported but do not stop the task
+async fn send_logs(
+    client: &TasksSubclient,
+    task_id: &str,
+    started_at: DateTime<Utc>,
+    run_id: &str,
+    logs: &LogBuffer,
+) {
+    let lines = logs.take();
+    if lines.is_empty() {
+        return;
+    }
+    if let Err(e) = client.append_task_run_logs(task_id, started_at, run_id, lines).await {
+        eprintln!("Failed to send logs: {}", e);
+    }
+}
@@ -5,6 +5,7 @@ import type { ListTaskRunsParams } from "bindings/ListTaskRunsParams"
 import type { ListTaskRunsResponse } from "bindings/ListTaskRunsResponse"
 import type { ListTasksParams } from "bindings/ListTasksParams"
 import type { ListTasksResponse } from "bindings/ListTasksResponse"
+import type { FilterableMetadata } from "bindings/FilterableMetadata"
 import { FetchError } from "ofetch"
 
 export const useTasksRepository = () => {
@@ -12,6 +13,9 @@ export const useTasksRepository = () => {
         async useTasks(params: ListTasksParams | Ref<ListTasksParams>, opts?: UseFetchOptions<ListTasksResponse>) {
             return useServerFetch<ListTasksResponse>("/tasks", { query: params, retry: 3, dedupe: "cancel", ...(opts || {}) })
         },
+        async useFilterableMetadataFields() {
+            return useServerFetch<FilterableMetadata>("/tasks/filterable-metadata")
+        },
         async useTaskRuns(taskId: string, params?: ListTaskRunsParams | Ref<ListTaskRunsParams>, opts?: UseFetchOptions<ListTaskRunsResponse>) {
             return useServerFetch<ListTaskRunsResponse>(`/tasks/${taskId}/runs`, { query: params, retry: 3, dedupe: "cancel", ...(opts || {}) })
         },
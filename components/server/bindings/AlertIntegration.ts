@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IncidentPriority } from "./IncidentPriority";
+
+/**
+ * An inbound integration that opens and resolves incidents from the alerts sent by an external system,
+ * like Prometheus Alertmanager or Grafana
+ */
+export type AlertIntegration = { organizationId: string, id: string, name: string, 
+/**
+ * The priority of the incidents whose alert has no known severity
+ */
+defaultPriority: IncidentPriority, createdAt: string, updatedAt: string, };
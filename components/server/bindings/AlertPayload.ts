@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AlertmanagerPayload } from "./AlertmanagerPayload";
+import type { NativeAlertPayload } from "./NativeAlertPayload";
+
+/**
+ * The payloads accepted by alert integrations
+ */
+export type AlertPayload = AlertmanagerPayload | NativeAlertPayload;
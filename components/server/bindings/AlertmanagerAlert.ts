@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExternalAlertStatus } from "./ExternalAlertStatus";
+
+export type AlertmanagerAlert = { status: ExternalAlertStatus, labels: { [key in string]?: string }, annotations: { [key in string]?: string }, 
+/**
+ * Sent by Alertmanager 0.22+ and Grafana. Computed from the labels otherwise, as Alertmanager does
+ */
+fingerprint: string | null, generatorURL: string | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TaskRunLogLine } from "./TaskRunLogLine";
+
+export type AppendTaskRunLogsCommand = { 
+/**
+ * The lines of output produced since the previous logs were sent, oldest first
+ */
+lines: Array<TaskRunLogLine>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AppendTaskRunLogsParams = { 
+/**
+ * Tells apart the runs of the task that started at the same time
+ */
+runId: string | null, };
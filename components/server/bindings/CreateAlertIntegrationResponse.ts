@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreateAlertIntegrationResponse = { id: string, 
+/**
+ * The secret the external system must send as a bearer token. It will not be returned again
+ */
+secret: string, };
@@ -1,5 +1,27 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { EntityMetadata } from "./EntityMetadata";
+import type { HttpMethod } from "./HttpMethod";
+import type { HttpMonitorAssertions } from "./HttpMonitorAssertions";
+import type { HttpMonitorAuthentication } from "./HttpMonitorAuthentication";
+import type { HttpMonitorRedirectPolicy } from "./HttpMonitorRedirectPolicy";
+import type { MonitorKindSettings } from "./MonitorKindSettings";
+import type { ProbeEngine } from "./ProbeEngine";
 import type { RequestHeaders } from "./RequestHeaders";
 
-export type CreateHttpMonitorCommand = { url: string, intervalSeconds: number, recoveryConfirmationThreshold: number, downtimeConfirmationThreshold: number, isActive: boolean, metadata: EntityMetadata, emailNotificationEnabled: boolean, pushNotificationEnabled: boolean, smsNotificationEnabled: boolean, requestHeaders: RequestHeaders, requestTimeoutMs: number, };
+export type CreateHttpMonitorCommand = { url: string, intervalSeconds: number, recoveryConfirmationThreshold: number, downtimeConfirmationThreshold: number, isActive: boolean, metadata: EntityMetadata, emailNotificationEnabled: boolean, pushNotificationEnabled: boolean, smsNotificationEnabled: boolean, requestHeaders: RequestHeaders, requestTimeoutMs: number, assertions: HttpMonitorAssertions, 
+/**
+ * Names of the probe locations to ping the monitor from, the default location is used if empty
+ */
+locations: Array<string>, 
+/**
+ * Minimum number of failing locations for a ping to be considered failed, a majority if not set
+ */
+locationQuorum: number | null, requestMethod: HttpMethod, requestBody: string | null, requestBodyContentType: string | null, authentication: HttpMonitorAuthentication, redirectPolicy: HttpMonitorRedirectPolicy, probeEngine: ProbeEngine, 
+/**
+ * Number of days before the certificate expires at which a warning incident is opened, 0 to disable it
+ */
+tlsExpiryWarningDays: number, 
+/**
+ * What the monitor checks, an HTTP monitor if not set
+ */
+kindSettings: MonitorKindSettings, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaintenanceWindowScope } from "./MaintenanceWindowScope";
+
+/**
+ * Creates a one-off maintenance window when `cronSchedule` is not set, otherwise a recurring one
+ */
+export type CreateMaintenanceWindowCommand = { title: string, description: string | null, 
+/**
+ * Defaults to now
+ */
+startsAt: string | null, 
+/**
+ * Required for one-off windows. Recurring windows recur forever if not set
+ */
+endsAt: string | null, cronSchedule: string | null, 
+/**
+ * Required for recurring windows
+ */
+durationSeconds: number | null, scope: MaintenanceWindowScope, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EntityMetadata } from "./EntityMetadata";
+import type { IncidentPriority } from "./IncidentPriority";
+
+export type CreateManualIncidentCommand = { title: string, description: string | null, priority: IncidentPriority, metadata: EntityMetadata, sendEmail: boolean, sendPushNotification: boolean, sendSms: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Replaces the users on call of a schedule by another user for a period
+ */
+export type CreateOnCallOverrideCommand = { userId: string, startsAt: string, endsAt: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OnCallRotations } from "./OnCallRotations";
+
+export type CreateOnCallScheduleCommand = { name: string, description: string | null, 
+/**
+ * The IANA time zone of the handoff times, e.g. `Europe/Paris`
+ */
+timeZone: string, rotations: OnCallRotations, };
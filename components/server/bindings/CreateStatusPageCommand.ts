@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StatusPageComponents } from "./StatusPageComponents";
+import type { StatusPageVisibility } from "./StatusPageVisibility";
+
+export type CreateStatusPageCommand = { slug: string, title: string, description: string | null, components: StatusPageComponents, visibility: StatusPageVisibility, };
@@ -1,3 +1,36 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EntityMetadata } from "./EntityMetadata";
 
-export type CreateTaskCommand = { id: string, name: string | null, description: string | null, cronSchedule: string | null, startWindowSeconds: number | null, latenessWindowSeconds: number | null, heartbeatTimeoutSeconds: number | null, };
+export type CreateTaskCommand = { id: string, name: string | null, description: string | null, cronSchedule: string | null, 
+/**
+ * The IANA time zone in which the cron schedule is evaluated (e.g. `Europe/Paris`). Defaults to UTC
+ */
+timeZone: string | null, startWindowSeconds: number | null, latenessWindowSeconds: number | null, heartbeatTimeoutSeconds: number | null, 
+/**
+ * The maximum duration of a run, after which the run is considered timed out. Runs can last forever if not set
+ */
+maxDurationSeconds: number | null, 
+/**
+ * The maximum number of runs that can be running at the same time, e.g. one run per shard. Defaults to 1
+ */
+maxConcurrentRuns: number | null, 
+/**
+ * The tasks that must finish before the task is due, e.g. the export that precedes an import
+ */
+upstreamTaskIds: Array<string> | null, 
+/**
+ * Key-value records used to tag the task (e.g. by team or environment) and to filter tasks
+ */
+metadata: EntityMetadata | null, 
+/**
+ * Whether users are notified of the incidents of the task by email. Defaults to true
+ */
+emailNotificationEnabled: boolean | null, 
+/**
+ * Whether users are notified of the incidents of the task by push notification. Defaults to true
+ */
+pushNotificationEnabled: boolean | null, 
+/**
+ * Whether users are notified of the incidents of the task by SMS. Defaults to false
+ */
+smsNotificationEnabled: boolean | null, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IncidentEventType } from "./IncidentEventType";
+import type { MetadataFilter } from "./MetadataFilter";
+
+export type CreateWebhookEndpointCommand = { url: string, eventTypes: Array<IncidentEventType>, metadataFilter: MetadataFilter, };
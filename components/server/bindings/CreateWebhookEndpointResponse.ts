@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreateWebhookEndpointResponse = { id: string, 
+/**
+ * The secret used to sign the payloads sent to this endpoint. It will not be returned again
+ */
+secret: string, };
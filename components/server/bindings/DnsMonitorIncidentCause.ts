@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DnsRecordType } from "./DnsRecordType";
+import type { HttpMonitorIncidentCausePing } from "./HttpMonitorIncidentCausePing";
+
+/**
+ * The cause of an incident opened for a DNS monitor whose record cannot be resolved, or has unexpected answers
+ */
+export type DnsMonitorIncidentCause = { hostname: string, recordType: DnsRecordType, expectedAnswers: Array<string>, lastPing: HttpMonitorIncidentCausePing, previousPings: Array<HttpMonitorIncidentCausePing>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The DNS record types a DNS monitor can resolve
+ */
+export type DnsRecordType = "A" | "AAAA" | "CNAME" | "MX" | "NS" | "TXT";
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A task that depends on the task of the incident, and whose expected run was skipped because of the incident
+ */
+export type DownstreamTaskSkippedEventPayload = { taskId: string, taskName: string, 
+/**
+ * When the skipped run was expected
+ */
+dueAt: string, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EscalationPolicyLevel } from "./EscalationPolicyLevel";
+
+/**
+ * Defines how an incident is escalated when nobody acknowledges it.
+ *
+ * Escalation level 0 is the initial notification of an incident, sent to the users on call of `on_call_schedule_id`
+ * (or to every member of the organization) using the channels configured on the incident source (HTTP monitor, task...).
+ * `levels[0]` describes escalation level 1, `levels[1]` escalation level 2, and so on.
+ */
+export type EscalationPolicy = { organizationId: string, 
+/**
+ * The schedule whose users on call receive the initial notification of incidents.
+ * Every member of the organization is notified if not set, or if nobody is on call
+ */
+onCallScheduleId: string | null, levels: Array<EscalationPolicyLevel>, updatedAt: string, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OrganizationUserRole } from "./OrganizationUserRole";
+
+export type EscalationPolicyLevel = { 
+/**
+ * Time to wait after the notification of the previous level before notifying this level
+ */
+delaySeconds: number, 
+/**
+ * Users to notify. If no user, role or user on call is targeted, every member of the organization is notified
+ */
+targetUserIds: Array<string>, 
+/**
+ * Members having one of these roles are notified
+ */
+targetRoles: Array<OrganizationUserRole>, 
+/**
+ * The users on call of these schedules at the time of the notification are notified
+ */
+targetOnCallScheduleIds: Array<string>, sendEmail: boolean, sendPushNotification: boolean, sendSms: boolean, };
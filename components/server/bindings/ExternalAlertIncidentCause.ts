@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The cause of an incident opened for an alert received by an alert integration.
+ * The labels of the alert are the metadata of the incident
+ */
+export type ExternalAlertIncidentCause = { integrationId: string, integrationName: string, fingerprint: string, title: string, description: string | null, 
+/**
+ * A link to the alert in the external system
+ */
+sourceUrl: string | null, };
@@ -1,4 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { FinishedTaskStatus } from "./FinishedTaskStatus";
 
-export type FinishTaskCommand = { status: FinishedTaskStatus, exitCode: number | null, errorMessage: string | null, };
+export type FinishTaskCommand = { status: FinishedTaskStatus, exitCode: number | null, errorMessage: string | null, 
+/**
+ * The id of the run to finish, as returned when it started. Defaults to the oldest running run of the task
+ */
+runId: string | null, };
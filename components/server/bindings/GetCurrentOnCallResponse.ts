@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OnCallShift } from "./OnCallShift";
+
+export type GetCurrentOnCallResponse = { date: string, 
+/**
+ * The users on call, without duplicates
+ */
+userIds: Array<string>, 
+/**
+ * The ongoing shifts, one per rotation, or the ongoing overrides
+ */
+shifts: Array<OnCallShift>, };
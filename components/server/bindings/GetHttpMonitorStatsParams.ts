@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpMonitorStatsBucketSize } from "./HttpMonitorStatsBucketSize";
+
+export type GetHttpMonitorStatsParams = { 
+/**
+ * Defaults to 24 hours before `to`
+ */
+from: string | null, 
+/**
+ * Defaults to now
+ */
+to: string | null, 
+/**
+ * Defaults to `hour`
+ */
+bucket: HttpMonitorStatsBucketSize | null, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OnCallOverride } from "./OnCallOverride";
+import type { OnCallSchedule } from "./OnCallSchedule";
+
+export type GetOnCallScheduleResponse = { onCallSchedule: OnCallSchedule, 
+/**
+ * The ongoing and future overrides of the schedule
+ */
+overrides: Array<OnCallOverride>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpMonitorIncidentCausePing } from "./HttpMonitorIncidentCausePing";
+
+/**
+ * The cause of an incident opened for a gRPC health monitor whose service is unreachable or not serving
+ */
+export type GrpcHealthMonitorIncidentCause = { host: string, port: number, 
+/**
+ * Empty when the overall health of the server is checked
+ */
+service: string, lastPing: HttpMonitorIncidentCausePing, previousPings: Array<HttpMonitorIncidentCausePing>, };
@@ -1,7 +1,40 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { EntityMetadata } from "./EntityMetadata";
+import type { HttpMethod } from "./HttpMethod";
+import type { HttpMonitorAssertions } from "./HttpMonitorAssertions";
+import type { HttpMonitorAuthentication } from "./HttpMonitorAuthentication";
 import type { HttpMonitorErrorKind } from "./HttpMonitorErrorKind";
+import type { HttpMonitorRedirectPolicy } from "./HttpMonitorRedirectPolicy";
 import type { HttpMonitorStatus } from "./HttpMonitorStatus";
+import type { MonitorKindSettings } from "./MonitorKindSettings";
+import type { ObservedTlsCertificate } from "./ObservedTlsCertificate";
+import type { ProbeEngine } from "./ProbeEngine";
 import type { RequestHeaders } from "./RequestHeaders";
 
-export type HttpMonitor = { organizationId: string, id: string, createdAt: string, url: string, firstPingAt: string | null, nextPingAt: string | null, lastPingAt: string | null, lastStatusChangeAt: string, recoveryConfirmationThreshold: number, downtimeConfirmationThreshold: number, intervalSeconds: number, lastHttpCode: number | null, status: HttpMonitorStatus, statusCounter: number, errorKind: HttpMonitorErrorKind, metadata: EntityMetadata, emailNotificationEnabled: boolean, pushNotificationEnabled: boolean, smsNotificationEnabled: boolean, archivedAt: string | null, requestHeaders: RequestHeaders, requestTimeoutMs: number, };
+export type HttpMonitor = { organizationId: string, id: string, createdAt: string, url: string, firstPingAt: string | null, nextPingAt: string | null, lastPingAt: string | null, lastStatusChangeAt: string, recoveryConfirmationThreshold: number, downtimeConfirmationThreshold: number, intervalSeconds: number, lastHttpCode: number | null, status: HttpMonitorStatus, statusCounter: number, errorKind: HttpMonitorErrorKind, metadata: EntityMetadata, emailNotificationEnabled: boolean, pushNotificationEnabled: boolean, smsNotificationEnabled: boolean, archivedAt: string | null, requestHeaders: RequestHeaders, requestTimeoutMs: number, assertions: HttpMonitorAssertions, 
+/**
+ * Names of the probe locations the monitor is pinged from
+ * An empty list means the default location only
+ */
+locations: Array<string>, 
+/**
+ * Minimum number of failing locations for a ping to be considered failed
+ * If None, a majority of the locations is required
+ */
+locationQuorum: number | null, requestMethod: HttpMethod, requestBody: string | null, 
+/**
+ * Sent as the `Content-Type` header along with the request body
+ */
+requestBodyContentType: string | null, authentication: HttpMonitorAuthentication, redirectPolicy: HttpMonitorRedirectPolicy, probeEngine: ProbeEngine, 
+/**
+ * The certificate presented by the server on the last ping that reached the TLS handshake
+ */
+tlsCertificate: ObservedTlsCertificate, 
+/**
+ * Number of days before the certificate expires at which a warning incident is opened, 0 to disable it
+ */
+tlsExpiryWarningDays: number, 
+/**
+ * What the monitor checks, along with the settings specific to the kind of check
+ */
+kindSettings: MonitorKindSettings, };
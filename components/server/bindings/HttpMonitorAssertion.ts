@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A check performed on the response of an HTTP monitor, in addition to the HTTP status code
+ */
+export type HttpMonitorAssertion = { "type": "statusCode", min: number, max: number, } | { "type": "bodyContains", value: string, } | { "type": "bodyMatches", pattern: string, } | { "type": "jsonPathEquals", path: string, value: string, } | { "type": "headerEquals", name: string, value: string, } | { "type": "maxResponseTime", maxMs: number, } | { "type": "maxBodySize", maxBytes: number, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpMonitorAssertion } from "./HttpMonitorAssertion";
+
+/**
+ * An assertion that did not hold, along with the value that was observed
+ */
+export type HttpMonitorAssertionFailure = { assertion: HttpMonitorAssertion, 
+/**
+ * The observed value, if it can be displayed (bodies are never recorded)
+ */
+actualValue: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The credentials sent by an HTTP monitor
+ */
+export type HttpMonitorAuthentication = { "type": "none" } | { "type": "basic", username: string, password: string, } | { "type": "bearer", token: string, };
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type HttpMonitorErrorKind = "unknown" | "none" | "httpcode" | "connect" | "builder" | "request" | "redirect" | "body" | "decode" | "timeout" | "browserservicecallfailed";
+export type HttpMonitorErrorKind = "unknown" | "none" | "httpcode" | "connect" | "builder" | "request" | "redirect" | "body" | "decode" | "timeout" | "browserservicecallfailed" | "assertionfailed" | "tlscertificate" | "dnsresolution" | "unexpecteddnsanswer" | "notserving";
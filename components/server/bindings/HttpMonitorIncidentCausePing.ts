@@ -1,4 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpMonitorAssertionFailure } from "./HttpMonitorAssertionFailure";
 import type { HttpMonitorErrorKind } from "./HttpMonitorErrorKind";
 
-export type HttpMonitorIncidentCausePing = { errorKind: HttpMonitorErrorKind, httpCode: number | null, };
+export type HttpMonitorIncidentCausePing = { errorKind: HttpMonitorErrorKind, httpCode: number | null, 
+/**
+ * Set when the error kind is `AssertionFailed`
+ */
+failedAssertion: HttpMonitorAssertionFailure | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How an HTTP monitor handles the redirects it receives
+ */
+export type HttpMonitorRedirectPolicy = { "type": "follow" } | { "type": "dontFollow" } | { "type": "maxHops", maxHops: number, };
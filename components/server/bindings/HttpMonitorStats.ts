@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpMonitorStatsBucket } from "./HttpMonitorStatsBucket";
+import type { HttpMonitorStatsBucketSize } from "./HttpMonitorStatsBucketSize";
+
+export type HttpMonitorStats = { from: string, to: string, bucketSize: HttpMonitorStatsBucketSize, 
+/**
+ * The statistics over the whole range
+ */
+summary: HttpMonitorStatsBucket, 
+/**
+ * One bucket per period, from the oldest to the most recent. Periods without pings are included
+ */
+buckets: Array<HttpMonitorStatsBucket>, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpMonitorErrorKindCount } from "./HttpMonitorErrorKindCount";
+
+export type HttpMonitorStatsBucket = { start: string, pingCount: bigint, 
+/**
+ * The share of pings without error, between 0 and 100. None if there was no ping
+ */
+uptimePercent: number | null, 
+/**
+ * Response time percentiles, in milliseconds. They are estimated from a histogram of the response times
+ */
+p50Ms: number | null, p95Ms: number | null, p99Ms: number | null, errorKinds: Array<HttpMonitorErrorKindCount>, };
@@ -8,4 +8,8 @@ import type { IncidentStatus } from "./IncidentStatus";
 /**
  * The base struct used by all incident types
  */
-export type Incident = { organizationId: string, id: string, createdAt: string, createdBy: string | null, resolvedAt: string | null, cause: IncidentCause | null, status: IncidentStatus, priority: IncidentPriority, incidentSourceType: IncidentSourceType, incidentSourceId: string, acknowledgedBy: Array<string>, metadata: EntityMetadata, };
+export type Incident = { organizationId: string, id: string, createdAt: string, createdBy: string | null, resolvedAt: string | null, cause: IncidentCause | null, status: IncidentStatus, priority: IncidentPriority, incidentSourceType: IncidentSourceType, incidentSourceId: string, acknowledgedBy: Array<string>, metadata: EntityMetadata, 
+/**
+ * A short description of the incident, set by users. Manual incidents always have a title
+ */
+title: string | null, };
@@ -1,7 +1,14 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DnsMonitorIncidentCause } from "./DnsMonitorIncidentCause";
+import type { ExternalAlertIncidentCause } from "./ExternalAlertIncidentCause";
+import type { GrpcHealthMonitorIncidentCause } from "./GrpcHealthMonitorIncidentCause";
 import type { HttpMonitorIncidentCause } from "./HttpMonitorIncidentCause";
+import type { ManualIncidentCause } from "./ManualIncidentCause";
+import type { TaskIncidentCause } from "./TaskIncidentCause";
+import type { TcpMonitorIncidentCause } from "./TcpMonitorIncidentCause";
+import type { TlsCertificateIncidentCause } from "./TlsCertificateIncidentCause";
 
 /**
  * An enum that represents the cause of an incident
  */
-export type IncidentCause = { "causeType": "HttpMonitorIncidentCause" } & HttpMonitorIncidentCause;
+export type IncidentCause = { "causeType": "HttpMonitorIncidentCause" } & HttpMonitorIncidentCause | { "causeType": "TaskIncidentCause" } & TaskIncidentCause | { "causeType": "ManualIncidentCause" } & ManualIncidentCause | { "causeType": "TlsCertificateIncidentCause" } & TlsCertificateIncidentCause | { "causeType": "TcpMonitorIncidentCause" } & TcpMonitorIncidentCause | { "causeType": "DnsMonitorIncidentCause" } & DnsMonitorIncidentCause | { "causeType": "GrpcHealthMonitorIncidentCause" } & GrpcHealthMonitorIncidentCause | { "causeType": "ExternalAlertIncidentCause" } & ExternalAlertIncidentCause;
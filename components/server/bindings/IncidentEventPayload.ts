@@ -1,7 +1,12 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { AcknowledgedEventPayload } from "./AcknowledgedEventPayload";
 import type { CommentPayload } from "./CommentPayload";
+import type { DownstreamTaskSkippedEventPayload } from "./DownstreamTaskSkippedEventPayload";
+import type { MaintenanceWindowEventPayload } from "./MaintenanceWindowEventPayload";
+import type { MetadataChangedEventPayload } from "./MetadataChangedEventPayload";
 import type { NotificationEventPayload } from "./NotificationEventPayload";
 import type { PingEventPayload } from "./PingEventPayload";
+import type { PriorityChangedEventPayload } from "./PriorityChangedEventPayload";
+import type { TitleChangedEventPayload } from "./TitleChangedEventPayload";
 
-export type IncidentEventPayload = { "Comment": CommentPayload } | { "Notification": NotificationEventPayload } | { "Acknowledged": AcknowledgedEventPayload } | { "MonitorPing": PingEventPayload };
+export type IncidentEventPayload = { "Comment": CommentPayload } | { "Notification": NotificationEventPayload } | { "Acknowledged": AcknowledgedEventPayload } | { "MonitorPing": PingEventPayload } | { "MaintenanceWindow": MaintenanceWindowEventPayload } | { "PriorityChanged": PriorityChangedEventPayload } | { "MetadataChanged": MetadataChangedEventPayload } | { "TitleChanged": TitleChangedEventPayload } | { "DownstreamTaskSkipped": DownstreamTaskSkippedEventPayload };
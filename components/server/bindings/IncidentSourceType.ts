@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type IncidentSourceType = "httpmonitor";
+export type IncidentSourceType = "httpmonitor" | "task" | "manual" | "tlscertificate" | "externalalert";
@@ -9,4 +9,8 @@ import type { UserNameInfo } from "./UserNameInfo";
 /**
  * A struct that includes the incident, the user who created it, and the users who have acknowledged it
  */
-export type IncidentWithUsers = { createdBy: UserNameInfo | null, acknowledgedBy: Array<UserNameInfo>, organizationId: string, id: string, createdAt: string, createdBy: string | null, resolvedAt: string | null, cause: IncidentCause | null, status: IncidentStatus, priority: IncidentPriority, incidentSourceType: IncidentSourceType, incidentSourceId: string, acknowledgedBy: Array<string>, metadata: EntityMetadata, };
+export type IncidentWithUsers = { createdBy: UserNameInfo | null, acknowledgedBy: Array<UserNameInfo>, organizationId: string, id: string, createdAt: string, createdBy: string | null, resolvedAt: string | null, cause: IncidentCause | null, status: IncidentStatus, priority: IncidentPriority, incidentSourceType: IncidentSourceType, incidentSourceId: string, acknowledgedBy: Array<string>, metadata: EntityMetadata, 
+/**
+ * A short description of the incident, set by users. Manual incidents always have a title
+ */
+title: string | null, };
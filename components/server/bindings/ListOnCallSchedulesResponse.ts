@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OnCallSchedule } from "./OnCallSchedule";
+
+export type ListOnCallSchedulesResponse = { onCallSchedules: Array<OnCallSchedule>, };
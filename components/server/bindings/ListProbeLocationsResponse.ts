@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ListProbeLocationsResponse = { 
+/**
+ * Names of the probe locations, the first one being the default location
+ */
+locations: Array<string>, };
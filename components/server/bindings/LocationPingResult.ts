@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpMonitorAssertionFailure } from "./HttpMonitorAssertionFailure";
+import type { HttpMonitorErrorKind } from "./HttpMonitorErrorKind";
+
+/**
+ * Result of a ping performed from a single probe location
+ */
+export type LocationPingResult = { location: string, errorKind: HttpMonitorErrorKind, httpCode: number | null, responseTimeMs: number, 
+/**
+ * Set when the error kind is `AssertionFailed`
+ */
+failedAssertion: HttpMonitorAssertionFailure | null, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaintenanceWindowScope } from "./MaintenanceWindowScope";
+
+/**
+ * A period during which the monitors and the tasks in its scope do not open incidents nor send notifications,
+ * typically while deploying. Windows are either one-off, or recurring following a cron schedule
+ */
+export type MaintenanceWindow = { organizationId: string, id: string, title: string, description: string | null, 
+/**
+ * The start of a one-off window, or the date from which a recurring window starts recurring
+ */
+startsAt: string, 
+/**
+ * The end of a one-off window, or the date until which a recurring window recurs
+ */
+endsAt: string | null, 
+/**
+ * None for one-off windows
+ */
+cronSchedule: string | null, 
+/**
+ * The duration of each occurrence of a recurring window
+ */
+durationSeconds: number | null, scope: MaintenanceWindowScope, createdAt: string, updatedAt: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A maintenance window that was ongoing while the incident was open
+ */
+export type MaintenanceWindowEventPayload = { maintenanceWindowId: string, title: string, startsAt: string, endsAt: string, };
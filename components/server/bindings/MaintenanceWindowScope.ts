@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MetadataFilter } from "./MetadataFilter";
+
+/**
+ * The monitors and tasks affected by a maintenance window.
+ * An entity is affected if it is listed by ID, or if its metadata matches the metadata filter
+ */
+export type MaintenanceWindowScope = { httpMonitorIds: Array<string>, taskIds: Array<string>, 
+/**
+ * Beware that an empty filter matches all the monitors and tasks of the organization
+ */
+metadataFilter: MetadataFilter | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The cause of an incident declared by a user.
+ * The title is the one given when the incident was declared, the incident title can be changed afterwards
+ */
+export type ManualIncidentCause = { declaredBy: string, title: string, description: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What a monitor checks. The target of the monitor is always its URL, whose scheme depends on the kind
+ */
+export type MonitorKind = "http" | "tcp" | "dns" | "grpcHealth";
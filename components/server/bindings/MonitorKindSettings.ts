@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DnsRecordType } from "./DnsRecordType";
+
+/**
+ * The settings specific to the kind of a monitor
+ */
+export type MonitorKindSettings = { "kind": "http" } | { "kind": "tcp" } | { "kind": "dns", recordType: DnsRecordType, 
+/**
+ * Answers that must all be part of the resolved records. Any answer is accepted if empty
+ */
+expectedAnswers: Array<string>, } | { "kind": "grpcHealth", 
+/**
+ * The name of the service whose health is checked, the overall health of the server if empty
+ */
+service: string, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExternalAlertStatus } from "./ExternalAlertStatus";
+import type { IncidentPriority } from "./IncidentPriority";
+
+export type NativeAlertPayload = { fingerprint: string, status: ExternalAlertStatus, title: string, description: string | null, labels: { [key in string]?: string }, priority: IncidentPriority | null, sourceUrl: string | null, };
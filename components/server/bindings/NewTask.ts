@@ -1,3 +1,4 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EntityMetadata } from "./EntityMetadata";
 
-export type NewTask = { name: string | null, description: string | null, cronSchedule: string | null, startWindowSeconds: number | null, latenessWindowSeconds: number | null, heartbeatTimeoutSeconds: number | null, };
+export type NewTask = { name: string | null, description: string | null, cronSchedule: string | null, timeZone: string | null, startWindowSeconds: number | null, latenessWindowSeconds: number | null, heartbeatTimeoutSeconds: number | null, maxDurationSeconds: number | null, maxConcurrentRuns: number | null, upstreamTaskIds: Array<string> | null, metadata: EntityMetadata | null, emailNotificationEnabled: boolean | null, pushNotificationEnabled: boolean | null, smsNotificationEnabled: boolean | null, };
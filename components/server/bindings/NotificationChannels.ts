@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The channels on which a user is notified
+ */
+export type NotificationChannels = { email: boolean, pushNotification: boolean, sms: boolean, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PriorityNotificationChannels } from "./PriorityNotificationChannels";
+import type { QuietHours } from "./QuietHours";
+import type { VacationMode } from "./VacationMode";
+
+/**
+ * How a member of an organization wants to be notified of the incidents of this organization.
+ * Members that have not configured their preferences are notified of every incident on every channel
+ */
+export type NotificationPreferences = { organizationId: string, userId: string, 
+/**
+ * The channels enabled for each incident priority. Priorities that are not listed are notified on every channel
+ */
+channels: Array<PriorityNotificationChannels>, quietHours: QuietHours | null, vacation: VacationMode | null, updatedAt: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TlsCertificate } from "./TlsCertificate";
+
+/**
+ * The certificate observed on the last ping of an HTTPS monitor that went through the TLS handshake
+ */
+export type ObservedTlsCertificate = TlsCertificate | null;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A user that temporarily replaces the users on call of a schedule, e.g. while they are on vacation
+ */
+export type OnCallOverride = { organizationId: string, id: string, onCallScheduleId: string, userId: string, startsAt: string, endsAt: string, createdAt: string, };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OnCallRotationKind } from "./OnCallRotationKind";
+
+/**
+ * Users taking turns being on call, handing off every day or every week
+ */
+export type OnCallRotation = { name: string, kind: OnCallRotationKind, 
+/**
+ * The users taking turns, in order
+ */
+userIds: Array<string>, 
+/**
+ * The local time of the handoffs, in the time zone of the schedule
+ */
+handoffTime: string, 
+/**
+ * The day of the handoffs of weekly rotations, e.g. `Mon`. Defaults to Monday, and is ignored by daily rotations
+ */
+handoffDay: string | null, 
+/**
+ * The first user of the rotation is on call from this date until the next handoff
+ */
+startsAt: string, };
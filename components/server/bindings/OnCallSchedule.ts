@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OnCallRotations } from "./OnCallRotations";
+
+/**
+ * Defines who is on call for an organization at any given time.
+ *
+ * Each rotation has its own on-call user: a schedule with a primary and a secondary rotation has two users on call.
+ * Overrides take precedence over the rotations: during an override, only the user of the override is on call
+ */
+export type OnCallSchedule = { organizationId: string, id: string, name: string, description: string | null, 
+/**
+ * The IANA time zone of the handoff times, e.g. `Europe/Paris`
+ */
+timeZone: string, rotations: OnCallRotations, createdAt: string, updatedAt: string, };
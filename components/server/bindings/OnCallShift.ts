@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A period during which a user is on call
+ */
+export type OnCallShift = { userId: string, startsAt: string, endsAt: string, 
+/**
+ * The rotation of the shift, none for overrides
+ */
+rotationName: string | null, 
+/**
+ * The override of the shift, none for rotations
+ */
+overrideId: string | null, };
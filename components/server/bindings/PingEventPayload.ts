@@ -1,4 +1,19 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpMonitorAssertionFailure } from "./HttpMonitorAssertionFailure";
 import type { HttpMonitorErrorKind } from "./HttpMonitorErrorKind";
+import type { LocationPingResult } from "./LocationPingResult";
+import type { TlsCertificate } from "./TlsCertificate";
 
-export type PingEventPayload = { httpCode: number | null, errorKind: HttpMonitorErrorKind, httpHeaders: { [key in string]?: string }, responseTimeMs: bigint, responseIpAddress: string | null, resolvedIpAddresses: Array<string>, responseFileId: string | null, screenshotFileId: string | null, };
+export type PingEventPayload = { httpCode: number | null, errorKind: HttpMonitorErrorKind, httpHeaders: { [key in string]?: string }, responseTimeMs: bigint, responseIpAddress: string | null, resolvedIpAddresses: Array<string>, responseFileId: string | null, screenshotFileId: string | null, 
+/**
+ * Set when the error kind is `AssertionFailed`
+ */
+failedAssertion: HttpMonitorAssertionFailure | null, 
+/**
+ * Results of the ping from each probe location, when the monitor is pinged from several locations
+ */
+locationResults: Array<LocationPingResult>, 
+/**
+ * The certificate presented by the server, when the ping reached the TLS handshake
+ */
+tlsCertificate: TlsCertificate | null, };
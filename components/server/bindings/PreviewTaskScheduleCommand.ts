@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PreviewTaskScheduleCommand = { cronSchedule: string, 
+/**
+ * The IANA time zone in which the cron schedule is evaluated (e.g. `Europe/Paris`). Defaults to UTC
+ */
+timeZone: string | null, startWindowSeconds: number | null, latenessWindowSeconds: number | null, 
+/**
+ * The number of upcoming runs to preview, 5 by default and at most 100
+ */
+runs: number | null, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IncidentPriority } from "./IncidentPriority";
+
+export type PriorityNotificationChannels = { priority: IncidentPriority, email: boolean, pushNotification: boolean, sms: boolean, };
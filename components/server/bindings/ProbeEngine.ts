@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How the requests of a monitor are performed
+ */
+export type ProbeEngine = "browser" | "lightweight";
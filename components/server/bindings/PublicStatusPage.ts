@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PublicStatusPageComponent } from "./PublicStatusPageComponent";
+import type { PublicStatusPageIncident } from "./PublicStatusPageIncident";
+import type { StatusPageComponentStatus } from "./StatusPageComponentStatus";
+
+/**
+ * The public view of a status page.
+ * It only exposes the name and the health of the components, never the monitored URLs, the metadata or the members of the organization
+ */
+export type PublicStatusPage = { title: string, description: string | null, 
+/**
+ * The most severe status of the components
+ */
+status: StatusPageComponentStatus, components: Array<PublicStatusPageComponent>, ongoingIncidents: Array<PublicStatusPageIncident>, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StatusPageComponentStatus } from "./StatusPageComponentStatus";
+import type { StatusPageUptimeBar } from "./StatusPageUptimeBar";
+
+export type PublicStatusPageComponent = { name: string, status: StatusPageComponentStatus, 
+/**
+ * The daily uptime of the component, from the oldest to the most recent day
+ */
+uptimeBars: Array<StatusPageUptimeBar>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IncidentPriority } from "./IncidentPriority";
+
+export type PublicStatusPageIncident = { startedAt: string, priority: IncidentPriority, 
+/**
+ * The names of the components affected by the incident
+ */
+components: Array<string>, };
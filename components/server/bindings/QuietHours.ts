@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IncidentPriority } from "./IncidentPriority";
+
+/**
+ * A daily period during which the user does not want to be woken up: push notifications and SMS are not sent, only e-mails.
+ * `start` can be after `end` for quiet hours spanning midnight, e.g. from 22:00 to 07:00
+ */
+export type QuietHours = { start: string, end: string, 
+/**
+ * The IANA time zone of `start` and `end`, e.g. `Europe/Paris`
+ */
+timeZone: string, 
+/**
+ * Incidents of these priorities are notified on every enabled channel, even during quiet hours
+ */
+allowedPriorities: Array<IncidentPriority>, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReceiveAlertsResponse = { 
+/**
+ * The number of incidents opened for firing alerts
+ */
+createdIncidents: number, 
+/**
+ * The number of firing alerts that already had an ongoing incident
+ */
+deduplicatedAlerts: number, 
+/**
+ * The number of incidents resolved by resolved alerts
+ */
+resolvedIncidents: number, };
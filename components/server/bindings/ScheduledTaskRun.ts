@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A run expected by the schedule of a task
+ */
+export type ScheduledTaskRun = { 
+/**
+ * The date at which the task is due to start
+ */
+dueAt: string, 
+/**
+ * The date at which the task is marked as late if it has not started (due at + start window)
+ */
+lateAt: string, 
+/**
+ * The date at which the task is marked as absent if it has not started (late at + lateness window)
+ */
+absentAt: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SendTaskHeartbeatParams = { 
+/**
+ * The id of the run sending the heartbeat, as returned when it started.
+ * Defaults to the oldest running run of the task
+ */
+runId: string | null, };
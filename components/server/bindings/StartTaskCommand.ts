@@ -10,6 +10,12 @@ export type StartTaskCommand = {
  */
 newTask: NewTask | null, 
 /**
- * Whether to abort the previous running task
+ * Whether to abort the previous running task.
+ * For tasks with concurrent runs, the oldest running run is aborted when no other run can start
  */
-abortPreviousRunningTask: boolean, };
+abortPreviousRunningTask: boolean, 
+/**
+ * Identifies the new run among the running runs of the task, to send its heartbeats and finish it
+ * (e.g. the name of a shard). A random id is generated if not set
+ */
+runId: string | null, };
@@ -1,4 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
-import type { TaskRun } from "./TaskRun";
 
-export type StartTaskResponse = { taskRun: TaskRun, };
+export type StartTaskResponse = { 
+/**
+ * The start date of the new task run, which identifies it (e.g. to send its logs)
+ */
+startedAt: string, 
+/**
+ * The id of the new task run, to send its heartbeats and finish it
+ */
+runId: string, };
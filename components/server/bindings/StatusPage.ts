@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StatusPageComponents } from "./StatusPageComponents";
+import type { StatusPageVisibility } from "./StatusPageVisibility";
+
+/**
+ * A page that displays the health of a selection of HTTP monitors and tasks, grouped into components.
+ * Public status pages can be read without being authenticated
+ */
+export type StatusPage = { organizationId: string, id: string, 
+/**
+ * Identifies the status page in its public URL
+ */
+slug: string, title: string, description: string | null, components: StatusPageComponents, visibility: StatusPageVisibility, createdAt: string, updatedAt: string, };
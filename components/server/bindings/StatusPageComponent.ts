@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A group of HTTP monitors and tasks that is displayed as a single line on a status page
+ */
+export type StatusPageComponent = { name: string, httpMonitorIds: Array<string>, taskIds: Array<string>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The status of a component, as displayed on a status page.
+ * Variants are ordered from the least to the most severe
+ */
+export type StatusPageComponentStatus = "unknown" | "operational" | "degraded" | "outage";
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The uptime of a component during a day (UTC)
+ */
+export type StatusPageUptimeBar = { date: string, 
+/**
+ * Between 0 and 1. None if the component was not monitored during this day
+ */
+uptimeRatio: number | null, downtimeSeconds: bigint, };
@@ -1,4 +1,29 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EntityMetadata } from "./EntityMetadata";
 import type { TaskStatus } from "./TaskStatus";
 
-export type Task = { id: string, organizationId: string, name: string, description: string | null, status: TaskStatus, previousStatus: TaskStatus | null, lastStatusChangeAt: string | null, cronSchedule: string | null, nextDueAt: string | null, startWindowSeconds: number, latenessWindowSeconds: number, heartbeatTimeoutSeconds: number, createdAt: string, };
+export type Task = { id: string, 
+/**
+ * An internal identifier, used to reference the task from other entities (e.g. incidents)
+ */
+uuid: string, organizationId: string, name: string, description: string | null, status: TaskStatus, previousStatus: TaskStatus | null, lastStatusChangeAt: string | null, cronSchedule: string | null, nextDueAt: string | null, startWindowSeconds: number, latenessWindowSeconds: number, heartbeatTimeoutSeconds: number, 
+/**
+ * The maximum duration of a run, after which the run is considered timed out. `None` if runs can last forever
+ */
+maxDurationSeconds: number | null, 
+/**
+ * The maximum number of runs of the task that can be running at the same time
+ */
+maxConcurrentRuns: number, 
+/**
+ * The tasks that must finish before this task is due, in addition to its cron schedule
+ */
+upstreamTaskIds: Array<string>, 
+/**
+ * The IANA time zone in which the cron schedule is evaluated (e.g. `Europe/Paris`)
+ */
+timeZone: string, metadata: EntityMetadata, 
+/**
+ * The channels through which users are notified of the incidents of the task
+ */
+emailNotificationEnabled: boolean, pushNotificationEnabled: boolean, smsNotificationEnabled: boolean, createdAt: string, };
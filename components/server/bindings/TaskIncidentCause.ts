@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TaskRunStatus } from "./TaskRunStatus";
+import type { TaskStatus } from "./TaskStatus";
+
+/**
+ * The cause of an incident opened for a failing or absent task
+ */
+export type TaskIncidentCause = { taskId: string, taskName: string, 
+/**
+ * The status of the task when the incident was opened (failing or absent)
+ */
+taskStatus: TaskStatus, 
+/**
+ * The status of the task run that caused the incident. Absent tasks have no task run.
+ */
+taskRunStatus: TaskRunStatus | null, taskRunExitCode: number | null, taskRunErrorMessage: string | null, 
+/**
+ * The last lines of output of the task run, when the run sent its output
+ */
+taskRunOutputTail: Array<string>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TaskPingTokenResponse = { 
+/**
+ * The token of the ping URLs of the task: `/ping/:token`, `/ping/:token/start`, `/ping/:token/fail` and `/ping/:token/:exit_code`
+ */
+pingToken: string, };
@@ -5,4 +5,8 @@ import type { TaskRunStatus } from "./TaskRunStatus";
  * A unspecialized representation of a task run, used at API and database boundaries
  * We have a set of conversions to/from this type to the specific task run types.
  */
-export type TaskRun = { organizationId: string, taskId: string, status: TaskRunStatus, startedAt: string, updatedAt: string, completedAt: string | null, exitCode: number | null, errorMessage: string | null, lastHeartbeatAt: string | null, heartbeatTimeoutSeconds: number, };
+export type TaskRun = { organizationId: string, taskId: string, 
+/**
+ * Identifies the run among the running runs of its task
+ */
+runId: string, status: TaskRunStatus, startedAt: string, updatedAt: string, completedAt: string | null, exitCode: number | null, errorMessage: string | null, lastHeartbeatAt: string | null, heartbeatTimeoutSeconds: number, };
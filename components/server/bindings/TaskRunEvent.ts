@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TaskRunEventPayload } from "./TaskRunEventPayload";
+import type { TaskRunEventType } from "./TaskRunEventType";
+
+/**
+ * An event that is recorded during a task run
+ */
+export type TaskRunEvent = { organizationId: string, taskId: string, taskRunId: string, taskRunStartedAt: string, createdAt: string, eventType: TaskRunEventType, eventPayload: TaskRunEventPayload | null, };
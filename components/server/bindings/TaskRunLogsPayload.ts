@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TaskRunLogLine } from "./TaskRunLogLine";
+
+export type TaskRunLogsPayload = { 
+/**
+ * The lines of output. When the lines were stored in a file, only the last lines
+ */
+lines: Array<TaskRunLogLine>, 
+/**
+ * The file that contains all the lines, when they were too large to be stored in the event
+ */
+fileId: string | null, };
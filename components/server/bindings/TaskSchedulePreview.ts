@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ScheduledTaskRun } from "./ScheduledTaskRun";
+
+export type TaskSchedulePreview = { 
+/**
+ * A human-readable description of the cron schedule, in English
+ */
+description: string, timeZone: string, startWindowSeconds: number, latenessWindowSeconds: number, 
+/**
+ * The next runs expected by the schedule, in chronological order
+ */
+nextRuns: Array<ScheduledTaskRun>, };
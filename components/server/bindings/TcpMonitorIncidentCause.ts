@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpMonitorIncidentCausePing } from "./HttpMonitorIncidentCausePing";
+
+/**
+ * The cause of an incident opened for a TCP monitor whose port cannot be connected to
+ */
+export type TcpMonitorIncidentCause = { host: string, port: number, lastPing: HttpMonitorIncidentCausePing, previousPings: Array<HttpMonitorIncidentCausePing>, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The leaf certificate presented by the server of an HTTPS monitor
+ */
+export type TlsCertificate = { subject: string, issuer: string, subjectAlternativeNames: Array<string>, notBefore: string, notAfter: string, 
+/**
+ * Whether the host of the monitor's URL matches one of the names of the certificate
+ */
+hostnameMatches: boolean, 
+/**
+ * Whether the certificate chain was verified by the probe engine
+ */
+validChain: boolean, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TlsCertificate } from "./TlsCertificate";
+
+/**
+ * The cause of an incident opened because the certificate of an HTTPS monitor is about to expire
+ */
+export type TlsCertificateIncidentCause = { certificate: TlsCertificate, 
+/**
+ * The number of days before expiry the incident was opened at
+ */
+expiryWarningDays: number, };
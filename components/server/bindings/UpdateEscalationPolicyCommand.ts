@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EscalationPolicyLevel } from "./EscalationPolicyLevel";
+
+export type UpdateEscalationPolicyCommand = { 
+/**
+ * The schedule whose users on call receive the initial notification of incidents
+ */
+onCallScheduleId: string | null, levels: Array<EscalationPolicyLevel>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EntityMetadata } from "./EntityMetadata";
+import type { IncidentPriority } from "./IncidentPriority";
+
+/**
+ * The fields of an incident to change. Fields that are not set are left unchanged
+ */
+export type UpdateIncidentCommand = { priority: IncidentPriority | null, metadata: EntityMetadata | null, title: string | null, };
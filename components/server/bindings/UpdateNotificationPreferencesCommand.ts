@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PriorityNotificationChannels } from "./PriorityNotificationChannels";
+import type { QuietHours } from "./QuietHours";
+import type { VacationMode } from "./VacationMode";
+
+export type UpdateNotificationPreferencesCommand = { channels: Array<PriorityNotificationChannels>, quietHours: QuietHours | null, vacation: VacationMode | null, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EntityMetadata } from "./EntityMetadata";
+
+/**
+ * The settings of a task to change. Fields that are not set are left unchanged,
+ * and nullable fields are cleared when they are set to null
+ */
+export type UpdateTaskCommand = { name: string | null, description?: string | null, cronSchedule?: string | null, 
+/**
+ * The IANA time zone in which the cron schedule is evaluated (e.g. `Europe/Paris`)
+ */
+timeZone: string | null, startWindowSeconds: number | null, latenessWindowSeconds: number | null, heartbeatTimeoutSeconds: number | null, maxDurationSeconds?: number | null, 
+/**
+ * The maximum number of runs that can be running at the same time
+ */
+maxConcurrentRuns: number | null, 
+/**
+ * The tasks that must finish before the task is due. An empty list removes all the dependencies of the task
+ */
+upstreamTaskIds: Array<string> | null, 
+/**
+ * The metadata of the task, which replaces its current metadata
+ */
+metadata: EntityMetadata | null, emailNotificationEnabled: boolean | null, pushNotificationEnabled: boolean | null, smsNotificationEnabled: boolean | null, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * While on vacation, the user is not notified at all. Their delegate, if any, is notified instead
+ */
+export type VacationMode = { 
+/**
+ * Vacation starts immediately if not set
+ */
+startsAt: string | null, 
+/**
+ * Vacation lasts until it is disabled if not set
+ */
+endsAt: string | null, delegateUserId: string | null, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IncidentEventType } from "./IncidentEventType";
+import type { WebhookDeliveryStatus } from "./WebhookDeliveryStatus";
+import type { WebhookPayload } from "./WebhookPayload";
+
+/**
+ * An attempt (or a series of attempts) to send an incident event to a webhook endpoint
+ */
+export type WebhookDelivery = { organizationId: string, id: string, webhookEndpointId: string, incidentId: string, eventType: IncidentEventType, payload: WebhookPayload, status: WebhookDeliveryStatus, attempts: number, nextAttemptAt: string | null, lastAttemptAt: string | null, lastResponseHttpCode: number | null, lastError: string | null, createdAt: string, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IncidentEventType } from "./IncidentEventType";
+import type { MetadataFilter } from "./MetadataFilter";
+
+/**
+ * An URL that receives the lifecycle events of the incidents of an organization
+ */
+export type WebhookEndpoint = { organizationId: string, id: string, url: string, eventTypes: Array<IncidentEventType>, 
+/**
+ * Only the events of the incidents matching this filter are sent
+ */
+metadataFilter: MetadataFilter, createdAt: string, updatedAt: string, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Incident } from "./Incident";
+import type { IncidentEvent } from "./IncidentEvent";
+import type { IncidentEventType } from "./IncidentEventType";
+
+/**
+ * The JSON body that is posted to webhook endpoints
+ */
+export type WebhookPayload = { eventType: IncidentEventType, incident: Incident, event: IncidentEvent, };
@@ -0,0 +1,3 @@
+-- Add down migration script here
+delete from incidents where incident_source_type = 1;
+alter table tasks drop column uuid;
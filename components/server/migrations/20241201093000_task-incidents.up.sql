@@ -0,0 +1,5 @@
+-- Add up migration script here
+
+-- tasks are identified by a user-provided text id, but incidents reference their source with a uuid
+alter table tasks add column uuid uuid not null default gen_random_uuid();
+create unique index on tasks (uuid);
@@ -0,0 +1,10 @@
+-- Add up migration script here
+
+-- An organization has at most one escalation policy.
+-- Levels are stored as a JSON array: the first element is escalation level 1 (level 0 being the initial notification of an incident)
+create table escalation_policies (
+    organization_id uuid primary key,
+    levels jsonb not null default '[]',
+    created_at timestamptz not null default now(),
+    updated_at timestamptz not null default now()
+);
@@ -0,0 +1,4 @@
+-- Add down migration script here
+alter table incident_timeline_events drop column webhook_dispatch_pending;
+drop table webhook_deliveries;
+drop table webhook_endpoints;
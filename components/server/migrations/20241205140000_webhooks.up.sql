@@ -0,0 +1,40 @@
+-- Add up migration script here
+
+create table webhook_endpoints (
+    organization_id uuid not null,
+    id uuid not null default gen_random_uuid(),
+    url text not null,
+    secret text not null, -- used to sign the payloads (HMAC-SHA256)
+    event_types smallint[] not null, -- the incident event types that trigger this webhook
+    metadata_filter jsonb not null,
+    created_at timestamptz not null default now(),
+    updated_at timestamptz not null default now(),
+    primary key (organization_id, id)
+);
+
+-- a "job queue" for webhook deliveries, that also acts as the delivery log of each endpoint
+create table webhook_deliveries (
+    organization_id uuid not null,
+    id uuid not null default gen_random_uuid(),
+    webhook_endpoint_id uuid not null,
+    incident_id uuid not null,
+    event_type smallint not null,
+    payload jsonb not null,
+    status smallint not null, -- 0: pending, 1: delivered, 2: failed
+    attempts smallint not null default 0,
+    next_attempt_at timestamptz, -- NULL once the delivery is not pending anymore
+    last_attempt_at timestamptz,
+    last_response_http_code smallint,
+    last_error text,
+    created_at timestamptz not null default now(),
+    primary key (organization_id, id),
+    foreign key (organization_id, webhook_endpoint_id) references webhook_endpoints (organization_id, id) on delete cascade
+);
+
+create index on webhook_deliveries (next_attempt_at) where next_attempt_at is not null;
+create index on webhook_deliveries (organization_id, webhook_endpoint_id, created_at desc);
+
+-- incident events are dispatched to the webhook endpoints asynchronously.
+-- Events that were recorded before webhooks existed are never dispatched
+alter table incident_timeline_events add column webhook_dispatch_pending boolean not null default false;
+create index on incident_timeline_events (created_at) where webhook_dispatch_pending;
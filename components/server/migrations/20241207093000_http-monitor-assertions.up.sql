@@ -0,0 +1,2 @@
+-- Add up migration script here
+alter table http_monitors add column assertions jsonb not null default '{}';
@@ -0,0 +1,16 @@
+-- Add up migration script here
+
+create table status_pages (
+    organization_id uuid not null,
+    id uuid not null default gen_random_uuid(),
+    slug text not null, -- used in the public URL of the status page, unique across all organizations
+    title text not null,
+    description text,
+    components jsonb not null, -- groups of http monitors and tasks, see the StatusPageComponents struct
+    visibility smallint not null, -- 0: public, 1: private
+    created_at timestamptz not null default now(),
+    updated_at timestamptz not null default now(),
+    primary key (organization_id, id)
+);
+
+create unique index on status_pages (slug);
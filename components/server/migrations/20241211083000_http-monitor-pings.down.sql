@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP FUNCTION create_http_monitor_pings_partition_for_month();
+DROP FUNCTION add_counters(bigint[], bigint[]);
+DROP TABLE http_monitor_ping_rollups;
+DROP TABLE http_monitor_pings;
@@ -0,0 +1,89 @@
+-- Add up migration script here
+
+-- the result of every ping of the HTTP monitors
+CREATE TABLE http_monitor_pings (
+    organization_id uuid NOT NULL,
+    http_monitor_id uuid NOT NULL,
+    created_at timestamptz NOT NULL,
+    status smallint NOT NULL, -- the status of the monitor after the ping
+    error_kind smallint NOT NULL,
+    http_code smallint,
+    response_time_ms integer NOT NULL,
+    PRIMARY KEY (
+        organization_id,
+        http_monitor_id,
+        created_at
+    )
+)
+PARTITION BY
+    RANGE (created_at);
+
+-- hourly aggregates of the pings, so that statistics over long periods can be computed without reading every ping
+CREATE TABLE http_monitor_ping_rollups (
+    organization_id uuid NOT NULL,
+    http_monitor_id uuid NOT NULL,
+    period_start timestamptz NOT NULL, -- the start of the hour
+    ping_count bigint NOT NULL,
+    successful_ping_count bigint NOT NULL, -- pings without error
+    -- the number of responses in each response time range, see RESPONSE_TIME_HISTOGRAM_BOUNDS_MS
+    response_time_histogram bigint[] NOT NULL,
+    -- the number of pings of each error kind, indexed by error kind + 1 (the unknown error kind is -1)
+    error_kind_counts bigint[] NOT NULL,
+    PRIMARY KEY (
+        organization_id,
+        http_monitor_id,
+        period_start
+    )
+);
+
+-- element-wise sum of two arrays of counters, the shortest one is padded with zeros
+CREATE OR REPLACE FUNCTION add_counters(a bigint[], b bigint[])
+RETURNS bigint[] AS $$
+    SELECT coalesce(array_agg(coalesce(x, 0) + coalesce(y, 0) ORDER BY i), '{}')
+    FROM unnest(a, b) WITH ORDINALITY AS t(x, y, i)
+$$ LANGUAGE sql IMMUTABLE;
+
+-- Function to create future partitions automatically
+CREATE OR REPLACE FUNCTION create_http_monitor_pings_partition_for_month()
+RETURNS void AS $$
+DECLARE
+    current_month_start date;
+    current_month_end date;
+    next_month_start date;
+    next_month_end date;
+    current_partition_name text;
+    next_partition_name text;
+BEGIN
+    current_month_start := date_trunc('month', now());
+    current_month_end := current_month_start + interval '1 month';
+    next_month_start := current_month_end;
+    next_month_end := next_month_start + interval '1 month';
+
+    current_partition_name := 'http_monitor_pings_y' || 
+                     to_char(current_month_start, 'YYYY') ||
+                     'm' || to_char(current_month_start, 'MM');
+    
+    next_partition_name := 'http_monitor_pings_y' || 
+                     to_char(next_month_start, 'YYYY') ||
+                     'm' || to_char(next_month_start, 'MM');
+    
+    EXECUTE format(
+        'CREATE TABLE IF NOT EXISTS %I PARTITION OF http_monitor_pings
+         FOR VALUES FROM (%L) TO (%L)',
+        current_partition_name,
+        current_month_start,
+        current_month_end
+    );
+
+    EXECUTE format(
+        'CREATE TABLE IF NOT EXISTS %I PARTITION OF http_monitor_pings
+         FOR VALUES FROM (%L) TO (%L)',
+        next_partition_name,
+        next_month_start,
+        next_month_end
+    );
+END;
+$$ LANGUAGE plpgsql;
+
+-- create the partitions of the current and the next month, the next ones are created by the CreateMonthlyPartitions background task
+SELECT create_http_monitor_pings_partition_for_month();
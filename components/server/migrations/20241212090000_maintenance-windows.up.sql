@@ -0,0 +1,16 @@
+-- Add up migration script here
+
+create table maintenance_windows (
+    organization_id uuid not null,
+    id uuid not null default gen_random_uuid(),
+    title text not null,
+    description text,
+    starts_at timestamptz not null, -- start of a one-off window, or date from which a recurring window starts recurring
+    ends_at timestamptz, -- end of a one-off window, or date until which a recurring window recurs (null: forever)
+    cron_schedule text, -- null for one-off windows
+    duration_seconds integer, -- duration of each occurrence of a recurring window
+    scope jsonb not null, -- the monitors and tasks affected by the window, see the MaintenanceWindowScope struct
+    created_at timestamptz not null default now(),
+    updated_at timestamptz not null default now(),
+    primary key (organization_id, id)
+);
@@ -0,0 +1,4 @@
+-- Add down migration script here
+alter table escalation_policies drop column on_call_schedule_id;
+drop table on_call_overrides;
+drop table on_call_schedules;
@@ -0,0 +1,30 @@
+-- Add up migration script here
+
+create table on_call_schedules (
+    organization_id uuid not null,
+    id uuid not null default gen_random_uuid(),
+    name text not null,
+    description text,
+    time_zone text not null, -- IANA time zone of the handoff times, e.g. Europe/Paris
+    rotations jsonb not null, -- users taking turns being on call, see the OnCallRotations struct
+    created_at timestamptz not null default now(),
+    updated_at timestamptz not null default now(),
+    primary key (organization_id, id)
+);
+
+create table on_call_overrides (
+    organization_id uuid not null,
+    id uuid not null default gen_random_uuid(),
+    on_call_schedule_id uuid not null,
+    user_id uuid not null, -- replaces the users on call of the schedule between starts_at and ends_at
+    starts_at timestamptz not null,
+    ends_at timestamptz not null,
+    created_at timestamptz not null default now(),
+    primary key (organization_id, id),
+    foreign key (organization_id, on_call_schedule_id) references on_call_schedules (organization_id, id) on delete cascade
+);
+
+create index on on_call_overrides (organization_id, on_call_schedule_id, ends_at);
+
+-- when set, the initial notification of incidents is only sent to the users on call
+alter table escalation_policies add column on_call_schedule_id uuid;
@@ -0,0 +1,11 @@
+-- Add up migration script here
+
+create table user_notification_preferences (
+    organization_id uuid not null,
+    user_id uuid not null,
+    channels jsonb not null, -- channels enabled per incident priority, see the PriorityNotificationChannels struct
+    quiet_hours jsonb, -- see the QuietHours struct
+    vacation jsonb, -- see the VacationMode struct
+    updated_at timestamptz not null default now(),
+    primary key (organization_id, user_id)
+);
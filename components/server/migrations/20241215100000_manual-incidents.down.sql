@@ -0,0 +1,3 @@
+-- Add down migration script here
+delete from incidents where incident_source_type = 2;
+alter table incidents drop column title;
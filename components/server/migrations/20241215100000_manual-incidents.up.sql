@@ -0,0 +1,4 @@
+-- Add up migration script here
+
+-- a short description of the incident, set by users. Manual incidents (incident_source_type = 2) always have a title
+alter table incidents add column title text;
@@ -0,0 +1,2 @@
+-- Add down migration script here
+alter table http_monitors drop column locations, drop column location_quorum;
@@ -0,0 +1,7 @@
+-- Add up migration script here
+
+-- monitors without locations are pinged from the default probe location
+alter table http_monitors
+    add column locations text[] not null default '{}',
+    -- the number of locations that must fail for a ping to be considered failed, defaults to a majority of the locations
+    add column location_quorum smallint;
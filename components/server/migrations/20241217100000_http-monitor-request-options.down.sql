@@ -0,0 +1,7 @@
+-- Add down migration script here
+alter table http_monitors
+    drop column request_method,
+    drop column request_body,
+    drop column request_body_content_type,
+    drop column authentication,
+    drop column redirect_policy;
@@ -0,0 +1,10 @@
+-- Add up migration script here
+
+alter table http_monitors
+    -- GET by default
+    add column request_method smallint not null default 0,
+    add column request_body text,
+    add column request_body_content_type text,
+    -- credentials are sent as an Authorization header
+    add column authentication jsonb not null default '{"type": "none"}',
+    add column redirect_policy jsonb not null default '{"type": "follow"}';
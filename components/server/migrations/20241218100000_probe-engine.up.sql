@@ -0,0 +1,4 @@
+-- Add up migration script here
+
+-- monitors are pinged by the browser service by default
+alter table http_monitors add column probe_engine smallint not null default 0;
@@ -0,0 +1,7 @@
+-- Add down migration script here
+delete from incidents where incident_source_type = 3;
+drop index unique_ongoing_or_to_be_confirmed_incidents;
+create unique index unique_ongoing_or_to_be_confirmed_incidents on incidents (organization_id, incident_source_id) where (status = 1 or status = 2);
+
+alter table http_monitors drop column tls_expiry_warning_days;
+alter table http_monitors drop column tls_certificate;
@@ -0,0 +1,12 @@
+-- Add up migration script here
+
+-- the certificate observed on the last ping that reached the TLS handshake, the json null if none was observed yet
+alter table http_monitors add column tls_certificate jsonb not null default 'null'::jsonb;
+-- a warning incident is opened this many days before the certificate expires, 0 disables it.
+-- It is disabled for the existing monitors, new monitors get their default value from the domain layer
+alter table http_monitors add column tls_expiry_warning_days smallint not null default 0;
+
+-- certificate expiry incidents (incident_source_type = 3) use the ID of their monitor as source ID,
+-- so the source type must be part of the unique index to let them be ongoing along with the monitor's own incidents
+drop index unique_ongoing_or_to_be_confirmed_incidents;
+create unique index unique_ongoing_or_to_be_confirmed_incidents on incidents (organization_id, incident_source_type, incident_source_id) where (status = 1 or status = 2);
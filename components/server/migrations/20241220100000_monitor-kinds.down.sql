@@ -0,0 +1,3 @@
+-- Add down migration script here
+delete from http_monitors where kind_settings->>'kind' != 'http';
+alter table http_monitors drop column kind_settings;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+
+-- existing monitors are HTTP monitors, the target of the other kinds is also stored in the url column
+alter table http_monitors add column kind_settings jsonb not null default '{"kind": "http"}'::jsonb;
@@ -0,0 +1,3 @@
+-- Add down migration script here
+delete from incidents where incident_source_type = 4;
+drop table alert_integrations;
@@ -0,0 +1,15 @@
+-- Add up migration script here
+
+create table alert_integrations (
+    organization_id uuid not null,
+    id uuid not null default gen_random_uuid(),
+    name text not null,
+    secret text not null, -- sent by the external system as a bearer token
+    default_priority smallint not null, -- the priority of the incidents whose alert has no known severity
+    created_at timestamptz not null default now(),
+    updated_at timestamptz not null default now(),
+    primary key (organization_id, id)
+);
+
+-- alerts are received without knowing the organization of the integration
+create unique index on alert_integrations (id);
@@ -0,0 +1,6 @@
+-- Add up migration script here
+
+-- the unguessable token of the ping URLs of a task (/ping/:token), 64 hexadecimal characters.
+-- gen_random_uuid() is cryptographically random, so that existing and new tasks get a token without the help of the application
+alter table tasks add column ping_token text not null default replace(gen_random_uuid()::text || gen_random_uuid()::text, '-', '');
+create unique index on tasks (ping_token);
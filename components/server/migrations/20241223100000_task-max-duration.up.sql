@@ -0,0 +1,5 @@
+-- Add up migration script here
+
+-- the maximum duration of a task run, after which the run is considered timed out even if it keeps sending heartbeats.
+-- NULL means that the runs of the task can last forever
+alter table tasks add column max_duration_seconds integer;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+
+-- the IANA time zone in which the cron schedule of a task is evaluated
+alter table tasks add column time_zone text not null default 'UTC';
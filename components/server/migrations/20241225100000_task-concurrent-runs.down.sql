@@ -0,0 +1,3 @@
+-- Add down migration script here
+alter table task_runs drop column run_id;
+alter table tasks drop column max_concurrent_runs;
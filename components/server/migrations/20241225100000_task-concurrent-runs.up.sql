@@ -0,0 +1,11 @@
+-- Add up migration script here
+
+-- the maximum number of runs of a task that can be running at the same time
+alter table tasks add column max_concurrent_runs integer not null default 1;
+
+-- identifies a run among the running runs of its task, it is supplied by the client that starts the run
+-- or generated by the server. Existing runs get a random identifier
+alter table task_runs add column run_id text;
+update task_runs set run_id = gen_random_uuid()::text;
+alter table task_runs alter column run_id set not null;
+create index on task_runs (organization_id, task_id, run_id);
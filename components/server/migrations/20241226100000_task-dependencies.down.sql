@@ -0,0 +1,2 @@
+-- Add down migration script here
+alter table tasks drop column upstream_task_ids;
@@ -0,0 +1,6 @@
+-- Add up migration script here
+
+-- the ids of the tasks that must finish before the task is due.
+-- The index is used to find the downstream tasks of a task that finished
+alter table tasks add column upstream_task_ids text[] not null default '{}';
+create index on tasks using gin (upstream_task_ids);
@@ -0,0 +1,5 @@
+-- Add up migration script here
+
+-- arbitrary key-value records used to tag tasks (e.g. by team or environment) and to filter them
+alter table tasks add column metadata jsonb;
+create index on tasks using gin (metadata);
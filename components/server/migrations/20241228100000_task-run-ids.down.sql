@@ -0,0 +1,13 @@
+-- Add down migration script here
+drop table running_task_runs;
+
+alter table task_run_events drop constraint task_run_events_organization_id_task_id_task_run_id_task_r_fkey;
+alter table task_run_events drop constraint task_run_events_pkey;
+alter table task_runs drop constraint task_runs_pkey;
+
+alter table task_runs add primary key (organization_id, task_id, started_at);
+create index on task_runs (organization_id, task_id, run_id);
+alter table task_run_events add primary key (organization_id, task_id, task_run_started_at, created_at);
+alter table task_run_events add foreign key (organization_id, task_id, task_run_started_at)
+    references task_runs (organization_id, task_id, started_at) on delete cascade;
+alter table task_run_events drop column task_run_id;
@@ -0,0 +1,35 @@
+-- Add up migration script here
+
+-- task runs are identified by their run id. The start date stays part of the key, since the run id of a finished run
+-- can be reused by a later run (e.g. the name of a shard), and since task runs are partitioned by start date
+alter table task_run_events add column task_run_id text;
+update task_run_events set task_run_id = task_runs.run_id
+    from task_runs
+    where task_runs.organization_id = task_run_events.organization_id
+    and task_runs.task_id = task_run_events.task_id
+    and task_runs.started_at = task_run_events.task_run_started_at;
+alter table task_run_events alter column task_run_id set not null;
+
+alter table task_run_events drop constraint task_run_events_organization_id_task_id_task_run_started_a_fkey;
+alter table task_run_events drop constraint task_run_events_pkey;
+alter table task_runs drop constraint task_runs_pkey;
+drop index task_runs_organization_id_task_id_run_id_idx;
+
+alter table task_runs add primary key (organization_id, task_id, run_id, started_at);
+alter table task_run_events add primary key (organization_id, task_id, task_run_id, task_run_started_at, created_at);
+alter table task_run_events add foreign key (organization_id, task_id, task_run_id, task_run_started_at)
+    references task_runs (organization_id, task_id, run_id, started_at) on delete cascade;
+
+-- a run id identifies a single running run of a task. Unique indexes of the partitioned task runs table must include
+-- the start date, so the running runs are tracked in this table, which is kept in sync with the status of the runs
+create table running_task_runs (
+    organization_id uuid not null,
+    task_id text not null,
+    run_id text not null,
+    started_at timestamptz not null,
+    primary key (organization_id, task_id, run_id),
+    foreign key (organization_id, task_id, run_id, started_at)
+        references task_runs (organization_id, task_id, run_id, started_at) on delete cascade
+);
+insert into running_task_runs (organization_id, task_id, run_id, started_at)
+    select organization_id, task_id, run_id, started_at from task_runs where status = 1;
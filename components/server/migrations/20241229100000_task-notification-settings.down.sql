@@ -0,0 +1,5 @@
+-- Add down migration script here
+alter table tasks
+    drop column email_notification_enabled,
+    drop column push_notification_enabled,
+    drop column sms_notification_enabled;
@@ -59,8 +59,43 @@ pub struct HttpMonitorsExecutorConfig {
     pub http_monitors_select_limit: u32,
     #[envconfig(from = "HTTP_MONITORS_EXECUTOR_INTERVAL_SECONDS", default = "2")]
     pub http_monitors_executor_interval_seconds: u64,
+    /// If neither this nor the probe locations are set, the browser service is not used
+    /// and every monitor is pinged with the lightweight probe engine
     #[envconfig(from = "BROWSER_SERVICE_GRPC_ADDRESS")]
-    pub browser_service_grpc_address: String,
+    pub browser_service_grpc_address: Option<String>,
+    /// comma separated list of named probe locations, as `name=grpc_address`
+    /// If not set, a single `default` location using the browser service address is used
+    #[envconfig(from = "PROBE_LOCATIONS")]
+    pub probe_locations: Option<String>,
+}
+
+impl HttpMonitorsExecutorConfig {
+    /// Returns the probe locations, as (name, browser service grpc address) pairs
+    /// The first location is the one used by monitors that have no location configured
+    /// Returns an empty list if no browser service is configured
+    pub fn probe_locations(&self) -> anyhow::Result<Vec<(String, String)>> {
+        let Some(probe_locations) = self.probe_locations.as_deref().filter(|s| !s.trim().is_empty())
+        else {
+            return Ok(self
+                .browser_service_grpc_address
+                .iter()
+                .map(|address| ("default".to_string(), address.clone()))
+                .collect());
+        };
+        probe_locations
+            .split(',')
+            .map(|location| {
+                let (name, address) = location
+                    .split_once('=')
+                    .ok_or_else(|| anyhow::anyhow!("Invalid probe location: {location}"))?;
+                let (name, address) = (name.trim(), address.trim());
+                if name.is_empty() || address.is_empty() {
+                    anyhow::bail!("Invalid probe location: {location}");
+                }
+                Ok((name.to_string(), address.to_string()))
+            })
+            .collect()
+    }
 }
 
 #[derive(Envconfig)]
@@ -73,6 +108,16 @@ pub struct DeadTaskRunsCollectorConfig {
     pub concurrent_tasks: usize,
 }
 
+#[derive(Envconfig)]
+pub struct TimedOutTaskRunsCollectorConfig {
+    #[envconfig(from = "TIMED_OUT_TASK_RUNS_COLLECTOR_INTERVAL", default = "10")]
+    pub interval_seconds: u64,
+    #[envconfig(from = "TIMED_OUT_TASK_RUNS_COLLECTOR_SELECT_LIMIT", default = "500")]
+    pub select_limit: u32,
+    #[envconfig(from = "TIMED_OUT_TASK_RUNS_COLLECTOR_CONCURRENT_TASKS", default = "1")]
+    pub concurrent_tasks: usize,
+}
+
 #[derive(Envconfig)]
 pub struct DueTasksCollectorConfig {
     #[envconfig(from = "DUE_TASKS_COLLECTOR_INTERVAL", default = "10")]
@@ -103,6 +148,16 @@ pub struct AbsentTasksCollectorConfig {
     pub concurrent_tasks: usize,
 }
 
+#[derive(Envconfig)]
+pub struct WebhookDeliveriesConfig {
+    #[envconfig(from = "WEBHOOK_DELIVERIES_INTERVAL", default = "2")]
+    pub interval_seconds: u64,
+    #[envconfig(from = "WEBHOOK_DELIVERIES_SELECT_LIMIT", default = "100")]
+    pub select_limit: u32,
+    #[envconfig(from = "WEBHOOK_DELIVERIES_CONCURRENT_TASKS", default = "1")]
+    pub concurrent_tasks: usize,
+}
+
 #[derive(Envconfig)]
 pub struct FileStorageConfig {
     #[envconfig(from = "FILE_STORAGE_BUCKET_NAME")]
@@ -135,6 +190,9 @@ pub struct AppConfig {
     #[envconfig(nested = true)]
     pub dead_task_runs_collector: DeadTaskRunsCollectorConfig,
 
+    #[envconfig(nested = true)]
+    pub timed_out_task_runs_collector: TimedOutTaskRunsCollectorConfig,
+
     #[envconfig(nested = true)]
     pub due_tasks_collector: DueTasksCollectorConfig,
 
@@ -143,6 +201,10 @@ pub struct AppConfig {
 
     #[envconfig(nested = true)]
     pub absent_tasks_collector: AbsentTasksCollectorConfig,
+
+    #[envconfig(nested = true)]
+    pub webhook_deliveries: WebhookDeliveriesConfig,
+
     #[envconfig(nested = true)]
     pub smtp: SmtpConfig
 }
@@ -4,7 +4,7 @@ use axum::extract::State;
 
 use crate::infrastructure::{
     adapters::{
-        api_access_token_repository_adapter::ApiAccessTokenRepositoryAdapter, file_storage_adapter::FileStorageAdapter, http_client_adapter::HttpClientAdapter, http_monitor_repository_adapter::HttpMonitorRepositoryAdapter, incident_event_repository_adapter::IncidentEventRepositoryAdapter, incident_notification_repository_adapter::IncidentNotificationRepositoryAdapter, incident_repository_adapter::IncidentRepositoryAdapter, mailer_adapter::MailerAdapter, organization_repository_adapter::OrganizationRepositoryAdapter, push_notification_server_adapter::PushNotificationServerAdapter, sms_notification_server_adapter::SmsNotificationServerAdapter, task_repository_adapter::TaskRepositoryAdapter, task_run_repository_adapter::TaskRunRepositoryAdapter, user_devices_repository_adapter::UserDevicesRepositoryAdapter, user_repository_adapter::UserRepositoryAdapter
+        alert_integration_repository_adapter::AlertIntegrationRepositoryAdapter, api_access_token_repository_adapter::ApiAccessTokenRepositoryAdapter, escalation_policy_repository_adapter::EscalationPolicyRepositoryAdapter, file_storage_adapter::FileStorageAdapter, http_client_adapter::HttpClientAdapter, http_monitor_repository_adapter::HttpMonitorRepositoryAdapter, incident_event_repository_adapter::IncidentEventRepositoryAdapter, incident_notification_repository_adapter::IncidentNotificationRepositoryAdapter, incident_repository_adapter::IncidentRepositoryAdapter, mailer_adapter::MailerAdapter, maintenance_window_repository_adapter::MaintenanceWindowRepositoryAdapter, notification_preferences_repository_adapter::NotificationPreferencesRepositoryAdapter, on_call_schedule_repository_adapter::OnCallScheduleRepositoryAdapter, organization_repository_adapter::OrganizationRepositoryAdapter, push_notification_server_adapter::PushNotificationServerAdapter, sms_notification_server_adapter::SmsNotificationServerAdapter, status_page_repository_adapter::StatusPageRepositoryAdapter, task_repository_adapter::TaskRepositoryAdapter, task_run_event_repository_adapter::TaskRunEventRepositoryAdapter, task_run_repository_adapter::TaskRunRepositoryAdapter, user_devices_repository_adapter::UserDevicesRepositoryAdapter, user_repository_adapter::UserRepositoryAdapter, webhook_client_adapter::WebhookClientAdapter, webhook_delivery_repository_adapter::WebhookDeliveryRepositoryAdapter, webhook_endpoint_repository_adapter::WebhookEndpointRepositoryAdapter
     },
     keycloak_client::KeycloakClient,
 };
@@ -38,4 +38,14 @@ pub struct Adapters {
     pub api_token_repository: ApiAccessTokenRepositoryAdapter,
     pub task_repository: TaskRepositoryAdapter,
     pub task_run_repository: TaskRunRepositoryAdapter,
+    pub task_run_event_repository: TaskRunEventRepositoryAdapter,
+    pub escalation_policy_repository: EscalationPolicyRepositoryAdapter,
+    pub webhook_endpoint_repository: WebhookEndpointRepositoryAdapter,
+    pub webhook_delivery_repository: WebhookDeliveryRepositoryAdapter,
+    pub webhook_client: WebhookClientAdapter,
+    pub status_page_repository: StatusPageRepositoryAdapter,
+    pub maintenance_window_repository: MaintenanceWindowRepositoryAdapter,
+    pub on_call_schedule_repository: OnCallScheduleRepositoryAdapter,
+    pub notification_preferences_repository: NotificationPreferencesRepositoryAdapter,
+    pub alert_integration_repository: AlertIntegrationRepositoryAdapter,
 }
@@ -7,8 +7,9 @@ use crate::domain::use_cases::{
     incidents::ExecuteIncidentNotificationsUseCase,
     tasks::{
         CollectAbsentTasksUseCase, CollectDeadTaskRunsUseCase, CollectDueTasksUseCase,
-        CollectLateTasksUseCase,
+        CollectLateTasksUseCase, CollectTimedOutTaskRunsUseCase,
     },
+    webhooks::DeliverWebhooksUseCase,
 };
 
 use super::application_config::AppConfig;
@@ -21,12 +22,16 @@ pub enum BackgroundTask {
     IncidentNotifications,
     /// Collect dead task runs
     CollectDeadTaskRuns,
+    /// Collect task runs that exceeded the maximum duration of their task
+    CollectTimedOutTaskRuns,
     /// Collect due tasks
     CollectDueTasks,
     /// Collect late tasks
     CollectLateTasks,
     /// Collect absent tasks
     CollectAbsentTasks,
+    /// Send pending webhook deliveries
+    WebhookDeliveries,
     /// Create monthly partitions for every partitioned table
     CreateMonthlyPartitions,
 }
@@ -39,6 +44,7 @@ pub async fn run_background_task(task: BackgroundTask) -> anyhow::Result<()> {
         BackgroundTask::CreateMonthlyPartitions => {
             application_state.adapters.task_run_repository.create_task_run_partition_for_month().await?;
             application_state.adapters.incident_event_repository.create_incident_timeline_partition_for_month().await?;
+            application_state.adapters.http_monitors_repository.create_http_monitor_pings_partition_for_month().await?;
         }
         BackgroundTask::HttpMonitors => {
             ExecuteHttpMonitorsUseCase {
@@ -55,6 +61,10 @@ pub async fn run_background_task(task: BackgroundTask) -> anyhow::Result<()> {
                     .adapters
                     .incident_notification_repository
                     .clone(),
+                maintenance_window_repository: application_state
+                    .adapters
+                    .maintenance_window_repository
+                    .clone(),
                 http_client: application_state.adapters.http_client.clone(),
                 file_storage: application_state.adapters.file_storage.clone(),
             }
@@ -68,6 +78,7 @@ pub async fn run_background_task(task: BackgroundTask) -> anyhow::Result<()> {
         BackgroundTask::IncidentNotifications => {
             ExecuteIncidentNotificationsUseCase {
                 organization_repository: application_state.adapters.organization_repository.clone(),
+                incident_repository: application_state.adapters.incident_repository.clone(),
                 incident_notification_repository: application_state
                     .adapters
                     .incident_notification_repository
@@ -76,6 +87,9 @@ pub async fn run_background_task(task: BackgroundTask) -> anyhow::Result<()> {
                     .adapters
                     .incident_event_repository
                     .clone(),
+                escalation_policy_repository: application_state.adapters.escalation_policy_repository.clone(),
+                on_call_schedule_repository: application_state.adapters.on_call_schedule_repository.clone(),
+                notification_preferences_repository: application_state.adapters.notification_preferences_repository.clone(),
                 push_notificaton_server: application_state
                     .adapters
                     .push_notification_server
@@ -94,11 +108,30 @@ pub async fn run_background_task(task: BackgroundTask) -> anyhow::Result<()> {
             CollectDeadTaskRunsUseCase {
                 task_repository: application_state.adapters.task_repository.clone(),
                 task_run_repository: application_state.adapters.task_run_repository.clone(),
+                task_run_event_repository: application_state.adapters.task_run_event_repository.clone(),
+                incident_repository: application_state.adapters.incident_repository.clone(),
+                incident_event_repository: application_state.adapters.incident_event_repository.clone(),
+                incident_notification_repository: application_state.adapters.incident_notification_repository.clone(),
+                maintenance_window_repository: application_state.adapters.maintenance_window_repository.clone(),
                 select_limit: config.dead_task_runs_collector.select_limit,
             }
             .collect_dead_task_runs()
             .await?;
         }
+        BackgroundTask::CollectTimedOutTaskRuns => {
+            CollectTimedOutTaskRunsUseCase {
+                task_repository: application_state.adapters.task_repository.clone(),
+                task_run_repository: application_state.adapters.task_run_repository.clone(),
+                task_run_event_repository: application_state.adapters.task_run_event_repository.clone(),
+                incident_repository: application_state.adapters.incident_repository.clone(),
+                incident_event_repository: application_state.adapters.incident_event_repository.clone(),
+                incident_notification_repository: application_state.adapters.incident_notification_repository.clone(),
+                maintenance_window_repository: application_state.adapters.maintenance_window_repository.clone(),
+                select_limit: config.timed_out_task_runs_collector.select_limit,
+            }
+            .collect_timed_out_task_runs()
+            .await?;
+        }
         BackgroundTask::CollectDueTasks => {
             CollectDueTasksUseCase {
                 task_repository: application_state.adapters.task_repository.clone(),
@@ -112,6 +145,8 @@ pub async fn run_background_task(task: BackgroundTask) -> anyhow::Result<()> {
             CollectLateTasksUseCase {
                 task_repository: application_state.adapters.task_repository.clone(),
                 task_run_repository: application_state.adapters.task_run_repository.clone(),
+                incident_repository: application_state.adapters.incident_repository.clone(),
+                incident_event_repository: application_state.adapters.incident_event_repository.clone(),
                 select_limit: config.late_tasks_collector.select_limit,
             }
             .collect_late_tasks()
@@ -121,11 +156,27 @@ pub async fn run_background_task(task: BackgroundTask) -> anyhow::Result<()> {
             CollectAbsentTasksUseCase {
                 task_repository: application_state.adapters.task_repository.clone(),
                 task_run_repository: application_state.adapters.task_run_repository.clone(),
+                incident_repository: application_state.adapters.incident_repository.clone(),
+                incident_event_repository: application_state.adapters.incident_event_repository.clone(),
+                incident_notification_repository: application_state.adapters.incident_notification_repository.clone(),
+                maintenance_window_repository: application_state.adapters.maintenance_window_repository.clone(),
                 select_limit: config.absent_tasks_collector.select_limit,
             }
             .collect_absent_tasks()
             .await?;
         }
+        BackgroundTask::WebhookDeliveries => {
+            DeliverWebhooksUseCase {
+                incident_repository: application_state.adapters.incident_repository.clone(),
+                incident_event_repository: application_state.adapters.incident_event_repository.clone(),
+                webhook_endpoint_repository: application_state.adapters.webhook_endpoint_repository.clone(),
+                webhook_delivery_repository: application_state.adapters.webhook_delivery_repository.clone(),
+                webhook_client: application_state.adapters.webhook_client.clone(),
+                select_limit: config.webhook_deliveries.select_limit,
+            }
+            .deliver_webhooks()
+            .await?;
+        }
     }
 
     Ok(())
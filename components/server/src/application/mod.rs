@@ -9,11 +9,14 @@ use sqlx::postgres::PgPoolOptions;
 use crate::{
     domain::use_cases::{
         http_monitors::ExecuteHttpMonitorsUseCase, incidents::ExecuteIncidentNotificationsUseCase,
-        tasks::{CollectAbsentTasksUseCase, CollectDeadTaskRunsUseCase, CollectDueTasksUseCase, CollectLateTasksUseCase},
+        tasks::{CollectAbsentTasksUseCase, CollectDeadTaskRunsUseCase, CollectDueTasksUseCase, CollectTimedOutTaskRunsUseCase, CollectLateTasksUseCase},
+        webhooks::DeliverWebhooksUseCase,
     },
     infrastructure::{
         adapters::{
+            alert_integration_repository_adapter::AlertIntegrationRepositoryAdapter,
             api_access_token_repository_adapter::ApiAccessTokenRepositoryAdapter,
+            escalation_policy_repository_adapter::EscalationPolicyRepositoryAdapter,
             file_storage_adapter::FileStorageAdapter,
             http_client_adapter::HttpClientAdapter,
             http_monitor_repository_adapter::HttpMonitorRepositoryAdapter,
@@ -21,13 +24,21 @@ use crate::{
             incident_notification_repository_adapter::IncidentNotificationRepositoryAdapter,
             incident_repository_adapter::IncidentRepositoryAdapter,
             mailer_adapter::{MailerAdapter, MailerAdapterConfig},
+            maintenance_window_repository_adapter::MaintenanceWindowRepositoryAdapter,
+            notification_preferences_repository_adapter::NotificationPreferencesRepositoryAdapter,
+            on_call_schedule_repository_adapter::OnCallScheduleRepositoryAdapter,
             organization_repository_adapter::OrganizationRepositoryAdapter,
             push_notification_server_adapter::PushNotificationServerAdapter,
             sms_notification_server_adapter::SmsNotificationServerAdapter,
+            status_page_repository_adapter::StatusPageRepositoryAdapter,
             task_repository_adapter::TaskRepositoryAdapter,
+            task_run_event_repository_adapter::TaskRunEventRepositoryAdapter,
             task_run_repository_adapter::TaskRunRepositoryAdapter,
             user_devices_repository_adapter::UserDevicesRepositoryAdapter,
             user_repository_adapter::UserRepositoryAdapter,
+            webhook_client_adapter::WebhookClientAdapter,
+            webhook_delivery_repository_adapter::WebhookDeliveryRepositoryAdapter,
+            webhook_endpoint_repository_adapter::WebhookEndpointRepositoryAdapter,
         },
         keycloak_client::KeycloakClient,
     },
@@ -52,6 +63,7 @@ pub async fn start_server() -> anyhow::Result<()> {
             .adapters
             .incident_notification_repository
             .clone(),
+        maintenance_window_repository: application_state.adapters.maintenance_window_repository.clone(),
         http_client: application_state.adapters.http_client.clone(),
         file_storage: application_state.adapters.file_storage.clone(),
     };
@@ -69,11 +81,15 @@ pub async fn start_server() -> anyhow::Result<()> {
 
     let execute_incident_notifications = ExecuteIncidentNotificationsUseCase {
         organization_repository: application_state.adapters.organization_repository.clone(),
+        incident_repository: application_state.adapters.incident_repository.clone(),
         incident_notification_repository: application_state
             .adapters
             .incident_notification_repository
             .clone(),
         incident_event_repository: application_state.adapters.incident_event_repository.clone(),
+        escalation_policy_repository: application_state.adapters.escalation_policy_repository.clone(),
+        on_call_schedule_repository: application_state.adapters.on_call_schedule_repository.clone(),
+        notification_preferences_repository: application_state.adapters.notification_preferences_repository.clone(),
         push_notificaton_server: application_state.adapters.push_notification_server.clone(),
         sms_notificaton_server: application_state.adapters.sms_notification_server.clone(),
         mailer: application_state.adapters.mailer.clone(),
@@ -94,6 +110,11 @@ pub async fn start_server() -> anyhow::Result<()> {
     let dead_task_runs_collector = CollectDeadTaskRunsUseCase {
         task_repository: application_state.adapters.task_repository.clone(),
         task_run_repository: application_state.adapters.task_run_repository.clone(),
+        task_run_event_repository: application_state.adapters.task_run_event_repository.clone(),
+        incident_repository: application_state.adapters.incident_repository.clone(),
+        incident_event_repository: application_state.adapters.incident_event_repository.clone(),
+        incident_notification_repository: application_state.adapters.incident_notification_repository.clone(),
+        maintenance_window_repository: application_state.adapters.maintenance_window_repository.clone(),
         select_limit: config.dead_task_runs_collector.select_limit,
     };
     let dead_task_runs_collector_tasks = dead_task_runs_collector.spawn_tasks(
@@ -101,6 +122,21 @@ pub async fn start_server() -> anyhow::Result<()> {
         Duration::from_secs(config.dead_task_runs_collector.interval_seconds),
     );
 
+    let timed_out_task_runs_collector = CollectTimedOutTaskRunsUseCase {
+        task_repository: application_state.adapters.task_repository.clone(),
+        task_run_repository: application_state.adapters.task_run_repository.clone(),
+        task_run_event_repository: application_state.adapters.task_run_event_repository.clone(),
+        incident_repository: application_state.adapters.incident_repository.clone(),
+        incident_event_repository: application_state.adapters.incident_event_repository.clone(),
+        incident_notification_repository: application_state.adapters.incident_notification_repository.clone(),
+        maintenance_window_repository: application_state.adapters.maintenance_window_repository.clone(),
+        select_limit: config.timed_out_task_runs_collector.select_limit,
+    };
+    let timed_out_task_runs_collector_tasks = timed_out_task_runs_collector.spawn_tasks(
+        config.timed_out_task_runs_collector.concurrent_tasks,
+        Duration::from_secs(config.timed_out_task_runs_collector.interval_seconds),
+    );
+
     let due_tasks_collector = CollectDueTasksUseCase {
         task_repository: application_state.adapters.task_repository.clone(),
         task_run_repository: application_state.adapters.task_run_repository.clone(),
@@ -114,6 +150,8 @@ pub async fn start_server() -> anyhow::Result<()> {
     let late_tasks_collector = CollectLateTasksUseCase {
         task_repository: application_state.adapters.task_repository.clone(),
         task_run_repository: application_state.adapters.task_run_repository.clone(),
+        incident_repository: application_state.adapters.incident_repository.clone(),
+        incident_event_repository: application_state.adapters.incident_event_repository.clone(),
         select_limit: config.late_tasks_collector.select_limit,
     };
     let late_tasks_collector_tasks = late_tasks_collector.spawn_tasks(
@@ -124,6 +162,10 @@ pub async fn start_server() -> anyhow::Result<()> {
     let absent_tasks_collector = CollectAbsentTasksUseCase {
         task_repository: application_state.adapters.task_repository.clone(),
         task_run_repository: application_state.adapters.task_run_repository.clone(),
+        incident_repository: application_state.adapters.incident_repository.clone(),
+        incident_event_repository: application_state.adapters.incident_event_repository.clone(),
+        incident_notification_repository: application_state.adapters.incident_notification_repository.clone(),
+        maintenance_window_repository: application_state.adapters.maintenance_window_repository.clone(),
         select_limit: config.absent_tasks_collector.select_limit,
     };
     let absent_tasks_collector_tasks = absent_tasks_collector.spawn_tasks(
@@ -131,6 +173,19 @@ pub async fn start_server() -> anyhow::Result<()> {
         Duration::from_secs(config.absent_tasks_collector.interval_seconds),
     );
 
+    let webhook_deliveries = DeliverWebhooksUseCase {
+        incident_repository: application_state.adapters.incident_repository.clone(),
+        incident_event_repository: application_state.adapters.incident_event_repository.clone(),
+        webhook_endpoint_repository: application_state.adapters.webhook_endpoint_repository.clone(),
+        webhook_delivery_repository: application_state.adapters.webhook_delivery_repository.clone(),
+        webhook_client: application_state.adapters.webhook_client.clone(),
+        select_limit: config.webhook_deliveries.select_limit,
+    };
+    let webhook_deliveries_tasks = webhook_deliveries.spawn_tasks(
+        config.webhook_deliveries.concurrent_tasks,
+        Duration::from_secs(config.webhook_deliveries.interval_seconds),
+    );
+
     let server_task = tokio::spawn(server::start_server(application_state, config.server_port));
 
     // Wait for all tasks to finish
@@ -138,9 +193,11 @@ pub async fn start_server() -> anyhow::Result<()> {
         http_monitors_tasks.join_all(),
         incident_notifications_tasks.join_all(),
         dead_task_runs_collector_tasks.join_all(),
+        timed_out_task_runs_collector_tasks.join_all(),
         due_tasks_collector_tasks.join_all(),
         late_tasks_collector_tasks.join_all(),
         absent_tasks_collector_tasks.join_all(),
+        webhook_deliveries_tasks.join_all(),
         server_task
     );
 
@@ -203,6 +260,17 @@ async fn build_app_state(config: Arc<AppConfig>) -> anyhow::Result<ApplicationSt
             .context("Failed to create file storage adapter")?,
         task_repository: TaskRepositoryAdapter { pool: pool.clone() },
         task_run_repository: TaskRunRepositoryAdapter { pool: pool.clone() },
+        task_run_event_repository: TaskRunEventRepositoryAdapter { pool: pool.clone() },
+        escalation_policy_repository: EscalationPolicyRepositoryAdapter { pool: pool.clone() },
+        webhook_endpoint_repository: WebhookEndpointRepositoryAdapter { pool: pool.clone() },
+        webhook_delivery_repository: WebhookDeliveryRepositoryAdapter { pool: pool.clone() },
+        webhook_client: WebhookClientAdapter::new()
+            .context("Failed to create webhook client adapter")?,
+        status_page_repository: StatusPageRepositoryAdapter { pool: pool.clone() },
+        maintenance_window_repository: MaintenanceWindowRepositoryAdapter { pool: pool.clone() },
+        on_call_schedule_repository: OnCallScheduleRepositoryAdapter { pool: pool.clone() },
+        notification_preferences_repository: NotificationPreferencesRepositoryAdapter { pool: pool.clone() },
+        alert_integration_repository: AlertIntegrationRepositoryAdapter { pool: pool.clone() },
     };
     Ok(ApplicationState {
         config: config.clone(),
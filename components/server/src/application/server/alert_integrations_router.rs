@@ -0,0 +1,263 @@
+use axum::{
+    extract::{Path, State},
+    http::{header::AUTHORIZATION, HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::{get, post},
+    Json, Router,
+};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    application::application_state::{ApplicationState, ExtractAppState},
+    domain::{
+        entities::{alert_integration::AlertPayload, authorization::AuthContext},
+        use_cases::alert_integrations::{
+            self, CreateAlertIntegrationCommand, CreateAlertIntegrationError,
+            DeleteAlertIntegrationError, GetAlertIntegrationError, ListAlertIntegrationsError,
+            ReceiveAlertsError, UpdateAlertIntegrationCommand, UpdateAlertIntegrationError,
+        },
+    },
+};
+
+pub fn alert_integrations_router() -> Router<ApplicationState> {
+    Router::new()
+        .route(
+            "/",
+            get(list_alert_integrations_handler).post(create_alert_integration_handler),
+        )
+        .route(
+            "/:integration_id",
+            get(get_alert_integration_handler)
+                .put(update_alert_integration_handler)
+                .delete(delete_alert_integration_handler),
+        )
+        .route("/:integration_id/alerts", post(receive_alerts_handler))
+}
+
+/// List the alert integrations of the organization
+#[utoipa::path(
+    get,
+    path = "/integrations",
+    responses(
+        (status = 200, description = "Alert integrations fetched successfully", body = ListAlertIntegrationsResponse),
+        (status = 403, description = "User is not authorized to list alert integrations"),
+        (status = 500, description = "Technical failure occured while listing alert integrations")
+    )
+)]
+async fn list_alert_integrations_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+) -> impl IntoResponse {
+    match alert_integrations::list_alert_integrations(
+        &auth_context,
+        &app_state.adapters.alert_integration_repository,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(ListAlertIntegrationsError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(ListAlertIntegrationsError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while listing alert integrations");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Create an alert integration
+///
+/// The response contains the secret the external system must send in the `Authorization` header
+/// as `Bearer <secret>` when it posts alerts to `/integrations/:integration_id/alerts`.
+/// The secret cannot be retrieved afterwards.
+#[utoipa::path(
+    post,
+    path = "/integrations",
+    request_body = CreateAlertIntegrationCommand,
+    responses(
+        (status = 201, description = "Alert integration created successfully", body = CreateAlertIntegrationResponse),
+        (status = 400, description = "Invalid alert integration"),
+        (status = 403, description = "User is not authorized to create alert integrations"),
+        (status = 500, description = "Technical failure occured while creating the alert integration")
+    )
+)]
+async fn create_alert_integration_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Json(command): Json<CreateAlertIntegrationCommand>,
+) -> impl IntoResponse {
+    match alert_integrations::create_alert_integration(
+        &auth_context,
+        &app_state.adapters.alert_integration_repository,
+        command,
+    )
+    .await
+    {
+        Ok(res) => (StatusCode::CREATED, Json(res)).into_response(),
+        Err(CreateAlertIntegrationError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(CreateAlertIntegrationError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while creating an alert integration");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+/// Get an alert integration
+#[utoipa::path(
+    get,
+    path = "/integrations/:integration_id",
+    responses(
+        (status = 200, description = "Alert integration fetched successfully", body = AlertIntegration),
+        (status = 403, description = "User is not authorized to read alert integrations"),
+        (status = 404, description = "Alert integration not found"),
+        (status = 500, description = "Technical failure occured while fetching the alert integration")
+    )
+)]
+async fn get_alert_integration_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(integration_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match alert_integrations::get_alert_integration(
+        &auth_context,
+        &app_state.adapters.alert_integration_repository,
+        integration_id,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(GetAlertIntegrationError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(GetAlertIntegrationError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(GetAlertIntegrationError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while getting an alert integration");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Update the name and the default priority of an alert integration
+#[utoipa::path(
+    put,
+    path = "/integrations/:integration_id",
+    request_body = UpdateAlertIntegrationCommand,
+    responses(
+        (status = 200, description = "Alert integration updated successfully"),
+        (status = 400, description = "Invalid alert integration"),
+        (status = 403, description = "User is not authorized to update alert integrations"),
+        (status = 404, description = "Alert integration not found"),
+        (status = 500, description = "Technical failure occured while updating the alert integration")
+    )
+)]
+async fn update_alert_integration_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(integration_id): Path<Uuid>,
+    Json(command): Json<UpdateAlertIntegrationCommand>,
+) -> impl IntoResponse {
+    match alert_integrations::update_alert_integration(
+        &auth_context,
+        &app_state.adapters.alert_integration_repository,
+        integration_id,
+        command,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(UpdateAlertIntegrationError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(UpdateAlertIntegrationError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(UpdateAlertIntegrationError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while updating an alert integration");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+/// Delete an alert integration
+///
+/// The incidents opened by the integration are kept.
+#[utoipa::path(
+    delete,
+    path = "/integrations/:integration_id",
+    responses(
+        (status = 200, description = "Alert integration deleted successfully"),
+        (status = 403, description = "User is not authorized to delete alert integrations"),
+        (status = 404, description = "Alert integration not found"),
+        (status = 500, description = "Technical failure occured while deleting the alert integration")
+    )
+)]
+async fn delete_alert_integration_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(integration_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match alert_integrations::delete_alert_integration(
+        &auth_context,
+        &app_state.adapters.alert_integration_repository,
+        integration_id,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(DeleteAlertIntegrationError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(DeleteAlertIntegrationError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(DeleteAlertIntegrationError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while deleting an alert integration");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Receive alerts from an external system
+///
+/// Accepts the webhook payloads of Prometheus Alertmanager and Grafana alerting, as well as a single alert
+/// in the native format. The request is authenticated with the secret of the integration,
+/// sent in the `Authorization` header as `Bearer <secret>`.
+///
+/// A firing alert opens an incident, unless the alert already has an ongoing incident,
+/// and a resolved alert resolves its incident. Alerts are identified by their fingerprint,
+/// and their labels become the metadata of their incident.
+#[utoipa::path(
+    post,
+    path = "/integrations/:integration_id/alerts",
+    request_body = AlertPayload,
+    responses(
+        (status = 200, description = "Alerts received successfully", body = ReceiveAlertsResponse),
+        (status = 400, description = "Invalid payload"),
+        (status = 401, description = "Unknown alert integration or invalid secret"),
+        (status = 500, description = "Technical failure occured while receiving alerts")
+    )
+)]
+async fn receive_alerts_handler(
+    State(app_state): ExtractAppState,
+    Path(integration_id): Path<Uuid>,
+    headers: HeaderMap,
+    Json(payload): Json<AlertPayload>,
+) -> impl IntoResponse {
+    let secret = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match alert_integrations::receive_alerts(
+        &app_state.adapters.alert_integration_repository,
+        &app_state.adapters.incident_repository,
+        &app_state.adapters.incident_event_repository,
+        &app_state.adapters.incident_notification_repository,
+        &app_state.adapters.maintenance_window_repository,
+        integration_id,
+        secret,
+        payload,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(ReceiveAlertsError::Unauthorized) => StatusCode::UNAUTHORIZED.into_response(),
+        Err(e @ ReceiveAlertsError::InvalidPayload(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(ReceiveAlertsError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while receiving alerts");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
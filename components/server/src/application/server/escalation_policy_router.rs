@@ -0,0 +1,92 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use tracing::warn;
+
+use crate::{
+    application::application_state::{ApplicationState, ExtractAppState},
+    domain::{
+        entities::authorization::AuthContext,
+        use_cases::escalation_policies::{
+            self, GetEscalationPolicyError, UpdateEscalationPolicyCommand,
+            UpdateEscalationPolicyError,
+        },
+    },
+};
+
+pub fn escalation_policy_router() -> Router<ApplicationState> {
+    Router::new().route(
+        "/",
+        get(get_escalation_policy_handler).put(update_escalation_policy_handler),
+    )
+}
+
+/// Get the escalation policy of the organization
+#[utoipa::path(
+    get,
+    path = "/escalation-policy",
+    responses(
+        (status = 200, description = "Escalation policy fetched successfully", body = GetEscalationPolicyResponse),
+        (status = 403, description = "User is not authorized to fetch the escalation policy"),
+        (status = 500, description = "Technical failure occured while fetching the escalation policy from the database")
+    )
+)]
+async fn get_escalation_policy_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+) -> impl IntoResponse {
+    match escalation_policies::get_escalation_policy(
+        &auth_context,
+        &app_state.adapters.escalation_policy_repository,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(GetEscalationPolicyError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(GetEscalationPolicyError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while getting escalation policy from the database");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Replace the escalation policy of the organization
+#[utoipa::path(
+    put,
+    path = "/escalation-policy",
+    request_body = UpdateEscalationPolicyCommand,
+    responses(
+        (status = 200, description = "Escalation policy updated successfully"),
+        (status = 400, description = "Invalid escalation policy"),
+        (status = 403, description = "User is not authorized to update the escalation policy"),
+        (status = 500, description = "Technical failure occured while updating the escalation policy")
+    )
+)]
+async fn update_escalation_policy_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Json(command): Json<UpdateEscalationPolicyCommand>,
+) -> impl IntoResponse {
+    match escalation_policies::update_escalation_policy(
+        &auth_context,
+        &app_state.adapters.escalation_policy_repository,
+        command,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(UpdateEscalationPolicyError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(e @ UpdateEscalationPolicyError::TooManyLevels)
+        | Err(e @ UpdateEscalationPolicyError::InvalidDelay) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(UpdateEscalationPolicyError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while updating escalation policy");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
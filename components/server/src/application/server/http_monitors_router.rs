@@ -15,7 +15,7 @@ use crate::{
         entities::authorization::AuthContext,
         use_cases::{
             http_monitors::{
-                self, ArchiveMonitorError, CreateHttpMonitorCommand, CreateHttpMonitorError, ListHttpMonitorsError, ListHttpMonitorsParams, ReadHttpMonitorError, ToggleMonitorError, UpdateHttpMonitorCommand, UpdateHttpMonitorError
+                self, ArchiveMonitorError, CreateHttpMonitorCommand, CreateHttpMonitorError, GetHttpMonitorStatsError, GetHttpMonitorStatsParams, ListHttpMonitorsError, ListProbeLocationsError, ListHttpMonitorsParams, ReadHttpMonitorError, ToggleMonitorError, UpdateHttpMonitorCommand, UpdateHttpMonitorError
             },
             incidents::{ListIncidentsError, ListIncidentsParams},
         },
@@ -32,6 +32,7 @@ pub fn http_monitors_router() -> Router<ApplicationState> {
             "/filterable-metadata",
             get(get_filterable_http_monitor_metadata_handler),
         )
+        .route("/locations", get(list_probe_locations_handler))
         .route(
             "/:monitor_id",
             get(get_http_monitor_handler).patch(update_http_monitor_handler),
@@ -40,6 +41,7 @@ pub fn http_monitors_router() -> Router<ApplicationState> {
             "/:monitor_id/incidents",
             get(get_http_monitor_incidents_handler),
         )
+        .route("/:monitor_id/stats", get(get_http_monitor_stats_handler))
         .route("/:monitor_id/toggle", post(toggle_http_monitor_handler))
         .route("/:monitor_id/archive", post(archive_http_monitor_handler))
 }
@@ -104,6 +106,48 @@ async fn get_http_monitor_incidents_handler(
     }
 }
 
+/// Get the stats of a HTTP monitor
+///
+/// Returns the uptime, the response time percentiles and the errors of a HTTP monitor, per bucket of time.
+#[utoipa::path(
+    get,
+    path = "/http-monitors/:monitor_id/stats",
+    params(GetHttpMonitorStatsParams),
+    responses(
+        (status = 200, description = "HTTP monitor stats computed successfully", body = HttpMonitorStats),
+        (status = 400, description = "The requested range is invalid or contains too many buckets"),
+        (status = 403, description = "User is not authorized to read the HTTP monitor"),
+        (status = 404, description = "HTTP monitor not found"),
+        (status = 500, description = "Technical failure occured while computing the HTTP monitor stats")
+    )
+)]
+async fn get_http_monitor_stats_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(monitor_id): Path<Uuid>,
+    Query(params): Query<GetHttpMonitorStatsParams>,
+) -> impl IntoResponse {
+    match http_monitors::get_http_monitor_stats(
+        &auth_context,
+        &app_state.adapters.http_monitors_repository,
+        monitor_id,
+        params,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(GetHttpMonitorStatsError::InvalidRange(message)) => {
+            (StatusCode::BAD_REQUEST, message).into_response()
+        }
+        Err(GetHttpMonitorStatsError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(GetHttpMonitorStatsError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(GetHttpMonitorStatsError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while computing HTTP monitor stats");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
 /// Toggle a HTTP monitor
 ///
 /// Toggles a HTTP monitor by its ID.
@@ -253,6 +297,7 @@ async fn create_http_monitor_handler(
     match http_monitors::create_http_monitor(
         &auth_context,
         &app_state.adapters.http_monitors_repository,
+        &app_state.adapters.http_client,
         command,
     )
     .await
@@ -260,6 +305,21 @@ async fn create_http_monitor_handler(
         Ok(res) => Json(res).into_response(),
         Err(CreateHttpMonitorError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
         Err(CreateHttpMonitorError::InvalidUrl(_)) => StatusCode::BAD_REQUEST.into_response(),
+        Err(e @ CreateHttpMonitorError::InvalidAssertion(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(e @ CreateHttpMonitorError::InvalidLocations(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(e @ CreateHttpMonitorError::InvalidRequest(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(e @ CreateHttpMonitorError::InvalidTlsExpiryWarning(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(e @ CreateHttpMonitorError::InvalidKindSettings(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
         Err(CreateHttpMonitorError::TechnicalFailure(e)) => {
             warn!(error = ?e, "Technical failure occured while getting creating a new monitor");
             StatusCode::INTERNAL_SERVER_ERROR.into_response()
@@ -290,6 +350,7 @@ async fn update_http_monitor_handler(
     match http_monitors::update_http_monitor(
         &auth_context,
         &app_state.adapters.http_monitors_repository,
+        &app_state.adapters.http_client,
         monitor_id,
         command,
     )
@@ -308,6 +369,21 @@ async fn update_http_monitor_handler(
         Err(UpdateHttpMonitorError::InvalidRequestTimeout) => {
             (StatusCode::BAD_REQUEST, "Invalid request timeout").into_response()
         }
+        Err(e @ UpdateHttpMonitorError::InvalidAssertion(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(e @ UpdateHttpMonitorError::InvalidLocations(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(e @ UpdateHttpMonitorError::InvalidRequest(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(e @ UpdateHttpMonitorError::InvalidTlsExpiryWarning(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(e @ UpdateHttpMonitorError::InvalidKindSettings(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
         Err(UpdateHttpMonitorError::MonitorIsArchived) => (
             StatusCode::BAD_REQUEST,
             "Monitor is archived and cannot be updated",
@@ -337,3 +413,24 @@ async fn get_filterable_http_monitor_metadata_handler(
         }
     }
 }
+
+/// List the probe locations
+///
+/// Returns the names of the locations HTTP monitors can be pinged from.
+#[utoipa::path(
+    get,
+    path = "/http-monitors/locations",
+    responses(
+        (status = 200, description = "Probe locations listed successfully", body = ListProbeLocationsResponse),
+        (status = 403, description = "User is not authorized to list the probe locations"),
+    )
+)]
+async fn list_probe_locations_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+) -> impl IntoResponse {
+    match http_monitors::list_probe_locations(&auth_context, &app_state.adapters.http_client).await {
+        Ok(res) => Json(res).into_response(),
+        Err(ListProbeLocationsError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+    }
+}
@@ -15,17 +15,23 @@ use crate::{
         entities::authorization::AuthContext,
         use_cases::incidents::{
             self, AcknowledgeIncidentError, CommentIncidentError, CommentIncidentRequest,
-            GetIncidentError, GetIncidentTimelineError, GetIncidentTimelineParams,
-            ListIncidentsError, ListIncidentsParams,
+            CreateManualIncidentCommand, CreateManualIncidentError, GetIncidentError,
+            GetIncidentTimelineError, GetIncidentTimelineParams, ListIncidentsError,
+            ListIncidentsParams, ResolveIncidentManuallyError, UpdateIncidentCommand,
+            UpdateIncidentError,
         },
     },
 };
 
 pub fn incidents_router() -> Router<ApplicationState> {
     Router::new()
-        .route("/", get(list_incidents_handler))
+        .route("/", get(list_incidents_handler).post(create_manual_incident_handler))
         .route("/filterable-metadata", get(get_filterable_incident_metadata_handler))
-        .route("/:incident_id", get(get_incident_handler))
+        .route(
+            "/:incident_id",
+            get(get_incident_handler).patch(update_incident_handler),
+        )
+        .route("/:incident_id/resolve", post(resolve_incident_handler))
         .route(
             "/:incident_id/acknowledge",
             post(acknowledge_incident_handler),
@@ -72,6 +78,126 @@ async fn list_incidents_handler(
     }
 }
 
+/// Declare an incident
+///
+/// Creates an incident that was not detected by a monitor or a task, and notifies the organization
+#[utoipa::path(
+    post,
+    path = "/incidents",
+    request_body = CreateManualIncidentCommand,
+    responses(
+        (status = 201, description = "Incident created successfully", body = CreateManualIncidentResponse),
+        (status = 400, description = "Invalid incident"),
+        (status = 403, description = "User is not authorized to create incidents"),
+        (status = 500, description = "Technical failure occured while creating the incident")
+    )
+)]
+async fn create_manual_incident_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Json(command): Json<CreateManualIncidentCommand>,
+) -> impl IntoResponse {
+    match incidents::create_manual_incident(
+        &auth_context,
+        &app_state.adapters.incident_repository,
+        &app_state.adapters.incident_event_repository,
+        &app_state.adapters.incident_notification_repository,
+        command,
+    )
+    .await
+    {
+        Ok(res) => (StatusCode::CREATED, Json(res)).into_response(),
+        Err(CreateManualIncidentError::InvalidIncident(message)) => {
+            (StatusCode::BAD_REQUEST, message).into_response()
+        }
+        Err(CreateManualIncidentError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(CreateManualIncidentError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while creating incident");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Update an incident
+///
+/// Changes the priority, metadata or title of an incident. Each change is recorded in the timeline of the incident
+#[utoipa::path(
+    patch,
+    path = "/incidents/{incident_id}",
+    request_body = UpdateIncidentCommand,
+    responses(
+        (status = 200, description = "Incident updated successfully"),
+        (status = 400, description = "Invalid incident"),
+        (status = 403, description = "User is not authorized to edit incidents"),
+        (status = 404, description = "Incident not found"),
+        (status = 500, description = "Technical failure occured while updating the incident")
+    )
+)]
+async fn update_incident_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(incident_id): Path<Uuid>,
+    Json(command): Json<UpdateIncidentCommand>,
+) -> impl IntoResponse {
+    match incidents::update_incident(
+        &auth_context,
+        &app_state.adapters.incident_repository,
+        &app_state.adapters.incident_event_repository,
+        incident_id,
+        command,
+    )
+    .await
+    {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(UpdateIncidentError::InvalidIncident(message)) => {
+            (StatusCode::BAD_REQUEST, message).into_response()
+        }
+        Err(UpdateIncidentError::IncidentNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(UpdateIncidentError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(UpdateIncidentError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while updating incident");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Resolve an incident
+#[utoipa::path(
+    post,
+    path = "/incidents/{incident_id}/resolve",
+    responses(
+        (status = 200, description = "Incident resolved successfully"),
+        (status = 403, description = "User is not authorized to resolve incidents"),
+        (status = 404, description = "Incident not found"),
+        (status = 409, description = "Incident is already resolved"),
+        (status = 500, description = "Technical failure occured while resolving the incident")
+    )
+)]
+async fn resolve_incident_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(incident_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match incidents::resolve_incident_manually(
+        &auth_context,
+        &app_state.adapters.incident_repository,
+        &app_state.adapters.incident_event_repository,
+        &app_state.adapters.incident_notification_repository,
+        incident_id,
+    )
+    .await
+    {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(ResolveIncidentManuallyError::IncidentNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(ResolveIncidentManuallyError::IncidentAlreadyResolved) => StatusCode::CONFLICT.into_response(),
+        Err(ResolveIncidentManuallyError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(ResolveIncidentManuallyError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while resolving incident");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
 /// Get a single incident by id
 #[utoipa::path(
     get,
@@ -127,7 +253,9 @@ async fn get_incident_timeline_handler(
 ) -> impl IntoResponse {
     match incidents::get_incident_timeline(
         &auth_context,
+        &app_state.adapters.incident_repository,
         &app_state.adapters.incident_event_repository,
+        &app_state.adapters.maintenance_window_repository,
         &app_state.adapters.user_repository,
         incident_id,
         params,
@@ -0,0 +1,131 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get},
+    Json, Router,
+};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    application::application_state::{ApplicationState, ExtractAppState},
+    domain::{
+        entities::authorization::AuthContext,
+        use_cases::maintenance_windows::{
+            self, CreateMaintenanceWindowCommand, CreateMaintenanceWindowError,
+            DeleteMaintenanceWindowError, ListMaintenanceWindowsError,
+        },
+    },
+};
+
+pub fn maintenance_windows_router() -> Router<ApplicationState> {
+    Router::new()
+        .route(
+            "/",
+            get(list_maintenance_windows_handler).post(create_maintenance_window_handler),
+        )
+        .route(
+            "/:maintenance_window_id",
+            delete(delete_maintenance_window_handler),
+        )
+}
+
+/// List the maintenance windows of the organization
+#[utoipa::path(
+    get,
+    path = "/maintenance-windows",
+    responses(
+        (status = 200, description = "Maintenance windows fetched successfully", body = ListMaintenanceWindowsResponse),
+        (status = 403, description = "User is not authorized to list maintenance windows"),
+        (status = 500, description = "Technical failure occured while listing maintenance windows")
+    )
+)]
+async fn list_maintenance_windows_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+) -> impl IntoResponse {
+    match maintenance_windows::list_maintenance_windows(
+        &auth_context,
+        &app_state.adapters.maintenance_window_repository,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(ListMaintenanceWindowsError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(ListMaintenanceWindowsError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while listing maintenance windows");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Create a maintenance window
+///
+/// While a maintenance window is ongoing, the monitors and tasks in its scope keep being checked,
+/// but they do not open incidents nor send notifications.
+#[utoipa::path(
+    post,
+    path = "/maintenance-windows",
+    request_body = CreateMaintenanceWindowCommand,
+    responses(
+        (status = 201, description = "Maintenance window created successfully", body = CreateMaintenanceWindowResponse),
+        (status = 400, description = "Invalid maintenance window"),
+        (status = 403, description = "User is not authorized to create maintenance windows"),
+        (status = 500, description = "Technical failure occured while creating the maintenance window")
+    )
+)]
+async fn create_maintenance_window_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Json(command): Json<CreateMaintenanceWindowCommand>,
+) -> impl IntoResponse {
+    match maintenance_windows::create_maintenance_window(
+        &auth_context,
+        &app_state.adapters.maintenance_window_repository,
+        command,
+    )
+    .await
+    {
+        Ok(res) => (StatusCode::CREATED, Json(res)).into_response(),
+        Err(CreateMaintenanceWindowError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(CreateMaintenanceWindowError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while creating a maintenance window");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+/// Delete a maintenance window
+#[utoipa::path(
+    delete,
+    path = "/maintenance-windows/:maintenance_window_id",
+    responses(
+        (status = 200, description = "Maintenance window deleted successfully"),
+        (status = 403, description = "User is not authorized to delete maintenance windows"),
+        (status = 404, description = "Maintenance window not found"),
+        (status = 500, description = "Technical failure occured while deleting the maintenance window")
+    )
+)]
+async fn delete_maintenance_window_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(maintenance_window_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match maintenance_windows::delete_maintenance_window(
+        &auth_context,
+        &app_state.adapters.maintenance_window_repository,
+        maintenance_window_id,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(DeleteMaintenanceWindowError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(DeleteMaintenanceWindowError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(DeleteMaintenanceWindowError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while deleting a maintenance window");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
@@ -1,28 +1,41 @@
+mod alert_integrations_router;
 mod api_tokens_router;
 mod auth_context_extractor;
+mod escalation_policy_router;
 mod file_router;
 mod http_monitors_router;
 mod incidents_router;
+mod maintenance_windows_router;
+mod notification_preferences_router;
+mod on_call_router;
 mod openapi;
 mod organizations_router;
 mod user_devices_router;
+mod status_pages_router;
 mod users_router;
 mod tasks_router;
+mod webhooks_router;
 
 use std::time::Duration;
 
+use alert_integrations_router::alert_integrations_router;
 use api_tokens_router::api_tokens_router;
 use axum::{routing::get, Json, Router};
+use escalation_policy_router::escalation_policy_router;
 use file_router::file_router;
 use http_monitors_router::http_monitors_router;
 use incidents_router::incidents_router;
+use maintenance_windows_router::maintenance_windows_router;
+use on_call_router::on_call_router;
 use openapi::redoc_router;
 use organizations_router::organizations_router;
-use tasks_router::tasks_router;
+use status_pages_router::{public_status_pages_router, status_pages_router};
+use tasks_router::{task_ping_router, tasks_router};
 use tokio::signal;
 use tower_http::{cors::CorsLayer, timeout::TimeoutLayer, trace::TraceLayer};
 use tracing::info;
 use users_router::users_router;
+use webhooks_router::webhooks_router;
 
 use super::{application_state::ApplicationState, built_info::build_info_json};
 
@@ -34,8 +47,16 @@ pub async fn start_server(application_state: ApplicationState, port: u16) -> any
         .nest("/organizations", organizations_router())
         .nest("/files", file_router())
         .nest("/tasks", tasks_router())
+        .nest("/ping", task_ping_router())
         .nest("/redoc", redoc_router())
         .nest("/api-tokens", api_tokens_router())
+        .nest("/escalation-policy", escalation_policy_router())
+        .nest("/webhooks", webhooks_router())
+        .nest("/status-pages", status_pages_router())
+        .nest("/public/status-pages", public_status_pages_router())
+        .nest("/maintenance-windows", maintenance_windows_router())
+        .nest("/on-call", on_call_router())
+        .nest("/integrations", alert_integrations_router())
         .route("/", get(|| async { Json(build_info_json()) }))
         .layer(CorsLayer::permissive())
         .with_state(application_state)
@@ -0,0 +1,63 @@
+use axum::{
+    extract::State, http::StatusCode, response::IntoResponse, routing::get, Json, Router,
+};
+use tracing::warn;
+
+use crate::{
+    application::application_state::{ApplicationState, ExtractAppState},
+    domain::{
+        entities::authorization::AuthContext,
+        use_cases::notification_preferences::{
+            self, GetNotificationPreferencesError, UpdateNotificationPreferencesCommand,
+            UpdateNotificationPreferencesError,
+        },
+    },
+};
+
+pub fn notification_preferences_router() -> Router<ApplicationState> {
+    Router::new().route(
+        "/",
+        get(get_notification_preferences_handler).put(update_notification_preferences_handler),
+    )
+}
+
+async fn get_notification_preferences_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+) -> impl IntoResponse {
+    match notification_preferences::get_notification_preferences(
+        &auth_context,
+        &app_state.adapters.notification_preferences_repository,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(GetNotificationPreferencesError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while getting notification preferences");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+async fn update_notification_preferences_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Json(command): Json<UpdateNotificationPreferencesCommand>,
+) -> impl IntoResponse {
+    match notification_preferences::update_notification_preferences(
+        &auth_context,
+        &app_state.adapters.notification_preferences_repository,
+        command,
+    )
+    .await
+    {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(UpdateNotificationPreferencesError::InvalidNotificationPreferences(msg)) => {
+            (StatusCode::BAD_REQUEST, msg).into_response()
+        }
+        Err(UpdateNotificationPreferencesError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while updating notification preferences");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
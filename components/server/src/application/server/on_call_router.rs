@@ -0,0 +1,323 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post},
+    Json, Router,
+};
+use chrono::Utc;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    application::application_state::{ApplicationState, ExtractAppState},
+    domain::{
+        entities::authorization::AuthContext,
+        use_cases::on_call::{
+            self, CreateOnCallOverrideCommand, CreateOnCallOverrideError,
+            CreateOnCallScheduleCommand, CreateOnCallScheduleError, DeleteOnCallOverrideError,
+            DeleteOnCallScheduleError, GetCurrentOnCallError, GetOnCallScheduleError,
+            ListOnCallSchedulesError, UpdateOnCallScheduleCommand, UpdateOnCallScheduleError,
+        },
+    },
+};
+
+pub fn on_call_router() -> Router<ApplicationState> {
+    Router::new()
+        .route(
+            "/",
+            get(list_on_call_schedules_handler).post(create_on_call_schedule_handler),
+        )
+        .route(
+            "/:on_call_schedule_id",
+            get(get_on_call_schedule_handler)
+                .put(update_on_call_schedule_handler)
+                .delete(delete_on_call_schedule_handler),
+        )
+        .route("/:on_call_schedule_id/now", get(get_current_on_call_handler))
+        .route(
+            "/:on_call_schedule_id/overrides",
+            post(create_on_call_override_handler),
+        )
+        .route(
+            "/:on_call_schedule_id/overrides/:on_call_override_id",
+            delete(delete_on_call_override_handler),
+        )
+}
+
+/// List the on-call schedules of the organization
+#[utoipa::path(
+    get,
+    path = "/on-call",
+    responses(
+        (status = 200, description = "On-call schedules fetched successfully", body = ListOnCallSchedulesResponse),
+        (status = 403, description = "User is not authorized to list on-call schedules"),
+        (status = 500, description = "Technical failure occured while listing on-call schedules")
+    )
+)]
+async fn list_on_call_schedules_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+) -> impl IntoResponse {
+    match on_call::list_on_call_schedules(
+        &auth_context,
+        &app_state.adapters.on_call_schedule_repository,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(ListOnCallSchedulesError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(ListOnCallSchedulesError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while listing on-call schedules");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Create an on-call schedule
+#[utoipa::path(
+    post,
+    path = "/on-call",
+    request_body = CreateOnCallScheduleCommand,
+    responses(
+        (status = 201, description = "On-call schedule created successfully", body = CreateOnCallScheduleResponse),
+        (status = 400, description = "Invalid on-call schedule"),
+        (status = 403, description = "User is not authorized to create on-call schedules"),
+        (status = 500, description = "Technical failure occured while creating the on-call schedule")
+    )
+)]
+async fn create_on_call_schedule_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Json(command): Json<CreateOnCallScheduleCommand>,
+) -> impl IntoResponse {
+    match on_call::create_on_call_schedule(
+        &auth_context,
+        &app_state.adapters.on_call_schedule_repository,
+        command,
+    )
+    .await
+    {
+        Ok(res) => (StatusCode::CREATED, Json(res)).into_response(),
+        Err(CreateOnCallScheduleError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(CreateOnCallScheduleError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while creating an on-call schedule");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+/// Get an on-call schedule, along with its ongoing and future overrides
+#[utoipa::path(
+    get,
+    path = "/on-call/:on_call_schedule_id",
+    responses(
+        (status = 200, description = "On-call schedule fetched successfully", body = GetOnCallScheduleResponse),
+        (status = 403, description = "User is not authorized to read on-call schedules"),
+        (status = 404, description = "On-call schedule not found"),
+        (status = 500, description = "Technical failure occured while fetching the on-call schedule")
+    )
+)]
+async fn get_on_call_schedule_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(on_call_schedule_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match on_call::get_on_call_schedule(
+        &auth_context,
+        &app_state.adapters.on_call_schedule_repository,
+        on_call_schedule_id,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(GetOnCallScheduleError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(GetOnCallScheduleError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(GetOnCallScheduleError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while getting an on-call schedule");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Update an on-call schedule
+#[utoipa::path(
+    put,
+    path = "/on-call/:on_call_schedule_id",
+    request_body = UpdateOnCallScheduleCommand,
+    responses(
+        (status = 200, description = "On-call schedule updated successfully"),
+        (status = 400, description = "Invalid on-call schedule"),
+        (status = 403, description = "User is not authorized to update on-call schedules"),
+        (status = 404, description = "On-call schedule not found"),
+        (status = 500, description = "Technical failure occured while updating the on-call schedule")
+    )
+)]
+async fn update_on_call_schedule_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(on_call_schedule_id): Path<Uuid>,
+    Json(command): Json<UpdateOnCallScheduleCommand>,
+) -> impl IntoResponse {
+    match on_call::update_on_call_schedule(
+        &auth_context,
+        &app_state.adapters.on_call_schedule_repository,
+        on_call_schedule_id,
+        command,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(UpdateOnCallScheduleError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(UpdateOnCallScheduleError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(UpdateOnCallScheduleError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while updating an on-call schedule");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+/// Delete an on-call schedule and its overrides
+#[utoipa::path(
+    delete,
+    path = "/on-call/:on_call_schedule_id",
+    responses(
+        (status = 200, description = "On-call schedule deleted successfully"),
+        (status = 403, description = "User is not authorized to delete on-call schedules"),
+        (status = 404, description = "On-call schedule not found"),
+        (status = 500, description = "Technical failure occured while deleting the on-call schedule")
+    )
+)]
+async fn delete_on_call_schedule_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(on_call_schedule_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match on_call::delete_on_call_schedule(
+        &auth_context,
+        &app_state.adapters.on_call_schedule_repository,
+        on_call_schedule_id,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(DeleteOnCallScheduleError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(DeleteOnCallScheduleError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(DeleteOnCallScheduleError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while deleting an on-call schedule");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Get who is currently on call for a schedule
+#[utoipa::path(
+    get,
+    path = "/on-call/:on_call_schedule_id/now",
+    responses(
+        (status = 200, description = "Users on call resolved successfully", body = GetCurrentOnCallResponse),
+        (status = 403, description = "User is not authorized to read on-call schedules"),
+        (status = 404, description = "On-call schedule not found"),
+        (status = 500, description = "Technical failure occured while resolving who is on call")
+    )
+)]
+async fn get_current_on_call_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(on_call_schedule_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match on_call::get_current_on_call(
+        &auth_context,
+        &app_state.adapters.on_call_schedule_repository,
+        on_call_schedule_id,
+        Utc::now(),
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(GetCurrentOnCallError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(GetCurrentOnCallError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(GetCurrentOnCallError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while resolving who is on call");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Create an override of an on-call schedule
+///
+/// During an override, the user of the override replaces all the users on call of the schedule.
+#[utoipa::path(
+    post,
+    path = "/on-call/:on_call_schedule_id/overrides",
+    request_body = CreateOnCallOverrideCommand,
+    responses(
+        (status = 201, description = "On-call override created successfully", body = CreateOnCallOverrideResponse),
+        (status = 400, description = "Invalid on-call override"),
+        (status = 403, description = "User is not authorized to create on-call overrides"),
+        (status = 404, description = "On-call schedule not found"),
+        (status = 500, description = "Technical failure occured while creating the on-call override")
+    )
+)]
+async fn create_on_call_override_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(on_call_schedule_id): Path<Uuid>,
+    Json(command): Json<CreateOnCallOverrideCommand>,
+) -> impl IntoResponse {
+    match on_call::create_on_call_override(
+        &auth_context,
+        &app_state.adapters.on_call_schedule_repository,
+        on_call_schedule_id,
+        command,
+    )
+    .await
+    {
+        Ok(res) => (StatusCode::CREATED, Json(res)).into_response(),
+        Err(CreateOnCallOverrideError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(CreateOnCallOverrideError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(e @ CreateOnCallOverrideError::InvalidOnCallOverride) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(CreateOnCallOverrideError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while creating an on-call override");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Delete an override of an on-call schedule
+#[utoipa::path(
+    delete,
+    path = "/on-call/:on_call_schedule_id/overrides/:on_call_override_id",
+    responses(
+        (status = 200, description = "On-call override deleted successfully"),
+        (status = 403, description = "User is not authorized to delete on-call overrides"),
+        (status = 404, description = "On-call override not found"),
+        (status = 500, description = "Technical failure occured while deleting the on-call override")
+    )
+)]
+async fn delete_on_call_override_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path((on_call_schedule_id, on_call_override_id)): Path<(Uuid, Uuid)>,
+) -> impl IntoResponse {
+    match on_call::delete_on_call_override(
+        &auth_context,
+        &app_state.adapters.on_call_schedule_repository,
+        on_call_schedule_id,
+        on_call_override_id,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(DeleteOnCallOverrideError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(DeleteOnCallOverrideError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(DeleteOnCallOverrideError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while deleting an on-call override");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
@@ -3,8 +3,8 @@ use utoipa_redoc::{Redoc, Servable};
 
 use super::*;
 use crate::domain::{
-    entities::{entity_metadata::EntityMetadata, http_monitor::*, incident::*, incident_event::*, task::{BoundaryTask, TaskId, TaskStatus}, task_run::{BoundaryTaskRun, TaskRunStatus}, user::UserNameInfo},
-    use_cases::{http_monitors::*, incidents::*, shared::OrderDirection, tasks::{FinishTaskCommand, GetTaskResponse, ListTaskRunsResponse, ListTasksResponse, NewTask, StartTaskCommand}},
+    entities::{alert_integration::*, entity_metadata::EntityMetadata, escalation_policy::*, http_monitor::*, http_monitor_assertion::*, http_monitor_request::*, http_monitor_ping::HttpMonitorErrorKindCount, incident::*, incident_event::*, maintenance_window::*, monitor_kind::*, on_call_schedule::*, task::{BoundaryTask, ScheduledTaskRun, TaskId, TaskSchedulePreview, TaskStatus}, organization::OrganizationUserRole, task_run::{BoundaryTaskRun, TaskRunStatus}, task_run_event::*, user::UserNameInfo, entity_metadata::{FilterableMetadata, FilterableMetadataItem, FilterableMetadataValue, MetadataFilter}, webhook::*, status_page::*},
+    use_cases::{alert_integrations::*, escalation_policies::*, http_monitors::*, incidents::*, maintenance_windows::*, on_call::*, shared::OrderDirection, status_pages::*, tasks::{FinishTaskCommand, GetTaskResponse, ListTaskRunsResponse, ListTasksResponse, NewTask, StartTaskCommand, StartTaskResponse, TaskPingTokenResponse, AppendTaskRunLogsCommand, ListTaskRunEventsResponse, UpdateTaskCommand, PreviewTaskScheduleCommand}, webhooks::*},
 };
 
 #[derive(OpenApi)]
@@ -13,19 +13,67 @@ use crate::domain::{
         incidents_router::list_incidents_handler,
         incidents_router::get_incident_handler,
         incidents_router::get_incident_timeline_handler,
+        incidents_router::create_manual_incident_handler,
+        incidents_router::update_incident_handler,
+        incidents_router::resolve_incident_handler,
         http_monitors_router::get_http_monitor_handler,
         http_monitors_router::list_http_monitors_handler,
         http_monitors_router::create_http_monitor_handler,
         http_monitors_router::update_http_monitor_handler,
         http_monitors_router::archive_http_monitor_handler,
         http_monitors_router::toggle_http_monitor_handler,
+        http_monitors_router::get_http_monitor_stats_handler,
+        http_monitors_router::list_probe_locations_handler,
         tasks_router::list_tasks_handler,
         tasks_router::create_task_handler,
+        tasks_router::preview_task_schedule_handler,
+        tasks_router::get_filterable_task_metadata_handler,
         tasks_router::get_task_handler,
+        tasks_router::update_task_handler,
+        tasks_router::delete_task_handler,
+        tasks_router::pause_task_handler,
+        tasks_router::resume_task_handler,
         tasks_router::start_task_handler,
         tasks_router::finish_task_handler,
         tasks_router::list_task_runs_handler,
-        tasks_router::send_task_heartbeat_handler
+        tasks_router::send_task_heartbeat_handler,
+        tasks_router::append_task_run_logs_handler,
+        tasks_router::list_task_run_events_handler,
+        tasks_router::get_task_ping_token_handler,
+        tasks_router::rotate_task_ping_token_handler,
+        tasks_router::ping_task_handler,
+        tasks_router::ping_task_signal_handler,
+        escalation_policy_router::get_escalation_policy_handler,
+        escalation_policy_router::update_escalation_policy_handler,
+        webhooks_router::list_webhook_endpoints_handler,
+        webhooks_router::create_webhook_endpoint_handler,
+        webhooks_router::get_webhook_endpoint_handler,
+        webhooks_router::update_webhook_endpoint_handler,
+        webhooks_router::delete_webhook_endpoint_handler,
+        webhooks_router::list_webhook_deliveries_handler,
+        status_pages_router::list_status_pages_handler,
+        status_pages_router::create_status_page_handler,
+        status_pages_router::get_status_page_handler,
+        status_pages_router::update_status_page_handler,
+        status_pages_router::delete_status_page_handler,
+        status_pages_router::get_public_status_page_handler,
+        maintenance_windows_router::list_maintenance_windows_handler,
+        maintenance_windows_router::create_maintenance_window_handler,
+        maintenance_windows_router::delete_maintenance_window_handler,
+        on_call_router::list_on_call_schedules_handler,
+        on_call_router::create_on_call_schedule_handler,
+        on_call_router::get_on_call_schedule_handler,
+        on_call_router::update_on_call_schedule_handler,
+        on_call_router::delete_on_call_schedule_handler,
+        on_call_router::get_current_on_call_handler,
+        on_call_router::create_on_call_override_handler,
+        on_call_router::delete_on_call_override_handler,
+        alert_integrations_router::list_alert_integrations_handler,
+        alert_integrations_router::create_alert_integration_handler,
+        alert_integrations_router::get_alert_integration_handler,
+        alert_integrations_router::update_alert_integration_handler,
+        alert_integrations_router::delete_alert_integration_handler,
+        alert_integrations_router::receive_alerts_handler
     ),
     components(schemas(
         ListIncidentsResponse,
@@ -41,12 +89,25 @@ use crate::domain::{
         HttpMonitorStatus,
         HttpMonitorIncidentCause,
         HttpMonitorIncidentCausePing,
+        TaskIncidentCause,
+        ManualIncidentCause,
+        TcpMonitorIncidentCause,
+        DnsMonitorIncidentCause,
+        GrpcHealthMonitorIncidentCause,
+        ExternalAlertIncidentCause,
         OrderDirection,
         IncidentEvent,
         IncidentEventPayload,
         CommentPayload,
         NotificationEventPayload,
         AcknowledgedEventPayload,
+        PriorityChangedEventPayload,
+        MetadataChangedEventPayload,
+        TitleChangedEventPayload,
+        DownstreamTaskSkippedEventPayload,
+        CreateManualIncidentCommand,
+        CreateManualIncidentResponse,
+        UpdateIncidentCommand,
         IncidentEventType,
         GetIncidentResponse,
         GetIncidentTimelineResponse,
@@ -58,6 +119,22 @@ use crate::domain::{
         CreateHttpMonitorCommand,
         ListHttpMonitorsResponse,
         RequestHeaders,
+        HttpMonitorAssertion,
+        HttpMonitorAssertions,
+        HttpMonitorAssertionFailure,
+        LocationPingResult,
+        HttpMethod,
+        HttpMonitorAuthentication,
+        HttpMonitorRedirectPolicy,
+        ProbeEngine,
+        MonitorKind,
+        MonitorKindSettings,
+        DnsRecordType,
+        ListProbeLocationsResponse,
+        HttpMonitorStats,
+        HttpMonitorStatsBucket,
+        HttpMonitorStatsBucketSize,
+        HttpMonitorErrorKindCount,
         TaskId,
         TaskStatus,
         TaskRunStatus,
@@ -65,10 +142,86 @@ use crate::domain::{
         BoundaryTaskRun,
         ListTasksResponse,
         GetTaskResponse,
+        TaskPingTokenResponse,
+        StartTaskResponse,
+        UpdateTaskCommand,
+        PreviewTaskScheduleCommand,
+        TaskSchedulePreview,
+        ScheduledTaskRun,
+        AppendTaskRunLogsCommand,
+        ListTaskRunEventsResponse,
+        TaskRunEvent,
+        TaskRunEventType,
+        TaskRunEventPayload,
+        TaskRunLogsPayload,
+        TaskRunLogLine,
+        TaskRunLogStream,
         FinishTaskCommand,
         StartTaskCommand,
         ListTaskRunsResponse,
-        NewTask
+        NewTask,
+        EscalationPolicy,
+        EscalationPolicyLevel,
+        GetEscalationPolicyResponse,
+        UpdateEscalationPolicyCommand,
+        OrganizationUserRole,
+        MetadataFilter,
+        FilterableMetadata,
+        FilterableMetadataItem,
+        FilterableMetadataValue,
+        WebhookEndpoint,
+        WebhookPayload,
+        WebhookDelivery,
+        WebhookDeliveryStatus,
+        ListWebhookEndpointsResponse,
+        CreateWebhookEndpointCommand,
+        CreateWebhookEndpointResponse,
+        UpdateWebhookEndpointCommand,
+        ListWebhookDeliveriesResponse,
+        StatusPage,
+        StatusPageComponents,
+        StatusPageComponent,
+        StatusPageVisibility,
+        StatusPageComponentStatus,
+        StatusPageUptimeBar,
+        ListStatusPagesResponse,
+        CreateStatusPageCommand,
+        CreateStatusPageResponse,
+        UpdateStatusPageCommand,
+        PublicStatusPage,
+        PublicStatusPageComponent,
+        PublicStatusPageIncident,
+        MaintenanceWindow,
+        MaintenanceWindowScope,
+        MaintenanceWindowEventPayload,
+        ListMaintenanceWindowsResponse,
+        CreateMaintenanceWindowCommand,
+        CreateMaintenanceWindowResponse,
+        OnCallSchedule,
+        OnCallRotations,
+        OnCallRotation,
+        OnCallRotationKind,
+        OnCallOverride,
+        OnCallShift,
+        ListOnCallSchedulesResponse,
+        GetOnCallScheduleResponse,
+        CreateOnCallScheduleCommand,
+        CreateOnCallScheduleResponse,
+        UpdateOnCallScheduleCommand,
+        GetCurrentOnCallResponse,
+        CreateOnCallOverrideCommand,
+        CreateOnCallOverrideResponse,
+        AlertIntegration,
+        ExternalAlertStatus,
+        AlertPayload,
+        AlertmanagerPayload,
+        AlertmanagerAlert,
+        NativeAlertPayload,
+        ListAlertIntegrationsResponse,
+        CreateAlertIntegrationCommand,
+        CreateAlertIntegrationResponse,
+        UpdateAlertIntegrationCommand,
+        ReceiveAlertsResponse
     ))
 )]
 struct ApiDoc;
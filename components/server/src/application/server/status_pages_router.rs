@@ -0,0 +1,248 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    application::application_state::{ApplicationState, ExtractAppState},
+    domain::{
+        entities::authorization::AuthContext,
+        use_cases::status_pages::{
+            self, CreateStatusPageCommand, CreateStatusPageError, DeleteStatusPageError,
+            GetPublicStatusPageError, GetStatusPageError, ListStatusPagesError,
+            UpdateStatusPageCommand, UpdateStatusPageError,
+        },
+    },
+};
+
+pub fn status_pages_router() -> Router<ApplicationState> {
+    Router::new()
+        .route(
+            "/",
+            get(list_status_pages_handler).post(create_status_page_handler),
+        )
+        .route(
+            "/:status_page_id",
+            get(get_status_page_handler)
+                .put(update_status_page_handler)
+                .delete(delete_status_page_handler),
+        )
+}
+
+/// Routes that can be called without being authenticated
+pub fn public_status_pages_router() -> Router<ApplicationState> {
+    Router::new().route("/:slug", get(get_public_status_page_handler))
+}
+
+/// List the status pages of the organization
+#[utoipa::path(
+    get,
+    path = "/status-pages",
+    responses(
+        (status = 200, description = "Status pages fetched successfully", body = ListStatusPagesResponse),
+        (status = 403, description = "User is not authorized to list status pages"),
+        (status = 500, description = "Technical failure occured while listing status pages")
+    )
+)]
+async fn list_status_pages_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+) -> impl IntoResponse {
+    match status_pages::list_status_pages(&auth_context, &app_state.adapters.status_page_repository)
+        .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(ListStatusPagesError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(ListStatusPagesError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while listing status pages");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Create a status page
+///
+/// Components group HTTP monitors and tasks of the organization. Public status pages are served at `/public/status-pages/:slug`.
+#[utoipa::path(
+    post,
+    path = "/status-pages",
+    request_body = CreateStatusPageCommand,
+    responses(
+        (status = 201, description = "Status page created successfully", body = CreateStatusPageResponse),
+        (status = 400, description = "Invalid status page"),
+        (status = 403, description = "User is not authorized to create status pages"),
+        (status = 409, description = "The slug is already used by another status page"),
+        (status = 500, description = "Technical failure occured while creating the status page")
+    )
+)]
+async fn create_status_page_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Json(command): Json<CreateStatusPageCommand>,
+) -> impl IntoResponse {
+    match status_pages::create_status_page(
+        &auth_context,
+        &app_state.adapters.status_page_repository,
+        &app_state.adapters.http_monitors_repository,
+        &app_state.adapters.task_repository,
+        command,
+    )
+    .await
+    {
+        Ok(res) => (StatusCode::CREATED, Json(res)).into_response(),
+        Err(CreateStatusPageError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(CreateStatusPageError::SlugAlreadyUsed) => StatusCode::CONFLICT.into_response(),
+        Err(CreateStatusPageError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while creating a status page");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+/// Get a status page
+#[utoipa::path(
+    get,
+    path = "/status-pages/:status_page_id",
+    responses(
+        (status = 200, description = "Status page fetched successfully", body = StatusPage),
+        (status = 403, description = "User is not authorized to read status pages"),
+        (status = 404, description = "Status page not found"),
+        (status = 500, description = "Technical failure occured while fetching the status page")
+    )
+)]
+async fn get_status_page_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(status_page_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match status_pages::get_status_page(
+        &auth_context,
+        &app_state.adapters.status_page_repository,
+        status_page_id,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(GetStatusPageError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(GetStatusPageError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(GetStatusPageError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while getting a status page");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Update a status page
+#[utoipa::path(
+    put,
+    path = "/status-pages/:status_page_id",
+    request_body = UpdateStatusPageCommand,
+    responses(
+        (status = 200, description = "Status page updated successfully"),
+        (status = 400, description = "Invalid status page"),
+        (status = 403, description = "User is not authorized to update status pages"),
+        (status = 404, description = "Status page not found"),
+        (status = 409, description = "The slug is already used by another status page"),
+        (status = 500, description = "Technical failure occured while updating the status page")
+    )
+)]
+async fn update_status_page_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(status_page_id): Path<Uuid>,
+    Json(command): Json<UpdateStatusPageCommand>,
+) -> impl IntoResponse {
+    match status_pages::update_status_page(
+        &auth_context,
+        &app_state.adapters.status_page_repository,
+        &app_state.adapters.http_monitors_repository,
+        &app_state.adapters.task_repository,
+        status_page_id,
+        command,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(UpdateStatusPageError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(UpdateStatusPageError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(UpdateStatusPageError::SlugAlreadyUsed) => StatusCode::CONFLICT.into_response(),
+        Err(UpdateStatusPageError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while updating a status page");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+/// Delete a status page
+#[utoipa::path(
+    delete,
+    path = "/status-pages/:status_page_id",
+    responses(
+        (status = 200, description = "Status page deleted successfully"),
+        (status = 403, description = "User is not authorized to delete status pages"),
+        (status = 404, description = "Status page not found"),
+        (status = 500, description = "Technical failure occured while deleting the status page")
+    )
+)]
+async fn delete_status_page_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(status_page_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match status_pages::delete_status_page(
+        &auth_context,
+        &app_state.adapters.status_page_repository,
+        status_page_id,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(DeleteStatusPageError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(DeleteStatusPageError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(DeleteStatusPageError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while deleting a status page");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Get the current status of a public status page
+///
+/// This endpoint does not require to be authenticated. Private status pages are not found.
+#[utoipa::path(
+    get,
+    path = "/public/status-pages/:slug",
+    responses(
+        (status = 200, description = "Status page fetched successfully", body = PublicStatusPage),
+        (status = 404, description = "Status page not found"),
+        (status = 500, description = "Technical failure occured while fetching the status page")
+    )
+)]
+async fn get_public_status_page_handler(
+    State(app_state): ExtractAppState,
+    Path(slug): Path<String>,
+) -> impl IntoResponse {
+    match status_pages::get_public_status_page(
+        &app_state.adapters.status_page_repository,
+        &app_state.adapters.http_monitors_repository,
+        &app_state.adapters.task_repository,
+        &app_state.adapters.incident_repository,
+        &slug,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(GetPublicStatusPageError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(GetPublicStatusPageError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while getting a public status page");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
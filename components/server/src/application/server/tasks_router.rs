@@ -6,6 +6,7 @@ use crate::{
     },
 };
 use axum::{
+    body::Bytes,
     extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
@@ -19,18 +20,41 @@ use tracing::warn;
 pub(crate) fn tasks_router() -> Router<ApplicationState> {
     Router::new()
         .route("/", get(list_tasks_handler).post(create_task_handler))
+        .route("/schedule-preview", post(preview_task_schedule_handler))
+        .route("/filterable-metadata", get(get_filterable_task_metadata_handler))
         .nest(
             "/:task_id",
             Router::new()
-                .route("/", get(get_task_handler))
+                .route(
+                    "/",
+                    get(get_task_handler)
+                        .patch(update_task_handler)
+                        .delete(delete_task_handler),
+                )
+                .route("/pause", post(pause_task_handler))
+                .route("/resume", post(resume_task_handler))
                 .route("/start", post(start_task_handler))
                 .route("/finish", post(finish_task_handler))
                 .route("/heartbeat", post(send_task_heartbeat_handler))
+                .route("/ping-token", get(get_task_ping_token_handler))
+                .route("/ping-token/rotate", post(rotate_task_ping_token_handler))
                 .route("/runs/:started_at", get(get_task_run_handler))
+                .route("/runs/:started_at/logs", post(append_task_run_logs_handler))
+                .route("/runs/:started_at/events", get(list_task_run_events_handler))
                 .route("/runs", get(list_task_runs_handler)),
         )
 }
 
+/// The unauthenticated ping URLs of the tasks, authenticated by the ping token of each task
+pub(crate) fn task_ping_router() -> Router<ApplicationState> {
+    Router::new()
+        .route("/:token", get(ping_task_handler).post(ping_task_handler))
+        .route(
+            "/:token/:signal",
+            get(ping_task_signal_handler).post(ping_task_signal_handler),
+        )
+}
+
 /// List all tasks for the current organization
 #[utoipa::path(
     get,
@@ -56,6 +80,33 @@ async fn list_tasks_handler(
     }
 }
 
+/// Get the filterable metadata of the tasks
+///
+/// Returns the metadata keys of the tasks of the current organization, along with their distinct values,
+/// which can be used to filter the list of tasks.
+#[utoipa::path(
+    get,
+    path = "/tasks/filterable-metadata",
+    responses(
+        (status = 200, body = FilterableMetadata),
+        (status = 403, description = "User is not authorized to list tasks"),
+        (status = 500, description = "Technical failure occured while getting the filterable metadata of tasks")
+    )
+)]
+async fn get_filterable_task_metadata_handler(
+    State(app_state): ExtractAppState,
+    auth_context: AuthContext,
+) -> impl IntoResponse {
+    match get_filterable_task_metadata(&auth_context, &app_state.adapters.task_repository).await {
+        Ok(response) => Json(response).into_response(),
+        Err(GetFilterableTaskMetadataError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(GetFilterableTaskMetadataError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while getting filterable task metadata");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
 /// Create a new task
 ///
 /// A task is an external process that can be excuted manually or on a schedule, and needs to be monitored.
@@ -82,6 +133,8 @@ async fn create_task_handler(
         Err(CreateTaskError::InvalidCronSchedule { details }) => {
             (StatusCode::BAD_REQUEST, format!("Invalid cron schedule: {details}")).into_response()
         }
+        Err(CreateTaskError::InvalidTimeZone { details }) => (StatusCode::BAD_REQUEST, details).into_response(),
+        Err(CreateTaskError::InvalidUpstreamTasks { details }) => (StatusCode::BAD_REQUEST, details).into_response(),
         Err(CreateTaskError::TaskAlreadyExists(task_id)) => {
             (StatusCode::CONFLICT, format!("Task already exists: {task_id}")).into_response()
         }
@@ -92,6 +145,39 @@ async fn create_task_handler(
     }
 }
 
+/// Preview a task schedule
+///
+/// Returns a human-readable description of a cron schedule, and its next runs with the dates
+/// at which a task with this schedule and these windows would be marked as late and absent.
+#[utoipa::path(
+    post,
+    path = "/tasks/schedule-preview",
+    request_body = PreviewTaskScheduleCommand,
+    responses(
+        (status = 200, body = TaskSchedulePreview),
+        (status = 400, description = "Invalid cron schedule or time zone"),
+        (status = 403, description = "User is not authorized to preview task schedules"),
+        (status = 500, description = "Technical failure occured while previewing a task schedule")
+    )
+)]
+async fn preview_task_schedule_handler(
+    auth_context: AuthContext,
+    Json(command): Json<PreviewTaskScheduleCommand>,
+) -> impl IntoResponse {
+    match preview_task_schedule_use_case(&auth_context, command).await {
+        Ok(preview) => Json(preview).into_response(),
+        Err(PreviewTaskScheduleError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(PreviewTaskScheduleError::InvalidCronSchedule { details }) => {
+            (StatusCode::BAD_REQUEST, format!("Invalid cron schedule: {details}")).into_response()
+        }
+        Err(PreviewTaskScheduleError::InvalidTimeZone { details }) => (StatusCode::BAD_REQUEST, details).into_response(),
+        Err(PreviewTaskScheduleError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while previewing a task schedule");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
 /// Get a task by id
 #[utoipa::path(
     get,
@@ -120,6 +206,175 @@ async fn get_task_handler(
     }
 }
 
+/// Update a task
+///
+/// Only the settings present in the request are changed. When the cron schedule changes,
+/// the next due date of the task is recalculated.
+#[utoipa::path(
+    patch,
+    path = "/tasks/:task_id",
+    request_body = UpdateTaskCommand,
+    responses(
+        (status = 200, description = "Task updated successfully", body = Task),
+        (status = 400, description = "Invalid request"),
+        (status = 403, description = "User is not authorized to update this task"),
+        (status = 404, description = "Task not found"),
+        (status = 500, description = "Technical failure occured while updating a task")
+    )
+)]
+async fn update_task_handler(
+    State(app_state): ExtractAppState,
+    auth_context: AuthContext,
+    Path(task_id): Path<TaskId>,
+    Json(command): Json<UpdateTaskCommand>,
+) -> impl IntoResponse {
+    match update_task_use_case(
+        &auth_context,
+        &app_state.adapters.task_repository,
+        &app_state.adapters.task_run_repository,
+        &app_state.adapters.incident_repository,
+        &app_state.adapters.incident_event_repository,
+        &app_state.adapters.incident_notification_repository,
+        task_id,
+        command,
+    )
+    .await
+    {
+        Ok(task) => Json(task).into_response(),
+        Err(UpdateTaskError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(UpdateTaskError::NotFound) => (StatusCode::NOT_FOUND, "Task not found").into_response(),
+        Err(UpdateTaskError::InvalidCronSchedule { details }) => {
+            (StatusCode::BAD_REQUEST, format!("Invalid cron schedule: {details}")).into_response()
+        }
+        Err(UpdateTaskError::InvalidTimeZone { details }) => (StatusCode::BAD_REQUEST, details).into_response(),
+        Err(UpdateTaskError::InvalidUpstreamTasks { details }) => (StatusCode::BAD_REQUEST, details).into_response(),
+        Err(UpdateTaskError::InvalidTask(details)) => (StatusCode::BAD_REQUEST, details).into_response(),
+        Err(UpdateTaskError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while updating a task");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Delete a task
+///
+/// The runs of the task are deleted as well, and its ongoing incident is resolved.
+#[utoipa::path(
+    delete,
+    path = "/tasks/:task_id",
+    responses(
+        (status = 204, description = "Task deleted successfully"),
+        (status = 403, description = "User is not authorized to delete this task"),
+        (status = 404, description = "Task not found"),
+        (status = 500, description = "Technical failure occured while deleting a task")
+    )
+)]
+async fn delete_task_handler(
+    State(app_state): ExtractAppState,
+    auth_context: AuthContext,
+    Path(task_id): Path<TaskId>,
+) -> impl IntoResponse {
+    match delete_task_use_case(
+        &auth_context,
+        &app_state.adapters.task_repository,
+        &app_state.adapters.incident_repository,
+        &app_state.adapters.incident_event_repository,
+        &app_state.adapters.incident_notification_repository,
+        task_id,
+    )
+    .await
+    {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(DeleteTaskError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(DeleteTaskError::NotFound) => (StatusCode::NOT_FOUND, "Task not found").into_response(),
+        Err(DeleteTaskError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while deleting a task");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Pause a task
+///
+/// A paused task is not expected to run: it never becomes due, late or absent, and its runs cannot be started
+/// until it is resumed. Running tasks cannot be paused.
+#[utoipa::path(
+    post,
+    path = "/tasks/:task_id/pause",
+    responses(
+        (status = 200, description = "Task paused successfully", body = Task),
+        (status = 403, description = "User is not authorized to pause this task"),
+        (status = 404, description = "Task not found"),
+        (status = 409, description = "Task is running or already paused"),
+        (status = 500, description = "Technical failure occured while pausing a task")
+    )
+)]
+async fn pause_task_handler(
+    State(app_state): ExtractAppState,
+    auth_context: AuthContext,
+    Path(task_id): Path<TaskId>,
+) -> impl IntoResponse {
+    match pause_task_use_case(
+        &auth_context,
+        &app_state.adapters.task_repository,
+        &app_state.adapters.task_run_repository,
+        &app_state.adapters.incident_repository,
+        &app_state.adapters.incident_event_repository,
+        &app_state.adapters.incident_notification_repository,
+        task_id,
+    )
+    .await
+    {
+        Ok(task) => Json(task).into_response(),
+        Err(PauseTaskError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(PauseTaskError::NotFound) => (StatusCode::NOT_FOUND, "Task not found").into_response(),
+        Err(PauseTaskError::TaskIsRunning) => (StatusCode::CONFLICT, "A running task cannot be paused").into_response(),
+        Err(PauseTaskError::TaskIsPaused) => (StatusCode::CONFLICT, "Task is already paused").into_response(),
+        Err(PauseTaskError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while pausing a task");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Resume a paused task
+///
+/// The task becomes healthy, and a scheduled task is next expected at the first due date of its schedule.
+#[utoipa::path(
+    post,
+    path = "/tasks/:task_id/resume",
+    responses(
+        (status = 200, description = "Task resumed successfully", body = Task),
+        (status = 403, description = "User is not authorized to resume this task"),
+        (status = 404, description = "Task not found"),
+        (status = 409, description = "Task is not paused"),
+        (status = 500, description = "Technical failure occured while resuming a task")
+    )
+)]
+async fn resume_task_handler(
+    State(app_state): ExtractAppState,
+    auth_context: AuthContext,
+    Path(task_id): Path<TaskId>,
+) -> impl IntoResponse {
+    match resume_task_use_case(
+        &auth_context,
+        &app_state.adapters.task_repository,
+        &app_state.adapters.task_run_repository,
+        task_id,
+    )
+    .await
+    {
+        Ok(task) => Json(task).into_response(),
+        Err(ResumeTaskError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(ResumeTaskError::NotFound) => (StatusCode::NOT_FOUND, "Task not found").into_response(),
+        Err(ResumeTaskError::TaskIsNotPaused) => (StatusCode::CONFLICT, "Task is not paused").into_response(),
+        Err(ResumeTaskError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while resuming a task");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
 /// List all runs for a task
 ///
 /// This endpoint can be used to get a paginated list of task runs for a task.
@@ -159,10 +414,11 @@ async fn list_task_runs_handler(
         content_type = "application/json"
     ),
     responses(
-        (status = 201, description = "Task run started successfully"),
+        (status = 201, description = "Task run started successfully", body = StartTaskResponse),
         (status = 403, description = "User is not authorized to start a task"),
         (status = 404, description = "Task not found"),
-        (status = 409, description = "Task already running"),
+        (status = 400, description = "Invalid run id, or invalid upstream tasks of the new task"),
+        (status = 409, description = "Task already running, or a run with the same id is already running"),
         (status = 500, description = "Technical failure occured while starting a task")
     )
 )]
@@ -173,10 +429,14 @@ async fn start_task_handler(
     Json(command): Json<Option<StartTaskCommand>>,
 ) -> impl IntoResponse {
     match start_task_use_case(&auth_context, &app_state.adapters.task_repository, &app_state.adapters.task_run_repository, task_id, command).await {
-        Ok(_) => StatusCode::CREATED.into_response(),
+        Ok(response) => (StatusCode::CREATED, Json(response)).into_response(),
         Err(StartTaskError::Forbidden) => (StatusCode::FORBIDDEN, "User is not allowed to start this task").into_response(),
         Err(StartTaskError::TaskNotFound) => (StatusCode::NOT_FOUND, "Task not found").into_response(),
         Err(StartTaskError::TaskAlreadyStarted) => (StatusCode::CONFLICT, "Task already started").into_response(),
+        Err(StartTaskError::TaskRunAlreadyStarted) => (StatusCode::CONFLICT, "A run with this id is already running").into_response(),
+        Err(e @ StartTaskError::InvalidRunId { .. }) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(StartTaskError::InvalidUpstreamTasks { details }) => (StatusCode::BAD_REQUEST, details).into_response(),
+        Err(StartTaskError::TaskIsPaused) => (StatusCode::CONFLICT, "Task is paused").into_response(),
         Err(StartTaskError::TechnicalFailure(e)) => {
             warn!(error = ?e, "Technical failure occured while starting a task");
             StatusCode::INTERNAL_SERVER_ERROR.into_response()
@@ -186,14 +446,16 @@ async fn start_task_handler(
 
 /// Send a heartbeat for a running task, to indicate that it is still running
 /// Without a regular heartbeat, a running task will eventually be considered failed and retried.
+/// Tasks with concurrent runs identify the run with the `runId` query parameter.
 #[utoipa::path(
     post,
     path = "/tasks/:task_id/heartbeat",
     responses(
         (status = 200, description = "Heartbeat sent successfully"),
         (status = 403, description = "User is not authorized to send a heartbeat for this task"),
-        (status = 404, description = "Task not found"),
+        (status = 404, description = "Task or task run not found"),
         (status = 400, description = "Task is not running"),
+        (status = 409, description = "The task run exceeded the maximum duration of the task and is no longer running"),
         (status = 500, description = "Technical failure occured while sending a heartbeat")
     )
 )]
@@ -201,12 +463,15 @@ async fn send_task_heartbeat_handler(
     State(app_state): ExtractAppState,
     auth_context: AuthContext,
     Path(task_id): Path<TaskId>,
+    Query(params): Query<SendTaskHeartbeatParams>,
 ) -> impl IntoResponse {
-    match send_task_heartbeat_use_case(&auth_context, &app_state.adapters.task_repository, &app_state.adapters.task_run_repository, task_id).await {
+    match send_task_heartbeat_use_case(&auth_context, &app_state.adapters.task_repository, &app_state.adapters.task_run_repository, task_id, params).await {
         Ok(_) => StatusCode::OK.into_response(),
         Err(SendTaskHeartbeatError::Forbidden) => (StatusCode::FORBIDDEN, "User is not allowed to send a heartbeat for this task").into_response(),
         Err(SendTaskHeartbeatError::TaskNotFound) => (StatusCode::NOT_FOUND, "Task not found").into_response(),
+        Err(SendTaskHeartbeatError::TaskRunNotFound) => (StatusCode::NOT_FOUND, "Task run not found").into_response(),
         Err(SendTaskHeartbeatError::TaskIsNotRunning) => (StatusCode::BAD_REQUEST, "Task is not running").into_response(),
+        Err(SendTaskHeartbeatError::TaskRunTimedOut) => (StatusCode::CONFLICT, "Task run exceeded the maximum duration of the task").into_response(),
         Err(SendTaskHeartbeatError::TechnicalFailure(e)) => {
             warn!(error = ?e, "Technical failure occured while sending a heartbeat");
             StatusCode::INTERNAL_SERVER_ERROR.into_response()
@@ -223,7 +488,7 @@ async fn send_task_heartbeat_handler(
     responses(
         (status = 200, description = "Task finished successfully"),
         (status = 403, description = "User is not authorized to finish a task"),
-        (status = 404, description = "Task not found"),
+        (status = 404, description = "Task or task run not found"),
     )
 )]
 async fn finish_task_handler(
@@ -232,10 +497,22 @@ async fn finish_task_handler(
     Path(task_id): Path<TaskId>,
     Json(command): Json<FinishTaskCommand>,
 ) -> impl IntoResponse {
-    match finish_task_use_case(&auth_context, &app_state.adapters.task_repository, &app_state.adapters.task_run_repository, task_id, command).await {
+    match finish_task_use_case(
+        &auth_context,
+        &app_state.adapters.task_repository,
+        &app_state.adapters.task_run_repository,
+        &app_state.adapters.task_run_event_repository,
+        &app_state.adapters.incident_repository,
+        &app_state.adapters.incident_event_repository,
+        &app_state.adapters.incident_notification_repository,
+        &app_state.adapters.maintenance_window_repository,
+        task_id,
+        command,
+    ).await {
         Ok(_) => StatusCode::OK.into_response(),
         Err(FinishTaskError::Forbidden) => (StatusCode::FORBIDDEN, "User is not allowed to finish this task").into_response(),
         Err(FinishTaskError::NotFound) => (StatusCode::NOT_FOUND, "Task not found").into_response(),
+        Err(FinishTaskError::TaskRunNotFound) => (StatusCode::NOT_FOUND, "Task run not found").into_response(),
         Err(FinishTaskError::TaskIsNotRunning) => (StatusCode::BAD_REQUEST, "Task is not running").into_response(),
         Err(FinishTaskError::TechnicalFailure(e)) => {
             warn!(error = ?e, "Technical failure occured while finishing a task");
@@ -244,7 +521,9 @@ async fn finish_task_handler(
     }
 }
 
-/// Get a single task run
+/// Get a single task run by its start date
+///
+/// Runs of the task that started at the same time are told apart by the `runId` query parameter
 #[utoipa::path(
     get,
     path = "/tasks/:task_id/runs/:started_at",
@@ -258,8 +537,9 @@ async fn get_task_run_handler(
     State(app_state): ExtractAppState,
     auth_context: AuthContext,
     Path((task_id, started_at)): Path<(TaskId, DateTime<Utc>)>,
+    Query(params): Query<GetTaskRunParams>,
 ) -> impl IntoResponse {
-    match get_task_run(&auth_context, &app_state.adapters.task_run_repository, task_id, started_at).await {
+    match get_task_run(&auth_context, &app_state.adapters.task_run_repository, task_id, started_at, params.run_id).await {
         Ok(response) => Json(response).into_response(),
         Err(GetTaskRunError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
         Err(GetTaskRunError::NotFound) => StatusCode::NOT_FOUND.into_response(),
@@ -269,3 +549,222 @@ async fn get_task_run_handler(
         }
     }
 }
+
+/// Append lines of output to a task run
+///
+/// The lines are stored as an event of the task run. When the lines are too large to be stored in the event,
+/// they are stored in a file that can be downloaded with `/files/:file_id`, and the event only keeps the last lines.
+/// The last lines of output of a failed run are included in the notifications of its incident.
+/// Runs of the task that started at the same time are told apart by the `runId` query parameter
+#[utoipa::path(
+    post,
+    path = "/tasks/:task_id/runs/:started_at/logs",
+    request_body = AppendTaskRunLogsCommand,
+    responses(
+        (status = 201, description = "Logs appended successfully"),
+        (status = 400, description = "Invalid logs"),
+        (status = 403, description = "User is not authorized to write task runs"),
+        (status = 404, description = "Task run not found"),
+        (status = 500, description = "Technical failure occured while appending logs")
+    )
+)]
+async fn append_task_run_logs_handler(
+    State(app_state): ExtractAppState,
+    auth_context: AuthContext,
+    Path((task_id, started_at)): Path<(TaskId, DateTime<Utc>)>,
+    Query(params): Query<AppendTaskRunLogsParams>,
+    Json(command): Json<AppendTaskRunLogsCommand>,
+) -> impl IntoResponse {
+    match append_task_run_logs(
+        &auth_context,
+        &app_state.adapters.task_run_repository,
+        &app_state.adapters.task_run_event_repository,
+        &app_state.adapters.file_storage,
+        task_id,
+        started_at,
+        params.run_id,
+        command,
+    ).await {
+        Ok(()) => StatusCode::CREATED.into_response(),
+        Err(AppendTaskRunLogsError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(AppendTaskRunLogsError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(e @ AppendTaskRunLogsError::InvalidLogs(_)) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(AppendTaskRunLogsError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while appending task run logs");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// List the events of a task run, such as its logs, oldest first
+///
+/// Runs of the task that started at the same time are told apart by the `runId` query parameter
+#[utoipa::path(
+    get,
+    path = "/tasks/:task_id/runs/:started_at/events",
+    responses(
+        (status = 200, body = ListTaskRunEventsResponse),
+        (status = 403, description = "User is not authorized to read task runs"),
+        (status = 404, description = "Task run not found"),
+        (status = 500, description = "Technical failure occured while listing task run events")
+    )
+)]
+async fn list_task_run_events_handler(
+    State(app_state): ExtractAppState,
+    auth_context: AuthContext,
+    Path((task_id, started_at)): Path<(TaskId, DateTime<Utc>)>,
+    Query(params): Query<ListTaskRunEventsParams>,
+) -> impl IntoResponse {
+    match list_task_run_events(
+        &auth_context,
+        &app_state.adapters.task_run_repository,
+        &app_state.adapters.task_run_event_repository,
+        task_id,
+        started_at,
+        params,
+    ).await {
+        Ok(response) => Json(response).into_response(),
+        Err(ListTaskRunEventsError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(ListTaskRunEventsError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(ListTaskRunEventsError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while listing task run events");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Get the ping token of a task
+///
+/// The token is part of the ping URLs of the task, which report its runs without an API token.
+#[utoipa::path(
+    get,
+    path = "/tasks/:task_id/ping-token",
+    responses(
+        (status = 200, body = TaskPingTokenResponse),
+        (status = 403, description = "User is not authorized to report task runs"),
+        (status = 404, description = "Task not found"),
+        (status = 500, description = "Technical failure occured while getting the ping token")
+    )
+)]
+async fn get_task_ping_token_handler(
+    State(app_state): ExtractAppState,
+    auth_context: AuthContext,
+    Path(task_id): Path<TaskId>,
+) -> impl IntoResponse {
+    match get_task_ping_token(&auth_context, &app_state.adapters.task_repository, task_id).await {
+        Ok(response) => Json(response).into_response(),
+        Err(GetTaskPingTokenError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(GetTaskPingTokenError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(GetTaskPingTokenError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while getting a task ping token");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Rotate the ping token of a task
+///
+/// The ping URLs with the previous token stop working immediately.
+#[utoipa::path(
+    post,
+    path = "/tasks/:task_id/ping-token/rotate",
+    responses(
+        (status = 200, body = TaskPingTokenResponse),
+        (status = 403, description = "User is not authorized to edit tasks"),
+        (status = 404, description = "Task not found"),
+        (status = 500, description = "Technical failure occured while rotating the ping token")
+    )
+)]
+async fn rotate_task_ping_token_handler(
+    State(app_state): ExtractAppState,
+    auth_context: AuthContext,
+    Path(task_id): Path<TaskId>,
+) -> impl IntoResponse {
+    match rotate_task_ping_token(&auth_context, &app_state.adapters.task_repository, task_id).await {
+        Ok(response) => Json(response).into_response(),
+        Err(RotateTaskPingTokenError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(RotateTaskPingTokenError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(RotateTaskPingTokenError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while rotating a task ping token");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Report the success of a task run with the ping token of the task
+///
+/// Accepts both GET and POST requests, so that it can be called with `curl` or `wget` from any cron job.
+/// A run that was not started with `/ping/:token/start` is started and finished at once.
+#[utoipa::path(
+    post,
+    path = "/ping/:token",
+    responses(
+        (status = 200, description = "Ping received successfully"),
+        (status = 404, description = "Unknown ping token"),
+        (status = 500, description = "Technical failure occured while receiving a ping")
+    )
+)]
+async fn ping_task_handler(
+    State(app_state): ExtractAppState,
+    Path(token): Path<String>,
+    body: Bytes,
+) -> impl IntoResponse {
+    handle_task_ping(&app_state, &token, TaskPing::Success, body).await
+}
+
+/// Report the start, the failure or the exit code of a task run with the ping token of the task
+///
+/// - `/ping/:token/start` starts a run, aborting the previous run if it is still running
+/// - `/ping/:token/fail` fails the run
+/// - `/ping/:token/:exit_code` finishes the run, with a success if the exit code is 0 and a failure otherwise
+///
+/// The request body, if any, is recorded as the error message of a failed run.
+/// Accepts both GET and POST requests.
+#[utoipa::path(
+    post,
+    path = "/ping/:token/:signal",
+    responses(
+        (status = 200, description = "Ping received successfully"),
+        (status = 404, description = "Unknown ping token or signal"),
+        (status = 500, description = "Technical failure occured while receiving a ping")
+    )
+)]
+async fn ping_task_signal_handler(
+    State(app_state): ExtractAppState,
+    Path((token, signal)): Path<(String, String)>,
+    body: Bytes,
+) -> impl IntoResponse {
+    let Some(ping) = TaskPing::from_path_segment(&signal) else {
+        return (StatusCode::NOT_FOUND, "Unknown ping signal").into_response();
+    };
+    handle_task_ping(&app_state, &token, ping, body).await
+}
+
+async fn handle_task_ping(
+    app_state: &ApplicationState,
+    token: &str,
+    ping: TaskPing,
+    body: Bytes,
+) -> axum::response::Response {
+    let body = (!body.is_empty()).then(|| String::from_utf8_lossy(&body).into_owned());
+    match ping_task(
+        &app_state.adapters.task_repository,
+        &app_state.adapters.task_run_repository,
+        &app_state.adapters.task_run_event_repository,
+        &app_state.adapters.incident_repository,
+        &app_state.adapters.incident_event_repository,
+        &app_state.adapters.incident_notification_repository,
+        &app_state.adapters.maintenance_window_repository,
+        token,
+        ping,
+        body,
+    ).await {
+        Ok(()) => (StatusCode::OK, "OK").into_response(),
+        Err(PingTaskError::UnknownToken) => (StatusCode::NOT_FOUND, "Unknown ping token").into_response(),
+        Err(PingTaskError::TaskIsPaused) => (StatusCode::CONFLICT, "Task is paused").into_response(),
+        Err(PingTaskError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while receiving a task ping");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
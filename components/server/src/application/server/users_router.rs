@@ -15,6 +15,7 @@ use crate::{
     },
 };
 
+use super::notification_preferences_router::notification_preferences_router;
 use super::user_devices_router::user_devices_router;
 
 pub fn users_router() -> Router<ApplicationState> {
@@ -26,6 +27,10 @@ pub fn users_router() -> Router<ApplicationState> {
             "/me",
             Router::new()
                 .nest("/devices", user_devices_router())
+                .nest(
+                    "/notification-preferences",
+                    notification_preferences_router(),
+                )
                 .route("/send-phone-otp", post(send_phone_otp_handler))
                 .route("/verify-phone-otp", post(verify_phone_otp_handler))
                 .route("/", get(get_profile_handler).put(update_profile_handler)),
@@ -0,0 +1,244 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use axum_extra::extract::Query;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    application::application_state::{ApplicationState, ExtractAppState},
+    domain::{
+        entities::authorization::AuthContext,
+        use_cases::webhooks::{
+            self, CreateWebhookEndpointCommand, CreateWebhookEndpointError,
+            DeleteWebhookEndpointError, GetWebhookEndpointError, ListWebhookDeliveriesError,
+            ListWebhookDeliveriesParams, ListWebhookEndpointsError, UpdateWebhookEndpointCommand,
+            UpdateWebhookEndpointError,
+        },
+    },
+};
+
+pub fn webhooks_router() -> Router<ApplicationState> {
+    Router::new()
+        .route(
+            "/",
+            get(list_webhook_endpoints_handler).post(create_webhook_endpoint_handler),
+        )
+        .route(
+            "/:webhook_id",
+            get(get_webhook_endpoint_handler)
+                .put(update_webhook_endpoint_handler)
+                .delete(delete_webhook_endpoint_handler),
+        )
+        .route("/:webhook_id/deliveries", get(list_webhook_deliveries_handler))
+}
+
+/// List the webhook endpoints of the organization
+#[utoipa::path(
+    get,
+    path = "/webhooks",
+    responses(
+        (status = 200, description = "Webhook endpoints fetched successfully", body = ListWebhookEndpointsResponse),
+        (status = 403, description = "User is not authorized to list webhook endpoints"),
+        (status = 500, description = "Technical failure occured while listing webhook endpoints")
+    )
+)]
+async fn list_webhook_endpoints_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+) -> impl IntoResponse {
+    match webhooks::list_webhook_endpoints(
+        &auth_context,
+        &app_state.adapters.webhook_endpoint_repository,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(ListWebhookEndpointsError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(ListWebhookEndpointsError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while listing webhook endpoints");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Create a webhook endpoint
+///
+/// The response contains the secret used to sign the payloads sent to the endpoint.
+/// Every payload is signed with HMAC-SHA256, and the signature is sent in the `X-DutyDuck-Signature` header
+/// as `sha256=<hex encoded signature>`. The secret cannot be retrieved afterwards.
+#[utoipa::path(
+    post,
+    path = "/webhooks",
+    request_body = CreateWebhookEndpointCommand,
+    responses(
+        (status = 201, description = "Webhook endpoint created successfully", body = CreateWebhookEndpointResponse),
+        (status = 400, description = "Invalid webhook endpoint"),
+        (status = 403, description = "User is not authorized to create webhook endpoints"),
+        (status = 500, description = "Technical failure occured while creating the webhook endpoint")
+    )
+)]
+async fn create_webhook_endpoint_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Json(command): Json<CreateWebhookEndpointCommand>,
+) -> impl IntoResponse {
+    match webhooks::create_webhook_endpoint(
+        &auth_context,
+        &app_state.adapters.webhook_endpoint_repository,
+        command,
+    )
+    .await
+    {
+        Ok(res) => (StatusCode::CREATED, Json(res)).into_response(),
+        Err(CreateWebhookEndpointError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(CreateWebhookEndpointError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while creating a webhook endpoint");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+/// Get a webhook endpoint
+#[utoipa::path(
+    get,
+    path = "/webhooks/:webhook_id",
+    responses(
+        (status = 200, description = "Webhook endpoint fetched successfully", body = WebhookEndpoint),
+        (status = 403, description = "User is not authorized to read webhook endpoints"),
+        (status = 404, description = "Webhook endpoint not found"),
+        (status = 500, description = "Technical failure occured while fetching the webhook endpoint")
+    )
+)]
+async fn get_webhook_endpoint_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(webhook_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match webhooks::get_webhook_endpoint(
+        &auth_context,
+        &app_state.adapters.webhook_endpoint_repository,
+        webhook_id,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(GetWebhookEndpointError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(GetWebhookEndpointError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(GetWebhookEndpointError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while getting a webhook endpoint");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Update the URL and the filters of a webhook endpoint
+#[utoipa::path(
+    put,
+    path = "/webhooks/:webhook_id",
+    request_body = UpdateWebhookEndpointCommand,
+    responses(
+        (status = 200, description = "Webhook endpoint updated successfully"),
+        (status = 400, description = "Invalid webhook endpoint"),
+        (status = 403, description = "User is not authorized to update webhook endpoints"),
+        (status = 404, description = "Webhook endpoint not found"),
+        (status = 500, description = "Technical failure occured while updating the webhook endpoint")
+    )
+)]
+async fn update_webhook_endpoint_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(webhook_id): Path<Uuid>,
+    Json(command): Json<UpdateWebhookEndpointCommand>,
+) -> impl IntoResponse {
+    match webhooks::update_webhook_endpoint(
+        &auth_context,
+        &app_state.adapters.webhook_endpoint_repository,
+        webhook_id,
+        command,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(UpdateWebhookEndpointError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(UpdateWebhookEndpointError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(UpdateWebhookEndpointError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while updating a webhook endpoint");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+/// Delete a webhook endpoint and its delivery log
+#[utoipa::path(
+    delete,
+    path = "/webhooks/:webhook_id",
+    responses(
+        (status = 200, description = "Webhook endpoint deleted successfully"),
+        (status = 403, description = "User is not authorized to delete webhook endpoints"),
+        (status = 404, description = "Webhook endpoint not found"),
+        (status = 500, description = "Technical failure occured while deleting the webhook endpoint")
+    )
+)]
+async fn delete_webhook_endpoint_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(webhook_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match webhooks::delete_webhook_endpoint(
+        &auth_context,
+        &app_state.adapters.webhook_endpoint_repository,
+        webhook_id,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(DeleteWebhookEndpointError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(DeleteWebhookEndpointError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(DeleteWebhookEndpointError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while deleting a webhook endpoint");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// List the deliveries of a webhook endpoint
+///
+/// Deliveries are listed from the most recent to the oldest, with the outcome of their last attempt.
+#[utoipa::path(
+    get,
+    path = "/webhooks/:webhook_id/deliveries",
+    responses(
+        (status = 200, description = "Webhook deliveries fetched successfully", body = ListWebhookDeliveriesResponse),
+        (status = 403, description = "User is not authorized to list webhook deliveries"),
+        (status = 500, description = "Technical failure occured while listing webhook deliveries")
+    )
+)]
+async fn list_webhook_deliveries_handler(
+    auth_context: AuthContext,
+    State(app_state): ExtractAppState,
+    Path(webhook_id): Path<Uuid>,
+    Query(params): Query<ListWebhookDeliveriesParams>,
+) -> impl IntoResponse {
+    match webhooks::list_webhook_deliveries(
+        &auth_context,
+        &app_state.adapters.webhook_delivery_repository,
+        webhook_id,
+        params,
+    )
+    .await
+    {
+        Ok(res) => Json(res).into_response(),
+        Err(ListWebhookDeliveriesError::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(ListWebhookDeliveriesError::TechnicalFailure(e)) => {
+            warn!(error = ?e, "Technical failure occured while listing webhook deliveries");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::{Builder, Uuid};
+use veil::Redact;
+
+use super::incident::IncidentPriority;
+
+/// The maximum number of alerts that can be received in a single payload
+pub const MAXIMUM_ALERTS_PER_PAYLOAD: usize = 100;
+
+/// The maximum length of the name of an alert integration
+pub const MAXIMUM_ALERT_INTEGRATION_NAME_LENGTH: usize = 100;
+
+/// An inbound integration that opens and resolves incidents from the alerts sent by an external system,
+/// like Prometheus Alertmanager or Grafana
+#[derive(Redact, Clone, Serialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct AlertIntegration {
+    pub organization_id: Uuid,
+    pub id: Uuid,
+    pub name: String,
+    /// Sent by the external system as a bearer token. It is only returned when the integration is created
+    #[redact]
+    #[serde(skip_serializing)]
+    #[ts(skip)]
+    #[schema(write_only)]
+    pub secret: String,
+    /// The priority of the incidents whose alert has no known severity
+    pub default_priority: IncidentPriority,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl AlertIntegration {
+    /// Generates a random 256-bit secret, encoded as an hexadecimal string
+    pub fn generate_secret() -> String {
+        let mut rng = rand::thread_rng();
+        hex::encode((0..32).map(|_| rng.gen::<u8>()).collect::<Vec<_>>())
+    }
+
+    /// Whether a secret is the one of the integration.
+    /// Digests are compared rather than the secrets themselves, so that the comparison time does not reveal the secret
+    pub fn verify_secret(&self, secret: &str) -> bool {
+        Sha256::digest(self.secret.as_bytes()) == Sha256::digest(secret.as_bytes())
+    }
+
+    /// The ID of the incident source of an alert.
+    /// It is derived from the fingerprint of the alert, so that all the notifications of an alert
+    /// are deduplicated into the same incident
+    pub fn alert_source_id(&self, fingerprint: &str) -> Uuid {
+        let mut hasher = Sha256::new();
+        hasher.update(self.id.as_bytes());
+        hasher.update(fingerprint.as_bytes());
+        let digest = hasher.finalize();
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        Builder::from_custom_bytes(bytes).into_uuid()
+    }
+}
+
+pub fn validate_alert_integration_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("The name cannot be empty".to_string());
+    }
+    if name.chars().count() > MAXIMUM_ALERT_INTEGRATION_NAME_LENGTH {
+        return Err(format!(
+            "The name cannot be longer than {MAXIMUM_ALERT_INTEGRATION_NAME_LENGTH} characters"
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum ExternalAlertStatus {
+    Firing,
+    Resolved,
+}
+
+/// An alert received by an integration, whatever the format of the payload it was received in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalAlert {
+    /// Identifies the alert across its notifications
+    pub fingerprint: String,
+    pub status: ExternalAlertStatus,
+    pub title: String,
+    pub description: Option<String>,
+    pub labels: HashMap<String, String>,
+    /// The priority given by the payload, either explicitly or through a `severity` label
+    pub priority: Option<IncidentPriority>,
+    /// A link to the alert in the external system
+    pub source_url: Option<String>,
+}
+
+/// The payloads accepted by alert integrations
+#[derive(Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(untagged)]
+#[ts(export)]
+pub enum AlertPayload {
+    /// The webhook payloads of Alertmanager and Grafana alerting, which share the same format
+    Alertmanager(AlertmanagerPayload),
+    /// A single alert in the Duty Duck format
+    Native(NativeAlertPayload),
+}
+
+#[derive(Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct AlertmanagerPayload {
+    pub alerts: Vec<AlertmanagerAlert>,
+}
+
+#[derive(Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct AlertmanagerAlert {
+    pub status: ExternalAlertStatus,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
+    /// Sent by Alertmanager 0.22+ and Grafana. Computed from the labels otherwise, as Alertmanager does
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+    #[serde(default, rename = "generatorURL")]
+    pub generator_url: Option<String>,
+}
+
+#[derive(Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct NativeAlertPayload {
+    pub fingerprint: String,
+    pub status: ExternalAlertStatus,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+    #[serde(default)]
+    pub priority: Option<IncidentPriority>,
+    #[serde(default)]
+    pub source_url: Option<String>,
+}
+
+impl AlertPayload {
+    /// Normalizes the alerts of the payload
+    pub fn into_alerts(self) -> Result<Vec<ExternalAlert>, String> {
+        let alerts = match self {
+            Self::Alertmanager(payload) => payload
+                .alerts
+                .into_iter()
+                .map(ExternalAlert::from)
+                .collect::<Vec<_>>(),
+            Self::Native(payload) => vec![ExternalAlert::from(payload)],
+        };
+        if alerts.len() > MAXIMUM_ALERTS_PER_PAYLOAD {
+            return Err(format!(
+                "A payload cannot contain more than {MAXIMUM_ALERTS_PER_PAYLOAD} alerts"
+            ));
+        }
+        if alerts.iter().any(|alert| alert.fingerprint.trim().is_empty()) {
+            return Err("The fingerprint of an alert cannot be empty".to_string());
+        }
+        if alerts.iter().any(|alert| alert.title.trim().is_empty()) {
+            return Err("The title of an alert cannot be empty".to_string());
+        }
+        Ok(alerts)
+    }
+}
+
+impl From<AlertmanagerAlert> for ExternalAlert {
+    fn from(alert: AlertmanagerAlert) -> Self {
+        let fingerprint = alert
+            .fingerprint
+            .filter(|fingerprint| !fingerprint.is_empty())
+            .unwrap_or_else(|| labels_fingerprint(&alert.labels));
+        let title = alert
+            .annotations
+            .get("summary")
+            .or_else(|| alert.labels.get("alertname"))
+            .cloned()
+            .unwrap_or_else(|| fingerprint.clone());
+        let priority = alert
+            .labels
+            .get("severity")
+            .and_then(|severity| priority_from_severity(severity));
+        Self {
+            fingerprint,
+            status: alert.status,
+            title,
+            description: alert.annotations.get("description").cloned(),
+            labels: alert.labels,
+            priority,
+            source_url: alert.generator_url.filter(|url| !url.is_empty()),
+        }
+    }
+}
+
+impl From<NativeAlertPayload> for ExternalAlert {
+    fn from(payload: NativeAlertPayload) -> Self {
+        let priority = payload.priority.or_else(|| {
+            payload
+                .labels
+                .get("severity")
+                .and_then(|severity| priority_from_severity(severity))
+        });
+        Self {
+            fingerprint: payload.fingerprint,
+            status: payload.status,
+            title: payload.title,
+            description: payload.description,
+            labels: payload.labels,
+            priority,
+            source_url: payload.source_url,
+        }
+    }
+}
+
+/// Maps the usual values of the `severity` label of alerting rules to incident priorities
+pub fn priority_from_severity(severity: &str) -> Option<IncidentPriority> {
+    match severity.to_ascii_lowercase().as_str() {
+        "emergency" | "page" => Some(IncidentPriority::Emergency),
+        "critical" => Some(IncidentPriority::Critical),
+        "major" | "error" | "high" => Some(IncidentPriority::Major),
+        "minor" | "medium" => Some(IncidentPriority::Minor),
+        "warning" | "low" => Some(IncidentPriority::Warning),
+        "notice" | "info" => Some(IncidentPriority::Notice),
+        _ => None,
+    }
+}
+
+/// A fingerprint that only depends on the labels of an alert, regardless of their order
+fn labels_fingerprint(labels: &HashMap<String, String>) -> String {
+    let sorted_labels = labels.iter().collect::<BTreeMap<_, _>>();
+    let mut hasher = Sha256::new();
+    for (name, value) in sorted_labels {
+        hasher.update(name.as_bytes());
+        hasher.update([0xff]);
+        hasher.update(value.as_bytes());
+        hasher.update([0xff]);
+    }
+    hex::encode(&hasher.finalize()[..8])
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn integration() -> AlertIntegration {
+        AlertIntegration {
+            organization_id: Uuid::new_v4(),
+            id: Uuid::new_v4(),
+            name: "Prometheus".to_string(),
+            secret: AlertIntegration::generate_secret(),
+            default_priority: IncidentPriority::Major,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn parse_alertmanager_payload() {
+        let payload: AlertPayload = serde_json::from_value(json!({
+            "version": "4",
+            "groupKey": "{}:{alertname=\"HighLatency\"}",
+            "status": "firing",
+            "receiver": "duty-duck",
+            "alerts": [
+                {
+                    "status": "firing",
+                    "labels": {"alertname": "HighLatency", "severity": "critical", "service": "api"},
+                    "annotations": {"summary": "API latency is high", "description": "p99 > 2s"},
+                    "startsAt": "2024-12-21T10:00:00Z",
+                    "endsAt": "0001-01-01T00:00:00Z",
+                    "generatorURL": "http://prometheus/graph",
+                    "fingerprint": "c6a2b4e8d1f3a5b7"
+                },
+                {
+                    "status": "resolved",
+                    "labels": {"alertname": "DiskFull", "instance": "db-1"},
+                    "annotations": {}
+                }
+            ]
+        }))
+        .unwrap();
+
+        let alerts = payload.into_alerts().unwrap();
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[0].fingerprint, "c6a2b4e8d1f3a5b7");
+        assert_eq!(alerts[0].status, ExternalAlertStatus::Firing);
+        assert_eq!(alerts[0].title, "API latency is high");
+        assert_eq!(alerts[0].description.as_deref(), Some("p99 > 2s"));
+        assert_eq!(alerts[0].priority, Some(IncidentPriority::Critical));
+        assert_eq!(alerts[0].labels.get("service").map(String::as_str), Some("api"));
+        assert_eq!(alerts[0].source_url.as_deref(), Some("http://prometheus/graph"));
+
+        // Without a fingerprint, it is computed from the labels
+        assert_eq!(alerts[1].status, ExternalAlertStatus::Resolved);
+        assert_eq!(alerts[1].title, "DiskFull");
+        assert_eq!(alerts[1].priority, None);
+        assert_eq!(alerts[1].fingerprint, labels_fingerprint(&alerts[1].labels));
+    }
+
+    #[test]
+    fn parse_native_payload() {
+        let payload: AlertPayload = serde_json::from_value(json!({
+            "fingerprint": "backup-failed",
+            "status": "firing",
+            "title": "Nightly backup failed",
+            "labels": {"severity": "warning"},
+            "priority": "critical"
+        }))
+        .unwrap();
+        let alerts = payload.into_alerts().unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].title, "Nightly backup failed");
+        // an explicit priority takes precedence over the severity label
+        assert_eq!(alerts[0].priority, Some(IncidentPriority::Critical));
+
+        let payload: AlertPayload = serde_json::from_value(json!({
+            "fingerprint": " ",
+            "status": "resolved",
+            "title": "Nightly backup failed"
+        }))
+        .unwrap();
+        assert!(payload.into_alerts().is_err());
+
+        assert!(serde_json::from_value::<AlertPayload>(json!({"status": "firing"})).is_err());
+    }
+
+    #[test]
+    fn labels_fingerprint_does_not_depend_on_order() {
+        let a = HashMap::from([
+            ("alertname".to_string(), "DiskFull".to_string()),
+            ("instance".to_string(), "db-1".to_string()),
+        ]);
+        let b = HashMap::from([
+            ("instance".to_string(), "db-1".to_string()),
+            ("alertname".to_string(), "DiskFull".to_string()),
+        ]);
+        let c = HashMap::from([
+            ("alertname".to_string(), "DiskFull".to_string()),
+            ("instance".to_string(), "db-2".to_string()),
+        ]);
+        assert_eq!(labels_fingerprint(&a), labels_fingerprint(&b));
+        assert_ne!(labels_fingerprint(&a), labels_fingerprint(&c));
+    }
+
+    #[test]
+    fn alert_source_id_is_stable() {
+        let integration = integration();
+        assert_eq!(
+            integration.alert_source_id("abc"),
+            integration.alert_source_id("abc")
+        );
+        assert_ne!(
+            integration.alert_source_id("abc"),
+            integration.alert_source_id("abd")
+        );
+        assert!(integration.verify_secret(&integration.secret.clone()));
+        assert!(!integration.verify_secret("wrong"));
+    }
+}
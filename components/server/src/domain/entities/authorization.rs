@@ -71,6 +71,56 @@ impl AuthContext {
             Permission::ReadTaskRuns => self
                 .active_organization_roles
                 .contains(OrganizationUserRole::Reporter),
+            Permission::ReadEscalationPolicies => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Reporter),
+            Permission::WriteEscalationPolicies => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Editor),
+            Permission::ReadWebhooks => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Reporter),
+            Permission::WriteWebhooks => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Editor),
+            Permission::ReadStatusPages => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Reporter),
+            Permission::WriteStatusPages => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Editor),
+            Permission::ReadMaintenanceWindows => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Reporter),
+            Permission::WriteMaintenanceWindows => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Editor),
+            Permission::ReadOnCallSchedules => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Reporter),
+            Permission::WriteOnCallSchedules => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Editor),
+            Permission::ReadAlertIntegrations => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Reporter),
+            Permission::WriteAlertIntegrations => self
+                .active_organization_roles
+                .contains(OrganizationUserRole::Editor),
+        }
+    }
+
+    /// The context of a request to the ping URLs of a task.
+    /// The ping token only authenticates the task, so the context is restricted to reporting its runs,
+    /// and is not attached to any user
+    pub fn task_ping(organization_id: Uuid) -> Self {
+        Self {
+            active_organization_id: organization_id,
+            active_user_id: Uuid::nil(),
+            active_organization_roles: OrganizationRoleSet::from_roles(vec![
+                OrganizationUserRole::Editor,
+            ]),
+            restricted_to_scopes: vec![Permission::WriteTaskRuns],
         }
     }
 
@@ -132,6 +182,30 @@ custom_derive! {
         WriteTaskRuns = 15,
         /// Read task runs
         ReadTaskRuns = 16,
+        /// Read the escalation policy of the organization
+        ReadEscalationPolicies = 17,
+        /// Write the escalation policy of the organization
+        WriteEscalationPolicies = 18,
+        /// Read the webhook endpoints and their deliveries
+        ReadWebhooks = 19,
+        /// Write webhook endpoints
+        WriteWebhooks = 20,
+        /// Read the status pages of the organization
+        ReadStatusPages = 21,
+        /// Write status pages
+        WriteStatusPages = 22,
+        /// Read the maintenance windows of the organization
+        ReadMaintenanceWindows = 23,
+        /// Write maintenance windows
+        WriteMaintenanceWindows = 24,
+        /// Read the on-call schedules of the organization
+        ReadOnCallSchedules = 25,
+        /// Write on-call schedules and their overrides
+        WriteOnCallSchedules = 26,
+        /// Read the alert integrations of the organization
+        ReadAlertIntegrations = 27,
+        /// Write alert integrations
+        WriteAlertIntegrations = 28,
     }
 }
 
@@ -154,6 +228,18 @@ impl From<i16> for Permission {
             14 => Self::ReadTasks,
             15 => Self::WriteTaskRuns,
             16 => Self::ReadTaskRuns,
+            17 => Self::ReadEscalationPolicies,
+            18 => Self::WriteEscalationPolicies,
+            19 => Self::ReadWebhooks,
+            20 => Self::WriteWebhooks,
+            21 => Self::ReadStatusPages,
+            22 => Self::WriteStatusPages,
+            23 => Self::ReadMaintenanceWindows,
+            24 => Self::WriteMaintenanceWindows,
+            25 => Self::ReadOnCallSchedules,
+            26 => Self::WriteOnCallSchedules,
+            27 => Self::ReadAlertIntegrations,
+            28 => Self::WriteAlertIntegrations,
             _ => panic!("invalid Permission discriminant: {value}"),
         }
     }
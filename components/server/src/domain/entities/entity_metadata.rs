@@ -58,4 +58,16 @@ pub struct FilterableMetadata {
 #[ts(export)]
 pub struct MetadataFilter {
     pub items: HashMap<String, Vec<String>>,
+}
+
+impl MetadataFilter {
+    /// Whether some metadata matches this filter, following the same rules as the database queries
+    pub fn matches(&self, metadata: &EntityMetadata) -> bool {
+        self.items.iter().all(|(key, values)| {
+            metadata
+                .records
+                .get(key)
+                .is_some_and(|value| values.contains(value))
+        })
+    }
 }
\ No newline at end of file
@@ -0,0 +1,157 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{
+    organization::{OrganizationRoleSet, OrganizationUserRole},
+    user::User,
+};
+
+pub const MAXIMUM_ESCALATION_LEVELS: usize = 10;
+pub const MINIMUM_ESCALATION_DELAY_SECONDS: u32 = 60;
+
+/// Defines how an incident is escalated when nobody acknowledges it.
+///
+/// Escalation level 0 is the initial notification of an incident, sent to the users on call of `on_call_schedule_id`
+/// (or to every member of the organization) using the channels configured on the incident source (HTTP monitor, task...).
+/// `levels[0]` describes escalation level 1, `levels[1]` escalation level 2, and so on.
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct EscalationPolicy {
+    pub organization_id: Uuid,
+    /// The schedule whose users on call receive the initial notification of incidents.
+    /// Every member of the organization is notified if not set, or if nobody is on call
+    #[serde(default)]
+    pub on_call_schedule_id: Option<Uuid>,
+    pub levels: Vec<EscalationPolicyLevel>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct EscalationPolicyLevel {
+    /// Time to wait after the notification of the previous level before notifying this level
+    pub delay_seconds: u32,
+    /// Users to notify. If no user, role or user on call is targeted, every member of the organization is notified
+    pub target_user_ids: Vec<Uuid>,
+    /// Members having one of these roles are notified
+    pub target_roles: Vec<OrganizationUserRole>,
+    /// The users on call of these schedules at the time of the notification are notified
+    #[serde(default)]
+    pub target_on_call_schedule_ids: Vec<Uuid>,
+    pub send_email: bool,
+    pub send_push_notification: bool,
+    pub send_sms: bool,
+}
+
+impl EscalationPolicy {
+    /// An organization that hasn't configured an escalation policy never escalates incidents
+    pub fn empty(organization_id: Uuid) -> Self {
+        Self {
+            organization_id,
+            on_call_schedule_id: None,
+            levels: vec![],
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Returns the configuration of an escalation level, if any.
+    /// Level 0 is the initial notification of an incident and is not part of the policy.
+    pub fn level(&self, escalation_level: i16) -> Option<&EscalationPolicyLevel> {
+        if escalation_level <= 0 {
+            return None;
+        }
+        self.levels.get(escalation_level as usize - 1)
+    }
+}
+
+impl EscalationPolicyLevel {
+    /// Whether a user is targeted by this escalation level, given their roles in the organization
+    /// and the users on call of `target_on_call_schedule_ids`
+    pub fn targets(&self, user: &User, user_roles: &OrganizationRoleSet, on_call_user_ids: &[Uuid]) -> bool {
+        if self.target_user_ids.is_empty() && self.target_roles.is_empty() && on_call_user_ids.is_empty() {
+            return true;
+        }
+        self.target_user_ids.contains(&user.id)
+            || on_call_user_ids.contains(&user.id)
+            || self.target_roles.iter().any(|role| user_roles.contains(*role))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_user() -> User {
+        User {
+            id: Uuid::new_v4(),
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            email: "john@example.com".to_string(),
+            phone_number: None,
+            phone_number_verified: false,
+            phone_number_otp: None,
+        }
+    }
+
+    fn test_level(target_user_ids: Vec<Uuid>, target_roles: Vec<OrganizationUserRole>) -> EscalationPolicyLevel {
+        EscalationPolicyLevel {
+            delay_seconds: 300,
+            target_user_ids,
+            target_roles,
+            target_on_call_schedule_ids: vec![],
+            send_email: true,
+            send_push_notification: true,
+            send_sms: false,
+        }
+    }
+
+    #[test]
+    fn test_level_0_is_not_part_of_the_policy() {
+        let policy = EscalationPolicy {
+            levels: vec![test_level(vec![], vec![])],
+            ..EscalationPolicy::empty(Uuid::new_v4())
+        };
+        assert!(policy.level(0).is_none());
+        assert!(policy.level(1).is_some());
+        assert!(policy.level(2).is_none());
+    }
+
+    #[test]
+    fn test_level_without_targets_targets_everyone() {
+        let level = test_level(vec![], vec![]);
+        assert!(level.targets(&test_user(), &OrganizationRoleSet::from_roles(vec![]), &[]));
+    }
+
+    #[test]
+    fn test_level_targets_users_and_roles() {
+        let user = test_user();
+        let by_id = test_level(vec![user.id], vec![]);
+        let by_role = test_level(vec![Uuid::new_v4()], vec![OrganizationUserRole::Editor]);
+
+        let reporter = OrganizationRoleSet::from_roles(vec![OrganizationUserRole::Reporter]);
+        let admin = OrganizationRoleSet::from_roles(vec![OrganizationUserRole::Administrator]);
+
+        assert!(by_id.targets(&user, &reporter, &[]));
+        assert!(!by_role.targets(&user, &reporter, &[]));
+        // Administrators include the editor role
+        assert!(by_role.targets(&user, &admin, &[]));
+    }
+
+    #[test]
+    fn test_level_targets_users_on_call() {
+        let user = test_user();
+        let no_roles = OrganizationRoleSet::from_roles(vec![]);
+        let mut level = test_level(vec![], vec![]);
+        level.target_on_call_schedule_ids = vec![Uuid::new_v4()];
+
+        assert!(level.targets(&user, &no_roles, &[user.id]));
+        assert!(!level.targets(&user, &no_roles, &[Uuid::new_v4()]));
+        // nobody is on call: every member is notified so that the incident is not missed
+        assert!(level.targets(&user, &no_roles, &[]));
+    }
+}
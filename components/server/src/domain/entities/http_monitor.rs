@@ -12,7 +12,15 @@ use uuid::Uuid;
 
 use crate::protos;
 
-use super::entity_metadata::EntityMetadata;
+use super::{
+    entity_metadata::EntityMetadata,
+    http_monitor_assertion::{HttpMonitorAssertionFailure, HttpMonitorAssertions},
+    http_monitor_request::{
+        HttpMethod, HttpMonitorAuthentication, HttpMonitorRedirectPolicy, ProbeEngine,
+    },
+    monitor_kind::{MonitorKind, MonitorKindSettings},
+    tls_certificate::ObservedTlsCertificate,
+};
 
 pub const MAXIMUM_REQUEST_TIMEOUT_MS: i64 = 20_000;
 
@@ -46,7 +54,33 @@ pub struct HttpMonitor {
     pub archived_at: Option<DateTime<Utc>>,
     #[sqlx(json)]
     pub request_headers: RequestHeaders,
-    pub request_timeout_ms: i32
+    pub request_timeout_ms: i32,
+    #[sqlx(json)]
+    pub assertions: HttpMonitorAssertions,
+    /// Names of the probe locations the monitor is pinged from
+    /// An empty list means the default location only
+    pub locations: Vec<String>,
+    /// Minimum number of failing locations for a ping to be considered failed
+    /// If None, a majority of the locations is required
+    #[ts(type = "number | null")]
+    pub location_quorum: Option<i16>,
+    pub request_method: HttpMethod,
+    pub request_body: Option<String>,
+    /// Sent as the `Content-Type` header along with the request body
+    pub request_body_content_type: Option<String>,
+    #[sqlx(json)]
+    pub authentication: HttpMonitorAuthentication,
+    #[sqlx(json)]
+    pub redirect_policy: HttpMonitorRedirectPolicy,
+    pub probe_engine: ProbeEngine,
+    /// The certificate presented by the server on the last ping that reached the TLS handshake
+    #[sqlx(json)]
+    pub tls_certificate: ObservedTlsCertificate,
+    /// Number of days before the certificate expires at which a warning incident is opened, 0 to disable it
+    pub tls_expiry_warning_days: i16,
+    /// What the monitor checks, along with the settings specific to the kind of check
+    #[sqlx(json)]
+    pub kind_settings: MonitorKindSettings,
 }
 
 impl HttpMonitor {
@@ -58,9 +92,70 @@ impl HttpMonitor {
         Duration::from_millis(self.request_timeout_ms as u64)
     }
 
+    pub fn kind(&self) -> MonitorKind {
+        self.kind_settings.kind()
+    }
+
     pub fn url(&self) -> anyhow::Result<Url> {
         Url::parse(&self.url).context("invalid url for monitor")
     }
+
+    /// Number of failing locations, out of `location_count`, required for a ping to be considered failed
+    pub fn failure_quorum(&self, location_count: usize) -> usize {
+        failure_quorum(self.location_quorum, location_count)
+    }
+}
+
+/// Computes the number of failing locations required to consider a ping failed.
+/// Defaults to a strict majority, and is always clamped between 1 and the number of locations.
+pub fn failure_quorum(location_quorum: Option<i16>, location_count: usize) -> usize {
+    let location_count = location_count.max(1);
+    let quorum = match location_quorum {
+        Some(quorum) => quorum.max(1) as usize,
+        None => location_count / 2 + 1,
+    };
+    quorum.min(location_count)
+}
+
+/// Validates the locations of a monitor against the available probe locations
+pub fn validate_locations(
+    locations: &[String],
+    location_quorum: Option<i16>,
+    probe_engine: ProbeEngine,
+    available_locations: &[String],
+) -> Result<(), String> {
+    if probe_engine == ProbeEngine::Lightweight && !locations.is_empty() {
+        return Err("locations are only supported by the browser probe engine".to_string());
+    }
+    if let Some(unknown) = locations.iter().find(|l| !available_locations.contains(l)) {
+        return Err(format!("unknown location: {unknown}"));
+    }
+    if locations.iter().enumerate().any(|(i, l)| locations[..i].contains(l)) {
+        return Err("locations must be unique".to_string());
+    }
+    if let Some(quorum) = location_quorum {
+        if quorum < 1 || quorum as usize > locations.len().max(1) {
+            return Err(format!(
+                "location quorum must be between 1 and the number of locations, got {quorum}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Result of a ping performed from a single probe location
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct LocationPingResult {
+    pub location: String,
+    pub error_kind: HttpMonitorErrorKind,
+    pub http_code: Option<i32>,
+    #[ts(type = "number")]
+    pub response_time_ms: u64,
+    /// Set when the error kind is `AssertionFailed`
+    #[serde(default)]
+    pub failed_assertion: Option<HttpMonitorAssertionFailure>,
 }
 
 #[derive(sqlx::Type, Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
@@ -121,6 +216,16 @@ pub enum HttpMonitorErrorKind {
     Decode = 7,
     Timeout = 8,
     BrowserServiceCallFailed = 9,
+    /// The response was received, but one of the monitor's assertions did not hold
+    AssertionFailed = 10,
+    /// The TLS certificate chain could not be verified (expired, untrusted or not matching the host)
+    TlsCertificate = 11,
+    /// The hostname of a DNS monitor could not be resolved, or has no record of the monitored type
+    DnsResolution = 12,
+    /// The records resolved by a DNS monitor do not include all the expected answers
+    UnexpectedDnsAnswer = 13,
+    /// The gRPC health checking service reported the service as not serving
+    NotServing = 14,
 }
 
 impl From<protos::HttpErrorKind> for HttpMonitorErrorKind {
@@ -135,6 +240,7 @@ impl From<protos::HttpErrorKind> for HttpMonitorErrorKind {
             protos::HttpErrorKind::Body => Self::Body,
             protos::HttpErrorKind::Decode => Self::Decode,
             protos::HttpErrorKind::Timeout => Self::Timeout,
+            protos::HttpErrorKind::TlsCertificate => Self::TlsCertificate,
         }
     }
 }
@@ -153,6 +259,11 @@ impl From<i16> for HttpMonitorErrorKind {
             7 => Self::Decode,
             8 => Self::Timeout,
             9 => Self::BrowserServiceCallFailed,
+            10 => Self::AssertionFailed,
+            11 => Self::TlsCertificate,
+            12 => Self::DnsResolution,
+            13 => Self::UnexpectedDnsAnswer,
+            14 => Self::NotServing,
             _ => panic!("invalid HttpMonitorErrorKind discriminant: {value}"),
         }
     }
@@ -169,3 +280,42 @@ impl From<Value> for RequestHeaders {
         serde_json::from_value(value).unwrap_or_default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{failure_quorum, validate_locations, ProbeEngine};
+
+    #[test]
+    fn failure_quorum_defaults_to_majority() {
+        assert_eq!(failure_quorum(None, 0), 1);
+        assert_eq!(failure_quorum(None, 1), 1);
+        assert_eq!(failure_quorum(None, 2), 2);
+        assert_eq!(failure_quorum(None, 3), 2);
+        assert_eq!(failure_quorum(None, 4), 3);
+        assert_eq!(failure_quorum(None, 5), 3);
+    }
+
+    #[test]
+    fn failure_quorum_is_clamped_to_location_count() {
+        assert_eq!(failure_quorum(Some(1), 3), 1);
+        assert_eq!(failure_quorum(Some(3), 3), 3);
+        assert_eq!(failure_quorum(Some(5), 3), 3);
+        assert_eq!(failure_quorum(Some(0), 3), 1);
+        assert_eq!(failure_quorum(Some(-2), 3), 1);
+    }
+
+    #[test]
+    fn validate_locations_tests() {
+        let available = vec!["paris".to_string(), "london".to_string(), "nyc".to_string()];
+        let locations = |l: &[&str]| l.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+
+        assert!(validate_locations(&[], None, ProbeEngine::Browser, &available).is_ok());
+        assert!(validate_locations(&locations(&["paris", "nyc"]), Some(2), ProbeEngine::Browser, &available).is_ok());
+        assert!(validate_locations(&locations(&["tokyo"]), None, ProbeEngine::Browser, &available).is_err());
+        assert!(validate_locations(&locations(&["paris", "paris"]), None, ProbeEngine::Browser, &available).is_err());
+        assert!(validate_locations(&locations(&["paris", "nyc"]), Some(3), ProbeEngine::Browser, &available).is_err());
+        assert!(validate_locations(&locations(&["paris"]), Some(0), ProbeEngine::Browser, &available).is_err());
+        assert!(validate_locations(&[], None, ProbeEngine::Lightweight, &available).is_ok());
+        assert!(validate_locations(&locations(&["paris"]), None, ProbeEngine::Lightweight, &available).is_err());
+    }
+}
@@ -0,0 +1,441 @@
+use std::{
+    collections::HashMap,
+    hash::{Hash, Hasher},
+    time::Duration,
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// The maximum number of assertions of an HTTP monitor
+pub const MAXIMUM_ASSERTIONS_PER_MONITOR: usize = 20;
+
+/// Observed values longer than this are truncated before being recorded in assertion failures
+const MAXIMUM_ACTUAL_VALUE_LENGTH: usize = 256;
+
+/// A check performed on the response of an HTTP monitor, in addition to the HTTP status code
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq, Hash)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+#[ts(export)]
+pub enum HttpMonitorAssertion {
+    /// The status code must be between `min` and `max` (inclusive)
+    StatusCode { min: u16, max: u16 },
+    /// The body must contain a string
+    BodyContains { value: String },
+    /// The body must match a regular expression
+    BodyMatches {
+        #[ts(type = "string")]
+        #[schema(value_type = String)]
+        pattern: AssertionPattern,
+    },
+    /// The body must be a JSON document, and the value at `path` must be equal to `value`.
+    /// Paths use a subset of the JSONPath syntax, e.g. `$.data.items[0].status`.
+    /// Strings are compared to `value` as is, other JSON values are compared to `value` once serialized (e.g. `true`, `42` or `null`)
+    JsonPathEquals { path: String, value: String },
+    /// The header must be present and equal to `value`. Header names are case-insensitive
+    HeaderEquals { name: String, value: String },
+    /// The response must be received in less than `max_ms` milliseconds
+    MaxResponseTime { max_ms: u32 },
+    /// The body must not be larger than `max_bytes` bytes
+    MaxBodySize { max_bytes: u32 },
+}
+
+/// An assertion that did not hold, along with the value that was observed
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct HttpMonitorAssertionFailure {
+    pub assertion: HttpMonitorAssertion,
+    /// The observed value, if it can be displayed (bodies are never recorded)
+    pub actual_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS, Default, ToSchema)]
+#[ts(export)]
+pub struct HttpMonitorAssertions {
+    #[serde(default)]
+    pub items: Vec<HttpMonitorAssertion>,
+}
+
+impl TryFrom<Value> for HttpMonitorAssertions {
+    type Error = serde_json::Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value)
+    }
+}
+
+/// A regular expression, compiled once when the assertion is deserialized rather than on every ping
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "String", into = "String")]
+pub struct AssertionPattern(Regex);
+
+impl AssertionPattern {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.0.is_match(haystack)
+    }
+}
+
+impl TryFrom<String> for AssertionPattern {
+    type Error = String;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        Regex::new(&pattern)
+            .map(Self)
+            .map_err(|e| format!("Invalid regular expression: {e}"))
+    }
+}
+
+impl From<AssertionPattern> for String {
+    fn from(pattern: AssertionPattern) -> Self {
+        pattern.as_str().to_string()
+    }
+}
+
+impl PartialEq for AssertionPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for AssertionPattern {}
+
+impl Hash for AssertionPattern {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+/// The parts of a response that assertions are evaluated against
+pub struct AssertedResponse<'a> {
+    pub http_code: Option<u16>,
+    pub http_headers: &'a HashMap<String, String>,
+    pub body: Option<&'a [u8]>,
+    pub body_size_bytes: u64,
+    pub response_time: Duration,
+}
+
+impl HttpMonitorAssertions {
+    /// Checks that every assertion is well-formed
+    pub fn validate(&self) -> Result<(), String> {
+        if self.items.len() > MAXIMUM_ASSERTIONS_PER_MONITOR {
+            return Err(format!(
+                "A monitor cannot have more than {MAXIMUM_ASSERTIONS_PER_MONITOR} assertions"
+            ));
+        }
+        self.items
+            .iter()
+            .try_for_each(HttpMonitorAssertion::validate)
+    }
+
+    /// Returns the first assertion that does not hold for a response, if any
+    pub fn evaluate(&self, response: &AssertedResponse) -> Option<HttpMonitorAssertionFailure> {
+        self.items
+            .iter()
+            .find_map(|assertion| assertion.evaluate(response))
+    }
+}
+
+impl HttpMonitorAssertion {
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::StatusCode { min, max } if min > max || *max > 999 => {
+                Err(format!("Invalid status code range: {min}-{max}"))
+            }
+            Self::JsonPathEquals { path, .. } => parse_json_path(path).map(|_| ()),
+            Self::HeaderEquals { name, .. } if name.trim().is_empty() => {
+                Err("Header name cannot be empty".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns a failure if the assertion does not hold for a response
+    pub fn evaluate(&self, response: &AssertedResponse) -> Option<HttpMonitorAssertionFailure> {
+        let (holds, actual_value) = match self {
+            Self::StatusCode { min, max } => (
+                response
+                    .http_code
+                    .is_some_and(|code| (*min..=*max).contains(&code)),
+                response.http_code.map(|code| code.to_string()),
+            ),
+            Self::BodyContains { value } => (
+                response
+                    .body
+                    .is_some_and(|body| String::from_utf8_lossy(body).contains(value.as_str())),
+                None,
+            ),
+            Self::BodyMatches { pattern } => (
+                response
+                    .body
+                    .is_some_and(|body| pattern.is_match(&String::from_utf8_lossy(body))),
+                None,
+            ),
+            Self::JsonPathEquals { path, value } => {
+                let actual_value = response
+                    .body
+                    .and_then(|body| serde_json::from_slice::<Value>(body).ok())
+                    .and_then(|json| json_path_lookup(&json, path).map(json_value_to_string));
+                (actual_value.as_ref() == Some(value), actual_value)
+            }
+            Self::HeaderEquals { name, value } => {
+                let actual_value = response
+                    .http_headers
+                    .iter()
+                    .find(|(header, _)| header.eq_ignore_ascii_case(name))
+                    .map(|(_, value)| value.clone());
+                (actual_value.as_ref() == Some(value), actual_value)
+            }
+            Self::MaxResponseTime { max_ms } => (
+                response.response_time <= Duration::from_millis(*max_ms as u64),
+                Some(response.response_time.as_millis().to_string()),
+            ),
+            Self::MaxBodySize { max_bytes } => {
+                let body_size = response
+                    .body_size_bytes
+                    .max(response.body.map(|body| body.len() as u64).unwrap_or(0));
+                (body_size <= *max_bytes as u64, Some(body_size.to_string()))
+            }
+        };
+
+        if holds {
+            None
+        } else {
+            Some(HttpMonitorAssertionFailure {
+                assertion: self.clone(),
+                actual_value: actual_value
+                    .map(|value| value.chars().take(MAXIMUM_ACTUAL_VALUE_LENGTH).collect()),
+            })
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum JsonPathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Parses a JSONPath made of keys and array indexes, e.g. `$.data.items[0].status` or `$["some key"]`
+fn parse_json_path(path: &str) -> Result<Vec<JsonPathSegment>, String> {
+    let invalid = || format!("Invalid JSON path: {path}");
+    let mut rest = path.strip_prefix('$').ok_or_else(invalid)?;
+    let mut segments = vec![];
+
+    while !rest.is_empty() {
+        if let Some(after_dot) = rest.strip_prefix('.') {
+            let end = after_dot.find(['.', '[']).unwrap_or(after_dot.len());
+            if end == 0 {
+                return Err(invalid());
+            }
+            segments.push(JsonPathSegment::Key(after_dot[..end].to_string()));
+            rest = &after_dot[end..];
+        } else if let Some(after_bracket) = rest.strip_prefix('[') {
+            let end = after_bracket.find(']').ok_or_else(invalid)?;
+            let inside = &after_bracket[..end];
+            let segment = match inside
+                .strip_prefix('"')
+                .and_then(|key| key.strip_suffix('"'))
+            {
+                Some(key) => JsonPathSegment::Key(key.to_string()),
+                None => JsonPathSegment::Index(inside.parse().map_err(|_| invalid())?),
+            };
+            segments.push(segment);
+            rest = &after_bracket[end + 1..];
+        } else {
+            return Err(invalid());
+        }
+    }
+
+    Ok(segments)
+}
+
+fn json_path_lookup<'a>(json: &'a Value, path: &str) -> Option<&'a Value> {
+    parse_json_path(path)
+        .ok()?
+        .iter()
+        .try_fold(json, |value, segment| match segment {
+            JsonPathSegment::Key(key) => value.get(key),
+            JsonPathSegment::Index(index) => value.get(*index),
+        })
+}
+
+fn json_value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response<'a>(headers: &'a HashMap<String, String>, body: &'a str) -> AssertedResponse<'a> {
+        AssertedResponse {
+            http_code: Some(200),
+            http_headers: headers,
+            body: Some(body.as_bytes()),
+            body_size_bytes: body.len() as u64,
+            response_time: Duration::from_millis(150),
+        }
+    }
+
+    fn pattern(pattern: &str) -> AssertionPattern {
+        pattern.to_string().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_status_code_assertion() {
+        let headers = HashMap::new();
+        let assertion = HttpMonitorAssertion::StatusCode { min: 200, max: 299 };
+        assert_eq!(assertion.evaluate(&response(&headers, "")), None);
+
+        let failure = HttpMonitorAssertion::StatusCode { min: 300, max: 399 }
+            .evaluate(&response(&headers, ""))
+            .unwrap();
+        assert_eq!(failure.actual_value.as_deref(), Some("200"));
+    }
+
+    #[test]
+    fn test_body_assertions() {
+        let headers = HashMap::new();
+        let response = response(&headers, "<html>Everything is fine</html>");
+
+        assert!(HttpMonitorAssertion::BodyContains {
+            value: "fine".to_string()
+        }
+        .evaluate(&response)
+        .is_none());
+        assert!(HttpMonitorAssertion::BodyContains {
+            value: "error".to_string()
+        }
+        .evaluate(&response)
+        .is_some());
+        assert!(HttpMonitorAssertion::BodyMatches {
+            pattern: pattern(r"Every\w+ is")
+        }
+        .evaluate(&response)
+        .is_none());
+        assert!(HttpMonitorAssertion::BodyMatches {
+            pattern: pattern(r"^fine")
+        }
+        .evaluate(&response)
+        .is_some());
+        assert!(HttpMonitorAssertion::MaxBodySize { max_bytes: 10 }
+            .evaluate(&response)
+            .is_some());
+    }
+
+    #[test]
+    fn test_json_path_assertion() {
+        let headers = HashMap::new();
+        let response = response(
+            &headers,
+            r#"{"status": "ok", "checks": [{"healthy": true}, {"count": 3}], "a key": null}"#,
+        );
+
+        let holds = |path: &str, value: &str| {
+            HttpMonitorAssertion::JsonPathEquals {
+                path: path.to_string(),
+                value: value.to_string(),
+            }
+            .evaluate(&response)
+            .is_none()
+        };
+        assert!(holds("$.status", "ok"));
+        assert!(holds("$.checks[0].healthy", "true"));
+        assert!(holds("$.checks[1].count", "3"));
+        assert!(holds(r#"$["a key"]"#, "null"));
+        assert!(!holds("$.status", "ko"));
+        assert!(!holds("$.missing", "ok"));
+    }
+
+    #[test]
+    fn test_header_and_response_time_assertions() {
+        let headers = HashMap::from([("content-type".to_string(), "application/json".to_string())]);
+        let response = response(&headers, "");
+
+        assert!(HttpMonitorAssertion::HeaderEquals {
+            name: "Content-Type".to_string(),
+            value: "application/json".to_string()
+        }
+        .evaluate(&response)
+        .is_none());
+        assert!(HttpMonitorAssertion::MaxResponseTime { max_ms: 100 }
+            .evaluate(&response)
+            .is_some());
+        assert!(HttpMonitorAssertion::MaxResponseTime { max_ms: 200 }
+            .evaluate(&response)
+            .is_none());
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(HttpMonitorAssertion::StatusCode { min: 300, max: 200 }
+            .validate()
+            .is_err());
+        assert!(HttpMonitorAssertion::JsonPathEquals {
+            path: "status".to_string(),
+            value: "ok".to_string()
+        }
+        .validate()
+        .is_err());
+        assert!(HttpMonitorAssertion::JsonPathEquals {
+            path: "$.items[x]".to_string(),
+            value: "ok".to_string()
+        }
+        .validate()
+        .is_err());
+        assert_eq!(
+            parse_json_path("$.a[2]").unwrap(),
+            vec![
+                JsonPathSegment::Key("a".to_string()),
+                JsonPathSegment::Index(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_assertions() {
+        let assertions = HttpMonitorAssertions::try_from(serde_json::json!({
+            "items": [{ "type": "bodyMatches", "pattern": "^ok$" }]
+        }))
+        .unwrap();
+        assert_eq!(
+            assertions.items,
+            vec![HttpMonitorAssertion::BodyMatches {
+                pattern: pattern("^ok$")
+            }]
+        );
+        assert_eq!(
+            serde_json::to_value(&assertions).unwrap(),
+            serde_json::json!({ "items": [{ "type": "bodyMatches", "pattern": "^ok$" }] })
+        );
+
+        // Invalid patterns and unknown assertions are rejected instead of being silently dropped
+        assert!(HttpMonitorAssertions::try_from(serde_json::json!({
+            "items": [{ "type": "bodyMatches", "pattern": "(" }]
+        }))
+        .is_err());
+        assert!(HttpMonitorAssertions::try_from(serde_json::json!({
+            "items": [{ "type": "unknownAssertion" }]
+        }))
+        .is_err());
+        assert!(HttpMonitorAssertions::try_from(serde_json::json!({}))
+            .unwrap()
+            .items
+            .is_empty());
+    }
+}
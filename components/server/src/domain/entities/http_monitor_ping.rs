@@ -0,0 +1,217 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::http_monitor::{HttpMonitorErrorKind, HttpMonitorStatus};
+
+/// The upper bounds (inclusive) of the ranges of the response time histograms, in milliseconds.
+/// Slower responses are counted in an additional, unbounded range.
+/// Changing these bounds would make the existing rollups meaningless
+pub const RESPONSE_TIME_HISTOGRAM_BOUNDS_MS: [i32; 20] = [
+    25, 50, 75, 100, 150, 200, 300, 400, 500, 750, 1000, 1500, 2000, 3000, 5000, 7500, 10000,
+    15000, 20000, 30000,
+];
+
+/// The result of a single ping of an HTTP monitor
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpMonitorPing {
+    pub organization_id: Uuid,
+    pub http_monitor_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    /// The status of the monitor after the ping
+    pub status: HttpMonitorStatus,
+    pub error_kind: HttpMonitorErrorKind,
+    pub http_code: Option<i16>,
+    pub response_time_ms: i32,
+}
+
+/// Aggregated results of the pings of an HTTP monitor over a period
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpMonitorPingRollup {
+    pub period_start: DateTime<Utc>,
+    pub ping_count: i64,
+    /// The number of pings without error
+    pub successful_ping_count: i64,
+    /// The number of responses in each range of [`RESPONSE_TIME_HISTOGRAM_BOUNDS_MS`].
+    /// Only the pings that received a response are counted
+    pub response_time_histogram: Vec<i64>,
+    /// The number of pings of each error kind, indexed by error kind + 1
+    pub error_kind_counts: Vec<i64>,
+}
+
+impl HttpMonitorPingRollup {
+    pub fn empty(period_start: DateTime<Utc>) -> Self {
+        Self {
+            period_start,
+            ping_count: 0,
+            successful_ping_count: 0,
+            response_time_histogram: vec![0; RESPONSE_TIME_HISTOGRAM_BOUNDS_MS.len() + 1],
+            error_kind_counts: vec![],
+        }
+    }
+
+    pub fn add_ping(&mut self, ping: &HttpMonitorPing) {
+        self.ping_count += 1;
+        if ping.error_kind == HttpMonitorErrorKind::None {
+            self.successful_ping_count += 1;
+        }
+        if ping.http_code.is_some() {
+            let range = RESPONSE_TIME_HISTOGRAM_BOUNDS_MS
+                .iter()
+                .position(|bound| ping.response_time_ms <= *bound)
+                .unwrap_or(RESPONSE_TIME_HISTOGRAM_BOUNDS_MS.len());
+            add_counters(&mut self.response_time_histogram, range, 1);
+        }
+        add_counters(
+            &mut self.error_kind_counts,
+            (ping.error_kind as i16 + 1) as usize,
+            1,
+        );
+    }
+
+    /// Adds the counters of another rollup to this one
+    pub fn merge(&mut self, other: &HttpMonitorPingRollup) {
+        self.ping_count += other.ping_count;
+        self.successful_ping_count += other.successful_ping_count;
+        for (range, count) in other.response_time_histogram.iter().enumerate() {
+            add_counters(&mut self.response_time_histogram, range, *count);
+        }
+        for (index, count) in other.error_kind_counts.iter().enumerate() {
+            add_counters(&mut self.error_kind_counts, index, *count);
+        }
+    }
+
+    /// The share of successful pings, between 0 and 100
+    pub fn uptime_percent(&self) -> Option<f64> {
+        (self.ping_count > 0)
+            .then(|| self.successful_ping_count as f64 * 100.0 / self.ping_count as f64)
+    }
+
+    /// Estimates a percentile of the response times (e.g. 0.95), in milliseconds.
+    /// Response times are assumed to be evenly distributed within each range of the histogram
+    pub fn response_time_percentile(&self, percentile: f64) -> Option<i32> {
+        let response_count = self.response_time_histogram.iter().sum::<i64>();
+        if response_count == 0 {
+            return None;
+        }
+
+        let rank = percentile * response_count as f64;
+        let mut cumulated_count = 0;
+        for (range, count) in self.response_time_histogram.iter().enumerate() {
+            if *count > 0 && (cumulated_count + count) as f64 >= rank {
+                let lower_bound = range
+                    .checked_sub(1)
+                    .map(|previous| RESPONSE_TIME_HISTOGRAM_BOUNDS_MS[previous])
+                    .unwrap_or(0);
+                // the last range is unbounded, its lower bound is the best estimate we have
+                let Some(upper_bound) = RESPONSE_TIME_HISTOGRAM_BOUNDS_MS.get(range) else {
+                    return Some(lower_bound);
+                };
+                let position_in_range = (rank - cumulated_count as f64) / *count as f64;
+                return Some(
+                    lower_bound + ((upper_bound - lower_bound) as f64 * position_in_range) as i32,
+                );
+            }
+            cumulated_count += count;
+        }
+        RESPONSE_TIME_HISTOGRAM_BOUNDS_MS.last().copied()
+    }
+
+    /// The number of pings of each error kind, omitting the error kinds that did not occur
+    pub fn error_kinds(&self) -> Vec<HttpMonitorErrorKindCount> {
+        self.error_kind_counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(index, count)| HttpMonitorErrorKindCount {
+                error_kind: (index as i16 - 1).into(),
+                count: *count,
+            })
+            .collect()
+    }
+}
+
+fn add_counters(counters: &mut Vec<i64>, index: usize, count: i64) {
+    if counters.len() <= index {
+        counters.resize(index + 1, 0);
+    }
+    counters[index] += count;
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct HttpMonitorErrorKindCount {
+    pub error_kind: HttpMonitorErrorKind,
+    pub count: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ping(error_kind: HttpMonitorErrorKind, response_time_ms: i32) -> HttpMonitorPing {
+        HttpMonitorPing {
+            organization_id: Uuid::new_v4(),
+            http_monitor_id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            status: HttpMonitorStatus::Up,
+            error_kind,
+            http_code: (error_kind != HttpMonitorErrorKind::Timeout).then_some(200),
+            response_time_ms,
+        }
+    }
+
+    #[test]
+    fn test_rollup() {
+        let mut rollup = HttpMonitorPingRollup::empty(Utc::now());
+        for response_time_ms in 1..=98 {
+            rollup.add_ping(&ping(HttpMonitorErrorKind::None, response_time_ms));
+        }
+        rollup.add_ping(&ping(HttpMonitorErrorKind::HttpCode, 40_000));
+        rollup.add_ping(&ping(HttpMonitorErrorKind::Timeout, 30_000));
+
+        assert_eq!(rollup.ping_count, 100);
+        assert_eq!(rollup.uptime_percent(), Some(98.0));
+        assert_eq!(
+            rollup.error_kinds(),
+            vec![
+                HttpMonitorErrorKindCount {
+                    error_kind: HttpMonitorErrorKind::None,
+                    count: 98
+                },
+                HttpMonitorErrorKindCount {
+                    error_kind: HttpMonitorErrorKind::HttpCode,
+                    count: 1
+                },
+                HttpMonitorErrorKindCount {
+                    error_kind: HttpMonitorErrorKind::Timeout,
+                    count: 1
+                },
+            ]
+        );
+
+        // the timeout did not receive a response, so only 99 response times are counted
+        let p50 = rollup.response_time_percentile(0.5).unwrap();
+        assert!((45..=55).contains(&p50), "p50 is {p50}");
+        assert_eq!(rollup.response_time_percentile(0.99), Some(30_000));
+    }
+
+    #[test]
+    fn test_merge_rollups() {
+        let mut first = HttpMonitorPingRollup::empty(Utc::now());
+        first.add_ping(&ping(HttpMonitorErrorKind::None, 10));
+        let mut second = HttpMonitorPingRollup::empty(Utc::now());
+        second.add_ping(&ping(HttpMonitorErrorKind::AssertionFailed, 10));
+
+        first.merge(&second);
+        assert_eq!(first.ping_count, 2);
+        assert_eq!(first.successful_ping_count, 1);
+        assert_eq!(first.response_time_histogram[0], 2);
+        assert_eq!(first.uptime_percent(), Some(50.0));
+        assert_eq!(HttpMonitorPingRollup::empty(Utc::now()).uptime_percent(), None);
+        assert_eq!(HttpMonitorPingRollup::empty(Utc::now()).response_time_percentile(0.5), None);
+    }
+}
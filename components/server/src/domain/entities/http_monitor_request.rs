@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::protos;
+
+/// The maximum size of the body sent by an HTTP monitor
+pub const MAXIMUM_REQUEST_BODY_SIZE_BYTES: usize = 64 * 1024;
+
+/// The maximum number of redirects an HTTP monitor can be configured to follow
+pub const MAXIMUM_REDIRECT_HOPS: u8 = 20;
+
+/// How the requests of a monitor are performed
+#[derive(sqlx::Type, Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema, Default)]
+#[repr(i16)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum ProbeEngine {
+    /// The page is loaded in a headless browser by the browser service, from the monitor's probe locations
+    #[default]
+    Browser = 0,
+    /// A plain HTTP request is sent by the server itself, better suited to API health checks
+    Lightweight = 1,
+}
+
+impl From<i16> for ProbeEngine {
+    fn from(value: i16) -> Self {
+        match value {
+            0 => Self::Browser,
+            1 => Self::Lightweight,
+            _ => panic!("invalid ProbeEngine discriminant: {value}"),
+        }
+    }
+}
+
+/// The HTTP method used to ping a monitor
+#[derive(sqlx::Type, Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema, Default)]
+#[repr(i16)]
+#[serde(rename_all = "UPPERCASE")]
+#[ts(export)]
+pub enum HttpMethod {
+    #[default]
+    Get = 0,
+    Head = 1,
+    Post = 2,
+    Put = 3,
+    Patch = 4,
+    Delete = 5,
+    Options = 6,
+}
+
+impl HttpMethod {
+    /// Whether a request with this method may carry a body
+    pub fn allows_body(&self) -> bool {
+        !matches!(self, Self::Get | Self::Head)
+    }
+}
+
+impl From<i16> for HttpMethod {
+    fn from(value: i16) -> Self {
+        match value {
+            0 => Self::Get,
+            1 => Self::Head,
+            2 => Self::Post,
+            3 => Self::Put,
+            4 => Self::Patch,
+            5 => Self::Delete,
+            6 => Self::Options,
+            _ => panic!("invalid HttpMethod discriminant: {value}"),
+        }
+    }
+}
+
+impl From<HttpMethod> for protos::HttpMethod {
+    fn from(value: HttpMethod) -> Self {
+        match value {
+            HttpMethod::Get => Self::Get,
+            HttpMethod::Head => Self::Head,
+            HttpMethod::Post => Self::Post,
+            HttpMethod::Put => Self::Put,
+            HttpMethod::Patch => Self::Patch,
+            HttpMethod::Delete => Self::Delete,
+            HttpMethod::Options => Self::Options,
+        }
+    }
+}
+
+/// The credentials sent by an HTTP monitor
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, Eq, ToSchema, Default)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+#[ts(export)]
+pub enum HttpMonitorAuthentication {
+    #[default]
+    None,
+    /// Credentials sent in a basic `Authorization` header
+    Basic { username: String, password: String },
+    /// Token sent in a bearer `Authorization` header
+    Bearer { token: String },
+}
+
+impl From<Value> for HttpMonitorAuthentication {
+    fn from(value: Value) -> Self {
+        serde_json::from_value(value).unwrap_or_default()
+    }
+}
+
+impl HttpMonitorAuthentication {
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::None => Ok(()),
+            Self::Basic { username, .. } if username.is_empty() => {
+                Err("The username of a basic authentication cannot be empty".to_string())
+            }
+            Self::Basic { username, .. } if username.contains(':') => {
+                Err("The username of a basic authentication cannot contain ':'".to_string())
+            }
+            Self::Basic { .. } => Ok(()),
+            Self::Bearer { token } if token.is_empty() => {
+                Err("The token of a bearer authentication cannot be empty".to_string())
+            }
+            Self::Bearer { .. } => Ok(()),
+        }
+    }
+}
+
+impl From<&HttpMonitorAuthentication> for Option<protos::HttpAuthentication> {
+    fn from(value: &HttpMonitorAuthentication) -> Self {
+        let kind = match value {
+            HttpMonitorAuthentication::None => return None,
+            HttpMonitorAuthentication::Basic { username, password } => {
+                protos::http_authentication::Kind::Basic(protos::BasicAuthentication {
+                    username: username.clone(),
+                    password: password.clone(),
+                })
+            }
+            HttpMonitorAuthentication::Bearer { token } => {
+                protos::http_authentication::Kind::Bearer(protos::BearerAuthentication {
+                    token: token.clone(),
+                })
+            }
+        };
+        Some(protos::HttpAuthentication { kind: Some(kind) })
+    }
+}
+
+/// How an HTTP monitor handles the redirects it receives
+#[derive(Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema, Default)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+#[ts(export)]
+pub enum HttpMonitorRedirectPolicy {
+    /// Redirects are followed, up to the probe engine's own limit
+    #[default]
+    Follow,
+    /// Redirects are not followed, the redirect response is the response of the monitor
+    DontFollow,
+    /// At most `max_hops` redirects are followed, the ping fails with a redirect error beyond that
+    MaxHops { max_hops: u8 },
+}
+
+impl From<Value> for HttpMonitorRedirectPolicy {
+    fn from(value: Value) -> Self {
+        serde_json::from_value(value).unwrap_or_default()
+    }
+}
+
+impl HttpMonitorRedirectPolicy {
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::MaxHops { max_hops } if !(1..=MAXIMUM_REDIRECT_HOPS).contains(max_hops) => Err(
+                format!("The maximum number of redirects must be between 1 and {MAXIMUM_REDIRECT_HOPS}"),
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl From<HttpMonitorRedirectPolicy> for protos::RedirectPolicy {
+    fn from(value: HttpMonitorRedirectPolicy) -> Self {
+        match value {
+            HttpMonitorRedirectPolicy::Follow => Self {
+                follow: true,
+                max_hops: None,
+            },
+            HttpMonitorRedirectPolicy::DontFollow => Self {
+                follow: false,
+                max_hops: None,
+            },
+            HttpMonitorRedirectPolicy::MaxHops { max_hops } => Self {
+                follow: true,
+                max_hops: Some(max_hops as u32),
+            },
+        }
+    }
+}
+
+/// Checks that the request an HTTP monitor sends is consistent
+pub fn validate_request(
+    method: HttpMethod,
+    body: Option<&str>,
+    body_content_type: Option<&str>,
+    authentication: &HttpMonitorAuthentication,
+    redirect_policy: &HttpMonitorRedirectPolicy,
+    request_headers: &HashMap<String, String>,
+) -> Result<(), String> {
+    if let Some(body) = body {
+        if !method.allows_body() {
+            return Err(format!("A {method:?} request cannot have a body"));
+        }
+        if body.len() > MAXIMUM_REQUEST_BODY_SIZE_BYTES {
+            return Err(format!(
+                "The request body cannot be larger than {MAXIMUM_REQUEST_BODY_SIZE_BYTES} bytes"
+            ));
+        }
+    }
+    if body_content_type.is_some() && body.is_none() {
+        return Err("A content type cannot be set without a request body".to_string());
+    }
+    authentication.validate()?;
+    if *authentication != HttpMonitorAuthentication::None
+        && request_headers
+            .keys()
+            .any(|name| name.eq_ignore_ascii_case("authorization"))
+    {
+        return Err(
+            "An Authorization header cannot be set along with an authentication".to_string(),
+        );
+    }
+    redirect_policy.validate()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn validate_request_tests() {
+        let no_headers = HashMap::new();
+        let none = HttpMonitorAuthentication::None;
+        let follow = HttpMonitorRedirectPolicy::Follow;
+
+        assert!(validate_request(HttpMethod::Get, None, None, &none, &follow, &no_headers).is_ok());
+        assert!(validate_request(
+            HttpMethod::Post,
+            Some("{\"query\":\"{ health }\"}"),
+            Some("application/json"),
+            &none,
+            &follow,
+            &no_headers
+        )
+        .is_ok());
+
+        // Bodies are only allowed for methods that accept them
+        assert!(validate_request(HttpMethod::Get, Some("body"), None, &none, &follow, &no_headers).is_err());
+        assert!(validate_request(HttpMethod::Head, Some("body"), None, &none, &follow, &no_headers).is_err());
+        let large_body = "a".repeat(MAXIMUM_REQUEST_BODY_SIZE_BYTES + 1);
+        assert!(validate_request(HttpMethod::Put, Some(&large_body), None, &none, &follow, &no_headers).is_err());
+
+        // A content type requires a body
+        assert!(validate_request(HttpMethod::Post, None, Some("text/plain"), &none, &follow, &no_headers).is_err());
+
+        // Authentication
+        let basic = HttpMonitorAuthentication::Basic {
+            username: "user".to_string(),
+            password: "password".to_string(),
+        };
+        assert!(validate_request(HttpMethod::Get, None, None, &basic, &follow, &no_headers).is_ok());
+        let empty_token = HttpMonitorAuthentication::Bearer { token: String::new() };
+        assert!(validate_request(HttpMethod::Get, None, None, &empty_token, &follow, &no_headers).is_err());
+        let authorization_header = HashMap::from([("authorization".to_string(), "Basic xxx".to_string())]);
+        assert!(validate_request(HttpMethod::Get, None, None, &basic, &follow, &authorization_header).is_err());
+        assert!(validate_request(HttpMethod::Get, None, None, &none, &follow, &authorization_header).is_ok());
+
+        // Redirect policy
+        for (max_hops, valid) in [(0, false), (1, true), (MAXIMUM_REDIRECT_HOPS, true), (MAXIMUM_REDIRECT_HOPS + 1, false)] {
+            let policy = HttpMonitorRedirectPolicy::MaxHops { max_hops };
+            assert_eq!(
+                validate_request(HttpMethod::Get, None, None, &none, &policy, &no_headers).is_ok(),
+                valid
+            );
+        }
+    }
+
+    #[test]
+    fn stored_values_fall_back_to_defaults() {
+        assert_eq!(
+            HttpMonitorAuthentication::from(serde_json::json!({"type": "bearer", "token": "abc"})),
+            HttpMonitorAuthentication::Bearer { token: "abc".to_string() }
+        );
+        assert_eq!(
+            HttpMonitorRedirectPolicy::from(serde_json::json!({"type": "maxHops", "maxHops": 3})),
+            HttpMonitorRedirectPolicy::MaxHops { max_hops: 3 }
+        );
+        assert_eq!(
+            HttpMonitorRedirectPolicy::from(serde_json::json!(null)),
+            HttpMonitorRedirectPolicy::Follow
+        );
+    }
+}
@@ -7,7 +7,16 @@ use ts_rs::TS;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use super::{entity_metadata::EntityMetadata, http_monitor::HttpMonitorErrorKind, user::UserNameInfo};
+use super::{
+    entity_metadata::EntityMetadata,
+    http_monitor::HttpMonitorErrorKind,
+    http_monitor_assertion::HttpMonitorAssertionFailure,
+    monitor_kind::DnsRecordType,
+    task::{TaskId, TaskStatus},
+    task_run::TaskRunStatus,
+    tls_certificate::TlsCertificate,
+    user::UserNameInfo,
+};
 
 /// The base struct used by all incident types
 #[derive(Serialize, Deserialize, TS, Debug, Clone, FromRow, ToSchema)]
@@ -28,6 +37,8 @@ pub struct Incident {
     pub acknowledged_by: Vec<Uuid>,
     #[sqlx(json)]
     pub metadata: EntityMetadata,
+    /// A short description of the incident, set by users. Manual incidents always have a title
+    pub title: Option<String>,
 }
 
 /// A struct that includes the incident, the user who created it, and the users who have acknowledged it
@@ -46,8 +57,32 @@ pub struct IncidentWithUsers {
 #[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
 #[serde(tag = "causeType", rename_all_fields = "camelCase")]
 #[ts(export)]
+#[allow(clippy::enum_variant_names)]
 pub enum IncidentCause {
     HttpMonitorIncidentCause(HttpMonitorIncidentCause),
+    TaskIncidentCause(TaskIncidentCause),
+    ManualIncidentCause(ManualIncidentCause),
+    TlsCertificateIncidentCause(TlsCertificateIncidentCause),
+    TcpMonitorIncidentCause(TcpMonitorIncidentCause),
+    DnsMonitorIncidentCause(DnsMonitorIncidentCause),
+    GrpcHealthMonitorIncidentCause(GrpcHealthMonitorIncidentCause),
+    ExternalAlertIncidentCause(ExternalAlertIncidentCause),
+}
+
+impl IncidentCause {
+    /// The pings that caused the incident of a monitor, whatever the kind of the monitor
+    pub fn monitor_pings(&self) -> Option<&HttpMonitorIncidentCause> {
+        match self {
+            Self::HttpMonitorIncidentCause(cause) => Some(cause),
+            Self::TcpMonitorIncidentCause(cause) => Some(&cause.pings),
+            Self::DnsMonitorIncidentCause(cause) => Some(&cause.pings),
+            Self::GrpcHealthMonitorIncidentCause(cause) => Some(&cause.pings),
+            Self::TaskIncidentCause(_)
+            | Self::ManualIncidentCause(_)
+            | Self::TlsCertificateIncidentCause(_)
+            | Self::ExternalAlertIncidentCause(_) => None,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
@@ -63,6 +98,103 @@ pub struct HttpMonitorIncidentCause {
 pub struct HttpMonitorIncidentCausePing {
     pub error_kind: HttpMonitorErrorKind,
     pub http_code: Option<i16>,
+    /// Set when the error kind is `AssertionFailed`
+    #[serde(default)]
+    pub failed_assertion: Option<HttpMonitorAssertionFailure>,
+}
+
+/// The cause of an incident opened for a TCP monitor whose port cannot be connected to
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TcpMonitorIncidentCause {
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub pings: HttpMonitorIncidentCause,
+    pub host: String,
+    pub port: u16,
+}
+
+/// The cause of an incident opened for a DNS monitor whose record cannot be resolved, or has unexpected answers
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct DnsMonitorIncidentCause {
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub pings: HttpMonitorIncidentCause,
+    pub hostname: String,
+    pub record_type: DnsRecordType,
+    pub expected_answers: Vec<String>,
+}
+
+/// The cause of an incident opened for a gRPC health monitor whose service is unreachable or not serving
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct GrpcHealthMonitorIncidentCause {
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub pings: HttpMonitorIncidentCause,
+    pub host: String,
+    pub port: u16,
+    /// Empty when the overall health of the server is checked
+    pub service: String,
+}
+
+/// The cause of an incident opened for a failing or absent task
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TaskIncidentCause {
+    #[ts(type = "string")]
+    pub task_id: TaskId,
+    pub task_name: String,
+    /// The status of the task when the incident was opened (failing or absent)
+    pub task_status: TaskStatus,
+    /// The status of the task run that caused the incident. Absent tasks have no task run.
+    pub task_run_status: Option<TaskRunStatus>,
+    pub task_run_exit_code: Option<i32>,
+    pub task_run_error_message: Option<String>,
+    /// The last lines of output of the task run, when the run sent its output
+    #[serde(default)]
+    pub task_run_output_tail: Vec<String>,
+}
+
+/// The cause of an incident declared by a user.
+/// The title is the one given when the incident was declared, the incident title can be changed afterwards
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct ManualIncidentCause {
+    pub declared_by: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+}
+
+/// The cause of an incident opened because the certificate of an HTTPS monitor is about to expire
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TlsCertificateIncidentCause {
+    pub certificate: TlsCertificate,
+    /// The number of days before expiry the incident was opened at
+    pub expiry_warning_days: i16,
+}
+
+/// The cause of an incident opened for an alert received by an alert integration.
+/// The labels of the alert are the metadata of the incident
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct ExternalAlertIncidentCause {
+    pub integration_id: Uuid,
+    pub integration_name: String,
+    pub fingerprint: String,
+    pub title: String,
+    pub description: Option<String>,
+    /// A link to the alert in the external system
+    pub source_url: Option<String>,
 }
 
 /// An enum that represents the status of an incident
@@ -138,12 +270,23 @@ impl IncidentPriority {
 #[ts(export)]
 pub enum IncidentSourceType {
     HttpMonitor = 0,
+    Task = 1,
+    /// Manual incidents are declared by users. Each of them has its own source ID, so they are never deduplicated
+    Manual = 2,
+    /// Certificate expiry incidents are opened with the ID of the monitor, along with its regular incidents
+    TlsCertificate = 3,
+    /// External alert incidents are opened with an ID derived from the integration and the fingerprint of the alert
+    ExternalAlert = 4,
 }
 
 impl From<i16> for IncidentSourceType {
     fn from(value: i16) -> Self {
         match value {
             0 => Self::HttpMonitor,
+            1 => Self::Task,
+            2 => Self::Manual,
+            3 => Self::TlsCertificate,
+            4 => Self::ExternalAlert,
             _ => panic!("invalid IncidentSourceType discriminant: {value}"),
         }
     }
@@ -155,6 +298,10 @@ impl From<i16> for IncidentSourceType {
 #[serde(tag = "type")]
 pub enum IncidentSource {
     HttpMonitor { id: Uuid },
+    Task { id: Uuid },
+    Manual { id: Uuid },
+    TlsCertificate { id: Uuid },
+    ExternalAlert { id: Uuid },
 }
 
 /// A struct that represents the data needed to create a new incident
@@ -168,4 +315,5 @@ pub struct NewIncident {
     pub source: IncidentSource,
     pub cause: Option<IncidentCause>,
     pub metadata: EntityMetadata,
+    pub title: Option<String>,
 }
@@ -7,7 +7,12 @@ use ts_rs::TS;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use super::http_monitor::HttpMonitorErrorKind;
+use super::{
+    entity_metadata::EntityMetadata,
+    http_monitor::{HttpMonitorErrorKind, LocationPingResult},
+    http_monitor_assertion::HttpMonitorAssertionFailure, incident::IncidentPriority,
+    task::TaskId, tls_certificate::TlsCertificate,
+};
 
 /// An event that is recorded for an incident.
 #[derive(Serialize, Deserialize, TS, Debug, Clone, FromRow, ToSchema)]
@@ -31,6 +36,11 @@ pub enum IncidentEventPayload {
     Notification(NotificationEventPayload),
     Acknowledged(AcknowledgedEventPayload),
     MonitorPing(PingEventPayload),
+    MaintenanceWindow(MaintenanceWindowEventPayload),
+    PriorityChanged(PriorityChangedEventPayload),
+    MetadataChanged(MetadataChangedEventPayload),
+    TitleChanged(TitleChangedEventPayload),
+    DownstreamTaskSkipped(DownstreamTaskSkippedEventPayload),
 }
 
 #[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema)]
@@ -70,6 +80,62 @@ pub struct PingEventPayload {
     pub resolved_ip_addresses: Vec<String>,
     pub response_file_id: Option<Uuid>,
     pub screenshot_file_id: Option<Uuid>,
+    /// Set when the error kind is `AssertionFailed`
+    #[serde(default)]
+    pub failed_assertion: Option<HttpMonitorAssertionFailure>,
+    /// Results of the ping from each probe location, when the monitor is pinged from several locations
+    #[serde(default)]
+    pub location_results: Vec<LocationPingResult>,
+    /// The certificate presented by the server, when the ping reached the TLS handshake
+    #[serde(default)]
+    pub tls_certificate: Option<Box<TlsCertificate>>,
+}
+
+/// A maintenance window that was ongoing while the incident was open
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct MaintenanceWindowEventPayload {
+    pub maintenance_window_id: Uuid,
+    pub title: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct PriorityChangedEventPayload {
+    pub previous_priority: IncidentPriority,
+    pub new_priority: IncidentPriority,
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct MetadataChangedEventPayload {
+    pub previous_metadata: EntityMetadata,
+    pub new_metadata: EntityMetadata,
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TitleChangedEventPayload {
+    pub previous_title: Option<String>,
+    pub new_title: Option<String>,
+}
+
+/// A task that depends on the task of the incident, and whose expected run was skipped because of the incident
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct DownstreamTaskSkippedEventPayload {
+    #[ts(type = "string")]
+    pub task_id: TaskId,
+    pub task_name: String,
+    /// When the skipped run was expected
+    pub due_at: DateTime<Utc>,
 }
 
 #[derive(sqlx::Type, Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
@@ -87,6 +153,12 @@ pub enum IncidentEventType {
     MonitorSwitchedToRecovering = 7,
     MonitorSwitchedToSuspicious = 8,
     MonitorSwitchedToDown = 9,
+    /// These events are not persisted, they are added to the timeline of the incidents that overlap a maintenance window
+    MaintenanceWindow = 10,
+    PriorityChanged = 11,
+    MetadataChanged = 12,
+    TitleChanged = 13,
+    DownstreamTaskSkipped = 14,
 }
 
 impl From<i16> for IncidentEventType {
@@ -102,6 +174,11 @@ impl From<i16> for IncidentEventType {
             7 => Self::MonitorSwitchedToRecovering,
             8 => Self::MonitorSwitchedToSuspicious,
             9 => Self::MonitorSwitchedToDown,
+            10 => Self::MaintenanceWindow,
+            11 => Self::PriorityChanged,
+            12 => Self::MetadataChanged,
+            13 => Self::TitleChanged,
+            14 => Self::DownstreamTaskSkipped,
             _ => panic!("invalid IncidentEventType discriminant: {value}"),
         }
     }
@@ -0,0 +1,255 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{
+    entity_metadata::{EntityMetadata, MetadataFilter},
+    incident::{Incident, IncidentSourceType},
+    task::TaskId,
+};
+
+/// The maximum number of occurrences of a recurring window that are computed at once
+const MAXIMUM_OCCURRENCES: usize = 1000;
+
+/// A period during which the monitors and the tasks in its scope do not open incidents nor send notifications,
+/// typically while deploying. Windows are either one-off, or recurring following a cron schedule
+#[derive(Serialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct MaintenanceWindow {
+    pub organization_id: Uuid,
+    pub id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    /// The start of a one-off window, or the date from which a recurring window starts recurring
+    pub starts_at: DateTime<Utc>,
+    /// The end of a one-off window, or the date until which a recurring window recurs
+    pub ends_at: Option<DateTime<Utc>>,
+    /// None for one-off windows
+    pub cron_schedule: Option<String>,
+    /// The duration of each occurrence of a recurring window
+    pub duration_seconds: Option<i32>,
+    pub scope: MaintenanceWindowScope,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The monitors and tasks affected by a maintenance window.
+/// An entity is affected if it is listed by ID, or if its metadata matches the metadata filter
+#[derive(Serialize, Deserialize, TS, Debug, Clone, Default, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct MaintenanceWindowScope {
+    #[serde(default)]
+    pub http_monitor_ids: Vec<Uuid>,
+    #[serde(default)]
+    #[ts(type = "Array<string>")]
+    pub task_ids: Vec<TaskId>,
+    /// Beware that an empty filter matches all the monitors and tasks of the organization
+    #[serde(default)]
+    pub metadata_filter: Option<MetadataFilter>,
+}
+
+impl From<Value> for MaintenanceWindowScope {
+    fn from(value: Value) -> Self {
+        serde_json::from_value(value).unwrap_or_default()
+    }
+}
+
+impl MaintenanceWindowScope {
+    pub fn is_empty(&self) -> bool {
+        self.http_monitor_ids.is_empty() && self.task_ids.is_empty() && self.metadata_filter.is_none()
+    }
+
+    fn matches_metadata(&self, metadata: &EntityMetadata) -> bool {
+        self.metadata_filter
+            .as_ref()
+            .is_some_and(|filter| filter.matches(metadata))
+    }
+}
+
+impl MaintenanceWindow {
+    pub fn covers_http_monitor(&self, monitor_id: Uuid, metadata: &EntityMetadata) -> bool {
+        self.scope.http_monitor_ids.contains(&monitor_id) || self.scope.matches_metadata(metadata)
+    }
+
+    pub fn covers_task(&self, task_id: &TaskId, metadata: &EntityMetadata) -> bool {
+        self.scope.task_ids.contains(task_id) || self.scope.matches_metadata(metadata)
+    }
+
+    /// External alerts have no source that can be listed in the scope of a window,
+    /// so they are only covered through the metadata filter of the window, which applies to their labels
+    pub fn covers_external_alert(&self, labels: &EntityMetadata) -> bool {
+        self.scope.matches_metadata(labels)
+    }
+
+    /// Whether the source of an incident is in the scope of the window.
+    /// The metadata of the incident is used, as it contains the metadata of its source
+    pub fn covers_incident(&self, incident: &Incident) -> bool {
+        match incident.incident_source_type {
+            IncidentSourceType::HttpMonitor | IncidentSourceType::TlsCertificate => {
+                self.covers_http_monitor(incident.incident_source_id, &incident.metadata)
+            }
+            IncidentSourceType::Task => {
+                let task_id = incident.metadata.records.get("task_id").map(String::as_str);
+                self.scope.task_ids.iter().any(|id| Some(id.as_str()) == task_id)
+                    || self.scope.matches_metadata(&incident.metadata)
+            }
+            IncidentSourceType::Manual | IncidentSourceType::ExternalAlert => {
+                self.scope.matches_metadata(&incident.metadata)
+            }
+        }
+    }
+
+    pub fn is_ongoing_at(&self, date: DateTime<Utc>) -> bool {
+        !self.occurrences_between(date, date + Duration::seconds(1)).is_empty()
+    }
+
+    /// The periods during which the window is ongoing and that overlap a range of dates, from the oldest to the most recent
+    pub fn occurrences_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        let (Some(cron_schedule), Some(duration_seconds)) =
+            (&self.cron_schedule, self.duration_seconds)
+        else {
+            return match self.ends_at {
+                Some(ends_at) if self.starts_at < to && ends_at > from => {
+                    vec![(self.starts_at, ends_at)]
+                }
+                _ => vec![],
+            };
+        };
+        let Ok(schedule) = parse_maintenance_cron_schedule(cron_schedule) else {
+            return vec![];
+        };
+
+        let duration = Duration::seconds(duration_seconds as i64);
+        // occurrences that started before `from` may still be ongoing.
+        // `after` is exclusive, hence the extra second
+        let search_start = (from - duration).max(self.starts_at) - Duration::seconds(1);
+        schedule
+            .after(&search_start)
+            .take_while(|start| *start < to && self.ends_at.is_none_or(|ends_at| *start < ends_at))
+            .map(|start| (start, start + duration))
+            .filter(|(_, end)| *end > from)
+            .take(MAXIMUM_OCCURRENCES)
+            .collect()
+    }
+}
+
+/// Parses a cron schedule. Schedules without seconds are accepted, and start at the first second of the minute
+pub fn parse_maintenance_cron_schedule(cron_schedule: &str) -> Result<cron::Schedule, String> {
+    let schedule = if cron_schedule.split_ascii_whitespace().count() < 6 {
+        format!("0 {cron_schedule}")
+    } else {
+        cron_schedule.to_string()
+    };
+    cron::Schedule::from_str(&schedule)
+        .map_err(|e| format!("Invalid cron schedule '{cron_schedule}': {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn create_test_window(
+        starts_at: DateTime<Utc>,
+        ends_at: Option<DateTime<Utc>>,
+        cron_schedule: Option<&str>,
+        duration_seconds: Option<i32>,
+    ) -> MaintenanceWindow {
+        MaintenanceWindow {
+            organization_id: Uuid::new_v4(),
+            id: Uuid::new_v4(),
+            title: "Deployment".to_string(),
+            description: None,
+            starts_at,
+            ends_at,
+            cron_schedule: cron_schedule.map(str::to_string),
+            duration_seconds,
+            scope: MaintenanceWindowScope::default(),
+            created_at: starts_at,
+            updated_at: starts_at,
+        }
+    }
+
+    #[test]
+    fn test_one_off_window() {
+        let start = Utc.with_ymd_and_hms(2024, 12, 12, 9, 0, 0).unwrap();
+        let window = create_test_window(start, Some(start + Duration::hours(1)), None, None);
+
+        assert!(!window.is_ongoing_at(start - Duration::seconds(1)));
+        assert!(window.is_ongoing_at(start));
+        assert!(window.is_ongoing_at(start + Duration::minutes(59)));
+        assert!(!window.is_ongoing_at(start + Duration::hours(1)));
+        assert_eq!(
+            window.occurrences_between(start - Duration::days(1), start + Duration::days(1)),
+            vec![(start, start + Duration::hours(1))]
+        );
+    }
+
+    #[test]
+    fn test_recurring_window() {
+        let start = Utc.with_ymd_and_hms(2024, 12, 12, 0, 0, 0).unwrap();
+        // every day at 22:00 for 4 hours, during 3 days
+        let window = create_test_window(
+            start,
+            Some(start + Duration::days(3)),
+            Some("0 22 * * *"),
+            Some(4 * 3600),
+        );
+
+        let first_occurrence = start + Duration::hours(22);
+        assert!(!window.is_ongoing_at(first_occurrence - Duration::seconds(1)));
+        assert!(window.is_ongoing_at(first_occurrence));
+        // the occurrence spans midnight
+        assert!(window.is_ongoing_at(first_occurrence + Duration::hours(3)));
+        assert!(!window.is_ongoing_at(first_occurrence + Duration::hours(4)));
+        // the window stopped recurring
+        assert!(!window.is_ongoing_at(first_occurrence + Duration::days(3)));
+
+        let occurrences =
+            window.occurrences_between(first_occurrence + Duration::hours(1), start + Duration::days(10));
+        assert_eq!(occurrences.len(), 3);
+        assert_eq!(occurrences[0].0, first_occurrence);
+        assert_eq!(occurrences[2].0, first_occurrence + Duration::days(2));
+    }
+
+    #[test]
+    fn test_scope() {
+        let mut window = create_test_window(Utc::now(), None, None, None);
+        let monitor_id = Uuid::new_v4();
+        let task_id = TaskId::new("nightly-backup".to_string()).unwrap();
+        let mut metadata = EntityMetadata::default();
+        metadata.records.insert("env".to_string(), "staging".to_string());
+
+        assert!(!window.covers_http_monitor(monitor_id, &metadata));
+        window.scope.http_monitor_ids.push(monitor_id);
+        window.scope.task_ids.push(task_id.clone());
+        assert!(window.covers_http_monitor(monitor_id, &EntityMetadata::default()));
+        assert!(window.covers_task(&task_id, &EntityMetadata::default()));
+        assert!(!window.covers_http_monitor(Uuid::new_v4(), &metadata));
+
+        let mut filter = MetadataFilter::default();
+        filter.items.insert("env".to_string(), vec!["staging".to_string()]);
+        window.scope.metadata_filter = Some(filter);
+        assert!(window.covers_http_monitor(Uuid::new_v4(), &metadata));
+        assert!(!window.covers_http_monitor(Uuid::new_v4(), &EntityMetadata::default()));
+    }
+
+    #[test]
+    fn test_parse_cron_schedule() {
+        assert!(parse_maintenance_cron_schedule("0 22 * * *").is_ok());
+        assert!(parse_maintenance_cron_schedule("0 0 22 * * *").is_ok());
+        assert!(parse_maintenance_cron_schedule("every day").is_err());
+    }
+}
@@ -1,5 +1,8 @@
 pub mod authorization;
 pub mod http_monitor;
+pub mod http_monitor_assertion;
+pub mod http_monitor_request;
+pub mod http_monitor_ping;
 pub mod incident;
 pub mod organization;
 pub mod user;
@@ -9,4 +12,16 @@ pub mod incident_event;
 pub mod incident_notification;
 pub mod entity_metadata;
 pub mod task;
-pub mod task_run;
\ No newline at end of file
+pub mod task_run;
+pub mod escalation_policy;
+pub mod webhook;
+pub mod status_page;
+pub mod maintenance_window;
+pub mod on_call_schedule;
+pub mod time_zone;
+pub mod tls_certificate;
+pub mod monitor_kind;
+pub mod alert_integration;
+pub mod task_run_event;
+
+pub mod notification_preferences;
\ No newline at end of file
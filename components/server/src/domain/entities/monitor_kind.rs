@@ -0,0 +1,231 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ts_rs::TS;
+use url::Url;
+use utoipa::ToSchema;
+
+use super::{
+    http_monitor_assertion::HttpMonitorAssertions,
+    http_monitor_request::HttpMonitorAuthentication,
+};
+
+/// The maximum number of answers a DNS monitor can expect
+pub const MAXIMUM_EXPECTED_DNS_ANSWERS: usize = 20;
+
+/// What a monitor checks. The target of the monitor is always its URL, whose scheme depends on the kind
+#[derive(Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema, Default)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub enum MonitorKind {
+    /// An HTTP(S) request, `http://` or `https://` URLs
+    #[default]
+    Http,
+    /// A TCP connection to a port, `tcp://host:port` URLs
+    Tcp,
+    /// A DNS resolution of a record, `dns://hostname` URLs
+    Dns,
+    /// A call to the standard gRPC health checking service, `grpc://host:port` or `grpcs://host:port` URLs
+    GrpcHealth,
+}
+
+/// The DNS record types a DNS monitor can resolve
+#[derive(Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema, Default)]
+#[serde(rename_all = "UPPERCASE")]
+#[ts(export)]
+pub enum DnsRecordType {
+    #[default]
+    A,
+    Aaaa,
+    Cname,
+    Mx,
+    Ns,
+    Txt,
+}
+
+/// The settings specific to the kind of a monitor
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, Eq, ToSchema, Default)]
+#[serde(tag = "kind", rename_all = "camelCase", rename_all_fields = "camelCase")]
+#[ts(export)]
+pub enum MonitorKindSettings {
+    #[default]
+    Http,
+    Tcp,
+    Dns {
+        record_type: DnsRecordType,
+        /// Answers that must all be part of the resolved records. Any answer is accepted if empty
+        #[serde(default)]
+        expected_answers: Vec<String>,
+    },
+    GrpcHealth {
+        /// The name of the service whose health is checked, the overall health of the server if empty
+        #[serde(default)]
+        service: String,
+    },
+}
+
+impl From<Value> for MonitorKindSettings {
+    fn from(value: Value) -> Self {
+        serde_json::from_value(value).unwrap_or_default()
+    }
+}
+
+impl MonitorKindSettings {
+    pub fn kind(&self) -> MonitorKind {
+        match self {
+            Self::Http => MonitorKind::Http,
+            Self::Tcp => MonitorKind::Tcp,
+            Self::Dns { .. } => MonitorKind::Dns,
+            Self::GrpcHealth { .. } => MonitorKind::GrpcHealth,
+        }
+    }
+
+    /// Checks that the URL of the monitor is a target of its kind,
+    /// and that the settings only supported by HTTP monitors are not used by other kinds
+    pub fn validate(
+        &self,
+        url: &Url,
+        locations: &[String],
+        assertions: &HttpMonitorAssertions,
+        authentication: &HttpMonitorAuthentication,
+        request_body: Option<&str>,
+    ) -> Result<(), String> {
+        let schemes: &[&str] = match self {
+            Self::Http => &["http", "https"],
+            Self::Tcp => &["tcp"],
+            Self::Dns { .. } => &["dns"],
+            Self::GrpcHealth { .. } => &["grpc", "grpcs"],
+        };
+        if !schemes.contains(&url.scheme()) {
+            return Err(format!(
+                "The URL of a {:?} monitor must start with {}",
+                self.kind(),
+                schemes
+                    .iter()
+                    .map(|scheme| format!("{scheme}://"))
+                    .collect::<Vec<_>>()
+                    .join(" or ")
+            ));
+        }
+        if self.kind() == MonitorKind::Http {
+            return Ok(());
+        }
+
+        if url.host_str().is_none_or(str::is_empty) {
+            return Err("The URL must have a host".to_string());
+        }
+        match self {
+            Self::Tcp | Self::GrpcHealth { .. } if url.port().is_none() => {
+                return Err("The URL must have a port".to_string());
+            }
+            Self::Dns { expected_answers, .. } => {
+                if url.port().is_some() || !matches!(url.path(), "" | "/") {
+                    return Err("The URL of a DNS monitor must only contain the hostname".to_string());
+                }
+                if expected_answers.len() > MAXIMUM_EXPECTED_DNS_ANSWERS {
+                    return Err(format!(
+                        "A DNS monitor cannot expect more than {MAXIMUM_EXPECTED_DNS_ANSWERS} answers"
+                    ));
+                }
+                if expected_answers.iter().any(|answer| answer.trim().is_empty()) {
+                    return Err("The expected answers cannot be empty".to_string());
+                }
+            }
+            _ => {}
+        }
+
+        if !locations.is_empty() {
+            return Err("locations are only supported by HTTP monitors".to_string());
+        }
+        if !assertions.items.is_empty() {
+            return Err("assertions are only supported by HTTP monitors".to_string());
+        }
+        if *authentication != HttpMonitorAuthentication::None || request_body.is_some() {
+            return Err("request bodies and authentication are only supported by HTTP monitors".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Whether all the expected answers are part of the resolved ones.
+/// Answers are compared case-insensitively, and regardless of the trailing dot of fully qualified names
+pub fn dns_answers_match(expected_answers: &[String], answers: &[String]) -> bool {
+    let normalize = |answer: &str| answer.trim().trim_end_matches('.').to_ascii_lowercase();
+    let answers = answers.iter().map(|a| normalize(a)).collect::<Vec<_>>();
+    expected_answers
+        .iter()
+        .all(|expected| answers.contains(&normalize(expected)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(settings: &MonitorKindSettings, url: &str) -> Result<(), String> {
+        settings.validate(
+            &Url::parse(url).unwrap(),
+            &[],
+            &HttpMonitorAssertions::default(),
+            &HttpMonitorAuthentication::None,
+            None,
+        )
+    }
+
+    #[test]
+    fn validate_tests() {
+        let dns = MonitorKindSettings::Dns {
+            record_type: DnsRecordType::A,
+            expected_answers: vec!["93.184.215.14".to_string()],
+        };
+        let grpc = MonitorKindSettings::GrpcHealth { service: String::new() };
+
+        assert!(validate(&MonitorKindSettings::Http, "https://example.com/health").is_ok());
+        assert!(validate(&MonitorKindSettings::Http, "tcp://example.com:5432").is_err());
+        assert!(validate(&MonitorKindSettings::Tcp, "tcp://example.com:5432").is_ok());
+        assert!(validate(&MonitorKindSettings::Tcp, "tcp://example.com").is_err());
+        assert!(validate(&MonitorKindSettings::Tcp, "https://example.com:443").is_err());
+        assert!(validate(&dns, "dns://example.com").is_ok());
+        assert!(validate(&dns, "dns://example.com:53").is_err());
+        assert!(validate(&dns, "dns://example.com/path").is_err());
+        assert!(validate(&grpc, "grpc://example.com:50051").is_ok());
+        assert!(validate(&grpc, "grpcs://example.com:443").is_ok());
+        assert!(validate(&grpc, "grpc://example.com").is_err());
+
+        let empty_answer = MonitorKindSettings::Dns {
+            record_type: DnsRecordType::Txt,
+            expected_answers: vec![" ".to_string()],
+        };
+        assert!(validate(&empty_answer, "dns://example.com").is_err());
+
+        // HTTP-only settings
+        let url = Url::parse("tcp://example.com:5432").unwrap();
+        let tcp = MonitorKindSettings::Tcp;
+        let none = HttpMonitorAuthentication::None;
+        let no_assertions = HttpMonitorAssertions::default();
+        assert!(tcp.validate(&url, &["eu".to_string()], &no_assertions, &none, None).is_err());
+        assert!(tcp.validate(&url, &[], &no_assertions, &none, Some("body")).is_err());
+        let bearer = HttpMonitorAuthentication::Bearer { token: "secret".to_string() };
+        assert!(tcp.validate(&url, &[], &no_assertions, &bearer, None).is_err());
+    }
+
+    #[test]
+    fn dns_answers_match_tests() {
+        let answers = vec!["mail.example.com.".to_string(), "10 mx.example.com.".to_string()];
+        assert!(dns_answers_match(&[], &answers));
+        assert!(dns_answers_match(&["Mail.Example.com".to_string()], &answers));
+        assert!(dns_answers_match(&["10 mx.example.com".to_string()], &answers));
+        assert!(!dns_answers_match(&["other.example.com".to_string()], &answers));
+        assert!(!dns_answers_match(&["mail.example.com".to_string()], &[]));
+    }
+
+    #[test]
+    fn stored_settings_fall_back_to_http() {
+        assert_eq!(
+            MonitorKindSettings::from(serde_json::json!({"kind": "dns", "recordType": "MX"})),
+            MonitorKindSettings::Dns {
+                record_type: DnsRecordType::Mx,
+                expected_answers: vec![],
+            }
+        );
+        assert_eq!(MonitorKindSettings::from(serde_json::json!(null)), MonitorKindSettings::Http);
+    }
+}
@@ -0,0 +1,337 @@
+use chrono::{DateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::{incident::IncidentPriority, time_zone::parse_time_zone};
+
+/// How a member of an organization wants to be notified of the incidents of this organization.
+/// Members that have not configured their preferences are notified of every incident on every channel
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct NotificationPreferences {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    /// The channels enabled for each incident priority. Priorities that are not listed are notified on every channel
+    pub channels: Vec<PriorityNotificationChannels>,
+    pub quiet_hours: Option<QuietHours>,
+    pub vacation: Option<VacationMode>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct PriorityNotificationChannels {
+    pub priority: IncidentPriority,
+    pub email: bool,
+    pub push_notification: bool,
+    pub sms: bool,
+}
+
+/// The channels on which a user is notified
+#[derive(Serialize, Deserialize, TS, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct NotificationChannels {
+    pub email: bool,
+    pub push_notification: bool,
+    pub sms: bool,
+}
+
+/// A daily period during which the user does not want to be woken up: push notifications and SMS are not sent, only e-mails.
+/// `start` can be after `end` for quiet hours spanning midnight, e.g. from 22:00 to 07:00
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct QuietHours {
+    #[ts(type = "string")]
+    pub start: NaiveTime,
+    #[ts(type = "string")]
+    pub end: NaiveTime,
+    /// The IANA time zone of `start` and `end`, e.g. `Europe/Paris`
+    pub time_zone: String,
+    /// Incidents of these priorities are notified on every enabled channel, even during quiet hours
+    #[serde(default)]
+    pub allowed_priorities: Vec<IncidentPriority>,
+}
+
+/// While on vacation, the user is not notified at all. Their delegate, if any, is notified instead
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct VacationMode {
+    /// Vacation starts immediately if not set
+    pub starts_at: Option<DateTime<Utc>>,
+    /// Vacation lasts until it is disabled if not set
+    pub ends_at: Option<DateTime<Utc>>,
+    pub delegate_user_id: Option<Uuid>,
+}
+
+impl NotificationChannels {
+    pub const ALL: Self = Self {
+        email: true,
+        push_notification: true,
+        sms: true,
+    };
+
+    pub fn is_empty(&self) -> bool {
+        !self.email && !self.push_notification && !self.sms
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self {
+            email: self.email || other.email,
+            push_notification: self.push_notification || other.push_notification,
+            sms: self.sms || other.sms,
+        }
+    }
+}
+
+impl QuietHours {
+    pub fn is_quiet_at(&self, date: DateTime<Utc>) -> bool {
+        // Time zones are validated when preferences are saved
+        let time_zone = parse_time_zone(&self.time_zone).unwrap_or(Tz::UTC);
+        let time = date.with_timezone(&time_zone).time();
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+impl VacationMode {
+    pub fn is_ongoing_at(&self, date: DateTime<Utc>) -> bool {
+        self.starts_at.is_none_or(|starts_at| starts_at <= date)
+            && self.ends_at.is_none_or(|ends_at| date < ends_at)
+    }
+}
+
+impl NotificationPreferences {
+    pub fn default_for(organization_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            organization_id,
+            user_id,
+            channels: vec![],
+            quiet_hours: None,
+            vacation: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn is_on_vacation_at(&self, date: DateTime<Utc>) -> bool {
+        self.vacation
+            .as_ref()
+            .is_some_and(|vacation| vacation.is_ongoing_at(date))
+    }
+
+    /// The channels on which the user wants to be notified of an incident of some priority at a date
+    pub fn channels_at(&self, priority: IncidentPriority, date: DateTime<Utc>) -> NotificationChannels {
+        if self.is_on_vacation_at(date) {
+            return NotificationChannels::default();
+        }
+
+        let mut channels = self
+            .channels
+            .iter()
+            .find(|c| c.priority == priority)
+            .map(|c| NotificationChannels {
+                email: c.email,
+                push_notification: c.push_notification,
+                sms: c.sms,
+            })
+            .unwrap_or(NotificationChannels::ALL);
+        if let Some(quiet_hours) = &self.quiet_hours {
+            if !quiet_hours.allowed_priorities.contains(&priority) && quiet_hours.is_quiet_at(date) {
+                channels.push_notification = false;
+                channels.sms = false;
+            }
+        }
+        channels
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(quiet_hours) = &self.quiet_hours {
+            parse_time_zone(&quiet_hours.time_zone)?;
+            if quiet_hours.start == quiet_hours.end {
+                return Err("Quiet hours must start and end at different times".to_string());
+            }
+        }
+        if let Some(vacation) = &self.vacation {
+            if let (Some(starts_at), Some(ends_at)) = (vacation.starts_at, vacation.ends_at) {
+                if ends_at <= starts_at {
+                    return Err("Vacation must end after it starts".to_string());
+                }
+            }
+            if vacation.delegate_user_id == Some(self.user_id) {
+                return Err("Users cannot delegate their notifications to themselves".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Resolves on which channels each recipient of a notification is notified, following their preferences.
+///
+/// Recipients on vacation are replaced by their delegate, if any. Delegates are notified following their own preferences,
+/// and are not replaced by their own delegate if they are on vacation too. Recipients that are not notified on any channel are omitted.
+///
+/// # Arguments
+///
+/// * `recipient_ids` - The users targeted by the notification
+/// * `preferences` - The preferences of the members of the organization. Members without preferences get the default ones
+/// * `priority` - The priority of the incident
+/// * `date` - The date of the notification
+pub fn resolve_notification_channels(
+    recipient_ids: &[Uuid],
+    preferences: &[NotificationPreferences],
+    priority: IncidentPriority,
+    date: DateTime<Utc>,
+) -> Vec<(Uuid, NotificationChannels)> {
+    let channels_of = |user_id: Uuid| {
+        preferences
+            .iter()
+            .find(|p| p.user_id == user_id)
+            .map(|p| p.channels_at(priority, date))
+            .unwrap_or(NotificationChannels::ALL)
+    };
+
+    let mut resolved: Vec<(Uuid, NotificationChannels)> = vec![];
+    let mut add = |user_id: Uuid, channels: NotificationChannels| {
+        if channels.is_empty() {
+            return;
+        }
+        match resolved.iter_mut().find(|(id, _)| *id == user_id) {
+            Some((_, existing)) => *existing = existing.union(channels),
+            None => resolved.push((user_id, channels)),
+        }
+    };
+
+    for recipient_id in recipient_ids {
+        let delegate_id = preferences
+            .iter()
+            .find(|p| p.user_id == *recipient_id && p.is_on_vacation_at(date))
+            .map(|p| p.vacation.as_ref().and_then(|vacation| vacation.delegate_user_id));
+        match delegate_id {
+            // the recipient is on vacation
+            Some(Some(delegate_id)) => add(delegate_id, channels_of(delegate_id)),
+            Some(None) => {}
+            None => add(*recipient_id, channels_of(*recipient_id)),
+        }
+    }
+    resolved
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+
+    fn preferences(user_id: Uuid) -> NotificationPreferences {
+        NotificationPreferences::default_for(Uuid::new_v4(), user_id)
+    }
+
+    #[test]
+    fn test_channels_per_priority() {
+        let mut prefs = preferences(Uuid::new_v4());
+        prefs.channels.push(PriorityNotificationChannels {
+            priority: IncidentPriority::Notice,
+            email: true,
+            push_notification: false,
+            sms: false,
+        });
+
+        let now = Utc::now();
+        assert_eq!(prefs.channels_at(IncidentPriority::Critical, now), NotificationChannels::ALL);
+        assert_eq!(
+            prefs.channels_at(IncidentPriority::Notice, now),
+            NotificationChannels {
+                email: true,
+                push_notification: false,
+                sms: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_quiet_hours_spanning_midnight() {
+        let mut prefs = preferences(Uuid::new_v4());
+        prefs.quiet_hours = Some(QuietHours {
+            start: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            time_zone: "America/New_York".to_string(),
+            allowed_priorities: vec![IncidentPriority::Emergency],
+        });
+
+        // 23:30 in New York
+        let night = Utc.with_ymd_and_hms(2024, 12, 13, 4, 30, 0).unwrap();
+        // 12:00 in New York
+        let day = Utc.with_ymd_and_hms(2024, 12, 13, 17, 0, 0).unwrap();
+
+        let quiet = prefs.channels_at(IncidentPriority::Major, night);
+        assert!(quiet.email);
+        assert!(!quiet.push_notification && !quiet.sms);
+        assert_eq!(prefs.channels_at(IncidentPriority::Emergency, night), NotificationChannels::ALL);
+        assert_eq!(prefs.channels_at(IncidentPriority::Major, day), NotificationChannels::ALL);
+    }
+
+    #[test]
+    fn test_vacation_delegation() {
+        let (alice, bob, carol) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let now = Utc::now();
+        let mut alice_prefs = preferences(alice);
+        alice_prefs.vacation = Some(VacationMode {
+            starts_at: Some(now - Duration::days(1)),
+            ends_at: Some(now + Duration::days(1)),
+            delegate_user_id: Some(bob),
+        });
+        let mut carol_prefs = preferences(carol);
+        carol_prefs.vacation = Some(VacationMode {
+            starts_at: None,
+            ends_at: None,
+            delegate_user_id: None,
+        });
+        let all_prefs = [alice_prefs.clone(), carol_prefs];
+
+        // Bob is notified once, even though he is both a recipient and a delegate. Carol is not notified
+        let resolved =
+            resolve_notification_channels(&[alice, bob, carol], &all_prefs, IncidentPriority::Major, now);
+        assert_eq!(resolved, vec![(bob, NotificationChannels::ALL)]);
+
+        // Alice's vacation is over
+        let resolved = resolve_notification_channels(
+            &[alice],
+            &all_prefs,
+            IncidentPriority::Major,
+            now + Duration::days(2),
+        );
+        assert_eq!(resolved, vec![(alice, NotificationChannels::ALL)]);
+    }
+
+    #[test]
+    fn test_validation() {
+        let user_id = Uuid::new_v4();
+        let mut prefs = preferences(user_id);
+        assert!(prefs.validate().is_ok());
+
+        prefs.vacation = Some(VacationMode {
+            starts_at: None,
+            ends_at: None,
+            delegate_user_id: Some(user_id),
+        });
+        assert!(prefs.validate().is_err());
+
+        prefs.vacation = None;
+        prefs.quiet_hours = Some(QuietHours {
+            start: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            time_zone: "Nowhere".to_string(),
+            allowed_priorities: vec![],
+        });
+        assert!(prefs.validate().is_err());
+    }
+}
@@ -0,0 +1,373 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::time_zone::{local_to_utc, parse_time_zone};
+
+/// The maximum number of rotations of an on-call schedule
+pub const MAXIMUM_ROTATIONS_PER_SCHEDULE: usize = 10;
+
+/// The maximum number of users taking turns in a rotation
+pub const MAXIMUM_USERS_PER_ROTATION: usize = 100;
+
+/// Defines who is on call for an organization at any given time.
+///
+/// Each rotation has its own on-call user: a schedule with a primary and a secondary rotation has two users on call.
+/// Overrides take precedence over the rotations: during an override, only the user of the override is on call
+#[derive(Serialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct OnCallSchedule {
+    pub organization_id: Uuid,
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    /// The IANA time zone of the handoff times, e.g. `Europe/Paris`
+    pub time_zone: String,
+    pub rotations: OnCallRotations,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, Default, ToSchema)]
+#[ts(export)]
+pub struct OnCallRotations {
+    #[serde(default)]
+    pub items: Vec<OnCallRotation>,
+}
+
+impl From<Value> for OnCallRotations {
+    fn from(value: Value) -> Self {
+        serde_json::from_value(value).unwrap_or_default()
+    }
+}
+
+/// Users taking turns being on call, handing off every day or every week
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct OnCallRotation {
+    pub name: String,
+    pub kind: OnCallRotationKind,
+    /// The users taking turns, in order
+    pub user_ids: Vec<Uuid>,
+    /// The local time of the handoffs, in the time zone of the schedule
+    #[ts(type = "string")]
+    #[schema(value_type = String, example = "09:00:00")]
+    pub handoff_time: NaiveTime,
+    /// The day of the handoffs of weekly rotations, e.g. `Mon`. Defaults to Monday, and is ignored by daily rotations
+    #[serde(default)]
+    #[ts(type = "string | null")]
+    #[schema(value_type = Option<String>, example = "Mon")]
+    pub handoff_day: Option<Weekday>,
+    /// The first user of the rotation is on call from this date until the next handoff
+    pub starts_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum OnCallRotationKind {
+    Daily,
+    Weekly,
+}
+
+/// A user that temporarily replaces the users on call of a schedule, e.g. while they are on vacation
+#[derive(Serialize, TS, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct OnCallOverride {
+    pub organization_id: Uuid,
+    pub id: Uuid,
+    pub on_call_schedule_id: Uuid,
+    pub user_id: Uuid,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A period during which a user is on call
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct OnCallShift {
+    pub user_id: Uuid,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// The rotation of the shift, none for overrides
+    pub rotation_name: Option<String>,
+    /// The override of the shift, none for rotations
+    pub override_id: Option<Uuid>,
+}
+
+impl OnCallRotationKind {
+    fn period_days(&self) -> i64 {
+        match self {
+            OnCallRotationKind::Daily => 1,
+            OnCallRotationKind::Weekly => 7,
+        }
+    }
+}
+
+impl OnCallRotation {
+    /// The date of the last handoff at or before a local date and time
+    fn last_handoff_date(&self, local: NaiveDateTime) -> NaiveDate {
+        let date = local.date();
+        let days_back = match self.kind {
+            OnCallRotationKind::Daily => 0,
+            OnCallRotationKind::Weekly => {
+                let handoff_day = self.handoff_day.unwrap_or(Weekday::Mon);
+                (date.weekday().num_days_from_monday() + 7 - handoff_day.num_days_from_monday()) % 7
+            }
+        };
+        let handoff_date = date - Duration::days(days_back as i64);
+        if handoff_date == date && local.time() < self.handoff_time {
+            handoff_date - Duration::days(self.kind.period_days())
+        } else {
+            handoff_date
+        }
+    }
+
+    /// The shift of the rotation that is ongoing at a date, if the rotation has started
+    pub fn shift_at(&self, time_zone: Tz, date: DateTime<Utc>) -> Option<OnCallShift> {
+        if self.user_ids.is_empty() || date < self.starts_at {
+            return None;
+        }
+
+        let first_handoff_date = self.last_handoff_date(self.starts_at.with_timezone(&time_zone).naive_local());
+        let handoff_date = self.last_handoff_date(date.with_timezone(&time_zone).naive_local());
+        // Handoffs are counted in local days, so that they keep happening at the same local time across DST changes
+        let shift_number = (handoff_date - first_handoff_date).num_days() / self.kind.period_days();
+
+        let starts_at = if shift_number == 0 {
+            self.starts_at
+        } else {
+            local_to_utc(time_zone, handoff_date.and_time(self.handoff_time))
+        };
+        let ends_at = local_to_utc(
+            time_zone,
+            (handoff_date + Duration::days(self.kind.period_days())).and_time(self.handoff_time),
+        );
+
+        Some(OnCallShift {
+            user_id: self.user_ids[shift_number as usize % self.user_ids.len()],
+            starts_at,
+            ends_at,
+            rotation_name: Some(self.name.clone()),
+            override_id: None,
+        })
+    }
+}
+
+impl OnCallSchedule {
+    /// The shifts that are ongoing at a date.
+    ///
+    /// # Arguments
+    ///
+    /// * `overrides` - The overrides of the schedule. Overrides of other schedules are ignored
+    /// * `date` - The date at which the shifts are ongoing
+    pub fn shifts_at(&self, overrides: &[OnCallOverride], date: DateTime<Utc>) -> Vec<OnCallShift> {
+        let override_shifts = overrides
+            .iter()
+            .filter(|o| o.on_call_schedule_id == self.id && o.starts_at <= date && date < o.ends_at)
+            .map(|o| OnCallShift {
+                user_id: o.user_id,
+                starts_at: o.starts_at,
+                ends_at: o.ends_at,
+                rotation_name: None,
+                override_id: Some(o.id),
+            })
+            .collect::<Vec<_>>();
+        if !override_shifts.is_empty() {
+            return override_shifts;
+        }
+
+        // Time zones are validated when schedules are saved
+        let time_zone = parse_time_zone(&self.time_zone).unwrap_or(Tz::UTC);
+        self.rotations
+            .items
+            .iter()
+            .filter_map(|rotation| rotation.shift_at(time_zone, date))
+            .collect()
+    }
+
+    /// The users on call at a date, without duplicates
+    pub fn on_call_user_ids(&self, overrides: &[OnCallOverride], date: DateTime<Utc>) -> Vec<Uuid> {
+        let mut user_ids = vec![];
+        for shift in self.shifts_at(overrides, date) {
+            if !user_ids.contains(&shift.user_id) {
+                user_ids.push(shift.user_id);
+            }
+        }
+        user_ids
+    }
+}
+
+pub fn validate_on_call_schedule(
+    name: &str,
+    time_zone: &str,
+    rotations: &OnCallRotations,
+) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("The name cannot be empty".to_string());
+    }
+    parse_time_zone(time_zone)?;
+    if rotations.items.len() > MAXIMUM_ROTATIONS_PER_SCHEDULE {
+        return Err(format!(
+            "A schedule cannot have more than {MAXIMUM_ROTATIONS_PER_SCHEDULE} rotations"
+        ));
+    }
+    for rotation in &rotations.items {
+        if rotation.name.trim().is_empty() {
+            return Err("Rotation names cannot be empty".to_string());
+        }
+        if rotation.user_ids.is_empty() || rotation.user_ids.len() > MAXIMUM_USERS_PER_ROTATION {
+            return Err(format!(
+                "Rotation '{}' must have between 1 and {MAXIMUM_USERS_PER_ROTATION} users",
+                rotation.name
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn create_test_schedule(time_zone: &str, rotations: Vec<OnCallRotation>) -> OnCallSchedule {
+        OnCallSchedule {
+            organization_id: Uuid::new_v4(),
+            id: Uuid::new_v4(),
+            name: "Backend".to_string(),
+            description: None,
+            time_zone: time_zone.to_string(),
+            rotations: OnCallRotations { items: rotations },
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn create_test_rotation(kind: OnCallRotationKind, user_ids: Vec<Uuid>, starts_at: DateTime<Utc>) -> OnCallRotation {
+        OnCallRotation {
+            name: "Primary".to_string(),
+            kind,
+            user_ids,
+            handoff_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            handoff_day: None,
+            starts_at,
+        }
+    }
+
+    #[test]
+    fn test_daily_rotation() {
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        // Wednesday at 15:00 UTC
+        let starts_at = Utc.with_ymd_and_hms(2024, 12, 11, 15, 0, 0).unwrap();
+        let schedule = create_test_schedule(
+            "UTC",
+            vec![create_test_rotation(OnCallRotationKind::Daily, vec![alice, bob], starts_at)],
+        );
+
+        assert!(schedule.on_call_user_ids(&[], starts_at - Duration::seconds(1)).is_empty());
+
+        let first_shift = &schedule.shifts_at(&[], starts_at)[0];
+        assert_eq!(first_shift.user_id, alice);
+        assert_eq!(first_shift.starts_at, starts_at);
+        assert_eq!(first_shift.ends_at, Utc.with_ymd_and_hms(2024, 12, 12, 9, 0, 0).unwrap());
+
+        let next_handoff = Utc.with_ymd_and_hms(2024, 12, 12, 9, 0, 0).unwrap();
+        assert_eq!(schedule.on_call_user_ids(&[], next_handoff - Duration::seconds(1)), vec![alice]);
+        assert_eq!(schedule.on_call_user_ids(&[], next_handoff), vec![bob]);
+        assert_eq!(schedule.on_call_user_ids(&[], next_handoff + Duration::days(1)), vec![alice]);
+        assert_eq!(schedule.on_call_user_ids(&[], next_handoff + Duration::days(4)), vec![bob]);
+    }
+
+    #[test]
+    fn test_weekly_rotation_in_time_zone() {
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        // Friday, October 18th 2024
+        let starts_at = Utc.with_ymd_and_hms(2024, 10, 18, 12, 0, 0).unwrap();
+        let mut rotation = create_test_rotation(OnCallRotationKind::Weekly, vec![alice, bob], starts_at);
+        rotation.handoff_day = Some(Weekday::Mon);
+        let schedule = create_test_schedule("Europe/Paris", vec![rotation]);
+
+        // Monday October 21st, 09:00 in Paris is 07:00 UTC (summer time)
+        let first_handoff = Utc.with_ymd_and_hms(2024, 10, 21, 7, 0, 0).unwrap();
+        assert_eq!(schedule.on_call_user_ids(&[], first_handoff - Duration::seconds(1)), vec![alice]);
+        assert_eq!(schedule.on_call_user_ids(&[], first_handoff), vec![bob]);
+
+        // Monday October 28th, 09:00 in Paris is 08:00 UTC (winter time)
+        let second_handoff = Utc.with_ymd_and_hms(2024, 10, 28, 8, 0, 0).unwrap();
+        let shift = &schedule.shifts_at(&[], second_handoff - Duration::seconds(1))[0];
+        assert_eq!(shift.user_id, bob);
+        assert_eq!(shift.starts_at, first_handoff);
+        assert_eq!(shift.ends_at, second_handoff);
+        assert_eq!(schedule.on_call_user_ids(&[], second_handoff), vec![alice]);
+    }
+
+    #[test]
+    fn test_overrides_and_multiple_rotations() {
+        let (alice, bob, carol) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let starts_at = Utc.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap();
+        let mut secondary = create_test_rotation(OnCallRotationKind::Weekly, vec![bob, alice], starts_at);
+        secondary.name = "Secondary".to_string();
+        let schedule = create_test_schedule(
+            "UTC",
+            vec![
+                create_test_rotation(OnCallRotationKind::Weekly, vec![alice, bob], starts_at),
+                secondary,
+                // the same user in several rotations is only listed once
+                create_test_rotation(OnCallRotationKind::Daily, vec![alice], starts_at),
+            ],
+        );
+        let date = starts_at + Duration::hours(1);
+        assert_eq!(schedule.on_call_user_ids(&[], date), vec![alice, bob]);
+
+        let on_call_override = OnCallOverride {
+            organization_id: schedule.organization_id,
+            id: Uuid::new_v4(),
+            on_call_schedule_id: schedule.id,
+            user_id: carol,
+            starts_at,
+            ends_at: starts_at + Duration::hours(2),
+            created_at: starts_at,
+        };
+        let other_schedule_override = OnCallOverride {
+            on_call_schedule_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            ..on_call_override.clone()
+        };
+        let overrides = [on_call_override.clone(), other_schedule_override];
+
+        let shifts = schedule.shifts_at(&overrides, date);
+        assert_eq!(shifts.len(), 1);
+        assert_eq!(shifts[0].user_id, carol);
+        assert_eq!(shifts[0].override_id, Some(on_call_override.id));
+        assert_eq!(
+            schedule.on_call_user_ids(&overrides, on_call_override.ends_at),
+            vec![alice, bob]
+        );
+    }
+
+    #[test]
+    fn test_validation() {
+        let rotations = OnCallRotations {
+            items: vec![create_test_rotation(OnCallRotationKind::Daily, vec![Uuid::new_v4()], Utc::now())],
+        };
+        assert!(validate_on_call_schedule("Backend", "America/New_York", &rotations).is_ok());
+        assert!(validate_on_call_schedule("Backend", "Mars/Olympus_Mons", &rotations).is_err());
+        assert!(validate_on_call_schedule(" ", "UTC", &rotations).is_err());
+
+        let empty_rotation = OnCallRotations {
+            items: vec![create_test_rotation(OnCallRotationKind::Daily, vec![], Utc::now())],
+        };
+        assert!(validate_on_call_schedule("Backend", "UTC", &empty_rotation).is_err());
+    }
+}
@@ -0,0 +1,280 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{
+    http_monitor::HttpMonitorStatus,
+    task::{TaskId, TaskStatus},
+};
+
+/// The number of days covered by the uptime bars of a status page
+pub const STATUS_PAGE_UPTIME_DAYS: i64 = 90;
+
+/// The maximum number of components of a status page
+pub const MAXIMUM_COMPONENTS_PER_STATUS_PAGE: usize = 50;
+
+/// A page that displays the health of a selection of HTTP monitors and tasks, grouped into components.
+/// Public status pages can be read without being authenticated
+#[derive(Serialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct StatusPage {
+    pub organization_id: Uuid,
+    pub id: Uuid,
+    /// Identifies the status page in its public URL
+    pub slug: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub components: StatusPageComponents,
+    pub visibility: StatusPageVisibility,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, Default, ToSchema)]
+#[ts(export)]
+pub struct StatusPageComponents {
+    #[serde(default)]
+    pub items: Vec<StatusPageComponent>,
+}
+
+impl From<Value> for StatusPageComponents {
+    fn from(value: Value) -> Self {
+        serde_json::from_value(value).unwrap_or_default()
+    }
+}
+
+impl StatusPageComponents {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.items.len() > MAXIMUM_COMPONENTS_PER_STATUS_PAGE {
+            return Err(format!(
+                "A status page cannot have more than {MAXIMUM_COMPONENTS_PER_STATUS_PAGE} components"
+            ));
+        }
+        if self.items.iter().any(|component| component.name.trim().is_empty()) {
+            return Err("Component names cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// A group of HTTP monitors and tasks that is displayed as a single line on a status page
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct StatusPageComponent {
+    pub name: String,
+    #[serde(default)]
+    pub http_monitor_ids: Vec<Uuid>,
+    #[serde(default)]
+    #[ts(type = "Array<string>")]
+    pub task_ids: Vec<TaskId>,
+}
+
+#[derive(sqlx::Type, Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[repr(i16)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum StatusPageVisibility {
+    /// Anyone can read the status page
+    Public = 0,
+    /// Only the members of the organization can read the status page
+    Private = 1,
+}
+
+impl From<i16> for StatusPageVisibility {
+    fn from(value: i16) -> Self {
+        match value {
+            0 => Self::Public,
+            1 => Self::Private,
+            _ => panic!("invalid StatusPageVisibility discriminant: {value}"),
+        }
+    }
+}
+
+/// Slugs are made of lowercase letters, digits and dashes, e.g. `acme-production`
+pub fn validate_status_page_slug(slug: &str) -> Result<(), String> {
+    let regex = Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").expect("valid slug regex");
+    if !(3..=64).contains(&slug.len()) || !regex.is_match(slug) {
+        return Err(format!(
+            "Invalid slug '{slug}': slugs must contain between 3 and 64 lowercase letters, digits or dashes"
+        ));
+    }
+    Ok(())
+}
+
+/// The status of a component, as displayed on a status page.
+/// Variants are ordered from the least to the most severe
+#[derive(Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub enum StatusPageComponentStatus {
+    /// None of the monitors and tasks of the component has a known status
+    Unknown,
+    Operational,
+    Degraded,
+    Outage,
+}
+
+impl StatusPageComponentStatus {
+    pub fn from_http_monitor_status(status: HttpMonitorStatus) -> Self {
+        match status {
+            HttpMonitorStatus::Up => Self::Operational,
+            HttpMonitorStatus::Recovering | HttpMonitorStatus::Suspicious => Self::Degraded,
+            HttpMonitorStatus::Down => Self::Outage,
+            HttpMonitorStatus::Unknown | HttpMonitorStatus::Inactive | HttpMonitorStatus::Archived => {
+                Self::Unknown
+            }
+        }
+    }
+
+    pub fn from_task_status(status: TaskStatus) -> Self {
+        match status {
+            TaskStatus::Healthy | TaskStatus::Running | TaskStatus::Due => Self::Operational,
+            TaskStatus::Late => Self::Degraded,
+            TaskStatus::Failing | TaskStatus::Absent => Self::Outage,
+            TaskStatus::Paused => Self::Unknown,
+        }
+    }
+
+    /// The most severe of several statuses. Unknown statuses are ignored, unless all the statuses are unknown
+    pub fn worst(statuses: impl IntoIterator<Item = Self>) -> Self {
+        statuses.into_iter().max().unwrap_or(Self::Unknown)
+    }
+}
+
+/// The uptime of a component during a day (UTC)
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct StatusPageUptimeBar {
+    #[ts(type = "string")]
+    pub date: NaiveDate,
+    /// Between 0 and 1. None if the component was not monitored during this day
+    pub uptime_ratio: Option<f64>,
+    pub downtime_seconds: i64,
+}
+
+/// Computes the daily uptime of a component over the last [`STATUS_PAGE_UPTIME_DAYS`] days, from the oldest to the most recent day.
+///
+/// # Arguments
+///
+/// * `monitored_since` - When the monitoring of the component started. Days before this date have no uptime
+/// * `downtimes` - The periods during which the component was down. Ongoing downtimes have no end
+/// * `now` - The current date. The current day is only counted until now
+pub fn compute_uptime_bars(
+    monitored_since: Option<DateTime<Utc>>,
+    downtimes: &[(DateTime<Utc>, Option<DateTime<Utc>>)],
+    now: DateTime<Utc>,
+) -> Vec<StatusPageUptimeBar> {
+    // merge overlapping downtimes so that they are not counted twice
+    let mut downtimes = downtimes
+        .iter()
+        .map(|(start, end)| (*start, end.unwrap_or(now).min(now)))
+        .filter(|(start, end)| start < end)
+        .collect::<Vec<_>>();
+    downtimes.sort();
+    let mut merged_downtimes: Vec<(DateTime<Utc>, DateTime<Utc>)> = vec![];
+    for (start, end) in downtimes {
+        match merged_downtimes.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged_downtimes.push((start, end)),
+        }
+    }
+
+    let today = now.date_naive();
+    (0..STATUS_PAGE_UPTIME_DAYS)
+        .rev()
+        .map(|days_ago| {
+            let date = today - Duration::days(days_ago);
+            let day_start = date.and_hms_opt(0, 0, 0).expect("valid time").and_utc();
+            let day_end = (day_start + Duration::days(1)).min(now);
+            let monitored_start = match monitored_since {
+                Some(monitored_since) if monitored_since < day_end => monitored_since.max(day_start),
+                _ => {
+                    return StatusPageUptimeBar {
+                        date,
+                        uptime_ratio: None,
+                        downtime_seconds: 0,
+                    }
+                }
+            };
+
+            let downtime_seconds = merged_downtimes
+                .iter()
+                .map(|(start, end)| {
+                    let overlap = (*end).min(day_end) - (*start).max(monitored_start);
+                    overlap.num_seconds().max(0)
+                })
+                .sum::<i64>();
+            let monitored_seconds = (day_end - monitored_start).num_seconds();
+
+            StatusPageUptimeBar {
+                date,
+                uptime_ratio: (monitored_seconds > 0)
+                    .then(|| 1.0 - downtime_seconds as f64 / monitored_seconds as f64),
+                downtime_seconds,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_slug_validation() {
+        assert!(validate_status_page_slug("acme").is_ok());
+        assert!(validate_status_page_slug("acme-production-2").is_ok());
+        assert!(validate_status_page_slug("ac").is_err());
+        assert!(validate_status_page_slug("Acme").is_err());
+        assert!(validate_status_page_slug("acme--prod").is_err());
+        assert!(validate_status_page_slug("-acme").is_err());
+        assert!(validate_status_page_slug("acme prod").is_err());
+    }
+
+    #[test]
+    fn test_worst_status() {
+        use StatusPageComponentStatus::*;
+        assert_eq!(StatusPageComponentStatus::worst([]), Unknown);
+        assert_eq!(StatusPageComponentStatus::worst([Unknown, Operational]), Operational);
+        assert_eq!(StatusPageComponentStatus::worst([Degraded, Operational]), Degraded);
+        assert_eq!(StatusPageComponentStatus::worst([Degraded, Outage, Unknown]), Outage);
+    }
+
+    #[test]
+    fn test_uptime_bars() {
+        let now = Utc.with_ymd_and_hms(2024, 12, 9, 12, 0, 0).unwrap();
+        let yesterday = Utc.with_ymd_and_hms(2024, 12, 8, 0, 0, 0).unwrap();
+        let downtimes = [
+            // 6 hours of downtime yesterday, reported by two overlapping incidents
+            (yesterday + Duration::hours(6), Some(yesterday + Duration::hours(10))),
+            (yesterday + Duration::hours(8), Some(yesterday + Duration::hours(12))),
+            // an ongoing downtime that started one hour ago
+            (now - Duration::hours(1), None),
+        ];
+
+        let bars = compute_uptime_bars(Some(yesterday - Duration::days(1)), &downtimes, now);
+        assert_eq!(bars.len(), STATUS_PAGE_UPTIME_DAYS as usize);
+
+        let today_bar = &bars[bars.len() - 1];
+        assert_eq!(today_bar.date, now.date_naive());
+        assert_eq!(today_bar.downtime_seconds, 3600);
+        assert_eq!(today_bar.uptime_ratio, Some(1.0 - 1.0 / 12.0));
+
+        let yesterday_bar = &bars[bars.len() - 2];
+        assert_eq!(yesterday_bar.downtime_seconds, 6 * 3600);
+        assert_eq!(yesterday_bar.uptime_ratio, Some(0.75));
+
+        assert_eq!(bars[bars.len() - 3].uptime_ratio, Some(1.0));
+        // the component was not monitored before
+        assert_eq!(bars[bars.len() - 4].uptime_ratio, None);
+    }
+}
@@ -7,14 +7,8 @@ pub struct AbsentTaskAggregate {
 
 
 impl AbsentTaskAggregate {
-    pub fn start(self, now: DateTime<Utc>) -> Result<RunningTaskAggregate, TaskAggregateError> {
+    pub fn start(self, now: DateTime<Utc>, run_id: String) -> Result<RunningTaskAggregate, TaskAggregateError> {
         let task = self.task.start(now)?;
-        let task_run = RunningTaskRun::new(
-            *task.base().organization_id(),
-            task.base().id().clone(),
-            now,
-            *task.base().heartbeat_timeout(),
-        );
-        Ok(RunningTaskAggregate { task, task_run })
+        Ok(RunningTaskAggregate::new(task, run_id, now))
     }
 }
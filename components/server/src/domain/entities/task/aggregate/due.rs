@@ -1,4 +1,4 @@
-use super::{DueTask, LateTaskAggregate, RunningTaskAggregate, RunningTaskRun, TaskAggregateError};
+use super::{DueTask, HealthyTaskAggregate, LateTaskAggregate, RunningTaskAggregate, TaskAggregateError};
 use chrono::{DateTime, Utc};
 
 pub struct DueTaskAggregate {
@@ -13,15 +13,17 @@ impl DueTaskAggregate {
         })
     }
 
+    /// State transition: Due -> Healthy, when the expected run is skipped because an upstream task failed
+    pub fn skip(self, now: DateTime<Utc>) -> Result<HealthyTaskAggregate, TaskAggregateError> {
+        Ok(HealthyTaskAggregate {
+            task: self.task.skip(now)?,
+            last_task_run: None,
+        })
+    }
+
     /// State transition: Due -> Running
-    pub fn start(self, now: DateTime<Utc>) -> Result<RunningTaskAggregate, TaskAggregateError> {
+    pub fn start(self, now: DateTime<Utc>, run_id: String) -> Result<RunningTaskAggregate, TaskAggregateError> {
         let task = self.task.start(now)?;
-        let task_run = RunningTaskRun::new(
-            *task.base().organization_id(),
-            task.base().id().clone(),
-            now,
-            *task.base().heartbeat_timeout(),
-        );
-        Ok(RunningTaskAggregate { task, task_run })
+        Ok(RunningTaskAggregate::new(task, run_id, now))
     }
 }
\ No newline at end of file
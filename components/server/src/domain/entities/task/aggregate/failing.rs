@@ -3,9 +3,11 @@ use chrono::{DateTime, Utc};
 use super::*;
 
 /// These are the only states a task run can be in for the related task to be failing
+#[derive(Debug, Clone)]
 pub enum FailingTaskRun {
     Failed(FailedTaskRun),
     Dead(DeadTaskRun),
+    TimedOut(TimedOutTaskRun),
 }
 
 impl From<FailingTaskRun> for BoundaryTaskRun {
@@ -13,6 +15,7 @@ impl From<FailingTaskRun> for BoundaryTaskRun {
         match failing {
             FailingTaskRun::Failed(f) => f.into(),
             FailingTaskRun::Dead(d) => d.into(),
+            FailingTaskRun::TimedOut(t) => t.into(),
         }
     }
 }
@@ -26,15 +29,18 @@ pub struct FailingTaskAggregate {
 impl FailingTaskAggregate {
     /// State transition: Failing -> Running
     /// Returns the new running task aggregate and the task run that was in the failing state
-    pub fn start(self, now: DateTime<Utc>) -> Result<(RunningTaskAggregate, FailingTaskRun), TaskAggregateError> {
+    pub fn start(self, now: DateTime<Utc>, run_id: String) -> Result<(RunningTaskAggregate, FailingTaskRun), TaskAggregateError> {
         let task = self.task.start(now)?;
-        let task_run = RunningTaskRun::new(
-            *task.base().organization_id(),
-            task.base().id().clone(),
-            now,
-            *task.base().heartbeat_timeout(),
-        );
-        Ok((RunningTaskAggregate { task, task_run }, self.task_run))
+        Ok((RunningTaskAggregate::new(task, run_id, now), self.task_run))
+    }
+
+    /// Whether the task is failing because its last run exceeded the maximum duration of the task.
+    /// When a run id is given, the timed out run must be the run with this id
+    pub fn has_timed_out(&self, run_id: Option<&str>) -> bool {
+        match &self.task_run {
+            FailingTaskRun::TimedOut(task_run) => run_id.is_none_or(|run_id| task_run.run_id() == run_id),
+            _ => false,
+        }
     }
 
     /// State transition: Failing -> Due
@@ -42,4 +48,10 @@ impl FailingTaskAggregate {
         let task = self.task.mark_due(now)?;
         Ok(DueTaskAggregate { task })
     }
+
+    /// State transition: Failing -> Due, when the upstream tasks of the task finished
+    pub fn mark_due_after_upstream_tasks(self, now: DateTime<Utc>) -> Result<DueTaskAggregate, TaskAggregateError> {
+        let task = self.task.mark_due_after_upstream_tasks(now)?;
+        Ok(DueTaskAggregate { task })
+    }
 }
@@ -33,15 +33,9 @@ impl HealthyTaskAggregate {
 
     /// State transition: Healthy -> Running
     /// Returns the new running task aggregate and the task run that was in the healthy state
-    pub fn start(self, now: DateTime<Utc>) -> Result<(RunningTaskAggregate, Option<HealthyTaskRun>), TaskAggregateError> {
+    pub fn start(self, now: DateTime<Utc>, run_id: String) -> Result<(RunningTaskAggregate, Option<HealthyTaskRun>), TaskAggregateError> {
         let task = self.task.start(now)?;
-        let task_run = RunningTaskRun::new(
-            *task.base().organization_id(),
-            task.base().id().clone(),
-            now,
-            *task.base().heartbeat_timeout(),
-        );
-        Ok((RunningTaskAggregate { task, task_run }, self.last_task_run))
+        Ok((RunningTaskAggregate::new(task, run_id, now), self.last_task_run))
     }
 
     /// State transition: Healthy -> Due
@@ -49,5 +43,11 @@ impl HealthyTaskAggregate {
         let task = self.task.mark_due(now)?;
         Ok(DueTaskAggregate { task })
     }
+
+    /// State transition: Healthy -> Due, when the upstream tasks of the task finished
+    pub fn mark_due_after_upstream_tasks(self, now: DateTime<Utc>) -> Result<DueTaskAggregate, TaskAggregateError> {
+        let task = self.task.mark_due_after_upstream_tasks(now)?;
+        Ok(DueTaskAggregate { task })
+    }
 }
 
@@ -1,6 +1,6 @@
 use super::{
-    AbsentTaskAggregate, LateTask, RunningTaskAggregate,
-    RunningTaskRun, TaskAggregateError, TaskStatus,
+    AbsentTaskAggregate, HealthyTaskAggregate, LateTask, RunningTaskAggregate,
+    TaskAggregateError, TaskStatus,
 };
 use chrono::{DateTime, Utc};
 
@@ -10,15 +10,9 @@ pub struct LateTaskAggregate {
 
 impl LateTaskAggregate {
     /// State transition: Late -> Running
-    pub fn start(self, now: DateTime<Utc>) -> Result<RunningTaskAggregate, TaskAggregateError> {
+    pub fn start(self, now: DateTime<Utc>, run_id: String) -> Result<RunningTaskAggregate, TaskAggregateError> {
         let task = self.task.start(now)?;
-        let task_run = RunningTaskRun::new(
-            *task.base().organization_id(),
-            task.base().id().clone(),
-            now,
-            *task.base().heartbeat_timeout(),
-        );
-        Ok(RunningTaskAggregate { task, task_run })
+        Ok(RunningTaskAggregate::new(task, run_id, now))
     }
 
     /// Checks whether the task should transition to the absent state
@@ -27,6 +21,14 @@ impl LateTaskAggregate {
         self.task.is_absent(now)
     }
 
+    /// State transition: Late -> Healthy, when the expected run is skipped because an upstream task failed
+    pub fn skip(self, now: DateTime<Utc>) -> Result<HealthyTaskAggregate, TaskAggregateError> {
+        Ok(HealthyTaskAggregate {
+            task: self.task.skip(now)?,
+            last_task_run: None,
+        })
+    }
+
     /// State transition: Late -> Absent
     pub fn mark_absent(
         self,
@@ -3,6 +3,7 @@ mod due;
 mod failing;
 mod healthy;
 mod late;
+mod paused;
 mod running;
 
 pub use absent::*;
@@ -11,15 +12,18 @@ pub use due::*;
 pub use failing::*;
 pub use healthy::*;
 pub use late::*;
+pub use paused::*;
 pub use running::*;
 
 use crate::domain::{
     entities::{task::*, task_run::*},
     ports::{
         task_repository::TaskRepository,
-        task_run_repository::TaskRunRepository,
+        task_run_repository::{ListTaskRunsOpts, TaskRunRepository},
     },
+    use_cases::tasks::UpdateTaskCommand,
 };
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -57,6 +61,96 @@ pub enum TaskAggregate {
     Healthy(HealthyTaskAggregate),
     /// A task that is absent, i.e. scheduled to run but did not start within the lateness window
     Absent(AbsentTaskAggregate),
+    /// A task that was paused by a user
+    Paused(PausedTaskAggregate),
+}
+
+impl TaskAggregate {
+    pub fn status(&self) -> TaskStatus {
+        match self {
+            TaskAggregate::Due(_) => TaskStatus::Due,
+            TaskAggregate::Late(_) => TaskStatus::Late,
+            TaskAggregate::Running(_) => TaskStatus::Running,
+            TaskAggregate::Failing(_) => TaskStatus::Failing,
+            TaskAggregate::Healthy(_) => TaskStatus::Healthy,
+            TaskAggregate::Absent(_) => TaskStatus::Absent,
+            TaskAggregate::Paused(_) => TaskStatus::Paused,
+        }
+    }
+
+    /// State transition: any state but Running and Paused -> Paused
+    pub fn pause(self, now: DateTime<Utc>) -> Result<PausedTaskAggregate, TaskAggregateError> {
+        let task = match self {
+            TaskAggregate::Healthy(a) => a.task.pause(now)?,
+            TaskAggregate::Failing(a) => a.task.pause(now)?,
+            TaskAggregate::Due(a) => a.task.pause(now)?,
+            TaskAggregate::Late(a) => a.task.pause(now)?,
+            TaskAggregate::Absent(a) => a.task.pause(now)?,
+            TaskAggregate::Running(_) => {
+                return Err(TaskAggregateError::InvalidStateTransition {
+                    from: (TaskStatus::Running, Some(TaskRunStatus::Running)),
+                    to: (TaskStatus::Paused, None),
+                    details: "a running task cannot be paused".to_string(),
+                })
+            }
+            TaskAggregate::Paused(_) => {
+                return Err(TaskAggregateError::InvalidStateTransition {
+                    from: (TaskStatus::Paused, None),
+                    to: (TaskStatus::Paused, None),
+                    details: "the task is already paused".to_string(),
+                })
+            }
+        };
+        Ok(PausedTaskAggregate { task })
+    }
+
+    /// Changes the settings of the task. A due, late or absent task whose cron schedule changes becomes healthy,
+    /// since it is no longer expected to run at the previous due date
+    pub fn update(
+        self,
+        now: DateTime<Utc>,
+        command: UpdateTaskCommand,
+    ) -> Result<TaskAggregate, TaskAggregateError> {
+        fn from_scheduled<T>(
+            task: UpdatedScheduledTask<T>,
+            unchanged: impl FnOnce(T) -> TaskAggregate,
+        ) -> TaskAggregate {
+            match task {
+                UpdatedScheduledTask::Unchanged(task) => unchanged(task),
+                UpdatedScheduledTask::Rescheduled(task) => TaskAggregate::Healthy(HealthyTaskAggregate {
+                    task: *task,
+                    last_task_run: None,
+                }),
+            }
+        }
+
+        Ok(match self {
+            TaskAggregate::Healthy(a) => TaskAggregate::Healthy(HealthyTaskAggregate {
+                task: a.task.update(now, command)?,
+                last_task_run: a.last_task_run,
+            }),
+            TaskAggregate::Failing(a) => TaskAggregate::Failing(FailingTaskAggregate {
+                task: a.task.update(now, command)?,
+                task_run: a.task_run,
+            }),
+            TaskAggregate::Running(a) => TaskAggregate::Running(RunningTaskAggregate {
+                task: a.task.update(now, command)?,
+                ..a
+            }),
+            TaskAggregate::Paused(a) => TaskAggregate::Paused(PausedTaskAggregate {
+                task: a.task.update(command)?,
+            }),
+            TaskAggregate::Due(a) => from_scheduled(a.task.update(now, command)?, |task| {
+                TaskAggregate::Due(DueTaskAggregate { task })
+            }),
+            TaskAggregate::Late(a) => from_scheduled(a.task.update(now, command)?, |task| {
+                TaskAggregate::Late(LateTaskAggregate { task })
+            }),
+            TaskAggregate::Absent(a) => from_scheduled(a.task.update(now, command)?, |task| {
+                TaskAggregate::Absent(AbsentTaskAggregate { task })
+            }),
+        })
+    }
 }
 
 /// Retrieve a task aggregate from the database by its id
@@ -71,23 +165,68 @@ where
     TR: TaskRepository,
     TRR: TaskRunRepository<Transaction = TR::Transaction>,
 {
-    match task_repository
-        .get_task(tx, organization_id, task_id)
-        .await?
-    {
+    let task = task_repository.get_task(tx, organization_id, task_id).await?;
+    load_task_aggregate(task_run_repository, tx, task).await
+}
+
+/// Retrieve a task aggregate from the database by its id, and lock the task until the end of the transaction.
+/// This must be used when the new state of the task depends on its running runs (e.g. when a run starts or finishes),
+/// so that concurrent runs of the task are serialized
+pub async fn get_task_aggregate_for_update<TR, TRR>(
+    task_repository: &TR,
+    task_run_repository: &TRR,
+    tx: &mut TR::Transaction,
+    organization_id: Uuid,
+    task_id: &TaskId,
+) -> anyhow::Result<Option<TaskAggregate>>
+where
+    TR: TaskRepository,
+    TRR: TaskRunRepository<Transaction = TR::Transaction>,
+{
+    let task = task_repository.get_task_for_update(tx, organization_id, task_id).await?;
+    load_task_aggregate(task_run_repository, tx, task).await
+}
+
+async fn load_task_aggregate<TRR>(
+    task_run_repository: &TRR,
+    tx: &mut TRR::Transaction,
+    task: Option<BoundaryTask>,
+) -> anyhow::Result<Option<TaskAggregate>>
+where
+    TRR: TaskRunRepository,
+{
+    match task {
         Some(task) => {
+            let organization_id = task.organization_id;
+            let task_id = &task.id.clone();
             let aggregate: TaskAggregate = match task.status {
                 TaskStatus::Running => {
-                    let task_run = task_run_repository
+                    let mut task_runs = task_run_repository
+                        .list_task_runs(
+                            tx,
+                            organization_id,
+                            ListTaskRunsOpts {
+                                task_id,
+                                include_statuses: &[TaskRunStatus::Running],
+                                limit: MAXIMUM_MAX_CONCURRENT_RUNS,
+                                offset: 0,
+                            },
+                        )
+                        .await?
+                        .runs;
+                    // a run that failed while other runs of the task were running started after the task started running
+                    let failed_task_run = task_run_repository
                         .get_latest_task_run(
                             tx,
                             organization_id,
                             task_id,
-                            &[TaskRunStatus::Running],
+                            &[TaskRunStatus::Failed, TaskRunStatus::Dead, TaskRunStatus::TimedOut],
                         )
-                        .await?;
+                        .await?
+                        .filter(|r| task.last_status_change_at.is_some_and(|at| r.started_at >= at));
+                    task_runs.extend(failed_task_run);
 
-                    from_boundary(task, task_run)?
+                    from_boundary(task, task_runs)?
                 }
                 TaskStatus::Failing => {
                     let task_run = task_run_repository
@@ -95,15 +234,16 @@ where
                             tx,
                             organization_id,
                             task_id,
-                            &[TaskRunStatus::Failed, TaskRunStatus::Dead],
+                            &[TaskRunStatus::Failed, TaskRunStatus::Dead, TaskRunStatus::TimedOut],
                         )
                         .await?;
 
-                    from_boundary(task, task_run)?
+                    from_boundary(task, task_run.into_iter().collect())?
                 }
-                TaskStatus::Due => from_boundary(task, None)?,
-                TaskStatus::Late => from_boundary(task, None)?,
-                TaskStatus::Absent => from_boundary(task, None)?,
+                TaskStatus::Due => from_boundary(task, vec![])?,
+                TaskStatus::Late => from_boundary(task, vec![])?,
+                TaskStatus::Absent => from_boundary(task, vec![])?,
+                TaskStatus::Paused => from_boundary(task, vec![])?,
                 TaskStatus::Healthy => {
                     let last_task_run = task_run_repository
                         .get_latest_task_run(
@@ -114,7 +254,7 @@ where
                         )
                         .await?;
 
-                    from_boundary(task, last_task_run)?
+                    from_boundary(task, last_task_run.into_iter().collect())?
                 }
             };
 
@@ -124,39 +264,86 @@ where
     }
 }
 
+/// Persist a task aggregate to the database
+/// Returns the boundaries that were saved, so callers can use them to react to the new state of the task
 pub async fn save_task_aggregate<TR, TRR>(
     task_repository: &TR,
     task_run_repository: &TRR,
     tx: &mut TR::Transaction,
     aggregate: TaskAggregate,
-) -> anyhow::Result<()>
+) -> anyhow::Result<(BoundaryTask, Vec<BoundaryTaskRun>)>
 where
     TR: TaskRepository,
     TRR: TaskRunRepository<Transaction = TR::Transaction>,
 {
-    let (boundary_task, boundary_task_run) =
+    let (boundary_task, boundary_task_runs) =
         to_boundary(aggregate).context("failed to convert task aggregate to boundary")?;
     task_repository
-        .upsert_task(tx, boundary_task)
+        .upsert_task(tx, boundary_task.clone())
         .await
         .context("failed to upsert task to the database")?;
-    if let Some(boundary_task_run) = boundary_task_run {
+    for boundary_task_run in &boundary_task_runs {
         task_run_repository
-            .upsert_task_run(tx, boundary_task_run)
+            .upsert_task_run(tx, boundary_task_run.clone())
             .await
             .context("failed to upsert task run to the database")?;
     }
 
-    Ok(())
+    Ok((boundary_task, boundary_task_runs))
+}
+
+/// Persist the task of an aggregate, without its runs.
+/// This must be used when only the settings of the task changed, so that runs updated concurrently are not overwritten
+pub async fn save_task_settings<TR>(
+    task_repository: &TR,
+    tx: &mut TR::Transaction,
+    aggregate: TaskAggregate,
+) -> anyhow::Result<BoundaryTask>
+where
+    TR: TaskRepository,
+{
+    let (boundary_task, _) =
+        to_boundary(aggregate).context("failed to convert task aggregate to boundary")?;
+    task_repository
+        .upsert_task(tx, boundary_task.clone())
+        .await
+        .context("failed to upsert task to the database")?;
+
+    Ok(boundary_task)
+}
+
+/// Persist the aggregate of a task after one of its runs completed, along with the completed run
+/// Returns the boundaries of the task and of the completed run
+pub async fn save_task_run_completion<TR, TRR>(
+    task_repository: &TR,
+    task_run_repository: &TRR,
+    tx: &mut TR::Transaction,
+    completion: TaskRunCompletion,
+) -> anyhow::Result<(BoundaryTask, BoundaryTaskRun)>
+where
+    TR: TaskRepository,
+    TRR: TaskRunRepository<Transaction = TR::Transaction>,
+{
+    let (boundary_task, _) =
+        save_task_aggregate(task_repository, task_run_repository, tx, completion.aggregate).await?;
+    task_run_repository
+        .upsert_task_run(tx, completion.task_run.clone())
+        .await
+        .context("failed to upsert completed task run to the database")?;
+
+    Ok((boundary_task, completion.task_run))
 }
 
+/// Builds a task aggregate from the boundaries of a task and of its relevant runs:
+/// the last run of a healthy or failing task, or the running runs of a running task
+/// along with the run that failed while they were running
 pub fn from_boundary(
     boundary_task: BoundaryTask,
-    boundary_task_run: Option<BoundaryTaskRun>,
+    boundary_task_runs: Vec<BoundaryTaskRun>,
 ) -> anyhow::Result<TaskAggregate> {
     Ok(match boundary_task.status {
         TaskStatus::Healthy => TaskAggregate::Healthy(HealthyTaskAggregate {
-            last_task_run: match boundary_task_run {
+            last_task_run: match boundary_task_runs.into_iter().next() {
                 Some(r) if r.status == TaskRunStatus::Finished => Some(HealthyTaskRun::Finished(r.try_into()?)),
                 Some(r) if r.status == TaskRunStatus::Aborted => Some(HealthyTaskRun::Aborted(r.try_into()?)),
                 Some(r) => anyhow::bail!(TaskAggregateError::InconsistentTaskRunState {
@@ -169,9 +356,10 @@ pub fn from_boundary(
             task: boundary_task.try_into()?,
         }),
         TaskStatus::Failing => TaskAggregate::Failing(FailingTaskAggregate {
-            task_run: match boundary_task_run {
+            task_run: match boundary_task_runs.into_iter().next() {
                 Some(r) if r.status == TaskRunStatus::Failed => FailingTaskRun::Failed(r.try_into()?),
                 Some(r) if r.status == TaskRunStatus::Dead => FailingTaskRun::Dead(r.try_into()?),
+                Some(r) if r.status == TaskRunStatus::TimedOut => FailingTaskRun::TimedOut(r.try_into()?),
                 Some(r) => anyhow::bail!(TaskAggregateError::InconsistentTaskRunState {
                     task_id: boundary_task.id.clone(),
                     task_run_status: r.status,
@@ -181,10 +369,33 @@ pub fn from_boundary(
             },
             task: boundary_task.try_into()?,
         }),
-        TaskStatus::Running => TaskAggregate::Running(RunningTaskAggregate {
-            task: boundary_task.try_into()?,
-            task_run: boundary_task_run.ok_or_else(|| anyhow::anyhow!("Missing task run for running task"))?.try_into()?,
-        }),
+        TaskStatus::Running => {
+            let (mut running, failed): (Vec<_>, Vec<_>) = boundary_task_runs
+                .into_iter()
+                .partition(|r| r.status == TaskRunStatus::Running);
+            if running.is_empty() {
+                anyhow::bail!("Missing task run for running task");
+            }
+            running.sort_by_key(|r| r.started_at);
+            TaskAggregate::Running(RunningTaskAggregate {
+                task_runs: running
+                    .into_iter()
+                    .map(RunningTaskRun::try_from)
+                    .collect::<Result<_, _>>()?,
+                failed_task_run: match failed.into_iter().next() {
+                    Some(r) if r.status == TaskRunStatus::Failed => Some(FailingTaskRun::Failed(r.try_into()?)),
+                    Some(r) if r.status == TaskRunStatus::Dead => Some(FailingTaskRun::Dead(r.try_into()?)),
+                    Some(r) if r.status == TaskRunStatus::TimedOut => Some(FailingTaskRun::TimedOut(r.try_into()?)),
+                    Some(r) => anyhow::bail!(TaskAggregateError::InconsistentTaskRunState {
+                        task_id: boundary_task.id.clone(),
+                        task_run_status: r.status,
+                        details: "invalid task run status for running task".to_string(),
+                    }),
+                    None => None,
+                },
+                task: boundary_task.try_into()?,
+            })
+        }
         TaskStatus::Due => TaskAggregate::Due(DueTaskAggregate {
             task: boundary_task.try_into()?,
         }),
@@ -194,21 +405,30 @@ pub fn from_boundary(
         TaskStatus::Absent => TaskAggregate::Absent(AbsentTaskAggregate {
             task: boundary_task.try_into()?,
         }),
+        TaskStatus::Paused => TaskAggregate::Paused(PausedTaskAggregate {
+            task: boundary_task.try_into()?,
+        }),
     })
 }
 
+/// Converts a task aggregate to the boundaries of the task and of the runs it holds.
+/// The run that failed while other runs of a running task were running is not included, it was saved when it failed
 pub fn to_boundary(
     aggregate: TaskAggregate,
-) -> anyhow::Result<(BoundaryTask, Option<BoundaryTaskRun>)> {
+) -> anyhow::Result<(BoundaryTask, Vec<BoundaryTaskRun>)> {
     Ok(match aggregate {
-        TaskAggregate::Due(d) => (d.task.try_into()?, None),
-        TaskAggregate::Late(l) => (l.task.try_into()?, None),
-        TaskAggregate::Running(r) => (r.task.try_into()?, Some(r.task_run.into())),
-        TaskAggregate::Failing(f) => (f.task.try_into()?, Some(f.task_run.into())),
+        TaskAggregate::Due(d) => (d.task.try_into()?, vec![]),
+        TaskAggregate::Late(l) => (l.task.try_into()?, vec![]),
+        TaskAggregate::Running(r) => (
+            r.task.try_into()?,
+            r.task_runs.into_iter().map(|tr| tr.into()).collect(),
+        ),
+        TaskAggregate::Failing(f) => (f.task.try_into()?, vec![f.task_run.into()]),
         TaskAggregate::Healthy(h) => (
             h.task.try_into()?,
-            h.last_task_run.map(|lr| lr.into()),
+            h.last_task_run.into_iter().map(|lr| lr.into()).collect(),
         ),
-        TaskAggregate::Absent(a) => (a.task.try_into()?, None),
+        TaskAggregate::Absent(a) => (a.task.try_into()?, vec![]),
+        TaskAggregate::Paused(p) => (p.task.try_into()?, vec![]),
     })
 }
@@ -0,0 +1,17 @@
+use super::*;
+use chrono::{DateTime, Utc};
+
+/// A task that was paused by a user. It has no associated task run
+pub struct PausedTaskAggregate {
+    pub(super) task: PausedTask,
+}
+
+impl PausedTaskAggregate {
+    /// State transition: Paused -> Healthy
+    pub fn resume(self, now: DateTime<Utc>) -> Result<HealthyTaskAggregate, TaskAggregateError> {
+        Ok(HealthyTaskAggregate {
+            task: self.task.resume(now)?,
+            last_task_run: None,
+        })
+    }
+}
@@ -1,86 +1,388 @@
 use super::{
-    FailingTaskAggregate, FailingTaskRun, HealthyTaskAggregate, HealthyTaskRun, RunningTask,
-    RunningTaskRun, TaskAggregateError, TaskRunStatus, TaskStatus,
+    BoundaryTaskRun, FailingTaskAggregate, FailingTaskRun, HealthyTaskAggregate, HealthyTaskRun, RunningTask,
+    RunningTaskRun, TaskAggregate, TaskAggregateError, TaskRunStatus, TaskStatus,
 };
 use chrono::{DateTime, Utc};
 
 /// A task that is currently running
-/// This task has an associated running task run
+/// This task has at least one associated running task run, and at most the maximum number of concurrent runs of the task
 #[derive(Debug, Clone)]
 pub struct RunningTaskAggregate {
     pub(super) task: RunningTask,
-    pub(super) task_run: RunningTaskRun,
+    /// The running task runs, from the oldest to the most recent one
+    pub(super) task_runs: Vec<RunningTaskRun>,
+    /// The last run that failed while other runs of the task were still running.
+    /// The task becomes failing when its last running run completes, whatever the outcome of that run
+    pub(super) failed_task_run: Option<FailingTaskRun>,
+}
+
+/// The state of a task after one of its runs completed, along with the completed run
+pub struct TaskRunCompletion {
+    /// The task is still running if other runs are running, otherwise it is healthy or failing
+    pub aggregate: TaskAggregate,
+    pub task_run: BoundaryTaskRun,
+}
+
+/// A run that completed, with the states in which a run can leave its task
+enum CompletedTaskRun {
+    Healthy(HealthyTaskRun),
+    Failing(FailingTaskRun),
 }
 
 impl RunningTaskAggregate {
+    pub(super) fn new(task: RunningTask, run_id: String, now: DateTime<Utc>) -> Self {
+        let task_run = RunningTaskRun::new(
+            *task.base().organization_id(),
+            task.base().id().clone(),
+            run_id,
+            now,
+            *task.base().heartbeat_timeout(),
+        );
+        RunningTaskAggregate {
+            task,
+            task_runs: vec![task_run],
+            failed_task_run: None,
+        }
+    }
+
+    /// The running task runs, from the oldest to the most recent one
+    pub fn task_runs(&self) -> &[RunningTaskRun] {
+        &self.task_runs
+    }
+
+    pub fn is_running(&self, run_id: &str) -> bool {
+        self.task_runs.iter().any(|task_run| task_run.run_id() == run_id)
+    }
+
+    /// Whether another run can start without exceeding the maximum number of concurrent runs of the task
+    pub fn can_start_run(&self) -> bool {
+        self.task_runs.len() < *self.task.base().max_concurrent_runs() as usize
+    }
+
+    /// Starts another run of the task, running concurrently with the runs that are already running
+    pub fn start_run(mut self, now: DateTime<Utc>, run_id: String) -> Result<RunningTaskAggregate, TaskAggregateError> {
+        if !self.can_start_run() {
+            return Err(TaskAggregateError::InvalidStateTransition {
+                from: (TaskStatus::Running, Some(TaskRunStatus::Running)),
+                to: (TaskStatus::Running, Some(TaskRunStatus::Running)),
+                details: "the task already runs its maximum number of concurrent runs".to_string(),
+            });
+        }
+        if self.is_running(&run_id) {
+            return Err(TaskAggregateError::InvalidStateTransition {
+                from: (TaskStatus::Running, Some(TaskRunStatus::Running)),
+                to: (TaskStatus::Running, Some(TaskRunStatus::Running)),
+                details: format!("a run with the id {run_id} is already running"),
+            });
+        }
+        self.task_runs.push(RunningTaskRun::new(
+            *self.task.base().organization_id(),
+            self.task.base().id().clone(),
+            run_id,
+            now,
+            *self.task.base().heartbeat_timeout(),
+        ));
+        Ok(self)
+    }
+
     pub fn receive_heartbeat(
-        self,
+        mut self,
+        run_id: Option<&str>,
         now: DateTime<Utc>,
     ) -> Result<RunningTaskAggregate, TaskAggregateError> {
-        Ok(RunningTaskAggregate {
-            task_run: self.task_run.receive_heartbeat(now)?,
-            ..self
-        })
+        let index = self.task_run_index(run_id)?;
+        let task_run = self.task_runs.remove(index);
+        self.task_runs.insert(index, task_run.receive_heartbeat(now)?);
+        Ok(self)
+    }
+
+    pub fn is_dead(&self, run_id: &str, now: DateTime<Utc>) -> bool {
+        self.task_runs
+            .iter()
+            .find(|task_run| task_run.run_id() == run_id)
+            .is_some_and(|task_run| now >= *task_run.last_heartbeat_at() + self.task.heartbeat_timeout())
+    }
+
+    pub fn is_timed_out(&self, run_id: &str, now: DateTime<Utc>) -> bool {
+        let Some(max_duration) = self.task.max_duration() else {
+            return false;
+        };
+        self.task_runs
+            .iter()
+            .find(|task_run| task_run.run_id() == run_id)
+            .is_some_and(|task_run| now >= *task_run.started_at() + max_duration)
     }
 
-    pub fn is_dead(&self, now: DateTime<Utc>) -> bool {
-        now >= *self.task_run.last_heartbeat_at() + self.task.heartbeat_timeout()
+    /// Whether the run with the given id exceeded the maximum duration of the task while other runs kept running
+    pub fn has_timed_out(&self, run_id: &str) -> bool {
+        matches!(&self.failed_task_run, Some(FailingTaskRun::TimedOut(task_run)) if task_run.run_id() == run_id)
     }
 
-    /// State transition: Running -> Healthy
+    /// State transition: Running -> Healthy, or Running if other runs are running
     pub fn mark_finished(
         self,
+        run_id: Option<&str>,
         now: DateTime<Utc>,
         exit_code: Option<i32>,
-    ) -> Result<HealthyTaskAggregate, TaskAggregateError> {
-        Ok(HealthyTaskAggregate {
-            task: self.task.finish(now)?,
-            last_task_run: Some(HealthyTaskRun::Finished(
-                self.task_run.mark_finished(now, exit_code)?,
-            )),
+    ) -> Result<TaskRunCompletion, TaskAggregateError> {
+        self.complete_task_run(run_id, now, |task_run| {
+            Ok(CompletedTaskRun::Healthy(HealthyTaskRun::Finished(
+                task_run.mark_finished(now, exit_code)?,
+            )))
         })
     }
 
-    /// State transition: Running -> Failed
+    /// State transition: Running -> Failed, or Running if other runs are running
     pub fn mark_failed(
         self,
+        run_id: Option<&str>,
         now: DateTime<Utc>,
         exit_code: Option<i32>,
         error_message: Option<String>,
-    ) -> Result<FailingTaskAggregate, TaskAggregateError> {
-        Ok(FailingTaskAggregate {
-            task: self.task.fail(now)?,
-            task_run: FailingTaskRun::Failed(self.task_run.mark_failed(
+    ) -> Result<TaskRunCompletion, TaskAggregateError> {
+        self.complete_task_run(run_id, now, |task_run| {
+            Ok(CompletedTaskRun::Failing(FailingTaskRun::Failed(task_run.mark_failed(
                 now,
                 exit_code,
                 error_message,
-            )?),
+            )?)))
         })
     }
 
-    /// State transition: Running -> Aborted
+    /// State transition: Running -> Aborted, or Running if other runs are running
     pub fn mark_aborted(
         self,
+        run_id: Option<&str>,
         now: DateTime<Utc>,
-    ) -> Result<HealthyTaskAggregate, TaskAggregateError> {
-        Ok(HealthyTaskAggregate {
-            task: self.task.abort(now)?,
-            last_task_run: Some(HealthyTaskRun::Aborted(self.task_run.mark_aborted(now)?)),
+    ) -> Result<TaskRunCompletion, TaskAggregateError> {
+        self.complete_task_run(run_id, now, |task_run| {
+            Ok(CompletedTaskRun::Healthy(HealthyTaskRun::Aborted(task_run.mark_aborted(now)?)))
         })
     }
 
-    /// State transition: Running -> Dead
-    pub fn mark_dead(self, now: DateTime<Utc>) -> Result<FailingTaskAggregate, TaskAggregateError> {
-        if !self.is_dead(now) {
+    /// State transition: Running -> Dead, or Running if other runs are running
+    pub fn mark_dead(self, run_id: &str, now: DateTime<Utc>) -> Result<TaskRunCompletion, TaskAggregateError> {
+        if !self.is_dead(run_id, now) {
             return Err(TaskAggregateError::InvalidStateTransition {
                 from: (TaskStatus::Running, Some(TaskRunStatus::Running)),
                 to: (TaskStatus::Failing, Some(TaskRunStatus::Dead)),
                 details: "task run is not dead".to_string(),
             });
         }
-        Ok(FailingTaskAggregate {
-            task: self.task.fail(now)?,
-            task_run: FailingTaskRun::Dead(self.task_run.mark_dead(now)?),
+        self.complete_task_run(Some(run_id), now, |task_run| {
+            Ok(CompletedTaskRun::Failing(FailingTaskRun::Dead(task_run.mark_dead(now)?)))
+        })
+    }
+
+    /// State transition: Running -> TimedOut, or Running if other runs are running
+    pub fn mark_timed_out(self, run_id: &str, now: DateTime<Utc>) -> Result<TaskRunCompletion, TaskAggregateError> {
+        let max_duration = match self.task.max_duration() {
+            Some(max_duration) if self.is_timed_out(run_id, now) => max_duration,
+            _ => {
+                return Err(TaskAggregateError::InvalidStateTransition {
+                    from: (TaskStatus::Running, Some(TaskRunStatus::Running)),
+                    to: (TaskStatus::Failing, Some(TaskRunStatus::TimedOut)),
+                    details: "task run has not exceeded the maximum duration of the task".to_string(),
+                })
+            }
+        };
+        self.complete_task_run(Some(run_id), now, |task_run| {
+            Ok(CompletedTaskRun::Failing(FailingTaskRun::TimedOut(
+                task_run.mark_timed_out(now, max_duration)?,
+            )))
         })
     }
+
+    /// The index of the running run with the given id, or of the oldest running run if no id is given
+    fn task_run_index(&self, run_id: Option<&str>) -> Result<usize, TaskAggregateError> {
+        match run_id {
+            None if !self.task_runs.is_empty() => Ok(0),
+            None => Err(TaskAggregateError::InconsistentTaskRunState {
+                task_id: self.task.base().id().clone(),
+                task_run_status: TaskRunStatus::Running,
+                details: "running task without running task run".to_string(),
+            }),
+            Some(run_id) => self
+                .task_runs
+                .iter()
+                .position(|task_run| task_run.run_id() == run_id)
+                .ok_or_else(|| TaskAggregateError::InvalidStateTransition {
+                    from: (TaskStatus::Running, None),
+                    to: (TaskStatus::Running, None),
+                    details: format!("no run with the id {run_id} is running"),
+                }),
+        }
+    }
+
+    /// Completes one of the running runs. The task keeps running until its last running run completes
+    fn complete_task_run(
+        mut self,
+        run_id: Option<&str>,
+        now: DateTime<Utc>,
+        complete: impl FnOnce(RunningTaskRun) -> Result<CompletedTaskRun, TaskAggregateError>,
+    ) -> Result<TaskRunCompletion, TaskAggregateError> {
+        let index = self.task_run_index(run_id)?;
+        let completed_task_run = complete(self.task_runs.remove(index))?;
+        let task_run: BoundaryTaskRun = match &completed_task_run {
+            CompletedTaskRun::Healthy(task_run) => task_run.clone().into(),
+            CompletedTaskRun::Failing(task_run) => task_run.clone().into(),
+        };
+
+        if !self.task_runs.is_empty() {
+            if let CompletedTaskRun::Failing(failed_task_run) = completed_task_run {
+                self.failed_task_run = Some(failed_task_run);
+            }
+            return Ok(TaskRunCompletion {
+                aggregate: TaskAggregate::Running(self),
+                task_run,
+            });
+        }
+
+        let aggregate = match (completed_task_run, self.failed_task_run) {
+            (CompletedTaskRun::Failing(failed_task_run), _)
+            | (CompletedTaskRun::Healthy(_), Some(failed_task_run)) => TaskAggregate::Failing(FailingTaskAggregate {
+                task: self.task.fail(now)?,
+                task_run: failed_task_run,
+            }),
+            (CompletedTaskRun::Healthy(HealthyTaskRun::Finished(finished)), None) => {
+                TaskAggregate::Healthy(HealthyTaskAggregate {
+                    task: self.task.finish(now)?,
+                    last_task_run: Some(HealthyTaskRun::Finished(finished)),
+                })
+            }
+            (CompletedTaskRun::Healthy(HealthyTaskRun::Aborted(aborted)), None) => {
+                TaskAggregate::Healthy(HealthyTaskAggregate {
+                    task: self.task.abort(now)?,
+                    last_task_run: Some(HealthyTaskRun::Aborted(aborted)),
+                })
+            }
+        };
+        Ok(TaskRunCompletion { aggregate, task_run })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    use crate::domain::entities::{
+        task::{from_boundary, to_boundary, BoundaryTask, TaskAggregate, TaskStatus},
+        task_run::{BoundaryTaskRun, TaskRunStatus},
+    };
+
+    fn running_task_aggregate(
+        max_duration_seconds: Option<i32>,
+        max_concurrent_runs: i32,
+    ) -> super::RunningTaskAggregate {
+        let now = Utc::now();
+        let task = BoundaryTask {
+            previous_status: Some(TaskStatus::Healthy),
+            last_status_change_at: Some(now),
+            max_duration_seconds,
+            max_concurrent_runs,
+            ..BoundaryTask::test_task(Uuid::new_v4(), "nightly-backup", TaskStatus::Running)
+        };
+        let task_run = BoundaryTaskRun {
+            organization_id: task.organization_id,
+            task_id: task.id.clone(),
+            run_id: "first".to_string(),
+            status: TaskRunStatus::Running,
+            started_at: now - Duration::minutes(10),
+            updated_at: now,
+            completed_at: None,
+            exit_code: None,
+            error_message: None,
+            last_heartbeat_at: Some(now),
+            heartbeat_timeout_seconds: 60,
+        };
+        match from_boundary(task, vec![task_run]).unwrap() {
+            TaskAggregate::Running(aggregate) => aggregate,
+            _ => panic!("expected a running task aggregate"),
+        }
+    }
+
+    #[test]
+    fn test_mark_timed_out_after_max_duration() {
+        let aggregate = running_task_aggregate(Some(300), 1);
+        assert!(aggregate.is_timed_out("first", Utc::now()));
+
+        let completion = aggregate.mark_timed_out("first", Utc::now()).unwrap();
+        assert_eq!(completion.task_run.status, TaskRunStatus::TimedOut);
+        let TaskAggregate::Failing(failing) = completion.aggregate else {
+            panic!("expected a failing task aggregate");
+        };
+        assert!(failing.has_timed_out(None));
+        let (task, task_runs) = to_boundary(TaskAggregate::Failing(failing)).unwrap();
+        assert_eq!(task.status, TaskStatus::Failing);
+        assert_eq!(task_runs[0].status, TaskRunStatus::TimedOut);
+    }
+
+    #[test]
+    fn test_cannot_mark_timed_out_before_max_duration() {
+        let aggregate = running_task_aggregate(Some(3600), 1);
+        assert!(!aggregate.is_timed_out("first", Utc::now()));
+        assert!(aggregate.mark_timed_out("first", Utc::now()).is_err());
+
+        // runs of tasks without a maximum duration never time out
+        let aggregate = running_task_aggregate(None, 1);
+        assert!(!aggregate.is_timed_out("first", Utc::now() + Duration::days(365)));
+        assert!(aggregate.mark_timed_out("first", Utc::now()).is_err());
+    }
+
+    #[test]
+    fn test_concurrent_runs_up_to_the_maximum() {
+        let now = Utc::now();
+        let aggregate = running_task_aggregate(None, 2);
+        assert!(aggregate.can_start_run());
+        // run ids are unique among the running runs of a task
+        assert!(aggregate.clone().start_run(now, "first".to_string()).is_err());
+
+        let aggregate = aggregate.start_run(now, "second".to_string()).unwrap();
+        assert!(aggregate.is_running("first") && aggregate.is_running("second"));
+        assert!(!aggregate.can_start_run());
+        assert!(aggregate.clone().start_run(now, "third".to_string()).is_err());
+
+        let aggregate = aggregate.receive_heartbeat(Some("second"), now).unwrap();
+        assert!(aggregate.clone().receive_heartbeat(Some("third"), now).is_err());
+
+        // the task keeps running until its last run completes
+        let completion = aggregate.mark_finished(Some("second"), now, Some(0)).unwrap();
+        assert_eq!(completion.task_run.run_id, "second");
+        assert_eq!(completion.task_run.status, TaskRunStatus::Finished);
+        let TaskAggregate::Running(aggregate) = completion.aggregate else {
+            panic!("expected a running task aggregate");
+        };
+        assert_eq!(aggregate.task_runs().len(), 1);
+
+        let completion = aggregate.mark_finished(None, now, None).unwrap();
+        assert_eq!(completion.task_run.run_id, "first");
+        assert_eq!(completion.aggregate.status(), TaskStatus::Healthy);
+    }
+
+    #[test]
+    fn test_concurrent_run_failure_fails_the_task_once_all_runs_completed() {
+        let now = Utc::now();
+        let aggregate = running_task_aggregate(Some(300), 2)
+            .start_run(now, "second".to_string())
+            .unwrap();
+        // only the first run exceeded the maximum duration
+        assert!(!aggregate.is_timed_out("second", now));
+
+        let completion = aggregate.mark_timed_out("first", now).unwrap();
+        assert_eq!(completion.task_run.status, TaskRunStatus::TimedOut);
+        let TaskAggregate::Running(aggregate) = completion.aggregate else {
+            panic!("expected a running task aggregate");
+        };
+        assert!(aggregate.has_timed_out("first"));
+        assert!(!aggregate.is_running("first"));
+
+        // a successful last run does not hide the failure of another run
+        let completion = aggregate.mark_finished(Some("second"), now, Some(0)).unwrap();
+        assert_eq!(completion.task_run.status, TaskRunStatus::Finished);
+        let (task, task_runs) = to_boundary(completion.aggregate).unwrap();
+        assert_eq!(task.status, TaskStatus::Failing);
+        assert_eq!(task_runs[0].run_id, "first");
+        assert_eq!(task_runs[0].status, TaskRunStatus::TimedOut);
+    }
 }
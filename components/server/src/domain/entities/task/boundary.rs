@@ -1,9 +1,12 @@
 use chrono::{DateTime, Utc};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domain::entities::entity_metadata::EntityMetadata;
+
 use super::id::TaskId;
 
 #[derive(Debug, Serialize, Deserialize, TS, ToSchema, Clone)]
@@ -14,6 +17,8 @@ use super::id::TaskId;
 pub struct BoundaryTask {
     #[ts(type = "string")]
     pub id: TaskId,
+    /// An internal identifier, used to reference the task from other entities (e.g. incidents)
+    pub uuid: Uuid,
     pub organization_id: Uuid,
     pub name: String,
     pub description: Option<String>,
@@ -25,9 +30,61 @@ pub struct BoundaryTask {
     pub start_window_seconds: i32,
     pub lateness_window_seconds: i32,
     pub heartbeat_timeout_seconds: i32,
+    /// The maximum duration of a run, after which the run is considered timed out. `None` if runs can last forever
+    pub max_duration_seconds: Option<i32>,
+    /// The maximum number of runs of the task that can be running at the same time
+    pub max_concurrent_runs: i32,
+    /// The tasks that must finish before this task is due, in addition to its cron schedule
+    #[ts(type = "Array<string>")]
+    pub upstream_task_ids: Vec<TaskId>,
+    /// The IANA time zone in which the cron schedule is evaluated (e.g. `Europe/Paris`)
+    pub time_zone: String,
+    pub metadata: EntityMetadata,
+    /// The channels through which users are notified of the incidents of the task
+    pub email_notification_enabled: bool,
+    pub push_notification_enabled: bool,
+    pub sms_notification_enabled: bool,
     pub created_at: DateTime<Utc>,
 }
 
+impl BoundaryTask {
+    /// An unscheduled task without runs. Tests override the fields they rely on with the struct update syntax
+    #[cfg(test)]
+    pub fn test_task(organization_id: Uuid, id: &str, status: TaskStatus) -> Self {
+        Self {
+            id: TaskId::new(id.to_string()).unwrap(),
+            uuid: Uuid::new_v4(),
+            organization_id,
+            name: id.to_string(),
+            description: None,
+            status,
+            previous_status: None,
+            last_status_change_at: Some(Utc::now()),
+            cron_schedule: None,
+            next_due_at: None,
+            start_window_seconds: 300,
+            lateness_window_seconds: 600,
+            heartbeat_timeout_seconds: 60,
+            max_duration_seconds: None,
+            max_concurrent_runs: 1,
+            upstream_task_ids: vec![],
+            time_zone: "UTC".to_string(),
+            metadata: EntityMetadata::default(),
+            email_notification_enabled: true,
+            push_notification_enabled: true,
+            sms_notification_enabled: false,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Generates a random 256-bit ping token, encoded as an hexadecimal string.
+/// The ping token authenticates the requests to the ping URLs of a task (`/ping/:token`)
+pub fn generate_task_ping_token() -> String {
+    let mut rng = rand::thread_rng();
+    hex::encode((0..32).map(|_| rng.gen::<u8>()).collect::<Vec<_>>())
+}
+
 /// An enum that represents the status of a task run
 #[derive(sqlx::Type, Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
 #[repr(i16)]
@@ -46,6 +103,8 @@ pub enum TaskStatus {
     Late = 4,
     /// The task was expected to start but has not started and the lateness window has passed
     Absent = 5,
+    /// The task was paused by a user, it is not expected to run until it is resumed
+    Paused = 6,
 }
 
 impl From<i16> for TaskStatus {
@@ -57,6 +116,7 @@ impl From<i16> for TaskStatus {
             3 => Self::Due,
             4 => Self::Late,
             5 => Self::Absent,
+            6 => Self::Paused,
             _ => panic!("invalid TaskStatus discriminant: {value}"),
         }
     }
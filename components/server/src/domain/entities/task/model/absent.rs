@@ -7,19 +7,43 @@ pub struct AbsentTask {
     // in the context of an absent task, the next_due_at field is the last time the task was due to run before it was marked absent,
     // i.e. the time of the expected run that never happened
     pub(super) next_due_at: DateTime<Utc>,
-    // absent tasks have a cron schedule
+    // absent tasks have a cron schedule, or upstream tasks that finished
     #[allow(unused)]
-    pub(super) cron_schedule: cron::Schedule,
+    pub(super) cron_schedule: Option<cron::Schedule>,
 }
 
 impl AbsentTask {
     /// State transition: Absent -> Running
     pub fn start(self, now: DateTime<Utc>) -> Result<RunningTask, TaskError> {
         Ok(RunningTask {
-            next_due_at: calculate_next_due_at(&self.base.cron_schedule, now)?,
+            next_due_at: calculate_next_due_at(&self.base.cron_schedule, self.base.time_zone, now)?,
             base: self.base,
         })
     }
+
+    pub fn update(
+        self,
+        now: DateTime<Utc>,
+        command: UpdateTaskCommand,
+    ) -> Result<UpdatedScheduledTask<AbsentTask>, TaskError> {
+        let (base, schedule_changed) = self.base.update(command)?;
+        if !schedule_changed {
+            return Ok(UpdatedScheduledTask::Unchanged(AbsentTask { base, ..self }));
+        }
+        Ok(UpdatedScheduledTask::Rescheduled(Box::new(HealthyTask {
+            next_due_at: calculate_next_due_at(&base.cron_schedule, base.time_zone, now)?,
+            base: TaskBase {
+                previous_status: Some(TaskStatus::Absent),
+                last_status_change_at: Some(now),
+                ..base
+            },
+        })))
+    }
+
+    /// State transition: Absent -> Paused
+    pub fn pause(self, now: DateTime<Utc>) -> Result<PausedTask, TaskError> {
+        Ok(self.base.pause(TaskStatus::Absent, now))
+    }
 }
 
 impl TryFrom<AbsentTask> for BoundaryTask {
@@ -52,12 +76,12 @@ impl TryFrom<BoundaryTask> for AbsentTask {
             })?;
 
         let base: TaskBase = boundary.try_into()?;
-        let cron_schedule = base
-            .cron_schedule
-            .clone()
-            .ok_or(TaskError::FailedToBuildFromBoundary {
-                details: "Cron schedule is required for absent task".to_string(),
-            })?;
+        if base.cron_schedule.is_none() && base.upstream_task_ids.is_empty() {
+            return Err(TaskError::FailedToBuildFromBoundary {
+                details: "Cron schedule or upstream tasks are required for absent task".to_string(),
+            });
+        }
+        let cron_schedule = base.cron_schedule.clone();
 
         Ok(AbsentTask {
             next_due_at,
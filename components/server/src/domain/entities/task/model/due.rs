@@ -5,15 +5,15 @@ use super::*;
 pub struct DueTask {
     pub(super) base: TaskBase,
     pub(super) next_due_at: DateTime<Utc>,
-    // due tasks have a cron schedule
-    pub(super) cron_schedule: cron::Schedule
+    // due tasks have a cron schedule, or upstream tasks that finished
+    pub(super) cron_schedule: Option<cron::Schedule>
 }
 
 impl DueTask {
     pub fn start(self, now: DateTime<Utc>) -> Result<RunningTask, TaskError> {
         Ok(RunningTask {
             // When a task starts, its next_due_at field is updated to the next time the task is due to run
-            next_due_at: calculate_next_due_at(&self.base.cron_schedule, now)?,
+            next_due_at: calculate_next_due_at(&self.base.cron_schedule, self.base.time_zone, now)?,
             base: TaskBase {
                 previous_status: Some(TaskStatus::Due),
                 last_status_change_at: Some(now),
@@ -44,6 +44,43 @@ impl DueTask {
             cron_schedule: self.cron_schedule,
         })
     }
+
+    pub fn update(
+        self,
+        now: DateTime<Utc>,
+        command: UpdateTaskCommand,
+    ) -> Result<UpdatedScheduledTask<DueTask>, TaskError> {
+        let (base, schedule_changed) = self.base.update(command)?;
+        if !schedule_changed {
+            return Ok(UpdatedScheduledTask::Unchanged(DueTask { base, ..self }));
+        }
+        Ok(UpdatedScheduledTask::Rescheduled(Box::new(HealthyTask {
+            next_due_at: calculate_next_due_at(&base.cron_schedule, base.time_zone, now)?,
+            base: TaskBase {
+                previous_status: Some(TaskStatus::Due),
+                last_status_change_at: Some(now),
+                ..base
+            },
+        })))
+    }
+
+    /// State transition: Due -> Healthy, when the expected run is skipped because an upstream task failed.
+    /// The task is expected again at the next date of its cron schedule, or when its upstream tasks finish
+    pub fn skip(self, now: DateTime<Utc>) -> Result<HealthyTask, TaskError> {
+        Ok(HealthyTask {
+            next_due_at: calculate_next_due_at(&self.base.cron_schedule, self.base.time_zone, now)?,
+            base: TaskBase {
+                previous_status: Some(TaskStatus::Due),
+                last_status_change_at: Some(now),
+                ..self.base
+            },
+        })
+    }
+
+    /// State transition: Due -> Paused
+    pub fn pause(self, now: DateTime<Utc>) -> Result<PausedTask, TaskError> {
+        Ok(self.base.pause(TaskStatus::Due, now))
+    }
 }
 
 impl TryFrom<DueTask> for BoundaryTask {
@@ -76,12 +113,12 @@ impl TryFrom<BoundaryTask> for DueTask {
                 details: "Next due at is required for due task".to_string(),
             })?;
         let base: TaskBase = boundary.try_into()?;
-        let cron_schedule = base
-            .cron_schedule
-            .clone()
-            .ok_or(TaskError::FailedToBuildFromBoundary {
-                details: "Cron schedule is required for due task".to_string(),
-            })?;
+        if base.cron_schedule.is_none() && base.upstream_task_ids.is_empty() {
+            return Err(TaskError::FailedToBuildFromBoundary {
+                details: "Cron schedule or upstream tasks are required for due task".to_string(),
+            });
+        }
+        let cron_schedule = base.cron_schedule.clone();
         Ok(DueTask {
             next_due_at,
             cron_schedule,
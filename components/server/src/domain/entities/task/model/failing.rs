@@ -12,7 +12,7 @@ impl FailingTask {
     pub fn start(self, now: DateTime<Utc>) -> Result<RunningTask, TaskError> {
         Ok(RunningTask {
             // When a task starts, its next_due_at field is updated to the next time the task is due to run
-            next_due_at: calculate_next_due_at(&self.base.cron_schedule, now)?,
+            next_due_at: calculate_next_due_at(&self.base.cron_schedule, self.base.time_zone, now)?,
             base: TaskBase {
                 previous_status: Some(TaskStatus::Failing),
                 last_status_change_at: Some(now),
@@ -45,7 +45,7 @@ impl FailingTask {
             // unwrap is safe because we already checked that the task is due to run,
             // so it must have a next_due_at
             next_due_at: self.next_due_at.unwrap(),
-            cron_schedule: self.base.cron_schedule.clone().unwrap(),
+            cron_schedule: self.base.cron_schedule.clone(),
             base: TaskBase {
                 previous_status: Some(TaskStatus::Failing),
                 last_status_change_at: Some(now),
@@ -53,6 +53,39 @@ impl FailingTask {
             },
         })
     }
+
+    /// State transition: Failing -> Due, when the upstream tasks of the task finished
+    pub fn mark_due_after_upstream_tasks(self, now: DateTime<Utc>) -> Result<DueTask, TaskError> {
+        if self.base.upstream_task_ids.is_empty() {
+            return Err(TaskError::InvalidStateTransition {
+                from: TaskStatus::Failing,
+                to: TaskStatus::Due,
+                details: "this task has no upstream tasks".to_string(),
+            });
+        }
+        Ok(DueTask {
+            cron_schedule: self.base.cron_schedule.clone(),
+            next_due_at: now,
+            base: TaskBase {
+                previous_status: Some(TaskStatus::Failing),
+                last_status_change_at: Some(now),
+                ..self.base
+            },
+        })
+    }
+
+    pub fn update(self, now: DateTime<Utc>, command: UpdateTaskCommand) -> Result<FailingTask, TaskError> {
+        let (base, schedule_changed) = self.base.update(command)?;
+        Ok(FailingTask {
+            next_due_at: base.next_due_at_after_update(schedule_changed, self.next_due_at, now)?,
+            base,
+        })
+    }
+
+    /// State transition: Failing -> Paused
+    pub fn pause(self, now: DateTime<Utc>) -> Result<PausedTask, TaskError> {
+        Ok(self.base.pause(TaskStatus::Failing, now))
+    }
 }
 
 impl TryFrom<FailingTask> for BoundaryTask {
@@ -5,6 +5,11 @@ use super::*;
 pub const DEFAULT_START_WINDOW: Duration = Duration::from_secs(120);
 pub const DEFAULT_LATENESS_WINDOW: Duration = Duration::from_secs(240);
 pub const DEFAULT_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(30);
+/// One week
+pub const MAXIMUM_MAX_DURATION_SECONDS: u32 = 7 * 24 * 3600;
+pub const DEFAULT_MAX_CONCURRENT_RUNS: u32 = 1;
+pub const MAXIMUM_MAX_CONCURRENT_RUNS: u32 = 100;
+pub const MAXIMUM_UPSTREAM_TASKS: usize = 10;
 
 /// A task that is in a healthy state (not failed, not late, not failing)
 #[derive(Debug, Clone)]
@@ -19,30 +24,41 @@ impl HealthyTask {
     pub fn new(organization_id: Uuid, command: CreateTaskCommand) -> Result<Self, TaskError> {
         let now = Utc::now();
         let cron_schedule = parse_cron_schedule(&command.cron_schedule)?;
+        let time_zone = match &command.time_zone {
+            Some(time_zone) => parse_task_time_zone(time_zone)?,
+            None => Tz::UTC,
+        };
+        let upstream_task_ids =
+            upstream_task_ids_from_value(&command.id, command.upstream_task_ids.unwrap_or_default())?;
 
         Ok(Self {
-            next_due_at: calculate_next_due_at(&cron_schedule, now)?,
+            next_due_at: calculate_next_due_at(&cron_schedule, time_zone, now)?,
             base: TaskBase {
                 name: command.name.unwrap_or_else(|| command.id.to_string()),
                 id: command.id,
+                uuid: Uuid::new_v4(),
                 organization_id,
                 description: command.description,
                 cron_schedule,
+                time_zone,
                 start_window: command
                     .start_window_seconds
-                    .map_or(DEFAULT_START_WINDOW, |secs| {
-                        Duration::from_secs(secs.clamp(5, 3600) as u64)
-                    }),
+                    .map_or(DEFAULT_START_WINDOW, start_window_from_seconds),
                 lateness_window: command
                     .lateness_window_seconds
-                    .map_or(DEFAULT_LATENESS_WINDOW, |secs| {
-                        Duration::from_secs(secs.clamp(5, 3600) as u64)
-                    }),
+                    .map_or(DEFAULT_LATENESS_WINDOW, lateness_window_from_seconds),
                 heartbeat_timeout: command
                     .heartbeat_timeout_seconds
-                    .map_or(DEFAULT_HEARTBEAT_TIMEOUT, |secs| {
-                        Duration::from_secs(secs.clamp(10, 3600) as u64)
-                    }),
+                    .map_or(DEFAULT_HEARTBEAT_TIMEOUT, heartbeat_timeout_from_seconds),
+                max_duration: command.max_duration_seconds.map(max_duration_from_seconds),
+                max_concurrent_runs: command
+                    .max_concurrent_runs
+                    .map_or(DEFAULT_MAX_CONCURRENT_RUNS, max_concurrent_runs_from_value),
+                upstream_task_ids,
+                metadata: command.metadata.unwrap_or_default(),
+                email_notification_enabled: command.email_notification_enabled.unwrap_or(true),
+                push_notification_enabled: command.push_notification_enabled.unwrap_or(true),
+                sms_notification_enabled: command.sms_notification_enabled.unwrap_or(false),
                 created_at: now,
                 previous_status: None,
                 last_status_change_at: Some(now),
@@ -54,7 +70,7 @@ impl HealthyTask {
     pub fn start(self, now: DateTime<Utc>) -> Result<RunningTask, TaskError> {
         Ok(RunningTask {
             // When a task starts, its next_due_at field is updated to the next time the task is due to run
-            next_due_at: calculate_next_due_at(&self.base.cron_schedule, now)?,
+            next_due_at: calculate_next_due_at(&self.base.cron_schedule, self.base.time_zone, now)?,
             base: TaskBase {
                 previous_status: Some(TaskStatus::Healthy),
                 last_status_change_at: Some(now),
@@ -84,9 +100,9 @@ impl HealthyTask {
             });
         }
         Ok(DueTask {
+            cron_schedule: self.base.cron_schedule.clone(),
             // unwrap is safe because we already checked that the task is due to run,
             // so it must have a next_due_at
-            cron_schedule: self.base.cron_schedule.clone().unwrap(),
             next_due_at: self.next_due_at.unwrap(),
             base: TaskBase {
                 previous_status: Some(TaskStatus::Healthy),
@@ -95,6 +111,39 @@ impl HealthyTask {
             },
         })
     }
+
+    /// State transition: Healthy -> Due, when the upstream tasks of the task finished
+    pub fn mark_due_after_upstream_tasks(self, now: DateTime<Utc>) -> Result<DueTask, TaskError> {
+        if self.base.upstream_task_ids.is_empty() {
+            return Err(TaskError::InvalidStateTransition {
+                from: TaskStatus::Healthy,
+                to: TaskStatus::Due,
+                details: "this task has no upstream tasks".to_string(),
+            });
+        }
+        Ok(DueTask {
+            cron_schedule: self.base.cron_schedule.clone(),
+            next_due_at: now,
+            base: TaskBase {
+                previous_status: Some(TaskStatus::Healthy),
+                last_status_change_at: Some(now),
+                ..self.base
+            },
+        })
+    }
+
+    pub fn update(self, now: DateTime<Utc>, command: UpdateTaskCommand) -> Result<HealthyTask, TaskError> {
+        let (base, schedule_changed) = self.base.update(command)?;
+        Ok(HealthyTask {
+            next_due_at: base.next_due_at_after_update(schedule_changed, self.next_due_at, now)?,
+            base,
+        })
+    }
+
+    /// State transition: Healthy -> Paused
+    pub fn pause(self, now: DateTime<Utc>) -> Result<PausedTask, TaskError> {
+        Ok(self.base.pause(TaskStatus::Healthy, now))
+    }
 }
 
 impl TryFrom<HealthyTask> for BoundaryTask {
@@ -10,6 +10,7 @@
 //!
 //! A task can be scheduled to run at a given cron schedule or not. Scheduled tasks have a start window and a lateness window,
 //! which together determine when a task transitions from `Due` to `Late` and from `Late` to `Absent`.
+//! A task can also depend on upstream tasks, in which case it becomes `Due` once its upstream tasks finished.
 //!
 //! Here's a visual representation of a task's timeline:
 //!
@@ -22,9 +23,18 @@
 use std::{str::FromStr, time::Duration};
 
 use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::domain::{
+    entities::{
+        entity_metadata::EntityMetadata,
+        time_zone::{local_to_utc, parse_time_zone},
+    },
+    use_cases::tasks::UpdateTaskCommand,
+};
+
 use super::{BoundaryTask, TaskId, TaskStatus};
 
 mod absent;
@@ -32,27 +42,43 @@ mod due;
 mod failing;
 mod healthy;
 mod late;
+mod paused;
 mod running;
+mod schedule_preview;
 
 pub use absent::*;
 pub use due::*;
 pub use failing::*;
 pub use healthy::*;
 pub use late::*;
+pub use paused::*;
 pub use running::*;
+pub use schedule_preview::*;
 
 /// Base struct with common fields shared by all task states
 #[derive(getset::Getters, Debug, Clone)]
 #[getset(get = "pub")]
 pub struct TaskBase {
     pub(super) id: TaskId,
+    pub(super) uuid: Uuid,
     pub(super) organization_id: Uuid,
     pub(super) name: String,
     pub(super) description: Option<String>,
     pub(super) cron_schedule: Option<cron::Schedule>,
+    /// The time zone in which the cron schedule is evaluated
+    pub(super) time_zone: Tz,
     pub(super) start_window: Duration,
     pub(super) lateness_window: Duration,
     pub(super) heartbeat_timeout: Duration,
+    pub(super) max_duration: Option<Duration>,
+    /// The maximum number of runs of the task that can be running at the same time
+    pub(super) max_concurrent_runs: u32,
+    /// The tasks that must finish before the task is due
+    pub(super) upstream_task_ids: Vec<TaskId>,
+    pub(super) metadata: EntityMetadata,
+    pub(super) email_notification_enabled: bool,
+    pub(super) push_notification_enabled: bool,
+    pub(super) sms_notification_enabled: bool,
     pub(super) created_at: DateTime<Utc>,
     pub(super) previous_status: Option<TaskStatus>,
     pub(super) last_status_change_at: Option<DateTime<Utc>>,
@@ -62,6 +88,8 @@ pub struct TaskBase {
 pub enum TaskError {
     #[error("Invalid cron schedule")]
     InvalidCronSchedule { details: cron::error::Error },
+    #[error("{details}")]
+    InvalidTimeZone { details: String },
     #[error("Failed to calculate next due at")]
     FailedToCalculateNextDueAt {
         schedule: String,
@@ -74,24 +102,172 @@ pub enum TaskError {
     },
     #[error("Failed to build task from boundary: {details}")]
     FailedToBuildFromBoundary { details: String },
+    #[error("{details}")]
+    InvalidUpstreamTasks { details: String },
+}
+
+impl TaskBase {
+    /// State transition: any state but Running -> Paused
+    fn pause(self, from: TaskStatus, now: DateTime<Utc>) -> PausedTask {
+        PausedTask {
+            base: TaskBase {
+                previous_status: Some(from),
+                last_status_change_at: Some(now),
+                ..self
+            },
+        }
+    }
+
+    /// Applies the changes of an update command to the settings of the task.
+    /// Returns the updated settings, and whether the cron schedule, its time zone or the upstream tasks changed
+    fn update(self, command: UpdateTaskCommand) -> Result<(TaskBase, bool), TaskError> {
+        let cron_schedule = match &command.cron_schedule {
+            Some(cron_schedule) => parse_cron_schedule(cron_schedule)?,
+            None => self.cron_schedule.clone(),
+        };
+        let time_zone = match &command.time_zone {
+            Some(time_zone) => parse_task_time_zone(time_zone)?,
+            None => self.time_zone,
+        };
+        let upstream_task_ids = match command.upstream_task_ids {
+            Some(upstream_task_ids) => upstream_task_ids_from_value(&self.id, upstream_task_ids)?,
+            None => self.upstream_task_ids.clone(),
+        };
+        let schedule_changed = cron_schedule.as_ref().map(|c| c.to_string())
+            != self.cron_schedule.as_ref().map(|c| c.to_string())
+            || time_zone != self.time_zone
+            || upstream_task_ids != self.upstream_task_ids;
+
+        Ok((
+            TaskBase {
+                name: command.name.unwrap_or(self.name),
+                description: command.description.unwrap_or(self.description),
+                cron_schedule,
+                time_zone,
+                start_window: command
+                    .start_window_seconds
+                    .map_or(self.start_window, start_window_from_seconds),
+                lateness_window: command
+                    .lateness_window_seconds
+                    .map_or(self.lateness_window, lateness_window_from_seconds),
+                heartbeat_timeout: command
+                    .heartbeat_timeout_seconds
+                    .map_or(self.heartbeat_timeout, heartbeat_timeout_from_seconds),
+                max_duration: command
+                    .max_duration_seconds
+                    .map_or(self.max_duration, |secs| secs.map(max_duration_from_seconds)),
+                max_concurrent_runs: command
+                    .max_concurrent_runs
+                    .map_or(self.max_concurrent_runs, max_concurrent_runs_from_value),
+                upstream_task_ids,
+                metadata: command.metadata.unwrap_or(self.metadata),
+                email_notification_enabled: command
+                    .email_notification_enabled
+                    .unwrap_or(self.email_notification_enabled),
+                push_notification_enabled: command
+                    .push_notification_enabled
+                    .unwrap_or(self.push_notification_enabled),
+                sms_notification_enabled: command
+                    .sms_notification_enabled
+                    .unwrap_or(self.sms_notification_enabled),
+                ..self
+            },
+            schedule_changed,
+        ))
+    }
+
+    /// The next due at of a task whose settings were updated. It only changes if the cron schedule changed
+    fn next_due_at_after_update(
+        &self,
+        schedule_changed: bool,
+        next_due_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<Option<DateTime<Utc>>, TaskError> {
+        match schedule_changed {
+            true => calculate_next_due_at(&self.cron_schedule, self.time_zone, now),
+            false => Ok(next_due_at),
+        }
+    }
+}
+
+/// A task that is expected to run at a given time (due, late or absent) after its settings were updated.
+/// When the cron schedule changes, the task is no longer expected at that time and becomes healthy
+pub enum UpdatedScheduledTask<T> {
+    Unchanged(T),
+    Rescheduled(Box<HealthyTask>),
+}
+
+fn start_window_from_seconds(secs: u32) -> Duration {
+    Duration::from_secs(secs.clamp(5, 3600) as u64)
+}
+
+fn lateness_window_from_seconds(secs: u32) -> Duration {
+    Duration::from_secs(secs.clamp(5, 3600) as u64)
+}
+
+fn heartbeat_timeout_from_seconds(secs: u32) -> Duration {
+    Duration::from_secs(secs.clamp(10, 3600) as u64)
 }
 
+fn max_duration_from_seconds(secs: u32) -> Duration {
+    Duration::from_secs(secs.clamp(60, MAXIMUM_MAX_DURATION_SECONDS) as u64)
+}
+
+fn max_concurrent_runs_from_value(value: u32) -> u32 {
+    value.clamp(1, MAXIMUM_MAX_CONCURRENT_RUNS)
+}
+
+/// Removes the duplicates from the upstream tasks of a task, which cannot depend on itself
+fn upstream_task_ids_from_value(task_id: &TaskId, upstream_task_ids: Vec<TaskId>) -> Result<Vec<TaskId>, TaskError> {
+    let mut unique_ids: Vec<TaskId> = Vec::with_capacity(upstream_task_ids.len());
+    for upstream_task_id in upstream_task_ids {
+        if upstream_task_id == *task_id {
+            return Err(TaskError::InvalidUpstreamTasks {
+                details: "a task cannot depend on itself".to_string(),
+            });
+        }
+        if !unique_ids.contains(&upstream_task_id) {
+            unique_ids.push(upstream_task_id);
+        }
+    }
+    if unique_ids.len() > MAXIMUM_UPSTREAM_TASKS {
+        return Err(TaskError::InvalidUpstreamTasks {
+            details: format!("a task cannot depend on more than {MAXIMUM_UPSTREAM_TASKS} tasks"),
+        });
+    }
+    Ok(unique_ids)
+}
+
+/// Calculates the next time a task is due to run, with its cron schedule evaluated in the time zone of the task.
+/// Local times that occur twice when clocks are set back are only due at their first occurrence,
+/// and local times skipped when clocks are set forward are due one hour later
 fn calculate_next_due_at(
     cron_schedule: &Option<cron::Schedule>,
+    time_zone: Tz,
     now: DateTime<Utc>,
 ) -> Result<Option<DateTime<Utc>>, TaskError> {
     if let Some(schedule) = cron_schedule {
-        let next_due_at = schedule.after(&now).next().ok_or_else(|| {
-            TaskError::FailedToCalculateNextDueAt {
+        // the schedule iterates over local times (represented in UTC, which has no DST changes),
+        // that are then converted to actual dates in the time zone of the task
+        let local_now = now.with_timezone(&time_zone).naive_local().and_utc();
+        let next_due_at = schedule
+            .after(&local_now)
+            .map(|local| local_to_utc(time_zone, local.naive_utc()))
+            // a local time repeated after clocks were set back was already due at its first occurrence
+            .find(|due_at| *due_at > now)
+            .ok_or_else(|| TaskError::FailedToCalculateNextDueAt {
                 schedule: schedule.to_string(),
-            }
-        })?;
+            })?;
         Ok(Some(next_due_at))
     } else {
         Ok(None)
     }
 }
 
+fn parse_task_time_zone(time_zone: &str) -> Result<Tz, TaskError> {
+    parse_time_zone(time_zone).map_err(|details| TaskError::InvalidTimeZone { details })
+}
+
 fn parse_cron_schedule(cron_schedule: &Option<String>) -> Result<Option<cron::Schedule>, TaskError> {
     match cron_schedule {
         Some(schedule) => {
@@ -119,13 +295,22 @@ impl TryFrom<BoundaryTask> for TaskBase {
     fn try_from(boundary: BoundaryTask) -> Result<Self, Self::Error> {
         Ok(TaskBase {
             id: boundary.id,
+            uuid: boundary.uuid,
             organization_id: boundary.organization_id,
             name: boundary.name,
             description: boundary.description,
             cron_schedule: parse_cron_schedule(&boundary.cron_schedule)?,
+            time_zone: parse_task_time_zone(&boundary.time_zone)?,
             start_window: Duration::from_secs(boundary.start_window_seconds as u64),
             lateness_window: Duration::from_secs(boundary.lateness_window_seconds as u64),
             heartbeat_timeout: Duration::from_secs(boundary.heartbeat_timeout_seconds as u64),
+            max_duration: boundary.max_duration_seconds.map(|secs| Duration::from_secs(secs as u64)),
+            max_concurrent_runs: boundary.max_concurrent_runs.max(1) as u32,
+            upstream_task_ids: boundary.upstream_task_ids,
+            metadata: boundary.metadata,
+            email_notification_enabled: boundary.email_notification_enabled,
+            push_notification_enabled: boundary.push_notification_enabled,
+            sms_notification_enabled: boundary.sms_notification_enabled,
             created_at: boundary.created_at,
             previous_status: boundary.previous_status,
             last_status_change_at: boundary.last_status_change_at,
@@ -137,6 +322,7 @@ impl From<TaskBase> for BoundaryTask {
     fn from(base: TaskBase) -> Self {
         BoundaryTask {
             id: base.id,
+            uuid: base.uuid,
             organization_id: base.organization_id,
             name: base.name,
             description: base.description,
@@ -148,6 +334,14 @@ impl From<TaskBase> for BoundaryTask {
             start_window_seconds: base.start_window.as_secs() as i32,
             lateness_window_seconds: base.lateness_window.as_secs() as i32,
             heartbeat_timeout_seconds: base.heartbeat_timeout.as_secs() as i32,
+            max_duration_seconds: base.max_duration.map(|d| d.as_secs() as i32),
+            max_concurrent_runs: base.max_concurrent_runs as i32,
+            upstream_task_ids: base.upstream_task_ids,
+            time_zone: base.time_zone.name().to_string(),
+            metadata: base.metadata,
+            email_notification_enabled: base.email_notification_enabled,
+            push_notification_enabled: base.push_notification_enabled,
+            sms_notification_enabled: base.sms_notification_enabled,
             created_at: base.created_at,
         }
     }
@@ -165,7 +359,7 @@ fn test_parse_cron_schedule() {
             cron_schedule_result.err().unwrap()
         );
         let cron_schedule_opt = cron_schedule_result.unwrap();
-        let next_due_at = calculate_next_due_at(&cron_schedule_opt, now);
+        let next_due_at = calculate_next_due_at(&cron_schedule_opt, Tz::UTC, now);
         assert!(
             next_due_at.is_ok(),
             "Failed to calculate next due at: {:?}",
@@ -178,3 +372,50 @@ fn test_parse_cron_schedule() {
         );
     }
 }
+
+#[test]
+fn test_calculate_next_due_at_in_time_zone() {
+    let schedule = parse_cron_schedule(&Some("0 2 * * *".to_string())).unwrap();
+    let paris = parse_task_time_zone("Europe/Paris").unwrap();
+    let date = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+
+    // 2am in Paris is 1am UTC in winter, and midnight UTC in summer
+    assert_eq!(
+        calculate_next_due_at(&schedule, paris, date("2024-01-15T12:00:00Z")).unwrap(),
+        Some(date("2024-01-16T01:00:00Z"))
+    );
+    assert_eq!(
+        calculate_next_due_at(&schedule, paris, date("2024-06-30T12:00:00Z")).unwrap(),
+        Some(date("2024-07-01T00:00:00Z"))
+    );
+    assert_eq!(
+        calculate_next_due_at(&schedule, Tz::UTC, date("2024-06-30T12:00:00Z")).unwrap(),
+        Some(date("2024-07-01T02:00:00Z"))
+    );
+    assert!(matches!(
+        parse_task_time_zone("Europe/Nowhere"),
+        Err(TaskError::InvalidTimeZone { .. })
+    ));
+}
+
+#[test]
+fn test_calculate_next_due_at_across_dst_changes() {
+    let schedule = parse_cron_schedule(&Some("30 2 * * *".to_string())).unwrap();
+    let paris = parse_task_time_zone("Europe/Paris").unwrap();
+    let date = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+
+    // on 2024-03-31, clocks go from 2am to 3am: 2:30am does not exist and the task is due at 3:30am instead
+    assert_eq!(
+        calculate_next_due_at(&schedule, paris, date("2024-03-30T12:00:00Z")).unwrap(),
+        Some(date("2024-03-31T01:30:00Z"))
+    );
+    // on 2024-10-27, clocks go from 3am back to 2am: 2:30am happens twice and the task is only due the first time
+    assert_eq!(
+        calculate_next_due_at(&schedule, paris, date("2024-10-26T12:00:00Z")).unwrap(),
+        Some(date("2024-10-27T00:30:00Z"))
+    );
+    assert_eq!(
+        calculate_next_due_at(&schedule, paris, date("2024-10-27T01:10:00Z")).unwrap(),
+        Some(date("2024-10-28T01:30:00Z"))
+    );
+}
@@ -0,0 +1,56 @@
+use super::*;
+use chrono::{DateTime, Utc};
+
+/// A task that was paused by a user.
+/// A paused task is not expected to run, so it never becomes due, late or absent
+pub struct PausedTask {
+    pub(super) base: TaskBase,
+}
+
+impl PausedTask {
+    /// State transition: Paused -> Healthy
+    pub fn resume(self, now: DateTime<Utc>) -> Result<HealthyTask, TaskError> {
+        Ok(HealthyTask {
+            // the next due at is calculated from the time the task is resumed,
+            // so that the runs that were missed while the task was paused are not expected
+            next_due_at: calculate_next_due_at(&self.base.cron_schedule, self.base.time_zone, now)?,
+            base: TaskBase {
+                previous_status: Some(TaskStatus::Paused),
+                last_status_change_at: Some(now),
+                ..self.base
+            },
+        })
+    }
+
+    pub fn update(self, command: UpdateTaskCommand) -> Result<PausedTask, TaskError> {
+        let (base, _) = self.base.update(command)?;
+        Ok(PausedTask { base })
+    }
+}
+
+impl TryFrom<PausedTask> for BoundaryTask {
+    type Error = TaskError;
+
+    fn try_from(task: PausedTask) -> Result<Self, Self::Error> {
+        Ok(BoundaryTask {
+            status: TaskStatus::Paused,
+            next_due_at: None,
+            ..BoundaryTask::from(task.base)
+        })
+    }
+}
+
+impl TryFrom<BoundaryTask> for PausedTask {
+    type Error = TaskError;
+
+    fn try_from(boundary: BoundaryTask) -> Result<Self, Self::Error> {
+        if boundary.status != TaskStatus::Paused {
+            return Err(TaskError::FailedToBuildFromBoundary {
+                details: "task status must be paused".to_string(),
+            });
+        }
+        Ok(PausedTask {
+            base: boundary.try_into()?,
+        })
+    }
+}
@@ -0,0 +1,268 @@
+//! A preview of the timeline of a scheduled task, used to check a cron schedule and its windows
+//! before creating or updating a task.
+use serde::Serialize;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::use_cases::tasks::PreviewTaskScheduleCommand;
+
+use super::*;
+
+pub const DEFAULT_SCHEDULE_PREVIEW_RUNS: u32 = 5;
+pub const MAXIMUM_SCHEDULE_PREVIEW_RUNS: u32 = 100;
+
+/// A run expected by the schedule of a task
+#[derive(Debug, Clone, Serialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct ScheduledTaskRun {
+    /// The date at which the task is due to start
+    pub due_at: DateTime<Utc>,
+    /// The date at which the task is marked as late if it has not started (due at + start window)
+    pub late_at: DateTime<Utc>,
+    /// The date at which the task is marked as absent if it has not started (late at + lateness window)
+    pub absent_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TaskSchedulePreview {
+    /// A human-readable description of the cron schedule, in English
+    pub description: String,
+    pub time_zone: String,
+    pub start_window_seconds: u32,
+    pub lateness_window_seconds: u32,
+    /// The next runs expected by the schedule, in chronological order
+    pub next_runs: Vec<ScheduledTaskRun>,
+}
+
+/// Previews the next runs of a task with the given schedule, as they would be expected after `now`.
+/// The windows are clamped and defaulted the same way as when a task is created
+pub fn preview_task_schedule(
+    command: PreviewTaskScheduleCommand,
+    now: DateTime<Utc>,
+) -> Result<TaskSchedulePreview, TaskError> {
+    let cron_schedule = parse_cron_schedule(&Some(command.cron_schedule))?;
+    let time_zone = match &command.time_zone {
+        Some(time_zone) => parse_task_time_zone(time_zone)?,
+        None => Tz::UTC,
+    };
+    let start_window = command
+        .start_window_seconds
+        .map_or(DEFAULT_START_WINDOW, start_window_from_seconds);
+    let lateness_window = command
+        .lateness_window_seconds
+        .map_or(DEFAULT_LATENESS_WINDOW, lateness_window_from_seconds);
+    let runs = command
+        .runs
+        .unwrap_or(DEFAULT_SCHEDULE_PREVIEW_RUNS)
+        .clamp(1, MAXIMUM_SCHEDULE_PREVIEW_RUNS);
+
+    let mut next_runs = Vec::with_capacity(runs as usize);
+    let mut after = now;
+    while next_runs.len() < runs as usize {
+        let Some(due_at) = calculate_next_due_at(&cron_schedule, time_zone, after)? else {
+            break;
+        };
+        next_runs.push(ScheduledTaskRun {
+            due_at,
+            late_at: due_at + start_window,
+            absent_at: due_at + start_window + lateness_window,
+        });
+        after = due_at;
+    }
+
+    Ok(TaskSchedulePreview {
+        description: cron_schedule
+            .map(|schedule| describe_cron_schedule(&schedule.to_string()))
+            .unwrap_or_default(),
+        time_zone: time_zone.name().to_string(),
+        start_window_seconds: start_window.as_secs() as u32,
+        lateness_window_seconds: lateness_window.as_secs() as u32,
+        next_runs,
+    })
+}
+
+const MONTHS: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November",
+    "December",
+];
+/// Days of week are numbered from 1 (Sunday) to 7 (Saturday) by the cron parser
+const DAYS_OF_WEEK: [&str; 7] = ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
+
+/// Describes a cron schedule with seconds (as normalized by `parse_cron_schedule`) in plain English,
+/// e.g. `0 30 2 * * Mon-Fri` is described as "At 02:30, on Monday through Friday"
+fn describe_cron_schedule(schedule: &str) -> String {
+    let fields: Vec<&str> = schedule.split_ascii_whitespace().collect();
+    let field = |index: usize| fields.get(index).copied().unwrap_or("*");
+    let (seconds, minutes, hours) = (field(0), field(1), field(2));
+
+    let mut parts = Vec::new();
+    match (seconds.parse::<u32>(), minutes.parse::<u32>()) {
+        // a fixed time of the day, or several times at the same minute
+        (Ok(second), Ok(minute)) if hours.split(',').all(|hour| hour.parse::<u32>().is_ok()) => {
+            let times: Vec<String> = hours
+                .split(',')
+                .map(|hour| match second {
+                    0 => format!("{:02}:{minute:02}", hour.parse::<u32>().unwrap_or_default()),
+                    _ => format!("{:02}:{minute:02}:{second:02}", hour.parse::<u32>().unwrap_or_default()),
+                })
+                .collect();
+            parts.push(format!("At {}", join_with_and(times)));
+        }
+        _ => {
+            if seconds != "0" {
+                parts.extend(describe_field(seconds, "second", None).map(|seconds| prefix_values("at", seconds)));
+            }
+            match describe_field(minutes, "minute", None) {
+                Some(minutes) => parts.push(prefix_values("at", minutes)),
+                None if seconds == "0" => parts.push("every minute".to_string()),
+                None => {}
+            }
+            parts.extend(describe_field(hours, "hour", None).map(|hours| prefix_values("during", hours)));
+        }
+    }
+    parts.extend(describe_field(field(3), "day", None).map(|days| format!("on {days} of the month")));
+    parts.extend(describe_field(field(5), "day", Some(&DAYS_OF_WEEK)).map(|days| format!("on {days}")));
+    parts.extend(describe_field(field(4), "month", Some(&MONTHS)).map(|months| format!("in {months}")));
+    parts.extend(describe_field(field(6), "year", None).map(|years| format!("in {years}")));
+
+    let description = parts.join(", ");
+    let mut chars = description.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => description,
+    }
+}
+
+/// Describes a single field of a cron schedule, or returns `None` if the field matches every value.
+/// When `names` is set, values are 1-based indexes in it (e.g. months and days of week)
+fn describe_field(field: &str, unit: &str, names: Option<&[&str]>) -> Option<String> {
+    if field == "*" || field == "?" {
+        return None;
+    }
+    let value = |value: &str| match (names, value.parse::<usize>()) {
+        (Some(names), Ok(index)) if (1..=names.len()).contains(&index) => names[index - 1].to_string(),
+        // abbreviated names, e.g. MON or Jan
+        (Some(names), _) => names
+            .iter()
+            .find(|name| name.to_lowercase().starts_with(&value.to_lowercase()))
+            .map_or_else(|| value.to_string(), |name| name.to_string()),
+        (None, _) => value.to_string(),
+    };
+
+    let items: Vec<String> = field
+        .split(',')
+        .map(|item| match item.split_once('/') {
+            Some((range, step)) => {
+                let every = format!("every {step} {unit}s");
+                match range.split_once('-') {
+                    Some((from, to)) => format!("{every} from {} through {}", value(from), value(to)),
+                    None if range == "*" => every,
+                    None => format!("{every} starting at {}", value(range)),
+                }
+            }
+            None => match item.split_once('-') {
+                Some((from, to)) => format!("{} through {}", value(from), value(to)),
+                None => value(item),
+            },
+        })
+        .collect();
+
+    // named values and steps read on their own (e.g. "Monday and Friday", "every 5 minutes"),
+    // plain values are introduced by their unit (e.g. "minute 5", "hours 9 through 17")
+    if names.is_some() || items.iter().all(|item| item.starts_with("every")) {
+        return Some(join_with_and(items));
+    }
+    let plural = items.len() > 1 || field.contains('-');
+    Some(format!("{unit}{} {}", if plural { "s" } else { "" }, join_with_and(items)))
+}
+
+/// Prefixes a described field, unless it is a step (e.g. "at minute 5", but "every 5 minutes")
+fn prefix_values(prefix: &str, description: String) -> String {
+    match description.starts_with("every") {
+        true => description,
+        false => format!("{prefix} {description}"),
+    }
+}
+
+fn join_with_and(mut items: Vec<String>) -> String {
+    match items.len() {
+        0 => String::new(),
+        1 => items.remove(0),
+        _ => {
+            let last = items.pop().unwrap_or_default();
+            format!("{} and {last}", items.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_cron_schedule() {
+        let cases = [
+            ("0 2 * * *", "At 02:00"),
+            ("30 2 * * 2-6", "At 02:30, on Monday through Friday"),
+            ("0 9,18 * * *", "At 09:00 and 18:00"),
+            ("* * * * *", "Every minute"),
+            ("*/5 * * * *", "Every 5 minutes"),
+            ("0 */2 * * *", "At minute 0, every 2 hours"),
+            ("*/15 9-17 * * MON-FRI", "Every 15 minutes, during hours 9 through 17, on Monday through Friday"),
+            ("0 30 * * * *", "At minute 30"),
+            ("30 * * * * *", "At second 30"),
+            ("0 0 1,15 * *", "At 00:00, on days 1 and 15 of the month"),
+            ("0 0 1 1 *", "At 00:00, on day 1 of the month, in January"),
+            ("*/10 * * * * *", "Every 10 seconds"),
+        ];
+        for (schedule, description) in cases {
+            let schedule = parse_cron_schedule(&Some(schedule.to_string())).unwrap().unwrap();
+            assert_eq!(describe_cron_schedule(&schedule.to_string()), description);
+        }
+    }
+
+    #[test]
+    fn test_preview_task_schedule() {
+        let now = DateTime::parse_from_rfc3339("2024-03-29T12:00:00Z").unwrap().with_timezone(&Utc);
+        let preview = preview_task_schedule(
+            PreviewTaskScheduleCommand {
+                cron_schedule: "30 2 * * *".to_string(),
+                time_zone: Some("Europe/Paris".to_string()),
+                start_window_seconds: Some(60),
+                lateness_window_seconds: Some(1),
+                runs: Some(3),
+            },
+            now,
+        )
+        .unwrap();
+
+        assert_eq!(preview.description, "At 02:30");
+        assert_eq!(preview.time_zone, "Europe/Paris");
+        // windows are clamped like the windows of tasks
+        assert_eq!(preview.lateness_window_seconds, 5);
+        let due_dates: Vec<String> = preview.next_runs.iter().map(|run| run.due_at.to_rfc3339()).collect();
+        // clocks are set forward on 2024-03-31, when 02:30 does not exist
+        assert_eq!(
+            due_dates,
+            ["2024-03-30T01:30:00+00:00", "2024-03-31T01:30:00+00:00", "2024-04-01T00:30:00+00:00"]
+        );
+        let run = &preview.next_runs[0];
+        assert_eq!(run.late_at - run.due_at, chrono::Duration::seconds(60));
+        assert_eq!(run.absent_at - run.late_at, chrono::Duration::seconds(5));
+
+        let result = preview_task_schedule(
+            PreviewTaskScheduleCommand {
+                cron_schedule: "every day".to_string(),
+                time_zone: None,
+                start_window_seconds: None,
+                lateness_window_seconds: None,
+                runs: None,
+            },
+            now,
+        );
+        assert!(matches!(result, Err(TaskError::InvalidCronSchedule { .. })));
+    }
+}
@@ -18,6 +18,8 @@ pub struct BoundaryTaskRun {
     pub organization_id: Uuid,
     #[ts(type = "string")]
     pub task_id: TaskId,
+    /// Identifies the run among the running runs of its task
+    pub run_id: String,
     pub status: TaskRunStatus,
     pub started_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -45,6 +47,9 @@ pub enum TaskRunStatus {
     /// The task run was presumed dead (no heartbeat within the heartbeat timeout)
     /// but it may still be running
     Dead = 5,
+    /// The task run exceeded the maximum duration of its task
+    /// but it may still be running
+    TimedOut = 6,
 }
 
 impl From<i16> for TaskRunStatus {
@@ -55,6 +60,7 @@ impl From<i16> for TaskRunStatus {
             3 => Self::Failed,
             4 => Self::Aborted,
             5 => Self::Dead,
+            6 => Self::TimedOut,
             _ => panic!("invalid TaskRunStatus discriminant: {value}"),
         }
     }
@@ -9,6 +9,7 @@ use super::super::boundary::{BoundaryTaskRun, TaskRunStatus};
 pub struct AbortedTaskRun {
     pub(super) organization_id: Uuid,
     pub(super) task_id: TaskId,
+    pub(super) run_id: String,
     pub(super) started_at: DateTime<Utc>,
     pub(super) completed_at: DateTime<Utc>,
     pub(super) updated_at: DateTime<Utc>,
@@ -32,6 +33,7 @@ impl TryFrom<BoundaryTaskRun> for AbortedTaskRun {
         Ok(Self {
             organization_id: boundary.organization_id,
             task_id: boundary.task_id,
+            run_id: boundary.run_id,
             started_at: boundary.started_at,
             completed_at,
             updated_at: boundary.updated_at,
@@ -45,6 +47,7 @@ impl From<AbortedTaskRun> for BoundaryTaskRun {
             status: TaskRunStatus::Aborted,
             organization_id: aborted.organization_id,
             task_id: aborted.task_id,
+            run_id: aborted.run_id,
             started_at: aborted.started_at,
             updated_at: aborted.updated_at,
             completed_at: Some(aborted.completed_at),
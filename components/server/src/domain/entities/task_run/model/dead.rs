@@ -7,9 +7,11 @@ use crate::domain::entities::task::TaskId;
 use super::TaskRunError;
 use super::super::boundary::{BoundaryTaskRun, TaskRunStatus};
 
+#[derive(Debug, Clone)]
 pub struct DeadTaskRun {
     pub(super) organization_id: Uuid,
     pub(super) task_id: TaskId,
+    pub(super) run_id: String,
     pub(super) started_at: DateTime<Utc>,
     pub(super) completed_at: DateTime<Utc>,
     pub(super) updated_at: DateTime<Utc>,
@@ -40,6 +42,7 @@ impl TryFrom<BoundaryTaskRun> for DeadTaskRun {
         Ok(Self {
             organization_id: boundary.organization_id,
             task_id: boundary.task_id,
+            run_id: boundary.run_id,
             started_at: boundary.started_at,
             completed_at,
             updated_at: boundary.updated_at,
@@ -56,6 +59,7 @@ impl From<DeadTaskRun> for BoundaryTaskRun {
             status: TaskRunStatus::Dead,
             organization_id: dead.organization_id,
             task_id: dead.task_id,
+            run_id: dead.run_id,
             started_at: dead.started_at,
             updated_at: dead.updated_at,
             completed_at: Some(dead.completed_at),
@@ -5,9 +5,11 @@ use crate::domain::entities::task::TaskId;
 use super::TaskRunError;
 use super::super::boundary::{BoundaryTaskRun, TaskRunStatus};
 
+#[derive(Debug, Clone)]
 pub struct FailedTaskRun {
     pub(super) organization_id: Uuid,
     pub(super) task_id: TaskId,
+    pub(super) run_id: String,
     pub(super) started_at: DateTime<Utc>,
     pub(super) completed_at: DateTime<Utc>,
     pub(super) updated_at: DateTime<Utc>,
@@ -34,6 +36,7 @@ impl TryFrom<BoundaryTaskRun> for FailedTaskRun {
         Ok(Self {
             organization_id: boundary.organization_id,
             task_id: boundary.task_id,
+            run_id: boundary.run_id,
             started_at: boundary.started_at,
             completed_at,
             updated_at: boundary.updated_at,
@@ -49,6 +52,7 @@ impl From<FailedTaskRun> for BoundaryTaskRun {
             status: TaskRunStatus::Failed,
             organization_id: failed.organization_id,
             task_id: failed.task_id,
+            run_id: failed.run_id,
             started_at: failed.started_at,
             updated_at: failed.updated_at,
             completed_at: Some(failed.completed_at),
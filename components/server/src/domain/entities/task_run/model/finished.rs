@@ -9,6 +9,7 @@ use super::super::boundary::{BoundaryTaskRun, TaskRunStatus};
 pub struct FinishedTaskRun {
     pub(super) organization_id: Uuid,
     pub(super) task_id: TaskId,
+    pub(super) run_id: String,
     pub(super) started_at: DateTime<Utc>,
     pub(super) completed_at: DateTime<Utc>,
     pub(super) updated_at: DateTime<Utc>,
@@ -33,6 +34,7 @@ impl TryFrom<BoundaryTaskRun> for FinishedTaskRun {
         Ok(Self {
             organization_id: boundary.organization_id,
             task_id: boundary.task_id,
+            run_id: boundary.run_id,
             started_at: boundary.started_at,
             completed_at,
             updated_at: boundary.updated_at,
@@ -47,6 +49,7 @@ impl From<FinishedTaskRun> for BoundaryTaskRun {
             status: TaskRunStatus::Finished,
             organization_id: finished.organization_id,
             task_id: finished.task_id,
+            run_id: finished.run_id,
             started_at: finished.started_at,
             updated_at: finished.updated_at,
             completed_at: Some(finished.completed_at),
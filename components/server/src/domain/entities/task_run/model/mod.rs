@@ -10,12 +10,14 @@ mod finished;
 mod failed;
 mod aborted;
 mod dead;
+mod timed_out;
 
 pub use running::RunningTaskRun;
 pub use finished::FinishedTaskRun;
 pub use failed::FailedTaskRun;
 pub use aborted::AbortedTaskRun;
 pub use dead::DeadTaskRun;
+pub use timed_out::TimedOutTaskRun;
 
 #[derive(Debug, Error)]
 pub enum TaskRunError {
@@ -6,7 +6,7 @@ use chrono::{DateTime, Utc};
 use getset::Getters;
 
 use crate::domain::entities::task::TaskId;
-use super::{AbortedTaskRun, DeadTaskRun, FailedTaskRun, FinishedTaskRun, TaskRunError};
+use super::{AbortedTaskRun, DeadTaskRun, FailedTaskRun, FinishedTaskRun, TaskRunError, TimedOutTaskRun};
 use super::super::boundary::{BoundaryTaskRun, TaskRunStatus};
 
 #[derive(Getters, Debug, Clone)]
@@ -14,16 +14,24 @@ use super::super::boundary::{BoundaryTaskRun, TaskRunStatus};
 pub struct RunningTaskRun {
     organization_id: Uuid,
     task_id: TaskId,
+    run_id: String,
     started_at: DateTime<Utc>,
     last_heartbeat_at: DateTime<Utc>,
     heartbeat_timeout: Duration,
 }
 
 impl RunningTaskRun {
-    pub fn new(organization_id: Uuid, task_id: TaskId, started_at: DateTime<Utc>, heartbeat_timeout: Duration) -> Self {
+    pub fn new(
+        organization_id: Uuid,
+        task_id: TaskId,
+        run_id: String,
+        started_at: DateTime<Utc>,
+        heartbeat_timeout: Duration,
+    ) -> Self {
         Self {
             organization_id,
             task_id,
+            run_id,
             started_at,
             last_heartbeat_at: started_at,
             heartbeat_timeout,
@@ -42,6 +50,28 @@ impl RunningTaskRun {
         Ok(DeadTaskRun {
             organization_id: self.organization_id,
             task_id: self.task_id,
+            run_id: self.run_id,
+            started_at: self.started_at,
+            completed_at: now,
+            updated_at: now,
+            last_heartbeat_at: self.last_heartbeat_at,
+            heartbeat_timeout: self.heartbeat_timeout,
+        })
+    }
+
+    /// Transition : Running -> TimedOut
+    pub fn mark_timed_out(self, now: DateTime<Utc>, max_duration: Duration) -> Result<TimedOutTaskRun, TaskRunError> {
+        if now < self.started_at + max_duration {
+            return Err(TaskRunError::InvalidStateTransition {
+                from: TaskRunStatus::Running,
+                to: TaskRunStatus::TimedOut,
+                details: "task run has not exceeded its maximum duration".to_string(),
+            });
+        }
+        Ok(TimedOutTaskRun {
+            organization_id: self.organization_id,
+            task_id: self.task_id,
+            run_id: self.run_id,
             started_at: self.started_at,
             completed_at: now,
             updated_at: now,
@@ -55,6 +85,7 @@ impl RunningTaskRun {
         Ok(AbortedTaskRun {
             organization_id: self.organization_id,
             task_id: self.task_id,
+            run_id: self.run_id,
             started_at: self.started_at,
             completed_at: now,
             updated_at: now,
@@ -77,6 +108,7 @@ impl RunningTaskRun {
         Ok(FinishedTaskRun {
             organization_id: self.organization_id,
             task_id: self.task_id,
+            run_id: self.run_id,
             started_at: self.started_at,
             completed_at: now,
             updated_at: now,
@@ -101,6 +133,7 @@ impl RunningTaskRun {
         Ok(FailedTaskRun {
             organization_id: self.organization_id,
             task_id: self.task_id,
+            run_id: self.run_id,
             started_at: self.started_at,
             completed_at: now,
             updated_at: now,
@@ -128,6 +161,7 @@ impl TryFrom<BoundaryTaskRun> for RunningTaskRun {
         Ok(Self {
             organization_id: boundary.organization_id,
             task_id: boundary.task_id,
+            run_id: boundary.run_id,
             started_at: boundary.started_at,
             last_heartbeat_at,
             heartbeat_timeout: Duration::from_secs(boundary.heartbeat_timeout_seconds as u64),
@@ -141,6 +175,7 @@ impl From<RunningTaskRun> for BoundaryTaskRun {
             status: TaskRunStatus::Running,
             organization_id: running.organization_id,
             task_id: running.task_id,
+            run_id: running.run_id,
             started_at: running.started_at,
             updated_at: running.last_heartbeat_at,
             completed_at: None,
@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::task::TaskId;
+use super::TaskRunError;
+use super::super::boundary::{BoundaryTaskRun, TaskRunStatus};
+
+/// A task run that exceeded the maximum duration of its task.
+/// Unlike a dead task run, it was still sending heartbeats, so the process may still be running
+#[derive(Debug, Clone)]
+pub struct TimedOutTaskRun {
+    pub(super) organization_id: Uuid,
+    pub(super) task_id: TaskId,
+    pub(super) run_id: String,
+    pub(super) started_at: DateTime<Utc>,
+    pub(super) completed_at: DateTime<Utc>,
+    pub(super) updated_at: DateTime<Utc>,
+    pub(super) last_heartbeat_at: DateTime<Utc>,
+    pub(super) heartbeat_timeout: Duration,
+}
+
+impl TimedOutTaskRun {
+    pub fn run_id(&self) -> &str {
+        &self.run_id
+    }
+}
+
+impl TryFrom<BoundaryTaskRun> for TimedOutTaskRun {
+    type Error = TaskRunError;
+
+    fn try_from(boundary: BoundaryTaskRun) -> Result<Self, Self::Error> {
+        if boundary.status != TaskRunStatus::TimedOut {
+            return Err(TaskRunError::FailedToBuildFromBoundary { 
+                details: "Task run status is not TimedOut".to_string() 
+            });
+        }
+
+        let completed_at = boundary.completed_at.ok_or(
+            TaskRunError::FailedToBuildFromBoundary { 
+                details: "Timed out task run must have completed_at".to_string() 
+            })?;
+
+        let last_heartbeat_at = boundary.last_heartbeat_at.ok_or(
+            TaskRunError::FailedToBuildFromBoundary { 
+                details: "Timed out task run must have last_heartbeat_at".to_string() 
+            })?;
+
+        Ok(Self {
+            organization_id: boundary.organization_id,
+            task_id: boundary.task_id,
+            run_id: boundary.run_id,
+            started_at: boundary.started_at,
+            completed_at,
+            updated_at: boundary.updated_at,
+            last_heartbeat_at,
+            heartbeat_timeout: Duration::from_secs(boundary.heartbeat_timeout_seconds as u64),
+        })
+    }
+}
+
+impl From<TimedOutTaskRun> for BoundaryTaskRun {
+    fn from(timed_out: TimedOutTaskRun) -> Self {
+        Self {
+            status: TaskRunStatus::TimedOut,
+            organization_id: timed_out.organization_id,
+            task_id: timed_out.task_id,
+            run_id: timed_out.run_id,
+            started_at: timed_out.started_at,
+            updated_at: timed_out.updated_at,
+            completed_at: Some(timed_out.completed_at),
+            exit_code: None,
+            error_message: None,
+            last_heartbeat_at: Some(timed_out.last_heartbeat_at),
+            heartbeat_timeout_seconds: timed_out.heartbeat_timeout.as_secs() as i32,
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::task::TaskId;
+
+/// The maximum number of lines that can be sent at once
+pub const MAXIMUM_TASK_RUN_LOG_LINES_PER_REQUEST: usize = 1000;
+/// Longer lines are truncated
+pub const MAXIMUM_TASK_RUN_LOG_LINE_LENGTH: usize = 4096;
+/// Above this size (in bytes), the lines sent at once are stored in the file storage, and only their tail is kept in the event
+pub const MAXIMUM_INLINE_TASK_RUN_LOGS_SIZE: usize = 16 * 1024;
+/// The number of lines kept in the event when the lines are stored in the file storage
+pub const TASK_RUN_LOGS_TAIL_LENGTH: usize = 50;
+
+/// An event that is recorded during a task run
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TaskRunEvent {
+    pub organization_id: Uuid,
+    #[ts(type = "string")]
+    pub task_id: TaskId,
+    pub task_run_id: String,
+    pub task_run_started_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub event_type: TaskRunEventType,
+    pub event_payload: Option<TaskRunEventPayload>,
+}
+
+#[derive(sqlx::Type, Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[repr(i16)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum TaskRunEventType {
+    /// Lines of output of the task run
+    Logs = 0,
+}
+
+impl From<i16> for TaskRunEventType {
+    fn from(value: i16) -> Self {
+        match value {
+            0 => Self::Logs,
+            _ => panic!("invalid TaskRunEventType discriminant: {value}"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all_fields = "camelCase")]
+#[ts(export)]
+pub enum TaskRunEventPayload {
+    Logs(TaskRunLogsPayload),
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TaskRunLogsPayload {
+    /// The lines of output. When the lines were stored in a file, only the last lines
+    pub lines: Vec<TaskRunLogLine>,
+    /// The file that contains all the lines, when they were too large to be stored in the event
+    pub file_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TaskRunLogLine {
+    pub stream: TaskRunLogStream,
+    pub line: String,
+}
+
+#[derive(Serialize, Deserialize, TS, Debug, Clone, Copy, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum TaskRunLogStream {
+    Stdout,
+    Stderr,
+}
+
+impl TaskRunLogLine {
+    /// The line as shown in files and notifications, stderr lines being prefixed with `[stderr] `
+    pub fn to_text(&self) -> String {
+        match self.stream {
+            TaskRunLogStream::Stdout => self.line.clone(),
+            TaskRunLogStream::Stderr => format!("[stderr] {}", self.line),
+        }
+    }
+}
+
+/// Validates the lines sent at once, and truncates the lines that are too long
+pub fn sanitize_task_run_log_lines(mut lines: Vec<TaskRunLogLine>) -> Result<Vec<TaskRunLogLine>, String> {
+    if lines.is_empty() {
+        return Err("at least one line is required".to_string());
+    }
+    if lines.len() > MAXIMUM_TASK_RUN_LOG_LINES_PER_REQUEST {
+        return Err(format!(
+            "at most {MAXIMUM_TASK_RUN_LOG_LINES_PER_REQUEST} lines can be sent at once"
+        ));
+    }
+    for line in lines.iter_mut() {
+        if let Some((index, _)) = line.line.char_indices().nth(MAXIMUM_TASK_RUN_LOG_LINE_LENGTH) {
+            line.line.truncate(index);
+        }
+    }
+    Ok(lines)
+}
+
+/// Whether the lines are too large to be stored in an event, and must be stored in the file storage
+pub fn task_run_log_lines_exceed_inline_size(lines: &[TaskRunLogLine]) -> bool {
+    lines.iter().map(|l| l.line.len() + 1).sum::<usize>() > MAXIMUM_INLINE_TASK_RUN_LOGS_SIZE
+}
+
+/// The content of the file that stores lines of output, one output line per line
+pub fn task_run_log_lines_to_text(lines: &[TaskRunLogLine]) -> String {
+    lines.iter().map(|line| line.to_text() + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(stream: TaskRunLogStream, line: &str) -> TaskRunLogLine {
+        TaskRunLogLine {
+            stream,
+            line: line.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_sanitize_task_run_log_lines() {
+        assert!(sanitize_task_run_log_lines(vec![]).is_err());
+        let too_many = vec![line(TaskRunLogStream::Stdout, "a"); MAXIMUM_TASK_RUN_LOG_LINES_PER_REQUEST + 1];
+        assert!(sanitize_task_run_log_lines(too_many).is_err());
+
+        let long_line = "é".repeat(MAXIMUM_TASK_RUN_LOG_LINE_LENGTH + 10);
+        let lines = sanitize_task_run_log_lines(vec![line(TaskRunLogStream::Stderr, &long_line)]).unwrap();
+        assert_eq!(lines[0].line.chars().count(), MAXIMUM_TASK_RUN_LOG_LINE_LENGTH);
+    }
+
+    #[test]
+    fn test_task_run_log_lines_to_text() {
+        let lines = vec![
+            line(TaskRunLogStream::Stdout, "copying files"),
+            line(TaskRunLogStream::Stderr, "disk full"),
+        ];
+        assert_eq!(task_run_log_lines_to_text(&lines), "copying files\n[stderr] disk full\n");
+        assert!(!task_run_log_lines_exceed_inline_size(&lines));
+
+        let large = vec![line(TaskRunLogStream::Stdout, &"a".repeat(1024)); 20];
+        assert!(task_run_log_lines_exceed_inline_size(&large));
+    }
+}
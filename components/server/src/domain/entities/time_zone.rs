@@ -0,0 +1,19 @@
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// Converts a local date and time to UTC. Ambiguous times resolve to their first occurrence,
+/// and times skipped by a DST change to the first time after the change
+pub fn local_to_utc(time_zone: Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    time_zone
+        .from_local_datetime(&local)
+        .earliest()
+        .or_else(|| time_zone.from_local_datetime(&(local + Duration::hours(1))).earliest())
+        .map(|date| date.with_timezone(&Utc))
+        .unwrap_or_else(|| local.and_utc())
+}
+
+pub fn parse_time_zone(time_zone: &str) -> Result<Tz, String> {
+    time_zone
+        .parse::<Tz>()
+        .map_err(|_| format!("Invalid time zone '{time_zone}'"))
+}
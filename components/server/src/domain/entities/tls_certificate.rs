@@ -0,0 +1,137 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// The number of days before expiry an incident is opened at, when not set on the monitor
+pub const DEFAULT_TLS_EXPIRY_WARNING_DAYS: i16 = 14;
+
+/// The maximum number of days before expiry an incident can be opened at
+pub const MAXIMUM_TLS_EXPIRY_WARNING_DAYS: i16 = 365;
+
+/// The leaf certificate presented by the server of an HTTPS monitor
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TlsCertificate {
+    pub subject: String,
+    pub issuer: String,
+    pub subject_alternative_names: Vec<String>,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    /// Whether the host of the monitor's URL matches one of the names of the certificate
+    pub hostname_matches: bool,
+    /// Whether the certificate chain was verified by the probe engine
+    pub valid_chain: bool,
+}
+
+impl TlsCertificate {
+    /// Whether the certificate expires within the given number of days, or has already expired
+    pub fn expires_within(&self, now: DateTime<Utc>, days: i16) -> bool {
+        self.not_after <= now + Duration::days(days as i64)
+    }
+}
+
+/// The certificate observed on the last ping of an HTTPS monitor that went through the TLS handshake
+#[derive(Serialize, Deserialize, TS, Debug, Clone, PartialEq, Eq, Default, ToSchema)]
+#[serde(transparent)]
+#[ts(export)]
+pub struct ObservedTlsCertificate(pub Option<TlsCertificate>);
+
+impl From<Value> for ObservedTlsCertificate {
+    fn from(value: Value) -> Self {
+        serde_json::from_value(value).unwrap_or_default()
+    }
+}
+
+/// Whether a hostname is covered by one of the names of a certificate.
+/// Wildcards only match a single, leftmost label, so `*.example.com` matches `www.example.com`
+/// but neither `example.com` nor `a.www.example.com`.
+pub fn certificate_matches_hostname(names: &[String], hostname: &str) -> bool {
+    let hostname = hostname.trim_end_matches('.').to_ascii_lowercase();
+    names.iter().any(|name| {
+        let name = name.trim_end_matches('.').to_ascii_lowercase();
+        match name.strip_prefix("*.") {
+            Some(suffix) => hostname
+                .split_once('.')
+                .is_some_and(|(label, rest)| !label.is_empty() && rest == suffix),
+            None => name == hostname,
+        }
+    })
+}
+
+/// Checks the number of days before expiry an incident is opened at. Zero disables the incidents
+pub fn validate_tls_expiry_warning_days(days: i16) -> Result<(), String> {
+    if !(0..=MAXIMUM_TLS_EXPIRY_WARNING_DAYS).contains(&days) {
+        return Err(format!(
+            "The TLS expiry warning must be between 0 and {MAXIMUM_TLS_EXPIRY_WARNING_DAYS} days"
+        ));
+    }
+    Ok(())
+}
+
+pub fn default_tls_expiry_warning_days() -> i16 {
+    DEFAULT_TLS_EXPIRY_WARNING_DAYS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn certificate_matches_hostname_tests() {
+        let names = vec!["example.com".to_string(), "*.example.com".to_string()];
+        assert!(certificate_matches_hostname(&names, "example.com"));
+        assert!(certificate_matches_hostname(&names, "WWW.Example.com"));
+        assert!(certificate_matches_hostname(&names, "www.example.com."));
+        assert!(!certificate_matches_hostname(&names, "a.www.example.com"));
+        assert!(!certificate_matches_hostname(&names, "example.org"));
+
+        let wildcard_only = vec!["*.example.com".to_string()];
+        assert!(!certificate_matches_hostname(&wildcard_only, "example.com"));
+        assert!(!certificate_matches_hostname(&[], "example.com"));
+
+        let ip_address = vec!["127.0.0.1".to_string()];
+        assert!(certificate_matches_hostname(&ip_address, "127.0.0.1"));
+    }
+
+    #[test]
+    fn expires_within_tests() {
+        let now = Utc::now();
+        let certificate = TlsCertificate {
+            subject: "CN=example.com".to_string(),
+            issuer: "CN=Example CA".to_string(),
+            subject_alternative_names: vec!["example.com".to_string()],
+            not_before: now - Duration::days(80),
+            not_after: now + Duration::days(10),
+            hostname_matches: true,
+            valid_chain: true,
+        };
+        assert!(certificate.expires_within(now, 14));
+        assert!(certificate.expires_within(now, 10));
+        assert!(!certificate.expires_within(now, 9));
+        assert!(!certificate.expires_within(now, 0));
+
+        let expired = TlsCertificate {
+            not_after: now - Duration::days(1),
+            ..certificate
+        };
+        assert!(expired.expires_within(now, 0));
+    }
+
+    #[test]
+    fn stored_certificate_falls_back_to_none() {
+        assert_eq!(ObservedTlsCertificate::from(serde_json::json!(null)), ObservedTlsCertificate(None));
+        assert_eq!(ObservedTlsCertificate::from(serde_json::json!({"subject": 1})), ObservedTlsCertificate(None));
+    }
+
+    #[test]
+    fn validate_tls_expiry_warning_days_tests() {
+        assert!(validate_tls_expiry_warning_days(0).is_ok());
+        assert!(validate_tls_expiry_warning_days(DEFAULT_TLS_EXPIRY_WARNING_DAYS).is_ok());
+        assert!(validate_tls_expiry_warning_days(MAXIMUM_TLS_EXPIRY_WARNING_DAYS).is_ok());
+        assert!(validate_tls_expiry_warning_days(-1).is_err());
+        assert!(validate_tls_expiry_warning_days(MAXIMUM_TLS_EXPIRY_WARNING_DAYS + 1).is_err());
+    }
+}
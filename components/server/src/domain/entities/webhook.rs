@@ -0,0 +1,334 @@
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use ts_rs::TS;
+use url::{Host, Url};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use veil::Redact;
+
+use super::{
+    entity_metadata::MetadataFilter,
+    incident::Incident,
+    incident_event::{IncidentEvent, IncidentEventType},
+};
+
+/// The incident events that can be sent to webhook endpoints
+pub const WEBHOOK_EVENT_TYPES: [IncidentEventType; 5] = [
+    IncidentEventType::Creation,
+    IncidentEventType::Confirmation,
+    IncidentEventType::Acknowledged,
+    IncidentEventType::Comment,
+    IncidentEventType::Resolution,
+];
+
+/// A delivery is given up after this number of failed attempts
+pub const MAXIMUM_WEBHOOK_DELIVERY_ATTEMPTS: i16 = 8;
+
+/// The delay before the first retry of a failed delivery. It is doubled after every failed attempt
+pub const WEBHOOK_DELIVERY_INITIAL_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Deliveries are claimed for this duration while they are being sent.
+/// A delivery whose outcome could not be recorded is sent again once its claim expires
+pub const WEBHOOK_DELIVERY_CLAIM_DURATION: Duration = Duration::from_secs(300);
+
+/// Whether webhooks can be sent to an IP address.
+/// Loopback, private, link-local and other non-routable addresses are refused,
+/// so that webhook endpoints cannot be used to reach the services of the server's network
+pub fn is_public_ip_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4_address(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ipv4_address(ip),
+            None => {
+                !(ip.is_unspecified()
+                    || ip.is_loopback()
+                    || ip.is_multicast()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local())
+            }
+        },
+    }
+}
+
+fn is_public_ipv4_address(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    !(first == 0
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        // shared address space (100.64.0.0/10)
+        || (first == 100 && (second & 0xc0) == 64))
+}
+
+/// Whether webhooks can be sent to the host of an URL.
+/// IP addresses must be public. Host names are checked again when they are resolved, before each delivery
+pub fn is_allowed_webhook_host(url: &Url) -> bool {
+    match url.host() {
+        Some(Host::Ipv4(ip)) => is_public_ipv4_address(ip),
+        Some(Host::Ipv6(ip)) => is_public_ip_address(IpAddr::V6(ip)),
+        Some(Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain != "localhost" && !domain.ends_with(".localhost")
+        }
+        None => false,
+    }
+}
+
+/// An URL that receives the lifecycle events of the incidents of an organization
+#[derive(Redact, Clone, Serialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct WebhookEndpoint {
+    pub organization_id: Uuid,
+    pub id: Uuid,
+    pub url: String,
+    /// Used to sign the payloads. It is only returned when the endpoint is created
+    #[redact]
+    #[serde(skip_serializing)]
+    #[ts(skip)]
+    #[schema(write_only)]
+    pub secret: String,
+    pub event_types: Vec<IncidentEventType>,
+    /// Only the events of the incidents matching this filter are sent
+    pub metadata_filter: MetadataFilter,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WebhookEndpoint {
+    /// Generates a random 256-bit secret, encoded as an hexadecimal string
+    pub fn generate_secret() -> String {
+        let mut rng = rand::thread_rng();
+        hex::encode((0..32).map(|_| rng.gen::<u8>()).collect::<Vec<_>>())
+    }
+
+    /// Whether an event of an incident should be sent to this endpoint
+    pub fn is_triggered_by(&self, incident: &Incident, event: &IncidentEvent) -> bool {
+        incident.organization_id == self.organization_id
+            && self.event_types.contains(&event.event_type)
+            && self.metadata_filter.matches(&incident.metadata)
+    }
+
+    /// Computes the HMAC-SHA256 signature of a payload, encoded as an hexadecimal string
+    pub fn sign(&self, payload: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC can take a key of any size");
+        mac.update(payload);
+        hex::encode(mac.finalize().into_bytes())
+    }
+}
+
+/// The JSON body that is posted to webhook endpoints
+#[derive(Serialize, Deserialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct WebhookPayload {
+    pub event_type: IncidentEventType,
+    pub incident: Incident,
+    pub event: IncidentEvent,
+}
+
+/// An attempt (or a series of attempts) to send an incident event to a webhook endpoint
+#[derive(Serialize, TS, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct WebhookDelivery {
+    pub organization_id: Uuid,
+    pub id: Uuid,
+    pub webhook_endpoint_id: Uuid,
+    pub incident_id: Uuid,
+    pub event_type: IncidentEventType,
+    pub payload: WebhookPayload,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i16,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    pub last_response_http_code: Option<i16>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookDelivery {
+    /// Records the outcome of a delivery attempt.
+    /// Failed attempts are retried with an exponential backoff, until the maximum number of attempts is reached
+    pub fn record_attempt(&mut self, http_code: Option<u16>, error: Option<String>, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.last_attempt_at = Some(now);
+        self.last_response_http_code = http_code.map(|code| code as i16);
+
+        let succeeded = error.is_none() && http_code.is_some_and(|code| (200..300).contains(&code));
+        self.last_error = match (error, http_code) {
+            (Some(error), _) => Some(error),
+            (None, Some(code)) if !succeeded => Some(format!("Endpoint responded with HTTP {code}")),
+            _ => None,
+        };
+
+        if succeeded {
+            self.status = WebhookDeliveryStatus::Delivered;
+            self.next_attempt_at = None;
+        } else if self.attempts >= MAXIMUM_WEBHOOK_DELIVERY_ATTEMPTS {
+            self.status = WebhookDeliveryStatus::Failed;
+            self.next_attempt_at = None;
+        } else {
+            let retry_delay = WEBHOOK_DELIVERY_INITIAL_RETRY_DELAY * 2u32.pow(self.attempts as u32 - 1);
+            self.next_attempt_at = Some(now + retry_delay);
+        }
+    }
+}
+
+#[derive(sqlx::Type, Serialize, Deserialize, TS, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[repr(i16)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum WebhookDeliveryStatus {
+    Pending = 0,
+    Delivered = 1,
+    Failed = 2,
+}
+
+impl From<i16> for WebhookDeliveryStatus {
+    fn from(value: i16) -> Self {
+        match value {
+            0 => Self::Pending,
+            1 => Self::Delivered,
+            2 => Self::Failed,
+            _ => panic!("invalid WebhookDeliveryStatus discriminant: {value}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::incident::{IncidentPriority, IncidentSourceType, IncidentStatus};
+
+    fn test_delivery() -> WebhookDelivery {
+        let event = IncidentEvent {
+            organization_id: Uuid::new_v4(),
+            incident_id: Uuid::new_v4(),
+            user_id: None,
+            created_at: Utc::now(),
+            event_type: IncidentEventType::Creation,
+            event_payload: None,
+        };
+        WebhookDelivery {
+            organization_id: event.organization_id,
+            id: Uuid::new_v4(),
+            webhook_endpoint_id: Uuid::new_v4(),
+            incident_id: event.incident_id,
+            event_type: event.event_type,
+            payload: WebhookPayload {
+                event_type: event.event_type,
+                incident: Incident {
+                    organization_id: event.organization_id,
+                    id: event.incident_id,
+                    created_at: Utc::now(),
+                    created_by: None,
+                    resolved_at: None,
+                    cause: None,
+                    status: IncidentStatus::Ongoing,
+                    priority: IncidentPriority::Major,
+                    incident_source_type: IncidentSourceType::HttpMonitor,
+                    incident_source_id: Uuid::new_v4(),
+                    acknowledged_by: vec![],
+                    title: None,
+                    metadata: Default::default(),
+                },
+                event,
+            },
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: Some(Utc::now()),
+            last_attempt_at: None,
+            last_response_http_code: None,
+            last_error: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_successful_attempt() {
+        let mut delivery = test_delivery();
+        delivery.record_attempt(Some(204), None, Utc::now());
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Delivered);
+        assert_eq!(delivery.attempts, 1);
+        assert_eq!(delivery.next_attempt_at, None);
+        assert_eq!(delivery.last_error, None);
+    }
+
+    #[test]
+    fn test_failed_attempts_are_retried_with_exponential_backoff() {
+        let mut delivery = test_delivery();
+        let now = Utc::now();
+
+        delivery.record_attempt(Some(500), None, now);
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.next_attempt_at, Some(now + Duration::from_secs(30)));
+        assert_eq!(delivery.last_response_http_code, Some(500));
+        assert!(delivery.last_error.is_some());
+
+        delivery.record_attempt(None, Some("Connection refused".to_string()), now);
+        assert_eq!(delivery.next_attempt_at, Some(now + Duration::from_secs(60)));
+        assert_eq!(delivery.last_error.as_deref(), Some("Connection refused"));
+
+        for _ in 2..MAXIMUM_WEBHOOK_DELIVERY_ATTEMPTS {
+            delivery.record_attempt(Some(500), None, now);
+        }
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+        assert_eq!(delivery.next_attempt_at, None);
+    }
+
+    #[test]
+    fn test_allowed_webhook_hosts() {
+        let allowed = |url: &str| is_allowed_webhook_host(&Url::parse(url).unwrap());
+        assert!(allowed("https://example.com/hook"));
+        assert!(allowed("https://93.184.216.34/hook"));
+        assert!(allowed("https://[2606:2800:220:1:248:1893:25c8:1946]/hook"));
+
+        assert!(!allowed("http://localhost:8080/hook"));
+        assert!(!allowed("http://api.localhost/hook"));
+        assert!(!allowed("http://127.0.0.1/hook"));
+        // decimal notation of 127.0.0.1
+        assert!(!allowed("http://2130706433/hook"));
+        assert!(!allowed("http://10.0.0.12/hook"));
+        assert!(!allowed("http://172.16.5.4/hook"));
+        assert!(!allowed("http://192.168.1.1/hook"));
+        assert!(!allowed("http://169.254.169.254/latest/meta-data"));
+        assert!(!allowed("http://100.64.0.1/hook"));
+        assert!(!allowed("http://0.0.0.0/hook"));
+        assert!(!allowed("http://[::1]/hook"));
+        assert!(!allowed("http://[fd00::1]/hook"));
+        assert!(!allowed("http://[fe80::1]/hook"));
+        assert!(!allowed("http://[::ffff:127.0.0.1]/hook"));
+    }
+
+    #[test]
+    fn test_signature() {
+        let endpoint = WebhookEndpoint {
+            organization_id: Uuid::new_v4(),
+            id: Uuid::new_v4(),
+            url: "https://example.com/hook".to_string(),
+            secret: "key".to_string(),
+            event_types: WEBHOOK_EVENT_TYPES.to_vec(),
+            metadata_filter: MetadataFilter::default(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        // Well-known HMAC-SHA256 test vector
+        assert_eq!(
+            endpoint.sign(b"The quick brown fox jumps over the lazy dog"),
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use uuid::Uuid;
+
+use crate::domain::entities::{alert_integration::AlertIntegration, incident::IncidentPriority};
+
+use super::transactional_repository::TransactionalRepository;
+
+#[derive(Clone)]
+pub struct NewAlertIntegration {
+    pub organization_id: Uuid,
+    pub name: String,
+    pub secret: String,
+    pub default_priority: IncidentPriority,
+}
+
+#[async_trait::async_trait]
+pub trait AlertIntegrationRepository:
+    TransactionalRepository + Clone + Send + Sync + 'static
+{
+    /// Lists all the alert integrations of an organization
+    async fn list_alert_integrations(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<AlertIntegration>>;
+
+    async fn get_alert_integration(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<AlertIntegration>>;
+
+    /// Gets an alert integration regardless of its organization, to authenticate the alerts it receives
+    async fn get_alert_integration_by_id(
+        &self,
+        tx: &mut Self::Transaction,
+        id: Uuid,
+    ) -> anyhow::Result<Option<AlertIntegration>>;
+
+    async fn create_alert_integration(
+        &self,
+        tx: &mut Self::Transaction,
+        integration: NewAlertIntegration,
+    ) -> anyhow::Result<Uuid>;
+
+    /// Updates an alert integration. Its secret is never updated.
+    /// Returns false if the integration does not exist
+    async fn update_alert_integration(
+        &self,
+        tx: &mut Self::Transaction,
+        id: Uuid,
+        integration: NewAlertIntegration,
+    ) -> anyhow::Result<bool>;
+
+    /// Deletes an alert integration. The incidents it opened are kept.
+    /// Returns false if the integration does not exist
+    async fn delete_alert_integration(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool>;
+}
@@ -0,0 +1,24 @@
+use uuid::Uuid;
+
+use crate::domain::entities::escalation_policy::EscalationPolicy;
+
+use super::transactional_repository::TransactionalRepository;
+
+#[async_trait::async_trait]
+pub trait EscalationPolicyRepository:
+    TransactionalRepository + Clone + Send + Sync + 'static
+{
+    /// Returns the escalation policy of an organization, if it has configured one
+    async fn get_escalation_policy(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Option<EscalationPolicy>>;
+
+    /// Creates or replaces the escalation policy of an organization
+    async fn upsert_escalation_policy(
+        &self,
+        tx: &mut Self::Transaction,
+        policy: EscalationPolicy,
+    ) -> anyhow::Result<()>;
+}
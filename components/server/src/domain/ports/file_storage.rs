@@ -30,6 +30,9 @@ pub trait FileStorage: Clone + Send + Sync + 'static {
     #[allow(unused)]
     async fn get_file(&self, key: FileStorageKey) -> anyhow::Result<Vec<u8>>;
 
+    /// Deletes the file. Deleting a file that does not exist is not an error
+    async fn delete_file(&self, key: FileStorageKey) -> anyhow::Result<()>;
+
     /// Returns a presigned URL for the file
     async fn get_file_url(&self, key: FileStorageKey) -> anyhow::Result<Url>;
 }
@@ -1,7 +1,47 @@
-use crate::domain::entities::http_monitor::HttpMonitorErrorKind;
+use crate::domain::entities::{
+    http_monitor::{HttpMonitor, HttpMonitorErrorKind},
+    http_monitor_request::{
+        HttpMethod, HttpMonitorAuthentication, HttpMonitorRedirectPolicy, ProbeEngine,
+    },
+    monitor_kind::MonitorKindSettings,
+    tls_certificate::TlsCertificate,
+};
 use async_trait::async_trait;
 use std::{collections::HashMap, time::Duration};
 
+/// The request sent to ping a monitor
+#[derive(Debug, Clone)]
+pub struct PingRequest {
+    pub endpoint: String,
+    pub request_timeout: Duration,
+    pub request_headers: HashMap<String, String>,
+    pub method: HttpMethod,
+    pub body: Option<String>,
+    pub body_content_type: Option<String>,
+    pub authentication: HttpMonitorAuthentication,
+    pub redirect_policy: HttpMonitorRedirectPolicy,
+    pub probe_engine: ProbeEngine,
+    /// Monitors that are not HTTP monitors are probed by the server itself, whatever their probe engine
+    pub kind_settings: MonitorKindSettings,
+}
+
+impl From<&HttpMonitor> for PingRequest {
+    fn from(monitor: &HttpMonitor) -> Self {
+        Self {
+            endpoint: monitor.url.clone(),
+            request_timeout: monitor.request_timeout(),
+            request_headers: monitor.request_headers.headers.clone(),
+            method: monitor.request_method,
+            body: monitor.request_body.clone(),
+            body_content_type: monitor.request_body_content_type.clone(),
+            authentication: monitor.authentication.clone(),
+            redirect_policy: monitor.redirect_policy,
+            probe_engine: monitor.probe_engine,
+            kind_settings: monitor.kind_settings.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Screenshot {
     pub data: Vec<u8>,
@@ -16,10 +56,11 @@ pub struct PingResponse {
     pub response_time: Duration,
     pub response_ip_address: Option<String>,
     pub resolved_ip_addresses: Vec<String>,
-    #[allow(unused)]
     pub response_body_size_bytes: u64,
     pub response_body_content: Option<Vec<u8>>,
-    pub screenshot: Option<Screenshot>
+    pub screenshot: Option<Screenshot>,
+    /// The certificate presented by the server, when the TLS handshake was performed
+    pub tls_certificate: Option<TlsCertificate>,
 }
 
 impl std::fmt::Debug for PingResponse {
@@ -30,16 +71,16 @@ impl std::fmt::Debug for PingResponse {
             .field("http_headers_len", &self.http_headers.len())
             .field("response_ip_address", &self.response_ip_address)
             .field("has_screenshot", &self.screenshot.is_some())
+            .field("tls_certificate", &self.tls_certificate)
             .finish()
     }
 }
 
 #[async_trait]
 pub trait HttpClient: Clone + Send + Sync + 'static {
-    async fn ping(
-        &self,
-        endpoint: &str,
-        request_timeout: Duration,
-        request_headers: HashMap<String, String>,
-    ) -> PingResponse;
+    /// Names of the probe locations the client can ping from
+    /// The first location is the default one
+    fn locations(&self) -> Vec<String>;
+
+    async fn ping(&self, location: &str, request: &PingRequest) -> PingResponse;
 }
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::domain::entities::{entity_metadata::{EntityMetadata, FilterableMetadata, MetadataFilter}, http_monitor::{HttpMonitor, HttpMonitorErrorKind, HttpMonitorStatus, RequestHeaders}};
+use crate::domain::entities::{entity_metadata::{EntityMetadata, FilterableMetadata, MetadataFilter}, http_monitor::{HttpMonitor, HttpMonitorErrorKind, HttpMonitorStatus, RequestHeaders}, http_monitor_assertion::HttpMonitorAssertions, http_monitor_ping::{HttpMonitorPing, HttpMonitorPingRollup}, http_monitor_request::{HttpMethod, HttpMonitorAuthentication, HttpMonitorRedirectPolicy, ProbeEngine}, monitor_kind::MonitorKindSettings, tls_certificate::TlsCertificate};
 
 use super::transactional_repository::TransactionalRepository;
 
@@ -56,6 +56,33 @@ pub trait HttpMonitorRepository: TransactionalRepository + Clone + Send + Sync +
 
     /// Get the filterable metadata for all the monitors of an organization
     async fn get_filterable_metadata(&self, organization_id: Uuid) -> anyhow::Result<FilterableMetadata>;
+
+    /// Records the result of a ping, and adds it to the hourly rollup of the monitor
+    async fn record_ping(
+        &self,
+        transaction: &mut Self::Transaction,
+        ping: HttpMonitorPing,
+    ) -> anyhow::Result<()>;
+
+    /// Lists the pings of a monitor between two dates, from the oldest to the most recent
+    async fn list_pings(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        monitor_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<HttpMonitorPing>>;
+
+    /// Lists the hourly rollups of the pings of a monitor that start between two dates, from the oldest to the most recent
+    async fn list_ping_rollups(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        monitor_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<HttpMonitorPingRollup>>;
 }
 
 #[derive(Debug)]
@@ -73,6 +100,17 @@ pub struct NewHttpMonitor {
     pub sms_notification_enabled: bool,
     pub request_headers: RequestHeaders,
     pub request_timeout_ms: i32,
+    pub assertions: HttpMonitorAssertions,
+    pub locations: Vec<String>,
+    pub location_quorum: Option<i16>,
+    pub request_method: HttpMethod,
+    pub request_body: Option<String>,
+    pub request_body_content_type: Option<String>,
+    pub authentication: HttpMonitorAuthentication,
+    pub redirect_policy: HttpMonitorRedirectPolicy,
+    pub probe_engine: ProbeEngine,
+    pub tls_expiry_warning_days: i16,
+    pub kind_settings: MonitorKindSettings,
 }
 
 #[derive(Debug)]
@@ -88,6 +126,9 @@ pub struct UpdateHttpMonitorStatusCommand {
     /// If None, the last http code will be removed
     pub last_http_code: Option<i16>,
     pub archived_at: Option<DateTime<Utc>>,
+    /// The certificate observed by the ping
+    /// If None, the last observed certificate is kept, as failed pings may not reach the TLS handshake
+    pub tls_certificate: Option<TlsCertificate>,
 }
 
 pub struct ListHttpMonitorsOutput {
@@ -6,12 +6,23 @@ use super::transactional_repository::TransactionalRepository;
 
 #[async_trait::async_trait]
 pub trait IncidentEventRepository: TransactionalRepository + Clone + Send + Sync + 'static {
+    /// Records a new incident event.
+    /// Events whose type is one of [WEBHOOK_EVENT_TYPES](crate::domain::entities::webhook::WEBHOOK_EVENT_TYPES) are also marked as pending a webhook dispatch
     async fn create_incident_event(
         &self,
         tx: &mut Self::Transaction,
         event: IncidentEvent,
     ) -> anyhow::Result<()>;
 
+    /// Returns the events that have not been dispatched to webhook endpoints yet, and marks them as dispatched.
+    /// This must be executed inside a transaction.
+    /// Concurrent transactions will not return the same events (events that are locked by a transaction will be skipped)
+    async fn take_events_pending_webhook_dispatch(
+        &self,
+        tx: &mut Self::Transaction,
+        limit: u32,
+    ) -> anyhow::Result<Vec<IncidentEvent>>;
+
     async fn get_incident_timeline(
         &self,
         organization_id: Uuid,
@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::maintenance_window::{MaintenanceWindow, MaintenanceWindowScope};
+
+use super::transactional_repository::TransactionalRepository;
+
+#[derive(Clone, Debug)]
+pub struct NewMaintenanceWindow {
+    pub organization_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub cron_schedule: Option<String>,
+    pub duration_seconds: Option<i32>,
+    pub scope: MaintenanceWindowScope,
+}
+
+#[async_trait::async_trait]
+pub trait MaintenanceWindowRepository: TransactionalRepository + Clone + Send + Sync + 'static {
+    /// Lists all the maintenance windows of an organization, from the most recent to the oldest
+    async fn list_maintenance_windows(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<MaintenanceWindow>>;
+
+    /// Lists the maintenance windows of an organization that may be ongoing at some point after a date,
+    /// i.e. the one-off windows that end after this date and the recurring windows that still recur
+    async fn list_maintenance_windows_ending_after(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        date: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<MaintenanceWindow>>;
+
+    async fn create_maintenance_window(
+        &self,
+        tx: &mut Self::Transaction,
+        maintenance_window: NewMaintenanceWindow,
+    ) -> anyhow::Result<Uuid>;
+
+    /// Deletes a maintenance window.
+    /// Returns false if the maintenance window does not exist
+    async fn delete_maintenance_window(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool>;
+}
@@ -13,4 +13,14 @@ pub mod sms_notification_server;
 pub mod file_storage;
 pub mod api_access_token_repository;
 pub mod task_repository;
-pub mod task_run_repository;
\ No newline at end of file
+pub mod task_run_repository;
+pub mod escalation_policy_repository;
+pub mod webhook_client;
+pub mod webhook_delivery_repository;
+pub mod webhook_endpoint_repository;
+pub mod status_page_repository;
+pub mod maintenance_window_repository;
+pub mod on_call_schedule_repository;
+pub mod notification_preferences_repository;
+pub mod alert_integration_repository;
+pub mod task_run_event_repository;
\ No newline at end of file
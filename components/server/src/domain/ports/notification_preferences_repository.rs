@@ -0,0 +1,32 @@
+use uuid::Uuid;
+
+use crate::domain::entities::notification_preferences::NotificationPreferences;
+
+use super::transactional_repository::TransactionalRepository;
+
+#[async_trait::async_trait]
+pub trait NotificationPreferencesRepository:
+    TransactionalRepository + Clone + Send + Sync + 'static
+{
+    /// Returns the notification preferences of a member of an organization, if they have configured them
+    async fn get_notification_preferences(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<NotificationPreferences>>;
+
+    /// Lists the notification preferences of all the members of an organization that have configured them
+    async fn list_notification_preferences(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<NotificationPreferences>>;
+
+    /// Creates or replaces the notification preferences of a member of an organization
+    async fn upsert_notification_preferences(
+        &self,
+        tx: &mut Self::Transaction,
+        preferences: NotificationPreferences,
+    ) -> anyhow::Result<()>;
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::on_call_schedule::{OnCallOverride, OnCallRotations, OnCallSchedule};
+
+use super::transactional_repository::TransactionalRepository;
+
+#[derive(Clone, Debug)]
+pub struct NewOnCallSchedule {
+    pub organization_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub time_zone: String,
+    pub rotations: OnCallRotations,
+}
+
+#[derive(Clone, Debug)]
+pub struct NewOnCallOverride {
+    pub organization_id: Uuid,
+    pub on_call_schedule_id: Uuid,
+    pub user_id: Uuid,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+#[async_trait::async_trait]
+pub trait OnCallScheduleRepository: TransactionalRepository + Clone + Send + Sync + 'static {
+    /// Lists all the on-call schedules of an organization, by name
+    async fn list_on_call_schedules(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<OnCallSchedule>>;
+
+    async fn get_on_call_schedule(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<OnCallSchedule>>;
+
+    async fn create_on_call_schedule(
+        &self,
+        tx: &mut Self::Transaction,
+        schedule: NewOnCallSchedule,
+    ) -> anyhow::Result<Uuid>;
+
+    /// Updates an on-call schedule.
+    /// Returns false if the schedule does not exist
+    async fn update_on_call_schedule(
+        &self,
+        tx: &mut Self::Transaction,
+        id: Uuid,
+        schedule: NewOnCallSchedule,
+    ) -> anyhow::Result<bool>;
+
+    /// Deletes an on-call schedule and its overrides.
+    /// Returns false if the schedule does not exist
+    async fn delete_on_call_schedule(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool>;
+
+    /// Lists the overrides of a schedule that end after a date, from the oldest to the most recent
+    async fn list_on_call_overrides_ending_after(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        on_call_schedule_id: Uuid,
+        date: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<OnCallOverride>>;
+
+    async fn create_on_call_override(
+        &self,
+        tx: &mut Self::Transaction,
+        on_call_override: NewOnCallOverride,
+    ) -> anyhow::Result<Uuid>;
+
+    /// Deletes an override of a schedule.
+    /// Returns false if the override does not exist
+    async fn delete_on_call_override(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        on_call_schedule_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool>;
+}
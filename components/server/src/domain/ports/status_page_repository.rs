@@ -0,0 +1,63 @@
+use uuid::Uuid;
+
+use crate::domain::entities::status_page::{StatusPage, StatusPageComponents, StatusPageVisibility};
+
+use super::transactional_repository::TransactionalRepository;
+
+#[derive(Clone, Debug)]
+pub struct NewStatusPage {
+    pub organization_id: Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub components: StatusPageComponents,
+    pub visibility: StatusPageVisibility,
+}
+
+#[async_trait::async_trait]
+pub trait StatusPageRepository: TransactionalRepository + Clone + Send + Sync + 'static {
+    /// Lists all the status pages of an organization
+    async fn list_status_pages(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<StatusPage>>;
+
+    async fn get_status_page(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<StatusPage>>;
+
+    /// Gets a status page by its slug. Slugs are unique across all the organizations
+    async fn get_status_page_by_slug(
+        &self,
+        tx: &mut Self::Transaction,
+        slug: &str,
+    ) -> anyhow::Result<Option<StatusPage>>;
+
+    async fn create_status_page(
+        &self,
+        tx: &mut Self::Transaction,
+        status_page: NewStatusPage,
+    ) -> anyhow::Result<Uuid>;
+
+    /// Updates a status page.
+    /// Returns false if the status page does not exist
+    async fn update_status_page(
+        &self,
+        tx: &mut Self::Transaction,
+        id: Uuid,
+        status_page: NewStatusPage,
+    ) -> anyhow::Result<bool>;
+
+    /// Deletes a status page.
+    /// Returns false if the status page does not exist
+    async fn delete_status_page(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool>;
+}
@@ -2,7 +2,10 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::domain::entities::task::{BoundaryTask, TaskId, TaskStatus};
+use crate::domain::entities::{
+    entity_metadata::{FilterableMetadata, MetadataFilter},
+    task::{BoundaryTask, TaskId, TaskStatus},
+};
 use super::transactional_repository::TransactionalRepository;
 
 #[async_trait]
@@ -15,16 +18,28 @@ pub trait TaskRepository: TransactionalRepository + Clone + Send + Sync + 'stati
         task_id: &TaskId,
     ) -> anyhow::Result<Option<BoundaryTask>>;
 
+    /// Get a single task by organization ID and task ID, and lock it until the end of the transaction
+    async fn get_task_for_update(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<Option<BoundaryTask>>;
+
     /// List tasks with pagination and filtering
     async fn list_tasks(
         &self,
         organization_id: Uuid,
         include_statuses: Vec<TaskStatus>,
         query: String,
+        metadata_filter: MetadataFilter,
         limit: u32,
         offset: u32,
     ) -> anyhow::Result<ListTasksOutput>;
 
+    /// Get the filterable metadata for all the tasks of an organization
+    async fn get_filterable_metadata(&self, organization_id: Uuid) -> anyhow::Result<FilterableMetadata>;
+
     /// Create or update an existing task
     async fn upsert_task(
         &self,
@@ -56,6 +71,46 @@ pub trait TaskRepository: TransactionalRepository + Clone + Send + Sync + 'stati
         limit: u32,
     ) -> anyhow::Result<Vec<BoundaryTask>>;
 
+    /// List the tasks that depend on a task
+    async fn list_downstream_tasks(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<Vec<BoundaryTask>>;
+
+    /// Get the task of a ping token, whatever its organization
+    async fn get_task_by_ping_token(
+        &self,
+        transaction: &mut Self::Transaction,
+        ping_token: &str,
+    ) -> anyhow::Result<Option<BoundaryTask>>;
+
+    /// Get the ping token of a task
+    async fn get_task_ping_token(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<Option<String>>;
+
+    /// Replace the ping token of a task. Returns false if the task does not exist
+    async fn set_task_ping_token(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+        ping_token: &str,
+    ) -> anyhow::Result<bool>;
+
+    /// Delete a task, along with its runs and their events, and remove it from the upstream tasks of other tasks.
+    /// Returns false if the task does not exist
+    async fn delete_task(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<bool>;
 }
 
 pub struct ListTasksOutput {
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    task::TaskId,
+    task_run_event::{TaskRunEvent, TaskRunEventType},
+};
+
+use super::transactional_repository::TransactionalRepository;
+
+#[async_trait]
+pub trait TaskRunEventRepository: TransactionalRepository + Clone + Send + Sync + 'static {
+    /// Records a new event for a task run
+    async fn create_task_run_event(
+        &self,
+        transaction: &mut Self::Transaction,
+        event: TaskRunEvent,
+    ) -> anyhow::Result<()>;
+
+    /// List the events of a task run, oldest first
+    #[allow(clippy::too_many_arguments)]
+    async fn list_task_run_events(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+        task_run_id: &str,
+        task_run_started_at: DateTime<Utc>,
+        limit: u32,
+        offset: u32,
+    ) -> anyhow::Result<ListTaskRunEventsOutput>;
+
+    /// List the latest events of a given type of a task run, newest first
+    #[allow(clippy::too_many_arguments)]
+    async fn list_latest_task_run_events(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+        task_run_id: &str,
+        task_run_started_at: DateTime<Utc>,
+        event_type: TaskRunEventType,
+        limit: u32,
+    ) -> anyhow::Result<Vec<TaskRunEvent>>;
+}
+
+pub struct ListTaskRunEventsOutput {
+    pub events: Vec<TaskRunEvent>,
+    pub total_events: u32,
+}
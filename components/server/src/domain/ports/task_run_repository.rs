@@ -43,16 +43,18 @@ pub trait TaskRunRepository: TransactionalRepository + Clone + Send + Sync + 'st
             .next())
     }
 
-    /// Get a single task run
+    /// Get a task run by its start date.
+    /// Runs of a task that started at the same time are told apart by their run id, when it is given
     async fn get_task_run(
         &self,
         transaction: &mut Self::Transaction,
         organization_id: Uuid,
         task_id: &TaskId,
         started_at: DateTime<Utc>,
+        run_id: Option<&str>,
     ) -> anyhow::Result<Option<BoundaryTaskRun>>;
 
-    /// Creates or updates an existing task run
+    /// Creates or updates an existing task run. Fails when another run of the task with the same run id is running
     async fn upsert_task_run(
         &self,
         transaction: &mut Self::Transaction,
@@ -66,6 +68,14 @@ pub trait TaskRunRepository: TransactionalRepository + Clone + Send + Sync + 'st
         now: DateTime<Utc>,
         limit: u32,
     ) -> anyhow::Result<Vec<(BoundaryTask, BoundaryTaskRun)>>;
+
+    /// List running task runs that exceeded the maximum duration of their task, along with their respective tasks
+    async fn list_timed_out_task_runs(
+        &self,
+        transaction: &mut Self::Transaction,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> anyhow::Result<Vec<(BoundaryTask, BoundaryTaskRun)>>;
 }
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, Default)]
+pub struct WebhookResponse {
+    /// The HTTP status code returned by the endpoint, if it responded
+    pub http_code: Option<u16>,
+    /// The reason why the request could not be sent or why no response was received, if any
+    pub error: Option<String>,
+}
+
+#[async_trait]
+pub trait WebhookClient: Clone + Send + Sync + 'static {
+    /// Posts a JSON body to an URL
+    async fn post(&self, url: &str, headers: HashMap<String, String>, body: Vec<u8>) -> WebhookResponse;
+}
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    incident_event::IncidentEventType,
+    webhook::{WebhookDelivery, WebhookPayload},
+};
+
+use super::transactional_repository::TransactionalRepository;
+
+#[derive(Clone)]
+pub struct NewWebhookDelivery {
+    pub organization_id: Uuid,
+    pub webhook_endpoint_id: Uuid,
+    pub incident_id: Uuid,
+    pub event_type: IncidentEventType,
+    pub payload: WebhookPayload,
+}
+
+#[async_trait::async_trait]
+pub trait WebhookDeliveryRepository:
+    TransactionalRepository + Clone + Send + Sync + 'static
+{
+    /// Creates a pending delivery, that is due immediately
+    async fn create_webhook_delivery(
+        &self,
+        tx: &mut Self::Transaction,
+        delivery: NewWebhookDelivery,
+    ) -> anyhow::Result<Uuid>;
+
+    /// Claims a list of pending deliveries whose next attempt is due, by postponing their next attempt to `claimed_until`.
+    /// Once the transaction is committed, the claimed deliveries will not be returned again until their claim expires,
+    /// so they can be sent outside of the transaction.
+    /// Concurrent transactions will not return the same deliveries (deliveries that are locked by a transaction will be skipped)
+    async fn claim_next_deliveries_to_send(
+        &self,
+        tx: &mut Self::Transaction,
+        limit: u32,
+        claimed_until: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<WebhookDelivery>>;
+
+    /// Updates the status and attempts of a delivery
+    async fn update_webhook_delivery(
+        &self,
+        tx: &mut Self::Transaction,
+        delivery: &WebhookDelivery,
+    ) -> anyhow::Result<()>;
+
+    /// Lists the deliveries of a webhook endpoint, most recent first
+    async fn list_webhook_deliveries(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        webhook_endpoint_id: Uuid,
+        limit: u32,
+        offset: u32,
+    ) -> anyhow::Result<ListWebhookDeliveriesOutput>;
+}
+
+pub struct ListWebhookDeliveriesOutput {
+    pub deliveries: Vec<WebhookDelivery>,
+    pub total_deliveries: u32,
+}
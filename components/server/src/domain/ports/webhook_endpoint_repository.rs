@@ -0,0 +1,59 @@
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    entity_metadata::MetadataFilter, incident_event::IncidentEventType, webhook::WebhookEndpoint,
+};
+
+use super::transactional_repository::TransactionalRepository;
+
+#[derive(Clone)]
+pub struct NewWebhookEndpoint {
+    pub organization_id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<IncidentEventType>,
+    pub metadata_filter: MetadataFilter,
+}
+
+#[async_trait::async_trait]
+pub trait WebhookEndpointRepository:
+    TransactionalRepository + Clone + Send + Sync + 'static
+{
+    /// Lists all the webhook endpoints of an organization
+    async fn list_webhook_endpoints(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<WebhookEndpoint>>;
+
+    async fn get_webhook_endpoint(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<WebhookEndpoint>>;
+
+    async fn create_webhook_endpoint(
+        &self,
+        tx: &mut Self::Transaction,
+        endpoint: NewWebhookEndpoint,
+    ) -> anyhow::Result<Uuid>;
+
+    /// Updates a webhook endpoint. Its secret is never updated.
+    /// Returns false if the endpoint does not exist
+    async fn update_webhook_endpoint(
+        &self,
+        tx: &mut Self::Transaction,
+        id: Uuid,
+        endpoint: NewWebhookEndpoint,
+    ) -> anyhow::Result<bool>;
+
+    /// Deletes a webhook endpoint and its deliveries.
+    /// Returns false if the endpoint does not exist
+    async fn delete_webhook_endpoint(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool>;
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        alert_integration::{validate_alert_integration_name, AlertIntegration},
+        authorization::{AuthContext, Permission},
+        incident::IncidentPriority,
+    },
+    ports::alert_integration_repository::{AlertIntegrationRepository, NewAlertIntegration},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Deserialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateAlertIntegrationCommand {
+    pub name: String,
+    pub default_priority: IncidentPriority,
+}
+
+#[derive(Serialize, TS, Clone, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateAlertIntegrationResponse {
+    pub id: Uuid,
+    /// The secret the external system must send as a bearer token. It will not be returned again
+    pub secret: String,
+}
+
+#[derive(Error, Debug)]
+pub enum CreateAlertIntegrationError {
+    #[error("Failed to create an alert integration: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to create alert integrations")]
+    Forbidden,
+    #[error("Invalid name: {0}")]
+    InvalidName(String),
+}
+
+pub async fn create_alert_integration(
+    auth_context: &AuthContext,
+    repository: &impl AlertIntegrationRepository,
+    command: CreateAlertIntegrationCommand,
+) -> Result<CreateAlertIntegrationResponse, CreateAlertIntegrationError> {
+    if !auth_context.can(Permission::WriteAlertIntegrations) {
+        return Err(CreateAlertIntegrationError::Forbidden);
+    }
+
+    validate_alert_integration_name(&command.name)
+        .map_err(CreateAlertIntegrationError::InvalidName)?;
+
+    let secret = AlertIntegration::generate_secret();
+    let mut tx = repository.begin_transaction().await?;
+    let id = repository
+        .create_alert_integration(
+            &mut tx,
+            NewAlertIntegration {
+                organization_id: auth_context.active_organization_id,
+                name: command.name,
+                secret: secret.clone(),
+                default_priority: command.default_priority,
+            },
+        )
+        .await?;
+    repository.commit_transaction(tx).await?;
+
+    Ok(CreateAlertIntegrationResponse { id, secret })
+}
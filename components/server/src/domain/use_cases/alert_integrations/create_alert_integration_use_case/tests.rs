@@ -0,0 +1,65 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        authorization::AuthContext, incident::IncidentPriority, organization::OrganizationUserRole,
+    },
+    infrastructure::mocks::alert_integration_repository_mock::AlertIntegrationRepositoryMock,
+};
+
+use super::{create_alert_integration, CreateAlertIntegrationCommand, CreateAlertIntegrationError};
+
+fn command(name: &str) -> CreateAlertIntegrationCommand {
+    CreateAlertIntegrationCommand {
+        name: name.to_string(),
+        default_priority: IncidentPriority::Major,
+    }
+}
+
+#[tokio::test]
+async fn test_create_alert_integration() -> anyhow::Result<()> {
+    let repository = AlertIntegrationRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let auth_context =
+        AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+
+    let response = create_alert_integration(&auth_context, &repository, command("Prometheus")).await?;
+
+    let state = repository.state.lock().await;
+    assert_eq!(state.len(), 1);
+    assert_eq!(state[0].id, response.id);
+    assert_eq!(state[0].organization_id, org_id);
+    assert_eq!(state[0].name, "Prometheus");
+    assert_eq!(state[0].secret, response.secret);
+    assert_eq!(response.secret.len(), 64);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_alert_integration_requires_write_permission() {
+    let repository = AlertIntegrationRepositoryMock::new();
+    let auth_context = AuthContext::test_context(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Reporter],
+        &[],
+    );
+
+    let result = create_alert_integration(&auth_context, &repository, command("Prometheus")).await;
+
+    assert!(matches!(result, Err(CreateAlertIntegrationError::Forbidden)));
+    assert!(repository.state.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_create_alert_integration_validation() {
+    let repository = AlertIntegrationRepositoryMock::new();
+    let auth_context =
+        AuthContext::test_context(Uuid::new_v4(), Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+
+    let result = create_alert_integration(&auth_context, &repository, command("  ")).await;
+    assert!(matches!(result, Err(CreateAlertIntegrationError::InvalidName(_))));
+
+    let result = create_alert_integration(&auth_context, &repository, command(&"a".repeat(101))).await;
+    assert!(matches!(result, Err(CreateAlertIntegrationError::InvalidName(_))));
+}
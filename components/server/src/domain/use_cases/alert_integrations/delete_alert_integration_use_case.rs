@@ -0,0 +1,39 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::authorization::{AuthContext, Permission},
+    ports::alert_integration_repository::AlertIntegrationRepository,
+};
+
+#[derive(Error, Debug)]
+pub enum DeleteAlertIntegrationError {
+    #[error("Failed to delete an alert integration: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to delete alert integrations")]
+    Forbidden,
+    #[error("Alert integration not found")]
+    NotFound,
+}
+
+/// Deletes an alert integration. The incidents it opened are kept, but they cannot be resolved by alerts anymore
+pub async fn delete_alert_integration(
+    auth_context: &AuthContext,
+    repository: &impl AlertIntegrationRepository,
+    alert_integration_id: Uuid,
+) -> Result<(), DeleteAlertIntegrationError> {
+    if !auth_context.can(Permission::WriteAlertIntegrations) {
+        return Err(DeleteAlertIntegrationError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let deleted = repository
+        .delete_alert_integration(&mut tx, auth_context.active_organization_id, alert_integration_id)
+        .await?;
+    if !deleted {
+        return Err(DeleteAlertIntegrationError::NotFound);
+    }
+    repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
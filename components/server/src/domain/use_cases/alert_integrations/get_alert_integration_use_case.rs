@@ -0,0 +1,36 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        alert_integration::AlertIntegration,
+        authorization::{AuthContext, Permission},
+    },
+    ports::alert_integration_repository::AlertIntegrationRepository,
+};
+
+#[derive(Error, Debug)]
+pub enum GetAlertIntegrationError {
+    #[error("User is not allowed to read alert integrations")]
+    Forbidden,
+    #[error("Alert integration not found")]
+    NotFound,
+    #[error("Technical failure occured while getting an alert integration")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+pub async fn get_alert_integration(
+    auth_context: &AuthContext,
+    repository: &impl AlertIntegrationRepository,
+    alert_integration_id: Uuid,
+) -> Result<AlertIntegration, GetAlertIntegrationError> {
+    if !auth_context.can(Permission::ReadAlertIntegrations) {
+        return Err(GetAlertIntegrationError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    repository
+        .get_alert_integration(&mut tx, auth_context.active_organization_id, alert_integration_id)
+        .await?
+        .ok_or(GetAlertIntegrationError::NotFound)
+}
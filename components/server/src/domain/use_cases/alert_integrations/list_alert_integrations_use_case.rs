@@ -0,0 +1,43 @@
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::{
+        alert_integration::AlertIntegration,
+        authorization::{AuthContext, Permission},
+    },
+    ports::alert_integration_repository::AlertIntegrationRepository,
+};
+
+#[derive(Serialize, TS, ToSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAlertIntegrationsResponse {
+    pub alert_integrations: Vec<AlertIntegration>,
+}
+
+#[derive(Error, Debug)]
+pub enum ListAlertIntegrationsError {
+    #[error("User is not allowed to list alert integrations")]
+    Forbidden,
+    #[error("Technical failure occured while listing alert integrations")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+pub async fn list_alert_integrations(
+    auth_context: &AuthContext,
+    repository: &impl AlertIntegrationRepository,
+) -> Result<ListAlertIntegrationsResponse, ListAlertIntegrationsError> {
+    if !auth_context.can(Permission::ReadAlertIntegrations) {
+        return Err(ListAlertIntegrationsError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let alert_integrations = repository
+        .list_alert_integrations(&mut tx, auth_context.active_organization_id)
+        .await?;
+
+    Ok(ListAlertIntegrationsResponse { alert_integrations })
+}
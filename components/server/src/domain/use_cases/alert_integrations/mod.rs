@@ -0,0 +1,13 @@
+mod create_alert_integration_use_case;
+mod delete_alert_integration_use_case;
+mod get_alert_integration_use_case;
+mod list_alert_integrations_use_case;
+mod receive_alerts_use_case;
+mod update_alert_integration_use_case;
+
+pub use create_alert_integration_use_case::*;
+pub use delete_alert_integration_use_case::*;
+pub use get_alert_integration_use_case::*;
+pub use list_alert_integrations_use_case::*;
+pub use receive_alerts_use_case::*;
+pub use update_alert_integration_use_case::*;
@@ -0,0 +1,259 @@
+use anyhow::Context;
+use chrono::Utc;
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        alert_integration::{AlertIntegration, AlertPayload, ExternalAlert, ExternalAlertStatus},
+        entity_metadata::EntityMetadata,
+        incident::{
+            ExternalAlertIncidentCause, Incident, IncidentCause, IncidentPriority, IncidentSource,
+            IncidentStatus, NewIncident,
+        },
+        incident_notification::IncidentNotificationPayload,
+    },
+    ports::{
+        alert_integration_repository::AlertIntegrationRepository,
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::{IncidentRepository, ListIncidentsOpts},
+        maintenance_window_repository::MaintenanceWindowRepository,
+    },
+    use_cases::{
+        incidents::{create_incident, resolve_incident, NotificationOpts},
+        maintenance_windows::find_ongoing_maintenance_window,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Serialize, TS, Clone, Debug, Default, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct ReceiveAlertsResponse {
+    /// The number of incidents opened for firing alerts
+    pub created_incidents: u32,
+    /// The number of firing alerts that already had an ongoing incident
+    pub deduplicated_alerts: u32,
+    /// The number of incidents resolved by resolved alerts
+    pub resolved_incidents: u32,
+}
+
+#[derive(Error, Debug)]
+pub enum ReceiveAlertsError {
+    #[error("Technical failure occured while receiving alerts")]
+    TechnicalFailure(#[from] anyhow::Error),
+    /// Unknown integrations and wrong secrets are not told apart, so that integration IDs cannot be probed
+    #[error("Invalid alert integration or secret")]
+    Unauthorized,
+    #[error("Invalid payload: {0}")]
+    InvalidPayload(String),
+}
+
+/// Opens an incident for each firing alert of the payload, and resolves the incident of each resolved alert.
+/// Alerts are deduplicated by fingerprint: a firing alert that already has an ongoing incident only updates its cause.
+/// No incident is opened for alerts whose labels are covered by an ongoing maintenance window.
+#[allow(clippy::too_many_arguments)]
+pub async fn receive_alerts<AIR, IR, IER, INR, MWR>(
+    alert_integration_repository: &AIR,
+    incident_repository: &IR,
+    incident_event_repository: &IER,
+    incident_notification_repository: &INR,
+    maintenance_window_repository: &MWR,
+    alert_integration_id: Uuid,
+    secret: Option<&str>,
+    payload: AlertPayload,
+) -> Result<ReceiveAlertsResponse, ReceiveAlertsError>
+where
+    AIR: AlertIntegrationRepository,
+    IR: IncidentRepository<Transaction = AIR::Transaction>,
+    IER: IncidentEventRepository<Transaction = AIR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = AIR::Transaction>,
+    MWR: MaintenanceWindowRepository<Transaction = AIR::Transaction>,
+{
+    let mut tx = alert_integration_repository.begin_transaction().await?;
+    let integration = alert_integration_repository
+        .get_alert_integration_by_id(&mut tx, alert_integration_id)
+        .await?
+        .filter(|integration| secret.is_some_and(|secret| integration.verify_secret(secret)))
+        .ok_or(ReceiveAlertsError::Unauthorized)?;
+
+    let alerts = payload
+        .into_alerts()
+        .map_err(ReceiveAlertsError::InvalidPayload)?;
+
+    let mut response = ReceiveAlertsResponse::default();
+    for alert in alerts {
+        let source = IncidentSource::ExternalAlert {
+            id: integration.alert_source_id(&alert.fingerprint),
+        };
+        let ongoing_incident =
+            get_ongoing_alert_incident(&mut tx, incident_repository, &integration, &source).await?;
+        let cause = IncidentCause::ExternalAlertIncidentCause(ExternalAlertIncidentCause {
+            integration_id: integration.id,
+            integration_name: integration.name.clone(),
+            fingerprint: alert.fingerprint.clone(),
+            title: alert.title.clone(),
+            description: alert.description.clone(),
+            source_url: alert.source_url.clone(),
+        });
+
+        match (alert.status, ongoing_incident) {
+            (ExternalAlertStatus::Firing, Some(incident)) => {
+                incident_repository
+                    .update_incident(
+                        &mut tx,
+                        Incident {
+                            cause: Some(cause),
+                            ..incident
+                        },
+                    )
+                    .await
+                    .context("Failed to update the cause of the ongoing alert incident")?;
+                response.deduplicated_alerts += 1;
+            }
+            (ExternalAlertStatus::Firing, None) => {
+                let created = create_alert_incident(
+                    &mut tx,
+                    incident_repository,
+                    incident_event_repository,
+                    incident_notification_repository,
+                    maintenance_window_repository,
+                    &integration,
+                    alert,
+                    source,
+                    cause,
+                )
+                .await?;
+                if created {
+                    response.created_incidents += 1;
+                }
+            }
+            (ExternalAlertStatus::Resolved, Some(incident)) => {
+                resolve_incident(
+                    &mut tx,
+                    incident_repository,
+                    incident_event_repository,
+                    incident_notification_repository,
+                    &incident,
+                    None,
+                )
+                .await
+                .context("Failed to resolve alert incident")?;
+                response.resolved_incidents += 1;
+            }
+            // the alert fired during a maintenance window, or its incident was resolved manually
+            (ExternalAlertStatus::Resolved, None) => {}
+        }
+    }
+    alert_integration_repository.commit_transaction(tx).await?;
+
+    Ok(response)
+}
+
+/// Opens the incident of a firing alert, unless its labels are covered by an ongoing maintenance window.
+/// Returns whether an incident was opened
+#[allow(clippy::too_many_arguments)]
+async fn create_alert_incident<IR, IER, INR, MWR>(
+    transaction: &mut IR::Transaction,
+    incident_repository: &IR,
+    incident_event_repository: &IER,
+    incident_notification_repository: &INR,
+    maintenance_window_repository: &MWR,
+    integration: &AlertIntegration,
+    alert: ExternalAlert,
+    source: IncidentSource,
+    cause: IncidentCause,
+) -> anyhow::Result<bool>
+where
+    IR: IncidentRepository,
+    IER: IncidentEventRepository<Transaction = IR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = IR::Transaction>,
+    MWR: MaintenanceWindowRepository<Transaction = IR::Transaction>,
+{
+    // the labels of the alert become the metadata of its incident, so that alert incidents can be filtered like any other
+    let metadata = EntityMetadata {
+        records: alert.labels,
+    };
+    if find_ongoing_maintenance_window(
+        transaction,
+        maintenance_window_repository,
+        integration.organization_id,
+        Utc::now(),
+        |window| window.covers_external_alert(&metadata),
+    )
+    .await?
+    .is_some()
+    {
+        return Ok(false);
+    }
+
+    let new_incident = NewIncident {
+        organization_id: integration.organization_id,
+        created_by: None,
+        status: IncidentStatus::Ongoing,
+        priority: alert.priority.unwrap_or(integration.default_priority),
+        source,
+        cause: Some(cause.clone()),
+        metadata,
+        title: Some(alert.title),
+    };
+    let notification = NotificationOpts {
+        send_sms: false,
+        send_push_notification: true,
+        send_email: true,
+        notification_payload: IncidentNotificationPayload {
+            incident_cause: cause,
+            incident_http_monitor_url: None,
+        },
+    };
+
+    create_incident(
+        transaction,
+        incident_repository,
+        incident_event_repository,
+        incident_notification_repository,
+        new_incident,
+        Some(notification),
+    )
+    .await
+    .context("Failed to create alert incident")?;
+
+    Ok(true)
+}
+
+/// Returns the ongoing incident of an alert, if any
+async fn get_ongoing_alert_incident<IR>(
+    transaction: &mut IR::Transaction,
+    incident_repository: &IR,
+    integration: &AlertIntegration,
+    source: &IncidentSource,
+) -> anyhow::Result<Option<Incident>>
+where
+    IR: IncidentRepository,
+{
+    let incident = incident_repository
+        .list_incidents(
+            transaction,
+            integration.organization_id,
+            ListIncidentsOpts {
+                include_statuses: &[IncidentStatus::Ongoing, IncidentStatus::ToBeConfirmed],
+                include_priorities: &IncidentPriority::ALL,
+                include_sources: std::slice::from_ref(source),
+                limit: 1,
+                ..Default::default()
+            },
+        )
+        .await
+        .context("Failed to list ongoing incidents for alert")?
+        .incidents
+        .into_iter()
+        .next();
+
+    Ok(incident)
+}
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, Utc};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        alert_integration::{AlertIntegration, AlertPayload},
+        entity_metadata::MetadataFilter,
+        incident::{IncidentCause, IncidentPriority, IncidentSourceType, IncidentStatus},
+        incident_event::IncidentEventType,
+        maintenance_window::{MaintenanceWindow, MaintenanceWindowScope},
+    },
+    infrastructure::mocks::{
+        alert_integration_repository_mock::AlertIntegrationRepositoryMock,
+        incident_event_repository_mock::IncidentEventRepositoryMock,
+        incident_notification_repository_mock::IncidentNotificationRepositoryMock,
+        incident_repository_mock::IncidentRepositoryMock,
+        maintenance_window_repository_mock::MaintenanceWindowRepositoryMock,
+    },
+};
+
+use super::{receive_alerts, ReceiveAlertsError, ReceiveAlertsResponse};
+
+struct Repositories {
+    alert_integration: AlertIntegrationRepositoryMock,
+    incident: IncidentRepositoryMock,
+    incident_event: IncidentEventRepositoryMock,
+    incident_notification: IncidentNotificationRepositoryMock,
+    maintenance_window: MaintenanceWindowRepositoryMock,
+}
+
+impl Repositories {
+    async fn new() -> (Self, AlertIntegration) {
+        let integration = AlertIntegration {
+            organization_id: Uuid::new_v4(),
+            id: Uuid::new_v4(),
+            name: "Prometheus".to_string(),
+            secret: AlertIntegration::generate_secret(),
+            default_priority: IncidentPriority::Minor,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        let alert_integration = AlertIntegrationRepositoryMock::new();
+        alert_integration.state.lock().await.push(integration.clone());
+        let repositories = Self {
+            alert_integration,
+            incident: IncidentRepositoryMock::new(),
+            incident_event: IncidentEventRepositoryMock::new(),
+            incident_notification: IncidentNotificationRepositoryMock::new(),
+            maintenance_window: MaintenanceWindowRepositoryMock::new(),
+        };
+        (repositories, integration)
+    }
+
+    async fn receive(
+        &self,
+        integration: &AlertIntegration,
+        secret: Option<&str>,
+        payload: serde_json::Value,
+    ) -> Result<ReceiveAlertsResponse, ReceiveAlertsError> {
+        let payload: AlertPayload = serde_json::from_value(payload).unwrap();
+        receive_alerts(
+            &self.alert_integration,
+            &self.incident,
+            &self.incident_event,
+            &self.incident_notification,
+            &self.maintenance_window,
+            integration.id,
+            secret,
+            payload,
+        )
+        .await
+    }
+}
+
+fn alertmanager_payload(status: &str, fingerprint: &str) -> serde_json::Value {
+    json!({
+        "version": "4",
+        "status": status,
+        "alerts": [{
+            "status": status,
+            "labels": {"alertname": "HighLatency", "severity": "critical", "service": "api"},
+            "annotations": {"summary": "API latency is high"},
+            "fingerprint": fingerprint
+        }]
+    })
+}
+
+#[tokio::test]
+async fn test_receive_firing_alert_opens_incident() -> anyhow::Result<()> {
+    let (repositories, integration) = Repositories::new().await;
+
+    let response = repositories
+        .receive(&integration, Some(&integration.secret), alertmanager_payload("firing", "abc"))
+        .await?;
+    assert_eq!(response.created_incidents, 1);
+
+    let incidents = repositories.incident.state.lock().await;
+    assert_eq!(incidents.len(), 1);
+    let incident = &incidents[0];
+    assert_eq!(incident.organization_id, integration.organization_id);
+    assert_eq!(incident.status, IncidentStatus::Ongoing);
+    assert_eq!(incident.priority, IncidentPriority::Critical);
+    assert_eq!(incident.incident_source_type, IncidentSourceType::ExternalAlert);
+    assert_eq!(incident.incident_source_id, integration.alert_source_id("abc"));
+    assert_eq!(incident.title.as_deref(), Some("API latency is high"));
+    assert_eq!(
+        incident.metadata.records,
+        HashMap::from([
+            ("alertname".to_string(), "HighLatency".to_string()),
+            ("severity".to_string(), "critical".to_string()),
+            ("service".to_string(), "api".to_string()),
+        ])
+    );
+    let Some(IncidentCause::ExternalAlertIncidentCause(cause)) = &incident.cause else {
+        panic!("unexpected incident cause: {:?}", incident.cause);
+    };
+    assert_eq!(cause.integration_id, integration.id);
+    assert_eq!(cause.fingerprint, "abc");
+
+    // the labels can be filtered on like the metadata of any other incident
+    let filter = MetadataFilter {
+        items: HashMap::from([("service".to_string(), vec!["api".to_string()])]),
+    };
+    assert!(filter.matches(&incident.metadata));
+
+    assert_eq!(repositories.incident_notification.state.lock().await.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_receive_alerts_deduplicates_and_resolves() -> anyhow::Result<()> {
+    let (repositories, integration) = Repositories::new().await;
+    let secret = Some(integration.secret.as_str());
+
+    repositories
+        .receive(&integration, secret, alertmanager_payload("firing", "abc"))
+        .await?;
+    // Alertmanager repeats firing alerts until they are resolved
+    let response = repositories
+        .receive(&integration, secret, alertmanager_payload("firing", "abc"))
+        .await?;
+    assert_eq!(response.created_incidents, 0);
+    assert_eq!(response.deduplicated_alerts, 1);
+    assert_eq!(repositories.incident.state.lock().await.len(), 1);
+
+    // another fingerprint is another incident
+    let response = repositories
+        .receive(&integration, secret, alertmanager_payload("firing", "def"))
+        .await?;
+    assert_eq!(response.created_incidents, 1);
+
+    let response = repositories
+        .receive(&integration, secret, alertmanager_payload("resolved", "abc"))
+        .await?;
+    assert_eq!(response.resolved_incidents, 1);
+    let incidents = repositories.incident.state.lock().await;
+    let resolved = incidents
+        .iter()
+        .find(|i| i.incident_source_id == integration.alert_source_id("abc"))
+        .unwrap();
+    assert_eq!(resolved.status, IncidentStatus::Resolved);
+    let other = incidents
+        .iter()
+        .find(|i| i.incident_source_id == integration.alert_source_id("def"))
+        .unwrap();
+    assert_eq!(other.status, IncidentStatus::Ongoing);
+    drop(incidents);
+
+    let events = repositories.incident_event.state.lock().await;
+    assert_eq!(
+        events
+            .iter()
+            .filter(|e| e.event_type == IncidentEventType::Resolution)
+            .count(),
+        1
+    );
+    drop(events);
+
+    // a resolved alert without an ongoing incident is ignored
+    let response = repositories
+        .receive(&integration, secret, alertmanager_payload("resolved", "abc"))
+        .await?;
+    assert_eq!(response.resolved_incidents, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_receive_native_alert_uses_default_priority() -> anyhow::Result<()> {
+    let (repositories, integration) = Repositories::new().await;
+
+    repositories
+        .receive(
+            &integration,
+            Some(&integration.secret),
+            json!({"fingerprint": "backup", "status": "firing", "title": "Backup failed"}),
+        )
+        .await?;
+
+    let incidents = repositories.incident.state.lock().await;
+    assert_eq!(incidents.len(), 1);
+    assert_eq!(incidents[0].priority, IncidentPriority::Minor);
+    assert_eq!(incidents[0].title.as_deref(), Some("Backup failed"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_receive_alerts_requires_secret() -> anyhow::Result<()> {
+    let (repositories, integration) = Repositories::new().await;
+
+    let result = repositories
+        .receive(&integration, Some("wrong"), alertmanager_payload("firing", "abc"))
+        .await;
+    assert!(matches!(result, Err(ReceiveAlertsError::Unauthorized)));
+    let result = repositories
+        .receive(&integration, None, alertmanager_payload("firing", "abc"))
+        .await;
+    assert!(matches!(result, Err(ReceiveAlertsError::Unauthorized)));
+
+    let unknown_integration = AlertIntegration {
+        id: Uuid::new_v4(),
+        ..integration.clone()
+    };
+    let result = repositories
+        .receive(
+            &unknown_integration,
+            Some(&integration.secret),
+            alertmanager_payload("firing", "abc"),
+        )
+        .await;
+    assert!(matches!(result, Err(ReceiveAlertsError::Unauthorized)));
+
+    assert!(repositories.incident.state.lock().await.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_receive_alert_during_maintenance_window() -> anyhow::Result<()> {
+    let (repositories, integration) = Repositories::new().await;
+    // a window that recurs every minute and lasts two minutes is always ongoing
+    repositories
+        .maintenance_window
+        .state
+        .lock()
+        .await
+        .push(MaintenanceWindow {
+            organization_id: integration.organization_id,
+            id: Uuid::new_v4(),
+            title: "API migration".to_string(),
+            description: None,
+            starts_at: Utc::now() - Duration::days(7),
+            ends_at: None,
+            cron_schedule: Some("* * * * *".to_string()),
+            duration_seconds: Some(120),
+            scope: MaintenanceWindowScope {
+                metadata_filter: Some(MetadataFilter {
+                    items: HashMap::from([("service".to_string(), vec!["api".to_string()])]),
+                }),
+                ..Default::default()
+            },
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        });
+
+    let response = repositories
+        .receive(&integration, Some(&integration.secret), alertmanager_payload("firing", "abc"))
+        .await?;
+    assert_eq!(response.created_incidents, 0);
+    assert!(repositories.incident.state.lock().await.is_empty());
+    Ok(())
+}
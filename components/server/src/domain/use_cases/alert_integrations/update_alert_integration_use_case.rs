@@ -0,0 +1,69 @@
+use serde::Deserialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        alert_integration::validate_alert_integration_name,
+        authorization::{AuthContext, Permission},
+        incident::IncidentPriority,
+    },
+    ports::alert_integration_repository::{AlertIntegrationRepository, NewAlertIntegration},
+};
+
+#[derive(Deserialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct UpdateAlertIntegrationCommand {
+    pub name: String,
+    pub default_priority: IncidentPriority,
+}
+
+#[derive(Error, Debug)]
+pub enum UpdateAlertIntegrationError {
+    #[error("Failed to update an alert integration: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to update alert integrations")]
+    Forbidden,
+    #[error("Alert integration not found")]
+    NotFound,
+    #[error("Invalid name: {0}")]
+    InvalidName(String),
+}
+
+/// Updates the name and the default priority of an alert integration. Its secret is kept.
+pub async fn update_alert_integration(
+    auth_context: &AuthContext,
+    repository: &impl AlertIntegrationRepository,
+    alert_integration_id: Uuid,
+    command: UpdateAlertIntegrationCommand,
+) -> Result<(), UpdateAlertIntegrationError> {
+    if !auth_context.can(Permission::WriteAlertIntegrations) {
+        return Err(UpdateAlertIntegrationError::Forbidden);
+    }
+
+    validate_alert_integration_name(&command.name)
+        .map_err(UpdateAlertIntegrationError::InvalidName)?;
+
+    let mut tx = repository.begin_transaction().await?;
+    let updated = repository
+        .update_alert_integration(
+            &mut tx,
+            alert_integration_id,
+            NewAlertIntegration {
+                organization_id: auth_context.active_organization_id,
+                name: command.name,
+                secret: String::new(),
+                default_priority: command.default_priority,
+            },
+        )
+        .await?;
+    if !updated {
+        return Err(UpdateAlertIntegrationError::NotFound);
+    }
+    repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
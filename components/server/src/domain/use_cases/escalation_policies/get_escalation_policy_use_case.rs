@@ -0,0 +1,46 @@
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        escalation_policy::EscalationPolicy,
+    },
+    ports::escalation_policy_repository::EscalationPolicyRepository,
+};
+
+#[derive(Serialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct GetEscalationPolicyResponse {
+    pub escalation_policy: EscalationPolicy,
+}
+
+#[derive(Error, Debug)]
+pub enum GetEscalationPolicyError {
+    #[error("Failed to get escalation policy: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to read the escalation policy")]
+    Forbidden,
+}
+
+/// Returns the escalation policy of the current organization.
+/// Organizations that have not configured one get an empty policy.
+pub async fn get_escalation_policy(
+    auth_context: &AuthContext,
+    repository: &impl EscalationPolicyRepository,
+) -> Result<GetEscalationPolicyResponse, GetEscalationPolicyError> {
+    if !auth_context.can(Permission::ReadEscalationPolicies) {
+        return Err(GetEscalationPolicyError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let escalation_policy = repository
+        .get_escalation_policy(&mut tx, auth_context.active_organization_id)
+        .await?
+        .unwrap_or_else(|| EscalationPolicy::empty(auth_context.active_organization_id));
+
+    Ok(GetEscalationPolicyResponse { escalation_policy })
+}
@@ -0,0 +1,5 @@
+mod get_escalation_policy_use_case;
+mod update_escalation_policy_use_case;
+
+pub use get_escalation_policy_use_case::*;
+pub use update_escalation_policy_use_case::*;
@@ -0,0 +1,82 @@
+use chrono::Utc;
+use serde::Deserialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        escalation_policy::{
+            EscalationPolicy, EscalationPolicyLevel, MAXIMUM_ESCALATION_LEVELS,
+            MINIMUM_ESCALATION_DELAY_SECONDS,
+        },
+    },
+    ports::escalation_policy_repository::EscalationPolicyRepository,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Deserialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct UpdateEscalationPolicyCommand {
+    /// The schedule whose users on call receive the initial notification of incidents
+    #[serde(default)]
+    pub on_call_schedule_id: Option<Uuid>,
+    pub levels: Vec<EscalationPolicyLevel>,
+}
+
+#[derive(Error, Debug)]
+pub enum UpdateEscalationPolicyError {
+    #[error("Failed to update escalation policy: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to update the escalation policy")]
+    Forbidden,
+    #[error("An escalation policy cannot have more than {MAXIMUM_ESCALATION_LEVELS} levels")]
+    TooManyLevels,
+    #[error("Escalation levels must be at least {MINIMUM_ESCALATION_DELAY_SECONDS} seconds apart")]
+    InvalidDelay,
+}
+
+/// Replaces the escalation policy of the current organization.
+/// Notifications that are already scheduled are not affected.
+pub async fn update_escalation_policy(
+    auth_context: &AuthContext,
+    repository: &impl EscalationPolicyRepository,
+    command: UpdateEscalationPolicyCommand,
+) -> Result<(), UpdateEscalationPolicyError> {
+    if !auth_context.can(Permission::WriteEscalationPolicies) {
+        return Err(UpdateEscalationPolicyError::Forbidden);
+    }
+
+    if command.levels.len() > MAXIMUM_ESCALATION_LEVELS {
+        return Err(UpdateEscalationPolicyError::TooManyLevels);
+    }
+
+    if command
+        .levels
+        .iter()
+        .any(|level| level.delay_seconds < MINIMUM_ESCALATION_DELAY_SECONDS)
+    {
+        return Err(UpdateEscalationPolicyError::InvalidDelay);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    repository
+        .upsert_escalation_policy(
+            &mut tx,
+            EscalationPolicy {
+                organization_id: auth_context.active_organization_id,
+                on_call_schedule_id: command.on_call_schedule_id,
+                levels: command.levels,
+                updated_at: Utc::now(),
+            },
+        )
+        .await?;
+    repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    authorization::AuthContext,
+    escalation_policy::{EscalationPolicyLevel, MAXIMUM_ESCALATION_LEVELS},
+    organization::OrganizationUserRole,
+};
+use crate::infrastructure::mocks::escalation_policy_repository_mock::EscalationPolicyRepositoryMock;
+
+use super::{update_escalation_policy, UpdateEscalationPolicyCommand, UpdateEscalationPolicyError};
+
+fn create_test_level(delay_seconds: u32) -> EscalationPolicyLevel {
+    EscalationPolicyLevel {
+        delay_seconds,
+        target_user_ids: vec![],
+        target_roles: vec![OrganizationUserRole::Administrator],
+        target_on_call_schedule_ids: vec![],
+        send_email: true,
+        send_push_notification: true,
+        send_sms: true,
+    }
+}
+
+#[tokio::test]
+async fn test_update_escalation_policy() -> anyhow::Result<()> {
+    let repository = EscalationPolicyRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let auth_context =
+        AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+
+    update_escalation_policy(
+        &auth_context,
+        &repository,
+        UpdateEscalationPolicyCommand {
+            on_call_schedule_id: None,
+            levels: vec![create_test_level(300), create_test_level(900)],
+        },
+    )
+    .await?;
+
+    // Updating the policy again replaces the levels
+    update_escalation_policy(
+        &auth_context,
+        &repository,
+        UpdateEscalationPolicyCommand {
+            on_call_schedule_id: None,
+            levels: vec![create_test_level(600)],
+        },
+    )
+    .await?;
+
+    let state = repository.state.lock().await;
+    assert_eq!(state.len(), 1);
+    assert_eq!(state[0].organization_id, org_id);
+    assert_eq!(state[0].levels, vec![create_test_level(600)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_escalation_policy_validation() -> anyhow::Result<()> {
+    let repository = EscalationPolicyRepositoryMock::new();
+    let auth_context = AuthContext::test_context(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Editor],
+        &[],
+    );
+
+    let result = update_escalation_policy(
+        &auth_context,
+        &repository,
+        UpdateEscalationPolicyCommand {
+            on_call_schedule_id: None,
+            levels: vec![create_test_level(10)],
+        },
+    )
+    .await;
+    assert!(matches!(result, Err(UpdateEscalationPolicyError::InvalidDelay)));
+
+    let result = update_escalation_policy(
+        &auth_context,
+        &repository,
+        UpdateEscalationPolicyCommand {
+            on_call_schedule_id: None,
+            levels: vec![create_test_level(300); MAXIMUM_ESCALATION_LEVELS + 1],
+        },
+    )
+    .await;
+    assert!(matches!(result, Err(UpdateEscalationPolicyError::TooManyLevels)));
+
+    assert!(repository.state.lock().await.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_escalation_policy_forbidden() -> anyhow::Result<()> {
+    let repository = EscalationPolicyRepositoryMock::new();
+    let auth_context = AuthContext::test_context(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Reporter],
+        &[],
+    );
+
+    let result = update_escalation_policy(
+        &auth_context,
+        &repository,
+        UpdateEscalationPolicyCommand {
+            on_call_schedule_id: None,
+            levels: vec![create_test_level(300)],
+        },
+    )
+    .await;
+    assert!(matches!(result, Err(UpdateEscalationPolicyError::Forbidden)));
+
+    Ok(())
+}
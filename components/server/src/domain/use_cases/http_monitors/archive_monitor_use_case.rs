@@ -62,11 +62,12 @@ where
                 error_kind: monitor.error_kind,
                 last_http_code: monitor.last_http_code,
                 archived_at: Some(now),
+                tls_certificate: None,
             },
         )
         .await?;
 
-    // Retrieve all ongoing incidents for this monitor
+    // Retrieve all ongoing incidents for this monitor, including the certificate expiry one
     let ongoing_incidents = incident_repository
         .list_incidents(
             &mut tx,
@@ -74,8 +75,11 @@ where
             ListIncidentsOpts {
                 include_statuses: &[IncidentStatus::Ongoing, IncidentStatus::ToBeConfirmed],
                 include_priorities: &IncidentPriority::ALL,
-                include_sources: &[IncidentSource::HttpMonitor { id: monitor.id }],
-                limit: 1,
+                include_sources: &[
+                    IncidentSource::HttpMonitor { id: monitor.id },
+                    IncidentSource::TlsCertificate { id: monitor.id },
+                ],
+                limit: 2,
                 ..Default::default()
             },
         )
@@ -89,6 +93,7 @@ where
             incident_event_repository,
             incident_notification_repository,
             &incident,
+            None,
         )
         .await?;
     }
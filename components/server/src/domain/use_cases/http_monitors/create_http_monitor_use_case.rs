@@ -8,9 +8,12 @@ use uuid::Uuid;
 
 use crate::domain::{
     entities::{
-        authorization::{AuthContext, Permission}, entity_metadata::EntityMetadata, http_monitor::{HttpMonitorStatus, RequestHeaders}
+        authorization::{AuthContext, Permission}, entity_metadata::EntityMetadata, http_monitor::{validate_locations, HttpMonitorStatus, RequestHeaders}, http_monitor_assertion::HttpMonitorAssertions, http_monitor_request::{validate_request, HttpMethod, HttpMonitorAuthentication, HttpMonitorRedirectPolicy, ProbeEngine}, monitor_kind::MonitorKindSettings, tls_certificate::{default_tls_expiry_warning_days, validate_tls_expiry_warning_days}
+    },
+    ports::{
+        http_client::HttpClient,
+        http_monitor_repository::{HttpMonitorRepository, NewHttpMonitor},
     },
-    ports::http_monitor_repository::{HttpMonitorRepository, NewHttpMonitor},
 };
 
 #[derive(Deserialize, TS, Debug, ToSchema)]
@@ -28,6 +31,33 @@ pub struct CreateHttpMonitorCommand {
     pub sms_notification_enabled: bool,
     pub request_headers: RequestHeaders,
     pub request_timeout_ms: i32,
+    #[serde(default)]
+    pub assertions: HttpMonitorAssertions,
+    /// Names of the probe locations to ping the monitor from, the default location is used if empty
+    #[serde(default)]
+    pub locations: Vec<String>,
+    /// Minimum number of failing locations for a ping to be considered failed, a majority if not set
+    #[serde(default)]
+    #[ts(type = "number | null")]
+    pub location_quorum: Option<i16>,
+    #[serde(default)]
+    pub request_method: HttpMethod,
+    #[serde(default)]
+    pub request_body: Option<String>,
+    #[serde(default)]
+    pub request_body_content_type: Option<String>,
+    #[serde(default)]
+    pub authentication: HttpMonitorAuthentication,
+    #[serde(default)]
+    pub redirect_policy: HttpMonitorRedirectPolicy,
+    #[serde(default)]
+    pub probe_engine: ProbeEngine,
+    /// Number of days before the certificate expires at which a warning incident is opened, 0 to disable it
+    #[serde(default = "default_tls_expiry_warning_days")]
+    pub tls_expiry_warning_days: i16,
+    /// What the monitor checks, an HTTP monitor if not set
+    #[serde(default)]
+    pub kind_settings: MonitorKindSettings,
 }
 
 #[derive(Serialize, TS, Clone, Debug)]
@@ -44,11 +74,22 @@ pub enum CreateHttpMonitorError {
     Forbidden,
     #[error("Invalid URL: {0}")]
     InvalidUrl(#[from] url::ParseError),
+    #[error("Invalid assertion: {0}")]
+    InvalidAssertion(String),
+    #[error("Invalid locations: {0}")]
+    InvalidLocations(String),
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("Invalid TLS expiry warning: {0}")]
+    InvalidTlsExpiryWarning(String),
+    #[error("Invalid kind settings: {0}")]
+    InvalidKindSettings(String),
 }
 
 pub async fn create_http_monitor(
     auth_context: &AuthContext,
     repository: &impl HttpMonitorRepository,
+    http_client: &impl HttpClient,
     command: CreateHttpMonitorCommand,
 ) -> Result<CreateHttpMonitorResponse, CreateHttpMonitorError> {
     if !auth_context.can(Permission::WriteHttpMonitors) {
@@ -58,6 +99,43 @@ pub async fn create_http_monitor(
     // Validate URL
     let url = Url::parse(&command.url)?;
 
+    command
+        .assertions
+        .validate()
+        .map_err(CreateHttpMonitorError::InvalidAssertion)?;
+
+    command
+        .kind_settings
+        .validate(
+            &url,
+            &command.locations,
+            &command.assertions,
+            &command.authentication,
+            command.request_body.as_deref(),
+        )
+        .map_err(CreateHttpMonitorError::InvalidKindSettings)?;
+
+    validate_locations(
+        &command.locations,
+        command.location_quorum,
+        command.probe_engine,
+        &http_client.locations(),
+    )
+    .map_err(CreateHttpMonitorError::InvalidLocations)?;
+
+    validate_request(
+        command.request_method,
+        command.request_body.as_deref(),
+        command.request_body_content_type.as_deref(),
+        &command.authentication,
+        &command.redirect_policy,
+        &command.request_headers.headers,
+    )
+    .map_err(CreateHttpMonitorError::InvalidRequest)?;
+
+    validate_tls_expiry_warning_days(command.tls_expiry_warning_days)
+        .map_err(CreateHttpMonitorError::InvalidTlsExpiryWarning)?;
+
     let new_monitor = NewHttpMonitor {
         organization_id: auth_context.active_organization_id,
         url: url.to_string(),
@@ -80,6 +158,17 @@ pub async fn create_http_monitor(
         sms_notification_enabled: command.sms_notification_enabled,
         request_headers: command.request_headers,
         request_timeout_ms: command.request_timeout_ms,
+        assertions: command.assertions,
+        locations: command.locations,
+        location_quorum: command.location_quorum,
+        request_method: command.request_method,
+        request_body: command.request_body,
+        request_body_content_type: command.request_body_content_type,
+        authentication: command.authentication,
+        redirect_policy: command.redirect_policy,
+        probe_engine: command.probe_engine,
+        tls_expiry_warning_days: command.tls_expiry_warning_days,
+        kind_settings: command.kind_settings,
     };
     let id = repository.create_http_monitor(new_monitor).await?;
     Ok(CreateHttpMonitorResponse { id })
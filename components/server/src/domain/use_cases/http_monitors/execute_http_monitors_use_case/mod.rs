@@ -1,40 +1,50 @@
 use std::time::Duration;
 
 use anyhow::Context;
-use futures::{stream, StreamExt};
+use futures::{future::join_all, stream, StreamExt};
 use tokio::task::JoinSet;
 use tracing::{debug, error, info};
 
 mod ping_result_handler;
 mod status_machine;
+mod tls_certificate_handler;
 
 #[cfg(test)]
 mod tests;
 
-use crate::domain::ports::{
-    file_storage::FileStorage, http_client::HttpClient,
-    http_monitor_repository::HttpMonitorRepository,
-    incident_event_repository::IncidentEventRepository,
-    incident_notification_repository::IncidentNotificationRepository,
-    incident_repository::IncidentRepository,
+use crate::domain::{
+    entities::{
+        http_monitor::HttpMonitor, http_monitor_request::ProbeEngine, monitor_kind::MonitorKind,
+    },
+    ports::{
+        file_storage::FileStorage,
+        http_client::{HttpClient, PingRequest},
+        http_monitor_repository::HttpMonitorRepository,
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository,
+        maintenance_window_repository::MaintenanceWindowRepository,
+    },
 };
 
 #[derive(Clone)]
-pub struct ExecuteHttpMonitorsUseCase<HMR, IR, IER, INR, HC, FS> {
+pub struct ExecuteHttpMonitorsUseCase<HMR, IR, IER, INR, MWR, HC, FS> {
     pub http_monitor_repository: HMR,
     pub incident_repository: IR,
     pub incident_event_repository: IER,
     pub incident_notification_repository: INR,
+    pub maintenance_window_repository: MWR,
     pub http_client: HC,
     pub file_storage: FS,
 }
 
-impl<HMR, IR, IER, INR, HC, FS> ExecuteHttpMonitorsUseCase<HMR, IR, IER, INR, HC, FS>
+impl<HMR, IR, IER, INR, MWR, HC, FS> ExecuteHttpMonitorsUseCase<HMR, IR, IER, INR, MWR, HC, FS>
 where
     HMR: HttpMonitorRepository,
     IR: IncidentRepository<Transaction = HMR::Transaction>,
     IER: IncidentEventRepository<Transaction = HMR::Transaction>,
     INR: IncidentNotificationRepository<Transaction = HMR::Transaction>,
+    MWR: MaintenanceWindowRepository<Transaction = HMR::Transaction>,
     HC: HttpClient,
     FS: FileStorage,
 {
@@ -109,21 +119,29 @@ where
             );
         }
 
-        // Ping the monitors concurrently and collect the results
+        // Ping the monitors concurrently, from each of their locations, and collect the results
         let mut ping_results = stream::iter(due_monitors)
             .map(|monitor| {
-                let url = monitor.url.clone();
                 let http_client = self.http_client.clone();
-                let request_headers = monitor.request_headers.headers.clone();
-                let request_timeout = monitor.request_timeout();
+                let ping_request = PingRequest::from(&monitor);
+                let locations = self.monitor_locations(&monitor);
                 async move {
-                    (monitor, http_client.ping(&url, request_timeout, request_headers).await)
+                    let location_pings = join_all(locations.into_iter().map(|location| {
+                        let http_client = &http_client;
+                        let ping_request = &ping_request;
+                        async move {
+                            let ping_response = http_client.ping(&location, ping_request).await;
+                            (location, ping_response)
+                        }
+                    }))
+                    .await;
+                    (monitor, location_pings)
                 }
             })
             .buffer_unordered(concurrency_limit);
 
         // Go through the ping results and handle them
-        while let Some((monitor, ping_result)) = ping_results.next().await {
+        while let Some((monitor, location_pings)) = ping_results.next().await {
             debug!(monitor_id = ?monitor.id, task_index, "Processing monitor ping result");
 
             let existing_incident = self
@@ -131,9 +149,14 @@ where
                 .await
                 .context("Failed to get existing incident for monitor")?;
 
-            self.handle_ping_response(&mut transaction, monitor, ping_result, existing_incident)
-                .await
-                .context("Failed to handle ping response")?;
+            self.handle_location_ping_responses(
+                &mut transaction,
+                monitor,
+                location_pings,
+                existing_incident,
+            )
+            .await
+            .context("Failed to handle ping response")?;
         }
 
         if monitors_len > 0 {
@@ -146,4 +169,18 @@ where
             .await?;
         Ok(monitors_len)
     }
+
+    /// Returns the locations the monitor must be pinged from
+    /// Monitors without any location, monitors using the lightweight probe engine,
+    /// and monitors that are not HTTP monitors, are pinged from the default location only
+    fn monitor_locations(&self, monitor: &HttpMonitor) -> Vec<String> {
+        if monitor.locations.is_empty()
+            || monitor.probe_engine == ProbeEngine::Lightweight
+            || monitor.kind() != MonitorKind::Http
+        {
+            self.http_client.locations().into_iter().take(1).collect()
+        } else {
+            monitor.locations.clone()
+        }
+    }
 }
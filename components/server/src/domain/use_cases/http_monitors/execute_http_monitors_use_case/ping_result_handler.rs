@@ -7,38 +7,134 @@ use uuid::Uuid;
 
 use crate::domain::{
     entities::{
-        http_monitor::{HttpMonitor, HttpMonitorStatus},
+        http_monitor::{HttpMonitor, HttpMonitorErrorKind, HttpMonitorStatus, LocationPingResult},
+        http_monitor_assertion::{AssertedResponse, HttpMonitorAssertionFailure},
+        http_monitor_ping::HttpMonitorPing,
         incident::{
-            HttpMonitorIncidentCause, HttpMonitorIncidentCausePing, Incident, IncidentCause,
-            IncidentPriority, IncidentSource, IncidentStatus, NewIncident,
+            DnsMonitorIncidentCause, GrpcHealthMonitorIncidentCause, HttpMonitorIncidentCause,
+            HttpMonitorIncidentCausePing, Incident, IncidentCause, IncidentPriority,
+            IncidentSource, IncidentStatus, NewIncident, TcpMonitorIncidentCause,
         },
         incident_event::{
             IncidentEvent, IncidentEventPayload, IncidentEventType, PingEventPayload,
         },
         incident_notification::IncidentNotificationPayload,
+        monitor_kind::MonitorKindSettings,
     },
     ports::{
         file_storage::{FileStorage, FileStorageKey},
-        http_client::{HttpClient, Screenshot},
+        http_client::{HttpClient, PingResponse, Screenshot},
         http_monitor_repository::{HttpMonitorRepository, UpdateHttpMonitorStatusCommand},
         incident_event_repository::IncidentEventRepository,
         incident_notification_repository::IncidentNotificationRepository,
         incident_repository::{IncidentRepository, ListIncidentsOpts},
+        maintenance_window_repository::MaintenanceWindowRepository,
+    },
+    use_cases::{
+        incidents::{confirm_incident, create_incident, resolve_incident, NotificationOpts},
+        maintenance_windows::find_ongoing_maintenance_window,
     },
-    use_cases::incidents::{confirm_incident, create_incident, resolve_incident, NotificationOpts},
 };
 
 use super::{status_machine, ExecuteHttpMonitorsUseCase};
 
-impl<HMR, IR, IER, INR, HC, FS> ExecuteHttpMonitorsUseCase<HMR, IR, IER, INR, HC, FS>
+impl<HMR, IR, IER, INR, MWR, HC, FS> ExecuteHttpMonitorsUseCase<HMR, IR, IER, INR, MWR, HC, FS>
 where
     HMR: HttpMonitorRepository,
     IR: IncidentRepository<Transaction = HMR::Transaction>,
     IER: IncidentEventRepository<Transaction = HMR::Transaction>,
     INR: IncidentNotificationRepository<Transaction = HMR::Transaction>,
+    MWR: MaintenanceWindowRepository<Transaction = HMR::Transaction>,
     HC: HttpClient,
     FS: FileStorage,
 {
+    /// Handles the result of pinging an HTTP monitor from a single location
+    #[tracing::instrument(skip(self, transaction))]
+    pub async fn handle_ping_response(
+        &self,
+        transaction: &mut HMR::Transaction,
+        monitor: HttpMonitor,
+        mut ping_response: PingResponse,
+        existing_incident: Option<Incident>,
+    ) -> anyhow::Result<()> {
+        let failed_assertion = evaluate_assertions(&monitor, &mut ping_response);
+        self.handle_evaluated_ping_response(
+            transaction,
+            monitor,
+            ping_response,
+            failed_assertion,
+            vec![],
+            existing_incident,
+        )
+        .await
+    }
+
+    /// Handles the results of pinging an HTTP monitor from each of its locations.
+    /// The ping is considered failed only if the number of failing locations reaches the monitor's quorum,
+    /// so a single flaky network path does not make the monitor go down.
+    /// The response of a location agreeing with the decision is used to update the monitor,
+    /// and the results of every location are recorded in the ping events.
+    #[tracing::instrument(skip(self, transaction))]
+    pub async fn handle_location_ping_responses(
+        &self,
+        transaction: &mut HMR::Transaction,
+        monitor: HttpMonitor,
+        mut location_pings: Vec<(String, PingResponse)>,
+        existing_incident: Option<Incident>,
+    ) -> anyhow::Result<()> {
+        if location_pings.len() <= 1 {
+            let (_, ping_response) = location_pings
+                .pop()
+                .context("No location to ping the monitor from")?;
+            return self
+                .handle_ping_response(transaction, monitor, ping_response, existing_incident)
+                .await;
+        }
+
+        let mut evaluated_pings = Vec::with_capacity(location_pings.len());
+        let mut location_results = Vec::with_capacity(location_pings.len());
+        for (location, mut ping_response) in location_pings {
+            let failed_assertion = evaluate_assertions(&monitor, &mut ping_response);
+            location_results.push(LocationPingResult {
+                location,
+                error_kind: ping_response.error_kind,
+                http_code: ping_response.http_code.map(|c| c as i32),
+                response_time_ms: ping_response.response_time.as_millis() as u64,
+                failed_assertion: failed_assertion.clone(),
+            });
+            evaluated_pings.push((ping_response, failed_assertion));
+        }
+
+        let failed_locations = location_results
+            .iter()
+            .filter(|result| result.error_kind != HttpMonitorErrorKind::None)
+            .count();
+        let ping_failed = failed_locations >= monitor.failure_quorum(location_results.len());
+        debug!(
+            monitor_id = ?monitor.id,
+            failed_locations,
+            locations = location_results.len(),
+            ping_failed,
+            "Monitor pinged from several locations"
+        );
+
+        let representative_index = evaluated_pings
+            .iter()
+            .position(|(response, _)| (response.error_kind != HttpMonitorErrorKind::None) == ping_failed)
+            .unwrap_or(0);
+        let (ping_response, failed_assertion) = evaluated_pings.swap_remove(representative_index);
+
+        self.handle_evaluated_ping_response(
+            transaction,
+            monitor,
+            ping_response,
+            failed_assertion,
+            location_results,
+            existing_incident,
+        )
+        .await
+    }
+
     ///
     /// Handles the result of pinging an HTTP monitor and updates the monitor's status accordingly.
     ///
@@ -46,6 +142,8 @@ where
     /// * `transaction` - The database transaction to use for any updates
     /// * `monitor` - The HTTP monitor that was pinged
     /// * `ping_response` - The response from pinging the monitor, containing error info and HTTP code
+    /// * `failed_assertion` - The assertion that did not hold for this response, if any
+    /// * `location_results` - The results of each location, when the monitor is pinged from several locations
     /// * `existing_incident` - Any existing incident associated with this monitor
     ///
     /// # Returns
@@ -55,14 +153,17 @@ where
     /// # Details
     /// This method:
     /// 1. Determines the next monitor status based on the ping result
-    /// 2. Updates the monitor's status and related fields in the database
-    /// 3. Creates/updates incidents if needed based on the monitor's new status
-    #[tracing::instrument(skip(self, transaction))]
-    pub async fn handle_ping_response(
+    /// 2. Updates the monitor's status and related fields in the database, and records the ping
+    /// 3. Opens or resolves the certificate expiry incident, based on the certificate observed by the ping
+    /// 4. Creates/updates incidents if needed based on the monitor's new status.
+    ///    While the monitor is under maintenance, no incident is created nor confirmed, so no notification is sent
+    async fn handle_evaluated_ping_response(
         &self,
         transaction: &mut HMR::Transaction,
         mut monitor: HttpMonitor,
-        mut ping_response: crate::domain::ports::http_client::PingResponse,
+        mut ping_response: PingResponse,
+        failed_assertion: Option<HttpMonitorAssertionFailure>,
+        location_results: Vec<LocationPingResult>,
         existing_incident: Option<Incident>,
     ) -> anyhow::Result<()> {
         let (status_counter, status) = status_machine::next_status(
@@ -70,11 +171,11 @@ where
             monitor.recovery_confirmation_threshold,
             monitor.status,
             monitor.status_counter,
-            ping_response.error_kind
-                == crate::domain::entities::http_monitor::HttpMonitorErrorKind::None,
+            ping_response.error_kind == HttpMonitorErrorKind::None,
         );
 
         let error_kind = ping_response.error_kind;
+        let tls_certificate = ping_response.tls_certificate.clone();
         let last_http_code = ping_response.http_code.map(|c| c as i16);
         let next_ping_at = Some(Utc::now() + monitor.interval());
         let last_status_change_at = if status != monitor.status {
@@ -92,11 +193,17 @@ where
             error_kind,
             last_status_change_at,
             archived_at: None,
+            tls_certificate: tls_certificate.clone(),
         };
 
         // Update the monitor so these info will be used to create the incident
         monitor.error_kind = error_kind;
         monitor.last_http_code = last_http_code;
+        let last_ping = HttpMonitorIncidentCausePing {
+            error_kind,
+            http_code: last_http_code,
+            failed_assertion,
+        };
 
         // Update the monitor (status and status_counter)
         self.http_monitor_repository
@@ -104,6 +211,26 @@ where
             .await
             .context("Failed to update HTTP monitor status")?;
 
+        // Keep track of every ping, to compute the uptime and the response times of the monitor
+        self.http_monitor_repository
+            .record_ping(
+                transaction,
+                HttpMonitorPing {
+                    organization_id: monitor.organization_id,
+                    http_monitor_id: monitor.id,
+                    created_at: Utc::now(),
+                    status,
+                    error_kind,
+                    http_code: last_http_code,
+                    response_time_ms: ping_response.response_time.as_millis() as i32,
+                },
+            )
+            .await
+            .context("Failed to record HTTP monitor ping")?;
+
+        self.handle_tls_certificate_expiry(transaction, &monitor, tls_certificate.as_ref())
+            .await?;
+
         match (status, existing_incident) {
             // the monitor can never be unknown or inactive when we are handling a ping response
             (
@@ -142,7 +269,13 @@ where
                 );
 
                 let ping_event = self
-                    .create_ping_event(&monitor, incident.id, &mut ping_response)
+                    .create_ping_event(
+                        &monitor,
+                        incident.id,
+                        &mut ping_response,
+                        last_ping.failed_assertion.as_ref(),
+                        &location_results,
+                    )
                     .await;
 
                 self.incident_event_repository
@@ -155,6 +288,7 @@ where
                     &self.incident_event_repository,
                     &self.incident_notification_repository,
                     &incident,
+                    None,
                 )
                 .await?;
             }
@@ -170,7 +304,13 @@ where
 
                 if status_counter == 1 {
                     let ping_event = self
-                        .create_ping_event(&monitor, incident.id, &mut ping_response)
+                        .create_ping_event(
+                            &monitor,
+                            incident.id,
+                            &mut ping_response,
+                            last_ping.failed_assertion.as_ref(),
+                            &location_results,
+                        )
                         .await;
 
                     let switch_to_recovering_event = IncidentEvent {
@@ -199,18 +339,24 @@ where
                     "Monitor next status is suspicious and there is no ongoing incident"
                 );
 
+                if self.is_under_maintenance(transaction, &monitor).await? {
+                    return Ok(());
+                }
+
                 self.create_incident_for_monitor(
                     transaction,
                     &monitor,
                     false,
-                    IncidentCause::HttpMonitorIncidentCause(HttpMonitorIncidentCause {
-                        last_ping: HttpMonitorIncidentCausePing {
-                            error_kind: monitor.error_kind,
-                            http_code: monitor.last_http_code,
+                    monitor_incident_cause(
+                        &monitor,
+                        HttpMonitorIncidentCause {
+                            last_ping: last_ping.clone(),
+                            previous_pings: HashSet::new(),
                         },
-                        previous_pings: HashSet::new(),
-                    }),
+                    ),
                     ping_response,
+                    last_ping.failed_assertion.as_ref(),
+                    &location_results,
                 )
                 .await?;
             }
@@ -223,18 +369,24 @@ where
                     "Monitor next status is down and there is no ongoing incident"
                 );
 
+                if self.is_under_maintenance(transaction, &monitor).await? {
+                    return Ok(());
+                }
+
                 self.create_incident_for_monitor(
                     transaction,
                     &monitor,
                     true,
-                    IncidentCause::HttpMonitorIncidentCause(HttpMonitorIncidentCause {
-                        last_ping: HttpMonitorIncidentCausePing {
-                            error_kind: monitor.error_kind,
-                            http_code: monitor.last_http_code,
+                    monitor_incident_cause(
+                        &monitor,
+                        HttpMonitorIncidentCause {
+                            last_ping: last_ping.clone(),
+                            previous_pings: HashSet::new(),
                         },
-                        previous_pings: HashSet::new(),
-                    }),
+                    ),
                     ping_response,
+                    last_ping.failed_assertion.as_ref(),
+                    &location_results,
                 )
                 .await?;
             }
@@ -245,11 +397,12 @@ where
                 Some(
                     ref incident @ Incident {
                         status: IncidentStatus::ToBeConfirmed,
-                        cause: Some(IncidentCause::HttpMonitorIncidentCause(ref cause)),
+                        cause: Some(ref cause),
                         ..
                     },
                 ),
-            ) => {
+            ) if cause.monitor_pings().is_some() => {
+                let cause = cause.monitor_pings().expect("checked by the match guard");
                 debug!(
                     monitor_id = ?monitor.id,
                     incident_id = ?incident.id,
@@ -258,18 +411,21 @@ where
                     "Monitor next status is down and there is an unconfirmed incident"
                 );
 
-                self.confirm_incident_for_monitor(transaction, &monitor, incident)
-                    .await?;
+                // the incident will be confirmed by the first ping after the maintenance
+                if !self.is_under_maintenance(transaction, &monitor).await? {
+                    self.confirm_incident_for_monitor(transaction, &monitor, incident)
+                        .await?;
+                }
 
-                if cause.last_ping.error_kind != monitor.error_kind
-                    || cause.last_ping.http_code != monitor.last_http_code
-                {
+                if cause.last_ping != last_ping {
                     self.handle_changing_incident_cause(
                         transaction,
                         &monitor,
                         incident,
                         cause,
+                        last_ping,
                         ping_response,
+                        &location_results,
                     )
                     .await?;
                 }
@@ -280,11 +436,12 @@ where
                 HttpMonitorStatus::Suspicious | HttpMonitorStatus::Down,
                 Some(
                     ref incident @ Incident {
-                        cause: Some(IncidentCause::HttpMonitorIncidentCause(ref cause)),
+                        cause: Some(ref cause),
                         ..
                     },
                 ),
-            ) => {
+            ) if cause.monitor_pings().is_some() => {
+                let cause = cause.monitor_pings().expect("checked by the match guard");
                 debug!(
                     monitor_id = ?monitor.id,
                     incident_id = ?incident.id,
@@ -294,22 +451,28 @@ where
                 );
 
                 // Create a ping event if the incident cause has changed
-                if cause.last_ping.error_kind != monitor.error_kind
-                    || cause.last_ping.http_code != monitor.last_http_code
-                {
+                if cause.last_ping != last_ping {
                     self.handle_changing_incident_cause(
                         transaction,
                         &monitor,
                         incident,
                         cause,
+                        last_ping,
                         ping_response,
+                        &location_results,
                     )
                     .await?;
                 }
                 // Else, create a ping event if the monitor is switching to a new status
                 else if status_counter == 1 {
                     let ping_event = self
-                        .create_ping_event(&monitor, incident.id, &mut ping_response)
+                        .create_ping_event(
+                            &monitor,
+                            incident.id,
+                            &mut ping_response,
+                            last_ping.failed_assertion.as_ref(),
+                            &location_results,
+                        )
                         .await;
 
                     self.incident_event_repository
@@ -337,7 +500,7 @@ where
                         .await?;
                 }
             }
-            // if the monitor is down or suspicious and the cause of the incident is empty or not an http monitor incident cause, we do nothing
+            // if the monitor is down or suspicious and the cause of the incident is empty or not a monitor incident cause, we do nothing
             // this should never happen, but we do not want to panic if it does
             (
                 HttpMonitorStatus::Down | HttpMonitorStatus::Suspicious,
@@ -382,16 +545,43 @@ where
         Ok(incident)
     }
 
+    /// Returns whether the monitor is covered by an ongoing maintenance window
+    pub(super) async fn is_under_maintenance(
+        &self,
+        transaction: &mut MWR::Transaction,
+        monitor: &HttpMonitor,
+    ) -> anyhow::Result<bool> {
+        let maintenance_window = find_ongoing_maintenance_window(
+            transaction,
+            &self.maintenance_window_repository,
+            monitor.organization_id,
+            Utc::now(),
+            |window| window.covers_http_monitor(monitor.id, &monitor.metadata),
+        )
+        .await?;
+        if let Some(window) = &maintenance_window {
+            debug!(
+                monitor_id = ?monitor.id,
+                maintenance_window_id = ?window.id,
+                "Monitor is under maintenance, no incident will be opened"
+            );
+        }
+        Ok(maintenance_window.is_some())
+    }
+
     /// Creates a new incident for the given monitor
     /// The incident is created in the same transaction as the monitor update.
     /// Returns the id of the created incident
+    #[allow(clippy::too_many_arguments)]
     async fn create_incident_for_monitor(
         &self,
         transaction: &mut IR::Transaction,
         monitor: &HttpMonitor,
         confirmed_incident: bool,
         incident_cause: IncidentCause,
-        mut ping_response: crate::domain::ports::http_client::PingResponse,
+        mut ping_response: PingResponse,
+        failed_assertion: Option<&HttpMonitorAssertionFailure>,
+        location_results: &[LocationPingResult],
     ) -> anyhow::Result<()>
     where
         IR: IncidentRepository,
@@ -426,6 +616,7 @@ where
             source: IncidentSource::HttpMonitor { id: monitor.id },
             cause: Some(incident_cause.clone()),
             metadata,
+            title: None,
         };
 
         debug!(incident = ?new_incident, monitor_id = ?monitor.id, "Creating new incident for monitor");
@@ -457,7 +648,13 @@ where
         .context("Failed to create incident")?;
 
         let ping_event = self
-            .create_ping_event(monitor, incident_id, &mut ping_response)
+            .create_ping_event(
+                monitor,
+                incident_id,
+                &mut ping_response,
+                failed_assertion,
+                location_results,
+            )
             .await;
 
         self.incident_event_repository
@@ -505,34 +702,43 @@ where
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn handle_changing_incident_cause(
         &self,
         transaction: &mut IR::Transaction,
         monitor: &HttpMonitor,
         incident: &Incident,
         cause: &HttpMonitorIncidentCause,
-        mut ping_response: crate::domain::ports::http_client::PingResponse,
+        last_ping: HttpMonitorIncidentCausePing,
+        mut ping_response: PingResponse,
+        location_results: &[LocationPingResult],
     ) -> anyhow::Result<()> {
         let mut previous_pings = cause.previous_pings.clone();
         previous_pings.insert(cause.last_ping.clone());
 
-        let cause = IncidentCause::HttpMonitorIncidentCause(HttpMonitorIncidentCause {
-            last_ping: HttpMonitorIncidentCausePing {
-                error_kind: monitor.error_kind,
-                http_code: monitor.last_http_code,
+        let ping_event = self
+            .create_ping_event(
+                monitor,
+                incident.id,
+                &mut ping_response,
+                last_ping.failed_assertion.as_ref(),
+                location_results,
+            )
+            .await;
+
+        let cause = monitor_incident_cause(
+            monitor,
+            HttpMonitorIncidentCause {
+                last_ping,
+                previous_pings,
             },
-            previous_pings,
-        });
+        );
 
         let updated_incident = Incident {
             cause: Some(cause),
             ..incident.clone()
         };
 
-        let ping_event = self
-            .create_ping_event(monitor, incident.id, &mut ping_response)
-            .await;
-
         self.incident_repository
             .update_incident(transaction, updated_incident)
             .await?;
@@ -547,7 +753,9 @@ where
         &self,
         monitor: &HttpMonitor,
         incident_id: uuid::Uuid,
-        ping_response: &mut crate::domain::ports::http_client::PingResponse,
+        ping_response: &mut PingResponse,
+        failed_assertion: Option<&HttpMonitorAssertionFailure>,
+        location_results: &[LocationPingResult],
     ) -> IncidentEvent {
         // Store the response body in the file storage
         let response_file_id = match ping_response.response_body_content.take() {
@@ -614,7 +822,66 @@ where
                 resolved_ip_addresses: ping_response.resolved_ip_addresses.clone(),
                 response_file_id,
                 screenshot_file_id,
+                failed_assertion: failed_assertion.cloned(),
+                location_results: location_results.to_vec(),
+                tls_certificate: ping_response.tls_certificate.clone().map(Box::new),
             })),
         }
     }
 }
+
+/// Builds the incident cause matching the kind of the monitor, from the pings that caused the incident
+fn monitor_incident_cause(monitor: &HttpMonitor, pings: HttpMonitorIncidentCause) -> IncidentCause {
+    let url = monitor.url().ok();
+    let host = url
+        .as_ref()
+        .and_then(|url| url.host_str())
+        .unwrap_or_default()
+        .to_string();
+    let port = url.as_ref().and_then(|url| url.port()).unwrap_or_default();
+    match &monitor.kind_settings {
+        MonitorKindSettings::Http => IncidentCause::HttpMonitorIncidentCause(pings),
+        MonitorKindSettings::Tcp => {
+            IncidentCause::TcpMonitorIncidentCause(TcpMonitorIncidentCause { pings, host, port })
+        }
+        MonitorKindSettings::Dns {
+            record_type,
+            expected_answers,
+        } => IncidentCause::DnsMonitorIncidentCause(DnsMonitorIncidentCause {
+            pings,
+            hostname: host,
+            record_type: *record_type,
+            expected_answers: expected_answers.clone(),
+        }),
+        MonitorKindSettings::GrpcHealth { service } => {
+            IncidentCause::GrpcHealthMonitorIncidentCause(GrpcHealthMonitorIncidentCause {
+                pings,
+                host,
+                port,
+                service: service.clone(),
+            })
+        }
+    }
+}
+
+/// Evaluates the monitor's assertions against the response, if it was received without error.
+/// The error kind of the response is set to `AssertionFailed` if an assertion did not hold.
+fn evaluate_assertions(
+    monitor: &HttpMonitor,
+    ping_response: &mut PingResponse,
+) -> Option<HttpMonitorAssertionFailure> {
+    if ping_response.error_kind != HttpMonitorErrorKind::None {
+        return None;
+    }
+    let failed_assertion = monitor.assertions.evaluate(&AssertedResponse {
+        http_code: ping_response.http_code,
+        http_headers: &ping_response.http_headers,
+        body: ping_response.response_body_content.as_deref(),
+        body_size_bytes: ping_response.response_body_size_bytes,
+        response_time: ping_response.response_time,
+    });
+    if failed_assertion.is_some() {
+        ping_response.error_kind = HttpMonitorErrorKind::AssertionFailed;
+    }
+    failed_assertion
+}
@@ -1,9 +1,15 @@
 use std::collections::HashSet;
 
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use uuid::Uuid;
 
 use crate::domain::entities::http_monitor::RequestHeaders;
+use crate::domain::entities::http_monitor_assertion::{
+    HttpMonitorAssertion, HttpMonitorAssertionFailure, HttpMonitorAssertions,
+};
+use crate::domain::entities::http_monitor_request::{
+    HttpMethod, HttpMonitorAuthentication, HttpMonitorRedirectPolicy,
+};
 use crate::domain::entities::incident::HttpMonitorIncidentCause;
 use crate::infrastructure::mocks::file_storage_mock::FileStorageMock;
 use crate::infrastructure::mocks::{
@@ -11,6 +17,7 @@ use crate::infrastructure::mocks::{
     incident_event_repository_mock::IncidentEventRepositoryMock,
     incident_notification_repository_mock::IncidentNotificationRepositoryMock,
     incident_repository_mock::IncidentRepositoryMock,
+    maintenance_window_repository_mock::MaintenanceWindowRepositoryMock,
 };
 use crate::{
     domain::{
@@ -19,9 +26,13 @@ use crate::{
             http_monitor::{HttpMonitor, HttpMonitorErrorKind, HttpMonitorStatus},
             incident::{
                 HttpMonitorIncidentCausePing, Incident, IncidentCause, IncidentPriority,
-                IncidentSourceType, IncidentStatus,
+                IncidentSourceType, IncidentStatus, TcpMonitorIncidentCause,
+                TlsCertificateIncidentCause,
             },
             incident_event::{IncidentEventPayload, IncidentEventType},
+            maintenance_window::{MaintenanceWindow, MaintenanceWindowScope},
+            monitor_kind::MonitorKindSettings,
+            tls_certificate::{TlsCertificate, DEFAULT_TLS_EXPIRY_WARNING_DAYS},
         },
         ports::{http_client::PingResponse, transactional_repository::TransactionalRepository},
     },
@@ -54,6 +65,18 @@ fn create_test_monitor(org_id: Uuid, status: HttpMonitorStatus) -> HttpMonitor {
         archived_at: None,
         request_headers: RequestHeaders::default(),
         request_timeout_ms: 2000,
+        assertions: Default::default(),
+        locations: vec![],
+        location_quorum: None,
+        request_method: Default::default(),
+        request_body: None,
+        request_body_content_type: None,
+        authentication: Default::default(),
+        redirect_policy: Default::default(),
+        probe_engine: Default::default(),
+        tls_certificate: Default::default(),
+        tls_expiry_warning_days: DEFAULT_TLS_EXPIRY_WARNING_DAYS,
+        kind_settings: Default::default(),
     }
 }
 
@@ -69,6 +92,7 @@ fn create_test_incident(org_id: Uuid, monitor: &HttpMonitor, status: IncidentSta
                 last_ping: HttpMonitorIncidentCausePing {
                     error_kind: HttpMonitorErrorKind::Timeout,
                     http_code: None,
+                    failed_assertion: None,
                 },
                 previous_pings: HashSet::new(),
             },
@@ -78,6 +102,7 @@ fn create_test_incident(org_id: Uuid, monitor: &HttpMonitor, status: IncidentSta
         incident_source_type: IncidentSourceType::HttpMonitor,
         incident_source_id: monitor.id,
         acknowledged_by: vec![],
+        title: None,
         metadata: EntityMetadata::default(),
     }
 }
@@ -105,6 +130,7 @@ async fn test_handle_ping_response_up_to_suspicious() -> anyhow::Result<()> {
         incident_repository: incident_repo,
         incident_event_repository: incident_event_repo,
         incident_notification_repository: incident_notification_repo,
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
         http_client: HttpClientMock::new(),
         file_storage: FileStorageMock,
     };
@@ -119,6 +145,7 @@ async fn test_handle_ping_response_up_to_suspicious() -> anyhow::Result<()> {
         response_body_size_bytes: 0,
         response_body_content: None,
         screenshot: None,
+        tls_certificate: None,
     };
 
     use_case
@@ -132,6 +159,14 @@ async fn test_handle_ping_response_up_to_suspicious() -> anyhow::Result<()> {
     assert_eq!(updated_monitor.status_counter, 1);
     assert_eq!(updated_monitor.error_kind, HttpMonitorErrorKind::Timeout);
 
+    // Verify the ping was recorded
+    let pings = use_case.http_monitor_repository.pings.lock().await;
+    let ping = pings.first().expect("Ping should be recorded");
+    assert_eq!(ping.http_monitor_id, updated_monitor.id);
+    assert_eq!(ping.status, HttpMonitorStatus::Suspicious);
+    assert_eq!(ping.error_kind, HttpMonitorErrorKind::Timeout);
+    assert_eq!(ping.response_time_ms, 1000);
+
     // Verify an unconfirmed incident was created
     let incident_state = use_case.incident_repository.state.lock().await;
     let incident = incident_state.first().expect("Incident should exist");
@@ -167,6 +202,7 @@ async fn test_handle_ping_response_down_without_existing_incident() -> anyhow::R
         incident_repository: incident_repo,
         incident_event_repository: incident_event_repo,
         incident_notification_repository: incident_notification_repo,
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
         http_client: HttpClientMock::new(),
         file_storage: FileStorageMock,
     };
@@ -181,6 +217,7 @@ async fn test_handle_ping_response_down_without_existing_incident() -> anyhow::R
         response_body_size_bytes: 0,
         response_body_content: None,
         screenshot: None,
+        tls_certificate: None,
     };
 
     // execute the use case
@@ -233,6 +270,7 @@ async fn test_handle_ping_response_suspicious_to_down() -> anyhow::Result<()> {
         incident_repository: incident_repo,
         incident_event_repository: incident_event_repo,
         incident_notification_repository: incident_notification_repo,
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
         http_client: HttpClientMock::new(),
         file_storage: FileStorageMock,
     };
@@ -247,6 +285,7 @@ async fn test_handle_ping_response_suspicious_to_down() -> anyhow::Result<()> {
         response_body_size_bytes: 0,
         response_body_content: None,
         screenshot: None,
+        tls_certificate: None,
     };
 
     let existing_incident = use_case
@@ -307,6 +346,7 @@ async fn test_handle_ping_response_down_to_recovering() -> anyhow::Result<()> {
         incident_repository: incident_repo,
         incident_event_repository: incident_event_repo,
         incident_notification_repository: incident_notification_repo,
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
         http_client: HttpClientMock::new(),
         file_storage: FileStorageMock,
     };
@@ -321,6 +361,7 @@ async fn test_handle_ping_response_down_to_recovering() -> anyhow::Result<()> {
         response_body_size_bytes: 0,
         response_body_content: None,
         screenshot: None,
+        tls_certificate: None,
     };
 
     let existing_incident = use_case
@@ -381,6 +422,7 @@ async fn test_no_new_incident_created_when_incident_exists() -> anyhow::Result<(
             incident_repository: incident_repo.clone(),
             incident_event_repository: incident_event_repo.clone(),
             incident_notification_repository: incident_notification_repo.clone(),
+            maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
             http_client: HttpClientMock::new(),
             file_storage: FileStorageMock,
         };
@@ -395,6 +437,7 @@ async fn test_no_new_incident_created_when_incident_exists() -> anyhow::Result<(
             response_body_size_bytes: 0,
             response_body_content: None,
             screenshot: None,
+            tls_certificate: None,
         };
 
         let existing_incident = use_case
@@ -440,6 +483,7 @@ async fn test_handle_ping_response_updates_incident_cause_when_error_code_change
             last_ping: HttpMonitorIncidentCausePing {
                 error_kind: HttpMonitorErrorKind::HttpCode,
                 http_code: Some(500),
+                failed_assertion: None,
             },
             previous_pings: HashSet::new(),
         },
@@ -463,6 +507,7 @@ async fn test_handle_ping_response_updates_incident_cause_when_error_code_change
         incident_repository: incident_repo.clone(),
         incident_event_repository: incident_event_repo.clone(),
         incident_notification_repository: incident_notification_repo.clone(),
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
         http_client: HttpClientMock::new(),
         file_storage: FileStorageMock,
     };
@@ -477,6 +522,7 @@ async fn test_handle_ping_response_updates_incident_cause_when_error_code_change
         response_body_size_bytes: 0,
         response_body_content: None,
         screenshot: None,
+        tls_certificate: None,
     };
 
     let existing_incident = use_case
@@ -509,7 +555,8 @@ async fn test_handle_ping_response_updates_incident_cause_when_error_code_change
             previous_pings[0],
             HttpMonitorIncidentCausePing {
                 error_kind: HttpMonitorErrorKind::HttpCode,
-                http_code: Some(500)
+                http_code: Some(500),
+                failed_assertion: None,
             }
         );
     } else {
@@ -561,6 +608,7 @@ async fn test_handle_ping_response_http_code_500_to_recovering() -> anyhow::Resu
                 last_ping: HttpMonitorIncidentCausePing {
                     error_kind: HttpMonitorErrorKind::HttpCode,
                     http_code: Some(500),
+                    failed_assertion: None,
                 },
                 previous_pings: HashSet::new(),
             },
@@ -573,6 +621,7 @@ async fn test_handle_ping_response_http_code_500_to_recovering() -> anyhow::Resu
         incident_repository: incident_repo,
         incident_event_repository: incident_event_repo,
         incident_notification_repository: incident_notification_repo,
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
         http_client: HttpClientMock::new(),
         file_storage: FileStorageMock,
     };
@@ -587,6 +636,7 @@ async fn test_handle_ping_response_http_code_500_to_recovering() -> anyhow::Resu
         response_body_size_bytes: 0,
         response_body_content: None,
         screenshot: None,
+        tls_certificate: None,
     };
 
     let existing_incident = use_case
@@ -629,7 +679,8 @@ async fn test_handle_ping_response_http_code_500_to_recovering() -> anyhow::Resu
             last_ping,
             &HttpMonitorIncidentCausePing {
                 error_kind: HttpMonitorErrorKind::HttpCode,
-                http_code: Some(500)
+                http_code: Some(500),
+                failed_assertion: None,
             }
         );
     } else {
@@ -685,6 +736,7 @@ async fn test_handle_ping_response_up_with_ongoing_incident() -> anyhow::Result<
         incident_repository: incident_repo,
         incident_event_repository: incident_event_repo,
         incident_notification_repository: incident_notification_repo,
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
         http_client: HttpClientMock::new(),
         file_storage: FileStorageMock,
     };
@@ -699,6 +751,7 @@ async fn test_handle_ping_response_up_with_ongoing_incident() -> anyhow::Result<
         response_body_size_bytes: 0,
         response_body_content: None,
         screenshot: None,
+        tls_certificate: None,
     };
 
     let existing_incident = use_case
@@ -767,6 +820,7 @@ async fn test_handle_ping_response_recovering_without_status_change() -> anyhow:
         incident_repository: incident_repo,
         incident_event_repository: incident_event_repo,
         incident_notification_repository: incident_notification_repo,
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
         http_client: HttpClientMock::new(),
         file_storage: FileStorageMock,
     };
@@ -781,6 +835,7 @@ async fn test_handle_ping_response_recovering_without_status_change() -> anyhow:
         response_body_size_bytes: 0,
         response_body_content: None,
         screenshot: None,
+        tls_certificate: None,
     };
 
     let existing_incident = use_case
@@ -831,6 +886,7 @@ async fn test_handle_ping_response_suspicious_with_ongoing_incident() -> anyhow:
                 last_ping: HttpMonitorIncidentCausePing {
                     error_kind: HttpMonitorErrorKind::HttpCode,
                     http_code: Some(500),
+                    failed_assertion: None,
                 },
                 previous_pings: HashSet::new(),
             },
@@ -843,6 +899,7 @@ async fn test_handle_ping_response_suspicious_with_ongoing_incident() -> anyhow:
         incident_repository: incident_repo,
         incident_event_repository: incident_event_repo,
         incident_notification_repository: incident_notification_repo,
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
         http_client: HttpClientMock::new(),
         file_storage: FileStorageMock,
     };
@@ -857,6 +914,7 @@ async fn test_handle_ping_response_suspicious_with_ongoing_incident() -> anyhow:
         response_body_size_bytes: 0,
         response_body_content: None,
         screenshot: None,
+        tls_certificate: None,
     };
 
     let existing_incident = use_case
@@ -888,7 +946,8 @@ async fn test_handle_ping_response_suspicious_with_ongoing_incident() -> anyhow:
             previous_pings[0],
             HttpMonitorIncidentCausePing {
                 error_kind: HttpMonitorErrorKind::HttpCode,
-                http_code: Some(500)
+                http_code: Some(500),
+                failed_assertion: None,
             }
         );
 
@@ -939,6 +998,7 @@ async fn test_handle_ping_response_unknown_to_suspicious_to_up() -> anyhow::Resu
         incident_repository: incident_repo,
         incident_event_repository: incident_event_repo,
         incident_notification_repository: incident_notification_repo,
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
         http_client: HttpClientMock::new(),
         file_storage: FileStorageMock,
     };
@@ -954,6 +1014,7 @@ async fn test_handle_ping_response_unknown_to_suspicious_to_up() -> anyhow::Resu
         response_body_size_bytes: 0,
         response_body_content: None,
         screenshot: None,
+        tls_certificate: None,
     };
 
     use_case
@@ -997,6 +1058,7 @@ async fn test_handle_ping_response_unknown_to_suspicious_to_up() -> anyhow::Resu
         response_body_size_bytes: 0,
         response_body_content: None,
         screenshot: None,
+        tls_certificate: None,
     };
 
     let existing_incident = use_case
@@ -1029,3 +1091,571 @@ async fn test_handle_ping_response_unknown_to_suspicious_to_up() -> anyhow::Resu
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_handle_ping_response_failed_assertion() -> anyhow::Result<()> {
+    let http_monitor_repo = HttpMonitorRepositoryMock::new();
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+
+    let org_id = Uuid::new_v4();
+    let mut monitor = create_test_monitor(org_id, HttpMonitorStatus::Up);
+    monitor.downtime_confirmation_threshold = 1;
+    monitor.assertions = HttpMonitorAssertions {
+        items: vec![HttpMonitorAssertion::BodyContains {
+            value: "healthy".to_string(),
+        }],
+    };
+
+    let mut tx = http_monitor_repo.begin_transaction().await?;
+
+    // Add monitor to repository
+    {
+        let mut state = http_monitor_repo.state.lock().await;
+        state.push(monitor.clone());
+    }
+
+    let use_case = ExecuteHttpMonitorsUseCase {
+        http_monitor_repository: http_monitor_repo,
+        incident_repository: incident_repo,
+        incident_event_repository: incident_event_repo,
+        incident_notification_repository: incident_notification_repo,
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
+        http_client: HttpClientMock::new(),
+        file_storage: FileStorageMock,
+    };
+
+    // The request succeeds, but the body does not contain the expected string
+    let ping_response = PingResponse {
+        error_kind: HttpMonitorErrorKind::None,
+        http_code: Some(200),
+        http_headers: Default::default(),
+        response_time: std::time::Duration::from_secs(1),
+        response_ip_address: None,
+        resolved_ip_addresses: vec![],
+        response_body_size_bytes: 14,
+        response_body_content: Some(b"service broken".to_vec()),
+        screenshot: None,
+        tls_certificate: None,
+    };
+
+    use_case
+        .handle_ping_response(&mut tx, monitor, ping_response, None)
+        .await?;
+
+    // Verify the monitor switched to down because of the assertion
+    let monitor_state = use_case.http_monitor_repository.state.lock().await;
+    let updated_monitor = monitor_state.first().expect("Monitor should exist");
+    assert_eq!(updated_monitor.status, HttpMonitorStatus::Down);
+    assert_eq!(updated_monitor.error_kind, HttpMonitorErrorKind::AssertionFailed);
+
+    // Verify the failed assertion is recorded in the incident cause
+    let expected_failure = HttpMonitorAssertionFailure {
+        assertion: HttpMonitorAssertion::BodyContains {
+            value: "healthy".to_string(),
+        },
+        actual_value: None,
+    };
+    let incident_state = use_case.incident_repository.state.lock().await;
+    let incident = incident_state.first().expect("Incident should exist");
+    assert_eq!(incident.status, IncidentStatus::Ongoing);
+    #[allow(irrefutable_let_patterns)]
+    if let Some(IncidentCause::HttpMonitorIncidentCause(cause)) = &incident.cause {
+        assert_eq!(cause.last_ping.error_kind, HttpMonitorErrorKind::AssertionFailed);
+        assert_eq!(cause.last_ping.failed_assertion, Some(expected_failure.clone()));
+    } else {
+        panic!("Incident cause should be HttpMonitorIncidentCause");
+    }
+
+    // Verify the failed assertion is recorded in the ping event
+    let events = use_case.incident_event_repository.state.lock().await;
+    let ping_event = events
+        .iter()
+        .find(|event| event.event_type == IncidentEventType::MonitorPinged)
+        .expect("Ping event should exist");
+    if let Some(IncidentEventPayload::MonitorPing(payload)) = &ping_event.event_payload {
+        assert_eq!(payload.failed_assertion, Some(expected_failure));
+    } else {
+        panic!("Ping event payload should be MonitorPing");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_handle_ping_response_down_under_maintenance() -> anyhow::Result<()> {
+    let http_monitor_repo = HttpMonitorRepositoryMock::new();
+    let maintenance_window_repo = MaintenanceWindowRepositoryMock::new();
+
+    let org_id = Uuid::new_v4();
+    let mut monitor = create_test_monitor(org_id, HttpMonitorStatus::Up);
+    monitor.downtime_confirmation_threshold = 1;
+
+    let mut tx = http_monitor_repo.begin_transaction().await?;
+    http_monitor_repo.state.lock().await.push(monitor.clone());
+    maintenance_window_repo.state.lock().await.push(MaintenanceWindow {
+        organization_id: org_id,
+        id: Uuid::new_v4(),
+        title: "Deployment".to_string(),
+        description: None,
+        starts_at: Utc::now() - Duration::minutes(5),
+        ends_at: Some(Utc::now() + Duration::minutes(5)),
+        cron_schedule: None,
+        duration_seconds: None,
+        scope: MaintenanceWindowScope {
+            http_monitor_ids: vec![monitor.id],
+            ..Default::default()
+        },
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    });
+
+    let use_case = ExecuteHttpMonitorsUseCase {
+        http_monitor_repository: http_monitor_repo,
+        incident_repository: IncidentRepositoryMock::new(),
+        incident_event_repository: IncidentEventRepositoryMock::new(),
+        incident_notification_repository: IncidentNotificationRepositoryMock::new(),
+        maintenance_window_repository: maintenance_window_repo,
+        http_client: HttpClientMock::new(),
+        file_storage: FileStorageMock,
+    };
+
+    let ping_response = PingResponse {
+        error_kind: HttpMonitorErrorKind::Timeout,
+        http_code: None,
+        http_headers: Default::default(),
+        response_time: std::time::Duration::from_secs(1),
+        response_ip_address: None,
+        resolved_ip_addresses: vec![],
+        response_body_size_bytes: 0,
+        response_body_content: None,
+        screenshot: None,
+        tls_certificate: None,
+    };
+
+    use_case
+        .handle_ping_response(&mut tx, monitor, ping_response, None)
+        .await?;
+
+    // The result of the ping is still recorded
+    let monitor_state = use_case.http_monitor_repository.state.lock().await;
+    assert_eq!(monitor_state[0].status, HttpMonitorStatus::Down);
+    assert_eq!(use_case.http_monitor_repository.pings.lock().await.len(), 1);
+
+    // But no incident is opened and no notification is sent
+    assert!(use_case.incident_repository.state.lock().await.is_empty());
+    assert!(use_case
+        .incident_notification_repository
+        .state
+        .lock()
+        .await
+        .is_empty());
+
+    Ok(())
+}
+
+fn create_test_ping_response(error_kind: HttpMonitorErrorKind, http_code: Option<u16>) -> PingResponse {
+    PingResponse {
+        error_kind,
+        http_code,
+        response_time: std::time::Duration::from_millis(200),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_handle_location_ping_responses_below_quorum() -> anyhow::Result<()> {
+    let http_monitor_repo = HttpMonitorRepositoryMock::new();
+
+    let org_id = Uuid::new_v4();
+    let mut monitor = create_test_monitor(org_id, HttpMonitorStatus::Up);
+    monitor.downtime_confirmation_threshold = 1;
+    monitor.locations = vec!["paris".to_string(), "london".to_string(), "nyc".to_string()];
+
+    let mut tx = http_monitor_repo.begin_transaction().await?;
+    http_monitor_repo.state.lock().await.push(monitor.clone());
+
+    let use_case = ExecuteHttpMonitorsUseCase {
+        http_monitor_repository: http_monitor_repo,
+        incident_repository: IncidentRepositoryMock::new(),
+        incident_event_repository: IncidentEventRepositoryMock::new(),
+        incident_notification_repository: IncidentNotificationRepositoryMock::new(),
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
+        http_client: HttpClientMock::with_locations(&["paris", "london", "nyc"]),
+        file_storage: FileStorageMock,
+    };
+
+    // Only one location out of three fails, the default quorum being a majority the monitor stays up
+    let location_pings = vec![
+        ("paris".to_string(), create_test_ping_response(HttpMonitorErrorKind::Timeout, None)),
+        ("london".to_string(), create_test_ping_response(HttpMonitorErrorKind::None, Some(200))),
+        ("nyc".to_string(), create_test_ping_response(HttpMonitorErrorKind::None, Some(200))),
+    ];
+
+    use_case
+        .handle_location_ping_responses(&mut tx, monitor, location_pings, None)
+        .await?;
+
+    let monitor_state = use_case.http_monitor_repository.state.lock().await;
+    assert_eq!(monitor_state[0].status, HttpMonitorStatus::Up);
+    assert_eq!(monitor_state[0].error_kind, HttpMonitorErrorKind::None);
+    assert_eq!(monitor_state[0].last_http_code, Some(200));
+    assert!(use_case.incident_repository.state.lock().await.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_execute_due_http_monitors_with_location_quorum() -> anyhow::Result<()> {
+    let http_monitor_repo = HttpMonitorRepositoryMock::new();
+    let http_client = HttpClientMock::with_locations(&["paris", "london", "nyc"]);
+
+    let org_id = Uuid::new_v4();
+    let mut monitor = create_test_monitor(org_id, HttpMonitorStatus::Up);
+    monitor.downtime_confirmation_threshold = 1;
+    monitor.locations = vec!["paris".to_string(), "london".to_string(), "nyc".to_string()];
+    monitor.location_quorum = Some(2);
+    http_monitor_repo.state.lock().await.push(monitor.clone());
+
+    http_client
+        .set_next_location_response("paris", create_test_ping_response(HttpMonitorErrorKind::Connect, None))
+        .await;
+    http_client
+        .set_next_location_response("london", create_test_ping_response(HttpMonitorErrorKind::None, Some(200)))
+        .await;
+    http_client
+        .set_next_location_response("nyc", create_test_ping_response(HttpMonitorErrorKind::HttpCode, Some(503)))
+        .await;
+
+    let use_case = ExecuteHttpMonitorsUseCase {
+        http_monitor_repository: http_monitor_repo,
+        incident_repository: IncidentRepositoryMock::new(),
+        incident_event_repository: IncidentEventRepositoryMock::new(),
+        incident_notification_repository: IncidentNotificationRepositoryMock::new(),
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
+        http_client,
+        file_storage: FileStorageMock,
+    };
+
+    let executed = use_case.fetch_and_execute_due_http_monitors(0, 10, 10).await?;
+    assert_eq!(executed, 1);
+
+    // Two locations out of three fail, which reaches the quorum
+    let monitor_state = use_case.http_monitor_repository.state.lock().await;
+    assert_eq!(monitor_state[0].status, HttpMonitorStatus::Down);
+    assert_eq!(monitor_state[0].error_kind, HttpMonitorErrorKind::Connect);
+
+    let incidents = use_case.incident_repository.state.lock().await;
+    assert_eq!(incidents.len(), 1);
+    assert_eq!(incidents[0].status, IncidentStatus::Ongoing);
+
+    // The result of each location is recorded in the ping event
+    let events = use_case.incident_event_repository.state.lock().await;
+    let ping_event = events
+        .iter()
+        .find(|e| e.event_type == IncidentEventType::MonitorPinged)
+        .expect("Ping event should exist");
+    let Some(IncidentEventPayload::MonitorPing(payload)) = &ping_event.event_payload else {
+        panic!("Ping event payload should be MonitorPing");
+    };
+    let location_results = payload
+        .location_results
+        .iter()
+        .map(|r| (r.location.as_str(), r.error_kind, r.http_code))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        location_results,
+        vec![
+            ("paris", HttpMonitorErrorKind::Connect, None),
+            ("london", HttpMonitorErrorKind::None, Some(200)),
+            ("nyc", HttpMonitorErrorKind::HttpCode, Some(503)),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_execute_due_http_monitors_sends_monitor_request() -> anyhow::Result<()> {
+    let http_monitor_repo = HttpMonitorRepositoryMock::new();
+    let http_client = HttpClientMock::new();
+
+    let org_id = Uuid::new_v4();
+    let mut monitor = create_test_monitor(org_id, HttpMonitorStatus::Up);
+    monitor.request_method = HttpMethod::Post;
+    monitor.request_body = Some("{\"query\":\"{ health }\"}".to_string());
+    monitor.request_body_content_type = Some("application/json".to_string());
+    monitor.authentication = HttpMonitorAuthentication::Bearer {
+        token: "secret".to_string(),
+    };
+    monitor.redirect_policy = HttpMonitorRedirectPolicy::MaxHops { max_hops: 2 };
+    http_monitor_repo.state.lock().await.push(monitor.clone());
+
+    http_client
+        .set_next_response(create_test_ping_response(HttpMonitorErrorKind::None, Some(200)))
+        .await;
+
+    let use_case = ExecuteHttpMonitorsUseCase {
+        http_monitor_repository: http_monitor_repo,
+        incident_repository: IncidentRepositoryMock::new(),
+        incident_event_repository: IncidentEventRepositoryMock::new(),
+        incident_notification_repository: IncidentNotificationRepositoryMock::new(),
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
+        http_client,
+        file_storage: FileStorageMock,
+    };
+
+    use_case.fetch_and_execute_due_http_monitors(0, 10, 10).await?;
+
+    let requests = use_case.http_client.requests.lock().await;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].endpoint, monitor.url);
+    assert_eq!(requests[0].method, HttpMethod::Post);
+    assert_eq!(requests[0].body, monitor.request_body);
+    assert_eq!(requests[0].body_content_type, monitor.request_body_content_type);
+    assert_eq!(requests[0].authentication, monitor.authentication);
+    assert_eq!(requests[0].redirect_policy, monitor.redirect_policy);
+
+    Ok(())
+}
+
+fn create_test_tls_certificate(expires_in: Duration, valid_chain: bool) -> TlsCertificate {
+    TlsCertificate {
+        subject: "CN=example.com".to_string(),
+        issuer: "CN=Example CA".to_string(),
+        subject_alternative_names: vec!["example.com".to_string()],
+        not_before: Utc::now() - Duration::days(60),
+        not_after: Utc::now() + expires_in,
+        hostname_matches: true,
+        valid_chain,
+    }
+}
+
+#[tokio::test]
+async fn test_handle_ping_response_expiring_tls_certificate() -> anyhow::Result<()> {
+    let http_monitor_repo = HttpMonitorRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let monitor = create_test_monitor(org_id, HttpMonitorStatus::Up);
+    http_monitor_repo.state.lock().await.push(monitor.clone());
+
+    let use_case = ExecuteHttpMonitorsUseCase {
+        http_monitor_repository: http_monitor_repo,
+        incident_repository: IncidentRepositoryMock::new(),
+        incident_event_repository: IncidentEventRepositoryMock::new(),
+        incident_notification_repository: IncidentNotificationRepositoryMock::new(),
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
+        http_client: HttpClientMock::new(),
+        file_storage: FileStorageMock,
+    };
+    let mut tx = use_case.http_monitor_repository.begin_transaction().await?;
+
+    // The certificate expires within the warning period of the monitor
+    let expiring_certificate = create_test_tls_certificate(Duration::days(5), true);
+    let ping_response = PingResponse {
+        tls_certificate: Some(expiring_certificate.clone()),
+        ..create_test_ping_response(HttpMonitorErrorKind::None, Some(200))
+    };
+    use_case
+        .handle_ping_response(&mut tx, monitor.clone(), ping_response, None)
+        .await?;
+
+    {
+        let monitor_state = use_case.http_monitor_repository.state.lock().await;
+        assert_eq!(monitor_state[0].status, HttpMonitorStatus::Up);
+        assert_eq!(monitor_state[0].tls_certificate.0, Some(expiring_certificate.clone()));
+
+        let incidents = use_case.incident_repository.state.lock().await;
+        assert_eq!(incidents.len(), 1);
+        assert_eq!(incidents[0].incident_source_type, IncidentSourceType::TlsCertificate);
+        assert_eq!(incidents[0].incident_source_id, monitor.id);
+        assert_eq!(incidents[0].priority, IncidentPriority::Warning);
+        assert_eq!(incidents[0].status, IncidentStatus::Ongoing);
+        assert_eq!(
+            incidents[0].cause,
+            Some(IncidentCause::TlsCertificateIncidentCause(TlsCertificateIncidentCause {
+                certificate: expiring_certificate.clone(),
+                expiry_warning_days: DEFAULT_TLS_EXPIRY_WARNING_DAYS,
+            }))
+        );
+        assert_eq!(use_case.incident_notification_repository.state.lock().await.len(), 1);
+    }
+
+    // A ping that does not reach the TLS handshake keeps the incident and the last observed certificate
+    use_case
+        .handle_ping_response(
+            &mut tx,
+            monitor.clone(),
+            create_test_ping_response(HttpMonitorErrorKind::Connect, None),
+            None,
+        )
+        .await?;
+    {
+        let monitor_state = use_case.http_monitor_repository.state.lock().await;
+        assert_eq!(monitor_state[0].tls_certificate.0, Some(expiring_certificate));
+
+        let incidents = use_case.incident_repository.state.lock().await;
+        let tls_incidents = incidents
+            .iter()
+            .filter(|i| i.incident_source_type == IncidentSourceType::TlsCertificate)
+            .collect::<Vec<_>>();
+        assert_eq!(tls_incidents.len(), 1);
+        assert_eq!(tls_incidents[0].status, IncidentStatus::Ongoing);
+    }
+
+    // The renewed certificate resolves the incident
+    let ping_response = PingResponse {
+        tls_certificate: Some(create_test_tls_certificate(Duration::days(90), true)),
+        ..create_test_ping_response(HttpMonitorErrorKind::None, Some(200))
+    };
+    use_case
+        .handle_ping_response(&mut tx, monitor, ping_response, None)
+        .await?;
+
+    let incidents = use_case.incident_repository.state.lock().await;
+    let tls_incident = incidents
+        .iter()
+        .find(|i| i.incident_source_type == IncidentSourceType::TlsCertificate)
+        .expect("Certificate expiry incident should exist");
+    assert_eq!(tls_incident.status, IncidentStatus::Resolved);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_handle_ping_response_invalid_tls_certificate() -> anyhow::Result<()> {
+    let http_monitor_repo = HttpMonitorRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let mut monitor = create_test_monitor(org_id, HttpMonitorStatus::Up);
+    monitor.downtime_confirmation_threshold = 1;
+    http_monitor_repo.state.lock().await.push(monitor.clone());
+
+    let use_case = ExecuteHttpMonitorsUseCase {
+        http_monitor_repository: http_monitor_repo,
+        incident_repository: IncidentRepositoryMock::new(),
+        incident_event_repository: IncidentEventRepositoryMock::new(),
+        incident_notification_repository: IncidentNotificationRepositoryMock::new(),
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
+        http_client: HttpClientMock::new(),
+        file_storage: FileStorageMock,
+    };
+    let mut tx = use_case.http_monitor_repository.begin_transaction().await?;
+
+    // The certificate has expired, so the chain is invalid and the monitor goes down
+    let expired_certificate = create_test_tls_certificate(Duration::days(-1), false);
+    let ping_response = PingResponse {
+        tls_certificate: Some(expired_certificate.clone()),
+        ..create_test_ping_response(HttpMonitorErrorKind::TlsCertificate, None)
+    };
+    use_case
+        .handle_ping_response(&mut tx, monitor, ping_response, None)
+        .await?;
+
+    let monitor_state = use_case.http_monitor_repository.state.lock().await;
+    assert_eq!(monitor_state[0].status, HttpMonitorStatus::Down);
+    assert_eq!(monitor_state[0].error_kind, HttpMonitorErrorKind::TlsCertificate);
+
+    // Both the downtime and the certificate expiry incidents are open
+    let incidents = use_case.incident_repository.state.lock().await;
+    let mut sources = incidents
+        .iter()
+        .map(|i| (i.incident_source_type as i16, i.priority, i.status))
+        .collect::<Vec<_>>();
+    sources.sort_by_key(|(source_type, _, _)| *source_type);
+    assert_eq!(
+        sources,
+        vec![
+            (IncidentSourceType::HttpMonitor as i16, IncidentPriority::Major, IncidentStatus::Ongoing),
+            (IncidentSourceType::TlsCertificate as i16, IncidentPriority::Warning, IncidentStatus::Ongoing),
+        ]
+    );
+
+    // The certificate is recorded in the ping event of the downtime incident
+    let events = use_case.incident_event_repository.state.lock().await;
+    let ping_event = events
+        .iter()
+        .find(|e| e.event_type == IncidentEventType::MonitorPinged)
+        .expect("Ping event should exist");
+    let Some(IncidentEventPayload::MonitorPing(payload)) = &ping_event.event_payload else {
+        panic!("Ping event payload should be MonitorPing");
+    };
+    assert_eq!(payload.tls_certificate, Some(Box::new(expired_certificate)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_handle_ping_response_tcp_monitor_incident_cause() -> anyhow::Result<()> {
+    let http_monitor_repo = HttpMonitorRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let mut monitor = create_test_monitor(org_id, HttpMonitorStatus::Up);
+    monitor.url = "tcp://db.example.com:5432".to_string();
+    monitor.kind_settings = MonitorKindSettings::Tcp;
+    monitor.downtime_confirmation_threshold = 1;
+    http_monitor_repo.state.lock().await.push(monitor.clone());
+
+    let use_case = ExecuteHttpMonitorsUseCase {
+        http_monitor_repository: http_monitor_repo,
+        incident_repository: IncidentRepositoryMock::new(),
+        incident_event_repository: IncidentEventRepositoryMock::new(),
+        incident_notification_repository: IncidentNotificationRepositoryMock::new(),
+        maintenance_window_repository: MaintenanceWindowRepositoryMock::new(),
+        http_client: HttpClientMock::new(),
+        file_storage: FileStorageMock,
+    };
+    let mut tx = use_case.http_monitor_repository.begin_transaction().await?;
+
+    use_case
+        .handle_ping_response(
+            &mut tx,
+            monitor.clone(),
+            create_test_ping_response(HttpMonitorErrorKind::Connect, None),
+            None,
+        )
+        .await?;
+
+    let connect_ping = HttpMonitorIncidentCausePing {
+        error_kind: HttpMonitorErrorKind::Connect,
+        http_code: None,
+        failed_assertion: None,
+    };
+    {
+        let incidents = use_case.incident_repository.state.lock().await;
+        assert_eq!(incidents.len(), 1);
+        assert_eq!(incidents[0].status, IncidentStatus::Ongoing);
+        assert_eq!(
+            incidents[0].cause,
+            Some(IncidentCause::TcpMonitorIncidentCause(TcpMonitorIncidentCause {
+                pings: HttpMonitorIncidentCause {
+                    last_ping: connect_ping.clone(),
+                    previous_pings: HashSet::new(),
+                },
+                host: "db.example.com".to_string(),
+                port: 5432,
+            }))
+        );
+    }
+
+    // A new cause keeps the kind of the incident cause, and the previous pings
+    let existing_incident = use_case.incident_repository.state.lock().await[0].clone();
+    let monitor = use_case.http_monitor_repository.state.lock().await[0].clone();
+    use_case
+        .handle_ping_response(
+            &mut tx,
+            monitor,
+            create_test_ping_response(HttpMonitorErrorKind::Timeout, None),
+            Some(existing_incident),
+        )
+        .await?;
+
+    let incidents = use_case.incident_repository.state.lock().await;
+    assert_eq!(incidents.len(), 1);
+    let Some(IncidentCause::TcpMonitorIncidentCause(cause)) = &incidents[0].cause else {
+        panic!("The incident cause should be a TCP monitor incident cause");
+    };
+    assert_eq!(cause.pings.last_ping.error_kind, HttpMonitorErrorKind::Timeout);
+    assert_eq!(cause.pings.previous_pings, HashSet::from([connect_ping]));
+    assert_eq!(cause.port, 5432);
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use anyhow::Context;
+use chrono::Utc;
+use tracing::debug;
+
+use crate::domain::{
+    entities::{
+        http_monitor::HttpMonitor,
+        incident::{
+            IncidentCause, IncidentPriority, IncidentSource, IncidentStatus, NewIncident,
+            TlsCertificateIncidentCause,
+        },
+        incident_notification::IncidentNotificationPayload,
+        tls_certificate::TlsCertificate,
+    },
+    ports::{
+        file_storage::FileStorage,
+        http_client::HttpClient,
+        http_monitor_repository::HttpMonitorRepository,
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::{IncidentRepository, ListIncidentsOpts},
+        maintenance_window_repository::MaintenanceWindowRepository,
+    },
+    use_cases::incidents::{create_incident, resolve_incident, NotificationOpts},
+};
+
+use super::ExecuteHttpMonitorsUseCase;
+
+impl<HMR, IR, IER, INR, MWR, HC, FS> ExecuteHttpMonitorsUseCase<HMR, IR, IER, INR, MWR, HC, FS>
+where
+    HMR: HttpMonitorRepository,
+    IR: IncidentRepository<Transaction = HMR::Transaction>,
+    IER: IncidentEventRepository<Transaction = HMR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = HMR::Transaction>,
+    MWR: MaintenanceWindowRepository<Transaction = HMR::Transaction>,
+    HC: HttpClient,
+    FS: FileStorage,
+{
+    /// Opens a warning incident when the certificate observed by a ping expires within the monitor's warning period,
+    /// and resolves it once a ping observes a certificate that does not (usually, a renewed one).
+    /// Pings that did not reach the TLS handshake leave the incident as is.
+    /// The incident is independent from the incidents opened when the monitor is down.
+    pub(super) async fn handle_tls_certificate_expiry(
+        &self,
+        transaction: &mut HMR::Transaction,
+        monitor: &HttpMonitor,
+        tls_certificate: Option<&TlsCertificate>,
+    ) -> anyhow::Result<()> {
+        let Some(certificate) = tls_certificate else {
+            return Ok(());
+        };
+        let expiring = monitor.tls_expiry_warning_days > 0
+            && certificate.expires_within(Utc::now(), monitor.tls_expiry_warning_days);
+
+        let existing_incident = self
+            .incident_repository
+            .list_incidents(
+                transaction,
+                monitor.organization_id,
+                ListIncidentsOpts {
+                    include_statuses: &[IncidentStatus::Ongoing, IncidentStatus::ToBeConfirmed],
+                    include_priorities: &IncidentPriority::ALL,
+                    include_sources: &[IncidentSource::TlsCertificate { id: monitor.id }],
+                    limit: 1,
+                    ..Default::default()
+                },
+            )
+            .await
+            .context("Failed to list existing certificate expiry incidents for monitor")?
+            .incidents
+            .into_iter()
+            .next();
+
+        match (expiring, existing_incident) {
+            (true, None) => {
+                if self.is_under_maintenance(transaction, monitor).await? {
+                    return Ok(());
+                }
+                self.create_tls_certificate_incident(transaction, monitor, certificate)
+                    .await
+            }
+            (false, Some(incident)) => {
+                debug!(
+                    monitor_id = ?monitor.id,
+                    incident_id = ?incident.id,
+                    not_after = ?certificate.not_after,
+                    "Certificate is no longer about to expire, resolving the incident"
+                );
+                resolve_incident(
+                    transaction,
+                    &self.incident_repository,
+                    &self.incident_event_repository,
+                    &self.incident_notification_repository,
+                    &incident,
+                    None,
+                )
+                .await?;
+                Ok(())
+            }
+            // the incident is already open, or there is nothing to report
+            (true, Some(_)) | (false, None) => Ok(()),
+        }
+    }
+
+    async fn create_tls_certificate_incident(
+        &self,
+        transaction: &mut HMR::Transaction,
+        monitor: &HttpMonitor,
+        certificate: &TlsCertificate,
+    ) -> anyhow::Result<()> {
+        let mut metadata = monitor.metadata.clone();
+        if let Ok(url) = monitor.url() {
+            metadata.records.insert("url".to_string(), url.to_string());
+            if let Some(host) = url.host_str() {
+                metadata
+                    .records
+                    .insert("host".to_string(), host.to_string());
+            }
+        }
+
+        let incident_cause = IncidentCause::TlsCertificateIncidentCause(TlsCertificateIncidentCause {
+            certificate: certificate.clone(),
+            expiry_warning_days: monitor.tls_expiry_warning_days,
+        });
+        let new_incident = NewIncident {
+            organization_id: monitor.organization_id,
+            created_by: None,
+            status: IncidentStatus::Ongoing,
+            priority: IncidentPriority::Warning,
+            source: IncidentSource::TlsCertificate { id: monitor.id },
+            cause: Some(incident_cause.clone()),
+            metadata,
+            title: None,
+        };
+
+        debug!(incident = ?new_incident, monitor_id = ?monitor.id, "Creating certificate expiry incident for monitor");
+
+        create_incident(
+            transaction,
+            &self.incident_repository,
+            &self.incident_event_repository,
+            &self.incident_notification_repository,
+            new_incident,
+            Some(NotificationOpts {
+                send_sms: monitor.sms_notification_enabled,
+                send_push_notification: monitor.push_notification_enabled,
+                send_email: monitor.email_notification_enabled,
+                notification_payload: IncidentNotificationPayload {
+                    incident_cause,
+                    incident_http_monitor_url: Some(monitor.url.clone()),
+                },
+            }),
+        )
+        .await
+        .context("Failed to create certificate expiry incident")?;
+
+        Ok(())
+    }
+}
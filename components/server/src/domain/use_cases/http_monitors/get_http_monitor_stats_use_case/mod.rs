@@ -0,0 +1,191 @@
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        http_monitor_ping::{HttpMonitorErrorKindCount, HttpMonitorPingRollup},
+    },
+    ports::http_monitor_repository::HttpMonitorRepository,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The maximum number of buckets that can be requested at once
+const MAXIMUM_STATS_BUCKETS: i64 = 2000;
+
+#[derive(Serialize, Deserialize, TS, Clone, Debug, Default, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct GetHttpMonitorStatsParams {
+    /// Defaults to 24 hours before `to`
+    pub from: Option<DateTime<Utc>>,
+    /// Defaults to now
+    pub to: Option<DateTime<Utc>>,
+    /// Defaults to `hour`
+    pub bucket: Option<HttpMonitorStatsBucketSize>,
+}
+
+#[derive(Serialize, Deserialize, TS, Clone, Copy, Debug, Default, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum HttpMonitorStatsBucketSize {
+    Minute,
+    #[default]
+    Hour,
+    Day,
+}
+
+impl HttpMonitorStatsBucketSize {
+    fn duration(self) -> TimeDelta {
+        match self {
+            Self::Minute => TimeDelta::minutes(1),
+            Self::Hour => TimeDelta::hours(1),
+            Self::Day => TimeDelta::days(1),
+        }
+    }
+}
+
+#[derive(Serialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct HttpMonitorStats {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub bucket_size: HttpMonitorStatsBucketSize,
+    /// The statistics over the whole range
+    pub summary: HttpMonitorStatsBucket,
+    /// One bucket per period, from the oldest to the most recent. Periods without pings are included
+    pub buckets: Vec<HttpMonitorStatsBucket>,
+}
+
+#[derive(Serialize, TS, Debug, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct HttpMonitorStatsBucket {
+    pub start: DateTime<Utc>,
+    pub ping_count: i64,
+    /// The share of pings without error, between 0 and 100. None if there was no ping
+    pub uptime_percent: Option<f64>,
+    /// Response time percentiles, in milliseconds. They are estimated from a histogram of the response times
+    pub p50_ms: Option<i32>,
+    pub p95_ms: Option<i32>,
+    pub p99_ms: Option<i32>,
+    pub error_kinds: Vec<HttpMonitorErrorKindCount>,
+}
+
+impl From<&HttpMonitorPingRollup> for HttpMonitorStatsBucket {
+    fn from(rollup: &HttpMonitorPingRollup) -> Self {
+        Self {
+            start: rollup.period_start,
+            ping_count: rollup.ping_count,
+            uptime_percent: rollup.uptime_percent(),
+            p50_ms: rollup.response_time_percentile(0.5),
+            p95_ms: rollup.response_time_percentile(0.95),
+            p99_ms: rollup.response_time_percentile(0.99),
+            error_kinds: rollup.error_kinds(),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum GetHttpMonitorStatsError {
+    #[error("User has no permission to read this monitor")]
+    Forbidden,
+    #[error("Monitor not found")]
+    NotFound,
+    #[error("Invalid range: {0}")]
+    InvalidRange(String),
+    #[error("Failed to get monitor stats from the database: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Computes the uptime, the response times and the errors of an HTTP monitor, per bucket of time.
+/// Minute buckets are computed from the raw pings, while hour and day buckets are computed from the hourly rollups,
+/// so that long ranges remain cheap to compute
+pub async fn get_http_monitor_stats(
+    auth_context: &AuthContext,
+    http_monitor_repository: &impl HttpMonitorRepository,
+    monitor_id: Uuid,
+    params: GetHttpMonitorStatsParams,
+) -> Result<HttpMonitorStats, GetHttpMonitorStatsError> {
+    if !auth_context.can(Permission::ReadHttpMonitors) {
+        return Err(GetHttpMonitorStatsError::Forbidden);
+    }
+
+    let bucket_size = params.bucket.unwrap_or_default();
+    let to = params.to.unwrap_or_else(Utc::now);
+    let from = params.from.unwrap_or(to - TimeDelta::hours(24));
+    if from >= to {
+        return Err(GetHttpMonitorStatsError::InvalidRange(
+            "from must be before to".to_string(),
+        ));
+    }
+    // buckets are aligned on their size, the first one may start before `from`
+    let first_bucket_start = from
+        .duration_trunc(bucket_size.duration())
+        .map_err(|e| GetHttpMonitorStatsError::InvalidRange(e.to_string()))?;
+    let bucket_count = (to - first_bucket_start).num_seconds() / bucket_size.duration().num_seconds() + 1;
+    if bucket_count > MAXIMUM_STATS_BUCKETS {
+        return Err(GetHttpMonitorStatsError::InvalidRange(format!(
+            "The range cannot contain more than {MAXIMUM_STATS_BUCKETS} buckets, use a larger bucket size"
+        )));
+    }
+
+    let organization_id = auth_context.active_organization_id;
+    let mut tx = http_monitor_repository.begin_transaction().await?;
+    http_monitor_repository
+        .get_http_monitor(&mut tx, organization_id, monitor_id)
+        .await?
+        .ok_or(GetHttpMonitorStatsError::NotFound)?;
+
+    let rollups = match bucket_size {
+        HttpMonitorStatsBucketSize::Minute => {
+            let pings = http_monitor_repository
+                .list_pings(&mut tx, organization_id, monitor_id, first_bucket_start, to)
+                .await?;
+            pings
+                .iter()
+                .map(|ping| {
+                    let mut rollup = HttpMonitorPingRollup::empty(ping.created_at);
+                    rollup.add_ping(ping);
+                    rollup
+                })
+                .collect::<Vec<_>>()
+        }
+        HttpMonitorStatsBucketSize::Hour | HttpMonitorStatsBucketSize::Day => {
+            http_monitor_repository
+                .list_ping_rollups(&mut tx, organization_id, monitor_id, first_bucket_start, to)
+                .await?
+        }
+    };
+    http_monitor_repository.rollback_transaction(tx).await?;
+
+    let mut buckets = (0..bucket_count)
+        .map(|index| first_bucket_start + bucket_size.duration() * index as i32)
+        .take_while(|start| *start < to)
+        .map(HttpMonitorPingRollup::empty)
+        .collect::<Vec<_>>();
+    let mut summary = HttpMonitorPingRollup::empty(from);
+    for rollup in &rollups {
+        let index = (rollup.period_start - first_bucket_start).num_seconds()
+            / bucket_size.duration().num_seconds();
+        if let Some(bucket) = buckets.get_mut(index as usize) {
+            bucket.merge(rollup);
+            summary.merge(rollup);
+        }
+    }
+
+    Ok(HttpMonitorStats {
+        from,
+        to,
+        bucket_size,
+        summary: HttpMonitorStatsBucket::from(&summary),
+        buckets: buckets.iter().map(HttpMonitorStatsBucket::from).collect(),
+    })
+}
@@ -0,0 +1,223 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        authorization::AuthContext,
+        entity_metadata::EntityMetadata,
+        http_monitor::{HttpMonitor, HttpMonitorErrorKind, HttpMonitorStatus, RequestHeaders},
+        http_monitor_ping::{HttpMonitorErrorKindCount, HttpMonitorPing},
+        organization::OrganizationUserRole,
+        tls_certificate::DEFAULT_TLS_EXPIRY_WARNING_DAYS,
+    },
+    infrastructure::mocks::http_monitor_repository_mock::HttpMonitorRepositoryMock,
+};
+
+use super::{
+    get_http_monitor_stats, GetHttpMonitorStatsError, GetHttpMonitorStatsParams,
+    HttpMonitorStatsBucketSize,
+};
+
+fn create_test_monitor(org_id: Uuid) -> HttpMonitor {
+    HttpMonitor {
+        id: Uuid::new_v4(),
+        organization_id: org_id,
+        created_at: Utc::now() - Duration::days(2),
+        url: "https://internal.example.com".to_string(),
+        first_ping_at: None,
+        next_ping_at: Some(Utc::now()),
+        last_ping_at: None,
+        last_status_change_at: Utc::now(),
+        recovery_confirmation_threshold: 3,
+        downtime_confirmation_threshold: 3,
+        interval_seconds: 60,
+        last_http_code: None,
+        status: HttpMonitorStatus::Up,
+        status_counter: 0,
+        error_kind: HttpMonitorErrorKind::None,
+        metadata: EntityMetadata::default(),
+        email_notification_enabled: true,
+        push_notification_enabled: true,
+        sms_notification_enabled: false,
+        archived_at: None,
+        request_headers: RequestHeaders::default(),
+        request_timeout_ms: 2000,
+        assertions: Default::default(),
+        locations: vec![],
+        location_quorum: None,
+        request_method: Default::default(),
+        request_body: None,
+        request_body_content_type: None,
+        authentication: Default::default(),
+        redirect_policy: Default::default(),
+        probe_engine: Default::default(),
+        tls_certificate: Default::default(),
+        tls_expiry_warning_days: DEFAULT_TLS_EXPIRY_WARNING_DAYS,
+        kind_settings: Default::default(),
+    }
+}
+
+fn create_test_ping(
+    monitor: &HttpMonitor,
+    created_at: DateTime<Utc>,
+    error_kind: HttpMonitorErrorKind,
+    response_time_ms: i32,
+) -> HttpMonitorPing {
+    HttpMonitorPing {
+        organization_id: monitor.organization_id,
+        http_monitor_id: monitor.id,
+        created_at,
+        status: HttpMonitorStatus::Up,
+        error_kind,
+        http_code: Some(200),
+        response_time_ms,
+    }
+}
+
+#[tokio::test]
+async fn test_get_http_monitor_stats() -> anyhow::Result<()> {
+    let repository = HttpMonitorRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let monitor = create_test_monitor(org_id);
+    let auth_context =
+        AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Reporter], &[]);
+    let from = Utc.with_ymd_and_hms(2024, 12, 11, 8, 30, 0).unwrap();
+    *repository.pings.lock().await = vec![
+        // before the first bucket
+        create_test_ping(&monitor, from - Duration::hours(1), HttpMonitorErrorKind::None, 10),
+        // in the first bucket, which starts at 08:00
+        create_test_ping(&monitor, from - Duration::minutes(10), HttpMonitorErrorKind::None, 10),
+        create_test_ping(&monitor, from + Duration::minutes(10), HttpMonitorErrorKind::HttpCode, 10),
+        // in the third bucket
+        create_test_ping(&monitor, from + Duration::minutes(100), HttpMonitorErrorKind::None, 10),
+    ];
+    repository.state.lock().await.push(monitor.clone());
+
+    let stats = get_http_monitor_stats(
+        &auth_context,
+        &repository,
+        monitor.id,
+        GetHttpMonitorStatsParams {
+            from: Some(from),
+            to: Some(from + Duration::hours(3)),
+            bucket: Some(HttpMonitorStatsBucketSize::Hour),
+        },
+    )
+    .await?;
+
+    assert_eq!(stats.buckets.len(), 4);
+    assert_eq!(stats.buckets[0].start, from - Duration::minutes(30));
+    assert_eq!(stats.buckets[0].ping_count, 2);
+    assert_eq!(stats.buckets[0].uptime_percent, Some(50.0));
+    assert_eq!(
+        stats.buckets[0].error_kinds,
+        vec![
+            HttpMonitorErrorKindCount {
+                error_kind: HttpMonitorErrorKind::None,
+                count: 1
+            },
+            HttpMonitorErrorKindCount {
+                error_kind: HttpMonitorErrorKind::HttpCode,
+                count: 1
+            },
+        ]
+    );
+    assert_eq!(stats.buckets[1].ping_count, 0);
+    assert_eq!(stats.buckets[1].uptime_percent, None);
+    assert_eq!(stats.buckets[2].ping_count, 1);
+    assert_eq!(stats.summary.ping_count, 3);
+    assert!(stats.summary.p50_ms.is_some_and(|p50| p50 <= 25));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_http_monitor_stats_with_minute_buckets() -> anyhow::Result<()> {
+    let repository = HttpMonitorRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let monitor = create_test_monitor(org_id);
+    let auth_context =
+        AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Reporter], &[]);
+    let from = Utc.with_ymd_and_hms(2024, 12, 11, 8, 0, 0).unwrap();
+    *repository.pings.lock().await = vec![
+        create_test_ping(&monitor, from + Duration::seconds(10), HttpMonitorErrorKind::None, 10),
+        create_test_ping(&monitor, from + Duration::seconds(70), HttpMonitorErrorKind::None, 10),
+        create_test_ping(&monitor, from + Duration::seconds(80), HttpMonitorErrorKind::None, 10),
+    ];
+    repository.state.lock().await.push(monitor.clone());
+
+    let stats = get_http_monitor_stats(
+        &auth_context,
+        &repository,
+        monitor.id,
+        GetHttpMonitorStatsParams {
+            from: Some(from),
+            to: Some(from + Duration::minutes(5)),
+            bucket: Some(HttpMonitorStatsBucketSize::Minute),
+        },
+    )
+    .await?;
+
+    let ping_counts = stats.buckets.iter().map(|b| b.ping_count).collect::<Vec<_>>();
+    assert_eq!(ping_counts, vec![1, 2, 0, 0, 0]);
+    assert_eq!(stats.summary.uptime_percent, Some(100.0));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_http_monitor_stats_invalid_range() {
+    let repository = HttpMonitorRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let monitor = create_test_monitor(org_id);
+    repository.state.lock().await.push(monitor.clone());
+    let auth_context =
+        AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Reporter], &[]);
+    let now = Utc::now();
+
+    let reversed_range = get_http_monitor_stats(
+        &auth_context,
+        &repository,
+        monitor.id,
+        GetHttpMonitorStatsParams {
+            from: Some(now),
+            to: Some(now - Duration::hours(1)),
+            bucket: None,
+        },
+    )
+    .await;
+    assert!(matches!(reversed_range, Err(GetHttpMonitorStatsError::InvalidRange(_))));
+
+    let too_many_buckets = get_http_monitor_stats(
+        &auth_context,
+        &repository,
+        monitor.id,
+        GetHttpMonitorStatsParams {
+            from: Some(now - Duration::days(30)),
+            to: Some(now),
+            bucket: Some(HttpMonitorStatsBucketSize::Minute),
+        },
+    )
+    .await;
+    assert!(matches!(too_many_buckets, Err(GetHttpMonitorStatsError::InvalidRange(_))));
+}
+
+#[tokio::test]
+async fn test_get_http_monitor_stats_of_another_organization() {
+    let repository = HttpMonitorRepositoryMock::new();
+    let monitor = create_test_monitor(Uuid::new_v4());
+    repository.state.lock().await.push(monitor.clone());
+    let auth_context = AuthContext::test_context(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Reporter],
+        &[],
+    );
+
+    let result = get_http_monitor_stats(
+        &auth_context,
+        &repository,
+        monitor.id,
+        GetHttpMonitorStatsParams::default(),
+    )
+    .await;
+    assert!(matches!(result, Err(GetHttpMonitorStatsError::NotFound)));
+}
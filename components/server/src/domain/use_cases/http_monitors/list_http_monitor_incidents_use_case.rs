@@ -47,7 +47,10 @@ pub async fn list_http_monitor_incidents(
             ListIncidentsOpts {
                 include_statuses: &include_statuses,
                 include_priorities: &include_priorities,
-                include_sources: &[IncidentSource::HttpMonitor { id: monitor_id }],
+                include_sources: &[
+                    IncidentSource::HttpMonitor { id: monitor_id },
+                    IncidentSource::TlsCertificate { id: monitor_id },
+                ],
                 limit: items_per_page,
                 offset: items_per_page * (page_number - 1),
                 from_date: params.from_date,
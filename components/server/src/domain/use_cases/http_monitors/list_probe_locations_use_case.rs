@@ -0,0 +1,35 @@
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::authorization::{AuthContext, Permission},
+    ports::http_client::HttpClient,
+};
+
+#[derive(Serialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct ListProbeLocationsResponse {
+    /// Names of the probe locations, the first one being the default location
+    pub locations: Vec<String>,
+}
+
+#[derive(Error, Debug)]
+pub enum ListProbeLocationsError {
+    #[error("Current user doesn't have the privilege to list the probe locations")]
+    Forbidden,
+}
+
+pub async fn list_probe_locations(
+    auth_context: &AuthContext,
+    http_client: &impl HttpClient,
+) -> Result<ListProbeLocationsResponse, ListProbeLocationsError> {
+    if !auth_context.can(Permission::ReadHttpMonitors) {
+        return Err(ListProbeLocationsError::Forbidden);
+    }
+    Ok(ListProbeLocationsResponse {
+        locations: http_client.locations(),
+    })
+}
@@ -7,6 +7,8 @@ mod read_http_monitor_use_case;
 mod toggle_http_monitor_use_case;
 mod get_filterable_http_monitor_metadata_use_case;
 mod archive_monitor_use_case;
+mod get_http_monitor_stats_use_case;
+mod list_probe_locations_use_case;
 pub use get_filterable_http_monitor_metadata_use_case::*;
 pub use update_http_monitor_use_case::*;
 pub use create_http_monitor_use_case::*;
@@ -15,4 +17,6 @@ pub use list_http_monitor_incidents_use_case::*;
 pub use list_http_monitors_use_case::*;
 pub use read_http_monitor_use_case::*;
 pub use toggle_http_monitor_use_case::*;
-pub use archive_monitor_use_case::*;
\ No newline at end of file
+pub use archive_monitor_use_case::*;
+pub use get_http_monitor_stats_use_case::*;
+pub use list_probe_locations_use_case::*;
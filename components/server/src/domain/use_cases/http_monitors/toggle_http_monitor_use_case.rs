@@ -80,11 +80,12 @@ where
                 error_kind: monitor.error_kind,
                 last_http_code: monitor.last_http_code,
                 archived_at: None,
+                tls_certificate: None,
             },
         )
         .await?;
 
-    // Retrieve all ongoing incidents for this monitor
+    // Retrieve all ongoing incidents for this monitor, including the certificate expiry one
     let ongoing_incidents = incident_repository
         .list_incidents(
             &mut tx,
@@ -92,8 +93,11 @@ where
             ListIncidentsOpts {
                 include_statuses: &[IncidentStatus::Ongoing, IncidentStatus::ToBeConfirmed],
                 include_priorities: &IncidentPriority::ALL,
-                include_sources: &[IncidentSource::HttpMonitor { id: monitor.id }],
-                limit: 1,
+                include_sources: &[
+                    IncidentSource::HttpMonitor { id: monitor.id },
+                    IncidentSource::TlsCertificate { id: monitor.id },
+                ],
+                limit: 2,
                 ..Default::default()
             },
         )
@@ -107,6 +111,7 @@ where
             incident_event_repository,
             incident_notification_repository,
             &incident,
+            None,
         )
         .await?;
     }
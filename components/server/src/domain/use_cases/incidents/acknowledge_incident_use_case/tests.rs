@@ -37,6 +37,7 @@ fn create_test_incident(org_id: Uuid) -> Incident {
                 last_ping: HttpMonitorIncidentCausePing {
                     error_kind: HttpMonitorErrorKind::Timeout,
                     http_code: None,
+                    failed_assertion: None,
                 },
                 previous_pings: HashSet::new(),
             },
@@ -46,6 +47,7 @@ fn create_test_incident(org_id: Uuid) -> Incident {
         incident_source_type: IncidentSourceType::HttpMonitor,
         incident_source_id: Uuid::new_v4(),
         acknowledged_by: vec![],
+        title: None,
         metadata: EntityMetadata::default(),
     }
 }
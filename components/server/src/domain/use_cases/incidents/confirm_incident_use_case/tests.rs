@@ -35,6 +35,7 @@ fn create_test_incident(org_id: Uuid, status: IncidentStatus) -> Incident {
             last_ping: HttpMonitorIncidentCausePing {
                 error_kind: HttpMonitorErrorKind::Timeout,
                 http_code: None,
+                failed_assertion: None,
             },
             previous_pings: HashSet::new(),
         })),
@@ -43,6 +44,7 @@ fn create_test_incident(org_id: Uuid, status: IncidentStatus) -> Incident {
         incident_source_type: crate::domain::entities::incident::IncidentSourceType::HttpMonitor,
         incident_source_id: Uuid::new_v4(),
         acknowledged_by: vec![],
+        title: None,
         metadata: EntityMetadata::default(),
     }
 }
@@ -74,6 +76,7 @@ async fn test_confirm_incident_success() -> anyhow::Result<()> {
                 last_ping: HttpMonitorIncidentCausePing {
                     error_kind: HttpMonitorErrorKind::Timeout,
                     http_code: None,
+                    failed_assertion: None,
                 },
                 previous_pings: HashSet::new(),
             }),
@@ -151,6 +154,7 @@ async fn test_confirm_incident_wrong_status() -> anyhow::Result<()> {
                 last_ping: HttpMonitorIncidentCausePing {
                     error_kind: HttpMonitorErrorKind::Timeout,
                     http_code: None,
+                    failed_assertion: None,
                 },
                 previous_pings: HashSet::new(),
             }),
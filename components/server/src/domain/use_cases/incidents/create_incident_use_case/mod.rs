@@ -50,7 +50,7 @@ where
     let event = IncidentEvent {
         incident_id,
         organization_id: new_incident.organization_id,
-        user_id: None,
+        user_id: new_incident.created_by,
         created_at: Utc::now(),
         event_type: IncidentEventType::Creation,
         event_payload: None,
@@ -33,10 +33,12 @@ fn create_test_new_incident(org_id: Uuid) -> NewIncident {
             last_ping: HttpMonitorIncidentCausePing {
                 error_kind: HttpMonitorErrorKind::Timeout,
                 http_code: None,
+                failed_assertion: None,
             },
             previous_pings: HashSet::new(),
         })),
         metadata: EntityMetadata::default(),
+        title: None,
     }
 }
 
@@ -59,6 +61,7 @@ async fn test_create_incident_success() -> anyhow::Result<()> {
                 last_ping: HttpMonitorIncidentCausePing {
                     error_kind: HttpMonitorErrorKind::Timeout,
                     http_code: None,
+                    failed_assertion: None,
                 },
                 previous_pings: HashSet::new(),
             }),
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        entity_metadata::EntityMetadata,
+        incident::{
+            IncidentCause, IncidentPriority, IncidentSource, IncidentStatus, ManualIncidentCause,
+            NewIncident,
+        },
+        incident_notification::IncidentNotificationPayload,
+    },
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository,
+    },
+};
+
+use super::{create_incident, NotificationOpts};
+
+#[cfg(test)]
+mod tests;
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateManualIncidentCommand {
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: IncidentPriority,
+    #[serde(default)]
+    pub metadata: EntityMetadata,
+    #[serde(default = "default_true")]
+    pub send_email: bool,
+    #[serde(default = "default_true")]
+    pub send_push_notification: bool,
+    #[serde(default)]
+    pub send_sms: bool,
+}
+
+#[derive(Debug, Serialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateManualIncidentResponse {
+    pub id: Uuid,
+}
+
+#[derive(Debug, Error)]
+pub enum CreateManualIncidentError {
+    #[error("Current user doesn't have the privilege to create incidents")]
+    Forbidden,
+    #[error("Invalid incident: {0}")]
+    InvalidIncident(String),
+    #[error("Failed to create incident: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Declares an incident that was not detected by a monitor or a task, and notifies the organization like any other incident.
+/// Manual incidents are not suppressed by maintenance windows, as they are declared on purpose
+pub async fn create_manual_incident<IR, IER, INR>(
+    auth_context: &AuthContext,
+    incident_repo: &IR,
+    incident_event_repo: &IER,
+    incident_notification_repo: &INR,
+    command: CreateManualIncidentCommand,
+) -> Result<CreateManualIncidentResponse, CreateManualIncidentError>
+where
+    IR: IncidentRepository,
+    IER: IncidentEventRepository<Transaction = IR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = IR::Transaction>,
+{
+    if !auth_context.can(Permission::EditIncidents) {
+        return Err(CreateManualIncidentError::Forbidden);
+    }
+    let title = command.title.trim().to_string();
+    if title.is_empty() {
+        return Err(CreateManualIncidentError::InvalidIncident(
+            "Incident title cannot be empty".to_string(),
+        ));
+    }
+
+    let cause = IncidentCause::ManualIncidentCause(ManualIncidentCause {
+        declared_by: auth_context.active_user_id,
+        title: title.clone(),
+        description: command.description,
+    });
+    let new_incident = NewIncident {
+        organization_id: auth_context.active_organization_id,
+        created_by: Some(auth_context.active_user_id),
+        status: IncidentStatus::Ongoing,
+        priority: command.priority,
+        source: IncidentSource::Manual { id: Uuid::new_v4() },
+        cause: Some(cause.clone()),
+        metadata: command.metadata,
+        title: Some(title),
+    };
+    let notification = NotificationOpts {
+        send_sms: command.send_sms,
+        send_push_notification: command.send_push_notification,
+        send_email: command.send_email,
+        notification_payload: IncidentNotificationPayload {
+            incident_cause: cause,
+            incident_http_monitor_url: None,
+        },
+    };
+
+    let mut tx = incident_repo.begin_transaction().await?;
+    let id = create_incident(
+        &mut tx,
+        incident_repo,
+        incident_event_repo,
+        incident_notification_repo,
+        new_incident,
+        Some(notification),
+    )
+    .await?;
+    incident_repo.commit_transaction(tx).await?;
+
+    Ok(CreateManualIncidentResponse { id })
+}
@@ -0,0 +1,101 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        authorization::AuthContext,
+        entity_metadata::EntityMetadata,
+        incident::{IncidentCause, IncidentPriority, IncidentSourceType, IncidentStatus},
+        incident_event::IncidentEventType,
+        organization::OrganizationUserRole,
+    },
+    infrastructure::mocks::{
+        incident_event_repository_mock::IncidentEventRepositoryMock,
+        incident_notification_repository_mock::IncidentNotificationRepositoryMock,
+        incident_repository_mock::IncidentRepositoryMock,
+    },
+};
+
+use super::{create_manual_incident, CreateManualIncidentCommand, CreateManualIncidentError};
+
+fn command(title: &str) -> CreateManualIncidentCommand {
+    CreateManualIncidentCommand {
+        title: title.to_string(),
+        description: Some("Customers cannot log in".to_string()),
+        priority: IncidentPriority::Critical,
+        metadata: EntityMetadata::default(),
+        send_email: true,
+        send_push_notification: true,
+        send_sms: false,
+    }
+}
+
+#[tokio::test]
+async fn test_create_manual_incident() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let (org_id, user_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let auth_context = AuthContext::test_context(org_id, user_id, &[OrganizationUserRole::Editor], &[]);
+
+    let response = create_manual_incident(
+        &auth_context,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        command("  Login is broken "),
+    )
+    .await?;
+
+    let incidents = incident_repo.state.lock().await;
+    assert_eq!(incidents.len(), 1);
+    let incident = &incidents[0];
+    assert_eq!(incident.id, response.id);
+    assert_eq!(incident.incident_source_type, IncidentSourceType::Manual);
+    assert_eq!(incident.status, IncidentStatus::Ongoing);
+    assert_eq!(incident.created_by, Some(user_id));
+    assert_eq!(incident.title.as_deref(), Some("Login is broken"));
+    assert!(matches!(
+        incident.cause,
+        Some(IncidentCause::ManualIncidentCause(ref cause)) if cause.declared_by == user_id
+    ));
+
+    let events = incident_event_repo.state.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_type, IncidentEventType::Creation);
+    assert_eq!(events[0].user_id, Some(user_id));
+
+    let notifications = incident_notification_repo.state.lock().await;
+    assert_eq!(notifications.len(), 1);
+    assert!(notifications[0].send_email && !notifications[0].send_sms);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_manual_incident_errors() {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+
+    let reporter = AuthContext::test_context(Uuid::new_v4(), Uuid::new_v4(), &[OrganizationUserRole::Reporter], &[]);
+    let result = create_manual_incident(
+        &reporter,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        command("Login is broken"),
+    )
+    .await;
+    assert!(matches!(result, Err(CreateManualIncidentError::Forbidden)));
+
+    let editor = AuthContext::test_context(Uuid::new_v4(), Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+    let result = create_manual_incident(
+        &editor,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        command(" "),
+    )
+    .await;
+    assert!(matches!(result, Err(CreateManualIncidentError::InvalidIncident(_))));
+    assert!(incident_repo.state.lock().await.is_empty());
+}
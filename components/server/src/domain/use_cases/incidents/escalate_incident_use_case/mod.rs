@@ -0,0 +1,86 @@
+use anyhow::Context;
+use chrono::Utc;
+use tracing::debug;
+
+use crate::domain::{
+    entities::{
+        escalation_policy::EscalationPolicy,
+        incident::IncidentStatus,
+        incident_notification::{IncidentNotification, IncidentNotificationType},
+    },
+    ports::{
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Schedules the next escalation level of an incident, once the notification of the current level has been sent.
+///
+/// Nothing is scheduled if the escalation policy has no further level, if the notification is not about a new incident,
+/// or if the incident has already been acknowledged or resolved.
+/// Returns the escalation level that has been scheduled, if any.
+pub async fn schedule_next_escalation<IR, INR>(
+    transaction: &mut IR::Transaction,
+    incident_repo: &IR,
+    incident_notification_repo: &INR,
+    escalation_policy: &EscalationPolicy,
+    sent_notification: &IncidentNotification,
+) -> anyhow::Result<Option<i16>>
+where
+    IR: IncidentRepository,
+    INR: IncidentNotificationRepository<Transaction = IR::Transaction>,
+{
+    // Resolution notifications are never escalated
+    if sent_notification.notification_type == IncidentNotificationType::IncidentResolution {
+        return Ok(None);
+    }
+
+    let next_escalation_level = sent_notification.escalation_level + 1;
+    let Some(next_level) = escalation_policy.level(next_escalation_level) else {
+        return Ok(None);
+    };
+
+    let incident = incident_repo
+        .get_incident(
+            transaction,
+            sent_notification.organization_id,
+            sent_notification.incident_id,
+        )
+        .await
+        .context("Failed to get incident to escalate")?;
+
+    match incident {
+        Some(incident)
+            if incident.status == IncidentStatus::Ongoing
+                && incident.acknowledged_by.is_empty() =>
+        {
+            debug!(
+                incident_id = ?incident.id,
+                escalation_level = next_escalation_level,
+                "Scheduling incident escalation"
+            );
+
+            incident_notification_repo
+                .upsert_incident_notification(
+                    transaction,
+                    IncidentNotification {
+                        escalation_level: next_escalation_level,
+                        notification_due_at: Utc::now()
+                            + chrono::Duration::seconds(next_level.delay_seconds as i64),
+                        send_sms: next_level.send_sms,
+                        send_push_notification: next_level.send_push_notification,
+                        send_email: next_level.send_email,
+                        ..sent_notification.clone()
+                    },
+                )
+                .await
+                .context("Failed to schedule incident escalation")?;
+
+            Ok(Some(next_escalation_level))
+        }
+        _ => Ok(None),
+    }
+}
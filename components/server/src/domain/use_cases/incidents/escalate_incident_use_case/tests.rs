@@ -0,0 +1,206 @@
+use std::collections::HashSet;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        entity_metadata::EntityMetadata,
+        escalation_policy::{EscalationPolicy, EscalationPolicyLevel},
+        http_monitor::HttpMonitorErrorKind,
+        incident::{
+            HttpMonitorIncidentCause, HttpMonitorIncidentCausePing, Incident, IncidentCause,
+            IncidentPriority, IncidentSourceType, IncidentStatus,
+        },
+        incident_notification::{
+            IncidentNotification, IncidentNotificationPayload, IncidentNotificationType,
+        },
+    },
+    ports::transactional_repository::TransactionalRepository,
+};
+use crate::infrastructure::mocks::{
+    incident_notification_repository_mock::IncidentNotificationRepositoryMock,
+    incident_repository_mock::IncidentRepositoryMock,
+};
+
+use super::schedule_next_escalation;
+
+fn create_test_cause() -> IncidentCause {
+    IncidentCause::HttpMonitorIncidentCause(HttpMonitorIncidentCause {
+        last_ping: HttpMonitorIncidentCausePing {
+            error_kind: HttpMonitorErrorKind::Timeout,
+            http_code: None,
+            failed_assertion: None,
+        },
+        previous_pings: HashSet::new(),
+    })
+}
+
+fn create_test_incident(org_id: Uuid) -> Incident {
+    Incident {
+        id: Uuid::new_v4(),
+        organization_id: org_id,
+        created_at: Utc::now(),
+        created_by: None,
+        resolved_at: None,
+        cause: Some(create_test_cause()),
+        status: IncidentStatus::Ongoing,
+        priority: IncidentPriority::Major,
+        incident_source_type: IncidentSourceType::HttpMonitor,
+        incident_source_id: Uuid::new_v4(),
+        acknowledged_by: vec![],
+        title: None,
+        metadata: EntityMetadata::default(),
+    }
+}
+
+fn create_test_notification(incident: &Incident, escalation_level: i16) -> IncidentNotification {
+    IncidentNotification {
+        organization_id: incident.organization_id,
+        incident_id: incident.id,
+        escalation_level,
+        notification_type: IncidentNotificationType::IncidentCreation,
+        notification_due_at: Utc::now(),
+        notification_payload: IncidentNotificationPayload {
+            incident_cause: create_test_cause(),
+            incident_http_monitor_url: Some("https://example.com".to_string()),
+        },
+        send_sms: false,
+        send_push_notification: true,
+        send_email: true,
+    }
+}
+
+fn create_test_policy(org_id: Uuid) -> EscalationPolicy {
+    EscalationPolicy {
+        levels: vec![
+            EscalationPolicyLevel {
+                delay_seconds: 300,
+                target_user_ids: vec![Uuid::new_v4()],
+                target_roles: vec![],
+                target_on_call_schedule_ids: vec![],
+                send_email: true,
+                send_push_notification: true,
+                send_sms: true,
+            },
+            EscalationPolicyLevel {
+                delay_seconds: 600,
+                target_user_ids: vec![],
+                target_roles: vec![],
+                target_on_call_schedule_ids: vec![],
+                send_email: true,
+                send_push_notification: false,
+                send_sms: false,
+            },
+        ],
+        ..EscalationPolicy::empty(org_id)
+    }
+}
+
+#[tokio::test]
+async fn test_schedule_next_escalation_level() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let mut tx = incident_repo.begin_transaction().await?;
+
+    let org_id = Uuid::new_v4();
+    let incident = create_test_incident(org_id);
+    incident_repo.state.lock().await.push(incident.clone());
+    let policy = create_test_policy(org_id);
+
+    let before = Utc::now();
+    let scheduled = schedule_next_escalation(
+        &mut tx,
+        &incident_repo,
+        &incident_notification_repo,
+        &policy,
+        &create_test_notification(&incident, 0),
+    )
+    .await?;
+    assert_eq!(scheduled, Some(1));
+
+    let state = incident_notification_repo.state.lock().await;
+    assert_eq!(state.len(), 1);
+    let notification = &state[0];
+    assert_eq!(notification.escalation_level, 1);
+    assert_eq!(notification.notification_type, IncidentNotificationType::IncidentCreation);
+    assert!(notification.notification_due_at >= before + chrono::Duration::seconds(300));
+    // Channels come from the escalation level, not from the previous notification
+    assert!(notification.send_sms);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_escalation_after_last_level() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let mut tx = incident_repo.begin_transaction().await?;
+
+    let org_id = Uuid::new_v4();
+    let incident = create_test_incident(org_id);
+    incident_repo.state.lock().await.push(incident.clone());
+
+    let scheduled = schedule_next_escalation(
+        &mut tx,
+        &incident_repo,
+        &incident_notification_repo,
+        &create_test_policy(org_id),
+        &create_test_notification(&incident, 2),
+    )
+    .await?;
+    assert_eq!(scheduled, None);
+
+    let scheduled = schedule_next_escalation(
+        &mut tx,
+        &incident_repo,
+        &incident_notification_repo,
+        &EscalationPolicy::empty(org_id),
+        &create_test_notification(&incident, 0),
+    )
+    .await?;
+    assert_eq!(scheduled, None);
+    assert!(incident_notification_repo.state.lock().await.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_escalation_for_acknowledged_or_resolved_incidents() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let mut tx = incident_repo.begin_transaction().await?;
+
+    let org_id = Uuid::new_v4();
+    let acknowledged_incident = Incident {
+        acknowledged_by: vec![Uuid::new_v4()],
+        title: None,
+        ..create_test_incident(org_id)
+    };
+    let resolved_incident = Incident {
+        status: IncidentStatus::Resolved,
+        resolved_at: Some(Utc::now()),
+        ..create_test_incident(org_id)
+    };
+    incident_repo
+        .state
+        .lock()
+        .await
+        .extend([acknowledged_incident.clone(), resolved_incident.clone()]);
+    let policy = create_test_policy(org_id);
+
+    for incident in [&acknowledged_incident, &resolved_incident] {
+        let scheduled = schedule_next_escalation(
+            &mut tx,
+            &incident_repo,
+            &incident_notification_repo,
+            &policy,
+            &create_test_notification(incident, 0),
+        )
+        .await?;
+        assert_eq!(scheduled, None);
+    }
+    assert!(incident_notification_repo.state.lock().await.is_empty());
+
+    Ok(())
+}
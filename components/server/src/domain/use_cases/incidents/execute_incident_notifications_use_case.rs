@@ -9,26 +9,38 @@ use uuid::Uuid;
 
 use crate::domain::{
     entities::{
-        incident::IncidentCause,
+        escalation_policy::{EscalationPolicy, EscalationPolicyLevel},
+        incident::{IncidentCause, IncidentPriority, TaskIncidentCause, TlsCertificateIncidentCause},
         incident_event::{
             IncidentEvent, IncidentEventPayload, IncidentEventType, NotificationEventPayload,
         },
         incident_notification::IncidentNotification,
-        organization::Organization,
+        notification_preferences::{resolve_notification_channels, NotificationChannels, NotificationPreferences},
+        organization::{Organization, OrganizationRoleSet},
         push_notification::{PushNotification, PushNotificationToken},
+        task::TaskStatus,
+        task_run::TaskRunStatus,
         user::User,
         user_device::UserDevice,
     },
     ports::{
-        incident_event_repository::IncidentEventRepository, incident_notification_repository::IncidentNotificationRepository, mailer::Mailer, organization_repository::OrganizationRepository, push_notification_server::PushNotificationServer, sms_notification_server::{Sms, SmsNotificationServer}, user_devices_repository::UserDevicesRepository
+        escalation_policy_repository::EscalationPolicyRepository, on_call_schedule_repository::OnCallScheduleRepository, incident_event_repository::IncidentEventRepository, incident_notification_repository::IncidentNotificationRepository, incident_repository::IncidentRepository, mailer::Mailer, notification_preferences_repository::NotificationPreferencesRepository, organization_repository::OrganizationRepository, push_notification_server::PushNotificationServer, sms_notification_server::{Sms, SmsNotificationServer}, user_devices_repository::UserDevicesRepository
     },
 };
 
+use crate::domain::use_cases::on_call::find_on_call_user_ids;
+
+use super::schedule_next_escalation;
+
 #[derive(Clone)]
-pub struct ExecuteIncidentNotificationsUseCase<OR, INR, IER, PNS, SNS, UDR, M> {
+pub struct ExecuteIncidentNotificationsUseCase<OR, IR, INR, IER, EPR, OCR, NPR, PNS, SNS, UDR, M> {
     pub organization_repository: OR,
+    pub incident_repository: IR,
     pub incident_notification_repository: INR,
     pub incident_event_repository: IER,
+    pub escalation_policy_repository: EPR,
+    pub on_call_schedule_repository: OCR,
+    pub notification_preferences_repository: NPR,
     pub push_notificaton_server: PNS,
     pub sms_notificaton_server: SNS,
     pub mailer: M,
@@ -36,11 +48,15 @@ pub struct ExecuteIncidentNotificationsUseCase<OR, INR, IER, PNS, SNS, UDR, M> {
     pub select_limit: u32,
 }
 
-impl<OR, INR, IER, PNS, SNS, UDR, M> ExecuteIncidentNotificationsUseCase<OR, INR, IER, PNS, SNS, UDR, M>
+impl<OR, IR, INR, IER, EPR, OCR, NPR, PNS, SNS, UDR, M> ExecuteIncidentNotificationsUseCase<OR, IR, INR, IER, EPR, OCR, NPR, PNS, SNS, UDR, M>
 where
     OR: OrganizationRepository,
+    IR: IncidentRepository<Transaction = INR::Transaction>,
     INR: IncidentNotificationRepository,
     IER: IncidentEventRepository<Transaction = INR::Transaction>,
+    EPR: EscalationPolicyRepository<Transaction = INR::Transaction>,
+    OCR: OnCallScheduleRepository<Transaction = INR::Transaction>,
+    NPR: NotificationPreferencesRepository<Transaction = INR::Transaction>,
     PNS: PushNotificationServer,
     SNS: SmsNotificationServer,
     UDR: UserDevicesRepository,
@@ -97,6 +113,9 @@ where
   {
         let mut user_devices_cache: UserDevicesByOrgCache = UserDevicesByOrgCache::new();
         let mut org_cache: OrgCache = OrgCache::new();
+        let mut user_roles_cache: UserRolesCache = UserRolesCache::new();
+        let mut escalation_policy_cache: EscalationPolicyCache = EscalationPolicyCache::new();
+        let mut notification_preferences_cache: NotificationPreferencesCache = NotificationPreferencesCache::new();
 
         let mut tx = self.incident_notification_repository.begin_transaction().await?;
         let incident_notifications = self
@@ -130,10 +149,47 @@ where
                 )),
             };
 
+            let escalation_policy = self
+                .fetch_escalation_policy(&mut tx, notification.organization_id, &mut escalation_policy_cache)
+                .await?;
+
+            let escalation_level = escalation_policy.level(notification.escalation_level);
+            let on_call_schedule_ids = match escalation_level {
+                Some(level) => level.target_on_call_schedule_ids.clone(),
+                None if notification.escalation_level == 0 => {
+                    escalation_policy.on_call_schedule_id.into_iter().collect()
+                }
+                None => vec![],
+            };
+            let on_call_user_ids = find_on_call_user_ids(
+                &mut tx,
+                &self.on_call_schedule_repository,
+                notification.organization_id,
+                &on_call_schedule_ids,
+                Utc::now(),
+            )
+            .await?;
+
+            // Incidents that cannot be found anymore are notified as major incidents
+            let priority = self
+                .incident_repository
+                .get_incident(&mut tx, notification.organization_id, notification.incident_id)
+                .await?
+                .map(|incident| incident.priority)
+                .unwrap_or(IncidentPriority::Major);
+            let notification_preferences = self
+                .fetch_notification_preferences(&mut tx, notification.organization_id, &mut notification_preferences_cache)
+                .await?;
+
             self.send_notification(
-                notification,
+                &notification,
+                escalation_level,
+                &on_call_user_ids,
+                priority,
+                &notification_preferences,
                 &mut user_devices_cache,
                 &mut org_cache,
+                &mut user_roles_cache,
             )
             .await?;
 
@@ -142,6 +198,16 @@ where
                     .create_incident_event(&mut tx, event)
                     .await?;
             }
+
+            // If nobody acknowledges the incident in time, the next escalation level will be notified
+            schedule_next_escalation(
+                &mut tx,
+                &self.incident_repository,
+                &self.incident_notification_repository,
+                &escalation_policy,
+                &notification,
+            )
+            .await?;
         }
 
         // Commit the transaction.
@@ -153,23 +219,66 @@ where
         Ok(incident_notifications_len)
     }
 
-    /// Sends an event notification, if any notification channel is enabled
+    /// Sends an event notification, if any notification channel is enabled.
+    /// Escalated notifications are only sent to the targets of their escalation level. Other notifications
+    /// (and escalated notifications whose level has been removed from the policy since) are sent to the users on call,
+    /// or to every member of the organization if nobody is on call.
+    /// Each recipient is then notified on the channels enabled in their notification preferences,
+    /// or their delegate is notified instead if they are on vacation.
+    #[allow(clippy::too_many_arguments)]
     async fn send_notification(
-        &self,        
-        notification: IncidentNotification,
+        &self,
+        notification: &IncidentNotification,
+        escalation_level: Option<&EscalationPolicyLevel>,
+        on_call_user_ids: &[Uuid],
+        priority: IncidentPriority,
+        notification_preferences: &[NotificationPreferences],
         user_devices_cache: &mut UserDevicesByOrgCache,
         org_cache: &mut OrgCache,
+        user_roles_cache: &mut UserRolesCache,
     ) -> anyhow::Result<()> {
         let org_id = notification.organization_id;
         let (org, org_users) =
             self.fetch_organization_and_users(org_id, org_cache).await?;
+        let targets = match escalation_level {
+            Some(level) => {
+                self.filter_escalation_level_targets(org_id, org_users.clone(), level, on_call_user_ids, user_roles_cache)
+                    .await?
+            }
+            None if !on_call_user_ids.is_empty() => org_users
+                .iter()
+                .filter(|user| on_call_user_ids.contains(&user.id))
+                .cloned()
+                .collect(),
+            None => org_users.clone(),
+        };
+        let target_ids = targets.iter().map(|user| user.id).collect::<Vec<_>>();
+
+        // Delegates are only notified if they are members of the organization
+        let recipients: Vec<(User, NotificationChannels)> =
+            resolve_notification_channels(&target_ids, notification_preferences, priority, Utc::now())
+                .into_iter()
+                .filter_map(|(user_id, channels)| {
+                    org_users
+                        .iter()
+                        .find(|user| user.id == user_id)
+                        .map(|user| (user.clone(), channels))
+                })
+                .collect();
+        let recipients_for = |channel: fn(&NotificationChannels) -> bool| {
+            recipients
+                .iter()
+                .filter(|(_, channels)| channel(channels))
+                .map(|(user, _)| user.clone())
+                .collect::<Vec<_>>()
+        };
 
         // Send e-mails, if e-email notifications are enabled
         if notification.send_email {
-            let messages = org_users
+            let messages = recipients_for(|channels| channels.email)
             .iter()
             .filter_map(
-                |user| match Self::build_email_message(&notification, user, &org) {
+                |user| match Self::build_email_message(notification, user, &org) {
                     Ok(message) => Some(message),
                     Err(e) => {
                         warn!(error = ?e, user = ?user, "Failed to build e-mail message for user");
@@ -183,11 +292,11 @@ where
 
         // Send SMS, if SMS notifications are enabled
         if notification.send_sms {
-            let messages = org_users
-            .into_iter()
+            let messages = recipients_for(|channels| channels.sms)
+            .iter()
             .filter(|user| user.phone_number.is_some() && user.phone_number_verified)
             .filter_map(
-                |user| match Self::build_sms_message(&notification, &user, &org) {
+                |user| match Self::build_sms_message(notification, user, &org) {
                     Ok(message) => Some(message),
                     Err(e) => {
                         warn!(error = ?e, user = ?user, "Failed to build SMS message for user");
@@ -204,10 +313,11 @@ where
             let devices_tokens = self.fetch_organization_devices_token(
                 user_devices_cache,
                 org_id,
+                &recipients_for(|channels| channels.push_notification),
             )
             .await?;
 
-            match Self::build_push_notification(&notification) {
+            match Self::build_push_notification(notification) {
                 Ok(push_notification) => {
                     self.push_notificaton_server
                         .send(&devices_tokens, &push_notification)
@@ -246,6 +356,36 @@ where
                     body: t!("newHttpMonitorIncidentPushNotificationBody", url = url).to_string(),
                 })
             }
+            IncidentCause::TaskIncidentCause(cause) => {
+                let reason = task_incident_reason(cause);
+                Ok(PushNotification {
+                    title: t!("newTaskIncidentPushNotificationTitle", task = cause.task_name).to_string(),
+                    body: t!("newTaskIncidentPushNotificationBody", task = cause.task_name, reason = reason).to_string(),
+                })
+            }
+            IncidentCause::ManualIncidentCause(cause) => Ok(PushNotification {
+                title: t!("newManualIncidentPushNotificationTitle", title = cause.title).to_string(),
+                body: t!("newManualIncidentPushNotificationBody", title = cause.title).to_string(),
+            }),
+            IncidentCause::TlsCertificateIncidentCause(cause) => {
+                let url = notification.notification_payload.incident_http_monitor_url.as_ref().context("Cannot build push notification, cause is TlsCertificateIncidentCause but HTTP monitor URL is not set")?;
+                let expires_on = tls_certificate_expiry_date(cause);
+                Ok(PushNotification {
+                    title: t!("newTlsCertificateIncidentPushNotificationTitle", url = url).to_string(),
+                    body: t!("newTlsCertificateIncidentPushNotificationBody", url = url, expiresOn = expires_on).to_string(),
+                })
+            }
+            cause @ (IncidentCause::TcpMonitorIncidentCause(_) | IncidentCause::DnsMonitorIncidentCause(_) | IncidentCause::GrpcHealthMonitorIncidentCause(_)) => {
+                let target = notification.notification_payload.incident_http_monitor_url.as_ref().context("Cannot build push notification, cause is a monitor incident cause but monitor URL is not set")?;
+                Ok(PushNotification {
+                    title: t!("newMonitorIncidentPushNotificationTitle", target = target).to_string(),
+                    body: t!("newMonitorIncidentPushNotificationBody", target = target, kind = monitor_kind_label(cause)).to_string(),
+                })
+            }
+            IncidentCause::ExternalAlertIncidentCause(cause) => Ok(PushNotification {
+                title: t!("newExternalAlertIncidentPushNotificationTitle", title = cause.title).to_string(),
+                body: t!("newExternalAlertIncidentPushNotificationBody", title = cause.title, integration = cause.integration_name).to_string(),
+            }),
         }
     }
 
@@ -276,6 +416,43 @@ where
                 subject = t!("newHttpMonitorIncidentEmailSubject", url = url).to_string();
                 body = t!("newHttpMonitorIncidentEmailBody", url = url, userName = user.first_name, org = user_org.name).to_string();
             }
+            IncidentCause::TaskIncidentCause(cause) => {
+                let reason = task_incident_reason(cause);
+                let mut details = match &cause.task_run_error_message {
+                    Some(error_message) => t!("taskIncidentErrorMessageDetails", errorMessage = error_message).to_string(),
+                    None => String::new(),
+                };
+                if !cause.task_run_output_tail.is_empty() {
+                    details.push_str(&t!("taskIncidentOutputTailDetails", output = cause.task_run_output_tail.join("\n")));
+                }
+                subject = t!("newTaskIncidentEmailSubject", task = cause.task_name).to_string();
+                body = t!("newTaskIncidentEmailBody", task = cause.task_name, reason = reason, details = details, userName = user.first_name, org = user_org.name).to_string();
+            }
+            IncidentCause::ManualIncidentCause(cause) => {
+                let details = cause.description.clone().unwrap_or_default();
+                subject = t!("newManualIncidentEmailSubject", title = cause.title).to_string();
+                body = t!("newManualIncidentEmailBody", title = cause.title, details = details, userName = user.first_name, org = user_org.name).to_string();
+            }
+            IncidentCause::TlsCertificateIncidentCause(cause) => {
+                let url = notification.notification_payload.incident_http_monitor_url.as_ref().context("Cannot build e-mail message, cause is TlsCertificateIncidentCause but HTTP monitor URL is not set")?;
+                let expires_on = tls_certificate_expiry_date(cause);
+                subject = t!("newTlsCertificateIncidentEmailSubject", url = url).to_string();
+                body = t!("newTlsCertificateIncidentEmailBody", url = url, expiresOn = expires_on, issuer = cause.certificate.issuer, userName = user.first_name, org = user_org.name).to_string();
+            }
+            cause @ (IncidentCause::TcpMonitorIncidentCause(_) | IncidentCause::DnsMonitorIncidentCause(_) | IncidentCause::GrpcHealthMonitorIncidentCause(_)) => {
+                let target = notification.notification_payload.incident_http_monitor_url.as_ref().context("Cannot build e-mail message, cause is a monitor incident cause but monitor URL is not set")?;
+                subject = t!("newMonitorIncidentEmailSubject", target = target).to_string();
+                body = t!("newMonitorIncidentEmailBody", target = target, kind = monitor_kind_label(cause), userName = user.first_name, org = user_org.name).to_string();
+            }
+            IncidentCause::ExternalAlertIncidentCause(cause) => {
+                let details = [cause.description.as_deref(), cause.source_url.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                subject = t!("newExternalAlertIncidentEmailSubject", title = cause.title).to_string();
+                body = t!("newExternalAlertIncidentEmailBody", title = cause.title, integration = cause.integration_name, details = details, userName = user.first_name, org = user_org.name).to_string();
+            }
         }
 
         M::builder()
@@ -298,6 +475,35 @@ where
                     message: t!("newHttpMonitorIncidentPushNotificationBody", url = url).to_string(),
                 })
             }
+            IncidentCause::TaskIncidentCause(cause) => {
+                let reason = task_incident_reason(cause);
+                Ok(Sms {
+                    phone_number: user.phone_number.clone().context("Cannot build SMS message, user has no phone number")?,
+                    message: t!("newTaskIncidentPushNotificationBody", task = cause.task_name, reason = reason).to_string(),
+                })
+            }
+            IncidentCause::ManualIncidentCause(cause) => Ok(Sms {
+                phone_number: user.phone_number.clone().context("Cannot build SMS message, user has no phone number")?,
+                message: t!("newManualIncidentPushNotificationBody", title = cause.title).to_string(),
+            }),
+            IncidentCause::TlsCertificateIncidentCause(cause) => {
+                let url = notification.notification_payload.incident_http_monitor_url.as_ref().context("Cannot build SMS message, cause is TlsCertificateIncidentCause but HTTP monitor URL is not set")?;
+                Ok(Sms {
+                    phone_number: user.phone_number.clone().context("Cannot build SMS message, user has no phone number")?,
+                    message: t!("newTlsCertificateIncidentPushNotificationBody", url = url, expiresOn = tls_certificate_expiry_date(cause)).to_string(),
+                })
+            }
+            cause @ (IncidentCause::TcpMonitorIncidentCause(_) | IncidentCause::DnsMonitorIncidentCause(_) | IncidentCause::GrpcHealthMonitorIncidentCause(_)) => {
+                let target = notification.notification_payload.incident_http_monitor_url.as_ref().context("Cannot build SMS message, cause is a monitor incident cause but monitor URL is not set")?;
+                Ok(Sms {
+                    phone_number: user.phone_number.clone().context("Cannot build SMS message, user has no phone number")?,
+                    message: t!("newMonitorIncidentPushNotificationBody", target = target, kind = monitor_kind_label(cause)).to_string(),
+                })
+            }
+            IncidentCause::ExternalAlertIncidentCause(cause) => Ok(Sms {
+                phone_number: user.phone_number.clone().context("Cannot build SMS message, user has no phone number")?,
+                message: t!("newExternalAlertIncidentPushNotificationBody", title = cause.title, integration = cause.integration_name).to_string(),
+            }),
         }
     }
 
@@ -350,15 +556,15 @@ where
         Ok(result)
     }
 
-    /// Fetches push notification tokens for devices in an organization.
+    /// Fetches push notification tokens for the devices of some users in an organization.
     ///
     /// Uses a cache to improve performance and falls back to the repository if needed.
     ///
     /// # Arguments
     ///
-    /// * `user_devices_repository` - The repository for user devices
     /// * `user_devices_cache` - A mutable reference to the user devices cache
     /// * `org_id` - The ID of the organization
+    /// * `recipients` - The users whose devices should be notified
     ///
     /// # Returns
     ///
@@ -367,6 +573,7 @@ where
         &self,
         user_devices_cache: &mut UserDevicesByOrgCache,
         org_id: Uuid,
+        recipients: &[User],
     ) -> anyhow::Result<Vec<PushNotificationToken>> {
         let org_user_devices = match user_devices_cache.get(&org_id) {
             Some(devices) => devices,
@@ -379,10 +586,116 @@ where
         };
         let devices_tokens = org_user_devices
             .iter()
+            .filter(|device| recipients.iter().any(|user| user.id == device.user_id))
             .filter_map(|device| device.push_notification_token.0.clone())
             .collect::<Vec<_>>();
         Ok(devices_tokens)
     }
+
+    /// Fetches the escalation policy of an organization, using a cache if available.
+    /// Organizations without an escalation policy get an empty one, so their incidents are never escalated.
+    async fn fetch_escalation_policy(
+        &self,
+        tx: &mut INR::Transaction,
+        org_id: Uuid,
+        cache: &mut EscalationPolicyCache,
+    ) -> anyhow::Result<EscalationPolicy> {
+        if let Some(policy) = cache.get(&org_id) {
+            return Ok(policy.clone());
+        }
+        let policy = self
+            .escalation_policy_repository
+            .get_escalation_policy(tx, org_id)
+            .await
+            .with_context(|| format!("Failed to fetch escalation policy of organization with id: {}", org_id))?
+            .unwrap_or_else(|| EscalationPolicy::empty(org_id));
+        cache.insert(org_id, policy.clone());
+        Ok(policy)
+    }
+
+    /// Fetches the notification preferences of the members of an organization, using a cache if available.
+    async fn fetch_notification_preferences(
+        &self,
+        tx: &mut INR::Transaction,
+        org_id: Uuid,
+        cache: &mut NotificationPreferencesCache,
+    ) -> anyhow::Result<Vec<NotificationPreferences>> {
+        if let Some(preferences) = cache.get(&org_id) {
+            return Ok(preferences.clone());
+        }
+        let preferences = self
+            .notification_preferences_repository
+            .list_notification_preferences(tx, org_id)
+            .await
+            .with_context(|| format!("Failed to fetch notification preferences of organization with id: {}", org_id))?;
+        cache.insert(org_id, preferences.clone());
+        Ok(preferences)
+    }
+
+    /// Keeps only the users targeted by an escalation level.
+    /// The roles of the users are only fetched if the level targets roles.
+    async fn filter_escalation_level_targets(
+        &self,
+        org_id: Uuid,
+        users: Vec<User>,
+        level: &EscalationPolicyLevel,
+        on_call_user_ids: &[Uuid],
+        user_roles_cache: &mut UserRolesCache,
+    ) -> anyhow::Result<Vec<User>> {
+        let mut targets = Vec::new();
+        for user in users {
+            let roles = if level.target_roles.is_empty() {
+                OrganizationRoleSet::from_roles(vec![])
+            } else {
+                match user_roles_cache.get(&(org_id, user.id)) {
+                    Some(roles) => roles.clone(),
+                    None => {
+                        let roles = OrganizationRoleSet::from_roles(
+                            self.organization_repository
+                                .list_organization_roles_for_user(org_id, user.id)
+                                .await
+                                .with_context(|| format!("Failed to fetch roles of user with id: {}", user.id))?,
+                        );
+                        user_roles_cache.insert((org_id, user.id), roles.clone());
+                        roles
+                    }
+                }
+            };
+            if level.targets(&user, &roles, on_call_user_ids) {
+                targets.push(user);
+            }
+        }
+        Ok(targets)
+    }
 }
+
+/// Describes why a task incident was opened, e.g. "has failed with exit code 1"
+fn task_incident_reason(cause: &TaskIncidentCause) -> String {
+    match (cause.task_status, cause.task_run_status, cause.task_run_exit_code) {
+        (TaskStatus::Absent, _, _) => t!("taskIncidentAbsentReason").to_string(),
+        (_, Some(TaskRunStatus::Dead), _) => t!("taskIncidentDeadReason").to_string(),
+        (_, Some(TaskRunStatus::TimedOut), _) => t!("taskIncidentTimedOutReason").to_string(),
+        (_, _, Some(exit_code)) => t!("taskIncidentFailedWithExitCodeReason", exitCode = exit_code).to_string(),
+        _ => t!("taskIncidentFailedReason").to_string(),
+    }
+}
+
+fn tls_certificate_expiry_date(cause: &TlsCertificateIncidentCause) -> String {
+    cause.certificate.not_after.format("%Y-%m-%d").to_string()
+}
+
+/// The name of the kind of monitor an incident was opened for, as shown in notifications
+fn monitor_kind_label(cause: &IncidentCause) -> &'static str {
+    match cause {
+        IncidentCause::TcpMonitorIncidentCause(_) => "TCP",
+        IncidentCause::DnsMonitorIncidentCause(_) => "DNS",
+        IncidentCause::GrpcHealthMonitorIncidentCause(_) => "gRPC health",
+        _ => "HTTP",
+    }
+}
+
 type UserDevicesByOrgCache = HashMap<Uuid, Vec<UserDevice>>;
 type OrgCache = HashMap<Uuid, (Organization, Vec<User>)>;
+type UserRolesCache = HashMap<(Uuid, Uuid), OrganizationRoleSet>;
+type EscalationPolicyCache = HashMap<Uuid, EscalationPolicy>;
+type NotificationPreferencesCache = HashMap<Uuid, Vec<NotificationPreferences>>;
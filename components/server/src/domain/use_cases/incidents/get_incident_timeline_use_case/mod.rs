@@ -0,0 +1,191 @@
+use chrono::Utc;
+use futures::{stream::FuturesOrdered, StreamExt};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        incident::Incident,
+        incident_event::{
+            IncidentEvent, IncidentEventPayload, IncidentEventType, MaintenanceWindowEventPayload,
+        },
+    },
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_repository::IncidentRepository,
+        maintenance_window_repository::MaintenanceWindowRepository,
+        user_repository::UserRepository,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Serialize, Deserialize, TS, Clone, Debug, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct GetIncidentTimelineParams {
+    pub page_number: Option<u32>,
+    pub items_per_page: Option<u32>,
+}
+
+#[derive(Serialize, TS, Clone, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct GetIncidentTimelineResponse {
+    pub items: Vec<TimelineItem>,
+}
+
+#[derive(Serialize, TS, Clone, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TimelineItem {
+    pub event: IncidentEvent,
+    pub user: Option<TimelineItemUser>,
+}
+
+#[derive(Serialize, TS, Clone, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TimelineItemUser {
+    pub id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+}
+
+#[derive(Error, Debug)]
+pub enum GetIncidentTimelineError {
+    #[error("Current user doesn't have the privilege the see incidents events")]
+    Forbidden,
+    #[error("Failed to get incidents from the database: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Gets a page of the timeline of an incident.
+/// The maintenance windows that were ongoing while the incident was open are added to the timeline as events
+pub async fn get_incident_timeline<IR, IER, MWR, UR>(
+    auth_context: &AuthContext,
+    incident_repository: &IR,
+    incident_event_repository: &IER,
+    maintenance_window_repository: &MWR,
+    user_repository: &UR,
+    incident_id: Uuid,
+    params: GetIncidentTimelineParams,
+) -> anyhow::Result<GetIncidentTimelineResponse, GetIncidentTimelineError>
+where
+    IR: IncidentRepository,
+    IER: IncidentEventRepository,
+    MWR: MaintenanceWindowRepository<Transaction = IR::Transaction>,
+    UR: UserRepository,
+{
+    if !auth_context.can(Permission::ReadIncidents) {
+        return Err(GetIncidentTimelineError::Forbidden);
+    }
+    let items_per_page = params.items_per_page.unwrap_or(10).min(50);
+    let page_number = params.page_number.unwrap_or(1);
+
+    // one more event is fetched to know where the next page starts
+    let mut events = incident_event_repository
+        .get_incident_timeline(
+            auth_context.active_organization_id,
+            incident_id,
+            items_per_page + 1,
+            (page_number - 1) * items_per_page,
+        )
+        .await?;
+    let next_page_start = (events.len() > items_per_page as usize)
+        .then(|| events.pop())
+        .flatten()
+        .map(|event| event.created_at);
+    let page_start = match page_number {
+        1 => None,
+        _ => events.first().map(|event| event.created_at),
+    };
+
+    let mut tx = incident_repository.begin_transaction().await?;
+    let incident = incident_repository
+        .get_incident(&mut tx, auth_context.active_organization_id, incident_id)
+        .await?;
+    // pages after the last event have no maintenance events either
+    if let Some(incident) = incident.filter(|_| page_number == 1 || !events.is_empty()) {
+        // each maintenance event belongs to the page of the events it is sorted between
+        let maintenance_events = list_maintenance_window_events(
+            &mut tx,
+            maintenance_window_repository,
+            &incident,
+        )
+        .await?
+        .into_iter()
+        .filter(|event| page_start.is_none_or(|start| event.created_at >= start))
+        .filter(|event| next_page_start.is_none_or(|end| event.created_at < end));
+        events.extend(maintenance_events);
+        events.sort_by_key(|event| event.created_at);
+    }
+    incident_repository.rollback_transaction(tx).await?;
+
+    let items = events
+        .into_iter()
+        .map(|event| async move {
+            if let Some(user_id) = event.user_id {
+                let user = user_repository
+                    .get_user(user_id, true)
+                    .await
+                    .ok()
+                    .flatten()
+                    .map(|user| TimelineItemUser {
+                        id: user.id,
+                        first_name: user.first_name,
+                        last_name: user.last_name,
+                    });
+                TimelineItem { event, user }
+            } else {
+                TimelineItem { event, user: None }
+            }
+        })
+        .collect::<FuturesOrdered<_>>()
+        .collect()
+        .await;
+
+    Ok(GetIncidentTimelineResponse { items })
+}
+
+/// Builds an event for every occurrence of a maintenance window that overlaps the incident
+async fn list_maintenance_window_events<MWR: MaintenanceWindowRepository>(
+    transaction: &mut MWR::Transaction,
+    maintenance_window_repository: &MWR,
+    incident: &Incident,
+) -> anyhow::Result<Vec<IncidentEvent>> {
+    let incident_end = incident.resolved_at.unwrap_or_else(Utc::now);
+    let events = maintenance_window_repository
+        .list_maintenance_windows_ending_after(transaction, incident.organization_id, incident.created_at)
+        .await?
+        .into_iter()
+        .filter(|window| window.covers_incident(incident))
+        .flat_map(|window| {
+            window
+                .occurrences_between(incident.created_at, incident_end)
+                .into_iter()
+                .map(move |(starts_at, ends_at)| IncidentEvent {
+                    organization_id: incident.organization_id,
+                    incident_id: incident.id,
+                    user_id: None,
+                    created_at: starts_at.max(incident.created_at),
+                    event_type: IncidentEventType::MaintenanceWindow,
+                    event_payload: Some(IncidentEventPayload::MaintenanceWindow(
+                        MaintenanceWindowEventPayload {
+                            maintenance_window_id: window.id,
+                            title: window.title.clone(),
+                            starts_at,
+                            ends_at,
+                        },
+                    )),
+                })
+        })
+        .collect();
+
+    Ok(events)
+}
@@ -0,0 +1,111 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            entity_metadata::EntityMetadata,
+            incident::{Incident, IncidentPriority, IncidentSourceType, IncidentStatus},
+            incident_event::{IncidentEventPayload, IncidentEventType},
+            maintenance_window::{MaintenanceWindow, MaintenanceWindowScope},
+            task::TaskId,
+        },
+        ports::transactional_repository::TransactionalRepository,
+    },
+    infrastructure::mocks::maintenance_window_repository_mock::MaintenanceWindowRepositoryMock,
+};
+
+use super::list_maintenance_window_events;
+
+fn create_test_window(
+    org_id: Uuid,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    scope: MaintenanceWindowScope,
+) -> MaintenanceWindow {
+    MaintenanceWindow {
+        organization_id: org_id,
+        id: Uuid::new_v4(),
+        title: "Database upgrade".to_string(),
+        description: None,
+        starts_at,
+        ends_at: Some(ends_at),
+        cron_schedule: None,
+        duration_seconds: None,
+        scope,
+        created_at: starts_at,
+        updated_at: starts_at,
+    }
+}
+
+#[tokio::test]
+async fn test_list_maintenance_window_events() -> anyhow::Result<()> {
+    let repository = MaintenanceWindowRepositoryMock::new();
+    let mut tx = repository.begin_transaction().await?;
+    let org_id = Uuid::new_v4();
+    let created_at = Utc.with_ymd_and_hms(2024, 12, 12, 9, 0, 0).unwrap();
+    let mut metadata = EntityMetadata::default();
+    metadata
+        .records
+        .insert("task_id".to_string(), "nightly-backup".to_string());
+    let incident = Incident {
+        organization_id: org_id,
+        id: Uuid::new_v4(),
+        created_at,
+        created_by: None,
+        resolved_at: Some(created_at + Duration::hours(2)),
+        cause: None,
+        status: IncidentStatus::Resolved,
+        priority: IncidentPriority::Major,
+        incident_source_type: IncidentSourceType::Task,
+        incident_source_id: Uuid::new_v4(),
+        acknowledged_by: vec![],
+        title: None,
+        metadata,
+    };
+    let task_scope = MaintenanceWindowScope {
+        task_ids: vec![TaskId::new("nightly-backup".to_string()).unwrap()],
+        ..Default::default()
+    };
+
+    let overlapping_window = create_test_window(
+        org_id,
+        created_at - Duration::minutes(30),
+        created_at + Duration::minutes(30),
+        task_scope.clone(),
+    );
+    {
+        let mut state = repository.state.lock().await;
+        state.push(overlapping_window.clone());
+        // after the resolution of the incident
+        state.push(create_test_window(
+            org_id,
+            created_at + Duration::hours(3),
+            created_at + Duration::hours(4),
+            task_scope,
+        ));
+        // another task
+        state.push(create_test_window(
+            org_id,
+            created_at,
+            created_at + Duration::hours(1),
+            MaintenanceWindowScope {
+                task_ids: vec![TaskId::new("hourly-sync".to_string()).unwrap()],
+                ..Default::default()
+            },
+        ));
+    }
+
+    let events = list_maintenance_window_events(&mut tx, &repository, &incident).await?;
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_type, IncidentEventType::MaintenanceWindow);
+    // the event is sorted after the creation of the incident
+    assert_eq!(events[0].created_at, created_at);
+    let Some(IncidentEventPayload::MaintenanceWindow(payload)) = &events[0].event_payload else {
+        panic!("Event payload should be MaintenanceWindow");
+    };
+    assert_eq!(payload.maintenance_window_id, overlapping_window.id);
+    assert_eq!(payload.starts_at, created_at - Duration::minutes(30));
+    Ok(())
+}
@@ -8,6 +8,10 @@ mod resolve_incident_use_case;
 mod acknowledge_incident_use_case;
 mod confirm_incident_use_case;
 mod get_filterable_incident_metadata_use_case;
+mod escalate_incident_use_case;
+mod create_manual_incident_use_case;
+mod update_incident_use_case;
+mod resolve_incident_manually_use_case;
 
 pub use list_incidents_use_case::*;
 pub use execute_incident_notifications_use_case::*;
@@ -18,4 +22,8 @@ pub use create_incident_use_case::*;
 pub use resolve_incident_use_case::*;
 pub use acknowledge_incident_use_case::*;
 pub use confirm_incident_use_case::*;
-pub use get_filterable_incident_metadata_use_case::*;
\ No newline at end of file
+pub use get_filterable_incident_metadata_use_case::*;
+pub use escalate_incident_use_case::*;
+pub use create_manual_incident_use_case::*;
+pub use update_incident_use_case::*;
+pub use resolve_incident_manually_use_case::*;
\ No newline at end of file
@@ -0,0 +1,71 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        incident::IncidentStatus,
+    },
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository,
+    },
+};
+
+use super::resolve_incident;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Error)]
+pub enum ResolveIncidentManuallyError {
+    #[error("Incident not found")]
+    IncidentNotFound,
+    #[error("Incident is already resolved")]
+    IncidentAlreadyResolved,
+    #[error("Current user doesn't have the privilege to resolve this incident")]
+    Forbidden,
+    #[error("Failed to resolve incident: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Resolves an incident on behalf of the current user, and cancels its pending notifications.
+/// Incidents opened by a monitor or a task are opened again if their source is still failing
+pub async fn resolve_incident_manually<IR, IER, INR>(
+    auth_context: &AuthContext,
+    incident_repo: &IR,
+    incident_event_repo: &IER,
+    incident_notification_repo: &INR,
+    incident_id: Uuid,
+) -> Result<(), ResolveIncidentManuallyError>
+where
+    IR: IncidentRepository,
+    IER: IncidentEventRepository<Transaction = IR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = IR::Transaction>,
+{
+    if !auth_context.can(Permission::EditIncidents) {
+        return Err(ResolveIncidentManuallyError::Forbidden);
+    }
+    let mut tx = incident_repo.begin_transaction().await?;
+    let incident = incident_repo
+        .get_incident(&mut tx, auth_context.active_organization_id, incident_id)
+        .await?
+        .ok_or(ResolveIncidentManuallyError::IncidentNotFound)?;
+    if incident.status == IncidentStatus::Resolved {
+        return Err(ResolveIncidentManuallyError::IncidentAlreadyResolved);
+    }
+
+    resolve_incident(
+        &mut tx,
+        incident_repo,
+        incident_event_repo,
+        incident_notification_repo,
+        &incident,
+        Some(auth_context.active_user_id),
+    )
+    .await?;
+    incident_repo.commit_transaction(tx).await?;
+
+    Ok(())
+}
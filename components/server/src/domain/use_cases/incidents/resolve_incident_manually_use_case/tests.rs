@@ -0,0 +1,101 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        authorization::AuthContext,
+        entity_metadata::EntityMetadata,
+        incident::{Incident, IncidentPriority, IncidentSourceType, IncidentStatus},
+        incident_event::IncidentEventType,
+        organization::OrganizationUserRole,
+    },
+    infrastructure::mocks::{
+        incident_event_repository_mock::IncidentEventRepositoryMock,
+        incident_notification_repository_mock::IncidentNotificationRepositoryMock,
+        incident_repository_mock::IncidentRepositoryMock,
+    },
+};
+
+use super::{resolve_incident_manually, ResolveIncidentManuallyError};
+
+fn create_test_incident(org_id: Uuid) -> Incident {
+    Incident {
+        organization_id: org_id,
+        id: Uuid::new_v4(),
+        created_at: Utc::now(),
+        created_by: None,
+        resolved_at: None,
+        cause: None,
+        status: IncidentStatus::Ongoing,
+        priority: IncidentPriority::Major,
+        incident_source_type: IncidentSourceType::Manual,
+        incident_source_id: Uuid::new_v4(),
+        acknowledged_by: vec![],
+        title: Some("Login is broken".to_string()),
+        metadata: EntityMetadata::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_incident_manually() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let (org_id, user_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let auth_context = AuthContext::test_context(org_id, user_id, &[OrganizationUserRole::Editor], &[]);
+    let incident = create_test_incident(org_id);
+    incident_repo.state.lock().await.push(incident.clone());
+
+    resolve_incident_manually(
+        &auth_context,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        incident.id,
+    )
+    .await?;
+
+    let resolved = incident_repo.state.lock().await[0].clone();
+    assert_eq!(resolved.status, IncidentStatus::Resolved);
+    assert!(resolved.resolved_at.is_some());
+    let events = incident_event_repo.state.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_type, IncidentEventType::Resolution);
+    assert_eq!(events[0].user_id, Some(user_id));
+    drop(events);
+
+    // Resolving the incident again fails
+    let result = resolve_incident_manually(
+        &auth_context,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        incident.id,
+    )
+    .await;
+    assert!(matches!(result, Err(ResolveIncidentManuallyError::IncidentAlreadyResolved)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resolve_incident_manually_requires_edit_permission() {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let auth_context = AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Reporter], &[]);
+    let incident = create_test_incident(org_id);
+    incident_repo.state.lock().await.push(incident.clone());
+
+    let result = resolve_incident_manually(
+        &auth_context,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        incident.id,
+    )
+    .await;
+
+    assert!(matches!(result, Err(ResolveIncidentManuallyError::Forbidden)));
+    assert_eq!(incident_repo.state.lock().await[0].status, IncidentStatus::Ongoing);
+}
@@ -1,5 +1,6 @@
 use anyhow::Context;
 use chrono::Utc;
+use uuid::Uuid;
 
 use crate::domain::{
     entities::{
@@ -22,12 +23,14 @@ pub enum ResolveIncidentOutput {
 /// Resolves an incident and sends the appropriate notifications
 /// If the incident is already resolved, it returns an error
 /// If the incident is to be confirmed, it deletes the incident without sending any notifications
+/// `resolved_by` is the user who resolved the incident, if it was not resolved automatically
 pub async fn resolve_incident<IR, IER, INR>(
     transaction: &mut IR::Transaction,
     incident_repo: &IR,
     incident_event_repo: &IER,
     incident_notification_repo: &INR,
     incident: &Incident,
+    resolved_by: Option<Uuid>,
 ) -> anyhow::Result<ResolveIncidentOutput>
 where
     IR: IncidentRepository,
@@ -65,7 +68,7 @@ where
         let event = IncidentEvent {
             organization_id: incident.organization_id,
             incident_id: incident.id,
-            user_id: None,
+            user_id: resolved_by,
             created_at: Utc::now(),
             event_type: IncidentEventType::Resolution,
             event_payload: None,
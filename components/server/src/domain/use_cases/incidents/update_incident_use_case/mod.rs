@@ -0,0 +1,130 @@
+use chrono::Utc;
+use serde::Deserialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        entity_metadata::EntityMetadata,
+        incident::IncidentPriority,
+        incident_event::{
+            IncidentEvent, IncidentEventPayload, IncidentEventType, MetadataChangedEventPayload,
+            PriorityChangedEventPayload, TitleChangedEventPayload,
+        },
+    },
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_repository::IncidentRepository,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The fields of an incident to change. Fields that are not set are left unchanged
+#[derive(Debug, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct UpdateIncidentCommand {
+    pub priority: Option<IncidentPriority>,
+    pub metadata: Option<EntityMetadata>,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Error)]
+pub enum UpdateIncidentError {
+    #[error("Incident not found")]
+    IncidentNotFound,
+    #[error("Current user doesn't have the privilege to edit this incident")]
+    Forbidden,
+    #[error("Invalid incident: {0}")]
+    InvalidIncident(String),
+    #[error("Failed to update incident: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Changes the priority, metadata or title of an incident.
+/// Each change is recorded in the timeline of the incident
+pub async fn update_incident<IR, IER>(
+    auth_context: &AuthContext,
+    incident_repo: &IR,
+    incident_event_repo: &IER,
+    incident_id: Uuid,
+    command: UpdateIncidentCommand,
+) -> Result<(), UpdateIncidentError>
+where
+    IR: IncidentRepository,
+    IER: IncidentEventRepository<Transaction = IR::Transaction>,
+{
+    if !auth_context.can(Permission::EditIncidents) {
+        return Err(UpdateIncidentError::Forbidden);
+    }
+    let title = match command.title {
+        Some(title) if title.trim().is_empty() => {
+            return Err(UpdateIncidentError::InvalidIncident(
+                "Incident title cannot be empty".to_string(),
+            ))
+        }
+        title => title.map(|title| title.trim().to_string()),
+    };
+
+    let mut tx = incident_repo.begin_transaction().await?;
+    let mut incident = incident_repo
+        .get_incident(&mut tx, auth_context.active_organization_id, incident_id)
+        .await?
+        .ok_or(UpdateIncidentError::IncidentNotFound)?;
+
+    let mut events = vec![];
+    if let Some(priority) = command.priority.filter(|p| *p != incident.priority) {
+        events.push((
+            IncidentEventType::PriorityChanged,
+            IncidentEventPayload::PriorityChanged(PriorityChangedEventPayload {
+                previous_priority: incident.priority,
+                new_priority: priority,
+            }),
+        ));
+        incident.priority = priority;
+    }
+    if let Some(metadata) = command.metadata.filter(|m| m.records != incident.metadata.records) {
+        events.push((
+            IncidentEventType::MetadataChanged,
+            IncidentEventPayload::MetadataChanged(MetadataChangedEventPayload {
+                previous_metadata: incident.metadata.clone(),
+                new_metadata: metadata.clone(),
+            }),
+        ));
+        incident.metadata = metadata;
+    }
+    if let Some(title) = title.filter(|t| incident.title.as_ref() != Some(t)) {
+        events.push((
+            IncidentEventType::TitleChanged,
+            IncidentEventPayload::TitleChanged(TitleChangedEventPayload {
+                previous_title: incident.title.clone(),
+                new_title: Some(title.clone()),
+            }),
+        ));
+        incident.title = Some(title);
+    }
+
+    if events.is_empty() {
+        return Ok(());
+    }
+    incident_repo.update_incident(&mut tx, incident).await?;
+    for (event_type, payload) in events {
+        let event = IncidentEvent {
+            organization_id: auth_context.active_organization_id,
+            incident_id,
+            user_id: Some(auth_context.active_user_id),
+            created_at: Utc::now(),
+            event_type,
+            event_payload: Some(payload),
+        };
+        incident_event_repo.create_incident_event(&mut tx, event).await?;
+    }
+    incident_repo.commit_transaction(tx).await?;
+
+    Ok(())
+}
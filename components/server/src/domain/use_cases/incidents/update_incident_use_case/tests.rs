@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        authorization::AuthContext,
+        entity_metadata::EntityMetadata,
+        incident::{Incident, IncidentPriority, IncidentSourceType, IncidentStatus},
+        incident_event::{IncidentEventPayload, IncidentEventType},
+        organization::OrganizationUserRole,
+    },
+    infrastructure::mocks::{
+        incident_event_repository_mock::IncidentEventRepositoryMock,
+        incident_repository_mock::IncidentRepositoryMock,
+    },
+};
+
+use super::{update_incident, UpdateIncidentCommand, UpdateIncidentError};
+
+fn create_test_incident(org_id: Uuid) -> Incident {
+    Incident {
+        organization_id: org_id,
+        id: Uuid::new_v4(),
+        created_at: Utc::now(),
+        created_by: None,
+        resolved_at: None,
+        cause: None,
+        status: IncidentStatus::Ongoing,
+        priority: IncidentPriority::Major,
+        incident_source_type: IncidentSourceType::HttpMonitor,
+        incident_source_id: Uuid::new_v4(),
+        acknowledged_by: vec![],
+        title: None,
+        metadata: EntityMetadata::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_update_incident_records_each_change() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let (org_id, user_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let auth_context = AuthContext::test_context(org_id, user_id, &[OrganizationUserRole::Editor], &[]);
+    let incident = create_test_incident(org_id);
+    incident_repo.state.lock().await.push(incident.clone());
+
+    update_incident(
+        &auth_context,
+        &incident_repo,
+        &incident_event_repo,
+        incident.id,
+        UpdateIncidentCommand {
+            priority: Some(IncidentPriority::Critical),
+            metadata: Some(EntityMetadata {
+                records: HashMap::from([("team".to_string(), "payments".to_string())]),
+            }),
+            title: Some("Checkout is down".to_string()),
+        },
+    )
+    .await?;
+
+    let updated = incident_repo.state.lock().await[0].clone();
+    assert_eq!(updated.priority, IncidentPriority::Critical);
+    assert_eq!(updated.metadata.records.get("team").map(String::as_str), Some("payments"));
+    assert_eq!(updated.title.as_deref(), Some("Checkout is down"));
+
+    let events = incident_event_repo.state.lock().await;
+    let event_types = events.iter().map(|e| e.event_type).collect::<Vec<_>>();
+    assert_eq!(
+        event_types,
+        vec![
+            IncidentEventType::PriorityChanged,
+            IncidentEventType::MetadataChanged,
+            IncidentEventType::TitleChanged
+        ]
+    );
+    assert!(events.iter().all(|e| e.user_id == Some(user_id)));
+    assert!(matches!(
+        &events[0].event_payload,
+        Some(IncidentEventPayload::PriorityChanged(payload))
+            if payload.previous_priority == IncidentPriority::Major
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_incident_without_changes() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let auth_context = AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+    let incident = create_test_incident(org_id);
+    incident_repo.state.lock().await.push(incident.clone());
+
+    update_incident(
+        &auth_context,
+        &incident_repo,
+        &incident_event_repo,
+        incident.id,
+        UpdateIncidentCommand {
+            priority: Some(IncidentPriority::Major),
+            metadata: None,
+            title: None,
+        },
+    )
+    .await?;
+
+    assert!(incident_event_repo.state.lock().await.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_incident_errors() {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let incident = create_test_incident(org_id);
+    incident_repo.state.lock().await.push(incident.clone());
+    let command = || UpdateIncidentCommand {
+        priority: Some(IncidentPriority::Critical),
+        metadata: None,
+        title: None,
+    };
+
+    let reporter = AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Reporter], &[]);
+    let result = update_incident(&reporter, &incident_repo, &incident_event_repo, incident.id, command()).await;
+    assert!(matches!(result, Err(UpdateIncidentError::Forbidden)));
+
+    let editor = AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+    let result = update_incident(&editor, &incident_repo, &incident_event_repo, Uuid::new_v4(), command()).await;
+    assert!(matches!(result, Err(UpdateIncidentError::IncidentNotFound)));
+
+    let result = update_incident(
+        &editor,
+        &incident_repo,
+        &incident_event_repo,
+        incident.id,
+        UpdateIncidentCommand {
+            priority: None,
+            metadata: None,
+            title: Some("".to_string()),
+        },
+    )
+    .await;
+    assert!(matches!(result, Err(UpdateIncidentError::InvalidIncident(_))));
+}
@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        maintenance_window::{parse_maintenance_cron_schedule, MaintenanceWindowScope},
+    },
+    ports::maintenance_window_repository::{MaintenanceWindowRepository, NewMaintenanceWindow},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Creates a one-off maintenance window when `cronSchedule` is not set, otherwise a recurring one
+#[derive(Deserialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateMaintenanceWindowCommand {
+    pub title: String,
+    pub description: Option<String>,
+    /// Defaults to now
+    pub starts_at: Option<DateTime<Utc>>,
+    /// Required for one-off windows. Recurring windows recur forever if not set
+    pub ends_at: Option<DateTime<Utc>>,
+    pub cron_schedule: Option<String>,
+    /// Required for recurring windows
+    pub duration_seconds: Option<i32>,
+    pub scope: MaintenanceWindowScope,
+}
+
+#[derive(Serialize, TS, Clone, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateMaintenanceWindowResponse {
+    pub id: Uuid,
+}
+
+#[derive(Error, Debug)]
+pub enum CreateMaintenanceWindowError {
+    #[error("Failed to create a maintenance window: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to create maintenance windows")]
+    Forbidden,
+    #[error("Invalid maintenance window: {0}")]
+    InvalidMaintenanceWindow(String),
+}
+
+pub async fn create_maintenance_window(
+    auth_context: &AuthContext,
+    repository: &impl MaintenanceWindowRepository,
+    command: CreateMaintenanceWindowCommand,
+) -> Result<CreateMaintenanceWindowResponse, CreateMaintenanceWindowError> {
+    if !auth_context.can(Permission::WriteMaintenanceWindows) {
+        return Err(CreateMaintenanceWindowError::Forbidden);
+    }
+
+    let maintenance_window = NewMaintenanceWindow {
+        organization_id: auth_context.active_organization_id,
+        title: command.title,
+        description: command.description,
+        starts_at: command.starts_at.unwrap_or_else(Utc::now),
+        ends_at: command.ends_at,
+        cron_schedule: command.cron_schedule,
+        duration_seconds: command.duration_seconds,
+        scope: command.scope,
+    };
+    validate_maintenance_window(&maintenance_window)
+        .map_err(CreateMaintenanceWindowError::InvalidMaintenanceWindow)?;
+
+    let mut tx = repository.begin_transaction().await?;
+    let id = repository
+        .create_maintenance_window(&mut tx, maintenance_window)
+        .await?;
+    repository.commit_transaction(tx).await?;
+
+    Ok(CreateMaintenanceWindowResponse { id })
+}
+
+fn validate_maintenance_window(maintenance_window: &NewMaintenanceWindow) -> Result<(), String> {
+    if maintenance_window.title.trim().is_empty() {
+        return Err("The title cannot be empty".to_string());
+    }
+    if maintenance_window.scope.is_empty() {
+        return Err(
+            "The scope must contain at least one HTTP monitor, one task or a metadata filter"
+                .to_string(),
+        );
+    }
+    if let Some(ends_at) = maintenance_window.ends_at {
+        if ends_at <= maintenance_window.starts_at {
+            return Err("The window must end after it starts".to_string());
+        }
+    }
+
+    match (&maintenance_window.cron_schedule, maintenance_window.duration_seconds) {
+        (Some(cron_schedule), Some(duration_seconds)) => {
+            parse_maintenance_cron_schedule(cron_schedule)?;
+            if duration_seconds <= 0 {
+                return Err("The duration must be positive".to_string());
+            }
+        }
+        (Some(_), None) => {
+            return Err("Recurring windows require a duration".to_string());
+        }
+        (None, Some(_)) => {
+            return Err("Only recurring windows have a duration, one-off windows have an end date".to_string());
+        }
+        (None, None) => {
+            if maintenance_window.ends_at.is_none() {
+                return Err("One-off windows require an end date".to_string());
+            }
+        }
+    }
+
+    Ok(())
+}
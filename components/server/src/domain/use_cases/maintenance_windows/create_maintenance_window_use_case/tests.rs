@@ -0,0 +1,108 @@
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        authorization::AuthContext, maintenance_window::MaintenanceWindowScope,
+        organization::OrganizationUserRole,
+    },
+    infrastructure::mocks::maintenance_window_repository_mock::MaintenanceWindowRepositoryMock,
+};
+
+use super::{
+    create_maintenance_window, CreateMaintenanceWindowCommand, CreateMaintenanceWindowError,
+};
+
+fn command(cron_schedule: Option<&str>, duration_seconds: Option<i32>) -> CreateMaintenanceWindowCommand {
+    CreateMaintenanceWindowCommand {
+        title: "Weekly deployment".to_string(),
+        description: None,
+        starts_at: None,
+        ends_at: cron_schedule.is_none().then(|| Utc::now() + Duration::hours(1)),
+        cron_schedule: cron_schedule.map(str::to_string),
+        duration_seconds,
+        scope: MaintenanceWindowScope {
+            http_monitor_ids: vec![Uuid::new_v4()],
+            ..Default::default()
+        },
+    }
+}
+
+#[tokio::test]
+async fn test_create_maintenance_window() -> anyhow::Result<()> {
+    let repository = MaintenanceWindowRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let auth_context =
+        AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+
+    let response = create_maintenance_window(
+        &auth_context,
+        &repository,
+        command(Some("0 22 * * 1"), Some(3600)),
+    )
+    .await?;
+
+    let state = repository.state.lock().await;
+    assert_eq!(state.len(), 1);
+    assert_eq!(state[0].id, response.id);
+    assert_eq!(state[0].organization_id, org_id);
+    assert_eq!(state[0].cron_schedule.as_deref(), Some("0 22 * * 1"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_maintenance_window_requires_write_permission() {
+    let repository = MaintenanceWindowRepositoryMock::new();
+    let auth_context = AuthContext::test_context(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Reporter],
+        &[],
+    );
+
+    let result = create_maintenance_window(&auth_context, &repository, command(None, None)).await;
+
+    assert!(matches!(result, Err(CreateMaintenanceWindowError::Forbidden)));
+    assert!(repository.state.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_create_invalid_maintenance_windows() {
+    let repository = MaintenanceWindowRepositoryMock::new();
+    let auth_context = AuthContext::test_context(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Editor],
+        &[],
+    );
+
+    let without_scope = CreateMaintenanceWindowCommand {
+        scope: MaintenanceWindowScope::default(),
+        ..command(None, None)
+    };
+    let without_end = CreateMaintenanceWindowCommand {
+        ends_at: None,
+        ..command(None, None)
+    };
+    let ending_before_start = CreateMaintenanceWindowCommand {
+        ends_at: Some(Utc::now() - Duration::hours(1)),
+        ..command(None, None)
+    };
+    let invalid_commands = [
+        without_scope,
+        without_end,
+        ending_before_start,
+        command(Some("every monday"), Some(3600)),
+        command(Some("0 22 * * 1"), None),
+        command(Some("0 22 * * 1"), Some(0)),
+    ];
+
+    for invalid_command in invalid_commands {
+        let result = create_maintenance_window(&auth_context, &repository, invalid_command).await;
+        assert!(matches!(
+            result,
+            Err(CreateMaintenanceWindowError::InvalidMaintenanceWindow(_))
+        ));
+    }
+    assert!(repository.state.lock().await.is_empty());
+}
@@ -0,0 +1,38 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::authorization::{AuthContext, Permission},
+    ports::maintenance_window_repository::MaintenanceWindowRepository,
+};
+
+#[derive(Error, Debug)]
+pub enum DeleteMaintenanceWindowError {
+    #[error("Failed to delete a maintenance window: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to delete maintenance windows")]
+    Forbidden,
+    #[error("Maintenance window not found")]
+    NotFound,
+}
+
+pub async fn delete_maintenance_window(
+    auth_context: &AuthContext,
+    repository: &impl MaintenanceWindowRepository,
+    maintenance_window_id: Uuid,
+) -> Result<(), DeleteMaintenanceWindowError> {
+    if !auth_context.can(Permission::WriteMaintenanceWindows) {
+        return Err(DeleteMaintenanceWindowError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let deleted = repository
+        .delete_maintenance_window(&mut tx, auth_context.active_organization_id, maintenance_window_id)
+        .await?;
+    if !deleted {
+        return Err(DeleteMaintenanceWindowError::NotFound);
+    }
+    repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        maintenance_window::MaintenanceWindow,
+    },
+    ports::maintenance_window_repository::MaintenanceWindowRepository,
+};
+
+#[derive(Serialize, TS, ToSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ListMaintenanceWindowsResponse {
+    pub maintenance_windows: Vec<MaintenanceWindow>,
+}
+
+#[derive(Error, Debug)]
+pub enum ListMaintenanceWindowsError {
+    #[error("User is not allowed to list maintenance windows")]
+    Forbidden,
+    #[error("Technical failure occured while listing maintenance windows")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+pub async fn list_maintenance_windows(
+    auth_context: &AuthContext,
+    repository: &impl MaintenanceWindowRepository,
+) -> Result<ListMaintenanceWindowsResponse, ListMaintenanceWindowsError> {
+    if !auth_context.can(Permission::ReadMaintenanceWindows) {
+        return Err(ListMaintenanceWindowsError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let maintenance_windows = repository
+        .list_maintenance_windows(&mut tx, auth_context.active_organization_id)
+        .await?;
+
+    Ok(ListMaintenanceWindowsResponse { maintenance_windows })
+}
@@ -0,0 +1,9 @@
+mod create_maintenance_window_use_case;
+mod delete_maintenance_window_use_case;
+mod list_maintenance_windows_use_case;
+mod ongoing_maintenance_window;
+
+pub use create_maintenance_window_use_case::*;
+pub use delete_maintenance_window_use_case::*;
+pub use list_maintenance_windows_use_case::*;
+pub use ongoing_maintenance_window::*;
@@ -0,0 +1,30 @@
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::maintenance_window::MaintenanceWindow,
+    ports::maintenance_window_repository::MaintenanceWindowRepository,
+};
+
+/// Returns a maintenance window of the organization that is ongoing at a date and that covers an entity, if any.
+/// Monitors and tasks do not open incidents nor send notifications while they are under maintenance
+pub async fn find_ongoing_maintenance_window<MWR>(
+    transaction: &mut MWR::Transaction,
+    maintenance_window_repository: &MWR,
+    organization_id: Uuid,
+    date: DateTime<Utc>,
+    covers: impl Fn(&MaintenanceWindow) -> bool,
+) -> anyhow::Result<Option<MaintenanceWindow>>
+where
+    MWR: MaintenanceWindowRepository,
+{
+    let window = maintenance_window_repository
+        .list_maintenance_windows_ending_after(transaction, organization_id, date)
+        .await
+        .context("Failed to list maintenance windows")?
+        .into_iter()
+        .find(|window| covers(window) && window.is_ongoing_at(date));
+
+    Ok(window)
+}
@@ -1,9 +1,16 @@
+pub mod alert_integrations;
 pub mod auth;
+pub mod escalation_policies;
 pub mod file_storage;
 pub mod http_monitors;
 pub mod incidents;
+pub mod maintenance_windows;
+pub mod notification_preferences;
+pub mod on_call;
 pub mod organizations;
 pub mod shared;
+pub mod status_pages;
 pub mod tasks;
 pub mod user_devices;
 pub mod users;
+pub mod webhooks;
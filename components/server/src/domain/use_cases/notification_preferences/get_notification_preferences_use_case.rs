@@ -0,0 +1,41 @@
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+
+use crate::domain::{
+    entities::{authorization::AuthContext, notification_preferences::NotificationPreferences},
+    ports::notification_preferences_repository::NotificationPreferencesRepository,
+};
+
+#[derive(Serialize, TS, Debug)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct GetNotificationPreferencesResponse {
+    pub notification_preferences: NotificationPreferences,
+}
+
+#[derive(Error, Debug)]
+pub enum GetNotificationPreferencesError {
+    #[error("Failed to get notification preferences: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Returns the notification preferences of the current user in the current organization.
+/// Users that have not configured them get the default preferences
+pub async fn get_notification_preferences(
+    auth_context: &AuthContext,
+    repository: &impl NotificationPreferencesRepository,
+) -> Result<GetNotificationPreferencesResponse, GetNotificationPreferencesError> {
+    let org_id = auth_context.active_organization_id;
+    let user_id = auth_context.active_user_id;
+
+    let mut tx = repository.begin_transaction().await?;
+    let notification_preferences = repository
+        .get_notification_preferences(&mut tx, org_id, user_id)
+        .await?
+        .unwrap_or_else(|| NotificationPreferences::default_for(org_id, user_id));
+
+    Ok(GetNotificationPreferencesResponse {
+        notification_preferences,
+    })
+}
@@ -0,0 +1,5 @@
+mod get_notification_preferences_use_case;
+mod update_notification_preferences_use_case;
+
+pub use get_notification_preferences_use_case::*;
+pub use update_notification_preferences_use_case::*;
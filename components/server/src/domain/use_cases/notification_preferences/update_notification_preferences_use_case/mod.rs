@@ -0,0 +1,63 @@
+use chrono::Utc;
+use serde::Deserialize;
+use thiserror::Error;
+use ts_rs::TS;
+
+use crate::domain::{
+    entities::{
+        authorization::AuthContext,
+        notification_preferences::{
+            NotificationPreferences, PriorityNotificationChannels, QuietHours, VacationMode,
+        },
+    },
+    ports::notification_preferences_repository::NotificationPreferencesRepository,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Deserialize, TS, Debug)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct UpdateNotificationPreferencesCommand {
+    #[serde(default)]
+    pub channels: Vec<PriorityNotificationChannels>,
+    pub quiet_hours: Option<QuietHours>,
+    pub vacation: Option<VacationMode>,
+}
+
+#[derive(Error, Debug)]
+pub enum UpdateNotificationPreferencesError {
+    #[error("Failed to update notification preferences: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Invalid notification preferences: {0}")]
+    InvalidNotificationPreferences(String),
+}
+
+/// Replaces the notification preferences of the current user in the current organization.
+/// They apply to the notifications sent from now on, including the escalations of ongoing incidents
+pub async fn update_notification_preferences(
+    auth_context: &AuthContext,
+    repository: &impl NotificationPreferencesRepository,
+    command: UpdateNotificationPreferencesCommand,
+) -> Result<(), UpdateNotificationPreferencesError> {
+    let preferences = NotificationPreferences {
+        organization_id: auth_context.active_organization_id,
+        user_id: auth_context.active_user_id,
+        channels: command.channels,
+        quiet_hours: command.quiet_hours,
+        vacation: command.vacation,
+        updated_at: Utc::now(),
+    };
+    preferences
+        .validate()
+        .map_err(UpdateNotificationPreferencesError::InvalidNotificationPreferences)?;
+
+    let mut tx = repository.begin_transaction().await?;
+    repository
+        .upsert_notification_preferences(&mut tx, preferences)
+        .await?;
+    repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
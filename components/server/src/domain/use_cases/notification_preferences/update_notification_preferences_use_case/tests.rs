@@ -0,0 +1,100 @@
+use chrono::NaiveTime;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        authorization::AuthContext,
+        incident::IncidentPriority,
+        notification_preferences::{PriorityNotificationChannels, QuietHours, VacationMode},
+        organization::OrganizationUserRole,
+    },
+    infrastructure::mocks::notification_preferences_repository_mock::NotificationPreferencesRepositoryMock,
+};
+
+use super::{
+    update_notification_preferences, UpdateNotificationPreferencesCommand,
+    UpdateNotificationPreferencesError,
+};
+
+#[tokio::test]
+async fn test_update_notification_preferences() -> anyhow::Result<()> {
+    let repository = NotificationPreferencesRepositoryMock::new();
+    let (org_id, user_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let auth_context =
+        AuthContext::test_context(org_id, user_id, &[OrganizationUserRole::Reporter], &[]);
+
+    update_notification_preferences(
+        &auth_context,
+        &repository,
+        UpdateNotificationPreferencesCommand {
+            channels: vec![PriorityNotificationChannels {
+                priority: IncidentPriority::Notice,
+                email: true,
+                push_notification: false,
+                sms: false,
+            }],
+            quiet_hours: Some(QuietHours {
+                start: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                end: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+                time_zone: "Europe/Paris".to_string(),
+                allowed_priorities: vec![],
+            }),
+            vacation: None,
+        },
+    )
+    .await?;
+
+    // Updating the preferences again replaces them
+    update_notification_preferences(
+        &auth_context,
+        &repository,
+        UpdateNotificationPreferencesCommand {
+            channels: vec![],
+            quiet_hours: None,
+            vacation: Some(VacationMode {
+                starts_at: None,
+                ends_at: None,
+                delegate_user_id: Some(Uuid::new_v4()),
+            }),
+        },
+    )
+    .await?;
+
+    let state = repository.state.lock().await;
+    assert_eq!(state.len(), 1);
+    assert_eq!(state[0].organization_id, org_id);
+    assert_eq!(state[0].user_id, user_id);
+    assert!(state[0].channels.is_empty());
+    assert!(state[0].quiet_hours.is_none());
+    assert!(state[0].vacation.is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cannot_delegate_to_oneself() {
+    let repository = NotificationPreferencesRepositoryMock::new();
+    let user_id = Uuid::new_v4();
+    let auth_context =
+        AuthContext::test_context(Uuid::new_v4(), user_id, &[OrganizationUserRole::Reporter], &[]);
+
+    let result = update_notification_preferences(
+        &auth_context,
+        &repository,
+        UpdateNotificationPreferencesCommand {
+            channels: vec![],
+            quiet_hours: None,
+            vacation: Some(VacationMode {
+                starts_at: None,
+                ends_at: None,
+                delegate_user_id: Some(user_id),
+            }),
+        },
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(UpdateNotificationPreferencesError::InvalidNotificationPreferences(_))
+    ));
+    assert!(repository.state.lock().await.is_empty());
+}
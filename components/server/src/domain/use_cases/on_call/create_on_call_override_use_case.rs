@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::authorization::{AuthContext, Permission},
+    ports::on_call_schedule_repository::{NewOnCallOverride, OnCallScheduleRepository},
+};
+
+/// Replaces the users on call of a schedule by another user for a period
+#[derive(Deserialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateOnCallOverrideCommand {
+    pub user_id: Uuid,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, TS, Clone, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateOnCallOverrideResponse {
+    pub id: Uuid,
+}
+
+#[derive(Error, Debug)]
+pub enum CreateOnCallOverrideError {
+    #[error("Failed to create an on-call override: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to create on-call overrides")]
+    Forbidden,
+    #[error("On-call schedule not found")]
+    NotFound,
+    #[error("Invalid on-call override: the override must end after it starts, and cannot be in the past")]
+    InvalidOnCallOverride,
+}
+
+pub async fn create_on_call_override(
+    auth_context: &AuthContext,
+    repository: &impl OnCallScheduleRepository,
+    on_call_schedule_id: Uuid,
+    command: CreateOnCallOverrideCommand,
+) -> Result<CreateOnCallOverrideResponse, CreateOnCallOverrideError> {
+    if !auth_context.can(Permission::WriteOnCallSchedules) {
+        return Err(CreateOnCallOverrideError::Forbidden);
+    }
+
+    if command.ends_at <= command.starts_at || command.ends_at <= Utc::now() {
+        return Err(CreateOnCallOverrideError::InvalidOnCallOverride);
+    }
+
+    let org_id = auth_context.active_organization_id;
+    let mut tx = repository.begin_transaction().await?;
+    if repository
+        .get_on_call_schedule(&mut tx, org_id, on_call_schedule_id)
+        .await?
+        .is_none()
+    {
+        return Err(CreateOnCallOverrideError::NotFound);
+    }
+    let id = repository
+        .create_on_call_override(
+            &mut tx,
+            NewOnCallOverride {
+                organization_id: org_id,
+                on_call_schedule_id,
+                user_id: command.user_id,
+                starts_at: command.starts_at,
+                ends_at: command.ends_at,
+            },
+        )
+        .await?;
+    repository.commit_transaction(tx).await?;
+
+    Ok(CreateOnCallOverrideResponse { id })
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        on_call_schedule::{validate_on_call_schedule, OnCallRotations},
+    },
+    ports::on_call_schedule_repository::{NewOnCallSchedule, OnCallScheduleRepository},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Deserialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateOnCallScheduleCommand {
+    pub name: String,
+    pub description: Option<String>,
+    /// The IANA time zone of the handoff times, e.g. `Europe/Paris`
+    pub time_zone: String,
+    #[serde(default)]
+    pub rotations: OnCallRotations,
+}
+
+#[derive(Serialize, TS, Clone, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateOnCallScheduleResponse {
+    pub id: Uuid,
+}
+
+#[derive(Error, Debug)]
+pub enum CreateOnCallScheduleError {
+    #[error("Failed to create an on-call schedule: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to create on-call schedules")]
+    Forbidden,
+    #[error("Invalid on-call schedule: {0}")]
+    InvalidOnCallSchedule(String),
+}
+
+pub async fn create_on_call_schedule(
+    auth_context: &AuthContext,
+    repository: &impl OnCallScheduleRepository,
+    command: CreateOnCallScheduleCommand,
+) -> Result<CreateOnCallScheduleResponse, CreateOnCallScheduleError> {
+    if !auth_context.can(Permission::WriteOnCallSchedules) {
+        return Err(CreateOnCallScheduleError::Forbidden);
+    }
+
+    validate_on_call_schedule(&command.name, &command.time_zone, &command.rotations)
+        .map_err(CreateOnCallScheduleError::InvalidOnCallSchedule)?;
+
+    let mut tx = repository.begin_transaction().await?;
+    let id = repository
+        .create_on_call_schedule(
+            &mut tx,
+            NewOnCallSchedule {
+                organization_id: auth_context.active_organization_id,
+                name: command.name,
+                description: command.description,
+                time_zone: command.time_zone,
+                rotations: command.rotations,
+            },
+        )
+        .await?;
+    repository.commit_transaction(tx).await?;
+
+    Ok(CreateOnCallScheduleResponse { id })
+}
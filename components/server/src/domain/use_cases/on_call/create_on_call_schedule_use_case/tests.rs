@@ -0,0 +1,84 @@
+use chrono::{NaiveTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        authorization::AuthContext,
+        on_call_schedule::{OnCallRotation, OnCallRotationKind, OnCallRotations},
+        organization::OrganizationUserRole,
+    },
+    infrastructure::mocks::on_call_schedule_repository_mock::OnCallScheduleRepositoryMock,
+};
+
+use super::{create_on_call_schedule, CreateOnCallScheduleCommand, CreateOnCallScheduleError};
+
+fn command(time_zone: &str) -> CreateOnCallScheduleCommand {
+    CreateOnCallScheduleCommand {
+        name: "Backend".to_string(),
+        description: None,
+        time_zone: time_zone.to_string(),
+        rotations: OnCallRotations {
+            items: vec![OnCallRotation {
+                name: "Primary".to_string(),
+                kind: OnCallRotationKind::Weekly,
+                user_ids: vec![Uuid::new_v4(), Uuid::new_v4()],
+                handoff_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                handoff_day: None,
+                starts_at: Utc::now(),
+            }],
+        },
+    }
+}
+
+#[tokio::test]
+async fn test_create_on_call_schedule() -> anyhow::Result<()> {
+    let repository = OnCallScheduleRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let auth_context =
+        AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+
+    let response = create_on_call_schedule(&auth_context, &repository, command("Europe/Paris")).await?;
+
+    let state = repository.state.lock().await;
+    assert_eq!(state.len(), 1);
+    assert_eq!(state[0].id, response.id);
+    assert_eq!(state[0].organization_id, org_id);
+    assert_eq!(state[0].time_zone, "Europe/Paris");
+    assert_eq!(state[0].rotations.items.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_on_call_schedule_requires_write_permission() {
+    let repository = OnCallScheduleRepositoryMock::new();
+    let auth_context = AuthContext::test_context(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Reporter],
+        &[],
+    );
+
+    let result = create_on_call_schedule(&auth_context, &repository, command("UTC")).await;
+
+    assert!(matches!(result, Err(CreateOnCallScheduleError::Forbidden)));
+    assert!(repository.state.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_create_on_call_schedule_with_invalid_time_zone() {
+    let repository = OnCallScheduleRepositoryMock::new();
+    let auth_context = AuthContext::test_context(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Editor],
+        &[],
+    );
+
+    let result = create_on_call_schedule(&auth_context, &repository, command("Paris")).await;
+
+    assert!(matches!(
+        result,
+        Err(CreateOnCallScheduleError::InvalidOnCallSchedule(_))
+    ));
+    assert!(repository.state.lock().await.is_empty());
+}
@@ -0,0 +1,44 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::authorization::{AuthContext, Permission},
+    ports::on_call_schedule_repository::OnCallScheduleRepository,
+};
+
+#[derive(Error, Debug)]
+pub enum DeleteOnCallOverrideError {
+    #[error("Failed to delete an on-call override: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to delete on-call overrides")]
+    Forbidden,
+    #[error("On-call override not found")]
+    NotFound,
+}
+
+pub async fn delete_on_call_override(
+    auth_context: &AuthContext,
+    repository: &impl OnCallScheduleRepository,
+    on_call_schedule_id: Uuid,
+    on_call_override_id: Uuid,
+) -> Result<(), DeleteOnCallOverrideError> {
+    if !auth_context.can(Permission::WriteOnCallSchedules) {
+        return Err(DeleteOnCallOverrideError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let deleted = repository
+        .delete_on_call_override(
+            &mut tx,
+            auth_context.active_organization_id,
+            on_call_schedule_id,
+            on_call_override_id,
+        )
+        .await?;
+    if !deleted {
+        return Err(DeleteOnCallOverrideError::NotFound);
+    }
+    repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
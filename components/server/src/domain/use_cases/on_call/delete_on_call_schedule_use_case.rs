@@ -0,0 +1,40 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::authorization::{AuthContext, Permission},
+    ports::on_call_schedule_repository::OnCallScheduleRepository,
+};
+
+#[derive(Error, Debug)]
+pub enum DeleteOnCallScheduleError {
+    #[error("Failed to delete an on-call schedule: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to delete on-call schedules")]
+    Forbidden,
+    #[error("On-call schedule not found")]
+    NotFound,
+}
+
+/// Deletes an on-call schedule and its overrides.
+/// Notifications targeting a deleted schedule are sent as if the schedule had nobody on call
+pub async fn delete_on_call_schedule(
+    auth_context: &AuthContext,
+    repository: &impl OnCallScheduleRepository,
+    on_call_schedule_id: Uuid,
+) -> Result<(), DeleteOnCallScheduleError> {
+    if !auth_context.can(Permission::WriteOnCallSchedules) {
+        return Err(DeleteOnCallScheduleError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let deleted = repository
+        .delete_on_call_schedule(&mut tx, auth_context.active_organization_id, on_call_schedule_id)
+        .await?;
+    if !deleted {
+        return Err(DeleteOnCallScheduleError::NotFound);
+    }
+    repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        on_call_schedule::OnCallShift,
+    },
+    ports::on_call_schedule_repository::OnCallScheduleRepository,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Serialize, TS, Debug, ToSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCurrentOnCallResponse {
+    pub date: DateTime<Utc>,
+    /// The users on call, without duplicates
+    pub user_ids: Vec<Uuid>,
+    /// The ongoing shifts, one per rotation, or the ongoing overrides
+    pub shifts: Vec<OnCallShift>,
+}
+
+#[derive(Error, Debug)]
+pub enum GetCurrentOnCallError {
+    #[error("User is not allowed to read on-call schedules")]
+    Forbidden,
+    #[error("On-call schedule not found")]
+    NotFound,
+    #[error("Technical failure occured while resolving who is on call")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Returns who is on call for a schedule at a date
+pub async fn get_current_on_call(
+    auth_context: &AuthContext,
+    repository: &impl OnCallScheduleRepository,
+    on_call_schedule_id: Uuid,
+    date: DateTime<Utc>,
+) -> Result<GetCurrentOnCallResponse, GetCurrentOnCallError> {
+    if !auth_context.can(Permission::ReadOnCallSchedules) {
+        return Err(GetCurrentOnCallError::Forbidden);
+    }
+
+    let org_id = auth_context.active_organization_id;
+    let mut tx = repository.begin_transaction().await?;
+    let schedule = repository
+        .get_on_call_schedule(&mut tx, org_id, on_call_schedule_id)
+        .await?
+        .ok_or(GetCurrentOnCallError::NotFound)?;
+    let overrides = repository
+        .list_on_call_overrides_ending_after(&mut tx, org_id, on_call_schedule_id, date)
+        .await?;
+
+    Ok(GetCurrentOnCallResponse {
+        date,
+        user_ids: schedule.on_call_user_ids(&overrides, date),
+        shifts: schedule.shifts_at(&overrides, date),
+    })
+}
@@ -0,0 +1,115 @@
+use chrono::{Duration, NaiveTime, TimeZone, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            authorization::AuthContext,
+            on_call_schedule::{OnCallRotation, OnCallRotationKind, OnCallRotations},
+            organization::OrganizationUserRole,
+        },
+        ports::{
+            on_call_schedule_repository::{
+                NewOnCallOverride, NewOnCallSchedule, OnCallScheduleRepository,
+            },
+            transactional_repository::TransactionalRepository,
+        },
+        use_cases::on_call::find_on_call_user_ids,
+    },
+    infrastructure::mocks::on_call_schedule_repository_mock::OnCallScheduleRepositoryMock,
+};
+
+use super::{get_current_on_call, GetCurrentOnCallError};
+
+#[tokio::test]
+async fn test_get_current_on_call() -> anyhow::Result<()> {
+    let repository = OnCallScheduleRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let auth_context =
+        AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Reporter], &[]);
+    let (alice, bob, carol) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    let starts_at = Utc.with_ymd_and_hms(2024, 12, 9, 9, 0, 0).unwrap();
+
+    let mut tx = repository.begin_transaction().await?;
+    let schedule_id = repository
+        .create_on_call_schedule(
+            &mut tx,
+            NewOnCallSchedule {
+                organization_id: org_id,
+                name: "Backend".to_string(),
+                description: None,
+                time_zone: "UTC".to_string(),
+                rotations: OnCallRotations {
+                    items: vec![OnCallRotation {
+                        name: "Primary".to_string(),
+                        kind: OnCallRotationKind::Daily,
+                        user_ids: vec![alice, bob],
+                        handoff_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                        handoff_day: None,
+                        starts_at,
+                    }],
+                },
+            },
+        )
+        .await?;
+    // Carol replaces Bob during the afternoon of the second day
+    let second_day_afternoon = starts_at + Duration::days(1) + Duration::hours(5);
+    repository
+        .create_on_call_override(
+            &mut tx,
+            NewOnCallOverride {
+                organization_id: org_id,
+                on_call_schedule_id: schedule_id,
+                user_id: carol,
+                starts_at: second_day_afternoon,
+                ends_at: second_day_afternoon + Duration::hours(4),
+            },
+        )
+        .await?;
+
+    let response = get_current_on_call(&auth_context, &repository, schedule_id, starts_at).await?;
+    assert_eq!(response.user_ids, vec![alice]);
+    assert_eq!(response.shifts[0].rotation_name.as_deref(), Some("Primary"));
+
+    let response = get_current_on_call(
+        &auth_context,
+        &repository,
+        schedule_id,
+        starts_at + Duration::days(1),
+    )
+    .await?;
+    assert_eq!(response.user_ids, vec![bob]);
+
+    let response =
+        get_current_on_call(&auth_context, &repository, schedule_id, second_day_afternoon).await?;
+    assert_eq!(response.user_ids, vec![carol]);
+    assert!(response.shifts[0].override_id.is_some());
+
+    // Unknown schedules are ignored when resolving the users to notify
+    let user_ids = find_on_call_user_ids(
+        &mut tx,
+        &repository,
+        org_id,
+        &[schedule_id, Uuid::new_v4()],
+        second_day_afternoon,
+    )
+    .await?;
+    assert_eq!(user_ids, vec![carol]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_current_on_call_of_unknown_schedule() {
+    let repository = OnCallScheduleRepositoryMock::new();
+    let auth_context = AuthContext::test_context(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Reporter],
+        &[],
+    );
+
+    let result = get_current_on_call(&auth_context, &repository, Uuid::new_v4(), Utc::now()).await;
+
+    assert!(matches!(result, Err(GetCurrentOnCallError::NotFound)));
+}
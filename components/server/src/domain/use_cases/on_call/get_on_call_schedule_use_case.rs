@@ -0,0 +1,58 @@
+use chrono::Utc;
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        on_call_schedule::{OnCallOverride, OnCallSchedule},
+    },
+    ports::on_call_schedule_repository::OnCallScheduleRepository,
+};
+
+#[derive(Serialize, TS, ToSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOnCallScheduleResponse {
+    pub on_call_schedule: OnCallSchedule,
+    /// The ongoing and future overrides of the schedule
+    pub overrides: Vec<OnCallOverride>,
+}
+
+#[derive(Error, Debug)]
+pub enum GetOnCallScheduleError {
+    #[error("User is not allowed to read on-call schedules")]
+    Forbidden,
+    #[error("On-call schedule not found")]
+    NotFound,
+    #[error("Technical failure occured while getting an on-call schedule")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+pub async fn get_on_call_schedule(
+    auth_context: &AuthContext,
+    repository: &impl OnCallScheduleRepository,
+    on_call_schedule_id: Uuid,
+) -> Result<GetOnCallScheduleResponse, GetOnCallScheduleError> {
+    if !auth_context.can(Permission::ReadOnCallSchedules) {
+        return Err(GetOnCallScheduleError::Forbidden);
+    }
+
+    let org_id = auth_context.active_organization_id;
+    let mut tx = repository.begin_transaction().await?;
+    let on_call_schedule = repository
+        .get_on_call_schedule(&mut tx, org_id, on_call_schedule_id)
+        .await?
+        .ok_or(GetOnCallScheduleError::NotFound)?;
+    let overrides = repository
+        .list_on_call_overrides_ending_after(&mut tx, org_id, on_call_schedule_id, Utc::now())
+        .await?;
+
+    Ok(GetOnCallScheduleResponse {
+        on_call_schedule,
+        overrides,
+    })
+}
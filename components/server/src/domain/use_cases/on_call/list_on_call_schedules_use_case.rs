@@ -0,0 +1,43 @@
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        on_call_schedule::OnCallSchedule,
+    },
+    ports::on_call_schedule_repository::OnCallScheduleRepository,
+};
+
+#[derive(Serialize, TS, ToSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ListOnCallSchedulesResponse {
+    pub on_call_schedules: Vec<OnCallSchedule>,
+}
+
+#[derive(Error, Debug)]
+pub enum ListOnCallSchedulesError {
+    #[error("User is not allowed to list on-call schedules")]
+    Forbidden,
+    #[error("Technical failure occured while listing on-call schedules")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+pub async fn list_on_call_schedules(
+    auth_context: &AuthContext,
+    repository: &impl OnCallScheduleRepository,
+) -> Result<ListOnCallSchedulesResponse, ListOnCallSchedulesError> {
+    if !auth_context.can(Permission::ReadOnCallSchedules) {
+        return Err(ListOnCallSchedulesError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let on_call_schedules = repository
+        .list_on_call_schedules(&mut tx, auth_context.active_organization_id)
+        .await?;
+
+    Ok(ListOnCallSchedulesResponse { on_call_schedules })
+}
@@ -0,0 +1,19 @@
+mod create_on_call_override_use_case;
+mod create_on_call_schedule_use_case;
+mod delete_on_call_override_use_case;
+mod delete_on_call_schedule_use_case;
+mod get_current_on_call_use_case;
+mod get_on_call_schedule_use_case;
+mod list_on_call_schedules_use_case;
+mod on_call_users;
+mod update_on_call_schedule_use_case;
+
+pub use create_on_call_override_use_case::*;
+pub use create_on_call_schedule_use_case::*;
+pub use delete_on_call_override_use_case::*;
+pub use delete_on_call_schedule_use_case::*;
+pub use get_current_on_call_use_case::*;
+pub use get_on_call_schedule_use_case::*;
+pub use list_on_call_schedules_use_case::*;
+pub use on_call_users::*;
+pub use update_on_call_schedule_use_case::*;
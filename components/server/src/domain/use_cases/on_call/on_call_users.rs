@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::ports::on_call_schedule_repository::OnCallScheduleRepository;
+
+/// Finds the users on call at a date for several schedules, without duplicates.
+/// Schedules that do not exist (e.g. because they have been deleted) are ignored
+pub async fn find_on_call_user_ids<OCR: OnCallScheduleRepository>(
+    tx: &mut OCR::Transaction,
+    repository: &OCR,
+    organization_id: Uuid,
+    on_call_schedule_ids: &[Uuid],
+    date: DateTime<Utc>,
+) -> anyhow::Result<Vec<Uuid>> {
+    let mut user_ids = vec![];
+    for schedule_id in on_call_schedule_ids {
+        let Some(schedule) = repository
+            .get_on_call_schedule(tx, organization_id, *schedule_id)
+            .await?
+        else {
+            continue;
+        };
+        let overrides = repository
+            .list_on_call_overrides_ending_after(tx, organization_id, *schedule_id, date)
+            .await?;
+        for user_id in schedule.on_call_user_ids(&overrides, date) {
+            if !user_ids.contains(&user_id) {
+                user_ids.push(user_id);
+            }
+        }
+    }
+    Ok(user_ids)
+}
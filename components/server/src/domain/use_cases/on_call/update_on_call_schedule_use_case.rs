@@ -0,0 +1,72 @@
+use serde::Deserialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        on_call_schedule::{validate_on_call_schedule, OnCallRotations},
+    },
+    ports::on_call_schedule_repository::{NewOnCallSchedule, OnCallScheduleRepository},
+};
+
+#[derive(Deserialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct UpdateOnCallScheduleCommand {
+    pub name: String,
+    pub description: Option<String>,
+    pub time_zone: String,
+    #[serde(default)]
+    pub rotations: OnCallRotations,
+}
+
+#[derive(Error, Debug)]
+pub enum UpdateOnCallScheduleError {
+    #[error("Failed to update an on-call schedule: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to update on-call schedules")]
+    Forbidden,
+    #[error("On-call schedule not found")]
+    NotFound,
+    #[error("Invalid on-call schedule: {0}")]
+    InvalidOnCallSchedule(String),
+}
+
+/// Replaces the rotations of an on-call schedule. The overrides of the schedule are kept
+pub async fn update_on_call_schedule(
+    auth_context: &AuthContext,
+    repository: &impl OnCallScheduleRepository,
+    on_call_schedule_id: Uuid,
+    command: UpdateOnCallScheduleCommand,
+) -> Result<(), UpdateOnCallScheduleError> {
+    if !auth_context.can(Permission::WriteOnCallSchedules) {
+        return Err(UpdateOnCallScheduleError::Forbidden);
+    }
+
+    validate_on_call_schedule(&command.name, &command.time_zone, &command.rotations)
+        .map_err(UpdateOnCallScheduleError::InvalidOnCallSchedule)?;
+
+    let mut tx = repository.begin_transaction().await?;
+    let updated = repository
+        .update_on_call_schedule(
+            &mut tx,
+            on_call_schedule_id,
+            NewOnCallSchedule {
+                organization_id: auth_context.active_organization_id,
+                name: command.name,
+                description: command.description,
+                time_zone: command.time_zone,
+                rotations: command.rotations,
+            },
+        )
+        .await?;
+    if !updated {
+        return Err(UpdateOnCallScheduleError::NotFound);
+    }
+    repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        status_page::{StatusPageComponents, StatusPageVisibility},
+    },
+    ports::{
+        http_monitor_repository::HttpMonitorRepository,
+        status_page_repository::{NewStatusPage, StatusPageRepository},
+        task_repository::TaskRepository,
+    },
+};
+
+use super::status_page_validation::{validate_status_page, StatusPageValidationError};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Deserialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateStatusPageCommand {
+    pub slug: String,
+    pub title: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub components: StatusPageComponents,
+    pub visibility: StatusPageVisibility,
+}
+
+#[derive(Serialize, TS, Clone, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateStatusPageResponse {
+    pub id: Uuid,
+}
+
+#[derive(Error, Debug)]
+pub enum CreateStatusPageError {
+    #[error("Failed to create a status page: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to create status pages")]
+    Forbidden,
+    #[error("Invalid status page: {0}")]
+    InvalidStatusPage(String),
+    #[error("The slug is already used by another status page")]
+    SlugAlreadyUsed,
+}
+
+impl From<StatusPageValidationError> for CreateStatusPageError {
+    fn from(error: StatusPageValidationError) -> Self {
+        match error {
+            StatusPageValidationError::Invalid(message) => Self::InvalidStatusPage(message),
+            StatusPageValidationError::SlugAlreadyUsed => Self::SlugAlreadyUsed,
+            StatusPageValidationError::TechnicalFailure(e) => Self::TechnicalFailure(e),
+        }
+    }
+}
+
+pub async fn create_status_page<SPR, HMR, TR>(
+    auth_context: &AuthContext,
+    status_page_repository: &SPR,
+    http_monitor_repository: &HMR,
+    task_repository: &TR,
+    command: CreateStatusPageCommand,
+) -> Result<CreateStatusPageResponse, CreateStatusPageError>
+where
+    SPR: StatusPageRepository,
+    HMR: HttpMonitorRepository<Transaction = SPR::Transaction>,
+    TR: TaskRepository<Transaction = SPR::Transaction>,
+{
+    if !auth_context.can(Permission::WriteStatusPages) {
+        return Err(CreateStatusPageError::Forbidden);
+    }
+
+    let status_page = NewStatusPage {
+        organization_id: auth_context.active_organization_id,
+        slug: command.slug,
+        title: command.title,
+        description: command.description,
+        components: command.components,
+        visibility: command.visibility,
+    };
+
+    let mut tx = status_page_repository.begin_transaction().await?;
+    validate_status_page(
+        &mut tx,
+        status_page_repository,
+        http_monitor_repository,
+        task_repository,
+        None,
+        &status_page,
+    )
+    .await?;
+    let id = status_page_repository
+        .create_status_page(&mut tx, status_page)
+        .await?;
+    status_page_repository.commit_transaction(tx).await?;
+
+    Ok(CreateStatusPageResponse { id })
+}
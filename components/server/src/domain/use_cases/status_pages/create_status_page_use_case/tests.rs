@@ -0,0 +1,146 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        authorization::AuthContext,
+        organization::OrganizationUserRole,
+        status_page::{StatusPageComponent, StatusPageComponents, StatusPageVisibility},
+        task::TaskId,
+    },
+    infrastructure::mocks::{
+        http_monitor_repository_mock::HttpMonitorRepositoryMock,
+        status_page_repository_mock::StatusPageRepositoryMock,
+        task_repository_mock::TaskRepositoryMock,
+    },
+};
+
+use super::{create_status_page, CreateStatusPageCommand, CreateStatusPageError};
+
+fn command(slug: &str, components: Vec<StatusPageComponent>) -> CreateStatusPageCommand {
+    CreateStatusPageCommand {
+        slug: slug.to_string(),
+        title: "Acme status".to_string(),
+        description: None,
+        components: StatusPageComponents { items: components },
+        visibility: StatusPageVisibility::Public,
+    }
+}
+
+#[tokio::test]
+async fn test_create_status_page() -> anyhow::Result<()> {
+    let status_page_repository = StatusPageRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let auth_context =
+        AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+
+    let response = create_status_page(
+        &auth_context,
+        &status_page_repository,
+        &HttpMonitorRepositoryMock::new(),
+        &TaskRepositoryMock::new(),
+        command("acme", vec![]),
+    )
+    .await?;
+
+    let state = status_page_repository.state.lock().await;
+    assert_eq!(state.len(), 1);
+    assert_eq!(state[0].id, response.id);
+    assert_eq!(state[0].organization_id, org_id);
+    assert_eq!(state[0].slug, "acme");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_status_page_requires_write_permission() {
+    let status_page_repository = StatusPageRepositoryMock::new();
+    let auth_context = AuthContext::test_context(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Reporter],
+        &[],
+    );
+
+    let result = create_status_page(
+        &auth_context,
+        &status_page_repository,
+        &HttpMonitorRepositoryMock::new(),
+        &TaskRepositoryMock::new(),
+        command("acme", vec![]),
+    )
+    .await;
+
+    assert!(matches!(result, Err(CreateStatusPageError::Forbidden)));
+    assert!(status_page_repository.state.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_create_status_page_with_used_slug() -> anyhow::Result<()> {
+    let status_page_repository = StatusPageRepositoryMock::new();
+    let editor = |org_id| {
+        AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[])
+    };
+
+    create_status_page(
+        &editor(Uuid::new_v4()),
+        &status_page_repository,
+        &HttpMonitorRepositoryMock::new(),
+        &TaskRepositoryMock::new(),
+        command("acme", vec![]),
+    )
+    .await?;
+
+    // slugs are unique across organizations
+    let result = create_status_page(
+        &editor(Uuid::new_v4()),
+        &status_page_repository,
+        &HttpMonitorRepositoryMock::new(),
+        &TaskRepositoryMock::new(),
+        command("acme", vec![]),
+    )
+    .await;
+
+    assert!(matches!(result, Err(CreateStatusPageError::SlugAlreadyUsed)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_status_page_validation() {
+    let auth_context =
+        AuthContext::test_context(Uuid::new_v4(), Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+    let create = |command| async {
+        create_status_page(
+            &auth_context,
+            &StatusPageRepositoryMock::new(),
+            &HttpMonitorRepositoryMock::new(),
+            &TaskRepositoryMock::new(),
+            command,
+        )
+        .await
+    };
+
+    let result = create(command("Acme Status", vec![])).await;
+    assert!(matches!(result, Err(CreateStatusPageError::InvalidStatusPage(_))));
+
+    // components cannot reference monitors or tasks that do not belong to the organization
+    let result = create(command(
+        "acme",
+        vec![StatusPageComponent {
+            name: "API".to_string(),
+            http_monitor_ids: vec![Uuid::new_v4()],
+            task_ids: vec![],
+        }],
+    ))
+    .await;
+    assert!(matches!(result, Err(CreateStatusPageError::InvalidStatusPage(_))));
+
+    let result = create(command(
+        "acme",
+        vec![StatusPageComponent {
+            name: "Backups".to_string(),
+            http_monitor_ids: vec![],
+            task_ids: vec![TaskId::new("nightly-backup".to_string()).unwrap()],
+        }],
+    ))
+    .await;
+    assert!(matches!(result, Err(CreateStatusPageError::InvalidStatusPage(_))));
+}
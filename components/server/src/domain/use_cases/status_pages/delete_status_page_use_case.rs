@@ -0,0 +1,38 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::authorization::{AuthContext, Permission},
+    ports::status_page_repository::StatusPageRepository,
+};
+
+#[derive(Error, Debug)]
+pub enum DeleteStatusPageError {
+    #[error("Failed to delete a status page: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to delete status pages")]
+    Forbidden,
+    #[error("Status page not found")]
+    NotFound,
+}
+
+pub async fn delete_status_page(
+    auth_context: &AuthContext,
+    repository: &impl StatusPageRepository,
+    status_page_id: Uuid,
+) -> Result<(), DeleteStatusPageError> {
+    if !auth_context.can(Permission::WriteStatusPages) {
+        return Err(DeleteStatusPageError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let deleted = repository
+        .delete_status_page(&mut tx, auth_context.active_organization_id, status_page_id)
+        .await?;
+    if !deleted {
+        return Err(DeleteStatusPageError::NotFound);
+    }
+    repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
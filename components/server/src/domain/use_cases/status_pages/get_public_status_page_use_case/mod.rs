@@ -0,0 +1,217 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::{
+        incident::{IncidentPriority, IncidentSource, IncidentSourceType, IncidentStatus},
+        status_page::{
+            compute_uptime_bars, StatusPageComponentStatus, StatusPageUptimeBar,
+            StatusPageVisibility, STATUS_PAGE_UPTIME_DAYS,
+        },
+    },
+    ports::{
+        http_monitor_repository::HttpMonitorRepository,
+        incident_repository::{IncidentRepository, ListIncidentsOpts},
+        status_page_repository::StatusPageRepository,
+        task_repository::TaskRepository,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The maximum number of incidents that are taken into account to compute the uptime of a status page
+const MAXIMUM_STATUS_PAGE_INCIDENTS: u32 = 10_000;
+
+/// The public view of a status page.
+/// It only exposes the name and the health of the components, never the monitored URLs, the metadata or the members of the organization
+#[derive(Serialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct PublicStatusPage {
+    pub title: String,
+    pub description: Option<String>,
+    /// The most severe status of the components
+    pub status: StatusPageComponentStatus,
+    pub components: Vec<PublicStatusPageComponent>,
+    pub ongoing_incidents: Vec<PublicStatusPageIncident>,
+}
+
+#[derive(Serialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct PublicStatusPageComponent {
+    pub name: String,
+    pub status: StatusPageComponentStatus,
+    /// The daily uptime of the component, from the oldest to the most recent day
+    pub uptime_bars: Vec<StatusPageUptimeBar>,
+}
+
+#[derive(Serialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct PublicStatusPageIncident {
+    pub started_at: DateTime<Utc>,
+    pub priority: IncidentPriority,
+    /// The names of the components affected by the incident
+    pub components: Vec<String>,
+}
+
+/// The statuses of the monitors and the tasks of a component, along with when they started to be monitored
+struct ComponentHealth {
+    name: String,
+    statuses: Vec<StatusPageComponentStatus>,
+    sources: Vec<IncidentSource>,
+    monitored_since: Option<DateTime<Utc>>,
+}
+
+#[derive(Error, Debug)]
+pub enum GetPublicStatusPageError {
+    #[error("Status page not found")]
+    NotFound,
+    #[error("Technical failure occured while getting a public status page")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Gets the current status of a public status page. This use case does not require to be authenticated
+pub async fn get_public_status_page<SPR, HMR, TR, IR>(
+    status_page_repository: &SPR,
+    http_monitor_repository: &HMR,
+    task_repository: &TR,
+    incident_repository: &IR,
+    slug: &str,
+) -> Result<PublicStatusPage, GetPublicStatusPageError>
+where
+    SPR: StatusPageRepository,
+    HMR: HttpMonitorRepository<Transaction = SPR::Transaction>,
+    TR: TaskRepository<Transaction = SPR::Transaction>,
+    IR: IncidentRepository<Transaction = SPR::Transaction>,
+{
+    let now = Utc::now();
+    let mut tx = status_page_repository.begin_transaction().await?;
+    let status_page = status_page_repository
+        .get_status_page_by_slug(&mut tx, slug)
+        .await?
+        .filter(|status_page| status_page.visibility == StatusPageVisibility::Public)
+        .ok_or(GetPublicStatusPageError::NotFound)?;
+    let organization_id = status_page.organization_id;
+
+    let mut components = vec![];
+    for component in status_page.components.items {
+        let mut statuses = vec![];
+        let mut sources = vec![];
+        let mut monitored_since: Option<DateTime<Utc>> = None;
+
+        for monitor_id in &component.http_monitor_ids {
+            if let Some(monitor) = http_monitor_repository
+                .get_http_monitor(&mut tx, organization_id, *monitor_id)
+                .await?
+            {
+                statuses.push(StatusPageComponentStatus::from_http_monitor_status(monitor.status));
+                sources.push(IncidentSource::HttpMonitor { id: monitor.id });
+                monitored_since = Some(monitored_since.map_or(monitor.created_at, |since| since.min(monitor.created_at)));
+            }
+        }
+        for task_id in &component.task_ids {
+            if let Some(task) = task_repository
+                .get_task(&mut tx, organization_id, task_id)
+                .await?
+            {
+                statuses.push(StatusPageComponentStatus::from_task_status(task.status));
+                sources.push(IncidentSource::Task { id: task.uuid });
+                monitored_since = Some(monitored_since.map_or(task.created_at, |since| since.min(task.created_at)));
+            }
+        }
+
+        components.push(ComponentHealth {
+            name: component.name,
+            statuses,
+            sources,
+            monitored_since,
+        });
+    }
+
+    // An empty list of sources would match the incidents of all the sources of the organization
+    let all_sources = components
+        .iter()
+        .flat_map(|component| component.sources.iter().cloned())
+        .collect::<Vec<_>>();
+    let incidents = if all_sources.is_empty() {
+        vec![]
+    } else {
+        incident_repository
+            .list_incidents(
+                &mut tx,
+                organization_id,
+                ListIncidentsOpts {
+                    // unconfirmed incidents are not published, so that flapping monitors do not show false outages
+                    include_statuses: &[IncidentStatus::Ongoing, IncidentStatus::Resolved],
+                    include_priorities: &IncidentPriority::ALL,
+                    include_sources: &all_sources,
+                    from_date: Some(now - Duration::days(STATUS_PAGE_UPTIME_DAYS)),
+                    limit: MAXIMUM_STATUS_PAGE_INCIDENTS,
+                    ..Default::default()
+                },
+            )
+            .await?
+            .incidents
+    };
+    status_page_repository.rollback_transaction(tx).await?;
+
+    let incident_source = |incident_source_type, id| match incident_source_type {
+        IncidentSourceType::HttpMonitor => IncidentSource::HttpMonitor { id },
+        IncidentSourceType::Task => IncidentSource::Task { id },
+        IncidentSourceType::Manual => IncidentSource::Manual { id },
+        IncidentSourceType::TlsCertificate => IncidentSource::TlsCertificate { id },
+        IncidentSourceType::ExternalAlert => IncidentSource::ExternalAlert { id },
+    };
+
+    let ongoing_incidents = incidents
+        .iter()
+        .filter(|incident| incident.status == IncidentStatus::Ongoing)
+        .map(|incident| {
+            let source = incident_source(incident.incident_source_type, incident.incident_source_id);
+            PublicStatusPageIncident {
+                started_at: incident.created_at,
+                priority: incident.priority,
+                components: components
+                    .iter()
+                    .filter(|component| component.sources.contains(&source))
+                    .map(|component| component.name.clone())
+                    .collect(),
+            }
+        })
+        .collect();
+
+    let components = components
+        .into_iter()
+        .map(|component| {
+            let downtimes = incidents
+                .iter()
+                .filter(|incident| {
+                    component.sources.contains(&incident_source(
+                        incident.incident_source_type,
+                        incident.incident_source_id,
+                    ))
+                })
+                .map(|incident| (incident.created_at, incident.resolved_at))
+                .collect::<Vec<_>>();
+            PublicStatusPageComponent {
+                name: component.name,
+                status: StatusPageComponentStatus::worst(component.statuses),
+                uptime_bars: compute_uptime_bars(component.monitored_since, &downtimes, now),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(PublicStatusPage {
+        title: status_page.title,
+        description: status_page.description,
+        status: StatusPageComponentStatus::worst(components.iter().map(|c| c.status)),
+        components,
+        ongoing_incidents,
+    })
+}
@@ -0,0 +1,201 @@
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        entity_metadata::EntityMetadata,
+        http_monitor::{HttpMonitor, HttpMonitorErrorKind, HttpMonitorStatus, RequestHeaders},
+        incident::{Incident, IncidentPriority, IncidentSourceType, IncidentStatus},
+        status_page::{
+            StatusPage, StatusPageComponent, StatusPageComponentStatus, StatusPageComponents,
+            StatusPageVisibility,
+        },
+        tls_certificate::DEFAULT_TLS_EXPIRY_WARNING_DAYS,
+    },
+    infrastructure::mocks::{
+        http_monitor_repository_mock::HttpMonitorRepositoryMock,
+        incident_repository_mock::IncidentRepositoryMock,
+        status_page_repository_mock::StatusPageRepositoryMock,
+        task_repository_mock::TaskRepositoryMock,
+    },
+};
+
+use super::{get_public_status_page, GetPublicStatusPageError};
+
+fn create_test_monitor(org_id: Uuid, status: HttpMonitorStatus) -> HttpMonitor {
+    HttpMonitor {
+        id: Uuid::new_v4(),
+        organization_id: org_id,
+        created_at: Utc::now() - Duration::days(2),
+        url: "https://internal.example.com".to_string(),
+        first_ping_at: None,
+        next_ping_at: Some(Utc::now()),
+        last_ping_at: None,
+        last_status_change_at: Utc::now(),
+        recovery_confirmation_threshold: 3,
+        downtime_confirmation_threshold: 3,
+        interval_seconds: 60,
+        last_http_code: None,
+        status,
+        status_counter: 0,
+        error_kind: HttpMonitorErrorKind::None,
+        metadata: EntityMetadata::default(),
+        email_notification_enabled: true,
+        push_notification_enabled: true,
+        sms_notification_enabled: false,
+        archived_at: None,
+        request_headers: RequestHeaders::default(),
+        request_timeout_ms: 2000,
+        assertions: Default::default(),
+        locations: vec![],
+        location_quorum: None,
+        request_method: Default::default(),
+        request_body: None,
+        request_body_content_type: None,
+        authentication: Default::default(),
+        redirect_policy: Default::default(),
+        probe_engine: Default::default(),
+        tls_certificate: Default::default(),
+        tls_expiry_warning_days: DEFAULT_TLS_EXPIRY_WARNING_DAYS,
+        kind_settings: Default::default(),
+    }
+}
+
+fn create_test_status_page(
+    org_id: Uuid,
+    visibility: StatusPageVisibility,
+    components: Vec<StatusPageComponent>,
+) -> StatusPage {
+    StatusPage {
+        organization_id: org_id,
+        id: Uuid::new_v4(),
+        slug: "acme".to_string(),
+        title: "Acme status".to_string(),
+        description: None,
+        components: StatusPageComponents { items: components },
+        visibility,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_get_public_status_page() -> anyhow::Result<()> {
+    let status_page_repository = StatusPageRepositoryMock::new();
+    let http_monitor_repository = HttpMonitorRepositoryMock::new();
+    let incident_repository = IncidentRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+
+    let api_monitor = create_test_monitor(org_id, HttpMonitorStatus::Down);
+    let website_monitor = create_test_monitor(org_id, HttpMonitorStatus::Up);
+    let website_backup_monitor = create_test_monitor(org_id, HttpMonitorStatus::Suspicious);
+    http_monitor_repository.state.lock().await.extend([
+        api_monitor.clone(),
+        website_monitor.clone(),
+        website_backup_monitor.clone(),
+    ]);
+    incident_repository.state.lock().await.push(Incident {
+        organization_id: org_id,
+        id: Uuid::new_v4(),
+        created_at: Utc::now() - Duration::minutes(30),
+        created_by: None,
+        resolved_at: None,
+        cause: None,
+        status: IncidentStatus::Ongoing,
+        priority: IncidentPriority::Major,
+        incident_source_type: IncidentSourceType::HttpMonitor,
+        incident_source_id: api_monitor.id,
+        acknowledged_by: vec![Uuid::new_v4()],
+        title: None,
+        metadata: EntityMetadata::default(),
+    });
+    // the backup monitor is suspicious, its incident is not confirmed yet
+    incident_repository.state.lock().await.push(Incident {
+        organization_id: org_id,
+        id: Uuid::new_v4(),
+        created_at: Utc::now() - Duration::minutes(10),
+        created_by: None,
+        resolved_at: None,
+        cause: None,
+        status: IncidentStatus::ToBeConfirmed,
+        priority: IncidentPriority::Major,
+        incident_source_type: IncidentSourceType::HttpMonitor,
+        incident_source_id: website_backup_monitor.id,
+        acknowledged_by: vec![],
+        title: None,
+        metadata: EntityMetadata::default(),
+    });
+    status_page_repository
+        .state
+        .lock()
+        .await
+        .push(create_test_status_page(
+            org_id,
+            StatusPageVisibility::Public,
+            vec![
+                StatusPageComponent {
+                    name: "API".to_string(),
+                    http_monitor_ids: vec![api_monitor.id],
+                    task_ids: vec![],
+                },
+                StatusPageComponent {
+                    name: "Website".to_string(),
+                    http_monitor_ids: vec![website_monitor.id, website_backup_monitor.id],
+                    task_ids: vec![],
+                },
+            ],
+        ));
+
+    let status_page = get_public_status_page(
+        &status_page_repository,
+        &http_monitor_repository,
+        &TaskRepositoryMock::new(),
+        &incident_repository,
+        "acme",
+    )
+    .await?;
+
+    assert_eq!(status_page.status, StatusPageComponentStatus::Outage);
+    assert_eq!(status_page.components.len(), 2);
+    assert_eq!(status_page.components[0].status, StatusPageComponentStatus::Outage);
+    assert_eq!(status_page.components[1].status, StatusPageComponentStatus::Degraded);
+
+    let api_uptime_today = status_page.components[0].uptime_bars.last().unwrap();
+    assert!(api_uptime_today.downtime_seconds >= 30 * 60);
+    let website_uptime_today = status_page.components[1].uptime_bars.last().unwrap();
+    assert_eq!(website_uptime_today.downtime_seconds, 0);
+
+    assert_eq!(status_page.ongoing_incidents.len(), 1);
+    assert_eq!(status_page.ongoing_incidents[0].components, vec!["API".to_string()]);
+
+    // internal details must not be exposed
+    let json = serde_json::to_string(&status_page)?;
+    assert!(!json.contains("internal.example.com"));
+    assert!(!json.contains(&api_monitor.id.to_string()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_private_status_page() {
+    let status_page_repository = StatusPageRepositoryMock::new();
+    status_page_repository
+        .state
+        .lock()
+        .await
+        .push(create_test_status_page(
+            Uuid::new_v4(),
+            StatusPageVisibility::Private,
+            vec![],
+        ));
+
+    let result = get_public_status_page(
+        &status_page_repository,
+        &HttpMonitorRepositoryMock::new(),
+        &TaskRepositoryMock::new(),
+        &IncidentRepositoryMock::new(),
+        "acme",
+    )
+    .await;
+
+    assert!(matches!(result, Err(GetPublicStatusPageError::NotFound)));
+}
@@ -0,0 +1,36 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        status_page::StatusPage,
+    },
+    ports::status_page_repository::StatusPageRepository,
+};
+
+#[derive(Error, Debug)]
+pub enum GetStatusPageError {
+    #[error("User is not allowed to read status pages")]
+    Forbidden,
+    #[error("Status page not found")]
+    NotFound,
+    #[error("Technical failure occured while getting a status page")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+pub async fn get_status_page(
+    auth_context: &AuthContext,
+    repository: &impl StatusPageRepository,
+    status_page_id: Uuid,
+) -> Result<StatusPage, GetStatusPageError> {
+    if !auth_context.can(Permission::ReadStatusPages) {
+        return Err(GetStatusPageError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    repository
+        .get_status_page(&mut tx, auth_context.active_organization_id, status_page_id)
+        .await?
+        .ok_or(GetStatusPageError::NotFound)
+}
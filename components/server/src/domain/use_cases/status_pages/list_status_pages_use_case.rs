@@ -0,0 +1,43 @@
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        status_page::StatusPage,
+    },
+    ports::status_page_repository::StatusPageRepository,
+};
+
+#[derive(Serialize, TS, ToSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ListStatusPagesResponse {
+    pub status_pages: Vec<StatusPage>,
+}
+
+#[derive(Error, Debug)]
+pub enum ListStatusPagesError {
+    #[error("User is not allowed to list status pages")]
+    Forbidden,
+    #[error("Technical failure occured while listing status pages")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+pub async fn list_status_pages(
+    auth_context: &AuthContext,
+    repository: &impl StatusPageRepository,
+) -> Result<ListStatusPagesResponse, ListStatusPagesError> {
+    if !auth_context.can(Permission::ReadStatusPages) {
+        return Err(ListStatusPagesError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let status_pages = repository
+        .list_status_pages(&mut tx, auth_context.active_organization_id)
+        .await?;
+
+    Ok(ListStatusPagesResponse { status_pages })
+}
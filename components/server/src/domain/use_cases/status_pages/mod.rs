@@ -0,0 +1,14 @@
+mod create_status_page_use_case;
+mod delete_status_page_use_case;
+mod get_public_status_page_use_case;
+mod get_status_page_use_case;
+mod list_status_pages_use_case;
+mod status_page_validation;
+mod update_status_page_use_case;
+
+pub use create_status_page_use_case::*;
+pub use delete_status_page_use_case::*;
+pub use get_public_status_page_use_case::*;
+pub use get_status_page_use_case::*;
+pub use list_status_pages_use_case::*;
+pub use update_status_page_use_case::*;
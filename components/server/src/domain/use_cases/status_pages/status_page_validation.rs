@@ -0,0 +1,84 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::status_page::validate_status_page_slug,
+    ports::{
+        http_monitor_repository::HttpMonitorRepository,
+        status_page_repository::{NewStatusPage, StatusPageRepository},
+        task_repository::TaskRepository,
+    },
+};
+
+#[derive(Error, Debug)]
+pub(super) enum StatusPageValidationError {
+    #[error("{0}")]
+    Invalid(String),
+    #[error("The slug is already used by another status page")]
+    SlugAlreadyUsed,
+    #[error("{0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Checks a status page before it is saved.
+/// Components can only reference the HTTP monitors and the tasks of the organization of the status page
+pub(super) async fn validate_status_page<SPR, HMR, TR>(
+    tx: &mut SPR::Transaction,
+    status_page_repository: &SPR,
+    http_monitor_repository: &HMR,
+    task_repository: &TR,
+    status_page_id: Option<Uuid>,
+    status_page: &NewStatusPage,
+) -> Result<(), StatusPageValidationError>
+where
+    SPR: StatusPageRepository,
+    HMR: HttpMonitorRepository<Transaction = SPR::Transaction>,
+    TR: TaskRepository<Transaction = SPR::Transaction>,
+{
+    validate_status_page_slug(&status_page.slug).map_err(StatusPageValidationError::Invalid)?;
+    if status_page.title.trim().is_empty() {
+        return Err(StatusPageValidationError::Invalid(
+            "The title of a status page cannot be empty".to_string(),
+        ));
+    }
+    status_page
+        .components
+        .validate()
+        .map_err(StatusPageValidationError::Invalid)?;
+
+    if let Some(existing) = status_page_repository
+        .get_status_page_by_slug(tx, &status_page.slug)
+        .await?
+    {
+        if Some(existing.id) != status_page_id {
+            return Err(StatusPageValidationError::SlugAlreadyUsed);
+        }
+    }
+
+    for component in &status_page.components.items {
+        for monitor_id in &component.http_monitor_ids {
+            if http_monitor_repository
+                .get_http_monitor(tx, status_page.organization_id, *monitor_id)
+                .await?
+                .is_none()
+            {
+                return Err(StatusPageValidationError::Invalid(format!(
+                    "HTTP monitor {monitor_id} does not exist"
+                )));
+            }
+        }
+        for task_id in &component.task_ids {
+            if task_repository
+                .get_task(tx, status_page.organization_id, task_id)
+                .await?
+                .is_none()
+            {
+                return Err(StatusPageValidationError::Invalid(format!(
+                    "Task {task_id} does not exist"
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use serde::Deserialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        status_page::{StatusPageComponents, StatusPageVisibility},
+    },
+    ports::{
+        http_monitor_repository::HttpMonitorRepository,
+        status_page_repository::{NewStatusPage, StatusPageRepository},
+        task_repository::TaskRepository,
+    },
+};
+
+use super::status_page_validation::{validate_status_page, StatusPageValidationError};
+
+#[derive(Deserialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct UpdateStatusPageCommand {
+    pub slug: String,
+    pub title: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub components: StatusPageComponents,
+    pub visibility: StatusPageVisibility,
+}
+
+#[derive(Error, Debug)]
+pub enum UpdateStatusPageError {
+    #[error("Failed to update a status page: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to update status pages")]
+    Forbidden,
+    #[error("Status page not found")]
+    NotFound,
+    #[error("Invalid status page: {0}")]
+    InvalidStatusPage(String),
+    #[error("The slug is already used by another status page")]
+    SlugAlreadyUsed,
+}
+
+impl From<StatusPageValidationError> for UpdateStatusPageError {
+    fn from(error: StatusPageValidationError) -> Self {
+        match error {
+            StatusPageValidationError::Invalid(message) => Self::InvalidStatusPage(message),
+            StatusPageValidationError::SlugAlreadyUsed => Self::SlugAlreadyUsed,
+            StatusPageValidationError::TechnicalFailure(e) => Self::TechnicalFailure(e),
+        }
+    }
+}
+
+pub async fn update_status_page<SPR, HMR, TR>(
+    auth_context: &AuthContext,
+    status_page_repository: &SPR,
+    http_monitor_repository: &HMR,
+    task_repository: &TR,
+    status_page_id: Uuid,
+    command: UpdateStatusPageCommand,
+) -> Result<(), UpdateStatusPageError>
+where
+    SPR: StatusPageRepository,
+    HMR: HttpMonitorRepository<Transaction = SPR::Transaction>,
+    TR: TaskRepository<Transaction = SPR::Transaction>,
+{
+    if !auth_context.can(Permission::WriteStatusPages) {
+        return Err(UpdateStatusPageError::Forbidden);
+    }
+
+    let status_page = NewStatusPage {
+        organization_id: auth_context.active_organization_id,
+        slug: command.slug,
+        title: command.title,
+        description: command.description,
+        components: command.components,
+        visibility: command.visibility,
+    };
+
+    let mut tx = status_page_repository.begin_transaction().await?;
+    validate_status_page(
+        &mut tx,
+        status_page_repository,
+        http_monitor_repository,
+        task_repository,
+        Some(status_page_id),
+        &status_page,
+    )
+    .await?;
+    let updated = status_page_repository
+        .update_status_page(&mut tx, status_page_id, status_page)
+        .await?;
+    if !updated {
+        return Err(UpdateStatusPageError::NotFound);
+    }
+    status_page_repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
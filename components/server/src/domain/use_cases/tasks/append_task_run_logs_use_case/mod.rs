@@ -0,0 +1,149 @@
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use thiserror::Error;
+use tracing::warn;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        task::TaskId,
+        task_run_event::{
+            sanitize_task_run_log_lines, task_run_log_lines_exceed_inline_size,
+            task_run_log_lines_to_text, TaskRunEvent, TaskRunEventPayload, TaskRunEventType,
+            TaskRunLogLine, TaskRunLogsPayload, TASK_RUN_LOGS_TAIL_LENGTH,
+        },
+    },
+    ports::{
+        file_storage::{FileStorage, FileStorageKey},
+        task_run_event_repository::TaskRunEventRepository,
+        task_run_repository::TaskRunRepository,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct AppendTaskRunLogsParams {
+    /// Tells apart the runs of the task that started at the same time
+    #[serde(default)]
+    pub run_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct AppendTaskRunLogsCommand {
+    /// The lines of output produced since the previous logs were sent, oldest first
+    pub lines: Vec<TaskRunLogLine>,
+}
+
+#[derive(Error, Debug)]
+pub enum AppendTaskRunLogsError {
+    #[error("Technical failure occured while appending task run logs")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("User is not allowed to write task runs")]
+    Forbidden,
+    #[error("Task run not found")]
+    NotFound,
+    #[error("Invalid logs: {0}")]
+    InvalidLogs(String),
+}
+
+/// Records lines of output of a task run.
+/// Lines that are too large to be stored in a task run event are stored in the file storage,
+/// and the event only keeps their last lines.
+/// Logs can still be sent after the run has finished, so that the last lines of output are not lost
+#[allow(clippy::too_many_arguments)]
+pub async fn append_task_run_logs<TRR, TRER, FS>(
+    auth_context: &AuthContext,
+    task_run_repository: &TRR,
+    task_run_event_repository: &TRER,
+    file_storage: &FS,
+    task_id: TaskId,
+    started_at: DateTime<Utc>,
+    run_id: Option<String>,
+    command: AppendTaskRunLogsCommand,
+) -> Result<(), AppendTaskRunLogsError>
+where
+    TRR: TaskRunRepository,
+    TRER: TaskRunEventRepository<Transaction = TRR::Transaction>,
+    FS: FileStorage,
+{
+    if !auth_context.can(Permission::WriteTaskRuns) {
+        return Err(AppendTaskRunLogsError::Forbidden);
+    }
+
+    let lines =
+        sanitize_task_run_log_lines(command.lines).map_err(AppendTaskRunLogsError::InvalidLogs)?;
+
+    let mut tx = task_run_repository.begin_transaction().await?;
+    let task_run = task_run_repository
+        .get_task_run(&mut tx, auth_context.active_organization_id, &task_id, started_at, run_id.as_deref())
+        .await?
+        .ok_or(AppendTaskRunLogsError::NotFound)?;
+
+    let payload = if task_run_log_lines_exceed_inline_size(&lines) {
+        let file_id = Uuid::new_v4();
+        file_storage
+            .store_file(
+                FileStorageKey {
+                    organization_id: auth_context.active_organization_id,
+                    file_id,
+                },
+                "text/plain; charset=utf-8",
+                task_run_log_lines_to_text(&lines).into_bytes(),
+            )
+            .await
+            .context("Failed to store task run logs")?;
+        let tail = lines[lines.len().saturating_sub(TASK_RUN_LOGS_TAIL_LENGTH)..].to_vec();
+        TaskRunLogsPayload {
+            lines: tail,
+            file_id: Some(file_id),
+        }
+    } else {
+        TaskRunLogsPayload {
+            lines,
+            file_id: None,
+        }
+    };
+
+    let stored_file = payload.file_id.map(|file_id| FileStorageKey {
+        organization_id: auth_context.active_organization_id,
+        file_id,
+    });
+    let result = async {
+        task_run_event_repository
+            .create_task_run_event(
+                &mut tx,
+                TaskRunEvent {
+                    organization_id: auth_context.active_organization_id,
+                    task_id,
+                    task_run_id: task_run.run_id,
+                    task_run_started_at: task_run.started_at,
+                    created_at: Utc::now(),
+                    event_type: TaskRunEventType::Logs,
+                    event_payload: Some(TaskRunEventPayload::Logs(payload)),
+                },
+            )
+            .await?;
+        task_run_repository.commit_transaction(tx).await
+    }
+    .await;
+
+    // the stored logs would not be referenced by any event
+    if let (Err(_), Some(key)) = (&result, stored_file) {
+        if let Err(e) = file_storage.delete_file(key).await {
+            warn!(error = ?e, file = %key, "Failed to delete the logs of a task run event that was not recorded");
+        }
+    }
+    result?;
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use chrono::{SubsecRound, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            authorization::{AuthContext, Permission},
+            organization::OrganizationUserRole,
+            task::TaskId,
+            task_run::{BoundaryTaskRun, TaskRunStatus},
+            task_run_event::{
+                TaskRunEventPayload, TaskRunLogLine, TaskRunLogStream, TASK_RUN_LOGS_TAIL_LENGTH,
+            },
+        },
+        use_cases::tasks::{list_task_run_events, ListTaskRunEventsParams},
+    },
+    infrastructure::mocks::{
+        file_storage_mock::FileStorageMock,
+        task_run_event_repository_mock::TaskRunEventRepositoryMock,
+        task_run_repository_mock::TaskRunRepositoryMock,
+    },
+};
+
+use super::{append_task_run_logs, AppendTaskRunLogsCommand, AppendTaskRunLogsError};
+
+async fn create_task_run(repository: &TaskRunRepositoryMock, organization_id: Uuid) -> BoundaryTaskRun {
+    let task_run = BoundaryTaskRun {
+        organization_id,
+        task_id: TaskId::new("nightly-backup".to_string()).unwrap(),
+        run_id: "first".to_string(),
+        status: TaskRunStatus::Running,
+        started_at: Utc::now().trunc_subsecs(6),
+        updated_at: Utc::now(),
+        completed_at: None,
+        exit_code: None,
+        error_message: None,
+        last_heartbeat_at: None,
+        heartbeat_timeout_seconds: 60,
+    };
+    repository.state.lock().await.push(task_run.clone());
+    task_run
+}
+
+fn lines(count: usize, length: usize) -> Vec<TaskRunLogLine> {
+    (0..count)
+        .map(|i| TaskRunLogLine {
+            stream: if i % 2 == 0 { TaskRunLogStream::Stdout } else { TaskRunLogStream::Stderr },
+            line: format!("{i:0>length$}"),
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_append_and_list_task_run_logs() -> anyhow::Result<()> {
+    let task_run_repository = TaskRunRepositoryMock::new();
+    let task_run_event_repository = TaskRunEventRepositoryMock::new();
+    let organization_id = Uuid::new_v4();
+    let auth_context =
+        AuthContext::test_context(organization_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+    let task_run = create_task_run(&task_run_repository, organization_id).await;
+
+    for _ in 0..2 {
+        append_task_run_logs(
+            &auth_context,
+            &task_run_repository,
+            &task_run_event_repository,
+            &FileStorageMock,
+            task_run.task_id.clone(),
+            task_run.started_at,
+            Some(task_run.run_id.clone()),
+            AppendTaskRunLogsCommand { lines: lines(3, 10) },
+        )
+        .await?;
+    }
+
+    let response = list_task_run_events(
+        &auth_context,
+        &task_run_repository,
+        &task_run_event_repository,
+        task_run.task_id.clone(),
+        task_run.started_at,
+        ListTaskRunEventsParams {
+            run_id: None,
+            page_number: Some(1),
+            items_per_page: Some(1),
+        },
+    )
+    .await?;
+    assert_eq!(response.total_events, 2);
+    assert_eq!(response.events.len(), 1);
+    let Some(TaskRunEventPayload::Logs(logs)) = &response.events[0].event_payload else {
+        panic!("unexpected payload: {:?}", response.events[0].event_payload);
+    };
+    assert_eq!(logs.lines, lines(3, 10));
+    assert_eq!(logs.file_id, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_append_task_run_logs_to_run_with_same_start_date() -> anyhow::Result<()> {
+    let task_run_repository = TaskRunRepositoryMock::new();
+    let task_run_event_repository = TaskRunEventRepositoryMock::new();
+    let organization_id = Uuid::new_v4();
+    let auth_context =
+        AuthContext::test_context(organization_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+    let other_run = BoundaryTaskRun {
+        run_id: "second".to_string(),
+        ..create_task_run(&task_run_repository, organization_id).await
+    };
+    task_run_repository.state.lock().await.push(other_run.clone());
+
+    append_task_run_logs(
+        &auth_context,
+        &task_run_repository,
+        &task_run_event_repository,
+        &FileStorageMock,
+        other_run.task_id.clone(),
+        other_run.started_at,
+        Some(other_run.run_id.clone()),
+        AppendTaskRunLogsCommand { lines: lines(3, 10) },
+    )
+    .await?;
+
+    let events = task_run_event_repository.state.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].task_run_id, other_run.run_id);
+    assert_eq!(events[0].task_run_started_at, other_run.started_at);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_append_large_task_run_logs_keeps_tail() -> anyhow::Result<()> {
+    let task_run_repository = TaskRunRepositoryMock::new();
+    let task_run_event_repository = TaskRunEventRepositoryMock::new();
+    let organization_id = Uuid::new_v4();
+    let auth_context =
+        AuthContext::test_context(organization_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+    let task_run = create_task_run(&task_run_repository, organization_id).await;
+
+    let all_lines = lines(500, 100);
+    append_task_run_logs(
+        &auth_context,
+        &task_run_repository,
+        &task_run_event_repository,
+        &FileStorageMock,
+        task_run.task_id.clone(),
+        task_run.started_at,
+        None,
+        AppendTaskRunLogsCommand { lines: all_lines.clone() },
+    )
+    .await?;
+
+    let events = task_run_event_repository.state.lock().await;
+    let Some(TaskRunEventPayload::Logs(logs)) = &events[0].event_payload else {
+        panic!("unexpected payload: {:?}", events[0].event_payload);
+    };
+    assert!(logs.file_id.is_some());
+    assert_eq!(logs.lines, all_lines[500 - TASK_RUN_LOGS_TAIL_LENGTH..]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_append_task_run_logs_errors() -> anyhow::Result<()> {
+    let task_run_repository = TaskRunRepositoryMock::new();
+    let task_run_event_repository = TaskRunEventRepositoryMock::new();
+    let organization_id = Uuid::new_v4();
+    let task_run = create_task_run(&task_run_repository, organization_id).await;
+    let append = |auth_context: AuthContext, run_id: &str, lines| {
+        let task_run_repository = task_run_repository.clone();
+        let task_run_event_repository = task_run_event_repository.clone();
+        let task_id = task_run.task_id.clone();
+        let started_at = task_run.started_at;
+        let run_id = Some(run_id.to_string());
+        async move {
+            append_task_run_logs(
+                &auth_context,
+                &task_run_repository,
+                &task_run_event_repository,
+                &FileStorageMock,
+                task_id,
+                started_at,
+                run_id,
+                AppendTaskRunLogsCommand { lines },
+            )
+            .await
+        }
+    };
+
+    let reader =
+        AuthContext::test_context(organization_id, Uuid::new_v4(), &[OrganizationUserRole::Reporter], &[]);
+    let result = append(reader, &task_run.run_id, lines(1, 10)).await;
+    assert!(matches!(result, Err(AppendTaskRunLogsError::Forbidden)));
+
+    let editor = || {
+        AuthContext::test_context(
+            organization_id,
+            Uuid::new_v4(),
+            &[OrganizationUserRole::Editor],
+            &[Permission::WriteTaskRuns],
+        )
+    };
+    let result = append(editor(), &task_run.run_id, vec![]).await;
+    assert!(matches!(result, Err(AppendTaskRunLogsError::InvalidLogs(_))));
+
+    let result = append(editor(), "unknown", lines(1, 10)).await;
+    assert!(matches!(result, Err(AppendTaskRunLogsError::NotFound)));
+
+    assert!(task_run_event_repository.state.lock().await.is_empty());
+    Ok(())
+}
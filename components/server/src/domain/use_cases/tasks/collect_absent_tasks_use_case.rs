@@ -1,6 +1,17 @@
 use crate::domain::{
     entities::task::{from_boundary, save_task_aggregate, TaskAggregate},
-    ports::{task_repository::TaskRepository, task_run_repository::TaskRunRepository},
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository,
+        maintenance_window_repository::MaintenanceWindowRepository,
+        task_repository::TaskRepository, task_run_repository::TaskRunRepository,
+    },
+};
+
+use super::{
+    create_task_incident, find_failing_upstream_task, get_ongoing_task_incident,
+    record_skipped_downstream_task,
 };
 
 use anyhow::Context;
@@ -10,16 +21,24 @@ use tokio::task::JoinSet;
 use tracing::{error, info};
 
 #[derive(Clone)]
-pub struct CollectAbsentTasksUseCase<TR, TRR> {
+pub struct CollectAbsentTasksUseCase<TR, TRR, IR, IER, INR, MWR> {
     pub task_repository: TR,
     pub task_run_repository: TRR,
+    pub incident_repository: IR,
+    pub incident_event_repository: IER,
+    pub incident_notification_repository: INR,
+    pub maintenance_window_repository: MWR,
     pub select_limit: u32,
 }
 
-impl<TR, TRR> CollectAbsentTasksUseCase<TR, TRR>
+impl<TR, TRR, IR, IER, INR, MWR> CollectAbsentTasksUseCase<TR, TRR, IR, IER, INR, MWR>
 where
     TR: TaskRepository,
     TRR: TaskRunRepository<Transaction = TR::Transaction>,
+    IR: IncidentRepository<Transaction = TR::Transaction>,
+    IER: IncidentEventRepository<Transaction = TR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = TR::Transaction>,
+    MWR: MaintenanceWindowRepository<Transaction = TR::Transaction>,
 {
     pub fn spawn_tasks(
         &self,
@@ -68,32 +87,80 @@ where
 
         let now = Utc::now();
 
-        let task_aggregates: Vec<TaskAggregate> = self
+        let tasks = self
             .task_repository
             .list_next_absent_tasks(&mut transaction, now, self.select_limit)
             .await
-            .context("Failed to get absent tasks from the database")?
-            .into_iter()
-            .map(|task| from_boundary(task, None))
-            .collect::<anyhow::Result<Vec<_>>>()
-            .context("Failed to convert absent tasks from boundaries to task aggregates")?;
-        let task_aggregates_len = task_aggregates.len();
-
-        // turn every task aggregate into a failing one and save it
-        for aggregate in task_aggregates {
-            let absent_aggregate = match aggregate {
-                TaskAggregate::Late(agg) => agg.mark_absent(now).context("Failed to mark task aggregate as absent. This is likely a bug in the SQL query used to retrieve aggregates")?,
-                _ => anyhow::bail!("unexpected task aggregate type. This is likely a bug in the SQL query used to retrieve aggregates"),
+            .context("Failed to get absent tasks from the database")?;
+        let tasks_len = tasks.len();
+
+        // turn every task aggregate into an absent one and save it,
+        // unless the run of the task is missing because an upstream task failed
+        for task in tasks {
+            // the run is only skipped when it can be attributed to the incident of a failing upstream task,
+            // the upstream task may have no incident, e.g. when it fails during a maintenance window
+            let upstream_incident =
+                match find_failing_upstream_task(&mut transaction, &self.task_repository, &task).await? {
+                    Some(upstream_task) => {
+                        get_ongoing_task_incident(&mut transaction, &self.incident_repository, &upstream_task)
+                            .await?
+                    }
+                    None => None,
+                };
+            let due_at = task.next_due_at;
+            let aggregate = from_boundary(task, vec![])
+                .context("Failed to convert absent task from boundary to task aggregate")?;
+            let TaskAggregate::Late(late_aggregate) = aggregate else {
+                anyhow::bail!("unexpected task aggregate type. This is likely a bug in the SQL query used to retrieve aggregates");
             };
 
-            save_task_aggregate(
-                &self.task_repository,
-                &self.task_run_repository,
-                &mut transaction,
-                TaskAggregate::Absent(absent_aggregate),
-            )
-            .await
-            .context("Failed to save task aggregate")?;
+            match upstream_incident {
+                None => {
+                    let absent_aggregate = late_aggregate.mark_absent(now).context("Failed to mark task aggregate as absent. This is likely a bug in the SQL query used to retrieve aggregates")?;
+                    let (task, _) = save_task_aggregate(
+                        &self.task_repository,
+                        &self.task_run_repository,
+                        &mut transaction,
+                        TaskAggregate::Absent(absent_aggregate),
+                    )
+                    .await
+                    .context("Failed to save task aggregate")?;
+
+                    create_task_incident(
+                        &mut transaction,
+                        &self.incident_repository,
+                        &self.incident_event_repository,
+                        &self.incident_notification_repository,
+                        &self.maintenance_window_repository,
+                        &task,
+                        None,
+                        Vec::new(),
+                    )
+                    .await
+                    .context("Failed to create incident for absent task")?;
+                }
+                Some(upstream_incident) => {
+                    let healthy_aggregate = late_aggregate
+                        .skip(now)
+                        .context("Failed to skip the expected run of a downstream task")?;
+                    let (task, _) = save_task_aggregate(
+                        &self.task_repository,
+                        &self.task_run_repository,
+                        &mut transaction,
+                        TaskAggregate::Healthy(healthy_aggregate),
+                    )
+                    .await
+                    .context("Failed to save task aggregate")?;
+                    record_skipped_downstream_task(
+                        &mut transaction,
+                        &self.incident_event_repository,
+                        &upstream_incident,
+                        &task,
+                        due_at.unwrap_or(now),
+                    )
+                    .await?;
+                }
+            }
         }
 
         self.task_repository
@@ -101,6 +168,6 @@ where
             .await
             .context("Failed to commit transaction")?;
 
-        Ok(task_aggregates_len)
+        Ok(tasks_len)
     }
 }
@@ -1,8 +1,17 @@
 use crate::domain::{
-    entities::task::{from_boundary, save_task_aggregate, RunningTaskAggregate, TaskAggregate},
-    ports::{task_repository::TaskRepository, task_run_repository::TaskRunRepository},
+    entities::task::{get_task_aggregate_for_update, save_task_run_completion, TaskAggregate},
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository,
+        maintenance_window_repository::MaintenanceWindowRepository,
+        task_repository::TaskRepository, task_run_event_repository::TaskRunEventRepository,
+        task_run_repository::TaskRunRepository,
+    },
 };
 
+use super::{create_task_incident, get_task_run_output_tail};
+
 use anyhow::Context;
 use chrono::Utc;
 use std::time::Duration;
@@ -10,16 +19,26 @@ use tokio::task::JoinSet;
 use tracing::{error, info};
 
 #[derive(Clone)]
-pub struct CollectDeadTaskRunsUseCase<TR, TRR> {
+pub struct CollectDeadTaskRunsUseCase<TR, TRR, TRER, IR, IER, INR, MWR> {
     pub task_repository: TR,
     pub task_run_repository: TRR,
+    pub task_run_event_repository: TRER,
+    pub incident_repository: IR,
+    pub incident_event_repository: IER,
+    pub incident_notification_repository: INR,
+    pub maintenance_window_repository: MWR,
     pub select_limit: u32,
 }
 
-impl<TR, TRR> CollectDeadTaskRunsUseCase<TR, TRR>
+impl<TR, TRR, TRER, IR, IER, INR, MWR> CollectDeadTaskRunsUseCase<TR, TRR, TRER, IR, IER, INR, MWR>
 where
     TR: TaskRepository,
     TRR: TaskRunRepository<Transaction = TR::Transaction>,
+    TRER: TaskRunEventRepository<Transaction = TR::Transaction>,
+    IR: IncidentRepository<Transaction = TR::Transaction>,
+    IER: IncidentEventRepository<Transaction = TR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = TR::Transaction>,
+    MWR: MaintenanceWindowRepository<Transaction = TR::Transaction>,
 {
     pub fn spawn_tasks(
         &self,
@@ -66,40 +85,55 @@ where
         let mut transaction = self.task_repository.begin_transaction().await?;
         let now = Utc::now();
 
-        let task_aggregates: Vec<TaskAggregate> = self
+        let dead_task_runs = self
             .task_run_repository
             .list_dead_task_runs(&mut transaction, now, self.select_limit)
             .await
-            .context("Failed to get dead task runs from the database")?
-            .into_iter()
-            .map(|(task, task_run)| from_boundary(task, Some(task_run)))
-            .collect::<anyhow::Result<Vec<_>>>()
-            .context("Failed to convert dead task runs from boundaries to task aggregates")?;
-
-        let running_task_aggregates: Vec<RunningTaskAggregate> = task_aggregates
-            .into_iter()
-            .map(|agg| match agg {
-                TaskAggregate::Running(agg) => Ok(agg),
-                _ => Err(anyhow::anyhow!(
-                    "Found a non running task aggregate. This is likely a bug in the SQL query used to retrieve aggregates"
-                )),
-            })
-            .collect::<anyhow::Result<Vec<_>>>()?;
-
-        let running_task_aggregates_len = running_task_aggregates.len();
+            .context("Failed to get dead task runs from the database")?;
+        let mut collected_task_runs = 0;
 
-        // turn every running task aggregate into a failing one and save it
-        for running_task_aggregate in running_task_aggregates {
-            let failing_aggregate = running_task_aggregate.mark_dead(now).context("Failed to mark running task aggregate as dead. This is likely a bug in the SQL query used to retrieve aggregates")?;
+        // every dead task run is collected on its own: its task only becomes failing once none of its runs is running
+        for (task, task_run) in dead_task_runs {
+            let running_task_aggregate = match get_task_aggregate_for_update(
+                &self.task_repository,
+                &self.task_run_repository,
+                &mut transaction,
+                task.organization_id,
+                &task.id,
+            )
+            .await
+            .context("Failed to get task aggregate")?
+            {
+                Some(TaskAggregate::Running(agg)) if agg.is_dead(&task_run.run_id, now) => agg,
+                // the run completed or received a heartbeat since it was listed, e.g. it was finished or collected concurrently
+                _ => continue,
+            };
+            let completion = running_task_aggregate.mark_dead(&task_run.run_id, now).context("Failed to mark task run as dead")?;
 
-            save_task_aggregate(
+            let (task, task_run) = save_task_run_completion(
                 &self.task_repository,
                 &self.task_run_repository,
                 &mut transaction,
-                TaskAggregate::Failing(failing_aggregate),
+                completion,
             )
             .await
             .context("Failed to save task aggregate")?;
+
+            let output_tail =
+                get_task_run_output_tail(&mut transaction, &self.task_run_event_repository, &task_run).await?;
+            create_task_incident(
+                &mut transaction,
+                &self.incident_repository,
+                &self.incident_event_repository,
+                &self.incident_notification_repository,
+                &self.maintenance_window_repository,
+                &task,
+                Some(&task_run),
+                output_tail,
+            )
+            .await
+            .context("Failed to create incident for dead task run")?;
+            collected_task_runs += 1;
         }
 
         self.task_repository
@@ -107,6 +141,6 @@ where
             .await
             .context("Failed to commit transaction")?;
 
-        Ok(running_task_aggregates_len)
+        Ok(collected_task_runs)
     }
 }
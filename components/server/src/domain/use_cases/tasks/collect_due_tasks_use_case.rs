@@ -74,7 +74,7 @@ where
             .await
             .context("Failed to get due tasks from the database")?
             .into_iter()
-            .map(|task| from_boundary(task, None))
+            .map(|task| from_boundary(task, vec![]))
             .collect::<anyhow::Result<Vec<_>>>()
             .context("Failed to convert due tasks from boundaries to task aggregates")?;
         let task_aggregates_len = task_aggregates.len();
@@ -1,8 +1,14 @@
 use crate::domain::{
     entities::task::{from_boundary, save_task_aggregate, TaskAggregate},
-    ports::{task_repository::TaskRepository, task_run_repository::TaskRunRepository},
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_repository::IncidentRepository, task_repository::TaskRepository,
+        task_run_repository::TaskRunRepository,
+    },
 };
 
+use super::{find_failing_upstream_task, get_ongoing_task_incident, record_skipped_downstream_task};
+
 use anyhow::Context;
 use chrono::Utc;
 use std::time::Duration;
@@ -10,16 +16,20 @@ use tokio::task::JoinSet;
 use tracing::{error, info};
 
 #[derive(Clone)]
-pub struct CollectLateTasksUseCase<TR, TRR> {
+pub struct CollectLateTasksUseCase<TR, TRR, IR, IER> {
     pub task_repository: TR,
     pub task_run_repository: TRR,
+    pub incident_repository: IR,
+    pub incident_event_repository: IER,
     pub select_limit: u32,
 }
 
-impl<TR, TRR> CollectLateTasksUseCase<TR, TRR>
+impl<TR, TRR, IR, IER> CollectLateTasksUseCase<TR, TRR, IR, IER>
 where
     TR: TaskRepository,
     TRR: TaskRunRepository<Transaction = TR::Transaction>,
+    IR: IncidentRepository<Transaction = TR::Transaction>,
+    IER: IncidentEventRepository<Transaction = TR::Transaction>,
 {
     pub fn spawn_tasks(
         &self,
@@ -68,33 +78,68 @@ where
 
         let now = Utc::now();
 
-        let task_aggregates: Vec<TaskAggregate> = self
+        let tasks = self
             .task_repository
             .list_due_tasks_running_late(&mut transaction, now, self.select_limit)
             .await
-            .context("Failed to get late tasks from the database")?
-            .into_iter()
-            .map(|task| from_boundary(task, None))
-            .collect::<anyhow::Result<Vec<_>>>()
-            .context("Failed to convert late tasks from boundaries to task aggregates")?;
-
-        let task_aggregates_len = task_aggregates.len();
-
-        // turn every task aggregate into a failing one and save it
-        for aggregate in task_aggregates {
-            let late_aggregate = match aggregate {
-                TaskAggregate::Due(agg) => agg.mark_late(now).context("Failed to mark task aggregate as late. This is likely a bug in the SQL query used to retrieve aggregates")?,
-                _ => anyhow::bail!("unexpected task aggregate type. This is likely a bug in the SQL query used to retrieve aggregates"),
+            .context("Failed to get late tasks from the database")?;
+
+        let tasks_len = tasks.len();
+
+        // turn every task aggregate into a late one and save it,
+        // unless the run of the task is missing because an upstream task failed
+        for task in tasks {
+            // the run is only skipped when it can be attributed to the incident of a failing upstream task,
+            // the upstream task may have no incident, e.g. when it fails during a maintenance window
+            let upstream_incident =
+                match find_failing_upstream_task(&mut transaction, &self.task_repository, &task).await? {
+                    Some(upstream_task) => {
+                        get_ongoing_task_incident(&mut transaction, &self.incident_repository, &upstream_task)
+                            .await?
+                    }
+                    None => None,
+                };
+            let due_at = task.next_due_at;
+            let aggregate = from_boundary(task, vec![])
+                .context("Failed to convert late task from boundary to task aggregate")?;
+            let TaskAggregate::Due(due_aggregate) = aggregate else {
+                anyhow::bail!("unexpected task aggregate type. This is likely a bug in the SQL query used to retrieve aggregates");
             };
 
-            save_task_aggregate(
-                &self.task_repository,
-                &self.task_run_repository,
-                &mut transaction,
-                TaskAggregate::Late(late_aggregate),
-            )
-            .await
-            .context("Failed to save task aggregate")?;
+            match upstream_incident {
+                None => {
+                    let late_aggregate = due_aggregate.mark_late(now).context("Failed to mark task aggregate as late. This is likely a bug in the SQL query used to retrieve aggregates")?;
+                    save_task_aggregate(
+                        &self.task_repository,
+                        &self.task_run_repository,
+                        &mut transaction,
+                        TaskAggregate::Late(late_aggregate),
+                    )
+                    .await
+                    .context("Failed to save task aggregate")?;
+                }
+                Some(upstream_incident) => {
+                    let healthy_aggregate = due_aggregate
+                        .skip(now)
+                        .context("Failed to skip the expected run of a downstream task")?;
+                    let (task, _) = save_task_aggregate(
+                        &self.task_repository,
+                        &self.task_run_repository,
+                        &mut transaction,
+                        TaskAggregate::Healthy(healthy_aggregate),
+                    )
+                    .await
+                    .context("Failed to save task aggregate")?;
+                    record_skipped_downstream_task(
+                        &mut transaction,
+                        &self.incident_event_repository,
+                        &upstream_incident,
+                        &task,
+                        due_at.unwrap_or(now),
+                    )
+                    .await?;
+                }
+            }
         }
 
         self.task_repository
@@ -102,6 +147,6 @@ where
             .await
             .context("Failed to commit transaction")?;
 
-        Ok(task_aggregates_len)
+        Ok(tasks_len)
     }
 }
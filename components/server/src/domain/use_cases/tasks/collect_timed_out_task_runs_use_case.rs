@@ -0,0 +1,147 @@
+use crate::domain::{
+    entities::task::{get_task_aggregate_for_update, save_task_run_completion, TaskAggregate},
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository,
+        maintenance_window_repository::MaintenanceWindowRepository,
+        task_repository::TaskRepository, task_run_event_repository::TaskRunEventRepository,
+        task_run_repository::TaskRunRepository,
+    },
+};
+
+use super::{create_task_incident, get_task_run_output_tail};
+
+use anyhow::Context;
+use chrono::Utc;
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tracing::{error, info};
+
+#[derive(Clone)]
+pub struct CollectTimedOutTaskRunsUseCase<TR, TRR, TRER, IR, IER, INR, MWR> {
+    pub task_repository: TR,
+    pub task_run_repository: TRR,
+    pub task_run_event_repository: TRER,
+    pub incident_repository: IR,
+    pub incident_event_repository: IER,
+    pub incident_notification_repository: INR,
+    pub maintenance_window_repository: MWR,
+    pub select_limit: u32,
+}
+
+impl<TR, TRR, TRER, IR, IER, INR, MWR> CollectTimedOutTaskRunsUseCase<TR, TRR, TRER, IR, IER, INR, MWR>
+where
+    TR: TaskRepository,
+    TRR: TaskRunRepository<Transaction = TR::Transaction>,
+    TRER: TaskRunEventRepository<Transaction = TR::Transaction>,
+    IR: IncidentRepository<Transaction = TR::Transaction>,
+    IER: IncidentEventRepository<Transaction = TR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = TR::Transaction>,
+    MWR: MaintenanceWindowRepository<Transaction = TR::Transaction>,
+{
+    pub fn spawn_tasks(
+        &self,
+        n_tasks: usize,
+        delay_between_two_executions: Duration,
+    ) -> JoinSet<()> {
+        let mut join_set = JoinSet::new();
+        if n_tasks == 0 {
+            info!("No task will be spawned. You need to call the `run collect-timed-out-task-runs` command manually to collect timed out task runs");
+            return join_set;
+        }
+
+        for _ in 0..n_tasks {
+            let mut interval = tokio::time::interval(delay_between_two_executions);
+            let executor = self.clone();
+
+            join_set.spawn(async move {
+                loop {
+                    tokio::select! {
+                        _ = interval.tick() => {
+                            match executor.collect_timed_out_task_runs().await {
+                                Ok(timed_out_task_runs) if timed_out_task_runs > 0 => {
+                                    info!(timed_out_task_runs, "Collected {} timed out task runs", timed_out_task_runs);
+                                }
+                                Err(e) => {
+                                    error!(error = ?e, "Failed to clear timed out task runs")
+                                }
+                                Ok(_) => {}
+                            }
+                        }
+                        _ = tokio::signal::ctrl_c() => {
+                            info!("Shutting down timed out task runs collector task");
+                            break;
+                        }
+                    }
+                }
+            });
+        }
+
+        join_set
+    }
+
+    pub async fn collect_timed_out_task_runs(&self) -> anyhow::Result<usize> {
+        let mut transaction = self.task_repository.begin_transaction().await?;
+        let now = Utc::now();
+
+        let timed_out_task_runs = self
+            .task_run_repository
+            .list_timed_out_task_runs(&mut transaction, now, self.select_limit)
+            .await
+            .context("Failed to get timed out task runs from the database")?;
+        let mut collected_task_runs = 0;
+
+        // every timed out task run is collected on its own: its task only becomes failing once none of its runs is running.
+        // The task run may still be sending heartbeats: they are rejected from now on, which lets the CLI kill the process
+        for (task, task_run) in timed_out_task_runs {
+            let running_task_aggregate = match get_task_aggregate_for_update(
+                &self.task_repository,
+                &self.task_run_repository,
+                &mut transaction,
+                task.organization_id,
+                &task.id,
+            )
+            .await
+            .context("Failed to get task aggregate")?
+            {
+                Some(TaskAggregate::Running(agg)) if agg.is_timed_out(&task_run.run_id, now) => agg,
+                // the run completed or received a heartbeat since it was listed, e.g. it was finished or collected concurrently
+                _ => continue,
+            };
+            let completion = running_task_aggregate.mark_timed_out(&task_run.run_id, now).context("Failed to mark task run as timed out")?;
+
+            let (task, task_run) = save_task_run_completion(
+                &self.task_repository,
+                &self.task_run_repository,
+                &mut transaction,
+                completion,
+            )
+            .await
+            .context("Failed to save task aggregate")?;
+
+            let output_tail =
+                get_task_run_output_tail(&mut transaction, &self.task_run_event_repository, &task_run).await?;
+            create_task_incident(
+                &mut transaction,
+                &self.incident_repository,
+                &self.incident_event_repository,
+                &self.incident_notification_repository,
+                &self.maintenance_window_repository,
+                &task,
+                Some(&task_run),
+                output_tail,
+            )
+            .await
+            .context("Failed to create incident for timed out task run")?;
+            collected_task_runs += 1;
+        }
+
+        self.task_repository
+            .commit_transaction(transaction)
+            .await
+            .context("Failed to commit transaction")?;
+
+        Ok(collected_task_runs)
+    }
+}
@@ -6,11 +6,14 @@ use utoipa::ToSchema;
 use crate::domain::{
     entities::{
         authorization::{AuthContext, Permission},
+        entity_metadata::EntityMetadata,
         task::{BoundaryTask, HealthyTask, TaskError, TaskId},
     },
     ports::task_repository::TaskRepository,
 };
 
+use super::find_unknown_upstream_task;
+
 #[derive(Error, Debug)]
 pub enum CreateTaskError {
     #[error("User is not allowed to create a task")]
@@ -21,6 +24,10 @@ pub enum CreateTaskError {
     TechnicalFailure(#[from] anyhow::Error),
     #[error("Invalid cron schedule: {details}")]
     InvalidCronSchedule { details: cron::error::Error },
+    #[error("{details}")]
+    InvalidTimeZone { details: String },
+    #[error("{details}")]
+    InvalidUpstreamTasks { details: String },
     #[error("Technical failure occured while creating a task")]
     TaskError(#[from] TaskError),
 }
@@ -34,9 +41,26 @@ pub struct CreateTaskCommand {
     pub name: Option<String>,
     pub description: Option<String>,
     pub cron_schedule: Option<String>,
+    /// The IANA time zone in which the cron schedule is evaluated (e.g. `Europe/Paris`). Defaults to UTC
+    pub time_zone: Option<String>,
     pub start_window_seconds: Option<u32>,
     pub lateness_window_seconds: Option<u32>,
     pub heartbeat_timeout_seconds: Option<u32>,
+    /// The maximum duration of a run, after which the run is considered timed out. Runs can last forever if not set
+    pub max_duration_seconds: Option<u32>,
+    /// The maximum number of runs that can be running at the same time, e.g. one run per shard. Defaults to 1
+    pub max_concurrent_runs: Option<u32>,
+    /// The tasks that must finish before the task is due, e.g. the export that precedes an import
+    #[ts(type = "Array<string> | null")]
+    pub upstream_task_ids: Option<Vec<TaskId>>,
+    /// Key-value records used to tag the task (e.g. by team or environment) and to filter tasks
+    pub metadata: Option<EntityMetadata>,
+    /// Whether users are notified of the incidents of the task by email. Defaults to true
+    pub email_notification_enabled: Option<bool>,
+    /// Whether users are notified of the incidents of the task by push notification. Defaults to true
+    pub push_notification_enabled: Option<bool>,
+    /// Whether users are notified of the incidents of the task by SMS. Defaults to false
+    pub sms_notification_enabled: Option<bool>,
 }
 
 pub async fn create_task_use_case(
@@ -58,11 +82,28 @@ pub async fn create_task_use_case(
         return Err(CreateTaskError::TaskAlreadyExists(command.id));
     }
 
-    let new_task = HealthyTask::new(auth_context.active_organization_id, command)?;
-    let new_task: BoundaryTask = new_task.try_into().map_err(|e| match e {
-        TaskError::InvalidCronSchedule { details } => CreateTaskError::InvalidCronSchedule { details },
-        _ => CreateTaskError::TechnicalFailure(e.into()),
-    })?;
+    let upstream_task_ids = command.upstream_task_ids.as_deref().unwrap_or_default();
+    if let Some(task_id) = find_unknown_upstream_task(
+        &mut tx,
+        task_repository,
+        auth_context.active_organization_id,
+        upstream_task_ids,
+    )
+    .await?
+    {
+        return Err(CreateTaskError::InvalidUpstreamTasks {
+            details: format!("Upstream task not found: {task_id}"),
+        });
+    }
+
+    let new_task: BoundaryTask = HealthyTask::new(auth_context.active_organization_id, command)
+        .and_then(|task| task.try_into())
+        .map_err(|e| match e {
+            TaskError::InvalidCronSchedule { details } => CreateTaskError::InvalidCronSchedule { details },
+            TaskError::InvalidTimeZone { details } => CreateTaskError::InvalidTimeZone { details },
+            TaskError::InvalidUpstreamTasks { details } => CreateTaskError::InvalidUpstreamTasks { details },
+            _ => CreateTaskError::TechnicalFailure(e.into()),
+        })?;
     task_repository.upsert_task(&mut tx, new_task).await?;
     task_repository.commit_transaction(tx).await?;
 
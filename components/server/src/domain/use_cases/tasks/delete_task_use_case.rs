@@ -0,0 +1,68 @@
+use anyhow::Context;
+use thiserror::Error;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        task::TaskId,
+    },
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository, task_repository::TaskRepository,
+    },
+};
+
+use super::resolve_task_incident;
+
+#[derive(Error, Debug)]
+pub enum DeleteTaskError {
+    #[error("User is not allowed to delete this task")]
+    Forbidden,
+    #[error("Task not found")]
+    NotFound,
+    #[error("Technical failure occured while deleting a task")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Deletes a task along with its runs. The ongoing incident of the task, if any, is resolved
+pub async fn delete_task_use_case<TR, IR, IER, INR>(
+    auth_context: &AuthContext,
+    task_repository: &TR,
+    incident_repository: &IR,
+    incident_event_repository: &IER,
+    incident_notification_repository: &INR,
+    task_id: TaskId,
+) -> Result<(), DeleteTaskError>
+where
+    TR: TaskRepository,
+    IR: IncidentRepository<Transaction = TR::Transaction>,
+    IER: IncidentEventRepository<Transaction = TR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = TR::Transaction>,
+{
+    if !auth_context.can(Permission::WriteTasks) {
+        return Err(DeleteTaskError::Forbidden);
+    }
+
+    let mut tx = task_repository.begin_transaction().await?;
+    let task = task_repository
+        .get_task(&mut tx, auth_context.active_organization_id, &task_id)
+        .await?
+        .ok_or(DeleteTaskError::NotFound)?;
+
+    resolve_task_incident(
+        &mut tx,
+        incident_repository,
+        incident_event_repository,
+        incident_notification_repository,
+        &task,
+    )
+    .await?;
+    task_repository
+        .delete_task(&mut tx, auth_context.active_organization_id, &task_id)
+        .await?;
+
+    task_repository.commit_transaction(tx).await.context("failed to commit transaction")?;
+
+    Ok(())
+}
@@ -8,9 +8,21 @@ use utoipa::ToSchema;
 use crate::domain::{
     entities::{
         authorization::{AuthContext, Permission},
-        task::{get_task_aggregate, save_task_aggregate, TaskAggregate, TaskId},
+        task::{get_task_aggregate_for_update, save_task_run_completion, TaskAggregate, TaskId, TaskStatus},
     },
-    ports::{task_repository::TaskRepository, task_run_repository::TaskRunRepository},
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository,
+        maintenance_window_repository::MaintenanceWindowRepository,
+        task_repository::TaskRepository, task_run_event_repository::TaskRunEventRepository,
+        task_run_repository::TaskRunRepository,
+    },
+};
+
+use super::{
+    create_task_incident, get_task_run_output_tail, mark_downstream_tasks_due,
+    resolve_task_incident,
 };
 
 #[derive(Error, Debug)]
@@ -21,6 +33,8 @@ pub enum FinishTaskError {
     NotFound,
     #[error("Task is not running")]
     TaskIsNotRunning,
+    #[error("Task run not found")]
+    TaskRunNotFound,
     #[error("Technical failure occured while finishing a task")]
     TechnicalFailure(#[from] anyhow::Error),
 }
@@ -43,25 +57,39 @@ pub struct FinishTaskCommand {
     pub exit_code: Option<i32>,
     #[serde(default)]
     pub error_message: Option<String>,
+    /// The id of the run to finish, as returned when it started. Defaults to the oldest running run of the task
+    #[serde(default)]
+    pub run_id: Option<String>,
 }
 
-pub async fn finish_task_use_case<TR, TRR>(
+#[allow(clippy::too_many_arguments)]
+pub async fn finish_task_use_case<TR, TRR, TRER, IR, IER, INR, MWR>(
     auth_context: &AuthContext,
     task_repository: &TR,
     task_run_repository: &TRR,
+    task_run_event_repository: &TRER,
+    incident_repository: &IR,
+    incident_event_repository: &IER,
+    incident_notification_repository: &INR,
+    maintenance_window_repository: &MWR,
     task_id: TaskId,
     command: FinishTaskCommand,
 ) -> Result<(), FinishTaskError>
 where
     TR: TaskRepository,
     TRR: TaskRunRepository<Transaction = TR::Transaction>,
+    TRER: TaskRunEventRepository<Transaction = TR::Transaction>,
+    IR: IncidentRepository<Transaction = TR::Transaction>,
+    IER: IncidentEventRepository<Transaction = TR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = TR::Transaction>,
+    MWR: MaintenanceWindowRepository<Transaction = TR::Transaction>,
 {
     if !auth_context.can(Permission::WriteTaskRuns) {
         return Err(FinishTaskError::Forbidden);
     }
 
     let mut tx = task_repository.begin_transaction().await?;
-    let aggregate = get_task_aggregate(
+    let aggregate = get_task_aggregate_for_update(
         task_repository,
         task_run_repository,
         &mut tx,
@@ -71,32 +99,71 @@ where
     .await?;
 
     let now = Utc::now();
-    let updated_aggregate = match aggregate {
+    let run_id = command.run_id.as_deref();
+    let completion = match aggregate {
         None => return Err(FinishTaskError::NotFound),
+        Some(TaskAggregate::Running(t)) if run_id.is_some_and(|run_id| !t.is_running(run_id)) => {
+            return Err(FinishTaskError::TaskRunNotFound)
+        }
         Some(TaskAggregate::Running(t)) => match command.status {
-            FinishedTaskStatus::Success => TaskAggregate::Healthy(
-                t.mark_finished(now, command.exit_code)
-                    .context("failed to finish running task")?,
-            ),
-            FinishedTaskStatus::Failure => TaskAggregate::Failing(
-                t.mark_failed(now, command.exit_code, command.error_message)
-                    .context("failed to finish running task")?,
-            ),
-            FinishedTaskStatus::Aborted => TaskAggregate::Healthy(
-                t.mark_aborted(now)
-                    .context("failed to finish running task")?,
-            ),
+            FinishedTaskStatus::Success => t
+                .mark_finished(run_id, now, command.exit_code)
+                .context("failed to finish running task")?,
+            FinishedTaskStatus::Failure => t
+                .mark_failed(run_id, now, command.exit_code, command.error_message)
+                .context("failed to finish running task")?,
+            FinishedTaskStatus::Aborted => t
+                .mark_aborted(run_id, now)
+                .context("failed to finish running task")?,
         },
         Some(_) => return Err(FinishTaskError::TaskIsNotRunning),
     };
 
-    save_task_aggregate(
+    let (task, task_run) = save_task_run_completion(
         task_repository,
         task_run_repository,
         &mut tx,
-        updated_aggregate,
+        completion,
     )
     .await?;
+
+    // A failed run opens an incident, while a successful run resolves the ongoing one
+    // once the task is healthy, i.e. when no other run is running and none failed in the meantime.
+    // Aborted runs leave any ongoing incident untouched.
+    match command.status {
+        FinishedTaskStatus::Success if task.status != TaskStatus::Healthy => {}
+        FinishedTaskStatus::Success => {
+            resolve_task_incident(
+                &mut tx,
+                incident_repository,
+                incident_event_repository,
+                incident_notification_repository,
+                &task,
+            )
+            .await?;
+            // the tasks that depend on this task can now run
+            mark_downstream_tasks_due(&mut tx, task_repository, task_run_repository, &task, now)
+                .await?;
+        }
+        FinishedTaskStatus::Failure => {
+            // the output sent by the run before it finished helps to understand the failure
+            let output_tail =
+                get_task_run_output_tail(&mut tx, task_run_event_repository, &task_run).await?;
+            create_task_incident(
+                &mut tx,
+                incident_repository,
+                incident_event_repository,
+                incident_notification_repository,
+                maintenance_window_repository,
+                &task,
+                Some(&task_run),
+                output_tail,
+            )
+            .await?
+        }
+        FinishedTaskStatus::Aborted => {}
+    }
+
     task_repository.commit_transaction(tx).await?;
 
     Ok(())
@@ -0,0 +1,30 @@
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        entity_metadata::FilterableMetadata,
+    },
+    ports::task_repository::TaskRepository,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum GetFilterableTaskMetadataError {
+    #[error("Current user doesn't have the privilege to list tasks")]
+    Forbidden,
+    #[error("Failed to get filterable metadata from the database: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+pub async fn get_filterable_task_metadata<TR: TaskRepository>(
+    auth_context: &AuthContext,
+    task_repository: &TR,
+) -> Result<FilterableMetadata, GetFilterableTaskMetadataError> {
+    if !auth_context.can(Permission::ReadTasks) {
+        return Err(GetFilterableTaskMetadataError::Forbidden);
+    }
+
+    task_repository
+        .get_filterable_metadata(auth_context.active_organization_id)
+        .await
+        .map_err(GetFilterableTaskMetadataError::TechnicalFailure)
+}
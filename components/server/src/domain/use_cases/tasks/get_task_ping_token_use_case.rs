@@ -0,0 +1,50 @@
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        task::TaskId,
+    },
+    ports::task_repository::TaskRepository,
+};
+
+#[derive(Serialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TaskPingTokenResponse {
+    /// The token of the ping URLs of the task: `/ping/:token`, `/ping/:token/start`, `/ping/:token/fail` and `/ping/:token/:exit_code`
+    pub ping_token: String,
+}
+
+#[derive(Error, Debug)]
+pub enum GetTaskPingTokenError {
+    #[error("Failed to get task ping token: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to report task runs")]
+    Forbidden,
+    #[error("Task not found")]
+    NotFound,
+}
+
+/// Returns the ping token of a task.
+/// Anyone who knows the token can report the runs of the task, so it requires the permission to write task runs
+pub async fn get_task_ping_token(
+    auth_context: &AuthContext,
+    repository: &impl TaskRepository,
+    task_id: TaskId,
+) -> Result<TaskPingTokenResponse, GetTaskPingTokenError> {
+    if !auth_context.can(Permission::WriteTaskRuns) {
+        return Err(GetTaskPingTokenError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let ping_token = repository
+        .get_task_ping_token(&mut tx, auth_context.active_organization_id, &task_id)
+        .await?
+        .ok_or(GetTaskPingTokenError::NotFound)?;
+
+    Ok(TaskPingTokenResponse { ping_token })
+}
@@ -1,6 +1,6 @@
 use anyhow::Context;
-use serde::Serialize;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use ts_rs::TS;
 use utoipa::ToSchema;
@@ -13,6 +13,15 @@ use crate::domain::{
     ports::task_run_repository::TaskRunRepository,
 };
 
+#[derive(Deserialize, TS, Debug)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct GetTaskRunParams {
+    /// Tells apart the runs of the task that started at the same time
+    #[serde(default)]
+    pub run_id: Option<String>,
+}
+
 #[derive(Serialize, TS, Debug, ToSchema)]
 #[serde(rename_all = "camelCase")]
 #[ts(export)]
@@ -34,7 +43,8 @@ pub async fn get_task_run(
     auth_context: &AuthContext,
     repository: &impl TaskRunRepository,
     task_id: TaskId,
-    task_run_started_at: DateTime<Utc>,
+    started_at: DateTime<Utc>,
+    run_id: Option<String>,
 ) -> Result<GetTaskRunResponse, GetTaskRunError> {
     if !auth_context.can(Permission::ReadTaskRuns) {
         return Err(GetTaskRunError::Forbidden);
@@ -50,7 +60,8 @@ pub async fn get_task_run(
             &mut tx,
             auth_context.active_organization_id,
             &task_id,
-            task_run_started_at,
+            started_at,
+            run_id.as_deref(),
         )
         .await
         .context("Failed to get task run from repository")?
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        task::TaskId,
+        task_run_event::TaskRunEvent,
+    },
+    ports::{
+        task_run_event_repository::{ListTaskRunEventsOutput, TaskRunEventRepository},
+        task_run_repository::TaskRunRepository,
+    },
+};
+
+#[derive(Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTaskRunEventsParams {
+    /// Tells apart the runs of the task that started at the same time
+    #[serde(default)]
+    pub run_id: Option<String>,
+    #[serde(default)]
+    pub page_number: Option<u32>,
+    #[serde(default)]
+    pub items_per_page: Option<u32>,
+}
+
+#[derive(Serialize, TS, ToSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTaskRunEventsResponse {
+    pub events: Vec<TaskRunEvent>,
+    pub total_events: u32,
+}
+
+#[derive(Error, Debug)]
+pub enum ListTaskRunEventsError {
+    #[error("User is not allowed to read task runs")]
+    Forbidden,
+    #[error("Task run not found")]
+    NotFound,
+    #[error("Technical failure occured while listing task run events")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Lists the events (e.g. the logs) of a task run, oldest first
+pub async fn list_task_run_events<TRR, TRER>(
+    auth_context: &AuthContext,
+    task_run_repository: &TRR,
+    task_run_event_repository: &TRER,
+    task_id: TaskId,
+    started_at: DateTime<Utc>,
+    params: ListTaskRunEventsParams,
+) -> Result<ListTaskRunEventsResponse, ListTaskRunEventsError>
+where
+    TRR: TaskRunRepository,
+    TRER: TaskRunEventRepository<Transaction = TRR::Transaction>,
+{
+    if !auth_context.can(Permission::ReadTaskRuns) {
+        return Err(ListTaskRunEventsError::Forbidden);
+    }
+
+    let mut tx = task_run_repository.begin_transaction().await?;
+    let task_run = task_run_repository
+        .get_task_run(&mut tx, auth_context.active_organization_id, &task_id, started_at, params.run_id.as_deref())
+        .await?
+        .ok_or(ListTaskRunEventsError::NotFound)?;
+
+    let items_per_page = params.items_per_page.unwrap_or(50).min(100);
+    let page_number = params.page_number.unwrap_or(1).max(1);
+    let ListTaskRunEventsOutput { events, total_events } = task_run_event_repository
+        .list_task_run_events(
+            &mut tx,
+            auth_context.active_organization_id,
+            &task_id,
+            &task_run.run_id,
+            task_run.started_at,
+            items_per_page,
+            (page_number - 1) * items_per_page,
+        )
+        .await?;
+
+    Ok(ListTaskRunEventsResponse { events, total_events })
+}
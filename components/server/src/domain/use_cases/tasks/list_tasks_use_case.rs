@@ -6,6 +6,7 @@ use utoipa::{IntoParams, ToSchema};
 use crate::domain::{
     entities::{
         authorization::{AuthContext, Permission},
+        entity_metadata::MetadataFilter,
         task::{BoundaryTask, TaskStatus},
     },
     ports::task_repository::{TaskRepository, ListTasksOutput},
@@ -23,6 +24,18 @@ pub struct ListTasksParams {
     pub page_number: Option<u32>,
     #[serde(default)]
     pub items_per_page: Option<u32>,
+    #[serde(default)]
+    #[ts(type = "Option<MetadataFilter>")]
+    pub metadata_filter: Option<String>,
+}
+
+impl ListTasksParams {
+    pub fn metadata_filter(&self) -> MetadataFilter {
+        self.metadata_filter
+            .as_ref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Serialize, TS, Debug, ToSchema)]
@@ -51,6 +64,7 @@ pub async fn list_tasks(
         return Err(ListTasksError::Forbidden);
     }
 
+    let metadata_filter = params.metadata_filter();
     let items_per_page = params.items_per_page.unwrap_or(10).min(50);
     let page_number = params.page_number.unwrap_or(1);
 
@@ -63,6 +77,7 @@ pub async fn list_tasks(
             auth_context.active_organization_id,
             params.include.unwrap_or_default(),
             params.search_query,
+            metadata_filter,
             items_per_page,
             items_per_page * (page_number - 1),
         )
@@ -6,10 +6,24 @@ mod start_task_use_case;
 mod send_task_heartbeat_use_case;
 mod list_task_runs_use_case;
 mod collect_dead_task_runs_use_case;
+mod collect_timed_out_task_runs_use_case;
 mod collect_due_tasks_use_case;
 mod collect_late_tasks_use_case;
 mod collect_absent_tasks_use_case;
 mod get_task_run_use_case;
+mod get_task_ping_token_use_case;
+mod rotate_task_ping_token_use_case;
+mod ping_task_use_case;
+mod append_task_run_logs_use_case;
+mod list_task_run_events_use_case;
+mod update_task_use_case;
+mod delete_task_use_case;
+mod pause_task_use_case;
+mod resume_task_use_case;
+mod preview_task_schedule_use_case;
+mod task_incidents;
+mod task_dependencies;
+mod get_filterable_task_metadata_use_case;
 
 pub use get_task_use_case::*;
 pub use list_tasks_use_case::*;
@@ -19,7 +33,21 @@ pub use start_task_use_case::*;
 pub use send_task_heartbeat_use_case::*;
 pub use list_task_runs_use_case::*;
 pub use collect_dead_task_runs_use_case::*;
+pub use collect_timed_out_task_runs_use_case::*;
 pub use collect_due_tasks_use_case::*;
 pub use collect_late_tasks_use_case::*;
 pub use collect_absent_tasks_use_case::*;
-pub use get_task_run_use_case::*;
\ No newline at end of file
+pub use get_task_run_use_case::*;
+pub use get_task_ping_token_use_case::*;
+pub use rotate_task_ping_token_use_case::*;
+pub use ping_task_use_case::*;
+pub use append_task_run_logs_use_case::*;
+pub use list_task_run_events_use_case::*;
+pub use update_task_use_case::*;
+pub use delete_task_use_case::*;
+pub use pause_task_use_case::*;
+pub use resume_task_use_case::*;
+pub use preview_task_schedule_use_case::*;
+pub use task_incidents::*;
+pub use task_dependencies::*;
+pub use get_filterable_task_metadata_use_case::*;
\ No newline at end of file
@@ -0,0 +1,98 @@
+use anyhow::Context;
+use chrono::Utc;
+use thiserror::Error;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        task::{get_task_aggregate_for_update, save_task_aggregate, BoundaryTask, TaskAggregate, TaskId},
+    },
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository, task_repository::TaskRepository,
+        task_run_repository::TaskRunRepository,
+    },
+};
+
+use super::resolve_task_incident;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Error, Debug)]
+pub enum PauseTaskError {
+    #[error("User is not allowed to pause this task")]
+    Forbidden,
+    #[error("Task not found")]
+    NotFound,
+    #[error("A running task cannot be paused")]
+    TaskIsRunning,
+    #[error("Task is already paused")]
+    TaskIsPaused,
+    #[error("Technical failure occured while pausing a task")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Pauses a task, e.g. during a migration. A paused task is not expected to run, so it never becomes due, late or absent,
+/// and its runs cannot be started until it is resumed. The ongoing incident of the task, if any, is resolved
+pub async fn pause_task_use_case<TR, TRR, IR, IER, INR>(
+    auth_context: &AuthContext,
+    task_repository: &TR,
+    task_run_repository: &TRR,
+    incident_repository: &IR,
+    incident_event_repository: &IER,
+    incident_notification_repository: &INR,
+    task_id: TaskId,
+) -> Result<BoundaryTask, PauseTaskError>
+where
+    TR: TaskRepository,
+    TRR: TaskRunRepository<Transaction = TR::Transaction>,
+    IR: IncidentRepository<Transaction = TR::Transaction>,
+    IER: IncidentEventRepository<Transaction = TR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = TR::Transaction>,
+{
+    if !auth_context.can(Permission::WriteTasks) {
+        return Err(PauseTaskError::Forbidden);
+    }
+
+    let mut tx = task_repository.begin_transaction().await?;
+    let aggregate = get_task_aggregate_for_update(
+        task_repository,
+        task_run_repository,
+        &mut tx,
+        auth_context.active_organization_id,
+        &task_id,
+    )
+    .await
+    .context("failed to get task aggregate from the database")?;
+
+    let paused_aggregate = match aggregate {
+        None => return Err(PauseTaskError::NotFound),
+        Some(TaskAggregate::Running(_)) => return Err(PauseTaskError::TaskIsRunning),
+        Some(TaskAggregate::Paused(_)) => return Err(PauseTaskError::TaskIsPaused),
+        Some(aggregate) => aggregate.pause(Utc::now()).context("failed to pause task")?,
+    };
+
+    let (task, _) = save_task_aggregate(
+        task_repository,
+        task_run_repository,
+        &mut tx,
+        TaskAggregate::Paused(paused_aggregate),
+    )
+    .await
+    .context("failed to save task aggregate to the database")?;
+
+    resolve_task_incident(
+        &mut tx,
+        incident_repository,
+        incident_event_repository,
+        incident_notification_repository,
+        &task,
+    )
+    .await?;
+
+    task_repository.commit_transaction(tx).await.context("failed to commit transaction")?;
+
+    Ok(task)
+}
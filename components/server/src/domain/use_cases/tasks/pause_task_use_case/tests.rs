@@ -0,0 +1,163 @@
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            authorization::AuthContext,
+            incident::IncidentStatus,
+            organization::OrganizationUserRole,
+            task::{BoundaryTask, TaskStatus},
+        },
+        ports::{task_repository::TaskRepository, transactional_repository::TransactionalRepository},
+        use_cases::tasks::{
+            create_task_incident, resume_task_use_case, start_task_use_case, ResumeTaskError,
+            StartTaskError,
+        },
+    },
+    infrastructure::mocks::{
+        incident_event_repository_mock::IncidentEventRepositoryMock,
+        incident_notification_repository_mock::IncidentNotificationRepositoryMock,
+        incident_repository_mock::IncidentRepositoryMock,
+        maintenance_window_repository_mock::MaintenanceWindowRepositoryMock,
+        task_repository_mock::TaskRepositoryMock,
+        task_run_repository_mock::TaskRunRepositoryMock,
+    },
+};
+
+use super::{pause_task_use_case, PauseTaskError};
+
+fn create_test_task(status: TaskStatus) -> BoundaryTask {
+    BoundaryTask {
+        cron_schedule: Some("0 0 0 * * *".to_string()),
+        next_due_at: Some(Utc::now() - Duration::days(3)),
+        ..BoundaryTask::test_task(Uuid::new_v4(), "nightly-backup", status)
+    }
+}
+
+#[tokio::test]
+async fn test_pause_and_resume_task() -> anyhow::Result<()> {
+    let task_repository = TaskRepositoryMock::new();
+    let task_run_repository = TaskRunRepositoryMock::new();
+    let incident_repository = IncidentRepositoryMock::new();
+    let incident_event_repository = IncidentEventRepositoryMock::new();
+    let incident_notification_repository = IncidentNotificationRepositoryMock::new();
+    let task = create_test_task(TaskStatus::Absent);
+    let mut tx = task_repository.begin_transaction().await?;
+    task_repository.upsert_task(&mut tx, task.clone()).await?;
+    create_task_incident(
+        &mut tx,
+        &incident_repository,
+        &incident_event_repository,
+        &incident_notification_repository,
+        &MaintenanceWindowRepositoryMock::new(),
+        &task,
+        None,
+        Vec::new(),
+    )
+    .await?;
+    let auth_context = AuthContext::test_context(
+        task.organization_id,
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Editor],
+        &[],
+    );
+
+    let paused = pause_task_use_case(
+        &auth_context,
+        &task_repository,
+        &task_run_repository,
+        &incident_repository,
+        &incident_event_repository,
+        &incident_notification_repository,
+        task.id.clone(),
+    )
+    .await?;
+    assert_eq!(paused.status, TaskStatus::Paused);
+    assert_eq!(paused.previous_status, Some(TaskStatus::Absent));
+    // paused tasks are never due
+    assert_eq!(paused.next_due_at, None);
+    assert_eq!(incident_repository.state.lock().await[0].status, IncidentStatus::Resolved);
+
+    let result = pause_task_use_case(
+        &auth_context,
+        &task_repository,
+        &task_run_repository,
+        &incident_repository,
+        &incident_event_repository,
+        &incident_notification_repository,
+        task.id.clone(),
+    )
+    .await;
+    assert!(matches!(result, Err(PauseTaskError::TaskIsPaused)));
+
+    // the runs of a paused task cannot be started
+    let result = start_task_use_case(
+        &auth_context,
+        &task_repository,
+        &task_run_repository,
+        task.id.clone(),
+        None,
+    )
+    .await;
+    assert!(matches!(result, Err(StartTaskError::TaskIsPaused)));
+
+    let resumed = resume_task_use_case(
+        &auth_context,
+        &task_repository,
+        &task_run_repository,
+        task.id.clone(),
+    )
+    .await?;
+    assert_eq!(resumed.status, TaskStatus::Healthy);
+    // the runs missed while the task was paused are not expected
+    let next_due_at = resumed.next_due_at.expect("scheduled task must have a next due at");
+    assert!(next_due_at > Utc::now());
+    assert!(next_due_at <= Utc::now() + Duration::days(1));
+
+    let result = resume_task_use_case(
+        &auth_context,
+        &task_repository,
+        &task_run_repository,
+        task.id.clone(),
+    )
+    .await;
+    assert!(matches!(result, Err(ResumeTaskError::TaskIsNotPaused)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cannot_pause_running_task() -> anyhow::Result<()> {
+    let task_repository = TaskRepositoryMock::new();
+    let task_run_repository = TaskRunRepositoryMock::new();
+    let task = create_test_task(TaskStatus::Healthy);
+    let mut tx = task_repository.begin_transaction().await?;
+    task_repository.upsert_task(&mut tx, task.clone()).await?;
+    let auth_context = AuthContext::test_context(
+        task.organization_id,
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Editor],
+        &[],
+    );
+    start_task_use_case(
+        &auth_context,
+        &task_repository,
+        &task_run_repository,
+        task.id.clone(),
+        None,
+    )
+    .await?;
+
+    let result = pause_task_use_case(
+        &auth_context,
+        &task_repository,
+        &task_run_repository,
+        &IncidentRepositoryMock::new(),
+        &IncidentEventRepositoryMock::new(),
+        &IncidentNotificationRepositoryMock::new(),
+        task.id.clone(),
+    )
+    .await;
+    assert!(matches!(result, Err(PauseTaskError::TaskIsRunning)));
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use thiserror::Error;
+
+use crate::domain::{
+    entities::{authorization::AuthContext, task::TaskStatus},
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository,
+        maintenance_window_repository::MaintenanceWindowRepository,
+        task_repository::TaskRepository, task_run_event_repository::TaskRunEventRepository,
+        task_run_repository::TaskRunRepository,
+    },
+};
+
+use super::{
+    finish_task_use_case, start_task_use_case, FinishTaskCommand, FinishTaskError,
+    FinishedTaskStatus, StartTaskCommand, StartTaskError,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The maximum length of the request body kept as the error message of a run
+pub const MAXIMUM_PING_BODY_LENGTH: usize = 10_000;
+
+/// The signal sent to a ping URL of a task
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskPing {
+    /// `/ping/:token/start`: a run of the task has started
+    Start,
+    /// `/ping/:token`: the run of the task has succeeded
+    Success,
+    /// `/ping/:token/fail`: the run of the task has failed
+    Fail,
+    /// `/ping/:token/:exit_code`: the run of the task has exited, with a success if the exit code is 0
+    ExitCode(i32),
+}
+
+impl TaskPing {
+    /// Parses the signal of the path of a ping URL, after the token
+    pub fn from_path_segment(segment: &str) -> Option<Self> {
+        match segment {
+            "start" => Some(Self::Start),
+            "fail" => Some(Self::Fail),
+            _ => segment.parse().ok().map(Self::ExitCode),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum PingTaskError {
+    #[error("Technical failure occured while pinging a task")]
+    TechnicalFailure(#[from] anyhow::Error),
+    /// Unknown tokens are not told apart from the tokens of deleted tasks
+    #[error("Unknown ping token")]
+    UnknownToken,
+    #[error("Task is paused")]
+    TaskIsPaused,
+}
+
+/// Reports the run of a task with its ping token, as `start_task_use_case` and `finish_task_use_case` would.
+///
+/// A start aborts the previous run if it is still running, since the process that would finish it is unlikely to exist anymore.
+/// A finish without a previous start records a run that starts and finishes at once,
+/// so that tasks only pinged at the end of their runs are monitored as well.
+/// Pings cannot tell runs apart: a finish finishes the oldest running run of tasks with concurrent runs.
+/// The body of the request, if any, becomes the error message of a failed run.
+#[allow(clippy::too_many_arguments)]
+pub async fn ping_task<TR, TRR, TRER, IR, IER, INR, MWR>(
+    task_repository: &TR,
+    task_run_repository: &TRR,
+    task_run_event_repository: &TRER,
+    incident_repository: &IR,
+    incident_event_repository: &IER,
+    incident_notification_repository: &INR,
+    maintenance_window_repository: &MWR,
+    ping_token: &str,
+    ping: TaskPing,
+    body: Option<String>,
+) -> Result<(), PingTaskError>
+where
+    TR: TaskRepository,
+    TRR: TaskRunRepository<Transaction = TR::Transaction>,
+    TRER: TaskRunEventRepository<Transaction = TR::Transaction>,
+    IR: IncidentRepository<Transaction = TR::Transaction>,
+    IER: IncidentEventRepository<Transaction = TR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = TR::Transaction>,
+    MWR: MaintenanceWindowRepository<Transaction = TR::Transaction>,
+{
+    let mut tx = task_repository.begin_transaction().await?;
+    let task = task_repository
+        .get_task_by_ping_token(&mut tx, ping_token)
+        .await?
+        .ok_or(PingTaskError::UnknownToken)?;
+    task_repository.rollback_transaction(tx).await?;
+
+    let auth_context = AuthContext::task_ping(task.organization_id);
+    // a finish only starts a run if none is running, even if the task can run several runs at once
+    let start = match ping == TaskPing::Start || task.status != TaskStatus::Running {
+        true => {
+            start_task_use_case(
+                &auth_context,
+                task_repository,
+                task_run_repository,
+                task.id.clone(),
+                Some(StartTaskCommand {
+                    new_task: None,
+                    abort_previous_running_task: ping == TaskPing::Start,
+                    run_id: None,
+                }),
+            )
+            .await
+        }
+        false => Err(StartTaskError::TaskAlreadyStarted),
+    };
+    match start {
+        Ok(_) | Err(StartTaskError::TaskAlreadyStarted) => {}
+        // the task was deleted since it was fetched
+        Err(StartTaskError::TaskNotFound) => return Err(PingTaskError::UnknownToken),
+        Err(StartTaskError::TaskIsPaused) => return Err(PingTaskError::TaskIsPaused),
+        Err(StartTaskError::Forbidden) => {
+            return Err(anyhow::anyhow!("task ping context cannot start tasks").into())
+        }
+        Err(e @ (StartTaskError::TaskRunAlreadyStarted | StartTaskError::InvalidRunId { .. })) => {
+            return Err(anyhow::anyhow!("failed to start a run with a generated run id: {e}").into())
+        }
+        Err(e @ StartTaskError::InvalidUpstreamTasks { .. }) => {
+            return Err(anyhow::anyhow!("failed to start an existing task: {e}").into())
+        }
+        Err(StartTaskError::TechnicalFailure(e)) => return Err(e.into()),
+    }
+
+    let (status, exit_code) = match ping {
+        TaskPing::Start => return Ok(()),
+        TaskPing::Success => (FinishedTaskStatus::Success, None),
+        TaskPing::Fail => (FinishedTaskStatus::Failure, None),
+        TaskPing::ExitCode(0) => (FinishedTaskStatus::Success, Some(0)),
+        TaskPing::ExitCode(code) => (FinishedTaskStatus::Failure, Some(code)),
+    };
+    // the body is only recorded as an error message when the ping reports a failure
+    let error_message = body
+        .filter(|body| matches!(status, FinishedTaskStatus::Failure) && !body.trim().is_empty())
+        .map(|body| body.chars().take(MAXIMUM_PING_BODY_LENGTH).collect());
+
+    let finish = finish_task_use_case(
+        &auth_context,
+        task_repository,
+        task_run_repository,
+        task_run_event_repository,
+        incident_repository,
+        incident_event_repository,
+        incident_notification_repository,
+        maintenance_window_repository,
+        task.id,
+        FinishTaskCommand {
+            status,
+            exit_code,
+            error_message,
+            run_id: None,
+        },
+    )
+    .await;
+    match finish {
+        Ok(()) => Ok(()),
+        Err(FinishTaskError::NotFound) => Err(PingTaskError::UnknownToken),
+        Err(FinishTaskError::Forbidden) => {
+            Err(anyhow::anyhow!("task ping context cannot finish tasks").into())
+        }
+        // the run was finished concurrently, by another ping or by the dead task runs collector
+        Err(FinishTaskError::TaskIsNotRunning | FinishTaskError::TaskRunNotFound) => Ok(()),
+        Err(FinishTaskError::TechnicalFailure(e)) => Err(e.into()),
+    }
+}
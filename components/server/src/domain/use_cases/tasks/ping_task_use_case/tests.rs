@@ -0,0 +1,255 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            authorization::AuthContext,
+            organization::OrganizationUserRole,
+            incident::{IncidentCause, TaskIncidentCause},
+            task::{BoundaryTask, TaskStatus},
+            task_run::TaskRunStatus,
+            task_run_event::{TaskRunLogLine, TaskRunLogStream},
+        },
+        ports::{task_repository::TaskRepository, transactional_repository::TransactionalRepository},
+        use_cases::tasks::{
+            append_task_run_logs, get_task_ping_token, rotate_task_ping_token,
+            AppendTaskRunLogsCommand,
+        },
+    },
+    infrastructure::mocks::{
+        file_storage_mock::FileStorageMock,
+        incident_event_repository_mock::IncidentEventRepositoryMock,
+        incident_notification_repository_mock::IncidentNotificationRepositoryMock,
+        incident_repository_mock::IncidentRepositoryMock,
+        maintenance_window_repository_mock::MaintenanceWindowRepositoryMock,
+        task_repository_mock::TaskRepositoryMock,
+        task_run_event_repository_mock::TaskRunEventRepositoryMock,
+        task_run_repository_mock::TaskRunRepositoryMock,
+    },
+};
+
+use super::{ping_task, PingTaskError, TaskPing, MAXIMUM_PING_BODY_LENGTH};
+
+struct Repositories {
+    task: TaskRepositoryMock,
+    task_run: TaskRunRepositoryMock,
+    task_run_event: TaskRunEventRepositoryMock,
+    incident: IncidentRepositoryMock,
+    incident_event: IncidentEventRepositoryMock,
+    incident_notification: IncidentNotificationRepositoryMock,
+    maintenance_window: MaintenanceWindowRepositoryMock,
+}
+
+impl Repositories {
+    /// Creates the repositories with a healthy task, and returns the ping token of the task
+    async fn new() -> anyhow::Result<(Self, BoundaryTask, String)> {
+        let repositories = Self {
+            task: TaskRepositoryMock::new(),
+            task_run: TaskRunRepositoryMock::new(),
+            task_run_event: TaskRunEventRepositoryMock::new(),
+            incident: IncidentRepositoryMock::new(),
+            incident_event: IncidentEventRepositoryMock::new(),
+            incident_notification: IncidentNotificationRepositoryMock::new(),
+            maintenance_window: MaintenanceWindowRepositoryMock::new(),
+        };
+        let task = BoundaryTask::test_task(Uuid::new_v4(), "nightly-backup", TaskStatus::Healthy);
+        let mut tx = repositories.task.begin_transaction().await?;
+        repositories.task.upsert_task(&mut tx, task.clone()).await?;
+        let ping_token = repositories
+            .task
+            .get_task_ping_token(&mut tx, task.organization_id, &task.id)
+            .await?
+            .unwrap();
+        Ok((repositories, task, ping_token))
+    }
+
+    async fn ping(
+        &self,
+        ping_token: &str,
+        ping: TaskPing,
+        body: Option<&str>,
+    ) -> Result<(), PingTaskError> {
+        ping_task(
+            &self.task,
+            &self.task_run,
+            &self.task_run_event,
+            &self.incident,
+            &self.incident_event,
+            &self.incident_notification,
+            &self.maintenance_window,
+            ping_token,
+            ping,
+            body.map(str::to_string),
+        )
+        .await
+    }
+
+    async fn task_status(&self) -> TaskStatus {
+        self.task.state.lock().await[0].status
+    }
+}
+
+#[test]
+fn test_parse_task_ping() {
+    assert_eq!(TaskPing::from_path_segment("start"), Some(TaskPing::Start));
+    assert_eq!(TaskPing::from_path_segment("fail"), Some(TaskPing::Fail));
+    assert_eq!(TaskPing::from_path_segment("0"), Some(TaskPing::ExitCode(0)));
+    assert_eq!(TaskPing::from_path_segment("137"), Some(TaskPing::ExitCode(137)));
+    assert_eq!(TaskPing::from_path_segment("finish"), None);
+}
+
+#[tokio::test]
+async fn test_ping_start_then_success() -> anyhow::Result<()> {
+    let (repositories, _, ping_token) = Repositories::new().await?;
+
+    repositories.ping(&ping_token, TaskPing::Start, None).await?;
+    assert_eq!(repositories.task_status().await, TaskStatus::Running);
+
+    repositories.ping(&ping_token, TaskPing::Success, None).await?;
+    assert_eq!(repositories.task_status().await, TaskStatus::Healthy);
+    let runs = repositories.task_run.state.lock().await;
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].status, TaskRunStatus::Finished);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ping_failure_without_start() -> anyhow::Result<()> {
+    let (repositories, _, ping_token) = Repositories::new().await?;
+
+    repositories
+        .ping(&ping_token, TaskPing::ExitCode(2), Some("rsync: connection refused"))
+        .await?;
+
+    assert_eq!(repositories.task_status().await, TaskStatus::Failing);
+    let runs = repositories.task_run.state.lock().await;
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].status, TaskRunStatus::Failed);
+    assert_eq!(runs[0].exit_code, Some(2));
+    assert_eq!(runs[0].error_message.as_deref(), Some("rsync: connection refused"));
+    drop(runs);
+    assert_eq!(repositories.incident.state.lock().await.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ping_success_does_not_record_body_as_error() -> anyhow::Result<()> {
+    let (repositories, _, ping_token) = Repositories::new().await?;
+
+    repositories
+        .ping(&ping_token, TaskPing::ExitCode(0), Some("backup completed"))
+        .await?;
+
+    let runs = repositories.task_run.state.lock().await;
+    assert_eq!(runs[0].status, TaskRunStatus::Finished);
+    assert_eq!(runs[0].exit_code, Some(0));
+    assert_eq!(runs[0].error_message, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ping_fail_truncates_body() -> anyhow::Result<()> {
+    let (repositories, _, ping_token) = Repositories::new().await?;
+
+    let body = "x".repeat(MAXIMUM_PING_BODY_LENGTH + 100);
+    repositories
+        .ping(&ping_token, TaskPing::Fail, Some(&body))
+        .await?;
+
+    let runs = repositories.task_run.state.lock().await;
+    assert_eq!(runs[0].exit_code, None);
+    assert_eq!(
+        runs[0].error_message.as_ref().map(|m| m.len()),
+        Some(MAXIMUM_PING_BODY_LENGTH)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ping_start_aborts_previous_run() -> anyhow::Result<()> {
+    let (repositories, _, ping_token) = Repositories::new().await?;
+
+    repositories.ping(&ping_token, TaskPing::Start, None).await?;
+    repositories.ping(&ping_token, TaskPing::Start, None).await?;
+
+    let runs = repositories.task_run.state.lock().await;
+    assert_eq!(runs.len(), 2);
+    assert!(runs.iter().any(|r| r.status == TaskRunStatus::Aborted));
+    assert!(runs.iter().any(|r| r.status == TaskRunStatus::Running));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ping_with_rotated_token() -> anyhow::Result<()> {
+    let (repositories, task, ping_token) = Repositories::new().await?;
+    let auth_context = AuthContext::test_context(
+        task.organization_id,
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Editor],
+        &[],
+    );
+
+    let rotated = rotate_task_ping_token(&auth_context, &repositories.task, task.id.clone()).await?;
+    assert_ne!(rotated.ping_token, ping_token);
+    assert_eq!(rotated.ping_token.len(), 64);
+    let current = get_task_ping_token(&auth_context, &repositories.task, task.id.clone()).await?;
+    assert_eq!(current.ping_token, rotated.ping_token);
+
+    let result = repositories.ping(&ping_token, TaskPing::Start, None).await;
+    assert!(matches!(result, Err(PingTaskError::UnknownToken)));
+    repositories
+        .ping(&rotated.ping_token, TaskPing::Start, None)
+        .await?;
+    assert_eq!(repositories.task_status().await, TaskStatus::Running);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ping_failure_includes_output_tail() -> anyhow::Result<()> {
+    let (repositories, task, ping_token) = Repositories::new().await?;
+    repositories.ping(&ping_token, TaskPing::Start, None).await?;
+
+    let task_run = repositories.task_run.state.lock().await[0].clone();
+    let auth_context = AuthContext::test_context(
+        task.organization_id,
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Editor],
+        &[],
+    );
+    let lines = (0..15)
+        .map(|i| TaskRunLogLine {
+            stream: TaskRunLogStream::Stdout,
+            line: format!("copying file {i}"),
+        })
+        .chain(std::iter::once(TaskRunLogLine {
+            stream: TaskRunLogStream::Stderr,
+            line: "No space left on device".to_string(),
+        }))
+        .collect();
+    append_task_run_logs(
+        &auth_context,
+        &repositories.task_run,
+        &repositories.task_run_event,
+        &FileStorageMock,
+        task.id.clone(),
+        task_run.started_at,
+        Some(task_run.run_id),
+        AppendTaskRunLogsCommand { lines },
+    )
+    .await?;
+
+    repositories.ping(&ping_token, TaskPing::ExitCode(1), None).await?;
+
+    let incidents = repositories.incident.state.lock().await;
+    let Some(IncidentCause::TaskIncidentCause(TaskIncidentCause {
+        task_run_output_tail,
+        ..
+    })) = &incidents[0].cause
+    else {
+        panic!("unexpected incident cause: {:?}", incidents[0].cause);
+    };
+    assert_eq!(task_run_output_tail.len(), 10);
+    assert_eq!(task_run_output_tail[0], "copying file 6");
+    assert_eq!(task_run_output_tail[9], "[stderr] No space left on device");
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use chrono::Utc;
+use serde::Deserialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::entities::{
+    authorization::{AuthContext, Permission},
+    task::{preview_task_schedule, TaskError, TaskSchedulePreview},
+};
+
+#[derive(Debug, Deserialize, TS, ToSchema, Clone)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewTaskScheduleCommand {
+    pub cron_schedule: String,
+    /// The IANA time zone in which the cron schedule is evaluated (e.g. `Europe/Paris`). Defaults to UTC
+    pub time_zone: Option<String>,
+    pub start_window_seconds: Option<u32>,
+    pub lateness_window_seconds: Option<u32>,
+    /// The number of upcoming runs to preview, 5 by default and at most 100
+    pub runs: Option<u32>,
+}
+
+#[derive(Error, Debug)]
+pub enum PreviewTaskScheduleError {
+    #[error("User is not allowed to preview task schedules")]
+    Forbidden,
+    #[error("Invalid cron schedule: {details}")]
+    InvalidCronSchedule { details: cron::error::Error },
+    #[error("{details}")]
+    InvalidTimeZone { details: String },
+    #[error("Technical failure occured while previewing a task schedule")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Previews the upcoming runs of a cron schedule, with the dates at which a task with this schedule
+/// would be marked as late and absent, without creating a task
+pub async fn preview_task_schedule_use_case(
+    auth_context: &AuthContext,
+    command: PreviewTaskScheduleCommand,
+) -> Result<TaskSchedulePreview, PreviewTaskScheduleError> {
+    if !auth_context.can(Permission::ReadTasks) {
+        return Err(PreviewTaskScheduleError::Forbidden);
+    }
+
+    preview_task_schedule(command, Utc::now()).map_err(|e| match e {
+        TaskError::InvalidCronSchedule { details } => PreviewTaskScheduleError::InvalidCronSchedule { details },
+        TaskError::InvalidTimeZone { details } => PreviewTaskScheduleError::InvalidTimeZone { details },
+        _ => PreviewTaskScheduleError::TechnicalFailure(e.into()),
+    })
+}
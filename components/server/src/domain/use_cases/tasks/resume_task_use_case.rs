@@ -0,0 +1,72 @@
+use anyhow::Context;
+use chrono::Utc;
+use thiserror::Error;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        task::{get_task_aggregate_for_update, save_task_aggregate, BoundaryTask, TaskAggregate, TaskId},
+    },
+    ports::{task_repository::TaskRepository, task_run_repository::TaskRunRepository},
+};
+
+#[derive(Error, Debug)]
+pub enum ResumeTaskError {
+    #[error("User is not allowed to resume this task")]
+    Forbidden,
+    #[error("Task not found")]
+    NotFound,
+    #[error("Task is not paused")]
+    TaskIsNotPaused,
+    #[error("Technical failure occured while resuming a task")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Resumes a paused task. The task becomes healthy, and scheduled tasks are next expected
+/// at the first due date of their cron schedule after now
+pub async fn resume_task_use_case<TR, TRR>(
+    auth_context: &AuthContext,
+    task_repository: &TR,
+    task_run_repository: &TRR,
+    task_id: TaskId,
+) -> Result<BoundaryTask, ResumeTaskError>
+where
+    TR: TaskRepository,
+    TRR: TaskRunRepository<Transaction = TR::Transaction>,
+{
+    if !auth_context.can(Permission::WriteTasks) {
+        return Err(ResumeTaskError::Forbidden);
+    }
+
+    let mut tx = task_repository.begin_transaction().await?;
+    let aggregate = get_task_aggregate_for_update(
+        task_repository,
+        task_run_repository,
+        &mut tx,
+        auth_context.active_organization_id,
+        &task_id,
+    )
+    .await
+    .context("failed to get task aggregate from the database")?;
+
+    let healthy_aggregate = match aggregate {
+        None => return Err(ResumeTaskError::NotFound),
+        Some(TaskAggregate::Paused(aggregate)) => {
+            aggregate.resume(Utc::now()).context("failed to resume task")?
+        }
+        Some(_) => return Err(ResumeTaskError::TaskIsNotPaused),
+    };
+
+    let (task, _) = save_task_aggregate(
+        task_repository,
+        task_run_repository,
+        &mut tx,
+        TaskAggregate::Healthy(healthy_aggregate),
+    )
+    .await
+    .context("failed to save task aggregate to the database")?;
+
+    task_repository.commit_transaction(tx).await.context("failed to commit transaction")?;
+
+    Ok(task)
+}
@@ -0,0 +1,45 @@
+use thiserror::Error;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        task::{generate_task_ping_token, TaskId},
+    },
+    ports::task_repository::TaskRepository,
+};
+
+use super::TaskPingTokenResponse;
+
+#[derive(Error, Debug)]
+pub enum RotateTaskPingTokenError {
+    #[error("Failed to rotate task ping token: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to edit tasks")]
+    Forbidden,
+    #[error("Task not found")]
+    NotFound,
+}
+
+/// Replaces the ping token of a task with a new random token.
+/// The ping URLs with the previous token stop working immediately
+pub async fn rotate_task_ping_token(
+    auth_context: &AuthContext,
+    repository: &impl TaskRepository,
+    task_id: TaskId,
+) -> Result<TaskPingTokenResponse, RotateTaskPingTokenError> {
+    if !auth_context.can(Permission::WriteTasks) {
+        return Err(RotateTaskPingTokenError::Forbidden);
+    }
+
+    let ping_token = generate_task_ping_token();
+    let mut tx = repository.begin_transaction().await?;
+    let updated = repository
+        .set_task_ping_token(&mut tx, auth_context.active_organization_id, &task_id, &ping_token)
+        .await?;
+    if !updated {
+        return Err(RotateTaskPingTokenError::NotFound);
+    }
+    repository.commit_transaction(tx).await?;
+
+    Ok(TaskPingTokenResponse { ping_token })
+}
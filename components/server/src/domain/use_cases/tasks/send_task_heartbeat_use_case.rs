@@ -1,11 +1,14 @@
 use anyhow::Context;
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use ts_rs::TS;
+use utoipa::IntoParams;
 
 use crate::domain::{
     entities::{
         authorization::{AuthContext, Permission},
-        task::{get_task_aggregate, save_task_aggregate, RunningTaskAggregate, TaskAggregate, TaskId},
+        task::{get_task_aggregate_for_update, save_task_aggregate, RunningTaskAggregate, TaskAggregate, TaskId},
     },
     ports::{task_repository::TaskRepository, task_run_repository::TaskRunRepository},
 };
@@ -16,17 +19,31 @@ pub enum SendTaskHeartbeatError {
     TaskNotFound,
     #[error("Task is not running")]
     TaskIsNotRunning,
+    #[error("Task run not found")]
+    TaskRunNotFound,
+    #[error("Task run exceeded the maximum duration of the task")]
+    TaskRunTimedOut,
     #[error("User is not allowed to send a heartbeat for this task")]
     Forbidden,
     #[error("Technical error")]
     TechnicalFailure(#[from] anyhow::Error),
 }
 
+#[derive(Serialize, Deserialize, TS, Clone, Debug, Default, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct SendTaskHeartbeatParams {
+    /// The id of the run sending the heartbeat, as returned when it started.
+    /// Defaults to the oldest running run of the task
+    pub run_id: Option<String>,
+}
+
 pub async fn send_task_heartbeat_use_case<TR, TRR>(
     auth_context: &AuthContext,
     task_repository: &TR,
     task_run_repository: &TRR,
     task_id: TaskId,
+    params: SendTaskHeartbeatParams,
 ) -> Result<(), SendTaskHeartbeatError>
 where
     TR: TaskRepository,
@@ -37,7 +54,7 @@ where
     }
 
     let mut tx = task_repository.begin_transaction().await?;
-    let aggregate = get_task_aggregate(
+    let aggregate = get_task_aggregate_for_update(
         task_repository,
         task_run_repository,
         &mut tx,
@@ -47,11 +64,21 @@ where
     .await?;
     let now = Utc::now();
 
+    let run_id = params.run_id.as_deref();
     let running_aggregate: RunningTaskAggregate = match aggregate {
         None => return Err(SendTaskHeartbeatError::TaskNotFound),
-        Some(TaskAggregate::Running(t)) => {
-            t.receive_heartbeat(now).context("failed to receive heartbeat")?
+        Some(TaskAggregate::Running(t)) => match run_id {
+            Some(run_id) if !t.is_running(run_id) => {
+                // the run was stopped by the platform while the process was still running, and other runs kept running
+                return Err(match t.has_timed_out(run_id) {
+                    true => SendTaskHeartbeatError::TaskRunTimedOut,
+                    false => SendTaskHeartbeatError::TaskRunNotFound,
+                });
+            }
+            _ => t.receive_heartbeat(run_id, now).context("failed to receive heartbeat")?,
         },
+        // the run was stopped by the platform while the process was still running
+        Some(TaskAggregate::Failing(t)) if t.has_timed_out(run_id) => return Err(SendTaskHeartbeatError::TaskRunTimedOut),
         Some(_) => return Err(SendTaskHeartbeatError::TaskIsNotRunning),
     };
 
@@ -0,0 +1,244 @@
+use anyhow::Context;
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        entity_metadata::EntityMetadata,
+        task::{
+            get_task_aggregate_for_update, save_task_aggregate, HealthyTaskAggregate,
+            RunningTaskAggregate, TaskAggregate, TaskAggregateError, TaskError, TaskId,
+        },
+    },
+    ports::{task_repository::TaskRepository, task_run_repository::TaskRunRepository},
+};
+
+use super::{find_unknown_upstream_task, CreateTaskCommand};
+
+#[cfg(test)]
+mod tests;
+
+/// An optional command that can be used to create a task on-the-fly when starting a task run
+#[derive(Debug, Clone, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct StartTaskCommand {
+    /// The properties of the new task to create if the task does not exist yet
+    #[serde(default)]
+    pub new_task: Option<NewTask>,
+    /// Whether to abort the previous running task.
+    /// For tasks with concurrent runs, the oldest running run is aborted when no other run can start
+    #[serde(default)]
+    pub abort_previous_running_task: bool,
+    /// Identifies the new run among the running runs of the task, to send its heartbeats and finish it
+    /// (e.g. the name of a shard). A random id is generated if not set
+    #[serde(default)]
+    pub run_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct NewTask {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub cron_schedule: Option<String>,
+    pub time_zone: Option<String>,
+    pub start_window_seconds: Option<u32>,
+    pub lateness_window_seconds: Option<u32>,
+    pub heartbeat_timeout_seconds: Option<u32>,
+    pub max_duration_seconds: Option<u32>,
+    pub max_concurrent_runs: Option<u32>,
+    #[ts(type = "Array<string> | null")]
+    pub upstream_task_ids: Option<Vec<TaskId>>,
+    pub metadata: Option<EntityMetadata>,
+    pub email_notification_enabled: Option<bool>,
+    pub push_notification_enabled: Option<bool>,
+    pub sms_notification_enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct StartTaskResponse {
+    /// The start date of the new task run, which identifies it (e.g. to send its logs)
+    pub started_at: DateTime<Utc>,
+    /// The id of the new task run, to send its heartbeats and finish it
+    pub run_id: String,
+}
+
+#[derive(Error, Debug)]
+pub enum StartTaskError {
+    #[error("Task not found")]
+    TaskNotFound,
+    #[error("Task already started")]
+    TaskAlreadyStarted,
+    #[error("A run with this id is already running")]
+    TaskRunAlreadyStarted,
+    #[error("Invalid run id: {details}")]
+    InvalidRunId { details: String },
+    #[error("{details}")]
+    InvalidUpstreamTasks { details: String },
+    #[error("Task is paused")]
+    TaskIsPaused,
+    #[error("User is not allowed to start this task")]
+    Forbidden,
+    #[error("Technical error")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+pub async fn start_task_use_case<TR, TRR>(
+    auth_context: &AuthContext,
+    task_repository: &TR,
+    task_run_repository: &TRR,
+    task_id: TaskId,
+    command: Option<StartTaskCommand>,
+) -> Result<StartTaskResponse, StartTaskError>
+where
+    TR: TaskRepository,
+    TRR: TaskRunRepository<Transaction = TR::Transaction>,
+{
+    if !auth_context.can(Permission::WriteTaskRuns) {
+        return Err(StartTaskError::Forbidden);
+    }
+
+    let run_id = match command.as_ref().and_then(|c| c.run_id.clone()) {
+        Some(run_id) => validate_run_id(run_id)?,
+        None => Uuid::new_v4().to_string(),
+    };
+    let abort_previous_running_task = command.as_ref().is_some_and(|c| c.abort_previous_running_task);
+
+    let mut tx = task_repository.begin_transaction().await?;
+    let aggregate = get_task_aggregate_for_update(
+        task_repository,
+        task_run_repository,
+        &mut tx,
+        auth_context.active_organization_id,
+        &task_id,
+    )
+    .await
+    .context("failed to get task aggregate from the database")?;
+
+    // timestamps are stored with a microsecond precision, the start date is truncated
+    // so that the returned start date identifies the stored task run
+    let mut now = Utc::now().trunc_subsecs(6);
+    // task runs are also identified by their start date, which must differ from the ones of the running runs
+    if let Some(TaskAggregate::Running(t)) = &aggregate {
+        if let Some(latest_started_at) = t.task_runs().iter().map(|r| *r.started_at()).max() {
+            now = now.max(latest_started_at + Duration::microseconds(1));
+        }
+    }
+
+    let running_aggregate: RunningTaskAggregate = match aggregate {
+        None => {
+            let command = command.ok_or(StartTaskError::TaskNotFound)?;
+            let new_task = command.new_task.ok_or(StartTaskError::TaskNotFound)?;
+            let upstream_task_ids = new_task.upstream_task_ids.as_deref().unwrap_or_default();
+            if let Some(upstream_task_id) = find_unknown_upstream_task(
+                &mut tx,
+                task_repository,
+                auth_context.active_organization_id,
+                upstream_task_ids,
+            )
+            .await?
+            {
+                return Err(StartTaskError::InvalidUpstreamTasks {
+                    details: format!("Upstream task not found: {upstream_task_id}"),
+                });
+            }
+            let new_task = CreateTaskCommand {
+                id: task_id,
+                name: new_task.name,
+                description: new_task.description,
+                cron_schedule: new_task.cron_schedule,
+                time_zone: new_task.time_zone,
+                start_window_seconds: new_task.start_window_seconds,
+                lateness_window_seconds: new_task.lateness_window_seconds,
+                heartbeat_timeout_seconds: new_task.heartbeat_timeout_seconds,
+                max_duration_seconds: new_task.max_duration_seconds,
+                max_concurrent_runs: new_task.max_concurrent_runs,
+                upstream_task_ids: new_task.upstream_task_ids,
+                metadata: new_task.metadata,
+                email_notification_enabled: new_task.email_notification_enabled,
+                push_notification_enabled: new_task.push_notification_enabled,
+                sms_notification_enabled: new_task.sms_notification_enabled,
+            };
+            let new_task = HealthyTaskAggregate::new(auth_context.active_organization_id, new_task)
+                .map_err(|e| match e {
+                    TaskAggregateError::TaskError(TaskError::InvalidUpstreamTasks { details }) => {
+                        StartTaskError::InvalidUpstreamTasks { details }
+                    }
+                    _ => StartTaskError::TechnicalFailure(anyhow::Error::from(e).context("failed to create a new task")),
+                })?;
+            new_task.start(now, run_id.clone()).context("failed to start new task")?.0
+        }
+        Some(TaskAggregate::Running(t)) => {
+            if t.is_running(&run_id) {
+                return Err(StartTaskError::TaskRunAlreadyStarted);
+            }
+            if t.can_start_run() {
+                t.start_run(now, run_id.clone()).context("failed to start another run of running task")?
+            } else if abort_previous_running_task {
+                // the oldest running run makes room for the new one
+                let completion = t.mark_aborted(None, now).context("failed to abort running task")?;
+                task_run_repository
+                    .upsert_task_run(&mut tx, completion.task_run)
+                    .await
+                    .context("failed to save aborted task run to the database")?;
+
+                match completion.aggregate {
+                    TaskAggregate::Running(t) => t.start_run(now, run_id.clone()),
+                    TaskAggregate::Healthy(t) => t.start(now, run_id.clone()).map(|(t, _)| t),
+                    TaskAggregate::Failing(t) => t.start(now, run_id.clone()).map(|(t, _)| t),
+                    _ => return Err(anyhow::anyhow!("aborting a task run left the task in an unexpected state").into()),
+                }
+                .context("failed to start aborted task")?
+            } else {
+                return Err(StartTaskError::TaskAlreadyStarted);
+            }
+        }
+        Some(TaskAggregate::Due(t)) => t.start(now, run_id.clone()).context("failed to start due task")?,
+        Some(TaskAggregate::Late(t)) => t.start(now, run_id.clone()).context("failed to start late task")?,
+        Some(TaskAggregate::Failing(t)) => t.start(now, run_id.clone()).context("failed to start failing task")?.0,
+        Some(TaskAggregate::Healthy(t)) => t.start(now, run_id.clone()).context("failed to start healthy task")?.0,
+        Some(TaskAggregate::Absent(t)) => t.start(now, run_id.clone()).context("failed to start absent task")?,
+        Some(TaskAggregate::Paused(_)) => return Err(StartTaskError::TaskIsPaused),
+    };
+
+    save_task_aggregate(
+        task_repository,
+        task_run_repository,
+        &mut tx,
+        TaskAggregate::Running(running_aggregate),
+    )
+    .await
+    .context("failed to save task aggregate to the database")?;
+
+    task_repository.commit_transaction(tx).await.context("failed to commit transaction")?;
+
+    Ok(StartTaskResponse { started_at: now, run_id })
+}
+
+/// The maximum length of a run id supplied by a client
+pub const MAXIMUM_RUN_ID_LENGTH: usize = 255;
+
+fn validate_run_id(run_id: String) -> Result<String, StartTaskError> {
+    let run_id = run_id.trim().to_string();
+    if run_id.is_empty() {
+        return Err(StartTaskError::InvalidRunId {
+            details: "the run id cannot be empty".to_string(),
+        });
+    }
+    if run_id.len() > MAXIMUM_RUN_ID_LENGTH {
+        return Err(StartTaskError::InvalidRunId {
+            details: format!("the run id cannot be longer than {MAXIMUM_RUN_ID_LENGTH} characters"),
+        });
+    }
+    Ok(run_id)
+}
@@ -0,0 +1,230 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            authorization::AuthContext,
+            organization::OrganizationUserRole,
+            task::{BoundaryTask, TaskId, TaskStatus},
+            task_run::TaskRunStatus,
+        },
+        ports::{task_repository::TaskRepository, transactional_repository::TransactionalRepository},
+        use_cases::tasks::{
+            finish_task_use_case, send_task_heartbeat_use_case, FinishTaskCommand, FinishTaskError,
+            FinishedTaskStatus, SendTaskHeartbeatError, SendTaskHeartbeatParams,
+        },
+    },
+    infrastructure::mocks::{
+        incident_event_repository_mock::IncidentEventRepositoryMock,
+        incident_notification_repository_mock::IncidentNotificationRepositoryMock,
+        incident_repository_mock::IncidentRepositoryMock,
+        maintenance_window_repository_mock::MaintenanceWindowRepositoryMock,
+        task_repository_mock::TaskRepositoryMock,
+        task_run_event_repository_mock::TaskRunEventRepositoryMock,
+        task_run_repository_mock::TaskRunRepositoryMock,
+    },
+};
+
+use super::{start_task_use_case, NewTask, StartTaskCommand, StartTaskError, StartTaskResponse};
+
+struct Repositories {
+    auth_context: AuthContext,
+    task: TaskRepositoryMock,
+    task_run: TaskRunRepositoryMock,
+    task_run_event: TaskRunEventRepositoryMock,
+    incident: IncidentRepositoryMock,
+    incident_event: IncidentEventRepositoryMock,
+    incident_notification: IncidentNotificationRepositoryMock,
+    maintenance_window: MaintenanceWindowRepositoryMock,
+}
+
+impl Repositories {
+    /// Creates the repositories with a healthy task allowing the given number of concurrent runs
+    async fn new(max_concurrent_runs: i32) -> anyhow::Result<(Self, BoundaryTask)> {
+        let task = BoundaryTask {
+            name: "Tenant export".to_string(),
+            max_concurrent_runs,
+            ..BoundaryTask::test_task(Uuid::new_v4(), "tenant-export", TaskStatus::Healthy)
+        };
+        let repositories = Self {
+            auth_context: AuthContext::test_context(
+                task.organization_id,
+                Uuid::new_v4(),
+                &[OrganizationUserRole::Editor],
+                &[],
+            ),
+            task: TaskRepositoryMock::new(),
+            task_run: TaskRunRepositoryMock::new(),
+            task_run_event: TaskRunEventRepositoryMock::new(),
+            incident: IncidentRepositoryMock::new(),
+            incident_event: IncidentEventRepositoryMock::new(),
+            incident_notification: IncidentNotificationRepositoryMock::new(),
+            maintenance_window: MaintenanceWindowRepositoryMock::new(),
+        };
+        let mut tx = repositories.task.begin_transaction().await?;
+        repositories.task.upsert_task(&mut tx, task.clone()).await?;
+        Ok((repositories, task))
+    }
+
+    async fn start(&self, task_id: &TaskId, run_id: Option<&str>) -> Result<StartTaskResponse, StartTaskError> {
+        start_task_use_case(
+            &self.auth_context,
+            &self.task,
+            &self.task_run,
+            task_id.clone(),
+            Some(StartTaskCommand {
+                new_task: None,
+                abort_previous_running_task: false,
+                run_id: run_id.map(str::to_string),
+            }),
+        )
+        .await
+    }
+
+    async fn finish(
+        &self,
+        task_id: &TaskId,
+        run_id: &str,
+        status: FinishedTaskStatus,
+    ) -> Result<(), FinishTaskError> {
+        finish_task_use_case(
+            &self.auth_context,
+            &self.task,
+            &self.task_run,
+            &self.task_run_event,
+            &self.incident,
+            &self.incident_event,
+            &self.incident_notification,
+            &self.maintenance_window,
+            task_id.clone(),
+            FinishTaskCommand {
+                status,
+                exit_code: None,
+                error_message: None,
+                run_id: Some(run_id.to_string()),
+            },
+        )
+        .await
+    }
+
+    async fn task_status(&self) -> TaskStatus {
+        self.task.state.lock().await[0].status
+    }
+
+    async fn task_run_status(&self, run_id: &str) -> TaskRunStatus {
+        self.task_run.state.lock().await.iter().find(|r| r.run_id == run_id).unwrap().status
+    }
+}
+
+#[tokio::test]
+async fn test_start_concurrent_runs_up_to_the_maximum() -> anyhow::Result<()> {
+    let (repositories, task) = Repositories::new(2).await?;
+
+    let first = repositories.start(&task.id, Some("tenant-a")).await?;
+    assert_eq!(first.run_id, "tenant-a");
+    let result = repositories.start(&task.id, Some("tenant-a")).await;
+    assert!(matches!(result, Err(StartTaskError::TaskRunAlreadyStarted)));
+
+    let second = repositories.start(&task.id, None).await?;
+    assert_ne!(second.run_id, first.run_id);
+    let result = repositories.start(&task.id, Some("tenant-c")).await;
+    assert!(matches!(result, Err(StartTaskError::TaskAlreadyStarted)));
+    assert_eq!(repositories.task_status().await, TaskStatus::Running);
+
+    send_task_heartbeat_use_case(
+        &repositories.auth_context,
+        &repositories.task,
+        &repositories.task_run,
+        task.id.clone(),
+        SendTaskHeartbeatParams { run_id: Some("tenant-a".to_string()) },
+    )
+    .await?;
+    let result = send_task_heartbeat_use_case(
+        &repositories.auth_context,
+        &repositories.task,
+        &repositories.task_run,
+        task.id.clone(),
+        SendTaskHeartbeatParams { run_id: Some("tenant-c".to_string()) },
+    )
+    .await;
+    assert!(matches!(result, Err(SendTaskHeartbeatError::TaskRunNotFound)));
+
+    // the task keeps running until all its runs are finished
+    repositories.finish(&task.id, "tenant-a", FinishedTaskStatus::Success).await?;
+    assert_eq!(repositories.task_status().await, TaskStatus::Running);
+    assert_eq!(repositories.task_run_status("tenant-a").await, TaskRunStatus::Finished);
+    let result = repositories.finish(&task.id, "tenant-a", FinishedTaskStatus::Success).await;
+    assert!(matches!(result, Err(FinishTaskError::TaskRunNotFound)));
+
+    // the finished run makes room for another one
+    repositories.start(&task.id, Some("tenant-c")).await?;
+    repositories.finish(&task.id, "tenant-c", FinishedTaskStatus::Success).await?;
+    repositories.finish(&task.id, &second.run_id, FinishedTaskStatus::Success).await?;
+    assert_eq!(repositories.task_status().await, TaskStatus::Healthy);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_concurrent_run_failure_fails_the_task() -> anyhow::Result<()> {
+    let (repositories, task) = Repositories::new(2).await?;
+
+    repositories.start(&task.id, Some("tenant-a")).await?;
+    repositories.start(&task.id, Some("tenant-b")).await?;
+
+    // the incident is opened as soon as a run fails
+    repositories.finish(&task.id, "tenant-a", FinishedTaskStatus::Failure).await?;
+    assert_eq!(repositories.task_status().await, TaskStatus::Running);
+    assert_eq!(repositories.task_run_status("tenant-a").await, TaskRunStatus::Failed);
+    assert_eq!(repositories.incident.state.lock().await.len(), 1);
+
+    // a successful run does not hide the failure of a concurrent one
+    repositories.finish(&task.id, "tenant-b", FinishedTaskStatus::Success).await?;
+    assert_eq!(repositories.task_status().await, TaskStatus::Failing);
+    assert_eq!(repositories.task_run_status("tenant-b").await, TaskRunStatus::Finished);
+    assert_eq!(repositories.incident.state.lock().await.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_start_with_invalid_run_id() -> anyhow::Result<()> {
+    let (repositories, task) = Repositories::new(1).await?;
+
+    let result = repositories.start(&task.id, Some("  ")).await;
+    assert!(matches!(result, Err(StartTaskError::InvalidRunId { .. })));
+    let result = repositories.start(&task.id, Some(&"a".repeat(256))).await;
+    assert!(matches!(result, Err(StartTaskError::InvalidRunId { .. })));
+
+    let response = repositories.start(&task.id, Some(" shard-1 ")).await?;
+    assert_eq!(response.run_id, "shard-1");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_start_new_task_with_unknown_upstream_task() -> anyhow::Result<()> {
+    let (repositories, task) = Repositories::new(1).await?;
+    let start_new_task = |upstream_task_id: &TaskId| {
+        let new_task: NewTask = serde_json::from_value(serde_json::json!({
+            "upstreamTaskIds": [upstream_task_id],
+        }))
+        .unwrap();
+        start_task_use_case(
+            &repositories.auth_context,
+            &repositories.task,
+            &repositories.task_run,
+            TaskId::new("tenant-report".to_string()).unwrap(),
+            Some(StartTaskCommand {
+                new_task: Some(new_task),
+                abort_previous_running_task: false,
+                run_id: None,
+            }),
+        )
+    };
+
+    let result = start_new_task(&TaskId::new("unknown-task".to_string()).unwrap()).await;
+    assert!(matches!(result, Err(StartTaskError::InvalidUpstreamTasks { .. })));
+    assert_eq!(repositories.task.state.lock().await.len(), 1);
+
+    start_new_task(&task.id).await?;
+    assert_eq!(repositories.task.state.lock().await.len(), 2);
+    Ok(())
+}
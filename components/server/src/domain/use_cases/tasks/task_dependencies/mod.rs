@@ -0,0 +1,186 @@
+use std::collections::HashSet;
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::task::{
+        get_task_aggregate, save_task_aggregate, BoundaryTask, TaskAggregate, TaskId, TaskStatus,
+    },
+    ports::{task_repository::TaskRepository, task_run_repository::TaskRunRepository},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Marks the downstream tasks of a task that just finished as due, if all their upstream tasks are healthy.
+/// Downstream tasks that are already due, late, running, absent or paused are left untouched.
+/// Returns the downstream tasks that were marked as due
+pub async fn mark_downstream_tasks_due<TR, TRR>(
+    transaction: &mut TR::Transaction,
+    task_repository: &TR,
+    task_run_repository: &TRR,
+    task: &BoundaryTask,
+    now: DateTime<Utc>,
+) -> anyhow::Result<Vec<BoundaryTask>>
+where
+    TR: TaskRepository,
+    TRR: TaskRunRepository<Transaction = TR::Transaction>,
+{
+    let downstream_tasks = task_repository
+        .list_downstream_tasks(transaction, task.organization_id, &task.id)
+        .await
+        .context("Failed to list the downstream tasks of the task")?;
+
+    let mut due_tasks = Vec::new();
+    for downstream_task in downstream_tasks {
+        if !matches!(downstream_task.status, TaskStatus::Healthy | TaskStatus::Failing) {
+            continue;
+        }
+        if !all_upstream_tasks_healthy(transaction, task_repository, &downstream_task).await? {
+            continue;
+        }
+
+        let due_aggregate = match get_task_aggregate(
+            task_repository,
+            task_run_repository,
+            transaction,
+            downstream_task.organization_id,
+            &downstream_task.id,
+        )
+        .await?
+        {
+            Some(TaskAggregate::Healthy(agg)) => agg.mark_due_after_upstream_tasks(now)?,
+            Some(TaskAggregate::Failing(agg)) => agg.mark_due_after_upstream_tasks(now)?,
+            _ => continue,
+        };
+        let (due_task, _) = save_task_aggregate(
+            task_repository,
+            task_run_repository,
+            transaction,
+            TaskAggregate::Due(due_aggregate),
+        )
+        .await
+        .context("Failed to save downstream task aggregate")?;
+        due_tasks.push(due_task);
+    }
+
+    Ok(due_tasks)
+}
+
+/// Returns the closest failing or absent task among the upstream tasks of a task, and their own upstream tasks.
+/// This is the root cause of a missing run of the task, if any
+pub async fn find_failing_upstream_task<TR>(
+    transaction: &mut TR::Transaction,
+    task_repository: &TR,
+    task: &BoundaryTask,
+) -> anyhow::Result<Option<BoundaryTask>>
+where
+    TR: TaskRepository,
+{
+    // upstream tasks are visited breadth-first, and only once, since dependencies can form cycles
+    let mut visited: HashSet<TaskId> = HashSet::from([task.id.clone()]);
+    let mut upstream_task_ids = task.upstream_task_ids.clone();
+    while !upstream_task_ids.is_empty() {
+        let mut next_upstream_task_ids = Vec::new();
+        for upstream_task_id in upstream_task_ids {
+            if !visited.insert(upstream_task_id.clone()) {
+                continue;
+            }
+            let Some(upstream_task) = task_repository
+                .get_task(transaction, task.organization_id, &upstream_task_id)
+                .await
+                .context("Failed to get upstream task")?
+            else {
+                continue;
+            };
+            if matches!(upstream_task.status, TaskStatus::Failing | TaskStatus::Absent) {
+                return Ok(Some(upstream_task));
+            }
+            next_upstream_task_ids.extend(upstream_task.upstream_task_ids);
+        }
+        upstream_task_ids = next_upstream_task_ids;
+    }
+
+    Ok(None)
+}
+
+/// Returns the first upstream task that does not exist in the organization, if any
+pub async fn find_unknown_upstream_task<TR>(
+    transaction: &mut TR::Transaction,
+    task_repository: &TR,
+    organization_id: Uuid,
+    upstream_task_ids: &[TaskId],
+) -> anyhow::Result<Option<TaskId>>
+where
+    TR: TaskRepository,
+{
+    for upstream_task_id in upstream_task_ids {
+        let upstream_task = task_repository
+            .get_task(transaction, organization_id, upstream_task_id)
+            .await
+            .context("Failed to get upstream task")?;
+        if upstream_task.is_none() {
+            return Ok(Some(upstream_task_id.clone()));
+        }
+    }
+    Ok(None)
+}
+
+/// Returns the first of the given upstream tasks that already depends on the task, directly or through its own
+/// upstream tasks, if any. Making the task depend on it would create a dependency cycle
+pub async fn find_cyclic_upstream_task<TR>(
+    transaction: &mut TR::Transaction,
+    task_repository: &TR,
+    organization_id: Uuid,
+    task_id: &TaskId,
+    upstream_task_ids: &[TaskId],
+) -> anyhow::Result<Option<TaskId>>
+where
+    TR: TaskRepository,
+{
+    // tasks that were already visited do not lead to the task, since the walk would have stopped there
+    let mut visited: HashSet<TaskId> = HashSet::new();
+    for upstream_task_id in upstream_task_ids {
+        let mut next_task_ids = vec![upstream_task_id.clone()];
+        while let Some(next_task_id) = next_task_ids.pop() {
+            if &next_task_id == task_id {
+                return Ok(Some(upstream_task_id.clone()));
+            }
+            if !visited.insert(next_task_id.clone()) {
+                continue;
+            }
+            if let Some(next_task) = task_repository
+                .get_task(transaction, organization_id, &next_task_id)
+                .await
+                .context("Failed to get upstream task")?
+            {
+                next_task_ids.extend(next_task.upstream_task_ids);
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Whether all the upstream tasks of a task are healthy.
+/// An upstream task that no longer exists is not healthy, so that the task does not become due without it
+async fn all_upstream_tasks_healthy<TR>(
+    transaction: &mut TR::Transaction,
+    task_repository: &TR,
+    task: &BoundaryTask,
+) -> anyhow::Result<bool>
+where
+    TR: TaskRepository,
+{
+    for upstream_task_id in &task.upstream_task_ids {
+        let upstream_task = task_repository
+            .get_task(transaction, task.organization_id, upstream_task_id)
+            .await
+            .context("Failed to get upstream task")?;
+        if upstream_task.is_none_or(|t| t.status != TaskStatus::Healthy) {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
@@ -0,0 +1,221 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::task::{BoundaryTask, TaskId, TaskStatus},
+    ports::{task_repository::TaskRepository, transactional_repository::TransactionalRepository},
+};
+use crate::infrastructure::mocks::{
+    task_repository_mock::TaskRepositoryMock, task_run_repository_mock::TaskRunRepositoryMock,
+};
+
+use super::{find_cyclic_upstream_task, find_failing_upstream_task, mark_downstream_tasks_due};
+
+fn create_test_task(
+    organization_id: Uuid,
+    id: &str,
+    status: TaskStatus,
+    upstream_task_ids: &[&str],
+) -> BoundaryTask {
+    BoundaryTask {
+        upstream_task_ids: upstream_task_ids
+            .iter()
+            .map(|id| TaskId::new(id.to_string()).unwrap())
+            .collect(),
+        ..BoundaryTask::test_task(organization_id, id, status)
+    }
+}
+
+async fn create_task_repository(tasks: Vec<BoundaryTask>) -> anyhow::Result<TaskRepositoryMock> {
+    let task_repository = TaskRepositoryMock::new();
+    let mut tx = task_repository.begin_transaction().await?;
+    for task in tasks {
+        task_repository.upsert_task(&mut tx, task).await?;
+    }
+    Ok(task_repository)
+}
+
+#[tokio::test]
+async fn test_mark_downstream_tasks_due_when_upstream_task_finished() -> anyhow::Result<()> {
+    let organization_id = Uuid::new_v4();
+    let export = create_test_task(organization_id, "export", TaskStatus::Healthy, &[]);
+    let task_repository = create_task_repository(vec![
+        export.clone(),
+        create_test_task(organization_id, "import", TaskStatus::Healthy, &["export"]),
+        create_test_task(organization_id, "unrelated", TaskStatus::Healthy, &[]),
+    ])
+    .await?;
+    let mut tx = task_repository.begin_transaction().await?;
+
+    let now = Utc::now();
+    let due_tasks = mark_downstream_tasks_due(
+        &mut tx,
+        &task_repository,
+        &TaskRunRepositoryMock::new(),
+        &export,
+        now,
+    )
+    .await?;
+
+    assert_eq!(due_tasks.len(), 1);
+    let import = task_repository
+        .get_task(&mut tx, organization_id, &TaskId::new("import".to_string()).unwrap())
+        .await?
+        .unwrap();
+    assert_eq!(import.status, TaskStatus::Due);
+    assert_eq!(import.next_due_at, Some(now));
+    let unrelated = task_repository
+        .get_task(&mut tx, organization_id, &TaskId::new("unrelated".to_string()).unwrap())
+        .await?
+        .unwrap();
+    assert_eq!(unrelated.status, TaskStatus::Healthy);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mark_downstream_tasks_due_waits_for_all_upstream_tasks() -> anyhow::Result<()> {
+    let organization_id = Uuid::new_v4();
+    let export = create_test_task(organization_id, "export", TaskStatus::Healthy, &[]);
+    let task_repository = create_task_repository(vec![
+        export.clone(),
+        create_test_task(organization_id, "fetch-rates", TaskStatus::Running, &[]),
+        create_test_task(
+            organization_id,
+            "import",
+            TaskStatus::Healthy,
+            &["export", "fetch-rates"],
+        ),
+    ])
+    .await?;
+    let mut tx = task_repository.begin_transaction().await?;
+
+    let due_tasks = mark_downstream_tasks_due(
+        &mut tx,
+        &task_repository,
+        &TaskRunRepositoryMock::new(),
+        &export,
+        Utc::now(),
+    )
+    .await?;
+
+    assert!(due_tasks.is_empty());
+    let import = task_repository
+        .get_task(&mut tx, organization_id, &TaskId::new("import".to_string()).unwrap())
+        .await?
+        .unwrap();
+    assert_eq!(import.status, TaskStatus::Healthy);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mark_downstream_tasks_due_waits_for_deleted_upstream_tasks() -> anyhow::Result<()> {
+    let organization_id = Uuid::new_v4();
+    let export = create_test_task(organization_id, "export", TaskStatus::Healthy, &[]);
+    let task_repository = create_task_repository(vec![
+        export.clone(),
+        create_test_task(
+            organization_id,
+            "import",
+            TaskStatus::Healthy,
+            &["export", "deleted-task"],
+        ),
+    ])
+    .await?;
+    let mut tx = task_repository.begin_transaction().await?;
+
+    let due_tasks = mark_downstream_tasks_due(
+        &mut tx,
+        &task_repository,
+        &TaskRunRepositoryMock::new(),
+        &export,
+        Utc::now(),
+    )
+    .await?;
+
+    assert!(due_tasks.is_empty());
+    let import = task_repository
+        .get_task(&mut tx, organization_id, &TaskId::new("import".to_string()).unwrap())
+        .await?
+        .unwrap();
+    assert_eq!(import.status, TaskStatus::Healthy);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_find_failing_upstream_task_returns_the_root_cause() -> anyhow::Result<()> {
+    let organization_id = Uuid::new_v4();
+    let report = create_test_task(organization_id, "report", TaskStatus::Due, &["import"]);
+    let task_repository = create_task_repository(vec![
+        create_test_task(organization_id, "export", TaskStatus::Failing, &[]),
+        create_test_task(organization_id, "import", TaskStatus::Healthy, &["export"]),
+        report.clone(),
+    ])
+    .await?;
+    let mut tx = task_repository.begin_transaction().await?;
+
+    let failing_upstream_task =
+        find_failing_upstream_task(&mut tx, &task_repository, &report).await?;
+
+    assert_eq!(
+        failing_upstream_task.map(|t| t.id),
+        Some(TaskId::new("export".to_string()).unwrap())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_find_failing_upstream_task_handles_cycles() -> anyhow::Result<()> {
+    let organization_id = Uuid::new_v4();
+    let import = create_test_task(organization_id, "import", TaskStatus::Due, &["export"]);
+    let task_repository = create_task_repository(vec![
+        create_test_task(organization_id, "export", TaskStatus::Healthy, &["import"]),
+        import.clone(),
+    ])
+    .await?;
+    let mut tx = task_repository.begin_transaction().await?;
+
+    let failing_upstream_task =
+        find_failing_upstream_task(&mut tx, &task_repository, &import).await?;
+
+    assert!(failing_upstream_task.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_find_cyclic_upstream_task() -> anyhow::Result<()> {
+    let organization_id = Uuid::new_v4();
+    let task_id = TaskId::new("import".to_string()).unwrap();
+    let task_repository = create_task_repository(vec![
+        create_test_task(organization_id, "import", TaskStatus::Healthy, &[]),
+        create_test_task(organization_id, "transform", TaskStatus::Healthy, &["import"]),
+        create_test_task(organization_id, "export", TaskStatus::Healthy, &["transform"]),
+        create_test_task(organization_id, "cleanup", TaskStatus::Healthy, &[]),
+    ])
+    .await?;
+    let mut tx = task_repository.begin_transaction().await?;
+    let ids = |ids: &[&str]| -> Vec<TaskId> {
+        ids.iter().map(|id| TaskId::new(id.to_string()).unwrap()).collect()
+    };
+
+    let cyclic_upstream_task = find_cyclic_upstream_task(
+        &mut tx,
+        &task_repository,
+        organization_id,
+        &task_id,
+        &ids(&["cleanup", "export"]),
+    )
+    .await?;
+    assert_eq!(cyclic_upstream_task, Some(TaskId::new("export".to_string()).unwrap()));
+
+    let cyclic_upstream_task =
+        find_cyclic_upstream_task(&mut tx, &task_repository, organization_id, &task_id, &ids(&["cleanup"]))
+            .await?;
+    assert!(cyclic_upstream_task.is_none());
+
+    Ok(())
+}
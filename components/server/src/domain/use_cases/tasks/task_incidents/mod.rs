@@ -0,0 +1,264 @@
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    entities::{
+        incident::{
+            Incident, IncidentCause, IncidentPriority, IncidentSource, IncidentStatus,
+            NewIncident, TaskIncidentCause,
+        },
+        incident_event::{DownstreamTaskSkippedEventPayload, IncidentEvent, IncidentEventPayload, IncidentEventType},
+        incident_notification::IncidentNotificationPayload,
+        task::BoundaryTask,
+        task_run::BoundaryTaskRun,
+        task_run_event::{TaskRunEventPayload, TaskRunEventType, TaskRunLogLine},
+    },
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::{IncidentRepository, ListIncidentsOpts},
+        maintenance_window_repository::MaintenanceWindowRepository,
+        task_run_event_repository::TaskRunEventRepository,
+    },
+    use_cases::{
+        incidents::{create_incident, resolve_incident, NotificationOpts},
+        maintenance_windows::find_ongoing_maintenance_window,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The number of lines of output of a task run included in the cause of its incident
+pub const TASK_INCIDENT_OUTPUT_TAIL_LENGTH: usize = 10;
+
+/// Opens an incident for a task that switched to failing or absent.
+/// If the task already has an ongoing incident (e.g. a task that keeps failing),
+/// no new incident is created and the cause of the ongoing incident is updated instead.
+/// No incident is opened while the task is under maintenance.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_task_incident<IR, IER, INR, MWR>(
+    transaction: &mut IR::Transaction,
+    incident_repo: &IR,
+    incident_event_repo: &IER,
+    incident_notification_repo: &INR,
+    maintenance_window_repo: &MWR,
+    task: &BoundaryTask,
+    task_run: Option<&BoundaryTaskRun>,
+    task_run_output_tail: Vec<String>,
+) -> anyhow::Result<()>
+where
+    IR: IncidentRepository,
+    IER: IncidentEventRepository<Transaction = IR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = IR::Transaction>,
+    MWR: MaintenanceWindowRepository<Transaction = IR::Transaction>,
+{
+    let cause = IncidentCause::TaskIncidentCause(TaskIncidentCause {
+        task_id: task.id.clone(),
+        task_name: task.name.clone(),
+        task_status: task.status,
+        task_run_status: task_run.map(|r| r.status),
+        task_run_exit_code: task_run.and_then(|r| r.exit_code),
+        task_run_error_message: task_run.and_then(|r| r.error_message.clone()),
+        task_run_output_tail,
+    });
+
+    if let Some(incident) =
+        get_ongoing_task_incident(transaction, incident_repo, task).await?
+    {
+        incident_repo
+            .update_incident(
+                transaction,
+                Incident {
+                    cause: Some(cause),
+                    ..incident
+                },
+            )
+            .await
+            .context("Failed to update the cause of the ongoing task incident")?;
+        return Ok(());
+    }
+
+    if find_ongoing_maintenance_window(
+        transaction,
+        maintenance_window_repo,
+        task.organization_id,
+        Utc::now(),
+        |window| window.covers_task(&task.id, &task.metadata),
+    )
+    .await?
+    .is_some()
+    {
+        return Ok(());
+    }
+
+    let mut metadata = task.metadata.clone();
+    metadata
+        .records
+        .insert("task_id".to_string(), task.id.to_string());
+
+    let new_incident = NewIncident {
+        organization_id: task.organization_id,
+        created_by: None,
+        status: IncidentStatus::Ongoing,
+        // TODO: let users configure this
+        priority: IncidentPriority::Major,
+        source: IncidentSource::Task { id: task.uuid },
+        cause: Some(cause.clone()),
+        metadata,
+        title: None,
+    };
+
+    let notification = NotificationOpts {
+        send_sms: task.sms_notification_enabled,
+        send_push_notification: task.push_notification_enabled,
+        send_email: task.email_notification_enabled,
+        notification_payload: IncidentNotificationPayload {
+            incident_cause: cause,
+            incident_http_monitor_url: None,
+        },
+    };
+
+    create_incident(
+        transaction,
+        incident_repo,
+        incident_event_repo,
+        incident_notification_repo,
+        new_incident,
+        Some(notification),
+    )
+    .await
+    .context("Failed to create task incident")?;
+
+    Ok(())
+}
+
+/// Resolves the ongoing incident of a task, if any
+pub async fn resolve_task_incident<IR, IER, INR>(
+    transaction: &mut IR::Transaction,
+    incident_repo: &IR,
+    incident_event_repo: &IER,
+    incident_notification_repo: &INR,
+    task: &BoundaryTask,
+) -> anyhow::Result<()>
+where
+    IR: IncidentRepository,
+    IER: IncidentEventRepository<Transaction = IR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = IR::Transaction>,
+{
+    if let Some(incident) =
+        get_ongoing_task_incident(transaction, incident_repo, task).await?
+    {
+        resolve_incident(
+            transaction,
+            incident_repo,
+            incident_event_repo,
+            incident_notification_repo,
+            &incident,
+            None,
+        )
+        .await
+        .context("Failed to resolve task incident")?;
+    }
+
+    Ok(())
+}
+
+/// Records on the ongoing incident of a failing upstream task that the expected run of a downstream task was skipped,
+/// so that the missing run is attributed to its root cause instead of opening an incident of its own
+pub async fn record_skipped_downstream_task<IER>(
+    transaction: &mut IER::Transaction,
+    incident_event_repo: &IER,
+    incident: &Incident,
+    downstream_task: &BoundaryTask,
+    due_at: DateTime<Utc>,
+) -> anyhow::Result<()>
+where
+    IER: IncidentEventRepository,
+{
+    let event = IncidentEvent {
+        organization_id: incident.organization_id,
+        incident_id: incident.id,
+        user_id: None,
+        created_at: Utc::now(),
+        event_type: IncidentEventType::DownstreamTaskSkipped,
+        event_payload: Some(IncidentEventPayload::DownstreamTaskSkipped(
+            DownstreamTaskSkippedEventPayload {
+                task_id: downstream_task.id.clone(),
+                task_name: downstream_task.name.clone(),
+                due_at,
+            },
+        )),
+    };
+    incident_event_repo
+        .create_incident_event(transaction, event)
+        .await
+        .context("Failed to record skipped downstream task on the upstream task incident")?;
+
+    Ok(())
+}
+
+/// Returns the ongoing incident of a task, if any
+pub async fn get_ongoing_task_incident<IR>(
+    transaction: &mut IR::Transaction,
+    incident_repo: &IR,
+    task: &BoundaryTask,
+) -> anyhow::Result<Option<Incident>>
+where
+    IR: IncidentRepository,
+{
+    let incident = incident_repo
+        .list_incidents(
+            transaction,
+            task.organization_id,
+            ListIncidentsOpts {
+                include_statuses: &[IncidentStatus::Ongoing, IncidentStatus::ToBeConfirmed],
+                include_priorities: &IncidentPriority::ALL,
+                include_sources: &[IncidentSource::Task { id: task.uuid }],
+                limit: 1,
+                ..Default::default()
+            },
+        )
+        .await
+        .context("Failed to list ongoing incidents for task")?
+        .incidents
+        .into_iter()
+        .next();
+
+    Ok(incident)
+}
+
+/// Returns the last lines of output sent by a task run, oldest first
+pub async fn get_task_run_output_tail<TRER>(
+    transaction: &mut TRER::Transaction,
+    task_run_event_repo: &TRER,
+    task_run: &BoundaryTaskRun,
+) -> anyhow::Result<Vec<String>>
+where
+    TRER: TaskRunEventRepository,
+{
+    let events = task_run_event_repo
+        .list_latest_task_run_events(
+            transaction,
+            task_run.organization_id,
+            &task_run.task_id,
+            &task_run.run_id,
+            task_run.started_at,
+            TaskRunEventType::Logs,
+            TASK_INCIDENT_OUTPUT_TAIL_LENGTH as u32,
+        )
+        .await
+        .context("Failed to list the latest logs of the task run")?;
+
+    // events are listed newest first, so the lines are collected from the end
+    let mut tail: Vec<String> = events
+        .iter()
+        .filter_map(|event| event.event_payload.as_ref())
+        .flat_map(|TaskRunEventPayload::Logs(logs)| logs.lines.iter().rev())
+        .take(TASK_INCIDENT_OUTPUT_TAIL_LENGTH)
+        .map(TaskRunLogLine::to_text)
+        .collect();
+    tail.reverse();
+
+    Ok(tail)
+}
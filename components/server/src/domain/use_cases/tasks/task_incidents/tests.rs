@@ -0,0 +1,398 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        entity_metadata::MetadataFilter,
+        incident::{IncidentCause, IncidentSourceType, IncidentStatus, TaskIncidentCause},
+        incident_event::{IncidentEventPayload, IncidentEventType},
+        incident_notification::IncidentNotificationType,
+        maintenance_window::{MaintenanceWindow, MaintenanceWindowScope},
+        task::{BoundaryTask, TaskId, TaskStatus},
+        task_run::{BoundaryTaskRun, TaskRunStatus},
+    },
+    ports::transactional_repository::TransactionalRepository,
+};
+use crate::infrastructure::mocks::{
+    incident_event_repository_mock::IncidentEventRepositoryMock,
+    incident_notification_repository_mock::IncidentNotificationRepositoryMock,
+    incident_repository_mock::IncidentRepositoryMock,
+    maintenance_window_repository_mock::MaintenanceWindowRepositoryMock,
+};
+
+use super::{
+    create_task_incident, get_ongoing_task_incident, record_skipped_downstream_task,
+    resolve_task_incident,
+};
+
+fn create_test_task(status: TaskStatus) -> BoundaryTask {
+    BoundaryTask {
+        previous_status: Some(TaskStatus::Running),
+        ..BoundaryTask::test_task(Uuid::new_v4(), "nightly-backup", status)
+    }
+}
+
+fn create_test_failed_task_run(task: &BoundaryTask, exit_code: i32) -> BoundaryTaskRun {
+    BoundaryTaskRun {
+        organization_id: task.organization_id,
+        task_id: task.id.clone(),
+        run_id: "first".to_string(),
+        status: TaskRunStatus::Failed,
+        started_at: Utc::now(),
+        updated_at: Utc::now(),
+        completed_at: Some(Utc::now()),
+        exit_code: Some(exit_code),
+        error_message: Some("disk full".to_string()),
+        last_heartbeat_at: None,
+        heartbeat_timeout_seconds: 30,
+    }
+}
+
+#[tokio::test]
+async fn test_create_task_incident_for_failing_task() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let mut tx = incident_repo.begin_transaction().await?;
+
+    let task = create_test_task(TaskStatus::Failing);
+    let task_run = create_test_failed_task_run(&task, 1);
+
+    create_task_incident(
+        &mut tx,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        &MaintenanceWindowRepositoryMock::new(),
+        &task,
+        Some(&task_run),
+        vec!["[stderr] rsync: write failed: No space left on device".to_string()],
+    )
+    .await?;
+
+    let incident_state = incident_repo.state.lock().await;
+    assert_eq!(incident_state.len(), 1);
+    let incident = &incident_state[0];
+    assert_eq!(incident.status, IncidentStatus::Ongoing);
+    assert_eq!(incident.incident_source_type, IncidentSourceType::Task);
+    assert_eq!(incident.incident_source_id, task.uuid);
+    assert_eq!(
+        incident.cause,
+        Some(IncidentCause::TaskIncidentCause(TaskIncidentCause {
+            task_id: task.id.clone(),
+            task_name: task.name.clone(),
+            task_status: TaskStatus::Failing,
+            task_run_status: Some(TaskRunStatus::Failed),
+            task_run_exit_code: Some(1),
+            task_run_error_message: Some("disk full".to_string()),
+            task_run_output_tail: vec![
+                "[stderr] rsync: write failed: No space left on device".to_string()
+            ],
+        }))
+    );
+
+    let event_state = incident_event_repo.state.lock().await;
+    assert_eq!(event_state.len(), 1);
+    assert_eq!(event_state[0].event_type, IncidentEventType::Creation);
+
+    let notification_state = incident_notification_repo.state.lock().await;
+    assert_eq!(notification_state.len(), 1);
+    assert_eq!(
+        notification_state[0].notification_type,
+        IncidentNotificationType::IncidentCreation
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_task_incident_uses_task_notification_settings() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let mut tx = incident_repo.begin_transaction().await?;
+
+    let task = BoundaryTask {
+        email_notification_enabled: false,
+        sms_notification_enabled: true,
+        ..create_test_task(TaskStatus::Absent)
+    };
+    create_task_incident(
+        &mut tx,
+        &incident_repo,
+        &IncidentEventRepositoryMock::new(),
+        &incident_notification_repo,
+        &MaintenanceWindowRepositoryMock::new(),
+        &task,
+        None,
+        Vec::new(),
+    )
+    .await?;
+
+    let notification_state = incident_notification_repo.state.lock().await;
+    assert_eq!(notification_state.len(), 1);
+    assert!(notification_state[0].send_sms);
+    assert!(notification_state[0].send_push_notification);
+    assert!(!notification_state[0].send_email);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_task_incident_updates_ongoing_incident() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let mut tx = incident_repo.begin_transaction().await?;
+
+    // The task is absent first, then it fails
+    let absent_task = create_test_task(TaskStatus::Absent);
+    create_task_incident(
+        &mut tx,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        &MaintenanceWindowRepositoryMock::new(),
+        &absent_task,
+        None,
+        Vec::new(),
+    )
+    .await?;
+
+    let failing_task = BoundaryTask {
+        status: TaskStatus::Failing,
+        ..absent_task.clone()
+    };
+    let task_run = create_test_failed_task_run(&failing_task, 2);
+    create_task_incident(
+        &mut tx,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        &MaintenanceWindowRepositoryMock::new(),
+        &failing_task,
+        Some(&task_run),
+        Vec::new(),
+    )
+    .await?;
+
+    // Only one incident is opened, and its cause reflects the latest failure
+    let incident_state = incident_repo.state.lock().await;
+    assert_eq!(incident_state.len(), 1);
+    match &incident_state[0].cause {
+        Some(IncidentCause::TaskIncidentCause(cause)) => {
+            assert_eq!(cause.task_status, TaskStatus::Failing);
+            assert_eq!(cause.task_run_exit_code, Some(2));
+        }
+        _ => panic!("Incident cause should be TaskIncidentCause"),
+    }
+
+    // Users are notified only once
+    let notification_state = incident_notification_repo.state.lock().await;
+    assert_eq!(notification_state.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resolve_task_incident() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let mut tx = incident_repo.begin_transaction().await?;
+
+    let task = create_test_task(TaskStatus::Absent);
+    create_task_incident(
+        &mut tx,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        &MaintenanceWindowRepositoryMock::new(),
+        &task,
+        None,
+        Vec::new(),
+    )
+    .await?;
+
+    let healthy_task = BoundaryTask {
+        status: TaskStatus::Healthy,
+        ..task
+    };
+    resolve_task_incident(
+        &mut tx,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        &healthy_task,
+    )
+    .await?;
+
+    let incident_state = incident_repo.state.lock().await;
+    assert_eq!(incident_state.len(), 1);
+    assert_eq!(incident_state[0].status, IncidentStatus::Resolved);
+
+    let event_state = incident_event_repo.state.lock().await;
+    assert_eq!(event_state.len(), 2);
+    assert_eq!(event_state[1].event_type, IncidentEventType::Resolution);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_task_incident_under_maintenance() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let maintenance_window_repo = MaintenanceWindowRepositoryMock::new();
+    let mut tx = incident_repo.begin_transaction().await?;
+
+    let task = create_test_task(TaskStatus::Failing);
+    // a window that recurs every minute and lasts two minutes is always ongoing
+    maintenance_window_repo.state.lock().await.push(MaintenanceWindow {
+        organization_id: task.organization_id,
+        id: Uuid::new_v4(),
+        title: "Nightly maintenance".to_string(),
+        description: None,
+        starts_at: Utc::now() - Duration::days(7),
+        ends_at: None,
+        cron_schedule: Some("* * * * *".to_string()),
+        duration_seconds: Some(120),
+        scope: MaintenanceWindowScope {
+            task_ids: vec![task.id.clone()],
+            ..Default::default()
+        },
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    });
+
+    create_task_incident(
+        &mut tx,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        &maintenance_window_repo,
+        &task,
+        None,
+        Vec::new(),
+    )
+    .await?;
+
+    assert!(incident_repo.state.lock().await.is_empty());
+    assert!(incident_notification_repo.state.lock().await.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_task_incident_uses_task_metadata() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let maintenance_window_repo = MaintenanceWindowRepositoryMock::new();
+    let mut tx = incident_repo.begin_transaction().await?;
+
+    let task_with_env = |env: &str| {
+        let mut task = create_test_task(TaskStatus::Failing);
+        task.metadata.records.insert("env".to_string(), env.to_string());
+        task
+    };
+    let staging_task = task_with_env("staging");
+    // the staging environment is under maintenance
+    maintenance_window_repo.state.lock().await.push(MaintenanceWindow {
+        organization_id: staging_task.organization_id,
+        id: Uuid::new_v4(),
+        title: "Staging maintenance".to_string(),
+        description: None,
+        starts_at: Utc::now() - Duration::days(7),
+        ends_at: Some(Utc::now() + Duration::days(1)),
+        cron_schedule: None,
+        duration_seconds: None,
+        scope: MaintenanceWindowScope {
+            metadata_filter: Some(MetadataFilter {
+                items: HashMap::from([("env".to_string(), vec!["staging".to_string()])]),
+            }),
+            ..Default::default()
+        },
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    });
+
+    let production_task = BoundaryTask {
+        organization_id: staging_task.organization_id,
+        ..task_with_env("production")
+    };
+    for task in [&staging_task, &production_task] {
+        create_task_incident(
+            &mut tx,
+            &incident_repo,
+            &incident_event_repo,
+            &incident_notification_repo,
+            &maintenance_window_repo,
+            task,
+            None,
+            Vec::new(),
+        )
+        .await?;
+    }
+
+    // only the production task is not under maintenance
+    let incident_state = incident_repo.state.lock().await;
+    assert_eq!(incident_state.len(), 1);
+    let records = &incident_state[0].metadata.records;
+    assert_eq!(records.get("env").map(String::as_str), Some("production"));
+    assert_eq!(records.get("task_id"), Some(&production_task.id.to_string()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_record_skipped_downstream_task() -> anyhow::Result<()> {
+    let incident_repo = IncidentRepositoryMock::new();
+    let incident_event_repo = IncidentEventRepositoryMock::new();
+    let incident_notification_repo = IncidentNotificationRepositoryMock::new();
+    let mut tx = incident_repo.begin_transaction().await?;
+
+    let upstream_task = create_test_task(TaskStatus::Failing);
+    let downstream_task = BoundaryTask {
+        id: TaskId::new("nightly-backup-upload".to_string()).unwrap(),
+        uuid: Uuid::new_v4(),
+        name: "Nightly backup upload".to_string(),
+        status: TaskStatus::Healthy,
+        upstream_task_ids: vec![upstream_task.id.clone()],
+        ..upstream_task.clone()
+    };
+
+    // without an ongoing incident, the skipped run cannot be attributed to the upstream task
+    assert!(get_ongoing_task_incident(&mut tx, &incident_repo, &upstream_task).await?.is_none());
+
+    create_task_incident(
+        &mut tx,
+        &incident_repo,
+        &incident_event_repo,
+        &incident_notification_repo,
+        &MaintenanceWindowRepositoryMock::new(),
+        &upstream_task,
+        None,
+        Vec::new(),
+    )
+    .await?;
+    let incident = get_ongoing_task_incident(&mut tx, &incident_repo, &upstream_task)
+        .await?
+        .expect("the upstream task must have an ongoing incident");
+    let due_at = Utc::now();
+    record_skipped_downstream_task(&mut tx, &incident_event_repo, &incident, &downstream_task, due_at)
+        .await?;
+
+    // the skipped run is attributed to the incident of the upstream task, and no other incident is opened
+    assert_eq!(incident_repo.state.lock().await.len(), 1);
+    let event_state = incident_event_repo.state.lock().await;
+    assert_eq!(event_state.len(), 2);
+    assert_eq!(event_state[1].event_type, IncidentEventType::DownstreamTaskSkipped);
+    match &event_state[1].event_payload {
+        Some(IncidentEventPayload::DownstreamTaskSkipped(payload)) => {
+            assert_eq!(payload.task_id, downstream_task.id);
+            assert_eq!(payload.due_at, due_at);
+        }
+        payload => panic!("unexpected event payload: {payload:?}"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+use anyhow::Context;
+use chrono::Utc;
+use serde::Deserialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        entity_metadata::EntityMetadata,
+        task::{get_task_aggregate_for_update, save_task_settings, BoundaryTask, TaskAggregateError, TaskError, TaskId, TaskStatus},
+    },
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_notification_repository::IncidentNotificationRepository,
+        incident_repository::IncidentRepository, task_repository::TaskRepository,
+        task_run_repository::TaskRunRepository,
+    },
+};
+
+use super::{find_cyclic_upstream_task, find_unknown_upstream_task, resolve_task_incident};
+
+#[cfg(test)]
+mod tests;
+
+/// The settings of a task to change. Fields that are not set are left unchanged,
+/// and nullable fields are cleared when they are set to null
+#[derive(Debug, Deserialize, TS, ToSchema, Clone, Default)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTaskCommand {
+    pub name: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    #[ts(optional, type = "string | null")]
+    #[schema(value_type = Option<String>)]
+    pub description: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    #[ts(optional, type = "string | null")]
+    #[schema(value_type = Option<String>)]
+    pub cron_schedule: Option<Option<String>>,
+    /// The IANA time zone in which the cron schedule is evaluated (e.g. `Europe/Paris`)
+    pub time_zone: Option<String>,
+    pub start_window_seconds: Option<u32>,
+    pub lateness_window_seconds: Option<u32>,
+    pub heartbeat_timeout_seconds: Option<u32>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    #[ts(optional, type = "number | null")]
+    #[schema(value_type = Option<u32>)]
+    pub max_duration_seconds: Option<Option<u32>>,
+    /// The maximum number of runs that can be running at the same time
+    pub max_concurrent_runs: Option<u32>,
+    /// The tasks that must finish before the task is due. An empty list removes all the dependencies of the task
+    #[ts(type = "Array<string> | null")]
+    pub upstream_task_ids: Option<Vec<TaskId>>,
+    /// The metadata of the task, which replaces its current metadata
+    pub metadata: Option<EntityMetadata>,
+    pub email_notification_enabled: Option<bool>,
+    pub push_notification_enabled: Option<bool>,
+    pub sms_notification_enabled: Option<bool>,
+}
+
+#[derive(Error, Debug)]
+pub enum UpdateTaskError {
+    #[error("User is not allowed to update this task")]
+    Forbidden,
+    #[error("Task not found")]
+    NotFound,
+    #[error("Invalid cron schedule: {details}")]
+    InvalidCronSchedule { details: cron::error::Error },
+    #[error("{details}")]
+    InvalidTimeZone { details: String },
+    #[error("{details}")]
+    InvalidUpstreamTasks { details: String },
+    #[error("Invalid task: {0}")]
+    InvalidTask(String),
+    #[error("Technical failure occured while updating a task")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Changes the settings of a task and returns the updated task.
+/// When the cron schedule changes, the next due date is recalculated, and an absent task becomes healthy:
+/// its ongoing incident is resolved, since the task is no longer expected at the previous due date
+#[allow(clippy::too_many_arguments)]
+pub async fn update_task_use_case<TR, TRR, IR, IER, INR>(
+    auth_context: &AuthContext,
+    task_repository: &TR,
+    task_run_repository: &TRR,
+    incident_repository: &IR,
+    incident_event_repository: &IER,
+    incident_notification_repository: &INR,
+    task_id: TaskId,
+    command: UpdateTaskCommand,
+) -> Result<BoundaryTask, UpdateTaskError>
+where
+    TR: TaskRepository,
+    TRR: TaskRunRepository<Transaction = TR::Transaction>,
+    IR: IncidentRepository<Transaction = TR::Transaction>,
+    IER: IncidentEventRepository<Transaction = TR::Transaction>,
+    INR: IncidentNotificationRepository<Transaction = TR::Transaction>,
+{
+    if !auth_context.can(Permission::WriteTasks) {
+        return Err(UpdateTaskError::Forbidden);
+    }
+    if command.name.as_ref().is_some_and(|name| name.trim().is_empty()) {
+        return Err(UpdateTaskError::InvalidTask("Task name cannot be empty".to_string()));
+    }
+
+    let mut tx = task_repository.begin_transaction().await?;
+    let aggregate = get_task_aggregate_for_update(
+        task_repository,
+        task_run_repository,
+        &mut tx,
+        auth_context.active_organization_id,
+        &task_id,
+    )
+    .await
+    .context("failed to get task aggregate from the database")?
+    .ok_or(UpdateTaskError::NotFound)?;
+    let previous_status = aggregate.status();
+
+    let upstream_task_ids = command.upstream_task_ids.as_deref().unwrap_or_default();
+    if let Some(task_id) = find_unknown_upstream_task(
+        &mut tx,
+        task_repository,
+        auth_context.active_organization_id,
+        upstream_task_ids,
+    )
+    .await?
+    {
+        return Err(UpdateTaskError::InvalidUpstreamTasks {
+            details: format!("Upstream task not found: {task_id}"),
+        });
+    }
+    if let Some(upstream_task_id) = find_cyclic_upstream_task(
+        &mut tx,
+        task_repository,
+        auth_context.active_organization_id,
+        &task_id,
+        upstream_task_ids,
+    )
+    .await?
+    {
+        return Err(UpdateTaskError::InvalidUpstreamTasks {
+            details: format!(
+                "Upstream task {upstream_task_id} depends on the task, which would create a dependency cycle"
+            ),
+        });
+    }
+
+    let aggregate = aggregate.update(Utc::now(), command).map_err(|e| match e {
+        TaskAggregateError::TaskError(TaskError::InvalidCronSchedule { details }) => {
+            UpdateTaskError::InvalidCronSchedule { details }
+        }
+        TaskAggregateError::TaskError(TaskError::InvalidTimeZone { details }) => {
+            UpdateTaskError::InvalidTimeZone { details }
+        }
+        TaskAggregateError::TaskError(TaskError::InvalidUpstreamTasks { details }) => {
+            UpdateTaskError::InvalidUpstreamTasks { details }
+        }
+        _ => UpdateTaskError::TechnicalFailure(e.into()),
+    })?;
+
+    let task = save_task_settings(task_repository, &mut tx, aggregate)
+        .await
+        .context("failed to save task aggregate to the database")?;
+
+    if previous_status == TaskStatus::Absent && task.status != TaskStatus::Absent {
+        resolve_task_incident(
+            &mut tx,
+            incident_repository,
+            incident_event_repository,
+            incident_notification_repository,
+            &task,
+        )
+        .await?;
+    }
+
+    task_repository.commit_transaction(tx).await.context("failed to commit transaction")?;
+
+    Ok(task)
+}
@@ -0,0 +1,344 @@
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            authorization::AuthContext,
+            incident::IncidentStatus,
+            organization::OrganizationUserRole,
+            task::{BoundaryTask, TaskId, TaskStatus},
+        },
+        ports::{task_repository::TaskRepository, transactional_repository::TransactionalRepository},
+        use_cases::tasks::create_task_incident,
+    },
+    infrastructure::mocks::{
+        incident_event_repository_mock::IncidentEventRepositoryMock,
+        incident_notification_repository_mock::IncidentNotificationRepositoryMock,
+        incident_repository_mock::IncidentRepositoryMock,
+        maintenance_window_repository_mock::MaintenanceWindowRepositoryMock,
+        task_repository_mock::TaskRepositoryMock,
+        task_run_repository_mock::TaskRunRepositoryMock,
+    },
+};
+
+use super::{update_task_use_case, UpdateTaskCommand, UpdateTaskError};
+
+struct Repositories {
+    task: TaskRepositoryMock,
+    task_run: TaskRunRepositoryMock,
+    incident: IncidentRepositoryMock,
+    incident_event: IncidentEventRepositoryMock,
+    incident_notification: IncidentNotificationRepositoryMock,
+}
+
+impl Repositories {
+    async fn new(task: &BoundaryTask) -> anyhow::Result<Self> {
+        let repositories = Self {
+            task: TaskRepositoryMock::new(),
+            task_run: TaskRunRepositoryMock::new(),
+            incident: IncidentRepositoryMock::new(),
+            incident_event: IncidentEventRepositoryMock::new(),
+            incident_notification: IncidentNotificationRepositoryMock::new(),
+        };
+        let mut tx = repositories.task.begin_transaction().await?;
+        repositories.task.upsert_task(&mut tx, task.clone()).await?;
+        Ok(repositories)
+    }
+
+    async fn update(
+        &self,
+        task: &BoundaryTask,
+        command: UpdateTaskCommand,
+    ) -> Result<BoundaryTask, UpdateTaskError> {
+        let auth_context = AuthContext::test_context(
+            task.organization_id,
+            Uuid::new_v4(),
+            &[OrganizationUserRole::Editor],
+            &[],
+        );
+        update_task_use_case(
+            &auth_context,
+            &self.task,
+            &self.task_run,
+            &self.incident,
+            &self.incident_event,
+            &self.incident_notification,
+            task.id.clone(),
+            command,
+        )
+        .await
+    }
+}
+
+fn create_test_task(status: TaskStatus) -> BoundaryTask {
+    BoundaryTask {
+        description: Some("Backs up the database".to_string()),
+        cron_schedule: Some("0 0 0 * * *".to_string()),
+        next_due_at: Some(Utc::now() - Duration::hours(1)),
+        ..BoundaryTask::test_task(Uuid::new_v4(), "nightly-backup", status)
+    }
+}
+
+#[test]
+fn test_deserialize_update_task_command() {
+    let command: UpdateTaskCommand = serde_json::from_str("{}").unwrap();
+    assert_eq!(command.description, None);
+
+    let command: UpdateTaskCommand = serde_json::from_str(r#"{"description": null}"#).unwrap();
+    assert_eq!(command.description, Some(None));
+
+    let command: UpdateTaskCommand =
+        serde_json::from_str(r#"{"cronSchedule": "*/5 * * * *", "maxDurationSeconds": 600}"#).unwrap();
+    assert_eq!(command.cron_schedule, Some(Some("*/5 * * * *".to_string())));
+    assert_eq!(command.max_duration_seconds, Some(Some(600)));
+}
+
+#[tokio::test]
+async fn test_update_task_only_changes_set_fields() -> anyhow::Result<()> {
+    let task = create_test_task(TaskStatus::Healthy);
+    let repositories = Repositories::new(&task).await?;
+
+    let updated = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                name: Some("Database backup".to_string()),
+                max_duration_seconds: Some(Some(600)),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(updated.name, "Database backup");
+    assert_eq!(updated.description, task.description);
+    assert_eq!(updated.max_duration_seconds, Some(600));
+    // the schedule did not change, so the task is still expected at the same time
+    assert_eq!(updated.next_due_at, task.next_due_at);
+
+    let updated = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                description: Some(None),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(updated.description, None);
+    assert_eq!(updated.max_duration_seconds, Some(600));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_task_schedule_recalculates_next_due_at() -> anyhow::Result<()> {
+    let task = create_test_task(TaskStatus::Healthy);
+    let repositories = Repositories::new(&task).await?;
+
+    let updated = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                cron_schedule: Some(Some("*/5 * * * *".to_string())),
+                ..Default::default()
+            },
+        )
+        .await?;
+    let next_due_at = updated.next_due_at.expect("scheduled task must have a next due at");
+    assert!(next_due_at > Utc::now());
+    assert!(next_due_at <= Utc::now() + Duration::minutes(5));
+
+    // removing the schedule means the task is no longer expected
+    let updated = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                cron_schedule: Some(None),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(updated.cron_schedule, None);
+    assert_eq!(updated.next_due_at, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_task_time_zone_recalculates_next_due_at() -> anyhow::Result<()> {
+    let task = create_test_task(TaskStatus::Healthy);
+    let repositories = Repositories::new(&task).await?;
+
+    let updated = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                time_zone: Some("America/New_York".to_string()),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(updated.time_zone, "America/New_York");
+    let next_due_at = updated.next_due_at.expect("scheduled task must have a next due at");
+    // the task runs at midnight in New York, which is never midnight UTC
+    assert_ne!(next_due_at.format("%H:%M").to_string(), "00:00");
+    assert!(next_due_at > Utc::now());
+
+    let result = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                time_zone: Some("Mars/Olympus_Mons".to_string()),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(UpdateTaskError::InvalidTimeZone { .. })));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_absent_task_schedule_resolves_incident() -> anyhow::Result<()> {
+    let task = create_test_task(TaskStatus::Absent);
+    let repositories = Repositories::new(&task).await?;
+    let mut tx = repositories.incident.begin_transaction().await?;
+    create_task_incident(
+        &mut tx,
+        &repositories.incident,
+        &repositories.incident_event,
+        &repositories.incident_notification,
+        &MaintenanceWindowRepositoryMock::new(),
+        &task,
+        None,
+        Vec::new(),
+    )
+    .await?;
+
+    // a change that does not affect the schedule keeps the task absent
+    let updated = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                heartbeat_timeout_seconds: Some(120),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(updated.status, TaskStatus::Absent);
+    assert_eq!(repositories.incident.state.lock().await[0].status, IncidentStatus::Ongoing);
+
+    let updated = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                cron_schedule: Some(Some("0 0 12 * * *".to_string())),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(updated.status, TaskStatus::Healthy);
+    assert_eq!(updated.previous_status, Some(TaskStatus::Absent));
+    assert_eq!(repositories.incident.state.lock().await[0].status, IncidentStatus::Resolved);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_task_errors() -> anyhow::Result<()> {
+    let task = create_test_task(TaskStatus::Healthy);
+    let repositories = Repositories::new(&task).await?;
+
+    let result = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                cron_schedule: Some(Some("every day".to_string())),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(UpdateTaskError::InvalidCronSchedule { .. })));
+
+    let result = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                name: Some("  ".to_string()),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(UpdateTaskError::InvalidTask(_))));
+
+    let unknown_task = create_test_task(TaskStatus::Healthy);
+    let result = repositories.update(&unknown_task, UpdateTaskCommand::default()).await;
+    assert!(matches!(result, Err(UpdateTaskError::NotFound)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_task_upstream_tasks() -> anyhow::Result<()> {
+    let task = create_test_task(TaskStatus::Healthy);
+    let repositories = Repositories::new(&task).await?;
+    let upstream_task = BoundaryTask {
+        id: TaskId::new("nightly-export".to_string()).unwrap(),
+        uuid: Uuid::new_v4(),
+        ..task.clone()
+    };
+    let mut tx = repositories.task.begin_transaction().await?;
+    repositories.task.upsert_task(&mut tx, upstream_task.clone()).await?;
+
+    let result = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                upstream_task_ids: Some(vec![TaskId::new("unknown-task".to_string()).unwrap()]),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(UpdateTaskError::InvalidUpstreamTasks { .. })));
+
+    let result = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                upstream_task_ids: Some(vec![task.id.clone()]),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(UpdateTaskError::InvalidUpstreamTasks { .. })));
+
+    let updated = repositories
+        .update(
+            &task,
+            UpdateTaskCommand {
+                upstream_task_ids: Some(vec![upstream_task.id.clone(), upstream_task.id.clone()]),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(updated.upstream_task_ids, vec![upstream_task.id.clone()]);
+
+    let downstream_task = BoundaryTask {
+        id: TaskId::new("nightly-report".to_string()).unwrap(),
+        uuid: Uuid::new_v4(),
+        upstream_task_ids: vec![task.id.clone()],
+        ..task.clone()
+    };
+    repositories.task.upsert_task(&mut tx, downstream_task.clone()).await?;
+    let result = repositories
+        .update(
+            &upstream_task,
+            UpdateTaskCommand {
+                upstream_task_ids: Some(vec![downstream_task.id.clone()]),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(UpdateTaskError::InvalidUpstreamTasks { .. })));
+    assert!(repositories
+        .task
+        .get_task(&mut tx, upstream_task.organization_id, &upstream_task.id)
+        .await?
+        .is_some_and(|t| t.upstream_task_ids.is_empty()));
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use url::Url;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        entity_metadata::MetadataFilter,
+        incident_event::IncidentEventType,
+        webhook::{is_allowed_webhook_host, WebhookEndpoint, WEBHOOK_EVENT_TYPES},
+    },
+    ports::webhook_endpoint_repository::{NewWebhookEndpoint, WebhookEndpointRepository},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Deserialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateWebhookEndpointCommand {
+    pub url: String,
+    pub event_types: Vec<IncidentEventType>,
+    #[serde(default)]
+    pub metadata_filter: MetadataFilter,
+}
+
+#[derive(Serialize, TS, Clone, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CreateWebhookEndpointResponse {
+    pub id: Uuid,
+    /// The secret used to sign the payloads sent to this endpoint. It will not be returned again
+    pub secret: String,
+}
+
+#[derive(Error, Debug)]
+pub enum CreateWebhookEndpointError {
+    #[error("Failed to create a webhook endpoint: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to create webhook endpoints")]
+    Forbidden,
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+    #[error("Webhook endpoints must use the http or https scheme")]
+    UnsupportedScheme,
+    #[error("Webhook endpoints cannot target local or private network addresses")]
+    ForbiddenHost,
+    #[error("Events of type {0:?} cannot be sent to webhook endpoints")]
+    UnsupportedEventType(IncidentEventType),
+    #[error("A webhook endpoint must subscribe to at least one event type")]
+    NoEventTypes,
+}
+
+pub async fn create_webhook_endpoint(
+    auth_context: &AuthContext,
+    repository: &impl WebhookEndpointRepository,
+    command: CreateWebhookEndpointCommand,
+) -> Result<CreateWebhookEndpointResponse, CreateWebhookEndpointError> {
+    if !auth_context.can(Permission::WriteWebhooks) {
+        return Err(CreateWebhookEndpointError::Forbidden);
+    }
+
+    let url = Url::parse(&command.url)?;
+    if !["http", "https"].contains(&url.scheme()) {
+        return Err(CreateWebhookEndpointError::UnsupportedScheme);
+    }
+    if !is_allowed_webhook_host(&url) {
+        return Err(CreateWebhookEndpointError::ForbiddenHost);
+    }
+    if command.event_types.is_empty() {
+        return Err(CreateWebhookEndpointError::NoEventTypes);
+    }
+    if let Some(event_type) = command
+        .event_types
+        .iter()
+        .find(|event_type| !WEBHOOK_EVENT_TYPES.contains(event_type))
+    {
+        return Err(CreateWebhookEndpointError::UnsupportedEventType(*event_type));
+    }
+
+    let secret = WebhookEndpoint::generate_secret();
+    let mut tx = repository.begin_transaction().await?;
+    let id = repository
+        .create_webhook_endpoint(
+            &mut tx,
+            NewWebhookEndpoint {
+                organization_id: auth_context.active_organization_id,
+                url: url.to_string(),
+                secret: secret.clone(),
+                event_types: command.event_types,
+                metadata_filter: command.metadata_filter,
+            },
+        )
+        .await?;
+    repository.commit_transaction(tx).await?;
+
+    Ok(CreateWebhookEndpointResponse { id, secret })
+}
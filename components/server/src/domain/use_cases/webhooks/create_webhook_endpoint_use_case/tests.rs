@@ -0,0 +1,110 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        authorization::AuthContext, entity_metadata::MetadataFilter,
+        incident_event::IncidentEventType, organization::OrganizationUserRole,
+    },
+    infrastructure::mocks::webhook_endpoint_repository_mock::WebhookEndpointRepositoryMock,
+};
+
+use super::{create_webhook_endpoint, CreateWebhookEndpointCommand, CreateWebhookEndpointError};
+
+fn command(url: &str, event_types: Vec<IncidentEventType>) -> CreateWebhookEndpointCommand {
+    CreateWebhookEndpointCommand {
+        url: url.to_string(),
+        event_types,
+        metadata_filter: MetadataFilter::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_create_webhook_endpoint() -> anyhow::Result<()> {
+    let repository = WebhookEndpointRepositoryMock::new();
+    let org_id = Uuid::new_v4();
+    let auth_context =
+        AuthContext::test_context(org_id, Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+
+    let response = create_webhook_endpoint(
+        &auth_context,
+        &repository,
+        command("https://example.com/hook", vec![IncidentEventType::Creation]),
+    )
+    .await?;
+
+    let state = repository.state.lock().await;
+    assert_eq!(state.len(), 1);
+    assert_eq!(state[0].id, response.id);
+    assert_eq!(state[0].organization_id, org_id);
+    assert_eq!(state[0].secret, response.secret);
+    assert_eq!(response.secret.len(), 64);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_webhook_endpoint_requires_write_permission() {
+    let repository = WebhookEndpointRepositoryMock::new();
+    let auth_context = AuthContext::test_context(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &[OrganizationUserRole::Reporter],
+        &[],
+    );
+
+    let result = create_webhook_endpoint(
+        &auth_context,
+        &repository,
+        command("https://example.com/hook", vec![IncidentEventType::Creation]),
+    )
+    .await;
+
+    assert!(matches!(result, Err(CreateWebhookEndpointError::Forbidden)));
+    assert!(repository.state.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_create_webhook_endpoint_validation() {
+    let repository = WebhookEndpointRepositoryMock::new();
+    let auth_context =
+        AuthContext::test_context(Uuid::new_v4(), Uuid::new_v4(), &[OrganizationUserRole::Editor], &[]);
+
+    let result = create_webhook_endpoint(
+        &auth_context,
+        &repository,
+        command("ftp://example.com/hook", vec![IncidentEventType::Creation]),
+    )
+    .await;
+    assert!(matches!(result, Err(CreateWebhookEndpointError::UnsupportedScheme)));
+
+    for url in ["http://localhost:3000/hook", "http://10.0.0.5/hook", "http://169.254.169.254/latest"] {
+        let result = create_webhook_endpoint(
+            &auth_context,
+            &repository,
+            command(url, vec![IncidentEventType::Creation]),
+        )
+        .await;
+        assert!(matches!(result, Err(CreateWebhookEndpointError::ForbiddenHost)));
+    }
+
+    let result = create_webhook_endpoint(
+        &auth_context,
+        &repository,
+        command("https://example.com/hook", vec![]),
+    )
+    .await;
+    assert!(matches!(result, Err(CreateWebhookEndpointError::NoEventTypes)));
+
+    let result = create_webhook_endpoint(
+        &auth_context,
+        &repository,
+        command(
+            "https://example.com/hook",
+            vec![IncidentEventType::Creation, IncidentEventType::Notification],
+        ),
+    )
+    .await;
+    assert!(matches!(
+        result,
+        Err(CreateWebhookEndpointError::UnsupportedEventType(IncidentEventType::Notification))
+    ));
+}
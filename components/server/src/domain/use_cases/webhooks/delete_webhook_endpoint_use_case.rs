@@ -0,0 +1,39 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::authorization::{AuthContext, Permission},
+    ports::webhook_endpoint_repository::WebhookEndpointRepository,
+};
+
+#[derive(Error, Debug)]
+pub enum DeleteWebhookEndpointError {
+    #[error("Failed to delete a webhook endpoint: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to delete webhook endpoints")]
+    Forbidden,
+    #[error("Webhook endpoint not found")]
+    NotFound,
+}
+
+/// Deletes a webhook endpoint, along with its delivery log and its pending deliveries
+pub async fn delete_webhook_endpoint(
+    auth_context: &AuthContext,
+    repository: &impl WebhookEndpointRepository,
+    webhook_endpoint_id: Uuid,
+) -> Result<(), DeleteWebhookEndpointError> {
+    if !auth_context.can(Permission::WriteWebhooks) {
+        return Err(DeleteWebhookEndpointError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let deleted = repository
+        .delete_webhook_endpoint(&mut tx, auth_context.active_organization_id, webhook_endpoint_id)
+        .await?;
+    if !deleted {
+        return Err(DeleteWebhookEndpointError::NotFound);
+    }
+    repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
@@ -0,0 +1,239 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    time::Duration,
+};
+
+use anyhow::Context;
+use chrono::Utc;
+use futures::future::join_all;
+use tokio::task::JoinSet;
+use tracing::{error, info, warn};
+use url::Url;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::webhook::{
+        is_allowed_webhook_host, WebhookEndpoint, WebhookPayload, WEBHOOK_DELIVERY_CLAIM_DURATION,
+    },
+    ports::{
+        incident_event_repository::IncidentEventRepository,
+        incident_repository::IncidentRepository,
+        webhook_client::{WebhookClient, WebhookResponse},
+        webhook_delivery_repository::{NewWebhookDelivery, WebhookDeliveryRepository},
+        webhook_endpoint_repository::WebhookEndpointRepository,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Turns incident events into webhook deliveries, and sends the deliveries that are due
+#[derive(Clone)]
+pub struct DeliverWebhooksUseCase<IR, IER, WER, WDR, WC> {
+    pub incident_repository: IR,
+    pub incident_event_repository: IER,
+    pub webhook_endpoint_repository: WER,
+    pub webhook_delivery_repository: WDR,
+    pub webhook_client: WC,
+    pub select_limit: u32,
+}
+
+impl<IR, IER, WER, WDR, WC> DeliverWebhooksUseCase<IR, IER, WER, WDR, WC>
+where
+    IR: IncidentRepository,
+    IER: IncidentEventRepository<Transaction = IR::Transaction>,
+    WER: WebhookEndpointRepository<Transaction = IR::Transaction>,
+    WDR: WebhookDeliveryRepository<Transaction = IR::Transaction>,
+    WC: WebhookClient,
+{
+    pub fn spawn_tasks(
+        &self,
+        n_tasks: usize,
+        delay_between_two_executions: Duration,
+    ) -> JoinSet<()> {
+        let mut join_set = JoinSet::new();
+        if n_tasks == 0 {
+            info!("No task will be spawned. You need to call the `run webhook-deliveries` command manually to deliver webhooks");
+            return join_set;
+        }
+
+        for _ in 0..n_tasks {
+            let mut interval = tokio::time::interval(delay_between_two_executions);
+            let executor = self.clone();
+
+            join_set.spawn(async move {
+                loop {
+                    tokio::select! {
+                        _ = interval.tick() => {
+                            if let Err(e) = executor.dispatch_pending_events().await {
+                                error!(error = ?e, "Failed to dispatch incident events to webhook endpoints");
+                            }
+                            match executor.send_due_deliveries().await {
+                                Ok(deliveries) if deliveries > 0 => {
+                                    info!(deliveries, "Sent {} webhook deliveries", deliveries);
+                                }
+                                Err(e) => {
+                                    error!(error = ?e, "Failed to send webhook deliveries")
+                                }
+                                Ok(_) => {}
+                            }
+                        }
+                        _ = tokio::signal::ctrl_c() => {
+                            info!("Shutting down webhook deliveries task");
+                            break;
+                        }
+                    }
+                }
+            });
+        }
+
+        join_set
+    }
+
+    /// Runs a single dispatch and delivery round
+    pub async fn deliver_webhooks(&self) -> anyhow::Result<usize> {
+        self.dispatch_pending_events().await?;
+        self.send_due_deliveries().await
+    }
+
+    /// Creates a delivery for every webhook endpoint subscribed to incident events that were not dispatched yet.
+    /// Returns the number of created deliveries
+    pub async fn dispatch_pending_events(&self) -> anyhow::Result<usize> {
+        let mut tx = self.incident_repository.begin_transaction().await?;
+        let events = self
+            .incident_event_repository
+            .take_events_pending_webhook_dispatch(&mut tx, self.select_limit)
+            .await
+            .context("Failed to get incident events pending webhook dispatch")?;
+
+        let mut endpoints_by_organization: HashMap<Uuid, Vec<WebhookEndpoint>> = HashMap::new();
+        let mut created_deliveries = 0;
+        for event in events {
+            let endpoints = match endpoints_by_organization.entry(event.organization_id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(
+                    self.webhook_endpoint_repository
+                        .list_webhook_endpoints(&mut tx, event.organization_id)
+                        .await
+                        .context("Failed to list webhook endpoints")?,
+                ),
+            };
+            if endpoints.is_empty() {
+                continue;
+            }
+
+            let Some(incident) = self
+                .incident_repository
+                .get_incident(&mut tx, event.organization_id, event.incident_id)
+                .await
+                .context("Failed to get incident")?
+            else {
+                warn!(incident_id = ?event.incident_id, "Incident of a pending webhook event not found");
+                continue;
+            };
+
+            for endpoint in endpoints
+                .iter()
+                .filter(|endpoint| endpoint.is_triggered_by(&incident, &event))
+            {
+                self.webhook_delivery_repository
+                    .create_webhook_delivery(
+                        &mut tx,
+                        NewWebhookDelivery {
+                            organization_id: event.organization_id,
+                            webhook_endpoint_id: endpoint.id,
+                            incident_id: incident.id,
+                            event_type: event.event_type,
+                            payload: WebhookPayload {
+                                event_type: event.event_type,
+                                incident: incident.clone(),
+                                event: event.clone(),
+                            },
+                        },
+                    )
+                    .await
+                    .context("Failed to create webhook delivery")?;
+                created_deliveries += 1;
+            }
+        }
+
+        self.incident_repository.commit_transaction(tx).await?;
+        Ok(created_deliveries)
+    }
+
+    /// Posts the deliveries whose next attempt is due, and records the outcome of each attempt.
+    /// Deliveries are claimed in a first transaction and their outcomes are recorded in a second one,
+    /// so that no row stays locked while the endpoints are being called.
+    /// Returns the number of attempted deliveries
+    pub async fn send_due_deliveries(&self) -> anyhow::Result<usize> {
+        let mut tx = self.webhook_delivery_repository.begin_transaction().await?;
+        let deliveries = self
+            .webhook_delivery_repository
+            .claim_next_deliveries_to_send(&mut tx, self.select_limit, Utc::now() + WEBHOOK_DELIVERY_CLAIM_DURATION)
+            .await
+            .context("Failed to claim next webhook deliveries to send")?;
+
+        let mut endpoints: HashMap<Uuid, WebhookEndpoint> = HashMap::new();
+        let mut requests = Vec::with_capacity(deliveries.len());
+        for delivery in &deliveries {
+            if !endpoints.contains_key(&delivery.webhook_endpoint_id) {
+                // Endpoints are deleted along with their deliveries, so they always exist here
+                let endpoint = self
+                    .webhook_endpoint_repository
+                    .get_webhook_endpoint(&mut tx, delivery.organization_id, delivery.webhook_endpoint_id)
+                    .await?
+                    .context("Webhook endpoint of a delivery not found")?;
+                endpoints.insert(endpoint.id, endpoint);
+            }
+            let endpoint = &endpoints[&delivery.webhook_endpoint_id];
+
+            // Endpoints created before hosts were checked may still target the server's network
+            if !Url::parse(&endpoint.url).is_ok_and(|url| is_allowed_webhook_host(&url)) {
+                warn!(webhook_endpoint_id = ?endpoint.id, "Refusing to send a webhook delivery to a local or private address");
+                requests.push(None);
+                continue;
+            }
+
+            let body = serde_json::to_vec(&delivery.payload).context("Failed to serialize webhook payload")?;
+            let headers = HashMap::from([
+                ("Content-Type".to_string(), "application/json".to_string()),
+                (
+                    "X-DutyDuck-Event".to_string(),
+                    serde_json::to_value(delivery.event_type)?
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                ),
+                ("X-DutyDuck-Delivery".to_string(), delivery.id.to_string()),
+                ("X-DutyDuck-Signature".to_string(), format!("sha256={}", endpoint.sign(&body))),
+            ]);
+            requests.push(Some((endpoint.url.clone(), headers, body)));
+        }
+        self.webhook_delivery_repository.commit_transaction(tx).await?;
+
+        let responses = join_all(requests.into_iter().map(|request| async move {
+            match request {
+                Some((url, headers, body)) => self.webhook_client.post(&url, headers, body).await,
+                None => WebhookResponse {
+                    http_code: None,
+                    error: Some("Webhook endpoints cannot target local or private network addresses".to_string()),
+                },
+            }
+        }))
+        .await;
+
+        let mut tx = self.webhook_delivery_repository.begin_transaction().await?;
+        let now = Utc::now();
+        let attempted_deliveries = deliveries.len();
+        for (mut delivery, response) in deliveries.into_iter().zip(responses) {
+            delivery.record_attempt(response.http_code, response.error, now);
+            self.webhook_delivery_repository
+                .update_webhook_delivery(&mut tx, &delivery)
+                .await
+                .context("Failed to update webhook delivery")?;
+        }
+
+        self.webhook_delivery_repository.commit_transaction(tx).await?;
+        Ok(attempted_deliveries)
+    }
+}
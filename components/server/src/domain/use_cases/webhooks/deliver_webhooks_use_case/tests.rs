@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            entity_metadata::{EntityMetadata, MetadataFilter},
+            incident::{Incident, IncidentPriority, IncidentSourceType, IncidentStatus},
+            incident_event::{IncidentEvent, IncidentEventType},
+            webhook::{WebhookDeliveryStatus, WebhookEndpoint, WebhookPayload},
+        },
+        ports::{
+            incident_event_repository::IncidentEventRepository,
+            transactional_repository::TransactionalRepository, webhook_client::WebhookResponse,
+        },
+    },
+    infrastructure::mocks::{
+        incident_event_repository_mock::IncidentEventRepositoryMock,
+        incident_repository_mock::IncidentRepositoryMock,
+        webhook_client_mock::WebhookClientMock,
+        webhook_delivery_repository_mock::WebhookDeliveryRepositoryMock,
+        webhook_endpoint_repository_mock::WebhookEndpointRepositoryMock,
+    },
+};
+
+use super::DeliverWebhooksUseCase;
+
+type TestUseCase = DeliverWebhooksUseCase<
+    IncidentRepositoryMock,
+    IncidentEventRepositoryMock,
+    WebhookEndpointRepositoryMock,
+    WebhookDeliveryRepositoryMock,
+    WebhookClientMock,
+>;
+
+fn create_use_case() -> TestUseCase {
+    DeliverWebhooksUseCase {
+        incident_repository: IncidentRepositoryMock::new(),
+        incident_event_repository: IncidentEventRepositoryMock::new(),
+        webhook_endpoint_repository: WebhookEndpointRepositoryMock::new(),
+        webhook_delivery_repository: WebhookDeliveryRepositoryMock::new(),
+        webhook_client: WebhookClientMock::new(),
+        select_limit: 10,
+    }
+}
+
+fn create_test_incident(org_id: Uuid, metadata: EntityMetadata) -> Incident {
+    Incident {
+        organization_id: org_id,
+        id: Uuid::new_v4(),
+        created_at: Utc::now(),
+        created_by: None,
+        resolved_at: None,
+        cause: None,
+        status: IncidentStatus::Ongoing,
+        priority: IncidentPriority::Major,
+        incident_source_type: IncidentSourceType::HttpMonitor,
+        incident_source_id: Uuid::new_v4(),
+        acknowledged_by: vec![],
+        title: None,
+        metadata,
+    }
+}
+
+fn create_test_endpoint(
+    org_id: Uuid,
+    event_types: Vec<IncidentEventType>,
+    metadata_filter: MetadataFilter,
+) -> WebhookEndpoint {
+    WebhookEndpoint {
+        organization_id: org_id,
+        id: Uuid::new_v4(),
+        url: "https://example.com/hook".to_string(),
+        secret: "secret".to_string(),
+        event_types,
+        metadata_filter,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+async fn create_event(use_case: &TestUseCase, incident: &Incident, event_type: IncidentEventType) -> anyhow::Result<()> {
+    let mut tx = use_case.incident_event_repository.begin_transaction().await?;
+    use_case
+        .incident_event_repository
+        .create_incident_event(
+            &mut tx,
+            IncidentEvent {
+                organization_id: incident.organization_id,
+                incident_id: incident.id,
+                user_id: None,
+                created_at: Utc::now(),
+                event_type,
+                event_payload: None,
+            },
+        )
+        .await
+}
+
+#[tokio::test]
+async fn test_events_are_dispatched_to_matching_endpoints() -> anyhow::Result<()> {
+    let use_case = create_use_case();
+    let org_id = Uuid::new_v4();
+    let incident = create_test_incident(
+        org_id,
+        EntityMetadata {
+            records: HashMap::from([("env".to_string(), "production".to_string())]),
+        },
+    );
+    use_case.incident_repository.state.lock().await.push(incident.clone());
+
+    let all_events = create_test_endpoint(org_id, vec![IncidentEventType::Creation], MetadataFilter::default());
+    let only_resolutions = create_test_endpoint(org_id, vec![IncidentEventType::Resolution], MetadataFilter::default());
+    let only_staging = create_test_endpoint(
+        org_id,
+        vec![IncidentEventType::Creation],
+        MetadataFilter {
+            items: HashMap::from([("env".to_string(), vec!["staging".to_string()])]),
+        },
+    );
+    let other_org = create_test_endpoint(Uuid::new_v4(), vec![IncidentEventType::Creation], MetadataFilter::default());
+    use_case.webhook_endpoint_repository.state.lock().await.extend([
+        all_events.clone(),
+        only_resolutions,
+        only_staging,
+        other_org,
+    ]);
+
+    create_event(&use_case, &incident, IncidentEventType::Creation).await?;
+    // Not a webhook event type
+    create_event(&use_case, &incident, IncidentEventType::Notification).await?;
+
+    assert_eq!(use_case.dispatch_pending_events().await?, 1);
+    let deliveries = use_case.webhook_delivery_repository.state.lock().await.clone();
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].webhook_endpoint_id, all_events.id);
+    assert_eq!(deliveries[0].event_type, IncidentEventType::Creation);
+    assert_eq!(deliveries[0].payload.incident.id, incident.id);
+
+    // Events are dispatched only once
+    assert_eq!(use_case.dispatch_pending_events().await?, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deliveries_are_signed_and_recorded() -> anyhow::Result<()> {
+    let use_case = create_use_case();
+    let org_id = Uuid::new_v4();
+    let incident = create_test_incident(org_id, EntityMetadata::default());
+    use_case.incident_repository.state.lock().await.push(incident.clone());
+    let endpoint = create_test_endpoint(org_id, vec![IncidentEventType::Creation], MetadataFilter::default());
+    use_case.webhook_endpoint_repository.state.lock().await.push(endpoint.clone());
+
+    create_event(&use_case, &incident, IncidentEventType::Creation).await?;
+    assert_eq!(use_case.deliver_webhooks().await?, 1);
+
+    let requests = use_case.webhook_client.requests.lock().await.clone();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].url, endpoint.url);
+    assert_eq!(
+        requests[0].headers.get("X-DutyDuck-Signature"),
+        Some(&format!("sha256={}", endpoint.sign(&requests[0].body)))
+    );
+    assert_eq!(requests[0].headers.get("X-DutyDuck-Event").map(String::as_str), Some("creation"));
+    let payload: WebhookPayload = serde_json::from_slice(&requests[0].body)?;
+    assert_eq!(payload.incident.id, incident.id);
+
+    let deliveries = use_case.webhook_delivery_repository.state.lock().await.clone();
+    assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Delivered);
+    assert_eq!(deliveries[0].attempts, 1);
+    assert_eq!(deliveries[0].last_response_http_code, Some(200));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_failed_deliveries_are_retried_later() -> anyhow::Result<()> {
+    let use_case = create_use_case();
+    let org_id = Uuid::new_v4();
+    let incident = create_test_incident(org_id, EntityMetadata::default());
+    use_case.incident_repository.state.lock().await.push(incident.clone());
+    let endpoint = create_test_endpoint(org_id, vec![IncidentEventType::Creation], MetadataFilter::default());
+    use_case.webhook_endpoint_repository.state.lock().await.push(endpoint);
+
+    create_event(&use_case, &incident, IncidentEventType::Creation).await?;
+    use_case
+        .webhook_client
+        .set_next_response(WebhookResponse {
+            http_code: Some(503),
+            error: None,
+        })
+        .await;
+    assert_eq!(use_case.deliver_webhooks().await?, 1);
+
+    let deliveries = use_case.webhook_delivery_repository.state.lock().await.clone();
+    assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Pending);
+    assert_eq!(deliveries[0].attempts, 1);
+    assert!(deliveries[0].next_attempt_at.unwrap() > Utc::now());
+
+    // The retry is not due yet
+    assert_eq!(use_case.send_due_deliveries().await?, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deliveries_to_private_addresses_are_refused() -> anyhow::Result<()> {
+    let use_case = create_use_case();
+    let org_id = Uuid::new_v4();
+    let incident = create_test_incident(org_id, EntityMetadata::default());
+    use_case.incident_repository.state.lock().await.push(incident.clone());
+    let endpoint = WebhookEndpoint {
+        url: "http://169.254.169.254/latest/meta-data".to_string(),
+        ..create_test_endpoint(org_id, vec![IncidentEventType::Creation], MetadataFilter::default())
+    };
+    use_case.webhook_endpoint_repository.state.lock().await.push(endpoint);
+
+    create_event(&use_case, &incident, IncidentEventType::Creation).await?;
+    assert_eq!(use_case.deliver_webhooks().await?, 1);
+
+    assert!(use_case.webhook_client.requests.lock().await.is_empty());
+    let deliveries = use_case.webhook_delivery_repository.state.lock().await.clone();
+    assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Pending);
+    assert_eq!(deliveries[0].attempts, 1);
+    assert!(deliveries[0].last_error.is_some());
+    Ok(())
+}
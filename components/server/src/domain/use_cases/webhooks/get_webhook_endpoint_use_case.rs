@@ -0,0 +1,36 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        webhook::WebhookEndpoint,
+    },
+    ports::webhook_endpoint_repository::WebhookEndpointRepository,
+};
+
+#[derive(Error, Debug)]
+pub enum GetWebhookEndpointError {
+    #[error("User is not allowed to read webhook endpoints")]
+    Forbidden,
+    #[error("Webhook endpoint not found")]
+    NotFound,
+    #[error("Technical failure occured while getting a webhook endpoint")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+pub async fn get_webhook_endpoint(
+    auth_context: &AuthContext,
+    repository: &impl WebhookEndpointRepository,
+    webhook_endpoint_id: Uuid,
+) -> Result<WebhookEndpoint, GetWebhookEndpointError> {
+    if !auth_context.can(Permission::ReadWebhooks) {
+        return Err(GetWebhookEndpointError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    repository
+        .get_webhook_endpoint(&mut tx, auth_context.active_organization_id, webhook_endpoint_id)
+        .await?
+        .ok_or(GetWebhookEndpointError::NotFound)
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        webhook::WebhookDelivery,
+    },
+    ports::webhook_delivery_repository::{ListWebhookDeliveriesOutput, WebhookDeliveryRepository},
+};
+
+#[derive(Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ListWebhookDeliveriesParams {
+    #[serde(default)]
+    pub page_number: Option<u32>,
+    #[serde(default)]
+    pub items_per_page: Option<u32>,
+}
+
+#[derive(Serialize, TS, ToSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ListWebhookDeliveriesResponse {
+    pub deliveries: Vec<WebhookDelivery>,
+    pub total_deliveries: u32,
+}
+
+#[derive(Error, Debug)]
+pub enum ListWebhookDeliveriesError {
+    #[error("User is not allowed to list webhook deliveries")]
+    Forbidden,
+    #[error("Technical failure occured while listing webhook deliveries")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+/// Lists the delivery log of a webhook endpoint, most recent first
+pub async fn list_webhook_deliveries(
+    auth_context: &AuthContext,
+    repository: &impl WebhookDeliveryRepository,
+    webhook_endpoint_id: Uuid,
+    params: ListWebhookDeliveriesParams,
+) -> Result<ListWebhookDeliveriesResponse, ListWebhookDeliveriesError> {
+    if !auth_context.can(Permission::ReadWebhooks) {
+        return Err(ListWebhookDeliveriesError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let items_per_page = params.items_per_page.unwrap_or(15).min(50);
+    let page_number = params.page_number.unwrap_or(1).max(1);
+    let ListWebhookDeliveriesOutput {
+        deliveries,
+        total_deliveries,
+    } = repository
+        .list_webhook_deliveries(
+            &mut tx,
+            auth_context.active_organization_id,
+            webhook_endpoint_id,
+            items_per_page,
+            (page_number - 1) * items_per_page,
+        )
+        .await?;
+
+    Ok(ListWebhookDeliveriesResponse {
+        deliveries,
+        total_deliveries,
+    })
+}
@@ -0,0 +1,43 @@
+use serde::Serialize;
+use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        webhook::WebhookEndpoint,
+    },
+    ports::webhook_endpoint_repository::WebhookEndpointRepository,
+};
+
+#[derive(Serialize, TS, ToSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ListWebhookEndpointsResponse {
+    pub webhook_endpoints: Vec<WebhookEndpoint>,
+}
+
+#[derive(Error, Debug)]
+pub enum ListWebhookEndpointsError {
+    #[error("User is not allowed to list webhook endpoints")]
+    Forbidden,
+    #[error("Technical failure occured while listing webhook endpoints")]
+    TechnicalFailure(#[from] anyhow::Error),
+}
+
+pub async fn list_webhook_endpoints(
+    auth_context: &AuthContext,
+    repository: &impl WebhookEndpointRepository,
+) -> Result<ListWebhookEndpointsResponse, ListWebhookEndpointsError> {
+    if !auth_context.can(Permission::ReadWebhooks) {
+        return Err(ListWebhookEndpointsError::Forbidden);
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let webhook_endpoints = repository
+        .list_webhook_endpoints(&mut tx, auth_context.active_organization_id)
+        .await?;
+
+    Ok(ListWebhookEndpointsResponse { webhook_endpoints })
+}
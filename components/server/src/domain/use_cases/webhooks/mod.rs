@@ -0,0 +1,15 @@
+mod create_webhook_endpoint_use_case;
+mod delete_webhook_endpoint_use_case;
+mod deliver_webhooks_use_case;
+mod get_webhook_endpoint_use_case;
+mod list_webhook_deliveries_use_case;
+mod list_webhook_endpoints_use_case;
+mod update_webhook_endpoint_use_case;
+
+pub use create_webhook_endpoint_use_case::*;
+pub use delete_webhook_endpoint_use_case::*;
+pub use deliver_webhooks_use_case::*;
+pub use get_webhook_endpoint_use_case::*;
+pub use list_webhook_deliveries_use_case::*;
+pub use list_webhook_endpoints_use_case::*;
+pub use update_webhook_endpoint_use_case::*;
@@ -0,0 +1,97 @@
+use serde::Deserialize;
+use thiserror::Error;
+use ts_rs::TS;
+use url::Url;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        authorization::{AuthContext, Permission},
+        entity_metadata::MetadataFilter,
+        incident_event::IncidentEventType,
+        webhook::{is_allowed_webhook_host, WEBHOOK_EVENT_TYPES},
+    },
+    ports::webhook_endpoint_repository::{NewWebhookEndpoint, WebhookEndpointRepository},
+};
+
+#[derive(Deserialize, TS, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct UpdateWebhookEndpointCommand {
+    pub url: String,
+    pub event_types: Vec<IncidentEventType>,
+    #[serde(default)]
+    pub metadata_filter: MetadataFilter,
+}
+
+#[derive(Error, Debug)]
+pub enum UpdateWebhookEndpointError {
+    #[error("Failed to update a webhook endpoint: {0}")]
+    TechnicalFailure(#[from] anyhow::Error),
+    #[error("Current user doesn't have the privilege to update webhook endpoints")]
+    Forbidden,
+    #[error("Webhook endpoint not found")]
+    NotFound,
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+    #[error("Webhook endpoints must use the http or https scheme")]
+    UnsupportedScheme,
+    #[error("Webhook endpoints cannot target local or private network addresses")]
+    ForbiddenHost,
+    #[error("Events of type {0:?} cannot be sent to webhook endpoints")]
+    UnsupportedEventType(IncidentEventType),
+    #[error("A webhook endpoint must subscribe to at least one event type")]
+    NoEventTypes,
+}
+
+/// Updates the URL and the filters of a webhook endpoint. Its secret is kept.
+pub async fn update_webhook_endpoint(
+    auth_context: &AuthContext,
+    repository: &impl WebhookEndpointRepository,
+    webhook_endpoint_id: Uuid,
+    command: UpdateWebhookEndpointCommand,
+) -> Result<(), UpdateWebhookEndpointError> {
+    if !auth_context.can(Permission::WriteWebhooks) {
+        return Err(UpdateWebhookEndpointError::Forbidden);
+    }
+
+    let url = Url::parse(&command.url)?;
+    if !["http", "https"].contains(&url.scheme()) {
+        return Err(UpdateWebhookEndpointError::UnsupportedScheme);
+    }
+    if !is_allowed_webhook_host(&url) {
+        return Err(UpdateWebhookEndpointError::ForbiddenHost);
+    }
+    if command.event_types.is_empty() {
+        return Err(UpdateWebhookEndpointError::NoEventTypes);
+    }
+    if let Some(event_type) = command
+        .event_types
+        .iter()
+        .find(|event_type| !WEBHOOK_EVENT_TYPES.contains(event_type))
+    {
+        return Err(UpdateWebhookEndpointError::UnsupportedEventType(*event_type));
+    }
+
+    let mut tx = repository.begin_transaction().await?;
+    let updated = repository
+        .update_webhook_endpoint(
+            &mut tx,
+            webhook_endpoint_id,
+            NewWebhookEndpoint {
+                organization_id: auth_context.active_organization_id,
+                url: url.to_string(),
+                secret: String::new(),
+                event_types: command.event_types,
+                metadata_filter: command.metadata_filter,
+            },
+        )
+        .await?;
+    if !updated {
+        return Err(UpdateWebhookEndpointError::NotFound);
+    }
+    repository.commit_transaction(tx).await?;
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use anyhow::Context;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{alert_integration::AlertIntegration, incident::IncidentPriority},
+        ports::alert_integration_repository::{AlertIntegrationRepository, NewAlertIntegration},
+    },
+    postgres_transactional_repo,
+};
+
+#[derive(Clone)]
+pub struct AlertIntegrationRepositoryAdapter {
+    pub pool: PgPool,
+}
+
+postgres_transactional_repo!(AlertIntegrationRepositoryAdapter);
+
+#[async_trait::async_trait]
+impl AlertIntegrationRepository for AlertIntegrationRepositoryAdapter {
+    async fn list_alert_integrations(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<AlertIntegration>> {
+        let records = sqlx::query!(
+            r#"
+            SELECT organization_id, id, name, secret, default_priority, created_at, updated_at
+            FROM alert_integrations
+            WHERE organization_id = $1
+            ORDER BY created_at
+            "#,
+            organization_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to list alert integrations")?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| AlertIntegration {
+                organization_id: record.organization_id,
+                id: record.id,
+                name: record.name,
+                secret: record.secret,
+                default_priority: IncidentPriority::from(record.default_priority),
+                created_at: record.created_at,
+                updated_at: record.updated_at,
+            })
+            .collect())
+    }
+
+    async fn get_alert_integration(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<AlertIntegration>> {
+        let record = sqlx::query!(
+            r#"
+            SELECT organization_id, id, name, secret, default_priority, created_at, updated_at
+            FROM alert_integrations
+            WHERE organization_id = $1 AND id = $2
+            "#,
+            organization_id,
+            id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .context("Failed to get alert integration")?;
+
+        Ok(record.map(|record| AlertIntegration {
+            organization_id: record.organization_id,
+            id: record.id,
+            name: record.name,
+            secret: record.secret,
+            default_priority: IncidentPriority::from(record.default_priority),
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }))
+    }
+
+    async fn get_alert_integration_by_id(
+        &self,
+        tx: &mut Self::Transaction,
+        id: Uuid,
+    ) -> anyhow::Result<Option<AlertIntegration>> {
+        let record = sqlx::query!(
+            r#"
+            SELECT organization_id, id, name, secret, default_priority, created_at, updated_at
+            FROM alert_integrations
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .context("Failed to get alert integration by id")?;
+
+        Ok(record.map(|record| AlertIntegration {
+            organization_id: record.organization_id,
+            id: record.id,
+            name: record.name,
+            secret: record.secret,
+            default_priority: IncidentPriority::from(record.default_priority),
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }))
+    }
+
+    async fn create_alert_integration(
+        &self,
+        tx: &mut Self::Transaction,
+        integration: NewAlertIntegration,
+    ) -> anyhow::Result<Uuid> {
+        let record = sqlx::query!(
+            r#"
+            INSERT INTO alert_integrations (organization_id, name, secret, default_priority)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id
+            "#,
+            integration.organization_id,
+            integration.name,
+            integration.secret,
+            integration.default_priority as i16,
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .context("Failed to create alert integration")?;
+
+        Ok(record.id)
+    }
+
+    async fn update_alert_integration(
+        &self,
+        tx: &mut Self::Transaction,
+        id: Uuid,
+        integration: NewAlertIntegration,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE alert_integrations
+            SET name = $3, default_priority = $4, updated_at = now()
+            WHERE organization_id = $1 AND id = $2
+            "#,
+            integration.organization_id,
+            id,
+            integration.name,
+            integration.default_priority as i16,
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to update alert integration")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_alert_integration(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query!(
+            "DELETE FROM alert_integrations WHERE organization_id = $1 AND id = $2",
+            organization_id,
+            id
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to delete alert integration")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
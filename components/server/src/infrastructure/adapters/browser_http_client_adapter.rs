@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use chrono::DateTime;
+use tonic::transport::Channel;
+use tracing::{error, warn};
+use url::Url;
+
+use crate::{
+    domain::{
+        entities::{
+            http_monitor::HttpMonitorErrorKind,
+            tls_certificate::{certificate_matches_hostname, TlsCertificate},
+        },
+        ports::http_client::{HttpClient, PingRequest, PingResponse, Screenshot},
+    },
+    protos::{self, browser_client::BrowserClient, HttpErrorKind, HttpRequest},
+};
+
+/// Pings the monitors by loading their page in the browser service of each probe location
+#[derive(Clone)]
+pub struct BrowserHttpClientAdapter {
+    /// One browser service client per probe location, the first one being the default location
+    clients: Vec<(String, BrowserClient<Channel>)>,
+}
+
+impl BrowserHttpClientAdapter {
+    /// Connects to the browser service of each location, given as (name, grpc address) pairs
+    pub async fn new(probe_locations: Vec<(String, String)>) -> anyhow::Result<Self> {
+        let mut clients = vec![];
+        for (location, address) in probe_locations {
+            let channel = Channel::from_shared(address)
+                .with_context(|| format!("Invalid browser service grpc address for location {location}"))?;
+            let client = BrowserClient::connect(channel)
+                .await
+                .with_context(|| format!("Failed to connect to browser service for location {location}"))?;
+            clients.push((location, client));
+        }
+        Ok(Self { clients })
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for BrowserHttpClientAdapter {
+    fn locations(&self) -> Vec<String> {
+        self.clients
+            .iter()
+            .map(|(location, _)| location.clone())
+            .collect()
+    }
+
+    async fn ping(&self, location: &str, request: &PingRequest) -> PingResponse {
+        let Some((_, client)) = self.clients.iter().find(|(name, _)| name == location) else {
+            error!("Unknown probe location: {}", location);
+            return PingResponse {
+                error_kind: HttpMonitorErrorKind::BrowserServiceCallFailed,
+                ..Default::default()
+            };
+        };
+        let mut client = client.clone();
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let http_request = HttpRequest {
+                endpoint: request.endpoint.clone(),
+                request_timeout_ms: request.request_timeout.as_millis() as u64,
+                http_headers: request.request_headers.clone(),
+                method: protos::HttpMethod::from(request.method).into(),
+                body: request.body.clone(),
+                body_content_type: request.body_content_type.clone(),
+                authentication: (&request.authentication).into(),
+                redirect_policy: Some(request.redirect_policy.into()),
+            };
+            match client.execute_http_request(http_request).await {
+                Ok(response) => {
+                    let response = response.into_inner();
+                    let tls_certificate = response
+                        .tls_certificate
+                        .and_then(|certificate| tls_certificate(certificate, &request.endpoint));
+
+                    // TODO: handle screenshots and other data
+                    return PingResponse {
+                        http_code: response.http_code.map(|code| code as u16),
+                        error_kind: response
+                            .error
+                            .and_then(|kind| HttpErrorKind::try_from(kind).ok())
+                            .map(|kind| kind.into())
+                            .unwrap_or(HttpMonitorErrorKind::None),
+                        http_headers: response.http_headers,
+                        response_time: Duration::from_millis(response.response_time_ms),
+                        response_ip_address: response.response_ip_address,
+                        resolved_ip_addresses: response.resolved_ip_addresses,
+                        response_body_size_bytes: response.response_body_size_bytes,
+                        response_body_content: response.response_body_content,
+                        screenshot: response.screenshot.map(|screenshot| Screenshot {
+                            data: screenshot.data,
+                            content_type: screenshot.content_type,
+                        }),
+                        tls_certificate,
+                    };
+                }
+                Err(e) => {
+                    if attempt >= 3 {
+                        error!(location, "Failed to call gRPC browser service: {:?}. Giving up.", e);
+                        return PingResponse {
+                            error_kind: HttpMonitorErrorKind::BrowserServiceCallFailed,
+                            ..Default::default()
+                        };
+                    }
+                    warn!(location, "Failed to call gRPC browser service: {:?}. Retrying ...", e);
+                    tokio::time::sleep(Duration::from_millis(1000)).await;
+                }
+            }
+        }
+    }
+}
+
+/// Converts the certificate reported by the browser service.
+/// The browser only reports the certificate of the pages it loaded, so the chain was verified
+fn tls_certificate(certificate: protos::TlsCertificate, endpoint: &str) -> Option<TlsCertificate> {
+    let hostname = Url::parse(endpoint).ok()?.host_str()?.trim_matches(['[', ']']).to_string();
+    Some(TlsCertificate {
+        hostname_matches: certificate_matches_hostname(
+            &certificate.subject_alternative_names,
+            &hostname,
+        ),
+        subject: certificate.subject,
+        issuer: certificate.issuer,
+        subject_alternative_names: certificate.subject_alternative_names,
+        not_before: DateTime::from_timestamp(certificate.valid_from, 0)?,
+        not_after: DateTime::from_timestamp(certificate.valid_to, 0)?,
+        valid_chain: true,
+    })
+}
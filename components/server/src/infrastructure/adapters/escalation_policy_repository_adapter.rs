@@ -0,0 +1,78 @@
+use anyhow::Context;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::escalation_policy::EscalationPolicy,
+        ports::escalation_policy_repository::EscalationPolicyRepository,
+    },
+    postgres_transactional_repo,
+};
+
+#[derive(Clone)]
+pub struct EscalationPolicyRepositoryAdapter {
+    pub pool: PgPool,
+}
+
+postgres_transactional_repo!(EscalationPolicyRepositoryAdapter);
+
+#[async_trait::async_trait]
+impl EscalationPolicyRepository for EscalationPolicyRepositoryAdapter {
+    async fn get_escalation_policy(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Option<EscalationPolicy>> {
+        let record = sqlx::query!(
+            r#"
+            SELECT organization_id, on_call_schedule_id, levels, updated_at
+            FROM escalation_policies
+            WHERE organization_id = $1
+            "#,
+            organization_id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .context("Failed to get escalation policy")?;
+
+        record
+            .map(|record| {
+                Ok(EscalationPolicy {
+                    organization_id: record.organization_id,
+                    on_call_schedule_id: record.on_call_schedule_id,
+                    levels: serde_json::from_value(record.levels)
+                        .context("Failed to deserialize escalation policy levels")?,
+                    updated_at: record.updated_at,
+                })
+            })
+            .transpose()
+    }
+
+    async fn upsert_escalation_policy(
+        &self,
+        tx: &mut Self::Transaction,
+        policy: EscalationPolicy,
+    ) -> anyhow::Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO escalation_policies (organization_id, on_call_schedule_id, levels, updated_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (organization_id) DO UPDATE SET
+                on_call_schedule_id = EXCLUDED.on_call_schedule_id,
+                levels = EXCLUDED.levels,
+                updated_at = EXCLUDED.updated_at
+            "#,
+            policy.organization_id,
+            policy.on_call_schedule_id,
+            serde_json::to_value(&policy.levels)
+                .expect("Failed to serialize escalation policy levels"),
+            policy.updated_at,
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to upsert escalation policy")?;
+
+        Ok(())
+    }
+}
@@ -54,6 +54,16 @@ impl FileStorage for FileStorageAdapter {
         Ok(response.body.collect().await?.to_vec())
     }
 
+    async fn delete_file(&self, key: FileStorageKey) -> anyhow::Result<()> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket_name)
+            .key(key.to_string())
+            .send()
+            .await?;
+        Ok(())
+    }
+
     async fn get_file_url(&self, key: FileStorageKey) -> anyhow::Result<Url> {
         let presigned_req = self
             .client
@@ -1,92 +1,61 @@
-use std::{collections::HashMap, time::Duration};
-
-use anyhow::Context;
-use tonic::transport::Channel;
-use tracing::{error, warn};
+use tracing::warn;
 
 use crate::{
     application::application_config::AppConfig,
     domain::{
-        entities::http_monitor::HttpMonitorErrorKind,
-        ports::http_client::{HttpClient, PingResponse, Screenshot},
+        entities::{http_monitor_request::ProbeEngine, monitor_kind::MonitorKindSettings},
+        ports::http_client::{HttpClient, PingRequest, PingResponse},
     },
-    protos::{browser_client::BrowserClient, HttpErrorKind, HttpRequest},
 };
 
+use super::{
+    browser_http_client_adapter::BrowserHttpClientAdapter,
+    lightweight_http_client_adapter::LightweightHttpClientAdapter,
+    network_probe_adapter::NetworkProbeAdapter,
+};
+
+/// Pings each HTTP monitor with the probe engine it is configured with, and probes the other kinds of monitors from the server itself.
+/// When no browser service is configured, every HTTP monitor is pinged with the lightweight probe engine.
 #[derive(Clone)]
 pub struct HttpClientAdapter {
-    client: BrowserClient<Channel>,
+    browser: Option<BrowserHttpClientAdapter>,
+    lightweight: LightweightHttpClientAdapter,
+    network: NetworkProbeAdapter,
 }
 
 impl HttpClientAdapter {
     pub async fn new(config: &AppConfig) -> anyhow::Result<Self> {
-        let channel = Channel::from_shared(
-            config
-                .http_monitors_executor
-                .browser_service_grpc_address
-                .clone(),
-        )
-        .context("Invalid browser service grpc address")?;
-        let client = BrowserClient::connect(channel)
-            .await
-            .context("Failed to connect to browser service")?;
-        Ok(Self { client })
+        let probe_locations = config.http_monitors_executor.probe_locations()?;
+        let browser = if probe_locations.is_empty() {
+            warn!("No browser service configured, every monitor will be pinged with the lightweight probe engine");
+            None
+        } else {
+            Some(BrowserHttpClientAdapter::new(probe_locations).await?)
+        };
+        Ok(Self {
+            browser,
+            lightweight: LightweightHttpClientAdapter::new(),
+            network: NetworkProbeAdapter::new(),
+        })
     }
 }
 
 #[async_trait::async_trait]
 impl HttpClient for HttpClientAdapter {
-    async fn ping(
-        &self,
-        endpoint: &str,
-        request_timeout: Duration,
-        request_headers: HashMap<String, String>,
-    ) -> PingResponse {
-        let mut client = self.client.clone();
-        let mut attempt = 0;
-        loop {
-            attempt += 1;
-            let request = HttpRequest {
-                endpoint: endpoint.to_string(),
-                request_timeout_ms: request_timeout.as_millis() as u64,
-                http_headers: request_headers.clone(),
-            };
-            match client.execute_http_request(request).await {
-                Ok(response) => {
-                    let response = response.into_inner();
+    fn locations(&self) -> Vec<String> {
+        match &self.browser {
+            Some(browser) => browser.locations(),
+            None => self.lightweight.locations(),
+        }
+    }
 
-                    // TODO: handle screenshots and other data
-                    return PingResponse {
-                        http_code: response.http_code.map(|code| code as u16),
-                        error_kind: response
-                            .error
-                            .and_then(|kind| HttpErrorKind::try_from(kind).ok())
-                            .map(|kind| kind.into())
-                            .unwrap_or(HttpMonitorErrorKind::None),
-                        http_headers: response.http_headers,
-                        response_time: Duration::from_millis(response.response_time_ms),
-                        response_ip_address: response.response_ip_address,
-                        resolved_ip_addresses: response.resolved_ip_addresses,
-                        response_body_size_bytes: response.response_body_size_bytes,
-                        response_body_content: response.response_body_content,
-                        screenshot: response.screenshot.map(|screenshot| Screenshot {
-                            data: screenshot.data,
-                            content_type: screenshot.content_type,
-                        }),
-                    };
-                }
-                Err(e) => {
-                    if attempt >= 3 {
-                        error!("Failed to call gRPC browser service: {:?}. Giving up.", e);
-                        return PingResponse {
-                            error_kind: HttpMonitorErrorKind::BrowserServiceCallFailed,
-                            ..Default::default()
-                        };
-                    }
-                    warn!("Failed to call gRPC browser service: {:?}. Retrying ...", e);
-                    tokio::time::sleep(Duration::from_millis(1000)).await;
-                }
-            }
+    async fn ping(&self, location: &str, request: &PingRequest) -> PingResponse {
+        if request.kind_settings != MonitorKindSettings::Http {
+            return self.network.ping(request).await;
+        }
+        match (&self.browser, request.probe_engine) {
+            (Some(browser), ProbeEngine::Browser) => browser.ping(location, request).await,
+            _ => self.lightweight.ping(location, request).await,
         }
     }
 }
@@ -1,14 +1,25 @@
 use async_trait::async_trait;
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
 use itertools::Itertools;
+use serde_json::Value;
 use sqlx::PgPool;
+use tracing::error;
 use uuid::Uuid;
 
 use crate::domain::{
     entities::{
         entity_metadata::{
-            FilterableMetadata, FilterableMetadataItem, FilterableMetadataValue, MetadataFilter,
+            EntityMetadata, FilterableMetadata, FilterableMetadataItem, FilterableMetadataValue,
+            MetadataFilter,
         },
-        http_monitor::{HttpMonitor, HttpMonitorErrorKind, HttpMonitorStatus},
+        http_monitor::{HttpMonitor, HttpMonitorErrorKind, HttpMonitorStatus, RequestHeaders},
+        http_monitor_assertion::HttpMonitorAssertions,
+        http_monitor_ping::{HttpMonitorPing, HttpMonitorPingRollup},
+        http_monitor_request::{
+            HttpMethod, HttpMonitorAuthentication, HttpMonitorRedirectPolicy, ProbeEngine,
+        },
+        monitor_kind::MonitorKindSettings,
+        tls_certificate::ObservedTlsCertificate,
     },
     ports::{
         http_monitor_repository::{
@@ -25,6 +36,104 @@ pub struct HttpMonitorRepositoryAdapter {
     pub pool: PgPool,
 }
 
+/// A monitor as stored in the database, with its assertions not decoded yet.
+/// Unlike the other JSON columns, invalid assertions cannot fall back to a default value,
+/// the monitor would otherwise be pinged without checking them
+struct HttpMonitorRow {
+    organization_id: Uuid,
+    id: Uuid,
+    created_at: DateTime<Utc>,
+    url: String,
+    first_ping_at: Option<DateTime<Utc>>,
+    next_ping_at: Option<DateTime<Utc>>,
+    last_ping_at: Option<DateTime<Utc>>,
+    last_status_change_at: DateTime<Utc>,
+    recovery_confirmation_threshold: i16,
+    downtime_confirmation_threshold: i16,
+    interval_seconds: i64,
+    last_http_code: Option<i16>,
+    status: HttpMonitorStatus,
+    status_counter: i16,
+    error_kind: HttpMonitorErrorKind,
+    metadata: EntityMetadata,
+    email_notification_enabled: bool,
+    push_notification_enabled: bool,
+    sms_notification_enabled: bool,
+    archived_at: Option<DateTime<Utc>>,
+    request_headers: RequestHeaders,
+    request_timeout_ms: i32,
+    assertions: Value,
+    locations: Vec<String>,
+    location_quorum: Option<i16>,
+    request_method: HttpMethod,
+    request_body: Option<String>,
+    request_body_content_type: Option<String>,
+    authentication: HttpMonitorAuthentication,
+    redirect_policy: HttpMonitorRedirectPolicy,
+    probe_engine: ProbeEngine,
+    tls_certificate: ObservedTlsCertificate,
+    tls_expiry_warning_days: i16,
+    kind_settings: MonitorKindSettings,
+}
+
+impl TryFrom<HttpMonitorRow> for HttpMonitor {
+    type Error = anyhow::Error;
+
+    fn try_from(row: HttpMonitorRow) -> Result<Self, Self::Error> {
+        Ok(HttpMonitor {
+            assertions: decode_assertions(row.id, row.assertions)?,
+            organization_id: row.organization_id,
+            id: row.id,
+            created_at: row.created_at,
+            url: row.url,
+            first_ping_at: row.first_ping_at,
+            next_ping_at: row.next_ping_at,
+            last_ping_at: row.last_ping_at,
+            last_status_change_at: row.last_status_change_at,
+            recovery_confirmation_threshold: row.recovery_confirmation_threshold,
+            downtime_confirmation_threshold: row.downtime_confirmation_threshold,
+            interval_seconds: row.interval_seconds,
+            last_http_code: row.last_http_code,
+            status: row.status,
+            status_counter: row.status_counter,
+            error_kind: row.error_kind,
+            metadata: row.metadata,
+            email_notification_enabled: row.email_notification_enabled,
+            push_notification_enabled: row.push_notification_enabled,
+            sms_notification_enabled: row.sms_notification_enabled,
+            archived_at: row.archived_at,
+            request_headers: row.request_headers,
+            request_timeout_ms: row.request_timeout_ms,
+            locations: row.locations,
+            location_quorum: row.location_quorum,
+            request_method: row.request_method,
+            request_body: row.request_body,
+            request_body_content_type: row.request_body_content_type,
+            authentication: row.authentication,
+            redirect_policy: row.redirect_policy,
+            probe_engine: row.probe_engine,
+            tls_certificate: row.tls_certificate,
+            tls_expiry_warning_days: row.tls_expiry_warning_days,
+            kind_settings: row.kind_settings,
+        })
+    }
+}
+
+fn decode_assertions(monitor_id: Uuid, assertions: Value) -> anyhow::Result<HttpMonitorAssertions> {
+    HttpMonitorAssertions::try_from(assertions)
+        .with_context(|| format!("Failed to decode the assertions of http monitor {monitor_id}"))
+}
+
+impl HttpMonitorRepositoryAdapter {
+    pub async fn create_http_monitor_pings_partition_for_month(&self) -> anyhow::Result<()> {
+        sqlx::query!("SELECT create_http_monitor_pings_partition_for_month()")
+            .execute(&self.pool)
+            .await
+            .context("Failed to create HTTP monitor pings partition for month")?;
+        Ok(())
+    }
+}
+
 crate::postgres_transactional_repo!(HttpMonitorRepositoryAdapter);
 
 #[async_trait]
@@ -36,15 +145,24 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
         organization_id: Uuid,
         monitor_id: Uuid,
     ) -> anyhow::Result<Option<HttpMonitor>> {
-        sqlx::query_as!(
-            HttpMonitor,
-            "SELECT * FROM http_monitors WHERE  organization_id = $1 AND id = $2",
+        let row = sqlx::query_as!(
+            HttpMonitorRow,
+            r#"SELECT organization_id, id, created_at, url, status, status_counter, first_ping_at, next_ping_at,
+            last_ping_at, last_status_change_at, recovery_confirmation_threshold, downtime_confirmation_threshold,
+            interval_seconds, last_http_code, error_kind, email_notification_enabled, push_notification_enabled,
+            sms_notification_enabled, metadata, request_timeout_ms, request_headers, archived_at,
+            assertions,
+            locations, location_quorum, request_method, request_body, request_body_content_type, authentication,
+            redirect_policy, probe_engine, tls_certificate, tls_expiry_warning_days, kind_settings
+            FROM http_monitors WHERE organization_id = $1 AND id = $2"#,
             organization_id,
             monitor_id,
         )
         .fetch_optional(transaction.as_mut())
         .await
-        .with_context(|| "Failed to get single http monitor from the database")
+        .with_context(|| "Failed to get single http monitor from the database")?;
+
+        row.map(HttpMonitor::try_from).transpose()
     }
 
     #[tracing::instrument(skip(self))]
@@ -119,33 +237,51 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
             .map(|row| row.filtered_count)
             .unwrap_or_default();
 
+        // a monitor that cannot be decoded is left out of the page, rather than failing the whole page
         let http_monitors = rows
             .into_iter()
-            .map(|row| HttpMonitor {
-                organization_id: row.organization_id,
-                id: row.id,
-                created_at: row.created_at,
-                url: row.url,
-                first_ping_at: row.first_ping_at,
-                next_ping_at: row.next_ping_at,
-                last_ping_at: row.last_ping_at,
-                last_status_change_at: row.last_status_change_at,
-                recovery_confirmation_threshold: row.recovery_confirmation_threshold,
-                downtime_confirmation_threshold: row.downtime_confirmation_threshold,
-                interval_seconds: row.interval_seconds as i64,
-                last_http_code: row.last_http_code,
-                status: row.status.into(),
-                status_counter: row.status_counter,
-                error_kind: row.error_kind.into(),
-                metadata: row.metadata.into(),
-                email_notification_enabled: row.email_notification_enabled,
-                push_notification_enabled: row.push_notification_enabled,
-                sms_notification_enabled: row.sms_notification_enabled,
-                archived_at: row.archived_at,
-                request_headers: row.request_headers.into(),
-                request_timeout_ms: row.request_timeout_ms,
+            .filter_map(|row| {
+                let assertions = decode_assertions(row.id, row.assertions)
+                    .inspect_err(|e| error!(error = ?e, "Skipping listed http monitor"))
+                    .ok()?;
+                Some(HttpMonitor {
+                    organization_id: row.organization_id,
+                    id: row.id,
+                    created_at: row.created_at,
+                    url: row.url,
+                    first_ping_at: row.first_ping_at,
+                    next_ping_at: row.next_ping_at,
+                    last_ping_at: row.last_ping_at,
+                    last_status_change_at: row.last_status_change_at,
+                    recovery_confirmation_threshold: row.recovery_confirmation_threshold,
+                    downtime_confirmation_threshold: row.downtime_confirmation_threshold,
+                    interval_seconds: row.interval_seconds as i64,
+                    last_http_code: row.last_http_code,
+                    status: row.status.into(),
+                    status_counter: row.status_counter,
+                    error_kind: row.error_kind.into(),
+                    metadata: row.metadata.into(),
+                    email_notification_enabled: row.email_notification_enabled,
+                    push_notification_enabled: row.push_notification_enabled,
+                    sms_notification_enabled: row.sms_notification_enabled,
+                    archived_at: row.archived_at,
+                    request_headers: row.request_headers.into(),
+                    request_timeout_ms: row.request_timeout_ms,
+                    assertions,
+                    locations: row.locations,
+                    location_quorum: row.location_quorum,
+                    request_method: row.request_method.into(),
+                    request_body: row.request_body,
+                    request_body_content_type: row.request_body_content_type,
+                    authentication: row.authentication.into(),
+                    redirect_policy: row.redirect_policy.into(),
+                    probe_engine: row.probe_engine.into(),
+                    tls_certificate: row.tls_certificate.into(),
+                    tls_expiry_warning_days: row.tls_expiry_warning_days,
+                    kind_settings: row.kind_settings.into(),
+                })
             })
-            .collect::<Vec<_>>();
+            .collect();
 
         Ok(ListHttpMonitorsOutput {
             monitors: http_monitors,
@@ -174,9 +310,20 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
                 recovery_confirmation_threshold,
                 email_notification_enabled,
                 push_notification_enabled,
-                sms_notification_enabled
+                sms_notification_enabled,
+                assertions,
+                locations,
+                location_quorum,
+                request_method,
+                request_body,
+                request_body_content_type,
+                authentication,
+                redirect_policy,
+                probe_engine,
+                tls_expiry_warning_days,
+                kind_settings
             ) 
-            values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24)
             returning id",
             monitor.organization_id,
             monitor.url,
@@ -191,6 +338,17 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
             monitor.email_notification_enabled,
             monitor.push_notification_enabled,
             monitor.sms_notification_enabled,
+            serde_json::to_value(monitor.assertions)?,
+            &monitor.locations,
+            monitor.location_quorum,
+            monitor.request_method as i16,
+            monitor.request_body,
+            monitor.request_body_content_type,
+            serde_json::to_value(monitor.authentication)?,
+            serde_json::to_value(monitor.redirect_policy)?,
+            monitor.probe_engine as i16,
+            monitor.tls_expiry_warning_days,
+            serde_json::to_value(monitor.kind_settings)?,
         )
         .fetch_one(&self.pool)
         .await?
@@ -203,19 +361,35 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
         transaction: &mut Self::Transaction,
         limit: u32,
     ) -> anyhow::Result<Vec<HttpMonitor>> {
-        let http_monitors = sqlx::query_as!(
-            HttpMonitor,
-            "SELECT * FROM  http_monitors
+        let rows = sqlx::query_as!(
+            HttpMonitorRow,
+            r#"SELECT organization_id, id, created_at, url, status, status_counter, first_ping_at, next_ping_at,
+            last_ping_at, last_status_change_at, recovery_confirmation_threshold, downtime_confirmation_threshold,
+            interval_seconds, last_http_code, error_kind, email_notification_enabled, push_notification_enabled,
+            sms_notification_enabled, metadata, request_timeout_ms, request_headers, archived_at,
+            assertions,
+            locations, location_quorum, request_method, request_body, request_body_content_type, authentication,
+            redirect_policy, probe_engine, tls_certificate, tls_expiry_warning_days, kind_settings
+            FROM http_monitors
             WHERE status != $1
             AND next_ping_at <= NOW()
             FOR UPDATE SKIP LOCKED
-            LIMIT $2",
+            LIMIT $2"#,
             HttpMonitorStatus::Inactive as i32,
             limit as i64,
         )
         .fetch_all(transaction.as_mut())
         .await?;
 
+        // a monitor that cannot be decoded is not pinged, but does not prevent the others from being pinged
+        let http_monitors = rows
+            .into_iter()
+            .filter_map(|row| {
+                HttpMonitor::try_from(row)
+                    .inspect_err(|e| error!(error = ?e, "Skipping due http monitor"))
+                    .ok()
+            })
+            .collect();
         Ok(http_monitors)
     }
 
@@ -224,6 +398,10 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
         transaction: &mut Self::Transaction,
         command: UpdateHttpMonitorStatusCommand,
     ) -> anyhow::Result<()> {
+        let tls_certificate = command
+            .tls_certificate
+            .map(serde_json::to_value)
+            .transpose()?;
         sqlx::query!(
             "UPDATE http_monitors SET 
                 status = $1,
@@ -234,7 +412,9 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
                 last_ping_at = now(),
                 first_ping_at = coalesce(first_ping_at, now()),
                 last_status_change_at = $6,
-                archived_at = $7
+                archived_at = $7,
+                -- keep the last observed certificate when none was observed
+                tls_certificate = coalesce($10, tls_certificate)
             WHERE organization_id = $8 and id = $9",
             command.status as i16,
             command.next_ping_at,
@@ -245,6 +425,7 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
             command.archived_at,
             command.organization_id,
             command.monitor_id,
+            tls_certificate,
         )
         .execute(transaction.as_mut())
         .await?;
@@ -260,6 +441,10 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
     ) -> anyhow::Result<bool> {
         let metadata = serde_json::to_value(monitor.metadata)?;
         let request_headers = serde_json::to_value(monitor.request_headers)?;
+        let assertions = serde_json::to_value(monitor.assertions)?;
+        let authentication = serde_json::to_value(monitor.authentication)?;
+        let redirect_policy = serde_json::to_value(monitor.redirect_policy)?;
+        let kind_settings = serde_json::to_value(monitor.kind_settings)?;
 
         let result = sqlx::query!(
             "UPDATE http_monitors SET 
@@ -275,7 +460,18 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
                 sms_notification_enabled = $10,
                 request_headers = $11,
                 request_timeout_ms = $12,
-                organization_id = $13
+                organization_id = $13,
+                assertions = $15,
+                locations = $16,
+                location_quorum = $17,
+                request_method = $18,
+                request_body = $19,
+                request_body_content_type = $20,
+                authentication = $21,
+                redirect_policy = $22,
+                probe_engine = $23,
+                tls_expiry_warning_days = $24,
+                kind_settings = $25
             WHERE organization_id = $13 and id = $14",
             monitor.url,                                    // $1
             monitor.status as i16,                          // $2
@@ -291,6 +487,17 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
             monitor.request_timeout_ms,                     // $12
             monitor.organization_id,                        // $13
             id,                                             // $14
+            &assertions,                                    // $15
+            &monitor.locations,                             // $16
+            monitor.location_quorum,                        // $17
+            monitor.request_method as i16,                  // $18
+            monitor.request_body,                           // $19
+            monitor.request_body_content_type,              // $20
+            &authentication,                                // $21
+            &redirect_policy,                               // $22
+            monitor.probe_engine as i16,                    // $23
+            monitor.tls_expiry_warning_days,                // $24
+            &kind_settings,                                 // $25
         )
         .execute(transaction.as_mut())
         .await?;
@@ -348,4 +555,102 @@ impl HttpMonitorRepository for HttpMonitorRepositoryAdapter {
 
         Ok(FilterableMetadata { items })
     }
+
+    async fn record_ping(
+        &self,
+        transaction: &mut Self::Transaction,
+        ping: HttpMonitorPing,
+    ) -> anyhow::Result<()> {
+        sqlx::query!(
+            "INSERT INTO http_monitor_pings (organization_id, http_monitor_id, created_at, status, error_kind, http_code, response_time_ms)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            ping.organization_id,
+            ping.http_monitor_id,
+            ping.created_at,
+            ping.status as i16,
+            ping.error_kind as i16,
+            ping.http_code,
+            ping.response_time_ms,
+        )
+        .execute(transaction.as_mut())
+        .await
+        .context("Failed to record HTTP monitor ping")?;
+
+        let mut rollup =
+            HttpMonitorPingRollup::empty(ping.created_at.duration_trunc(TimeDelta::hours(1))?);
+        rollup.add_ping(&ping);
+        sqlx::query!(
+            "INSERT INTO http_monitor_ping_rollups (organization_id, http_monitor_id, period_start, ping_count, successful_ping_count, response_time_histogram, error_kind_counts)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (organization_id, http_monitor_id, period_start) DO UPDATE SET
+                ping_count = http_monitor_ping_rollups.ping_count + EXCLUDED.ping_count,
+                successful_ping_count = http_monitor_ping_rollups.successful_ping_count + EXCLUDED.successful_ping_count,
+                response_time_histogram = add_counters(http_monitor_ping_rollups.response_time_histogram, EXCLUDED.response_time_histogram),
+                error_kind_counts = add_counters(http_monitor_ping_rollups.error_kind_counts, EXCLUDED.error_kind_counts)",
+            ping.organization_id,
+            ping.http_monitor_id,
+            rollup.period_start,
+            rollup.ping_count,
+            rollup.successful_ping_count,
+            &rollup.response_time_histogram,
+            &rollup.error_kind_counts,
+        )
+        .execute(transaction.as_mut())
+        .await
+        .context("Failed to update HTTP monitor ping rollup")?;
+
+        Ok(())
+    }
+
+    async fn list_pings(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        monitor_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<HttpMonitorPing>> {
+        let pings = sqlx::query_as!(
+            HttpMonitorPing,
+            "SELECT organization_id, http_monitor_id, created_at, status, error_kind, http_code, response_time_ms
+            FROM http_monitor_pings
+            WHERE organization_id = $1 AND http_monitor_id = $2 AND created_at >= $3 AND created_at < $4
+            ORDER BY created_at",
+            organization_id,
+            monitor_id,
+            from,
+            to,
+        )
+        .fetch_all(transaction.as_mut())
+        .await
+        .context("Failed to list HTTP monitor pings")?;
+
+        Ok(pings)
+    }
+
+    async fn list_ping_rollups(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        monitor_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<HttpMonitorPingRollup>> {
+        let rollups = sqlx::query_as!(
+            HttpMonitorPingRollup,
+            "SELECT period_start, ping_count, successful_ping_count, response_time_histogram, error_kind_counts
+            FROM http_monitor_ping_rollups
+            WHERE organization_id = $1 AND http_monitor_id = $2 AND period_start >= $3 AND period_start < $4
+            ORDER BY period_start",
+            organization_id,
+            monitor_id,
+            from,
+            to,
+        )
+        .fetch_all(transaction.as_mut())
+        .await
+        .context("Failed to list HTTP monitor ping rollups")?;
+
+        Ok(rollups)
+    }
 }
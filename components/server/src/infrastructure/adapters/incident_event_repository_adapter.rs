@@ -3,7 +3,7 @@ use sqlx::postgres::PgPool;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::incident_event::IncidentEvent,
+    entities::{incident_event::IncidentEvent, webhook::WEBHOOK_EVENT_TYPES},
     ports::incident_event_repository::IncidentEventRepository,
 };
 
@@ -34,14 +34,15 @@ impl IncidentEventRepository for IncidentEventRepositoryAdapter {
         let event_created_at = event.created_at;
 
         sqlx::query!(
-            "INSERT INTO incident_timeline_events (organization_id, incident_id, user_id, created_at, event_type, event_payload)
-            VALUES ($1, $2, $3, $4, $5, $6)",
+            "INSERT INTO incident_timeline_events (organization_id, incident_id, user_id, created_at, event_type, event_payload, webhook_dispatch_pending)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
             event.organization_id,
             event.incident_id,
             event.user_id,
             event.created_at,
             event.event_type as i16,
-            serde_json::to_value(event.event_payload)?
+            serde_json::to_value(event.event_payload)?,
+            WEBHOOK_EVENT_TYPES.contains(&event.event_type),
         )
         .execute(&mut **tx)
         .await
@@ -92,4 +93,41 @@ impl IncidentEventRepository for IncidentEventRepositoryAdapter {
 
         Ok(events)
     }
+
+    async fn take_events_pending_webhook_dispatch(
+        &self,
+        tx: &mut Self::Transaction,
+        limit: u32,
+    ) -> anyhow::Result<Vec<IncidentEvent>> {
+        let events = sqlx::query!(
+            "UPDATE incident_timeline_events SET webhook_dispatch_pending = false
+            WHERE (organization_id, incident_id, created_at) IN (
+                SELECT organization_id, incident_id, created_at
+                FROM incident_timeline_events
+                WHERE webhook_dispatch_pending
+                ORDER BY created_at
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *",
+            limit as i64
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to fetch incident events pending webhook dispatch")?
+        .into_iter()
+        .map(|record| IncidentEvent {
+            organization_id: record.organization_id,
+            incident_id: record.incident_id,
+            created_at: record.created_at,
+            user_id: record.user_id,
+            event_type: record.event_type.into(),
+            event_payload: record
+                .event_payload
+                .and_then(|payload| serde_json::from_value(payload).ok()),
+        })
+        .collect();
+
+        Ok(events)
+    }
 }
@@ -28,6 +28,10 @@ impl IncidentRepository for IncidentRepositoryAdapter {
         };
         let (incident_source_type, incident_source_id) = match incident.source {
             IncidentSource::HttpMonitor { id } => (IncidentSourceType::HttpMonitor as i16, id),
+            IncidentSource::Task { id } => (IncidentSourceType::Task as i16, id),
+            IncidentSource::Manual { id } => (IncidentSourceType::Manual as i16, id),
+            IncidentSource::TlsCertificate { id } => (IncidentSourceType::TlsCertificate as i16, id),
+            IncidentSource::ExternalAlert { id } => (IncidentSourceType::ExternalAlert as i16, id),
         };
         let new_incident_id = sqlx::query!(
             "insert into incidents (
@@ -38,9 +42,10 @@ impl IncidentRepository for IncidentRepositoryAdapter {
                 metadata,
                 cause,
                 incident_source_type,
-                incident_source_id
+                incident_source_id,
+                title
             ) 
-            values ($1, $2, $3, $4, $5, $6, $7, $8)
+            values ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             returning id",
             incident.organization_id,
             incident.created_by,
@@ -49,7 +54,8 @@ impl IncidentRepository for IncidentRepositoryAdapter {
             serde_json::to_value(incident.metadata)?,
             cause,
             incident_source_type,
-            incident_source_id
+            incident_source_id,
+            incident.title
         )
         .fetch_one(transaction.as_mut())
         .await?
@@ -97,8 +103,41 @@ impl IncidentRepository for IncidentRepositoryAdapter {
         let http_monitor_sources_ids = opts
             .include_sources
             .iter()
-            .map(|s| match s {
-                IncidentSource::HttpMonitor { id } => *id,
+            .filter_map(|s| match s {
+                IncidentSource::HttpMonitor { id } => Some(*id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let task_sources_ids = opts
+            .include_sources
+            .iter()
+            .filter_map(|s| match s {
+                IncidentSource::Task { id } => Some(*id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let tls_certificate_sources_ids = opts
+            .include_sources
+            .iter()
+            .filter_map(|s| match s {
+                IncidentSource::TlsCertificate { id } => Some(*id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let external_alert_sources_ids = opts
+            .include_sources
+            .iter()
+            .filter_map(|s| match s {
+                IncidentSource::ExternalAlert { id } => Some(*id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let manual_sources_ids = opts
+            .include_sources
+            .iter()
+            .filter_map(|s| match s {
+                IncidentSource::Manual { id } => Some(*id),
+                _ => None,
             })
             .collect::<Vec<_>>();
 
@@ -129,10 +168,14 @@ impl IncidentRepository for IncidentRepositoryAdapter {
             -- Filter by priority
             AND priority IN (SELECT unnest($3::integer[]))
 
-            -- Filter by http monitor ids
+            -- Filter by sources (http monitor ids, task ids, certificate expiry monitor ids, external alert ids and manual incident ids)
             AND (
-                $7::uuid[] = '{{}}' OR
-                (i.incident_source_type = $6 AND i.incident_source_id = ANY($7::uuid[]))
+                ($7::uuid[] = '{{}}' AND $12::uuid[] = '{{}}' AND $14::uuid[] = '{{}}' AND $16::uuid[] = '{{}}' AND $18::uuid[] = '{{}}') OR
+                (i.incident_source_type = $6 AND i.incident_source_id = ANY($7::uuid[])) OR
+                (i.incident_source_type = $11 AND i.incident_source_id = ANY($12::uuid[])) OR
+                (i.incident_source_type = $13 AND i.incident_source_id = ANY($14::uuid[])) OR
+                (i.incident_source_type = $15 AND i.incident_source_id = ANY($16::uuid[])) OR
+                (i.incident_source_type = $17 AND i.incident_source_id = ANY($18::uuid[]))
             )
 
             -- Filter by date (ongoing incidents are always returned)
@@ -185,6 +228,22 @@ impl IncidentRepository for IncidentRepositoryAdapter {
         .bind(opts.to_date)
         // $10: metadata filter
         .bind(&metadata_filter)
+        // $11: task incident_source_type
+        .bind(IncidentSourceType::Task as i16)
+        // $12: task ids
+        .bind(&task_sources_ids)
+        // $13: certificate expiry incident_source_type
+        .bind(IncidentSourceType::TlsCertificate as i16)
+        // $14: certificate expiry monitor ids
+        .bind(&tls_certificate_sources_ids)
+        // $15: external alert incident_source_type
+        .bind(IncidentSourceType::ExternalAlert as i16)
+        // $16: external alert ids
+        .bind(&external_alert_sources_ids)
+        // $17: manual incident_source_type
+        .bind(IncidentSourceType::Manual as i16)
+        // $18: manual incident ids
+        .bind(&manual_sources_ids)
         .fetch_all(transaction.as_mut())
         .await?;
 
@@ -210,6 +269,7 @@ impl IncidentRepository for IncidentRepositoryAdapter {
                 incident_source_id: row.get::<Uuid, _>("incident_source_id"),
                 incident_source_type: row.get::<i16, _>("incident_source_type").into(),
                 acknowledged_by: row.get::<Vec<Uuid>, _>("acknowledged_by"),
+                title: row.get("title"),
             })
             .collect();
 
@@ -259,6 +319,7 @@ impl IncidentRepository for IncidentRepositoryAdapter {
             incident_source_id: record.incident_source_id,
             incident_source_type: record.incident_source_type.into(),
             acknowledged_by: record.acknowledged_by,
+            title: record.title,
         }))
     }
 
@@ -297,8 +358,9 @@ impl IncidentRepository for IncidentRepositoryAdapter {
                 cause = $4,
                 incident_source_type = $5,
                 incident_source_id = $6,
-                resolved_at = $7
-            WHERE organization_id = $8 AND id = $9",
+                resolved_at = $7,
+                title = $8
+            WHERE organization_id = $9 AND id = $10",
             incident.status as i16,
             incident.priority as i16,
             serde_json::to_value(incident.metadata)?,
@@ -306,6 +368,7 @@ impl IncidentRepository for IncidentRepositoryAdapter {
             incident.incident_source_type as i16,
             incident.incident_source_id,
             incident.resolved_at,
+            incident.title,
             incident.organization_id,
             incident.id
         )
@@ -0,0 +1,574 @@
+use std::{
+    error::Error,
+    net::{IpAddr, TcpStream, ToSocketAddrs},
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use openssl::{
+    asn1::{Asn1Time, Asn1TimeRef},
+    ssl::{SslConnector, SslMethod, SslVerifyMode},
+    x509::{X509NameRef, X509},
+};
+use reqwest::{header::CONTENT_TYPE, redirect::Policy, tls::TlsInfo, Method};
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::domain::{
+    entities::{
+        http_monitor::HttpMonitorErrorKind,
+        http_monitor_request::{HttpMethod, HttpMonitorAuthentication, HttpMonitorRedirectPolicy},
+        tls_certificate::{certificate_matches_hostname, TlsCertificate},
+    },
+    ports::http_client::{HttpClient, PingRequest, PingResponse},
+};
+
+/// The location reported by the lightweight probe engine, which always pings from the server itself
+const LIGHTWEIGHT_LOCATION: &str = "default";
+
+const USER_AGENT: &str = "DutyDuck-Monitor/1.0";
+
+/// Pings the monitors with plain HTTP requests sent by the server itself.
+/// Unlike the browser service, no page is rendered, so no screenshot is taken and the body is the raw response body.
+#[derive(Clone, Default)]
+pub struct LightweightHttpClientAdapter;
+
+impl LightweightHttpClientAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// A new client is built for every ping, so that connections are never reused
+    /// and the response time always includes the connection setup
+    fn build_client(request: &PingRequest) -> reqwest::Result<reqwest::Client> {
+        let redirect_policy = match request.redirect_policy {
+            HttpMonitorRedirectPolicy::Follow => Policy::default(),
+            HttpMonitorRedirectPolicy::DontFollow => Policy::none(),
+            // reqwest counts the original request along with the redirects
+            HttpMonitorRedirectPolicy::MaxHops { max_hops } => Policy::limited(max_hops as usize + 1),
+        };
+        reqwest::Client::builder()
+            .timeout(request.request_timeout)
+            .redirect(redirect_policy)
+            .pool_max_idle_per_host(0)
+            .user_agent(USER_AGENT)
+            .tls_info(true)
+            .build()
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for LightweightHttpClientAdapter {
+    fn locations(&self) -> Vec<String> {
+        vec![LIGHTWEIGHT_LOCATION.to_string()]
+    }
+
+    async fn ping(&self, _location: &str, request: &PingRequest) -> PingResponse {
+        let client = match Self::build_client(request) {
+            Ok(client) => client,
+            Err(e) => {
+                warn!(error = ?e, "Failed to build the lightweight HTTP client");
+                return PingResponse {
+                    error_kind: HttpMonitorErrorKind::Builder,
+                    ..Default::default()
+                };
+            }
+        };
+
+        let mut request_builder = client.request(method(request.method), &request.endpoint);
+        for (name, value) in &request.request_headers {
+            request_builder = request_builder.header(name, value);
+        }
+        request_builder = match &request.authentication {
+            HttpMonitorAuthentication::None => request_builder,
+            HttpMonitorAuthentication::Basic { username, password } => {
+                request_builder.basic_auth(username, Some(password))
+            }
+            HttpMonitorAuthentication::Bearer { token } => request_builder.bearer_auth(token),
+        };
+        if let Some(body) = &request.body {
+            if let Some(content_type) = &request.body_content_type {
+                request_builder = request_builder.header(CONTENT_TYPE, content_type);
+            }
+            request_builder = request_builder.body(body.clone());
+        }
+
+        let resolved_ip_addresses = resolve_ip_addresses(&request.endpoint).await;
+
+        let fetch_start = Instant::now();
+        let response = match request_builder.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                debug!(error = ?e, endpoint = request.endpoint, "Lightweight ping failed");
+                let response_time = fetch_start.elapsed();
+                let error_kind = error_kind(&e);
+                // The certificate cannot be retrieved from the failed request, so it is fetched with a bare handshake
+                let tls_certificate = match (error_kind, e.url()) {
+                    (HttpMonitorErrorKind::TlsCertificate, Some(url)) => {
+                        fetch_unverified_certificate(url.clone(), request.request_timeout).await
+                    }
+                    _ => None,
+                };
+                return PingResponse {
+                    error_kind,
+                    response_time,
+                    resolved_ip_addresses,
+                    tls_certificate,
+                    ..Default::default()
+                };
+            }
+        };
+
+        let http_code = response.status().as_u16();
+        let http_headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_string()))
+            })
+            .collect();
+        let response_ip_address = response.remote_addr().map(|addr| addr.ip().to_string());
+        // The certificate of the last response is the one of the final URL, after the redirects
+        let tls_certificate = response
+            .extensions()
+            .get::<TlsInfo>()
+            .and_then(|tls_info| tls_info.peer_certificate())
+            .and_then(|der| parse_certificate(der, response.url(), true));
+
+        let body = response.bytes().await;
+        let response_time = fetch_start.elapsed();
+        let (error_kind, body) = match body {
+            Ok(body) if http_code >= 400 => (HttpMonitorErrorKind::HttpCode, Some(body.to_vec())),
+            Ok(body) => (HttpMonitorErrorKind::None, Some(body.to_vec())),
+            Err(e) => (error_kind(&e), None),
+        };
+
+        PingResponse {
+            http_code: Some(http_code),
+            error_kind,
+            http_headers,
+            response_time,
+            response_ip_address,
+            resolved_ip_addresses,
+            response_body_size_bytes: body.as_ref().map(|body| body.len() as u64).unwrap_or(0),
+            response_body_content: body,
+            screenshot: None,
+            tls_certificate,
+        }
+    }
+}
+
+fn method(method: HttpMethod) -> Method {
+    match method {
+        HttpMethod::Get => Method::GET,
+        HttpMethod::Head => Method::HEAD,
+        HttpMethod::Post => Method::POST,
+        HttpMethod::Put => Method::PUT,
+        HttpMethod::Patch => Method::PATCH,
+        HttpMethod::Delete => Method::DELETE,
+        HttpMethod::Options => Method::OPTIONS,
+    }
+}
+
+/// Maps a reqwest error to the error kinds reported by the browser service
+fn error_kind(error: &reqwest::Error) -> HttpMonitorErrorKind {
+    if is_tls_certificate_error(error) {
+        HttpMonitorErrorKind::TlsCertificate
+    } else if error.is_timeout() {
+        HttpMonitorErrorKind::Timeout
+    } else if error.is_connect() {
+        HttpMonitorErrorKind::Connect
+    } else if error.is_redirect() {
+        HttpMonitorErrorKind::Redirect
+    } else if error.is_builder() {
+        HttpMonitorErrorKind::Builder
+    } else if error.is_body() {
+        HttpMonitorErrorKind::Body
+    } else if error.is_decode() {
+        HttpMonitorErrorKind::Decode
+    } else if error.is_request() {
+        HttpMonitorErrorKind::Request
+    } else {
+        HttpMonitorErrorKind::Unknown
+    }
+}
+
+/// Whether the request failed because the certificate chain of the server could not be verified.
+/// The TLS errors are not exposed by reqwest, so the messages of the underlying errors are inspected
+fn is_tls_certificate_error(error: &reqwest::Error) -> bool {
+    let mut source = error.source();
+    while let Some(error) = source {
+        let message = error.to_string();
+        // OpenSSL and rustls messages respectively
+        if message.contains("certificate verify failed") || message.contains("invalid peer certificate") {
+            return true;
+        }
+        source = error.source();
+    }
+    false
+}
+
+/// Retrieves the certificate of a server that failed the verification, with a TLS handshake that skips it.
+/// No HTTP request is sent, so the monitor's credentials are never sent to an unverified server
+async fn fetch_unverified_certificate(url: Url, timeout: Duration) -> Option<TlsCertificate> {
+    let host = url.host_str()?.trim_matches(['[', ']']).to_string();
+    let port = url.port_or_known_default()?;
+    let handshake = tokio::task::spawn_blocking(move || -> anyhow::Result<Option<TlsCertificate>> {
+        let mut stream = None;
+        for address in (host.as_str(), port).to_socket_addrs()? {
+            if let Ok(connected) = TcpStream::connect_timeout(&address, timeout) {
+                stream = Some(connected);
+                break;
+            }
+        }
+        let Some(stream) = stream else {
+            return Ok(None);
+        };
+        stream.set_read_timeout(Some(timeout))?;
+        stream.set_write_timeout(Some(timeout))?;
+
+        let mut connector = SslConnector::builder(SslMethod::tls_client())?;
+        connector.set_verify(SslVerifyMode::NONE);
+        let stream = connector
+            .build()
+            .configure()?
+            .verify_hostname(false)
+            .connect(&host, stream)?;
+        Ok(stream
+            .ssl()
+            .peer_certificate()
+            .and_then(|certificate| certificate.to_der().ok())
+            .and_then(|der| parse_certificate(&der, &url, false)))
+    });
+    match handshake.await {
+        Ok(Ok(certificate)) => certificate,
+        Ok(Err(e)) => {
+            debug!(error = ?e, "Failed to retrieve the certificate of the endpoint");
+            None
+        }
+        Err(e) => {
+            warn!(error = ?e, "The certificate retrieval task failed");
+            None
+        }
+    }
+}
+
+/// Parses a DER encoded certificate, and checks it against the host of the URL it was presented for
+fn parse_certificate(der: &[u8], url: &Url, valid_chain: bool) -> Option<TlsCertificate> {
+    let certificate = X509::from_der(der).ok()?;
+    let subject_alternative_names = certificate
+        .subject_alt_names()
+        .map(|names| {
+            names
+                .iter()
+                .filter_map(|name| {
+                    name.dnsname()
+                        .map(str::to_string)
+                        .or_else(|| name.ipaddress().and_then(ip_address_to_string))
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let hostname = url.host_str()?.trim_matches(['[', ']']);
+    Some(TlsCertificate {
+        subject: name_to_string(certificate.subject_name()),
+        issuer: name_to_string(certificate.issuer_name()),
+        hostname_matches: certificate_matches_hostname(&subject_alternative_names, hostname),
+        subject_alternative_names,
+        not_before: asn1_time_to_date_time(certificate.not_before())?,
+        not_after: asn1_time_to_date_time(certificate.not_after())?,
+        valid_chain,
+    })
+}
+
+/// Formats a distinguished name as `CN=example.com, O=Example`
+fn name_to_string(name: &X509NameRef) -> String {
+    name.entries()
+        .map(|entry| {
+            let key = entry.object().nid().short_name().unwrap_or("?");
+            let value = entry
+                .data()
+                .as_utf8()
+                .map(|value| value.to_string())
+                .unwrap_or_default();
+            format!("{key}={value}")
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn ip_address_to_string(bytes: &[u8]) -> Option<String> {
+    let ip_address = match bytes.len() {
+        4 => IpAddr::from(<[u8; 4]>::try_from(bytes).ok()?),
+        16 => IpAddr::from(<[u8; 16]>::try_from(bytes).ok()?),
+        _ => return None,
+    };
+    Some(ip_address.to_string())
+}
+
+fn asn1_time_to_date_time(time: &Asn1TimeRef) -> Option<DateTime<Utc>> {
+    let since_epoch = Asn1Time::from_unix(0).ok()?.diff(time).ok()?;
+    DateTime::from_timestamp(since_epoch.days as i64 * 86_400 + since_epoch.secs as i64, 0)
+}
+
+/// Resolves all the IP addresses of the endpoint's host
+async fn resolve_ip_addresses(endpoint: &str) -> Vec<String> {
+    let Some((host, port)) = Url::parse(endpoint).ok().and_then(|url| {
+        Some((
+            url.host_str()?.trim_matches(['[', ']']).to_string(),
+            url.port_or_known_default()?,
+        ))
+    }) else {
+        return vec![];
+    };
+    let addresses = match tokio::net::lookup_host((host.as_str(), port)).await {
+        Ok(addresses) => addresses,
+        Err(e) => {
+            debug!(error = ?e, host, "Failed to resolve the IP addresses of the endpoint");
+            return vec![];
+        }
+    };
+    let mut ip_addresses = Vec::<String>::new();
+    for address in addresses {
+        let ip_address = address.ip().to_string();
+        if !ip_addresses.contains(&ip_address) {
+            ip_addresses.push(ip_address);
+        }
+    }
+    ip_addresses
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use axum::{
+        body::Bytes,
+        http::{HeaderMap, StatusCode},
+        response::Redirect,
+        routing::{get, post},
+        Router,
+    };
+
+    use super::*;
+    use crate::domain::entities::{http_monitor_request::ProbeEngine, monitor_kind::MonitorKindSettings};
+
+    /// Starts a local HTTP server and returns its base URL
+    async fn start_server() -> String {
+        let router = Router::new()
+            .route("/", get(|| async { "Hello there!" }))
+            .route(
+                "/status/503",
+                get(|| async { (StatusCode::SERVICE_UNAVAILABLE, "Unavailable") }),
+            )
+            .route("/redirect/1", get(|| async { Redirect::temporary("/") }))
+            .route("/redirect/2", get(|| async { Redirect::temporary("/redirect/1") }))
+            .route(
+                "/echo",
+                post(|headers: HeaderMap, body: Bytes| async move {
+                    let header = |name: &str| {
+                        headers
+                            .get(name)
+                            .and_then(|value| value.to_str().ok())
+                            .unwrap_or_default()
+                            .to_string()
+                    };
+                    format!(
+                        "{}|{}|{}",
+                        header("authorization"),
+                        header("content-type"),
+                        String::from_utf8_lossy(&body)
+                    )
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        format!("http://{address}")
+    }
+
+    fn ping_request(endpoint: String) -> PingRequest {
+        PingRequest {
+            endpoint,
+            request_timeout: Duration::from_secs(5),
+            request_headers: HashMap::new(),
+            method: HttpMethod::Get,
+            body: None,
+            body_content_type: None,
+            authentication: HttpMonitorAuthentication::None,
+            redirect_policy: HttpMonitorRedirectPolicy::Follow,
+            probe_engine: ProbeEngine::Lightweight,
+            kind_settings: MonitorKindSettings::Http,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ping_success() {
+        let base_url = start_server().await;
+        let client = LightweightHttpClientAdapter::new();
+
+        let response = client
+            .ping(LIGHTWEIGHT_LOCATION, &ping_request(format!("{base_url}/")))
+            .await;
+
+        assert_eq!(response.error_kind, HttpMonitorErrorKind::None);
+        assert_eq!(response.http_code, Some(200));
+        assert_eq!(response.response_body_content, Some(b"Hello there!".to_vec()));
+        assert_eq!(response.response_body_size_bytes, 12);
+        assert_eq!(response.response_ip_address.as_deref(), Some("127.0.0.1"));
+        assert_eq!(response.resolved_ip_addresses, vec!["127.0.0.1".to_string()]);
+        assert!(response.http_headers.contains_key("content-type"));
+    }
+
+    #[tokio::test]
+    async fn test_ping_errors() {
+        let base_url = start_server().await;
+        let client = LightweightHttpClientAdapter::new();
+
+        let response = client
+            .ping(LIGHTWEIGHT_LOCATION, &ping_request(format!("{base_url}/status/503")))
+            .await;
+        assert_eq!(response.error_kind, HttpMonitorErrorKind::HttpCode);
+        assert_eq!(response.http_code, Some(503));
+
+        // Nothing listens on the port of a dropped listener
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        let response = client
+            .ping(LIGHTWEIGHT_LOCATION, &ping_request(format!("http://{address}/")))
+            .await;
+        assert_eq!(response.error_kind, HttpMonitorErrorKind::Connect);
+        assert_eq!(response.http_code, None);
+    }
+
+    #[tokio::test]
+    async fn test_ping_with_method_body_and_authentication() {
+        let base_url = start_server().await;
+        let client = LightweightHttpClientAdapter::new();
+
+        let request = PingRequest {
+            method: HttpMethod::Post,
+            body: Some("{\"query\":\"{ health }\"}".to_string()),
+            body_content_type: Some("application/json".to_string()),
+            authentication: HttpMonitorAuthentication::Bearer {
+                token: "secret".to_string(),
+            },
+            ..ping_request(format!("{base_url}/echo"))
+        };
+        let response = client.ping(LIGHTWEIGHT_LOCATION, &request).await;
+
+        assert_eq!(response.error_kind, HttpMonitorErrorKind::None);
+        assert_eq!(
+            response.response_body_content,
+            Some(b"Bearer secret|application/json|{\"query\":\"{ health }\"}".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ping_redirect_policies() {
+        let base_url = start_server().await;
+        let client = LightweightHttpClientAdapter::new();
+        let ping = |redirect_policy| {
+            let request = PingRequest {
+                redirect_policy,
+                ..ping_request(format!("{base_url}/redirect/2"))
+            };
+            let client = client.clone();
+            async move { client.ping(LIGHTWEIGHT_LOCATION, &request).await }
+        };
+
+        let response = ping(HttpMonitorRedirectPolicy::Follow).await;
+        assert_eq!(response.error_kind, HttpMonitorErrorKind::None);
+        assert_eq!(response.http_code, Some(200));
+
+        let response = ping(HttpMonitorRedirectPolicy::DontFollow).await;
+        assert_eq!(response.error_kind, HttpMonitorErrorKind::None);
+        assert_eq!(response.http_code, Some(307));
+
+        let response = ping(HttpMonitorRedirectPolicy::MaxHops { max_hops: 2 }).await;
+        assert_eq!(response.http_code, Some(200));
+
+        let response = ping(HttpMonitorRedirectPolicy::MaxHops { max_hops: 1 }).await;
+        assert_eq!(response.error_kind, HttpMonitorErrorKind::Redirect);
+    }
+
+    /// Starts a local HTTPS server presenting a self-signed certificate, and returns its port
+    fn start_self_signed_tls_server(not_after: &Asn1TimeRef) -> u16 {
+        use openssl::{
+            ec::{EcGroup, EcKey},
+            hash::MessageDigest,
+            nid::Nid,
+            pkey::PKey,
+            ssl::SslAcceptor,
+            x509::{extension::SubjectAlternativeName, X509NameBuilder},
+        };
+
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        let key = PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap();
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "localhost").unwrap();
+        let name = name.build();
+
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder.set_not_after(not_after).unwrap();
+        let san = SubjectAlternativeName::new()
+            .dns("localhost")
+            .ip("127.0.0.1")
+            .build(&builder.x509v3_context(None, None))
+            .unwrap();
+        builder.append_extension(san).unwrap();
+        builder.sign(&key, MessageDigest::sha256()).unwrap();
+        let certificate = builder.build();
+
+        let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
+        acceptor.set_private_key(&key).unwrap();
+        acceptor.set_certificate(&certificate).unwrap();
+        let acceptor = acceptor.build();
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            // The handshakes fail once the client rejects the certificate, which is expected
+            for stream in listener.incoming().flatten() {
+                let _ = acceptor.accept(stream);
+            }
+        });
+        port
+    }
+
+    #[tokio::test]
+    async fn test_ping_invalid_tls_certificate() {
+        let not_after = Asn1Time::days_from_now(30).unwrap();
+        let port = start_self_signed_tls_server(&not_after);
+        let client = LightweightHttpClientAdapter::new();
+
+        let response = client
+            .ping(LIGHTWEIGHT_LOCATION, &ping_request(format!("https://localhost:{port}/")))
+            .await;
+
+        assert_eq!(response.error_kind, HttpMonitorErrorKind::TlsCertificate);
+        let certificate = response
+            .tls_certificate
+            .expect("The certificate should be fetched without verification");
+        assert_eq!(certificate.subject, "CN=localhost");
+        assert_eq!(certificate.issuer, "CN=localhost");
+        assert_eq!(
+            certificate.subject_alternative_names,
+            vec!["localhost".to_string(), "127.0.0.1".to_string()]
+        );
+        assert!(certificate.hostname_matches);
+        assert!(!certificate.valid_chain);
+        assert_eq!(Some(certificate.not_after), asn1_time_to_date_time(&not_after));
+    }
+}
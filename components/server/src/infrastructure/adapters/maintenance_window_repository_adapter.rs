@@ -0,0 +1,108 @@
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::maintenance_window::MaintenanceWindow,
+        ports::maintenance_window_repository::{MaintenanceWindowRepository, NewMaintenanceWindow},
+    },
+    postgres_transactional_repo,
+};
+
+#[derive(Clone)]
+pub struct MaintenanceWindowRepositoryAdapter {
+    pub pool: PgPool,
+}
+
+postgres_transactional_repo!(MaintenanceWindowRepositoryAdapter);
+
+#[async_trait::async_trait]
+impl MaintenanceWindowRepository for MaintenanceWindowRepositoryAdapter {
+    async fn list_maintenance_windows(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<MaintenanceWindow>> {
+        sqlx::query_as!(
+            MaintenanceWindow,
+            "SELECT * FROM maintenance_windows WHERE organization_id = $1 ORDER BY starts_at DESC",
+            organization_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to list maintenance windows")
+    }
+
+    async fn list_maintenance_windows_ending_after(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        date: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<MaintenanceWindow>> {
+        sqlx::query_as!(
+            MaintenanceWindow,
+            r#"
+            SELECT * FROM maintenance_windows
+            WHERE organization_id = $1
+            AND (
+                ends_at IS NULL
+                OR ends_at > $2
+                -- the last occurrence of a recurring window may end after the window stops recurring
+                OR (cron_schedule IS NOT NULL AND ends_at + make_interval(secs => duration_seconds) > $2)
+            )
+            "#,
+            organization_id,
+            date
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to list maintenance windows ending after a date")
+    }
+
+    async fn create_maintenance_window(
+        &self,
+        tx: &mut Self::Transaction,
+        maintenance_window: NewMaintenanceWindow,
+    ) -> anyhow::Result<Uuid> {
+        let record = sqlx::query!(
+            r#"
+            INSERT INTO maintenance_windows (organization_id, title, description, starts_at, ends_at, cron_schedule, duration_seconds, scope)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id
+            "#,
+            maintenance_window.organization_id,
+            maintenance_window.title,
+            maintenance_window.description,
+            maintenance_window.starts_at,
+            maintenance_window.ends_at,
+            maintenance_window.cron_schedule,
+            maintenance_window.duration_seconds,
+            serde_json::to_value(&maintenance_window.scope)?,
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .context("Failed to create maintenance window")?;
+
+        Ok(record.id)
+    }
+
+    async fn delete_maintenance_window(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query!(
+            "DELETE FROM maintenance_windows WHERE organization_id = $1 AND id = $2",
+            organization_id,
+            id
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to delete maintenance window")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
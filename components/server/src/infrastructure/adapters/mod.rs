@@ -1,4 +1,7 @@
 pub mod http_client_adapter;
+pub mod browser_http_client_adapter;
+pub mod lightweight_http_client_adapter;
+pub mod network_probe_adapter;
 pub mod http_monitor_repository_adapter;
 pub mod incident_repository_adapter;
 pub mod organization_repository_adapter;
@@ -12,4 +15,14 @@ pub mod sms_notification_server_adapter;
 pub mod file_storage_adapter;
 pub mod api_access_token_repository_adapter;
 pub mod task_repository_adapter;
-pub mod task_run_repository_adapter;
\ No newline at end of file
+pub mod task_run_repository_adapter;
+pub mod escalation_policy_repository_adapter;
+pub mod webhook_endpoint_repository_adapter;
+pub mod webhook_delivery_repository_adapter;
+pub mod webhook_client_adapter;
+pub mod status_page_repository_adapter;
+pub mod maintenance_window_repository_adapter;
+pub mod on_call_schedule_repository_adapter;
+pub mod notification_preferences_repository_adapter;
+pub mod alert_integration_repository_adapter;
+pub mod task_run_event_repository_adapter;
@@ -0,0 +1,360 @@
+use std::time::{Duration, Instant};
+
+use hickory_resolver::{
+    config::{ResolverConfig, ResolverOpts},
+    error::{ResolveError, ResolveErrorKind},
+    proto::rr::RecordType,
+    system_conf::read_system_conf,
+    TokioAsyncResolver,
+};
+use tokio::net::TcpStream;
+use tonic::{
+    transport::{ClientTlsConfig, Endpoint},
+    Code,
+};
+use tonic_health::pb::{
+    health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest,
+};
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::domain::{
+    entities::{
+        http_monitor::HttpMonitorErrorKind,
+        monitor_kind::{dns_answers_match, DnsRecordType, MonitorKindSettings},
+    },
+    ports::http_client::{PingRequest, PingResponse},
+};
+
+/// Probes the TCP, DNS and gRPC health monitors from the server itself.
+/// Like the lightweight probe engine, nothing is reused between two pings, so that each response time
+/// includes the connection setup, and DNS answers are never served from a cache.
+#[derive(Clone)]
+pub struct NetworkProbeAdapter {
+    resolver_config: ResolverConfig,
+    resolver_opts: ResolverOpts,
+}
+
+impl NetworkProbeAdapter {
+    pub fn new() -> Self {
+        let (resolver_config, mut resolver_opts) = read_system_conf().unwrap_or_else(|e| {
+            warn!(error = ?e, "Failed to read the system DNS configuration, using the default resolvers");
+            (ResolverConfig::default(), ResolverOpts::default())
+        });
+        resolver_opts.cache_size = 0;
+        Self {
+            resolver_config,
+            resolver_opts,
+        }
+    }
+
+    pub async fn ping(&self, request: &PingRequest) -> PingResponse {
+        let Some(url) = Url::parse(&request.endpoint)
+            .ok()
+            .filter(|url| url.host_str().is_some())
+        else {
+            warn!(endpoint = request.endpoint, "Invalid monitor target");
+            return PingResponse {
+                error_kind: HttpMonitorErrorKind::Builder,
+                ..Default::default()
+            };
+        };
+
+        match &request.kind_settings {
+            MonitorKindSettings::Tcp => ping_tcp(&url, request.request_timeout).await,
+            MonitorKindSettings::Dns {
+                record_type,
+                expected_answers,
+            } => {
+                self.ping_dns(&url, *record_type, expected_answers, request.request_timeout)
+                    .await
+            }
+            MonitorKindSettings::GrpcHealth { service } => {
+                ping_grpc_health(&url, service, request.request_timeout).await
+            }
+            MonitorKindSettings::Http => {
+                warn!(endpoint = request.endpoint, "HTTP monitors cannot be probed by the network probe");
+                PingResponse {
+                    error_kind: HttpMonitorErrorKind::Builder,
+                    ..Default::default()
+                }
+            }
+        }
+    }
+
+    /// Resolves the records of the monitored type, which are reported as the response body, one per line
+    async fn ping_dns(
+        &self,
+        url: &Url,
+        record_type: DnsRecordType,
+        expected_answers: &[String],
+        timeout: Duration,
+    ) -> PingResponse {
+        let hostname = url.host_str().unwrap_or_default();
+        let mut resolver_opts = self.resolver_opts.clone();
+        resolver_opts.timeout = timeout;
+        let resolver = TokioAsyncResolver::tokio(self.resolver_config.clone(), resolver_opts);
+
+        let start = Instant::now();
+        let lookup = tokio::time::timeout(timeout, resolver.lookup(hostname, record_type.into())).await;
+        let response_time = start.elapsed();
+        let lookup = match lookup {
+            Ok(Ok(lookup)) => lookup,
+            Ok(Err(e)) => {
+                debug!(error = ?e, hostname, "DNS ping failed");
+                return PingResponse {
+                    error_kind: dns_error_kind(&e),
+                    response_time,
+                    ..Default::default()
+                };
+            }
+            Err(_) => {
+                return PingResponse {
+                    error_kind: HttpMonitorErrorKind::Timeout,
+                    response_time,
+                    ..Default::default()
+                };
+            }
+        };
+
+        let answers = lookup.iter().map(|rdata| rdata.to_string()).collect::<Vec<_>>();
+        let error_kind = if dns_answers_match(expected_answers, &answers) {
+            HttpMonitorErrorKind::None
+        } else {
+            HttpMonitorErrorKind::UnexpectedDnsAnswer
+        };
+        let resolved_ip_addresses = match record_type {
+            DnsRecordType::A | DnsRecordType::Aaaa => answers.clone(),
+            _ => vec![],
+        };
+        let body = answers.join("\n").into_bytes();
+        PingResponse {
+            error_kind,
+            response_time,
+            resolved_ip_addresses,
+            response_body_size_bytes: body.len() as u64,
+            response_body_content: Some(body),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for NetworkProbeAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<DnsRecordType> for RecordType {
+    fn from(value: DnsRecordType) -> Self {
+        match value {
+            DnsRecordType::A => Self::A,
+            DnsRecordType::Aaaa => Self::AAAA,
+            DnsRecordType::Cname => Self::CNAME,
+            DnsRecordType::Mx => Self::MX,
+            DnsRecordType::Ns => Self::NS,
+            DnsRecordType::Txt => Self::TXT,
+        }
+    }
+}
+
+/// Opens a TCP connection to the port of the monitor, and closes it right away
+async fn ping_tcp(url: &Url, timeout: Duration) -> PingResponse {
+    let host = url.host_str().unwrap_or_default().trim_matches(['[', ']']);
+    let port = url.port().unwrap_or_default();
+
+    let start = Instant::now();
+    let connection = tokio::time::timeout(timeout, TcpStream::connect((host, port))).await;
+    let response_time = start.elapsed();
+    match connection {
+        Ok(Ok(stream)) => PingResponse {
+            error_kind: HttpMonitorErrorKind::None,
+            response_time,
+            response_ip_address: stream.peer_addr().ok().map(|addr| addr.ip().to_string()),
+            ..Default::default()
+        },
+        Ok(Err(e)) => {
+            debug!(error = ?e, host, port, "TCP ping failed");
+            PingResponse {
+                error_kind: HttpMonitorErrorKind::Connect,
+                response_time,
+                ..Default::default()
+            }
+        }
+        Err(_) => PingResponse {
+            error_kind: HttpMonitorErrorKind::Timeout,
+            response_time,
+            ..Default::default()
+        },
+    }
+}
+
+/// Calls the `grpc.health.v1.Health/Check` method of the server, over TLS for `grpcs://` targets
+async fn ping_grpc_health(url: &Url, service: &str, timeout: Duration) -> PingResponse {
+    let scheme = if url.scheme() == "grpcs" { "https" } else { "http" };
+    let uri = format!(
+        "{scheme}://{}:{}",
+        url.host_str().unwrap_or_default(),
+        url.port().unwrap_or_default()
+    );
+    let endpoint = Endpoint::from_shared(uri)
+        .map(|endpoint| endpoint.connect_timeout(timeout).timeout(timeout))
+        .and_then(|endpoint| match scheme {
+            "https" => endpoint.tls_config(ClientTlsConfig::new().with_native_roots()),
+            _ => Ok(endpoint),
+        });
+    let endpoint = match endpoint {
+        Ok(endpoint) => endpoint,
+        Err(e) => {
+            warn!(error = ?e, "Failed to build the gRPC endpoint");
+            return PingResponse {
+                error_kind: HttpMonitorErrorKind::Builder,
+                ..Default::default()
+            };
+        }
+    };
+
+    let start = Instant::now();
+    let channel = match endpoint.connect().await {
+        Ok(channel) => channel,
+        Err(e) => {
+            debug!(error = ?e, endpoint = ?endpoint.uri(), "gRPC health ping failed to connect");
+            return PingResponse {
+                error_kind: HttpMonitorErrorKind::Connect,
+                response_time: start.elapsed(),
+                ..Default::default()
+            };
+        }
+    };
+    let response = HealthClient::new(channel)
+        .check(HealthCheckRequest {
+            service: service.to_string(),
+        })
+        .await;
+    let response_time = start.elapsed();
+
+    let error_kind = match response {
+        Ok(response) if response.get_ref().status() == ServingStatus::Serving => {
+            HttpMonitorErrorKind::None
+        }
+        Ok(_) => HttpMonitorErrorKind::NotServing,
+        Err(status) => {
+            debug!(status = ?status, endpoint = ?endpoint.uri(), "gRPC health ping failed");
+            match status.code() {
+                // the health service answers NOT_FOUND for unknown services
+                Code::NotFound => HttpMonitorErrorKind::NotServing,
+                Code::DeadlineExceeded => HttpMonitorErrorKind::Timeout,
+                Code::Unavailable => HttpMonitorErrorKind::Connect,
+                _ => HttpMonitorErrorKind::Request,
+            }
+        }
+    };
+    PingResponse {
+        error_kind,
+        response_time,
+        ..Default::default()
+    }
+}
+
+fn dns_error_kind(error: &ResolveError) -> HttpMonitorErrorKind {
+    match error.kind() {
+        ResolveErrorKind::Timeout => HttpMonitorErrorKind::Timeout,
+        ResolveErrorKind::NoConnections | ResolveErrorKind::Io(_) => HttpMonitorErrorKind::Connect,
+        _ => HttpMonitorErrorKind::DnsResolution,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use tonic::transport::{server::TcpIncoming, Server};
+    use tonic_health::ServingStatus;
+
+    use super::*;
+    use crate::domain::entities::{
+        http_monitor_request::{
+            HttpMethod, HttpMonitorAuthentication, HttpMonitorRedirectPolicy, ProbeEngine,
+        },
+        monitor_kind::MonitorKindSettings,
+    };
+
+    fn ping_request(endpoint: String, kind_settings: MonitorKindSettings) -> PingRequest {
+        PingRequest {
+            endpoint,
+            request_timeout: Duration::from_secs(5),
+            request_headers: HashMap::new(),
+            method: HttpMethod::Get,
+            body: None,
+            body_content_type: None,
+            authentication: HttpMonitorAuthentication::None,
+            redirect_policy: HttpMonitorRedirectPolicy::Follow,
+            probe_engine: ProbeEngine::Lightweight,
+            kind_settings,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ping_tcp() {
+        let probe = NetworkProbeAdapter::new();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let response = probe
+            .ping(&ping_request(format!("tcp://{address}"), MonitorKindSettings::Tcp))
+            .await;
+        assert_eq!(response.error_kind, HttpMonitorErrorKind::None);
+        assert_eq!(response.response_ip_address.as_deref(), Some("127.0.0.1"));
+
+        // Nothing listens on the port of a dropped listener
+        drop(listener);
+        let response = probe
+            .ping(&ping_request(format!("tcp://{address}"), MonitorKindSettings::Tcp))
+            .await;
+        assert_eq!(response.error_kind, HttpMonitorErrorKind::Connect);
+    }
+
+    #[tokio::test]
+    async fn test_ping_grpc_health() {
+        let (mut reporter, health_service) = tonic_health::server::health_reporter();
+        reporter
+            .set_service_status("orders", ServingStatus::NotServing)
+            .await;
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let incoming = TcpIncoming::from_listener(listener, true, None).unwrap();
+        tokio::spawn(
+            Server::builder()
+                .add_service(health_service)
+                .serve_with_incoming(incoming),
+        );
+
+        let probe = NetworkProbeAdapter::new();
+        let ping = |service: &str| {
+            let request = ping_request(
+                format!("grpc://{address}"),
+                MonitorKindSettings::GrpcHealth {
+                    service: service.to_string(),
+                },
+            );
+            let probe = probe.clone();
+            async move { probe.ping(&request).await }
+        };
+
+        assert_eq!(ping("").await.error_kind, HttpMonitorErrorKind::None);
+        assert_eq!(ping("orders").await.error_kind, HttpMonitorErrorKind::NotServing);
+        assert_eq!(ping("unknown").await.error_kind, HttpMonitorErrorKind::NotServing);
+
+        // Nothing listens on the port of a dropped listener
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        let request = ping_request(
+            format!("grpc://{address}"),
+            MonitorKindSettings::GrpcHealth {
+                service: String::new(),
+            },
+        );
+        assert_eq!(probe.ping(&request).await.error_kind, HttpMonitorErrorKind::Connect);
+    }
+}
@@ -0,0 +1,122 @@
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::notification_preferences::NotificationPreferences,
+        ports::notification_preferences_repository::NotificationPreferencesRepository,
+    },
+    postgres_transactional_repo,
+};
+
+#[derive(Clone)]
+pub struct NotificationPreferencesRepositoryAdapter {
+    pub pool: PgPool,
+}
+
+postgres_transactional_repo!(NotificationPreferencesRepositoryAdapter);
+
+struct NotificationPreferencesRecord {
+    organization_id: Uuid,
+    user_id: Uuid,
+    channels: Value,
+    quiet_hours: Option<Value>,
+    vacation: Option<Value>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<NotificationPreferencesRecord> for NotificationPreferences {
+    type Error = anyhow::Error;
+
+    fn try_from(record: NotificationPreferencesRecord) -> Result<Self, Self::Error> {
+        Ok(NotificationPreferences {
+            organization_id: record.organization_id,
+            user_id: record.user_id,
+            channels: serde_json::from_value(record.channels)
+                .context("Failed to deserialize notification channels")?,
+            quiet_hours: record
+                .quiet_hours
+                .map(serde_json::from_value)
+                .transpose()
+                .context("Failed to deserialize quiet hours")?,
+            vacation: record
+                .vacation
+                .map(serde_json::from_value)
+                .transpose()
+                .context("Failed to deserialize vacation mode")?,
+            updated_at: record.updated_at,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationPreferencesRepository for NotificationPreferencesRepositoryAdapter {
+    async fn get_notification_preferences(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<NotificationPreferences>> {
+        sqlx::query_as!(
+            NotificationPreferencesRecord,
+            "SELECT * FROM user_notification_preferences WHERE organization_id = $1 AND user_id = $2",
+            organization_id,
+            user_id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .context("Failed to get notification preferences")?
+        .map(NotificationPreferences::try_from)
+        .transpose()
+    }
+
+    async fn list_notification_preferences(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<NotificationPreferences>> {
+        sqlx::query_as!(
+            NotificationPreferencesRecord,
+            "SELECT * FROM user_notification_preferences WHERE organization_id = $1",
+            organization_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to list notification preferences")?
+        .into_iter()
+        .map(NotificationPreferences::try_from)
+        .collect()
+    }
+
+    async fn upsert_notification_preferences(
+        &self,
+        tx: &mut Self::Transaction,
+        preferences: NotificationPreferences,
+    ) -> anyhow::Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO user_notification_preferences (organization_id, user_id, channels, quiet_hours, vacation, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (organization_id, user_id) DO UPDATE SET
+                channels = EXCLUDED.channels,
+                quiet_hours = EXCLUDED.quiet_hours,
+                vacation = EXCLUDED.vacation,
+                updated_at = EXCLUDED.updated_at
+            "#,
+            preferences.organization_id,
+            preferences.user_id,
+            serde_json::to_value(&preferences.channels)?,
+            preferences.quiet_hours.as_ref().map(serde_json::to_value).transpose()?,
+            preferences.vacation.as_ref().map(serde_json::to_value).transpose()?,
+            preferences.updated_at,
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to upsert notification preferences")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::on_call_schedule::{OnCallOverride, OnCallSchedule},
+        ports::on_call_schedule_repository::{
+            NewOnCallOverride, NewOnCallSchedule, OnCallScheduleRepository,
+        },
+    },
+    postgres_transactional_repo,
+};
+
+#[derive(Clone)]
+pub struct OnCallScheduleRepositoryAdapter {
+    pub pool: PgPool,
+}
+
+postgres_transactional_repo!(OnCallScheduleRepositoryAdapter);
+
+#[async_trait::async_trait]
+impl OnCallScheduleRepository for OnCallScheduleRepositoryAdapter {
+    async fn list_on_call_schedules(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<OnCallSchedule>> {
+        sqlx::query_as!(
+            OnCallSchedule,
+            "SELECT * FROM on_call_schedules WHERE organization_id = $1 ORDER BY name",
+            organization_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to list on-call schedules")
+    }
+
+    async fn get_on_call_schedule(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<OnCallSchedule>> {
+        sqlx::query_as!(
+            OnCallSchedule,
+            "SELECT * FROM on_call_schedules WHERE organization_id = $1 AND id = $2",
+            organization_id,
+            id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .context("Failed to get on-call schedule")
+    }
+
+    async fn create_on_call_schedule(
+        &self,
+        tx: &mut Self::Transaction,
+        schedule: NewOnCallSchedule,
+    ) -> anyhow::Result<Uuid> {
+        let record = sqlx::query!(
+            r#"
+            INSERT INTO on_call_schedules (organization_id, name, description, time_zone, rotations)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            "#,
+            schedule.organization_id,
+            schedule.name,
+            schedule.description,
+            schedule.time_zone,
+            serde_json::to_value(&schedule.rotations)?,
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .context("Failed to create on-call schedule")?;
+
+        Ok(record.id)
+    }
+
+    async fn update_on_call_schedule(
+        &self,
+        tx: &mut Self::Transaction,
+        id: Uuid,
+        schedule: NewOnCallSchedule,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE on_call_schedules
+            SET name = $3, description = $4, time_zone = $5, rotations = $6, updated_at = now()
+            WHERE organization_id = $1 AND id = $2
+            "#,
+            schedule.organization_id,
+            id,
+            schedule.name,
+            schedule.description,
+            schedule.time_zone,
+            serde_json::to_value(&schedule.rotations)?,
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to update on-call schedule")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_on_call_schedule(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query!(
+            "DELETE FROM on_call_schedules WHERE organization_id = $1 AND id = $2",
+            organization_id,
+            id
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to delete on-call schedule")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_on_call_overrides_ending_after(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        on_call_schedule_id: Uuid,
+        date: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<OnCallOverride>> {
+        sqlx::query_as!(
+            OnCallOverride,
+            r#"
+            SELECT * FROM on_call_overrides
+            WHERE organization_id = $1 AND on_call_schedule_id = $2 AND ends_at > $3
+            ORDER BY starts_at
+            "#,
+            organization_id,
+            on_call_schedule_id,
+            date
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to list on-call overrides")
+    }
+
+    async fn create_on_call_override(
+        &self,
+        tx: &mut Self::Transaction,
+        on_call_override: NewOnCallOverride,
+    ) -> anyhow::Result<Uuid> {
+        let record = sqlx::query!(
+            r#"
+            INSERT INTO on_call_overrides (organization_id, on_call_schedule_id, user_id, starts_at, ends_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            "#,
+            on_call_override.organization_id,
+            on_call_override.on_call_schedule_id,
+            on_call_override.user_id,
+            on_call_override.starts_at,
+            on_call_override.ends_at,
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .context("Failed to create on-call override")?;
+
+        Ok(record.id)
+    }
+
+    async fn delete_on_call_override(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        on_call_schedule_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query!(
+            "DELETE FROM on_call_overrides WHERE organization_id = $1 AND on_call_schedule_id = $2 AND id = $3",
+            organization_id,
+            on_call_schedule_id,
+            id
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to delete on-call override")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
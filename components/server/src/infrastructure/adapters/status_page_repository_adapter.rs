@@ -0,0 +1,138 @@
+use anyhow::Context;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::status_page::StatusPage,
+        ports::status_page_repository::{NewStatusPage, StatusPageRepository},
+    },
+    postgres_transactional_repo,
+};
+
+#[derive(Clone)]
+pub struct StatusPageRepositoryAdapter {
+    pub pool: PgPool,
+}
+
+postgres_transactional_repo!(StatusPageRepositoryAdapter);
+
+#[async_trait::async_trait]
+impl StatusPageRepository for StatusPageRepositoryAdapter {
+    async fn list_status_pages(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<StatusPage>> {
+        sqlx::query_as!(
+            StatusPage,
+            "SELECT * FROM status_pages WHERE organization_id = $1 ORDER BY title",
+            organization_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to list status pages")
+    }
+
+    async fn get_status_page(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<StatusPage>> {
+        sqlx::query_as!(
+            StatusPage,
+            "SELECT * FROM status_pages WHERE organization_id = $1 AND id = $2",
+            organization_id,
+            id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .context("Failed to get status page")
+    }
+
+    async fn get_status_page_by_slug(
+        &self,
+        tx: &mut Self::Transaction,
+        slug: &str,
+    ) -> anyhow::Result<Option<StatusPage>> {
+        sqlx::query_as!(
+            StatusPage,
+            "SELECT * FROM status_pages WHERE slug = $1",
+            slug
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .context("Failed to get status page by slug")
+    }
+
+    async fn create_status_page(
+        &self,
+        tx: &mut Self::Transaction,
+        status_page: NewStatusPage,
+    ) -> anyhow::Result<Uuid> {
+        let record = sqlx::query!(
+            r#"
+            INSERT INTO status_pages (organization_id, slug, title, description, components, visibility)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id
+            "#,
+            status_page.organization_id,
+            status_page.slug,
+            status_page.title,
+            status_page.description,
+            serde_json::to_value(&status_page.components)?,
+            status_page.visibility as i16,
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .context("Failed to create status page")?;
+
+        Ok(record.id)
+    }
+
+    async fn update_status_page(
+        &self,
+        tx: &mut Self::Transaction,
+        id: Uuid,
+        status_page: NewStatusPage,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE status_pages
+            SET slug = $3, title = $4, description = $5, components = $6, visibility = $7, updated_at = now()
+            WHERE organization_id = $1 AND id = $2
+            "#,
+            status_page.organization_id,
+            id,
+            status_page.slug,
+            status_page.title,
+            status_page.description,
+            serde_json::to_value(&status_page.components)?,
+            status_page.visibility as i16,
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to update status page")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_status_page(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query!(
+            "DELETE FROM status_pages WHERE organization_id = $1 AND id = $2",
+            organization_id,
+            id
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to delete status page")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
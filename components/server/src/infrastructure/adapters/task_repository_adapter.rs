@@ -1,10 +1,14 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use itertools::Itertools;
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::task::{BoundaryTask, TaskId, TaskStatus},
+    entities::{
+        entity_metadata::{FilterableMetadata, FilterableMetadataItem, FilterableMetadataValue, MetadataFilter},
+        task::{BoundaryTask, TaskId, TaskStatus},
+    },
     ports::{
         task_repository::{ListTasksOutput, TaskRepository},
         transactional_repository::TransactionalRepository,
@@ -41,6 +45,7 @@ impl TaskRepository for TaskRepositoryAdapter {
         let task = record.map(|row| BoundaryTask {
             organization_id: row.organization_id,
             id: TaskId::new(row.id).expect("Invalid task ID in database"),
+            uuid: row.uuid,
             name: row.name,
             description: row.description,
             status: TaskStatus::from(row.status),
@@ -51,17 +56,44 @@ impl TaskRepository for TaskRepositoryAdapter {
             start_window_seconds: row.start_window_seconds,
             lateness_window_seconds: row.lateness_window_seconds,
             heartbeat_timeout_seconds: row.heartbeat_timeout_seconds,
+            max_duration_seconds: row.max_duration_seconds,
+            time_zone: row.time_zone,
+            max_concurrent_runs: row.max_concurrent_runs,
+            upstream_task_ids: row.upstream_task_ids.into_iter().map(TaskId::from).collect(),
+            metadata: row.metadata.into(),
+            email_notification_enabled: row.email_notification_enabled,
+            push_notification_enabled: row.push_notification_enabled,
+            sms_notification_enabled: row.sms_notification_enabled,
             created_at: row.created_at,
         });
 
         Ok(task)
     }
 
+    async fn get_task_for_update(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<Option<BoundaryTask>> {
+        sqlx::query!(
+            "SELECT 1 as locked FROM tasks WHERE organization_id = $1 AND id = $2 FOR UPDATE",
+            organization_id,
+            task_id.as_str(),
+        )
+        .fetch_optional(transaction.as_mut())
+        .await
+        .with_context(|| "Failed to lock task")?;
+
+        self.get_task(transaction, organization_id, task_id).await
+    }
+
     async fn list_tasks(
         &self,
         organization_id: Uuid,
         include_statuses: Vec<TaskStatus>,
         query: String,
+        metadata_filter: MetadataFilter,
         limit: u32,
         offset: u32,
     ) -> anyhow::Result<ListTasksOutput> {
@@ -71,14 +103,33 @@ impl TaskRepository for TaskRepositoryAdapter {
             .into_iter()
             .map(|s| s as i32)
             .collect::<Vec<_>>();
+        let metadata_filter = serde_json::to_value(metadata_filter.items)?;
 
         let rows = sqlx::query!(
             r#"
+            WITH filter_conditions AS (
+                SELECT
+                    key,
+                    jsonb_array_elements_text(value) as filter_value
+                FROM jsonb_each($6::jsonb)
+            )
             SELECT *, COUNT(*) OVER() as "filtered_count!" 
             FROM tasks
             WHERE organization_id = $1
             AND ($2::integer[] = '{}' OR status = ANY($2))
             AND ($3 = '' OR name ILIKE $3 OR description ILIKE $3)
+            -- filter by metadata
+            AND (
+                $6::jsonb = '{}'::jsonb OR
+                NOT EXISTS (
+                    SELECT 1 FROM filter_conditions fc
+                    WHERE NOT EXISTS (
+                        SELECT 1 FROM jsonb_each(tasks.metadata->'records') m
+                        WHERE m.key = fc.key
+                        AND (m.value #>> '{}') = fc.filter_value
+                    )
+                )
+            )
             ORDER BY name
             LIMIT $4 OFFSET $5
             "#,
@@ -87,6 +138,7 @@ impl TaskRepository for TaskRepositoryAdapter {
             &query,
             limit as i64,
             offset as i64,
+            &metadata_filter,
         )
         .fetch_all(&mut *tx)
         .await?;
@@ -110,6 +162,7 @@ impl TaskRepository for TaskRepositoryAdapter {
             .map(|row| BoundaryTask {
                 organization_id: row.organization_id,
                 id: TaskId::new(row.id).expect("Invalid task ID in database"),
+                uuid: row.uuid,
                 name: row.name,
                 description: row.description,
                 status: TaskStatus::from(row.status),
@@ -120,6 +173,14 @@ impl TaskRepository for TaskRepositoryAdapter {
                 start_window_seconds: row.start_window_seconds,
                 lateness_window_seconds: row.lateness_window_seconds,
                 heartbeat_timeout_seconds: row.heartbeat_timeout_seconds,
+                max_duration_seconds: row.max_duration_seconds,
+                time_zone: row.time_zone,
+                max_concurrent_runs: row.max_concurrent_runs,
+                upstream_task_ids: row.upstream_task_ids.into_iter().map(TaskId::from).collect(),
+                metadata: row.metadata.into(),
+                email_notification_enabled: row.email_notification_enabled,
+                push_notification_enabled: row.push_notification_enabled,
+                sms_notification_enabled: row.sms_notification_enabled,
                 created_at: row.created_at,
             })
             .collect();
@@ -131,6 +192,46 @@ impl TaskRepository for TaskRepositoryAdapter {
         })
     }
 
+    async fn get_filterable_metadata(&self, organization_id: Uuid) -> anyhow::Result<FilterableMetadata> {
+        let records = sqlx::query!(
+            r#"
+            SELECT
+            key as "key!",
+            value #>> '{}' as "value!",
+            COUNT(*) as "value_occurrence_count!"
+            FROM tasks,
+            jsonb_each(metadata -> 'records') as fields(key, value)
+            WHERE tasks.organization_id = $1
+            GROUP BY key, value #>> '{}'
+            ORDER BY key, value #>> '{}'
+            "#,
+            organization_id,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let items = records
+            .into_iter()
+            .chunk_by(|r| r.key.clone())
+            .into_iter()
+            .map(|(key, chunk)| {
+                let distinct_values: Vec<FilterableMetadataValue> = chunk
+                    .map(|r| FilterableMetadataValue {
+                        value: r.value,
+                        value_count: r.value_occurrence_count as u64,
+                    })
+                    .collect();
+                FilterableMetadataItem {
+                    key,
+                    key_cardinality: distinct_values.len() as u64,
+                    distinct_values,
+                }
+            })
+            .collect();
+
+        Ok(FilterableMetadata { items })
+    }
+
     async fn upsert_task(
         &self,
         transaction: &mut Self::Transaction,
@@ -141,6 +242,7 @@ impl TaskRepository for TaskRepositoryAdapter {
             INSERT INTO tasks (
                 organization_id, 
                 id, 
+                uuid,
                 name, 
                 description, 
                 status,
@@ -150,9 +252,17 @@ impl TaskRepository for TaskRepositoryAdapter {
                 start_window_seconds, 
                 lateness_window_seconds,
                 heartbeat_timeout_seconds,
-                last_status_change_at
+                last_status_change_at,
+                max_duration_seconds,
+                time_zone,
+                max_concurrent_runs,
+                upstream_task_ids,
+                metadata,
+                email_notification_enabled,
+                push_notification_enabled,
+                sms_notification_enabled
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            VALUES ($1, $2, $13, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $14, $15, $16, $17, $18, $19, $20, $21)
             ON CONFLICT (organization_id, id) DO UPDATE SET
                 name = $3,
                 description = $4,
@@ -163,7 +273,15 @@ impl TaskRepository for TaskRepositoryAdapter {
                 start_window_seconds = $9,
                 lateness_window_seconds = $10,
                 heartbeat_timeout_seconds = $11,
-                last_status_change_at = $12
+                last_status_change_at = $12,
+                max_duration_seconds = $14,
+                time_zone = $15,
+                max_concurrent_runs = $16,
+                upstream_task_ids = $17,
+                metadata = $18,
+                email_notification_enabled = $19,
+                push_notification_enabled = $20,
+                sms_notification_enabled = $21
             "#,
             task.organization_id, // $1
             task.id.as_str(), // $2
@@ -177,6 +295,15 @@ impl TaskRepository for TaskRepositoryAdapter {
             task.lateness_window_seconds, // $10
             task.heartbeat_timeout_seconds, // $11
             task.last_status_change_at, // $12
+            task.uuid, // $13
+            task.max_duration_seconds, // $14
+            task.time_zone, // $15
+            task.max_concurrent_runs, // $16
+            &task.upstream_task_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>(), // $17
+            serde_json::to_value(task.metadata)?, // $18
+            task.email_notification_enabled, // $19
+            task.push_notification_enabled, // $20
+            task.sms_notification_enabled, // $21
         )
         .execute(transaction.as_mut())
         .await?;
@@ -199,11 +326,13 @@ impl TaskRepository for TaskRepositoryAdapter {
             AND status != $2 -- status is not due 
             AND status != $3 -- status is not running
             AND status != $4 -- status is not absent
-            LIMIT $5",
+            AND status != $5 -- status is not paused
+            LIMIT $6",
             now,
             TaskStatus::Due as i16,
             TaskStatus::Running as i16,
             TaskStatus::Absent as i16,
+            TaskStatus::Paused as i16,
             limit as i64
         )
         .fetch_all(transaction.as_mut())
@@ -214,6 +343,7 @@ impl TaskRepository for TaskRepositoryAdapter {
             .map(|row| BoundaryTask {
                 organization_id: row.organization_id,
                 id: TaskId::new(row.id).expect("Invalid task ID in database"),
+                uuid: row.uuid,
                 name: row.name,
                 description: row.description,
                 status: TaskStatus::from(row.status),
@@ -224,6 +354,14 @@ impl TaskRepository for TaskRepositoryAdapter {
                 start_window_seconds: row.start_window_seconds,
                 lateness_window_seconds: row.lateness_window_seconds,
                 heartbeat_timeout_seconds: row.heartbeat_timeout_seconds,
+                max_duration_seconds: row.max_duration_seconds,
+                time_zone: row.time_zone,
+                max_concurrent_runs: row.max_concurrent_runs,
+                upstream_task_ids: row.upstream_task_ids.into_iter().map(TaskId::from).collect(),
+                metadata: row.metadata.into(),
+                email_notification_enabled: row.email_notification_enabled,
+                push_notification_enabled: row.push_notification_enabled,
+                sms_notification_enabled: row.sms_notification_enabled,
                 created_at: row.created_at,
             })
             .collect();
@@ -241,8 +379,7 @@ impl TaskRepository for TaskRepositoryAdapter {
             let rows = sqlx::query!(
                 "
                 SELECT * FROM tasks
-                WHERE cron_schedule IS NOT NULL
-                AND $1::timestamptz >= next_due_at + (start_window_seconds || ' seconds')::interval
+                WHERE $1::timestamptz >= next_due_at + (start_window_seconds || ' seconds')::interval
                 AND status = $2 -- status is due
                 LIMIT $3",
                 now,
@@ -257,6 +394,7 @@ impl TaskRepository for TaskRepositoryAdapter {
                 .map(|row| BoundaryTask {
                     organization_id: row.organization_id,
                     id: TaskId::new(row.id).expect("Invalid task ID in database"),
+                    uuid: row.uuid,
                     name: row.name,
                     description: row.description,
                     status: TaskStatus::from(row.status),
@@ -267,6 +405,14 @@ impl TaskRepository for TaskRepositoryAdapter {
                     start_window_seconds: row.start_window_seconds,
                     lateness_window_seconds: row.lateness_window_seconds,
                     heartbeat_timeout_seconds: row.heartbeat_timeout_seconds,
+                    max_duration_seconds: row.max_duration_seconds,
+                    time_zone: row.time_zone,
+                    max_concurrent_runs: row.max_concurrent_runs,
+                    upstream_task_ids: row.upstream_task_ids.into_iter().map(TaskId::from).collect(),
+                    metadata: row.metadata.into(),
+                    email_notification_enabled: row.email_notification_enabled,
+                    push_notification_enabled: row.push_notification_enabled,
+                    sms_notification_enabled: row.sms_notification_enabled,
                     created_at: row.created_at,
                 })
                 .collect();
@@ -284,8 +430,7 @@ impl TaskRepository for TaskRepositoryAdapter {
         let rows = sqlx::query!(
             "
             SELECT * FROM tasks
-            WHERE cron_schedule IS NOT NULL
-            AND $1::timestamptz >= next_due_at + (start_window_seconds || ' seconds')::interval + (lateness_window_seconds || ' seconds')::interval
+            WHERE $1::timestamptz >= next_due_at + (start_window_seconds || ' seconds')::interval + (lateness_window_seconds || ' seconds')::interval
             AND status = $2 -- status is late
             LIMIT $3",
             now,
@@ -300,6 +445,53 @@ impl TaskRepository for TaskRepositoryAdapter {
             .map(|row| BoundaryTask {
                 organization_id: row.organization_id,
                 id: TaskId::new(row.id).expect("Invalid task ID in database"),
+                uuid: row.uuid,
+                name: row.name,
+                description: row.description,
+                status: TaskStatus::from(row.status),
+                previous_status: row.previous_status.map(TaskStatus::from),
+                last_status_change_at: row.last_status_change_at,
+                cron_schedule: row.cron_schedule,
+                next_due_at: row.next_due_at,
+                start_window_seconds: row.start_window_seconds,
+                lateness_window_seconds: row.lateness_window_seconds,
+                heartbeat_timeout_seconds: row.heartbeat_timeout_seconds,
+                max_duration_seconds: row.max_duration_seconds,
+                time_zone: row.time_zone,
+                max_concurrent_runs: row.max_concurrent_runs,
+                upstream_task_ids: row.upstream_task_ids.into_iter().map(TaskId::from).collect(),
+                metadata: row.metadata.into(),
+                email_notification_enabled: row.email_notification_enabled,
+                push_notification_enabled: row.push_notification_enabled,
+                sms_notification_enabled: row.sms_notification_enabled,
+                created_at: row.created_at,
+            })
+            .collect();
+
+        Ok(tasks)
+    }
+
+    async fn list_downstream_tasks(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<Vec<BoundaryTask>> {
+        let rows = sqlx::query!(
+            "SELECT * FROM tasks WHERE organization_id = $1 AND $2 = ANY(upstream_task_ids) ORDER BY id",
+            organization_id,
+            task_id.as_str(),
+        )
+        .fetch_all(transaction.as_mut())
+        .await
+        .with_context(|| "Failed to list downstream tasks from database")?;
+
+        let tasks = rows
+            .into_iter()
+            .map(|row| BoundaryTask {
+                organization_id: row.organization_id,
+                id: TaskId::new(row.id).expect("Invalid task ID in database"),
+                uuid: row.uuid,
                 name: row.name,
                 description: row.description,
                 status: TaskStatus::from(row.status),
@@ -310,10 +502,127 @@ impl TaskRepository for TaskRepositoryAdapter {
                 start_window_seconds: row.start_window_seconds,
                 lateness_window_seconds: row.lateness_window_seconds,
                 heartbeat_timeout_seconds: row.heartbeat_timeout_seconds,
+                max_duration_seconds: row.max_duration_seconds,
+                time_zone: row.time_zone,
+                max_concurrent_runs: row.max_concurrent_runs,
+                upstream_task_ids: row.upstream_task_ids.into_iter().map(TaskId::from).collect(),
+                metadata: row.metadata.into(),
+                email_notification_enabled: row.email_notification_enabled,
+                push_notification_enabled: row.push_notification_enabled,
+                sms_notification_enabled: row.sms_notification_enabled,
                 created_at: row.created_at,
             })
             .collect();
 
         Ok(tasks)
     }
+
+    async fn get_task_by_ping_token(
+        &self,
+        transaction: &mut Self::Transaction,
+        ping_token: &str,
+    ) -> anyhow::Result<Option<BoundaryTask>> {
+        let record = sqlx::query!(
+            "SELECT * FROM tasks WHERE ping_token = $1",
+            ping_token,
+        )
+        .fetch_optional(transaction.as_mut())
+        .await
+        .with_context(|| "Failed to get task by ping token from database")?;
+
+        let task = record.map(|row| BoundaryTask {
+            organization_id: row.organization_id,
+            id: TaskId::new(row.id).expect("Invalid task ID in database"),
+            uuid: row.uuid,
+            name: row.name,
+            description: row.description,
+            status: TaskStatus::from(row.status),
+            previous_status: row.previous_status.map(TaskStatus::from),
+            last_status_change_at: row.last_status_change_at,
+            cron_schedule: row.cron_schedule,
+            next_due_at: row.next_due_at,
+            start_window_seconds: row.start_window_seconds,
+            lateness_window_seconds: row.lateness_window_seconds,
+            heartbeat_timeout_seconds: row.heartbeat_timeout_seconds,
+            max_duration_seconds: row.max_duration_seconds,
+            time_zone: row.time_zone,
+            max_concurrent_runs: row.max_concurrent_runs,
+            upstream_task_ids: row.upstream_task_ids.into_iter().map(TaskId::from).collect(),
+            metadata: row.metadata.into(),
+            email_notification_enabled: row.email_notification_enabled,
+            push_notification_enabled: row.push_notification_enabled,
+            sms_notification_enabled: row.sms_notification_enabled,
+            created_at: row.created_at,
+        });
+
+        Ok(task)
+    }
+
+    async fn get_task_ping_token(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<Option<String>> {
+        let token = sqlx::query_scalar!(
+            "SELECT ping_token FROM tasks WHERE organization_id = $1 AND id = $2",
+            organization_id,
+            task_id.as_str(),
+        )
+        .fetch_optional(transaction.as_mut())
+        .await
+        .with_context(|| "Failed to get task ping token from database")?;
+
+        Ok(token)
+    }
+
+    async fn set_task_ping_token(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+        ping_token: &str,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query!(
+            "UPDATE tasks SET ping_token = $3 WHERE organization_id = $1 AND id = $2",
+            organization_id,
+            task_id.as_str(),
+            ping_token,
+        )
+        .execute(transaction.as_mut())
+        .await
+        .with_context(|| "Failed to update task ping token in database")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_task(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<bool> {
+        // the task is no longer an upstream task of the other tasks
+        sqlx::query!(
+            "UPDATE tasks SET upstream_task_ids = array_remove(upstream_task_ids, $2)
+            WHERE organization_id = $1 AND $2 = ANY(upstream_task_ids)",
+            organization_id,
+            task_id.as_str(),
+        )
+        .execute(transaction.as_mut())
+        .await
+        .with_context(|| "Failed to remove task from the upstream tasks of other tasks")?;
+
+        // task runs and task run events are deleted in cascade
+        let result = sqlx::query!(
+            "DELETE FROM tasks WHERE organization_id = $1 AND id = $2",
+            organization_id,
+            task_id.as_str(),
+        )
+        .execute(transaction.as_mut())
+        .await
+        .with_context(|| "Failed to delete task from database")?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
@@ -0,0 +1,145 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        task::TaskId,
+        task_run_event::{TaskRunEvent, TaskRunEventType},
+    },
+    ports::task_run_event_repository::{ListTaskRunEventsOutput, TaskRunEventRepository},
+};
+
+#[derive(Clone)]
+pub struct TaskRunEventRepositoryAdapter {
+    pub pool: PgPool,
+}
+
+crate::postgres_transactional_repo!(TaskRunEventRepositoryAdapter);
+
+#[async_trait]
+impl TaskRunEventRepository for TaskRunEventRepositoryAdapter {
+    async fn create_task_run_event(
+        &self,
+        transaction: &mut Self::Transaction,
+        event: TaskRunEvent,
+    ) -> anyhow::Result<()> {
+        sqlx::query!(
+            "INSERT INTO task_run_events (organization_id, task_id, task_run_id, task_run_started_at, created_at, event_type, event_payload)
+            VALUES ($1, $2, $7, $3, $4, $5, $6)",
+            event.organization_id,
+            event.task_id.as_str(),
+            event.task_run_started_at,
+            event.created_at,
+            event.event_type as i16,
+            serde_json::to_value(event.event_payload)?,
+            event.task_run_id,
+        )
+        .execute(transaction.as_mut())
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to persist task run event with type {:?} and timestamp: {}",
+                event.event_type, event.created_at
+            )
+        })?;
+
+        Ok(())
+    }
+
+    async fn list_task_run_events(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+        task_run_id: &str,
+        task_run_started_at: DateTime<Utc>,
+        limit: u32,
+        offset: u32,
+    ) -> anyhow::Result<ListTaskRunEventsOutput> {
+        let rows = sqlx::query!(
+            r#"SELECT *, COUNT(*) OVER() as "total_count!" FROM task_run_events
+            WHERE organization_id = $1 AND task_id = $2 AND task_run_started_at = $3 AND task_run_id = $6
+            -- this should help postgres select the correct partitions for the events
+            AND created_at >= $3
+            ORDER BY created_at ASC
+            LIMIT $4 OFFSET $5"#,
+            organization_id,
+            task_id.as_str(),
+            task_run_started_at,
+            limit as i64,
+            offset as i64,
+            task_run_id,
+        )
+        .fetch_all(transaction.as_mut())
+        .await
+        .context("Failed to fetch task run events")?;
+
+        let total_events = rows.first().map(|row| row.total_count).unwrap_or_default();
+        let events = rows
+            .into_iter()
+            .map(|row| TaskRunEvent {
+                organization_id: row.organization_id,
+                task_id: TaskId::from(row.task_id),
+                task_run_id: row.task_run_id,
+                task_run_started_at: row.task_run_started_at,
+                created_at: row.created_at,
+                event_type: row.event_type.into(),
+                event_payload: row
+                    .event_payload
+                    .and_then(|payload| serde_json::from_value(payload).ok()),
+            })
+            .collect();
+
+        Ok(ListTaskRunEventsOutput {
+            events,
+            total_events: total_events as u32,
+        })
+    }
+
+    async fn list_latest_task_run_events(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+        task_run_id: &str,
+        task_run_started_at: DateTime<Utc>,
+        event_type: TaskRunEventType,
+        limit: u32,
+    ) -> anyhow::Result<Vec<TaskRunEvent>> {
+        let events = sqlx::query!(
+            "SELECT * FROM task_run_events
+            WHERE organization_id = $1 AND task_id = $2 AND task_run_started_at = $3 AND task_run_id = $6
+            AND created_at >= $3
+            AND event_type = $4
+            ORDER BY created_at DESC
+            LIMIT $5",
+            organization_id,
+            task_id.as_str(),
+            task_run_started_at,
+            event_type as i16,
+            limit as i64,
+            task_run_id,
+        )
+        .fetch_all(transaction.as_mut())
+        .await
+        .context("Failed to fetch latest task run events")?
+        .into_iter()
+        .map(|row| TaskRunEvent {
+            organization_id: row.organization_id,
+            task_id: TaskId::from(row.task_id),
+            task_run_id: row.task_run_id,
+            task_run_started_at: row.task_run_started_at,
+            created_at: row.created_at,
+            event_type: row.event_type.into(),
+            event_payload: row
+                .event_payload
+                .and_then(|payload| serde_json::from_value(payload).ok()),
+        })
+        .collect();
+
+        Ok(events)
+    }
+}
@@ -87,6 +87,7 @@ impl TaskRunRepository for TaskRunRepositoryAdapter {
             .map(|r| BoundaryTaskRun {
                 organization_id: r.organization_id,
                 task_id: r.task_id.into(),
+                run_id: r.run_id,
                 status: r.status.into(),
                 started_at: r.started_at,
                 updated_at: r.updated_at,
@@ -111,6 +112,7 @@ impl TaskRunRepository for TaskRunRepositoryAdapter {
         organization_id: Uuid,
         task_id: &TaskId,
         started_at: DateTime<Utc>,
+        run_id: Option<&str>,
     ) -> anyhow::Result<Option<BoundaryTaskRun>> {
         sqlx::query_as!(
             BoundaryTaskRun,
@@ -120,10 +122,14 @@ impl TaskRunRepository for TaskRunRepositoryAdapter {
             WHERE organization_id = $1
             AND task_id = $2
             AND started_at = $3
+            AND ($4::text IS NULL OR run_id = $4)
+            ORDER BY run_id
+            LIMIT 1
             "#,
             organization_id,
             task_id.as_str(),
             started_at,
+            run_id,
         )
         .fetch_optional(transaction.as_mut())
         .await
@@ -140,6 +146,7 @@ impl TaskRunRepository for TaskRunRepositoryAdapter {
             INSERT INTO task_runs (
                 organization_id,
                 task_id,
+                run_id,
                 status,
                 started_at,
                 completed_at,
@@ -148,8 +155,8 @@ impl TaskRunRepository for TaskRunRepositoryAdapter {
                 last_heartbeat_at,
                 heartbeat_timeout_seconds
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-            ON CONFLICT (organization_id, task_id, started_at) DO UPDATE SET
+            VALUES ($1, $2, $10, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (organization_id, task_id, run_id, started_at) DO UPDATE SET
                 status = $3,
                 completed_at = $5,
                 exit_code = $6,
@@ -166,11 +173,43 @@ impl TaskRunRepository for TaskRunRepositoryAdapter {
             task_run.error_message, // $7
             task_run.last_heartbeat_at, // $8
             task_run.heartbeat_timeout_seconds, // $9
+            task_run.run_id, // $10
         )
         .execute(transaction.as_mut())
         .await
         .context("Failed to create task run")?;
 
+        if task_run.status == TaskRunStatus::Running {
+            let result = sqlx::query!(
+                "INSERT INTO running_task_runs (organization_id, task_id, run_id, started_at)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (organization_id, task_id, run_id) DO UPDATE SET started_at = EXCLUDED.started_at
+                WHERE running_task_runs.started_at = EXCLUDED.started_at",
+                task_run.organization_id,
+                task_run.task_id.as_str(),
+                task_run.run_id,
+                task_run.started_at,
+            )
+            .execute(transaction.as_mut())
+            .await
+            .context("Failed to track running task run")?;
+
+            if result.rows_affected() == 0 {
+                anyhow::bail!("Another run with the id {} is already running", task_run.run_id);
+            }
+        } else {
+            sqlx::query!(
+                "DELETE FROM running_task_runs WHERE organization_id = $1 AND task_id = $2 AND run_id = $3 AND started_at = $4",
+                task_run.organization_id,
+                task_run.task_id.as_str(),
+                task_run.run_id,
+                task_run.started_at,
+            )
+            .execute(transaction.as_mut())
+            .await
+            .context("Failed to untrack running task run")?;
+        }
+
         Ok(())
     }
 
@@ -184,6 +223,7 @@ impl TaskRunRepository for TaskRunRepositoryAdapter {
         let rows = sqlx::query!(
             r#"
             SELECT 
+                tasks.uuid as "task_uuid!",
                 tasks.status as "task_status!",
                 tasks.name as "task_name!",
                 tasks.description as "task_description",
@@ -194,6 +234,14 @@ impl TaskRunRepository for TaskRunRepositoryAdapter {
                 tasks.start_window_seconds as "task_start_window_seconds",
                 tasks.lateness_window_seconds as "task_lateness_window_seconds",
                 tasks.heartbeat_timeout_seconds as "task_heartbeat_timeout_seconds",
+                tasks.max_duration_seconds as "task_max_duration_seconds",
+                tasks.time_zone as "task_time_zone!",
+                tasks.max_concurrent_runs as "task_max_concurrent_runs!",
+                tasks.upstream_task_ids as "task_upstream_task_ids!",
+                tasks.metadata as "task_metadata",
+                tasks.email_notification_enabled as "task_email_notification_enabled!",
+                tasks.push_notification_enabled as "task_push_notification_enabled!",
+                tasks.sms_notification_enabled as "task_sms_notification_enabled!",
                 tasks.created_at as "task_created_at",
                 task_runs.*
             FROM task_runs
@@ -215,6 +263,101 @@ impl TaskRunRepository for TaskRunRepositoryAdapter {
             .map(|r| {
                 let task = BoundaryTask {
                     id: r.task_id.clone().into(),
+                    uuid: r.task_uuid,
+                    status: r.task_status.into(),
+                    organization_id: r.organization_id,
+                    name: r.task_name,
+                    description: r.task_description,
+                    previous_status: r.task_previous_status.map(|s| s.into()),
+                    last_status_change_at: r.task_last_status_change_at,
+                    cron_schedule: r.task_cron_schedule,
+                    next_due_at: r.task_next_due_at,
+                    start_window_seconds: r.task_start_window_seconds,
+                    lateness_window_seconds: r.task_lateness_window_seconds,
+                    heartbeat_timeout_seconds: r.task_heartbeat_timeout_seconds,
+                    max_duration_seconds: r.task_max_duration_seconds,
+                    time_zone: r.task_time_zone,
+                    max_concurrent_runs: r.task_max_concurrent_runs,
+                    upstream_task_ids: r.task_upstream_task_ids.into_iter().map(TaskId::from).collect(),
+                    metadata: r.task_metadata.into(),
+                    email_notification_enabled: r.task_email_notification_enabled,
+                    push_notification_enabled: r.task_push_notification_enabled,
+                    sms_notification_enabled: r.task_sms_notification_enabled,
+                    created_at: r.task_created_at,
+                };
+
+                let task_run = BoundaryTaskRun {
+                    organization_id: r.organization_id,
+                    task_id: r.task_id.into(),
+                    run_id: r.run_id,
+                    status: r.status.into(),
+                    started_at: r.started_at,
+                    updated_at: r.updated_at,
+                    completed_at: r.completed_at,
+                    exit_code: r.exit_code,
+                    error_message: r.error_message,
+                    last_heartbeat_at: r.last_heartbeat_at,
+                    heartbeat_timeout_seconds: r.heartbeat_timeout_seconds,
+                };
+                (task, task_run)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(rows)
+    }
+
+    async fn list_timed_out_task_runs(
+        &self,
+        transaction: &mut Self::Transaction,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> anyhow::Result<Vec<(BoundaryTask, BoundaryTaskRun)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT 
+                tasks.uuid as "task_uuid!",
+                tasks.status as "task_status!",
+                tasks.name as "task_name!",
+                tasks.description as "task_description",
+                tasks.previous_status as "task_previous_status",
+                tasks.last_status_change_at as "task_last_status_change_at",
+                tasks.cron_schedule as "task_cron_schedule",
+                tasks.next_due_at as "task_next_due_at",
+                tasks.start_window_seconds as "task_start_window_seconds",
+                tasks.lateness_window_seconds as "task_lateness_window_seconds",
+                tasks.heartbeat_timeout_seconds as "task_heartbeat_timeout_seconds",
+                tasks.max_duration_seconds as "task_max_duration_seconds",
+                tasks.time_zone as "task_time_zone!",
+                tasks.max_concurrent_runs as "task_max_concurrent_runs!",
+                tasks.upstream_task_ids as "task_upstream_task_ids!",
+                tasks.metadata as "task_metadata",
+                tasks.email_notification_enabled as "task_email_notification_enabled!",
+                tasks.push_notification_enabled as "task_push_notification_enabled!",
+                tasks.sms_notification_enabled as "task_sms_notification_enabled!",
+                tasks.created_at as "task_created_at",
+                task_runs.*
+            FROM task_runs
+            INNER JOIN tasks ON task_runs.organization_id = tasks.organization_id AND task_runs.task_id = tasks.id
+            WHERE tasks.max_duration_seconds IS NOT NULL
+                AND task_runs.started_at <= ($1::timestamptz - INTERVAL '1 second' * tasks.max_duration_seconds)
+                AND task_runs.status = $2
+            ORDER BY task_runs.started_at ASC
+            LIMIT $3
+            "#,
+            now,
+            TaskRunStatus::Running as i16,
+            limit as i64,
+        )
+        .fetch_all(transaction.as_mut())
+        .await
+        .context("Failed to list timed out task runs")?;
+
+        let rows = rows
+            .into_iter()
+            .map(|r| {
+                let task = BoundaryTask {
+                    id: r.task_id.clone().into(),
+                    uuid: r.task_uuid,
                     status: r.task_status.into(),
                     organization_id: r.organization_id,
                     name: r.task_name,
@@ -226,12 +369,21 @@ impl TaskRunRepository for TaskRunRepositoryAdapter {
                     start_window_seconds: r.task_start_window_seconds,
                     lateness_window_seconds: r.task_lateness_window_seconds,
                     heartbeat_timeout_seconds: r.task_heartbeat_timeout_seconds,
+                    max_duration_seconds: r.task_max_duration_seconds,
+                    time_zone: r.task_time_zone,
+                    max_concurrent_runs: r.task_max_concurrent_runs,
+                    upstream_task_ids: r.task_upstream_task_ids.into_iter().map(TaskId::from).collect(),
+                    metadata: r.task_metadata.into(),
+                    email_notification_enabled: r.task_email_notification_enabled,
+                    push_notification_enabled: r.task_push_notification_enabled,
+                    sms_notification_enabled: r.task_sms_notification_enabled,
                     created_at: r.task_created_at,
                 };
 
                 let task_run = BoundaryTaskRun {
                     organization_id: r.organization_id,
                     task_id: r.task_id.into(),
+                    run_id: r.run_id,
                     status: r.status.into(),
                     started_at: r.started_at,
                     updated_at: r.updated_at,
@@ -0,0 +1,68 @@
+use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Duration};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+use crate::domain::{
+    entities::webhook::is_public_ip_address,
+    ports::webhook_client::{WebhookClient, WebhookResponse},
+};
+
+/// Webhook endpoints that take longer than this to respond are considered unreachable
+const WEBHOOK_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone)]
+pub struct WebhookClientAdapter {
+    http_client: reqwest::Client,
+}
+
+impl WebhookClientAdapter {
+    pub fn new() -> anyhow::Result<Self> {
+        let http_client = reqwest::Client::builder()
+            .timeout(WEBHOOK_REQUEST_TIMEOUT)
+            .dns_resolver(Arc::new(PublicAddressResolver))
+            // a redirection could target an address that was not checked
+            .redirect(reqwest::redirect::Policy::none())
+            .build()?;
+        Ok(Self { http_client })
+    }
+}
+
+/// Resolves host names to their public addresses only,
+/// so that a webhook endpoint cannot reach the server's network through its DNS records
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addresses = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|address| is_public_ip_address(address.ip()))
+                .collect::<Vec<SocketAddr>>();
+            if addresses.is_empty() {
+                return Err(format!("{} does not resolve to any public address", name.as_str()).into());
+            }
+            Ok(Box::new(addresses.into_iter()) as Addrs)
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookClient for WebhookClientAdapter {
+    async fn post(&self, url: &str, headers: HashMap<String, String>, body: Vec<u8>) -> WebhookResponse {
+        let mut request = self.http_client.post(url).body(body);
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+
+        match request.send().await {
+            Ok(response) => WebhookResponse {
+                http_code: Some(response.status().as_u16()),
+                error: None,
+            },
+            Err(e) => WebhookResponse {
+                http_code: None,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::webhook::{WebhookDelivery, WebhookDeliveryStatus},
+        ports::webhook_delivery_repository::{
+            ListWebhookDeliveriesOutput, NewWebhookDelivery, WebhookDeliveryRepository,
+        },
+    },
+    postgres_transactional_repo,
+};
+
+#[derive(Clone)]
+pub struct WebhookDeliveryRepositoryAdapter {
+    pub pool: PgPool,
+}
+
+postgres_transactional_repo!(WebhookDeliveryRepositoryAdapter);
+
+#[async_trait::async_trait]
+impl WebhookDeliveryRepository for WebhookDeliveryRepositoryAdapter {
+    async fn create_webhook_delivery(
+        &self,
+        tx: &mut Self::Transaction,
+        delivery: NewWebhookDelivery,
+    ) -> anyhow::Result<Uuid> {
+        let record = sqlx::query!(
+            r#"
+            INSERT INTO webhook_deliveries (organization_id, webhook_endpoint_id, incident_id, event_type, payload, status, next_attempt_at)
+            VALUES ($1, $2, $3, $4, $5, $6, now())
+            RETURNING id
+            "#,
+            delivery.organization_id,
+            delivery.webhook_endpoint_id,
+            delivery.incident_id,
+            delivery.event_type as i16,
+            serde_json::to_value(&delivery.payload).expect("Failed to serialize webhook payload"),
+            WebhookDeliveryStatus::Pending as i16,
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .context("Failed to create webhook delivery")?;
+
+        Ok(record.id)
+    }
+
+    async fn claim_next_deliveries_to_send(
+        &self,
+        tx: &mut Self::Transaction,
+        limit: u32,
+        claimed_until: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<WebhookDelivery>> {
+        let records = sqlx::query!(
+            r#"
+            UPDATE webhook_deliveries SET next_attempt_at = $3
+            WHERE (organization_id, id) IN (
+                SELECT organization_id, id FROM webhook_deliveries
+                WHERE status = $1 AND next_attempt_at <= now()
+                ORDER BY next_attempt_at
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *
+            "#,
+            WebhookDeliveryStatus::Pending as i16,
+            limit as i64,
+            claimed_until,
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to claim next webhook deliveries to send")?;
+
+        records
+            .into_iter()
+            .map(|record| {
+                Ok(WebhookDelivery {
+                    organization_id: record.organization_id,
+                    id: record.id,
+                    webhook_endpoint_id: record.webhook_endpoint_id,
+                    incident_id: record.incident_id,
+                    event_type: record.event_type.into(),
+                    payload: serde_json::from_value(record.payload)
+                        .context("Failed to deserialize webhook payload")?,
+                    status: record.status.into(),
+                    attempts: record.attempts,
+                    next_attempt_at: record.next_attempt_at,
+                    last_attempt_at: record.last_attempt_at,
+                    last_response_http_code: record.last_response_http_code,
+                    last_error: record.last_error,
+                    created_at: record.created_at,
+                })
+            })
+            .collect()
+    }
+
+    async fn update_webhook_delivery(
+        &self,
+        tx: &mut Self::Transaction,
+        delivery: &WebhookDelivery,
+    ) -> anyhow::Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = $3, attempts = $4, next_attempt_at = $5, last_attempt_at = $6, last_response_http_code = $7, last_error = $8
+            WHERE organization_id = $1 AND id = $2
+            "#,
+            delivery.organization_id,
+            delivery.id,
+            delivery.status as i16,
+            delivery.attempts,
+            delivery.next_attempt_at,
+            delivery.last_attempt_at,
+            delivery.last_response_http_code,
+            delivery.last_error,
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to update webhook delivery")?;
+
+        Ok(())
+    }
+
+    async fn list_webhook_deliveries(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        webhook_endpoint_id: Uuid,
+        limit: u32,
+        offset: u32,
+    ) -> anyhow::Result<ListWebhookDeliveriesOutput> {
+        let records = sqlx::query!(
+            r#"
+            SELECT *, COUNT(*) OVER () as "total_count!" FROM webhook_deliveries
+            WHERE organization_id = $1 AND webhook_endpoint_id = $2
+            ORDER BY created_at DESC
+            LIMIT $3 OFFSET $4
+            "#,
+            organization_id,
+            webhook_endpoint_id,
+            limit as i64,
+            offset as i64,
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to list webhook deliveries")?;
+
+        let total_deliveries = records.first().map(|record| record.total_count).unwrap_or(0);
+        let deliveries = records
+            .into_iter()
+            .map(|record| {
+                Ok(WebhookDelivery {
+                    organization_id: record.organization_id,
+                    id: record.id,
+                    webhook_endpoint_id: record.webhook_endpoint_id,
+                    incident_id: record.incident_id,
+                    event_type: record.event_type.into(),
+                    payload: serde_json::from_value(record.payload)
+                        .context("Failed to deserialize webhook payload")?,
+                    status: record.status.into(),
+                    attempts: record.attempts,
+                    next_attempt_at: record.next_attempt_at,
+                    last_attempt_at: record.last_attempt_at,
+                    last_response_http_code: record.last_response_http_code,
+                    last_error: record.last_error,
+                    created_at: record.created_at,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(ListWebhookDeliveriesOutput {
+            deliveries,
+            total_deliveries: total_deliveries as u32,
+        })
+    }
+}
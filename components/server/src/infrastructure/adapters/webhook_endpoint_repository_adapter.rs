@@ -0,0 +1,162 @@
+use anyhow::Context;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{incident_event::IncidentEventType, webhook::WebhookEndpoint},
+        ports::webhook_endpoint_repository::{NewWebhookEndpoint, WebhookEndpointRepository},
+    },
+    postgres_transactional_repo,
+};
+
+#[derive(Clone)]
+pub struct WebhookEndpointRepositoryAdapter {
+    pub pool: PgPool,
+}
+
+postgres_transactional_repo!(WebhookEndpointRepositoryAdapter);
+
+#[async_trait::async_trait]
+impl WebhookEndpointRepository for WebhookEndpointRepositoryAdapter {
+    async fn list_webhook_endpoints(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<WebhookEndpoint>> {
+        let records = sqlx::query!(
+            r#"
+            SELECT organization_id, id, url, secret, event_types, metadata_filter, created_at, updated_at
+            FROM webhook_endpoints
+            WHERE organization_id = $1
+            ORDER BY created_at
+            "#,
+            organization_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to list webhook endpoints")?;
+
+        records
+            .into_iter()
+            .map(|record| {
+                Ok(WebhookEndpoint {
+                    organization_id: record.organization_id,
+                    id: record.id,
+                    url: record.url,
+                    secret: record.secret,
+                    event_types: record.event_types.into_iter().map(IncidentEventType::from).collect(),
+                    metadata_filter: serde_json::from_value(record.metadata_filter)
+                        .context("Failed to deserialize webhook endpoint metadata filter")?,
+                    created_at: record.created_at,
+                    updated_at: record.updated_at,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_webhook_endpoint(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<WebhookEndpoint>> {
+        let record = sqlx::query!(
+            r#"
+            SELECT organization_id, id, url, secret, event_types, metadata_filter, created_at, updated_at
+            FROM webhook_endpoints
+            WHERE organization_id = $1 AND id = $2
+            "#,
+            organization_id,
+            id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .context("Failed to get webhook endpoint")?;
+
+        record
+            .map(|record| {
+                Ok(WebhookEndpoint {
+                    organization_id: record.organization_id,
+                    id: record.id,
+                    url: record.url,
+                    secret: record.secret,
+                    event_types: record.event_types.into_iter().map(IncidentEventType::from).collect(),
+                    metadata_filter: serde_json::from_value(record.metadata_filter)
+                        .context("Failed to deserialize webhook endpoint metadata filter")?,
+                    created_at: record.created_at,
+                    updated_at: record.updated_at,
+                })
+            })
+            .transpose()
+    }
+
+    async fn create_webhook_endpoint(
+        &self,
+        tx: &mut Self::Transaction,
+        endpoint: NewWebhookEndpoint,
+    ) -> anyhow::Result<Uuid> {
+        let record = sqlx::query!(
+            r#"
+            INSERT INTO webhook_endpoints (organization_id, url, secret, event_types, metadata_filter)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            "#,
+            endpoint.organization_id,
+            endpoint.url,
+            endpoint.secret,
+            &endpoint.event_types.iter().map(|t| *t as i16).collect::<Vec<_>>(),
+            serde_json::to_value(&endpoint.metadata_filter)
+                .expect("Failed to serialize webhook endpoint metadata filter"),
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .context("Failed to create webhook endpoint")?;
+
+        Ok(record.id)
+    }
+
+    async fn update_webhook_endpoint(
+        &self,
+        tx: &mut Self::Transaction,
+        id: Uuid,
+        endpoint: NewWebhookEndpoint,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE webhook_endpoints
+            SET url = $3, event_types = $4, metadata_filter = $5, updated_at = now()
+            WHERE organization_id = $1 AND id = $2
+            "#,
+            endpoint.organization_id,
+            id,
+            endpoint.url,
+            &endpoint.event_types.iter().map(|t| *t as i16).collect::<Vec<_>>(),
+            serde_json::to_value(&endpoint.metadata_filter)
+                .expect("Failed to serialize webhook endpoint metadata filter"),
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to update webhook endpoint")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_webhook_endpoint(
+        &self,
+        tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query!(
+            "DELETE FROM webhook_endpoints WHERE organization_id = $1 AND id = $2",
+            organization_id,
+            id
+        )
+        .execute(&mut **tx)
+        .await
+        .context("Failed to delete webhook endpoint")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,133 @@
+use axum::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::alert_integration::AlertIntegration,
+    ports::{
+        alert_integration_repository::{AlertIntegrationRepository, NewAlertIntegration},
+        transactional_repository::{TransactionMock, TransactionalRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct AlertIntegrationRepositoryMock {
+    pub state: Arc<Mutex<Vec<AlertIntegration>>>,
+}
+
+impl AlertIntegrationRepositoryMock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionalRepository for AlertIntegrationRepositoryMock {
+    type Transaction = TransactionMock;
+
+    async fn begin_transaction(&self) -> anyhow::Result<Self::Transaction> {
+        Ok(TransactionMock)
+    }
+
+    async fn commit_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn rollback_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AlertIntegrationRepository for AlertIntegrationRepositoryMock {
+    async fn list_alert_integrations(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<AlertIntegration>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .filter(|i| i.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_alert_integration(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<AlertIntegration>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .find(|i| i.organization_id == organization_id && i.id == id)
+            .cloned())
+    }
+
+    async fn get_alert_integration_by_id(
+        &self,
+        _tx: &mut Self::Transaction,
+        id: Uuid,
+    ) -> anyhow::Result<Option<AlertIntegration>> {
+        let state = self.state.lock().await;
+        Ok(state.iter().find(|i| i.id == id).cloned())
+    }
+
+    async fn create_alert_integration(
+        &self,
+        _tx: &mut Self::Transaction,
+        integration: NewAlertIntegration,
+    ) -> anyhow::Result<Uuid> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        self.state.lock().await.push(AlertIntegration {
+            organization_id: integration.organization_id,
+            id,
+            name: integration.name,
+            secret: integration.secret,
+            default_priority: integration.default_priority,
+            created_at: now,
+            updated_at: now,
+        });
+        Ok(id)
+    }
+
+    async fn update_alert_integration(
+        &self,
+        _tx: &mut Self::Transaction,
+        id: Uuid,
+        integration: NewAlertIntegration,
+    ) -> anyhow::Result<bool> {
+        let mut state = self.state.lock().await;
+        match state
+            .iter_mut()
+            .find(|i| i.organization_id == integration.organization_id && i.id == id)
+        {
+            Some(existing) => {
+                existing.name = integration.name;
+                existing.default_priority = integration.default_priority;
+                existing.updated_at = Utc::now();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn delete_alert_integration(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let mut state = self.state.lock().await;
+        let len = state.len();
+        state.retain(|i| !(i.organization_id == organization_id && i.id == id));
+        Ok(state.len() < len)
+    }
+}
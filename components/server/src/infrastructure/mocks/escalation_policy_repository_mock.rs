@@ -0,0 +1,73 @@
+use axum::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::escalation_policy::EscalationPolicy,
+    ports::{
+        escalation_policy_repository::EscalationPolicyRepository,
+        transactional_repository::{TransactionMock, TransactionalRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct EscalationPolicyRepositoryMock {
+    pub state: Arc<Mutex<Vec<EscalationPolicy>>>,
+}
+
+impl EscalationPolicyRepositoryMock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionalRepository for EscalationPolicyRepositoryMock {
+    type Transaction = TransactionMock;
+
+    async fn begin_transaction(&self) -> anyhow::Result<Self::Transaction> {
+        Ok(TransactionMock)
+    }
+
+    async fn commit_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn rollback_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EscalationPolicyRepository for EscalationPolicyRepositoryMock {
+    async fn get_escalation_policy(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Option<EscalationPolicy>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .find(|p| p.organization_id == organization_id)
+            .cloned())
+    }
+
+    async fn upsert_escalation_policy(
+        &self,
+        _tx: &mut Self::Transaction,
+        policy: EscalationPolicy,
+    ) -> anyhow::Result<()> {
+        let mut state = self.state.lock().await;
+        match state
+            .iter_mut()
+            .find(|p| p.organization_id == policy.organization_id)
+        {
+            Some(existing) => *existing = policy,
+            None => state.push(policy),
+        }
+        Ok(())
+    }
+}
@@ -17,6 +17,10 @@ impl FileStorage for FileStorageMock {
         anyhow::bail!("Not implemented")
     }
 
+    async fn delete_file(&self, _key: FileStorageKey) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     /// Returns a presigned URL for the file
     async fn get_file_url(&self, _key: FileStorageKey) -> anyhow::Result<Url> {
         anyhow::bail!("Not implemented")
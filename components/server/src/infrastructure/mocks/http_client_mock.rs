@@ -1,22 +1,46 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use tokio::sync::Mutex;
 
-use crate::domain::ports::http_client::{HttpClient, PingResponse};
+use crate::domain::ports::http_client::{HttpClient, PingRequest, PingResponse};
 
 #[derive(Clone)]
 pub struct HttpClientMock {
+    pub locations: Vec<String>,
     pub next_response: Arc<Mutex<Option<PingResponse>>>,
+    /// Responses by location, taking precedence over `next_response`
+    pub next_location_responses: Arc<Mutex<HashMap<String, PingResponse>>>,
+    /// Requests received by the client, in order
+    pub requests: Arc<Mutex<Vec<PingRequest>>>,
 }
 
 impl HttpClientMock {
     pub fn new() -> Self {
         Self {
+            locations: vec!["default".to_string()],
             next_response: Arc::new(Mutex::new(None)),
+            next_location_responses: Arc::new(Mutex::new(HashMap::new())),
+            requests: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
+    #[allow(unused)]
+    pub fn with_locations(locations: &[&str]) -> Self {
+        Self {
+            locations: locations.iter().map(|l| l.to_string()).collect(),
+            ..Self::new()
+        }
+    }
+
+    #[allow(unused)]
+    pub async fn set_next_location_response(&self, location: &str, response: PingResponse) {
+        self.next_location_responses
+            .lock()
+            .await
+            .insert(location.to_string(), response);
+    }
+
     #[allow(unused)]
     pub async fn set_next_response(&self, response: PingResponse) {
         *self.next_response.lock().await = Some(response);
@@ -25,12 +49,15 @@ impl HttpClientMock {
 
 #[async_trait]
 impl HttpClient for HttpClientMock {
-    async fn ping(
-        &self,
-        _endpoint: &str,
-        _request_timeout: Duration,
-        _request_headers: HashMap<String, String>,
-    ) -> PingResponse {
+    fn locations(&self) -> Vec<String> {
+        self.locations.clone()
+    }
+
+    async fn ping(&self, location: &str, request: &PingRequest) -> PingResponse {
+        self.requests.lock().await.push(request.clone());
+        if let Some(response) = self.next_location_responses.lock().await.remove(location) {
+            return response;
+        }
         let mut next_response = self.next_response.lock().await;
         next_response.take().unwrap()
     }
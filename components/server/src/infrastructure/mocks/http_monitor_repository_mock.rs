@@ -1,11 +1,11 @@
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::{entity_metadata::{FilterableMetadata, MetadataFilter}, http_monitor::{HttpMonitor, HttpMonitorStatus}},
+    entities::{entity_metadata::{FilterableMetadata, MetadataFilter}, http_monitor::{HttpMonitor, HttpMonitorStatus}, http_monitor_ping::{HttpMonitorPing, HttpMonitorPingRollup}, tls_certificate::ObservedTlsCertificate},
     ports::{
         http_monitor_repository::{
             HttpMonitorRepository, ListHttpMonitorsOutput, NewHttpMonitor,
@@ -18,12 +18,14 @@ use crate::domain::{
 #[derive(Clone)]
 pub struct HttpMonitorRepositoryMock {
     pub state: Arc<Mutex<Vec<HttpMonitor>>>,
+    pub pings: Arc<Mutex<Vec<HttpMonitorPing>>>,
 }
 
 impl HttpMonitorRepositoryMock {
     pub fn new() -> Self {
         Self {
             state: Arc::new(Mutex::new(Vec::new())),
+            pings: Arc::new(Mutex::new(Vec::new())),
         }
     }
 }
@@ -124,6 +126,18 @@ impl HttpMonitorRepository for HttpMonitorRepositoryMock {
             archived_at: None,
             request_headers: monitor.request_headers,
             request_timeout_ms: monitor.request_timeout_ms,
+            assertions: monitor.assertions,
+            locations: monitor.locations,
+            location_quorum: monitor.location_quorum,
+            request_method: monitor.request_method,
+            request_body: monitor.request_body,
+            request_body_content_type: monitor.request_body_content_type,
+            authentication: monitor.authentication,
+            redirect_policy: monitor.redirect_policy,
+            probe_engine: monitor.probe_engine,
+            tls_certificate: Default::default(),
+            tls_expiry_warning_days: monitor.tls_expiry_warning_days,
+            kind_settings: monitor.kind_settings,
         };
 
         let mut state = self.state.lock().await;
@@ -150,6 +164,16 @@ impl HttpMonitorRepository for HttpMonitorRepositoryMock {
             existing.email_notification_enabled = monitor.email_notification_enabled;
             existing.push_notification_enabled = monitor.push_notification_enabled;
             existing.sms_notification_enabled = monitor.sms_notification_enabled;
+            existing.locations = monitor.locations;
+            existing.location_quorum = monitor.location_quorum;
+            existing.request_method = monitor.request_method;
+            existing.request_body = monitor.request_body;
+            existing.request_body_content_type = monitor.request_body_content_type;
+            existing.authentication = monitor.authentication;
+            existing.redirect_policy = monitor.redirect_policy;
+            existing.probe_engine = monitor.probe_engine;
+            existing.tls_expiry_warning_days = monitor.tls_expiry_warning_days;
+            existing.kind_settings = monitor.kind_settings;
             Ok(true)
         } else {
             Ok(false)
@@ -191,6 +215,9 @@ impl HttpMonitorRepository for HttpMonitorRepositoryMock {
             monitor.error_kind = command.error_kind;
             monitor.last_http_code = command.last_http_code;
             monitor.last_status_change_at = command.last_status_change_at;
+            if let Some(tls_certificate) = command.tls_certificate {
+                monitor.tls_certificate = ObservedTlsCertificate(Some(tls_certificate));
+            }
             monitor.last_ping_at = Some(Utc::now());
             if monitor.first_ping_at.is_none() {
                 monitor.first_ping_at = Some(Utc::now());
@@ -206,12 +233,65 @@ impl HttpMonitorRepository for HttpMonitorRepositoryMock {
     ) -> anyhow::Result<FilterableMetadata> {
         Ok(FilterableMetadata { items: vec![] })
     }
+
+    async fn record_ping(
+        &self,
+        _transaction: &mut Self::Transaction,
+        ping: HttpMonitorPing,
+    ) -> anyhow::Result<()> {
+        self.pings.lock().await.push(ping);
+        Ok(())
+    }
+
+    async fn list_pings(
+        &self,
+        _transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        monitor_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<HttpMonitorPing>> {
+        let pings = self.pings.lock().await;
+        Ok(pings
+            .iter()
+            .filter(|p| p.organization_id == organization_id && p.http_monitor_id == monitor_id)
+            .filter(|p| p.created_at >= from && p.created_at < to)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_ping_rollups(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        monitor_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<HttpMonitorPingRollup>> {
+        let pings = self
+            .list_pings(transaction, organization_id, monitor_id, from, to)
+            .await?;
+        let mut rollups: Vec<HttpMonitorPingRollup> = vec![];
+        for ping in pings {
+            let period_start = ping.created_at.duration_trunc(TimeDelta::hours(1))?;
+            match rollups.iter_mut().find(|r| r.period_start == period_start) {
+                Some(rollup) => rollup.add_ping(&ping),
+                None => {
+                    let mut rollup = HttpMonitorPingRollup::empty(period_start);
+                    rollup.add_ping(&ping);
+                    rollups.push(rollup);
+                }
+            }
+        }
+        rollups.sort_by_key(|r| r.period_start);
+        Ok(rollups)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::entities::{entity_metadata::EntityMetadata, http_monitor::RequestHeaders};
+    use crate::domain::entities::{entity_metadata::EntityMetadata, http_monitor::RequestHeaders, tls_certificate::DEFAULT_TLS_EXPIRY_WARNING_DAYS};
 
     fn create_test_monitor(org_id: Uuid, url: &str, status: HttpMonitorStatus) -> NewHttpMonitor {
         NewHttpMonitor {
@@ -228,6 +308,17 @@ mod tests {
             sms_notification_enabled: false,
             request_headers: RequestHeaders::default(),
             request_timeout_ms: 2000,
+            assertions: Default::default(),
+            locations: vec![],
+            location_quorum: None,
+            request_method: Default::default(),
+            request_body: None,
+            request_body_content_type: None,
+            authentication: Default::default(),
+            redirect_policy: Default::default(),
+            probe_engine: Default::default(),
+            tls_expiry_warning_days: DEFAULT_TLS_EXPIRY_WARNING_DAYS,
+            kind_settings: Default::default(),
         }
     }
 
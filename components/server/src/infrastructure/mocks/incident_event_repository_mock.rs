@@ -5,7 +5,7 @@ use tokio::sync::Mutex;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::incident_event::IncidentEvent,
+    entities::{incident_event::IncidentEvent, webhook::WEBHOOK_EVENT_TYPES},
     ports::{
         incident_event_repository::IncidentEventRepository,
         transactional_repository::{TransactionMock, TransactionalRepository},
@@ -15,12 +15,14 @@ use crate::domain::{
 #[derive(Clone)]
 pub struct IncidentEventRepositoryMock {
     pub state: Arc<Mutex<Vec<IncidentEvent>>>,
+    pub pending_webhook_dispatch: Arc<Mutex<Vec<IncidentEvent>>>,
 }
 
 impl IncidentEventRepositoryMock {
     pub fn new() -> Self {
         Self {
             state: Arc::new(Mutex::new(Vec::new())),
+            pending_webhook_dispatch: Arc::new(Mutex::new(Vec::new())),
         }
     }
 }
@@ -49,11 +51,24 @@ impl IncidentEventRepository for IncidentEventRepositoryMock {
         _tx: &mut Self::Transaction,
         event: IncidentEvent,
     ) -> anyhow::Result<()> {
+        if WEBHOOK_EVENT_TYPES.contains(&event.event_type) {
+            self.pending_webhook_dispatch.lock().await.push(event.clone());
+        }
         let mut state = self.state.lock().await;
         state.push(event);
         Ok(())
     }
 
+    async fn take_events_pending_webhook_dispatch(
+        &self,
+        _tx: &mut Self::Transaction,
+        limit: u32,
+    ) -> anyhow::Result<Vec<IncidentEvent>> {
+        let mut pending = self.pending_webhook_dispatch.lock().await;
+        let n = pending.len().min(limit as usize);
+        Ok(pending.drain(..n).collect())
+    }
+
     async fn get_incident_timeline(
         &self,
         organization_id: Uuid,
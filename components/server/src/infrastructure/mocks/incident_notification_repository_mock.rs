@@ -53,7 +53,7 @@ impl IncidentNotificationRepository for IncidentNotificationRepositoryMock {
         let now = Utc::now();
 
         // Sort by due date to ensure we get the most urgent notifications first
-        state.sort_by(|a, b| a.notification_due_at.cmp(&b.notification_due_at));
+        state.sort_by_key(|a| a.notification_due_at);
 
         let due_notifications: Vec<IncidentNotification> = state
             .iter()
@@ -137,6 +137,7 @@ mod tests {
                     last_ping: HttpMonitorIncidentCausePing {
                         error_kind: HttpMonitorErrorKind::Timeout,
                         http_code: None,
+                        failed_assertion: None,
                     },
                     previous_pings: HashSet::new(),
                 }),
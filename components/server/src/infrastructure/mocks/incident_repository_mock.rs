@@ -66,6 +66,10 @@ impl IncidentRepository for IncidentRepositoryMock {
         let id = Uuid::new_v4();
         let (incident_source_type, incident_source_id) = match incident.source {
             IncidentSource::HttpMonitor { id } => (IncidentSourceType::HttpMonitor, id),
+            IncidentSource::Task { id } => (IncidentSourceType::Task, id),
+            IncidentSource::Manual { id } => (IncidentSourceType::Manual, id),
+            IncidentSource::TlsCertificate { id } => (IncidentSourceType::TlsCertificate, id),
+            IncidentSource::ExternalAlert { id } => (IncidentSourceType::ExternalAlert, id),
         };
         let incident = Incident {
             organization_id: incident.organization_id,
@@ -79,6 +83,7 @@ impl IncidentRepository for IncidentRepositoryMock {
             incident_source_type,
             incident_source_id,
             acknowledged_by: vec![],
+            title: incident.title,
             metadata: incident.metadata,
         };
 
@@ -96,13 +101,44 @@ impl IncidentRepository for IncidentRepositoryMock {
         let state = self.state.lock().await;
 
 
-        // right now the filter map is unnecessary, but it will change when we have more incident sources
-        #[allow(clippy::unnecessary_filter_map)]
         let include_http_monitors_ids = opts
             .include_sources
             .iter()
             .filter_map(|s| match s {
                 IncidentSource::HttpMonitor { id } => Some(*id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let include_tasks_ids = opts
+            .include_sources
+            .iter()
+            .filter_map(|s| match s {
+                IncidentSource::Task { id } => Some(*id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let include_tls_certificates_ids = opts
+            .include_sources
+            .iter()
+            .filter_map(|s| match s {
+                IncidentSource::TlsCertificate { id } => Some(*id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let include_external_alerts_ids = opts
+            .include_sources
+            .iter()
+            .filter_map(|s| match s {
+                IncidentSource::ExternalAlert { id } => Some(*id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let include_manual_ids = opts
+            .include_sources
+            .iter()
+            .filter_map(|s| match s {
+                IncidentSource::Manual { id } => Some(*id),
+                _ => None,
             })
             .collect::<Vec<_>>();
 
@@ -119,6 +155,14 @@ impl IncidentRepository for IncidentRepositoryMock {
                 opts.include_sources.is_empty()
                     || (i.incident_source_type == IncidentSourceType::HttpMonitor
                         && include_http_monitors_ids.contains(&i.incident_source_id))
+                    || (i.incident_source_type == IncidentSourceType::Task
+                        && include_tasks_ids.contains(&i.incident_source_id))
+                    || (i.incident_source_type == IncidentSourceType::TlsCertificate
+                        && include_tls_certificates_ids.contains(&i.incident_source_id))
+                    || (i.incident_source_type == IncidentSourceType::ExternalAlert
+                        && include_external_alerts_ids.contains(&i.incident_source_id))
+                    || (i.incident_source_type == IncidentSourceType::Manual
+                        && include_manual_ids.contains(&i.incident_source_id))
             })
             .filter(|i| {
                 opts.from_date
@@ -219,6 +263,7 @@ mod tests {
                 last_ping: HttpMonitorIncidentCausePing {
                     error_kind: HttpMonitorErrorKind::HttpCode,
                     http_code: Some(500),
+                    failed_assertion: None,
                 },
                 previous_pings: HashSet::new(),
             })),
@@ -226,6 +271,7 @@ mod tests {
             priority: IncidentPriority::Critical,
             source: IncidentSource::HttpMonitor { id: Uuid::new_v4() },
             metadata: EntityMetadata::default(),
+            title: None,
         }
     }
 
@@ -234,8 +280,8 @@ mod tests {
         let repo = IncidentRepositoryMock::new();
         let org_id = Uuid::new_v4();
         let incident = create_test_incident(org_id);
-        let source_id = match incident.source {
-            IncidentSource::HttpMonitor { id } => id,
+        let IncidentSource::HttpMonitor { id: source_id } = incident.source else {
+            panic!("test incident source should be an HTTP monitor");
         };
 
         let mut tx = repo.begin_transaction().await?;
@@ -347,6 +393,55 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_list_incidents_with_manual_source_filter() -> anyhow::Result<()> {
+        let repo = IncidentRepositoryMock::new();
+        let org_id = Uuid::new_v4();
+        let mut tx = repo.begin_transaction().await?;
+
+        let manual_incident_id = Uuid::new_v4();
+        let mut manual_incident = create_test_incident(org_id);
+        manual_incident.source = IncidentSource::Manual {
+            id: manual_incident_id,
+        };
+        manual_incident.title = Some("Database migration".to_string());
+
+        repo.create_incident(&mut tx, manual_incident).await?;
+        repo.create_incident(&mut tx, create_test_incident(org_id))
+            .await?;
+
+        let result = repo
+            .list_incidents(
+                &mut tx,
+                org_id,
+                ListIncidentsOpts {
+                    include_statuses: &[],
+                    include_priorities: &[],
+                    include_sources: &[IncidentSource::Manual {
+                        id: manual_incident_id,
+                    }],
+                    from_date: None,
+                    to_date: None,
+                    offset: 0,
+                    limit: 10,
+                    order_by: OrderIncidentsBy::CreatedAt,
+                    order_direction: OrderDirection::Desc,
+                    metadata_filter: MetadataFilter::default(),
+                },
+            )
+            .await?;
+
+        assert_eq!(result.incidents.len(), 1);
+        assert_eq!(
+            result.incidents[0].incident_source_type,
+            IncidentSourceType::Manual
+        );
+        assert_eq!(result.incidents[0].incident_source_id, manual_incident_id);
+        assert_eq!(result.total_filtered_incidents, 1);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_list_incidents_with_date_filter() -> anyhow::Result<()> {
         let repo = IncidentRepositoryMock::new();
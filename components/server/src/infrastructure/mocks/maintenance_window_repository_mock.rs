@@ -0,0 +1,115 @@
+use axum::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::maintenance_window::MaintenanceWindow,
+    ports::{
+        maintenance_window_repository::{MaintenanceWindowRepository, NewMaintenanceWindow},
+        transactional_repository::{TransactionMock, TransactionalRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct MaintenanceWindowRepositoryMock {
+    pub state: Arc<Mutex<Vec<MaintenanceWindow>>>,
+}
+
+impl MaintenanceWindowRepositoryMock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionalRepository for MaintenanceWindowRepositoryMock {
+    type Transaction = TransactionMock;
+
+    async fn begin_transaction(&self) -> anyhow::Result<Self::Transaction> {
+        Ok(TransactionMock)
+    }
+
+    async fn commit_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn rollback_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MaintenanceWindowRepository for MaintenanceWindowRepositoryMock {
+    async fn list_maintenance_windows(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<MaintenanceWindow>> {
+        let state = self.state.lock().await;
+        let mut windows = state
+            .iter()
+            .filter(|w| w.organization_id == organization_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        windows.sort_by_key(|w| std::cmp::Reverse(w.starts_at));
+        Ok(windows)
+    }
+
+    async fn list_maintenance_windows_ending_after(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        date: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<MaintenanceWindow>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .filter(|w| w.organization_id == organization_id)
+            .filter(|w| {
+                w.ends_at.is_none_or(|ends_at| {
+                    ends_at + Duration::seconds(w.duration_seconds.unwrap_or(0) as i64) > date
+                })
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn create_maintenance_window(
+        &self,
+        _tx: &mut Self::Transaction,
+        maintenance_window: NewMaintenanceWindow,
+    ) -> anyhow::Result<Uuid> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        self.state.lock().await.push(MaintenanceWindow {
+            organization_id: maintenance_window.organization_id,
+            id,
+            title: maintenance_window.title,
+            description: maintenance_window.description,
+            starts_at: maintenance_window.starts_at,
+            ends_at: maintenance_window.ends_at,
+            cron_schedule: maintenance_window.cron_schedule,
+            duration_seconds: maintenance_window.duration_seconds,
+            scope: maintenance_window.scope,
+            created_at: now,
+            updated_at: now,
+        });
+        Ok(id)
+    }
+
+    async fn delete_maintenance_window(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let mut state = self.state.lock().await;
+        let len = state.len();
+        state.retain(|w| !(w.organization_id == organization_id && w.id == id));
+        Ok(state.len() < len)
+    }
+}
@@ -5,4 +5,14 @@ pub mod incident_notification_repository_mock;
 pub mod http_client_mock;
 pub mod file_storage_mock;
 pub mod task_repository_mock;
-pub mod task_run_repository_mock;
\ No newline at end of file
+pub mod task_run_repository_mock;
+pub mod escalation_policy_repository_mock;
+pub mod webhook_client_mock;
+pub mod webhook_endpoint_repository_mock;
+pub mod webhook_delivery_repository_mock;
+pub mod status_page_repository_mock;
+pub mod maintenance_window_repository_mock;
+pub mod on_call_schedule_repository_mock;
+pub mod notification_preferences_repository_mock;
+pub mod alert_integration_repository_mock;
+pub mod task_run_event_repository_mock;
\ No newline at end of file
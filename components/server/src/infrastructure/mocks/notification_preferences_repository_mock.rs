@@ -0,0 +1,86 @@
+use axum::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::notification_preferences::NotificationPreferences,
+    ports::{
+        notification_preferences_repository::NotificationPreferencesRepository,
+        transactional_repository::{TransactionMock, TransactionalRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct NotificationPreferencesRepositoryMock {
+    pub state: Arc<Mutex<Vec<NotificationPreferences>>>,
+}
+
+impl NotificationPreferencesRepositoryMock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionalRepository for NotificationPreferencesRepositoryMock {
+    type Transaction = TransactionMock;
+
+    async fn begin_transaction(&self) -> anyhow::Result<Self::Transaction> {
+        Ok(TransactionMock)
+    }
+
+    async fn commit_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn rollback_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl NotificationPreferencesRepository for NotificationPreferencesRepositoryMock {
+    async fn get_notification_preferences(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<NotificationPreferences>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .find(|p| p.organization_id == organization_id && p.user_id == user_id)
+            .cloned())
+    }
+
+    async fn list_notification_preferences(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<NotificationPreferences>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .filter(|p| p.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn upsert_notification_preferences(
+        &self,
+        _tx: &mut Self::Transaction,
+        preferences: NotificationPreferences,
+    ) -> anyhow::Result<()> {
+        let mut state = self.state.lock().await;
+        match state.iter_mut().find(|p| {
+            p.organization_id == preferences.organization_id && p.user_id == preferences.user_id
+        }) {
+            Some(existing) => *existing = preferences,
+            None => state.push(preferences),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::on_call_schedule::{OnCallOverride, OnCallSchedule},
+    ports::{
+        on_call_schedule_repository::{
+            NewOnCallOverride, NewOnCallSchedule, OnCallScheduleRepository,
+        },
+        transactional_repository::{TransactionMock, TransactionalRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct OnCallScheduleRepositoryMock {
+    pub state: Arc<Mutex<Vec<OnCallSchedule>>>,
+    pub overrides: Arc<Mutex<Vec<OnCallOverride>>>,
+}
+
+impl OnCallScheduleRepositoryMock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(Vec::new())),
+            overrides: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionalRepository for OnCallScheduleRepositoryMock {
+    type Transaction = TransactionMock;
+
+    async fn begin_transaction(&self) -> anyhow::Result<Self::Transaction> {
+        Ok(TransactionMock)
+    }
+
+    async fn commit_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn rollback_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OnCallScheduleRepository for OnCallScheduleRepositoryMock {
+    async fn list_on_call_schedules(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<OnCallSchedule>> {
+        let state = self.state.lock().await;
+        let mut schedules = state
+            .iter()
+            .filter(|s| s.organization_id == organization_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        schedules.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(schedules)
+    }
+
+    async fn get_on_call_schedule(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<OnCallSchedule>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .find(|s| s.organization_id == organization_id && s.id == id)
+            .cloned())
+    }
+
+    async fn create_on_call_schedule(
+        &self,
+        _tx: &mut Self::Transaction,
+        schedule: NewOnCallSchedule,
+    ) -> anyhow::Result<Uuid> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        self.state.lock().await.push(OnCallSchedule {
+            organization_id: schedule.organization_id,
+            id,
+            name: schedule.name,
+            description: schedule.description,
+            time_zone: schedule.time_zone,
+            rotations: schedule.rotations,
+            created_at: now,
+            updated_at: now,
+        });
+        Ok(id)
+    }
+
+    async fn update_on_call_schedule(
+        &self,
+        _tx: &mut Self::Transaction,
+        id: Uuid,
+        schedule: NewOnCallSchedule,
+    ) -> anyhow::Result<bool> {
+        let mut state = self.state.lock().await;
+        let Some(existing) = state
+            .iter_mut()
+            .find(|s| s.organization_id == schedule.organization_id && s.id == id)
+        else {
+            return Ok(false);
+        };
+        existing.name = schedule.name;
+        existing.description = schedule.description;
+        existing.time_zone = schedule.time_zone;
+        existing.rotations = schedule.rotations;
+        existing.updated_at = Utc::now();
+        Ok(true)
+    }
+
+    async fn delete_on_call_schedule(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let mut state = self.state.lock().await;
+        let len = state.len();
+        state.retain(|s| !(s.organization_id == organization_id && s.id == id));
+        self.overrides
+            .lock()
+            .await
+            .retain(|o| !(o.organization_id == organization_id && o.on_call_schedule_id == id));
+        Ok(state.len() < len)
+    }
+
+    async fn list_on_call_overrides_ending_after(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        on_call_schedule_id: Uuid,
+        date: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<OnCallOverride>> {
+        let overrides = self.overrides.lock().await;
+        let mut overrides = overrides
+            .iter()
+            .filter(|o| {
+                o.organization_id == organization_id
+                    && o.on_call_schedule_id == on_call_schedule_id
+                    && o.ends_at > date
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        overrides.sort_by_key(|o| o.starts_at);
+        Ok(overrides)
+    }
+
+    async fn create_on_call_override(
+        &self,
+        _tx: &mut Self::Transaction,
+        on_call_override: NewOnCallOverride,
+    ) -> anyhow::Result<Uuid> {
+        let id = Uuid::new_v4();
+        self.overrides.lock().await.push(OnCallOverride {
+            organization_id: on_call_override.organization_id,
+            id,
+            on_call_schedule_id: on_call_override.on_call_schedule_id,
+            user_id: on_call_override.user_id,
+            starts_at: on_call_override.starts_at,
+            ends_at: on_call_override.ends_at,
+            created_at: Utc::now(),
+        });
+        Ok(id)
+    }
+
+    async fn delete_on_call_override(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        on_call_schedule_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let mut overrides = self.overrides.lock().await;
+        let len = overrides.len();
+        overrides.retain(|o| {
+            !(o.organization_id == organization_id
+                && o.on_call_schedule_id == on_call_schedule_id
+                && o.id == id)
+        });
+        Ok(overrides.len() < len)
+    }
+}
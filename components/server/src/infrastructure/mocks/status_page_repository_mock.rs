@@ -0,0 +1,138 @@
+use axum::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::status_page::StatusPage,
+    ports::{
+        status_page_repository::{NewStatusPage, StatusPageRepository},
+        transactional_repository::{TransactionMock, TransactionalRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct StatusPageRepositoryMock {
+    pub state: Arc<Mutex<Vec<StatusPage>>>,
+}
+
+impl StatusPageRepositoryMock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionalRepository for StatusPageRepositoryMock {
+    type Transaction = TransactionMock;
+
+    async fn begin_transaction(&self) -> anyhow::Result<Self::Transaction> {
+        Ok(TransactionMock)
+    }
+
+    async fn commit_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn rollback_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StatusPageRepository for StatusPageRepositoryMock {
+    async fn list_status_pages(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<StatusPage>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .filter(|p| p.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_status_page(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<StatusPage>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .find(|p| p.organization_id == organization_id && p.id == id)
+            .cloned())
+    }
+
+    async fn get_status_page_by_slug(
+        &self,
+        _tx: &mut Self::Transaction,
+        slug: &str,
+    ) -> anyhow::Result<Option<StatusPage>> {
+        let state = self.state.lock().await;
+        Ok(state.iter().find(|p| p.slug == slug).cloned())
+    }
+
+    async fn create_status_page(
+        &self,
+        _tx: &mut Self::Transaction,
+        status_page: NewStatusPage,
+    ) -> anyhow::Result<Uuid> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        self.state.lock().await.push(StatusPage {
+            organization_id: status_page.organization_id,
+            id,
+            slug: status_page.slug,
+            title: status_page.title,
+            description: status_page.description,
+            components: status_page.components,
+            visibility: status_page.visibility,
+            created_at: now,
+            updated_at: now,
+        });
+        Ok(id)
+    }
+
+    async fn update_status_page(
+        &self,
+        _tx: &mut Self::Transaction,
+        id: Uuid,
+        status_page: NewStatusPage,
+    ) -> anyhow::Result<bool> {
+        let mut state = self.state.lock().await;
+        match state
+            .iter_mut()
+            .find(|p| p.organization_id == status_page.organization_id && p.id == id)
+        {
+            Some(existing) => {
+                existing.slug = status_page.slug;
+                existing.title = status_page.title;
+                existing.description = status_page.description;
+                existing.components = status_page.components;
+                existing.visibility = status_page.visibility;
+                existing.updated_at = Utc::now();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn delete_status_page(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let mut state = self.state.lock().await;
+        let len = state.len();
+        state.retain(|p| !(p.organization_id == organization_id && p.id == id));
+        Ok(state.len() < len)
+    }
+}
@@ -1,11 +1,20 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use std::{sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::Duration,
+};
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::task::{BoundaryTask, TaskId, TaskStatus},
+    entities::{
+        entity_metadata::{
+            FilterableMetadata, FilterableMetadataItem, FilterableMetadataValue, MetadataFilter,
+        },
+        task::{generate_task_ping_token, BoundaryTask, TaskId, TaskStatus},
+    },
     ports::{
         task_repository::{ListTasksOutput, TaskRepository},
         transactional_repository::{TransactionMock, TransactionalRepository},
@@ -15,12 +24,15 @@ use crate::domain::{
 #[derive(Clone)]
 pub struct TaskRepositoryMock {
     pub state: Arc<Mutex<Vec<BoundaryTask>>>,
+    /// The ping tokens of the tasks, by task UUID
+    pub ping_tokens: Arc<Mutex<HashMap<Uuid, String>>>,
 }
 
 impl TaskRepositoryMock {
     pub fn new() -> Self {
         Self {
             state: Arc::new(Mutex::new(Vec::new())),
+            ping_tokens: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 }
@@ -57,11 +69,21 @@ impl TaskRepository for TaskRepositoryMock {
             .cloned())
     }
 
+    async fn get_task_for_update(
+        &self,
+        transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<Option<BoundaryTask>> {
+        self.get_task(transaction, organization_id, task_id).await
+    }
+
     async fn list_tasks(
         &self,
         organization_id: Uuid,
         include_statuses: Vec<TaskStatus>,
         query: String,
+        metadata_filter: MetadataFilter,
         limit: u32,
         offset: u32,
     ) -> anyhow::Result<ListTasksOutput> {
@@ -84,6 +106,7 @@ impl TaskRepository for TaskRepositoryMock {
                         .map(|d| d.to_lowercase().contains(&query.to_lowercase()))
                         .unwrap_or(false)
             })
+            .filter(|t| metadata_filter.matches(&t.metadata))
             .cloned()
             .collect();
 
@@ -101,6 +124,34 @@ impl TaskRepository for TaskRepositoryMock {
         })
     }
 
+    async fn get_filterable_metadata(&self, organization_id: Uuid) -> anyhow::Result<FilterableMetadata> {
+        let state = self.state.lock().await;
+        let mut value_counts: BTreeMap<&String, BTreeMap<&String, u64>> = BTreeMap::new();
+        for (key, value) in state
+            .iter()
+            .filter(|t| t.organization_id == organization_id)
+            .flat_map(|t| t.metadata.records.iter())
+        {
+            *value_counts.entry(key).or_default().entry(value).or_default() += 1;
+        }
+
+        let items = value_counts
+            .into_iter()
+            .map(|(key, values)| FilterableMetadataItem {
+                key: key.clone(),
+                key_cardinality: values.len() as u64,
+                distinct_values: values
+                    .into_iter()
+                    .map(|(value, value_count)| FilterableMetadataValue {
+                        value: value.clone(),
+                        value_count,
+                    })
+                    .collect(),
+            })
+            .collect();
+        Ok(FilterableMetadata { items })
+    }
+
     async fn upsert_task(
         &self,
         _transaction: &mut Self::Transaction,
@@ -115,14 +166,26 @@ impl TaskRepository for TaskRepositoryMock {
             existing.name = task.name;
             existing.description = task.description;
             existing.status = task.status;
+            existing.previous_status = task.previous_status;
+            existing.last_status_change_at = task.last_status_change_at;
             existing.cron_schedule = task.cron_schedule;
             existing.next_due_at = task.next_due_at;
             existing.start_window_seconds = task.start_window_seconds;
             existing.lateness_window_seconds = task.lateness_window_seconds;
             existing.heartbeat_timeout_seconds = task.heartbeat_timeout_seconds;
+            existing.max_duration_seconds = task.max_duration_seconds;
+            existing.time_zone = task.time_zone;
+            existing.max_concurrent_runs = task.max_concurrent_runs;
+            existing.upstream_task_ids = task.upstream_task_ids;
+            existing.metadata = task.metadata;
             Ok(task.id)
         } else {
             let id = task.id.clone();
+            // like the database, which generates the ping token of new tasks
+            self.ping_tokens
+                .lock()
+                .await
+                .insert(task.uuid, generate_task_ping_token());
             state.push(task);
             Ok(id)
         }
@@ -189,27 +252,100 @@ impl TaskRepository for TaskRepositoryMock {
             .cloned()
             .collect())
     }
+
+    async fn list_downstream_tasks(
+        &self,
+        _transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<Vec<BoundaryTask>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .filter(|t| t.organization_id == organization_id && t.upstream_task_ids.contains(task_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_task_by_ping_token(
+        &self,
+        _transaction: &mut Self::Transaction,
+        ping_token: &str,
+    ) -> anyhow::Result<Option<BoundaryTask>> {
+        let state = self.state.lock().await;
+        let ping_tokens = self.ping_tokens.lock().await;
+        Ok(state
+            .iter()
+            .find(|t| ping_tokens.get(&t.uuid).is_some_and(|token| token == ping_token))
+            .cloned())
+    }
+
+    async fn get_task_ping_token(
+        &self,
+        _transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<Option<String>> {
+        let state = self.state.lock().await;
+        let ping_tokens = self.ping_tokens.lock().await;
+        Ok(state
+            .iter()
+            .find(|t| t.id == *task_id && t.organization_id == organization_id)
+            .and_then(|t| ping_tokens.get(&t.uuid).cloned()))
+    }
+
+    async fn set_task_ping_token(
+        &self,
+        _transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+        ping_token: &str,
+    ) -> anyhow::Result<bool> {
+        let state = self.state.lock().await;
+        let Some(task) = state
+            .iter()
+            .find(|t| t.id == *task_id && t.organization_id == organization_id)
+        else {
+            return Ok(false);
+        };
+        self.ping_tokens
+            .lock()
+            .await
+            .insert(task.uuid, ping_token.to_string());
+        Ok(true)
+    }
+
+    async fn delete_task(
+        &self,
+        _transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+    ) -> anyhow::Result<bool> {
+        let mut state = self.state.lock().await;
+        let Some(index) = state
+            .iter()
+            .position(|t| t.id == *task_id && t.organization_id == organization_id)
+        else {
+            return Ok(false);
+        };
+        let task = state.remove(index);
+        self.ping_tokens.lock().await.remove(&task.uuid);
+        for t in state.iter_mut().filter(|t| t.organization_id == organization_id) {
+            t.upstream_task_ids.retain(|id| id != task_id);
+        }
+        Ok(true)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::entities::entity_metadata::EntityMetadata;
 
     fn create_test_task(org_id: Uuid, name: &str, status: TaskStatus) -> BoundaryTask {
         BoundaryTask {
-            organization_id: org_id,
-            id: TaskId::new(name.to_string()).expect("Invalid task ID"),
-            name: name.to_string(),
-            description: None,
-            status,
-            previous_status: None,
-            last_status_change_at: None,
-            cron_schedule: None,
             next_due_at: Some(Utc::now()),
-            start_window_seconds: 300,
-            lateness_window_seconds: 600,
-            heartbeat_timeout_seconds: 60,
-            created_at: Utc::now(),
+            ..BoundaryTask::test_task(org_id, name, status)
         }
     }
 
@@ -233,5 +369,40 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_list_tasks_filters_by_metadata() -> anyhow::Result<()> {
+        let repo = TaskRepositoryMock::new();
+        let org_id = Uuid::new_v4();
+
+        for (name, team) in [("billing-export", "billing"), ("search-reindex", "search")] {
+            let task = BoundaryTask {
+                metadata: EntityMetadata {
+                    records: HashMap::from([("team".to_string(), team.to_string())]),
+                },
+                ..create_test_task(org_id, name, TaskStatus::Healthy)
+            };
+            repo.upsert_task(&mut TransactionMock, task).await?;
+        }
+        repo.upsert_task(&mut TransactionMock, create_test_task(org_id, "untagged", TaskStatus::Healthy))
+            .await?;
+
+        let metadata_filter = MetadataFilter {
+            items: HashMap::from([("team".to_string(), vec!["billing".to_string()])]),
+        };
+        let output = repo
+            .list_tasks(org_id, vec![], String::new(), metadata_filter, 10, 0)
+            .await?;
+        assert_eq!(output.total_tasks, 3);
+        assert_eq!(output.total_filtered_tasks, 1);
+        assert_eq!(output.tasks[0].name, "billing-export");
+
+        let filterable_metadata = repo.get_filterable_metadata(org_id).await?;
+        assert_eq!(filterable_metadata.items.len(), 1);
+        assert_eq!(filterable_metadata.items[0].key, "team");
+        assert_eq!(filterable_metadata.items[0].key_cardinality, 2);
+
+        Ok(())
+    }
+
     // Add more tests similar to http_monitor_repository_mock tests...
 }
@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        task::TaskId,
+        task_run_event::{TaskRunEvent, TaskRunEventType},
+    },
+    ports::{
+        task_run_event_repository::{ListTaskRunEventsOutput, TaskRunEventRepository},
+        transactional_repository::{TransactionMock, TransactionalRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct TaskRunEventRepositoryMock {
+    pub state: Arc<Mutex<Vec<TaskRunEvent>>>,
+}
+
+impl TaskRunEventRepositoryMock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionalRepository for TaskRunEventRepositoryMock {
+    type Transaction = TransactionMock;
+
+    async fn begin_transaction(&self) -> anyhow::Result<Self::Transaction> {
+        Ok(TransactionMock)
+    }
+
+    async fn commit_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn rollback_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TaskRunEventRepository for TaskRunEventRepositoryMock {
+    async fn create_task_run_event(
+        &self,
+        _transaction: &mut Self::Transaction,
+        event: TaskRunEvent,
+    ) -> anyhow::Result<()> {
+        self.state.lock().await.push(event);
+        Ok(())
+    }
+
+    async fn list_task_run_events(
+        &self,
+        _transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+        task_run_id: &str,
+        task_run_started_at: DateTime<Utc>,
+        limit: u32,
+        offset: u32,
+    ) -> anyhow::Result<ListTaskRunEventsOutput> {
+        let state = self.state.lock().await;
+        let mut events: Vec<TaskRunEvent> = state
+            .iter()
+            .filter(|e| {
+                e.organization_id == organization_id
+                    && e.task_id == *task_id
+                    && e.task_run_id == task_run_id
+                    && e.task_run_started_at == task_run_started_at
+            })
+            .cloned()
+            .collect();
+        events.sort_by_key(|e| e.created_at);
+        let total_events = events.len() as u32;
+
+        Ok(ListTaskRunEventsOutput {
+            events: events
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect(),
+            total_events,
+        })
+    }
+
+    async fn list_latest_task_run_events(
+        &self,
+        _transaction: &mut Self::Transaction,
+        organization_id: Uuid,
+        task_id: &TaskId,
+        task_run_id: &str,
+        task_run_started_at: DateTime<Utc>,
+        event_type: TaskRunEventType,
+        limit: u32,
+    ) -> anyhow::Result<Vec<TaskRunEvent>> {
+        let state = self.state.lock().await;
+        let mut events: Vec<TaskRunEvent> = state
+            .iter()
+            .filter(|e| {
+                e.organization_id == organization_id
+                    && e.task_id == *task_id
+                    && e.task_run_id == task_run_id
+                    && e.task_run_started_at == task_run_started_at
+                    && e.event_type == event_type
+            })
+            .cloned()
+            .collect();
+        events.sort_by_key(|e| std::cmp::Reverse(e.created_at));
+        events.truncate(limit as usize);
+
+        Ok(events)
+    }
+}
@@ -56,7 +56,7 @@ impl TaskRunRepository for TaskRunRepositoryMock {
     ) -> anyhow::Result<ListTaskRunsOutput> {
         let state = self.state.lock().await;
 
-        let filtered_runs: Vec<BoundaryTaskRun> = state
+        let mut filtered_runs: Vec<BoundaryTaskRun> = state
             .iter()
             .filter(|r| r.organization_id == organization_id && r.task_id == *opts.task_id)
             .filter(|r| {
@@ -64,6 +64,8 @@ impl TaskRunRepository for TaskRunRepositoryMock {
             })
             .cloned()
             .collect();
+        // most recent runs first, like the database adapter
+        filtered_runs.sort_by_key(|r| std::cmp::Reverse(r.started_at));
 
         let start = opts.offset as usize;
         let end = (opts.offset + opts.limit) as usize;
@@ -81,15 +83,18 @@ impl TaskRunRepository for TaskRunRepositoryMock {
         organization_id: Uuid,
         task_id: &TaskId,
         started_at: DateTime<Utc>,
+        run_id: Option<&str>,
     ) -> anyhow::Result<Option<BoundaryTaskRun>> {
         let state = self.state.lock().await;
         Ok(state
             .iter()
-            .find(|r| {
+            .filter(|r| {
                 r.organization_id == organization_id
                     && r.task_id == *task_id
                     && r.started_at == started_at
+                    && run_id.is_none_or(|run_id| r.run_id == run_id)
             })
+            .min_by(|a, b| a.run_id.cmp(&b.run_id))
             .cloned())
     }
 
@@ -100,9 +105,22 @@ impl TaskRunRepository for TaskRunRepositoryMock {
     ) -> anyhow::Result<()> {
         let mut state = self.state.lock().await;
 
+        if task_run.status == TaskRunStatus::Running
+            && state.iter().any(|r| {
+                r.organization_id == task_run.organization_id
+                    && r.task_id == task_run.task_id
+                    && r.run_id == task_run.run_id
+                    && r.status == TaskRunStatus::Running
+                    && r.started_at != task_run.started_at
+            })
+        {
+            anyhow::bail!("Another run with the id {} is already running", task_run.run_id);
+        }
+
         if let Some(existing) = state.iter_mut().find(|r| {
             r.organization_id == task_run.organization_id
                 && r.task_id == task_run.task_id
+                && r.run_id == task_run.run_id
                 && r.started_at == task_run.started_at
         }) {
             existing.status = task_run.status;
@@ -124,6 +142,15 @@ impl TaskRunRepository for TaskRunRepositoryMock {
     ) -> anyhow::Result<Vec<(BoundaryTask, BoundaryTaskRun)>> {
         unimplemented!("list_dead_task_runs is not implemented for this mock")
     }
+
+    async fn list_timed_out_task_runs(
+        &self,
+        _transaction: &mut Self::Transaction,
+        _now: DateTime<Utc>,
+        _limit: u32,
+    ) -> anyhow::Result<Vec<(BoundaryTask, BoundaryTaskRun)>> {
+        unimplemented!("list_timed_out_task_runs is not implemented for this mock")
+    }
 }
 
 #[cfg(test)]
@@ -134,6 +161,7 @@ mod tests {
         BoundaryTaskRun {
             organization_id: org_id,
             task_id: TaskId::new(task_id.to_string()).expect("Valid test task ID"),
+            run_id: Uuid::new_v4().to_string(),
             status,
             started_at: Utc::now(),
             updated_at: Utc::now(),
@@ -0,0 +1,53 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::domain::ports::webhook_client::{WebhookClient, WebhookResponse};
+
+#[derive(Debug, Clone)]
+pub struct SentWebhookRequest {
+    pub url: String,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Clone)]
+pub struct WebhookClientMock {
+    /// Every request that was posted, in order
+    pub requests: Arc<Mutex<Vec<SentWebhookRequest>>>,
+    /// Returned by the next call to `post`. Defaults to a HTTP 200 response
+    pub next_response: Arc<Mutex<Option<WebhookResponse>>>,
+}
+
+impl WebhookClientMock {
+    pub fn new() -> Self {
+        Self {
+            requests: Arc::new(Mutex::new(Vec::new())),
+            next_response: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub async fn set_next_response(&self, response: WebhookResponse) {
+        *self.next_response.lock().await = Some(response);
+    }
+}
+
+#[async_trait]
+impl WebhookClient for WebhookClientMock {
+    async fn post(&self, url: &str, headers: HashMap<String, String>, body: Vec<u8>) -> WebhookResponse {
+        self.requests.lock().await.push(SentWebhookRequest {
+            url: url.to_string(),
+            headers,
+            body,
+        });
+        self.next_response
+            .lock()
+            .await
+            .take()
+            .unwrap_or(WebhookResponse {
+                http_code: Some(200),
+                error: None,
+            })
+    }
+}
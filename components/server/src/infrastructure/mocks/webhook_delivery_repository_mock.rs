@@ -0,0 +1,138 @@
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::webhook::{WebhookDelivery, WebhookDeliveryStatus},
+    ports::{
+        transactional_repository::{TransactionMock, TransactionalRepository},
+        webhook_delivery_repository::{
+            ListWebhookDeliveriesOutput, NewWebhookDelivery, WebhookDeliveryRepository,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct WebhookDeliveryRepositoryMock {
+    pub state: Arc<Mutex<Vec<WebhookDelivery>>>,
+}
+
+impl WebhookDeliveryRepositoryMock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionalRepository for WebhookDeliveryRepositoryMock {
+    type Transaction = TransactionMock;
+
+    async fn begin_transaction(&self) -> anyhow::Result<Self::Transaction> {
+        Ok(TransactionMock)
+    }
+
+    async fn commit_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn rollback_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for WebhookDeliveryRepositoryMock {
+    async fn create_webhook_delivery(
+        &self,
+        _tx: &mut Self::Transaction,
+        delivery: NewWebhookDelivery,
+    ) -> anyhow::Result<Uuid> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        self.state.lock().await.push(WebhookDelivery {
+            organization_id: delivery.organization_id,
+            id,
+            webhook_endpoint_id: delivery.webhook_endpoint_id,
+            incident_id: delivery.incident_id,
+            event_type: delivery.event_type,
+            payload: delivery.payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: Some(now),
+            last_attempt_at: None,
+            last_response_http_code: None,
+            last_error: None,
+            created_at: now,
+        });
+        Ok(id)
+    }
+
+    async fn claim_next_deliveries_to_send(
+        &self,
+        _tx: &mut Self::Transaction,
+        limit: u32,
+        claimed_until: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<WebhookDelivery>> {
+        let mut state = self.state.lock().await;
+        let now = Utc::now();
+        Ok(state
+            .iter_mut()
+            .filter(|d| {
+                d.status == WebhookDeliveryStatus::Pending
+                    && d.next_attempt_at.is_some_and(|at| at <= now)
+            })
+            .take(limit as usize)
+            .map(|d| {
+                d.next_attempt_at = Some(claimed_until);
+                d.clone()
+            })
+            .collect())
+    }
+
+    async fn update_webhook_delivery(
+        &self,
+        _tx: &mut Self::Transaction,
+        delivery: &WebhookDelivery,
+    ) -> anyhow::Result<()> {
+        let mut state = self.state.lock().await;
+        if let Some(existing) = state
+            .iter_mut()
+            .find(|d| d.organization_id == delivery.organization_id && d.id == delivery.id)
+        {
+            *existing = delivery.clone();
+        }
+        Ok(())
+    }
+
+    async fn list_webhook_deliveries(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        webhook_endpoint_id: Uuid,
+        limit: u32,
+        offset: u32,
+    ) -> anyhow::Result<ListWebhookDeliveriesOutput> {
+        let state = self.state.lock().await;
+        let mut deliveries: Vec<_> = state
+            .iter()
+            .filter(|d| {
+                d.organization_id == organization_id && d.webhook_endpoint_id == webhook_endpoint_id
+            })
+            .cloned()
+            .collect();
+        deliveries.sort_by_key(|d| std::cmp::Reverse(d.created_at));
+        let total_deliveries = deliveries.len() as u32;
+        Ok(ListWebhookDeliveriesOutput {
+            deliveries: deliveries
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect(),
+            total_deliveries,
+        })
+    }
+}
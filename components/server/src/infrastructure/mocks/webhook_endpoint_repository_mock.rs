@@ -0,0 +1,126 @@
+use axum::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::webhook::WebhookEndpoint,
+    ports::{
+        transactional_repository::{TransactionMock, TransactionalRepository},
+        webhook_endpoint_repository::{NewWebhookEndpoint, WebhookEndpointRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct WebhookEndpointRepositoryMock {
+    pub state: Arc<Mutex<Vec<WebhookEndpoint>>>,
+}
+
+impl WebhookEndpointRepositoryMock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionalRepository for WebhookEndpointRepositoryMock {
+    type Transaction = TransactionMock;
+
+    async fn begin_transaction(&self) -> anyhow::Result<Self::Transaction> {
+        Ok(TransactionMock)
+    }
+
+    async fn commit_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn rollback_transaction(&self, _transaction: Self::Transaction) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl WebhookEndpointRepository for WebhookEndpointRepositoryMock {
+    async fn list_webhook_endpoints(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+    ) -> anyhow::Result<Vec<WebhookEndpoint>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .filter(|e| e.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_webhook_endpoint(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<WebhookEndpoint>> {
+        let state = self.state.lock().await;
+        Ok(state
+            .iter()
+            .find(|e| e.organization_id == organization_id && e.id == id)
+            .cloned())
+    }
+
+    async fn create_webhook_endpoint(
+        &self,
+        _tx: &mut Self::Transaction,
+        endpoint: NewWebhookEndpoint,
+    ) -> anyhow::Result<Uuid> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        self.state.lock().await.push(WebhookEndpoint {
+            organization_id: endpoint.organization_id,
+            id,
+            url: endpoint.url,
+            secret: endpoint.secret,
+            event_types: endpoint.event_types,
+            metadata_filter: endpoint.metadata_filter,
+            created_at: now,
+            updated_at: now,
+        });
+        Ok(id)
+    }
+
+    async fn update_webhook_endpoint(
+        &self,
+        _tx: &mut Self::Transaction,
+        id: Uuid,
+        endpoint: NewWebhookEndpoint,
+    ) -> anyhow::Result<bool> {
+        let mut state = self.state.lock().await;
+        match state
+            .iter_mut()
+            .find(|e| e.organization_id == endpoint.organization_id && e.id == id)
+        {
+            Some(existing) => {
+                existing.url = endpoint.url;
+                existing.event_types = endpoint.event_types;
+                existing.metadata_filter = endpoint.metadata_filter;
+                existing.updated_at = Utc::now();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn delete_webhook_endpoint(
+        &self,
+        _tx: &mut Self::Transaction,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let mut state = self.state.lock().await;
+        let len = state.len();
+        state.retain(|e| !(e.organization_id == organization_id && e.id == id));
+        Ok(state.len() < len)
+    }
+}